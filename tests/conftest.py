@@ -21,3 +21,52 @@ def state_dir(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
 @pytest.fixture()
 def cli_runner() -> CliRunner:
     return CliRunner()
+
+
+@pytest.fixture()
+def config_dir(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    path = tmp_path / "wx_config"
+    (path / "profiles").mkdir(parents=True)
+    monkeypatch.setenv("WX_CONFIG_DIR", str(path))
+    monkeypatch.delenv("WX_PROFILE", raising=False)
+    return path
+
+
+@pytest.fixture(autouse=True)
+def _no_fallback_ai_backends(monkeypatch: pytest.MonkeyPatch) -> None:
+    # Keep a developer's real keys from sending test prompts to fallback backends.
+    for name in ("OPENAI_API_KEY", "ANTHROPIC_API_KEY", "OLLAMA_MODEL"):
+        monkeypatch.delenv(name, raising=False)
+
+
+@pytest.fixture(autouse=True)
+def _no_background_fetches(monkeypatch: pytest.MonkeyPatch) -> None:
+    # Forecasts look up NASA POWER normals, the SPC outlook and the ensemble spread, and
+    # METAR reports the aviation advisories and PIREPs; keep online-mode tests off the network.
+    monkeypatch.setattr("wx.normals.fetch_monthly_normals", lambda lat, lon: None)
+    monkeypatch.setattr("wx.orchestrator.fetch_spc_outlook", lambda lat, lon, **kwargs: None)
+    monkeypatch.setattr("wx.orchestrator.fetch_ensemble_members", lambda lat, lon, **kwargs: None)
+    monkeypatch.setattr("wx.orchestrator.fetch_airsigmets", lambda **kwargs: [])
+    monkeypatch.setattr("wx.orchestrator.fetch_gairmets", lambda **kwargs: [])
+    monkeypatch.setattr("wx.orchestrator.fetch_pireps", lambda station, **kwargs: [])
+
+
+@pytest.fixture(autouse=True)
+def _fast_http_retries(monkeypatch: pytest.MonkeyPatch) -> None:
+    # Retries back off for seconds, geocodes are spaced a second apart, and --timeout changes a
+    # process-wide policy.
+    from wx.httpclient import HttpPolicy
+
+    monkeypatch.setattr("wx.httpclient._sleep", lambda seconds: None)
+    monkeypatch.setattr("wx.fetchers.GEOCODE_INTERVAL", 0.0)
+    monkeypatch.setattr("wx.httpclient._policy", HttpPolicy())
+
+
+@pytest.fixture(autouse=True)
+def _builtin_hazards_only(monkeypatch: pytest.MonkeyPatch) -> None:
+    # Profile hazard rules are registered process-wide; start each test with the built-ins.
+    from wx import risk
+
+    monkeypatch.setattr(risk, "_SCORERS", dict(risk._SCORERS))
+    monkeypatch.setattr(risk, "HAZARD_ALERT_KEYWORDS", dict(risk.HAZARD_ALERT_KEYWORDS))
+    monkeypatch.setattr(risk, "_CUSTOM_HAZARDS", [])
@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.airquality import air_quality_pack, apply_air_quality, aqi_category, wants_air_quality
+from wx.forecaster import ForecasterResponse
+
+NOW = datetime(2026, 7, 1, 15, tzinfo=UTC)
+
+
+def _hourly(aqi: list[float], start: datetime = NOW) -> dict[str, list]:
+    hours = len(aqi)
+    return {
+        "time": [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)],
+        "us_aqi": aqi,
+        "us_aqi_pm2_5": [40.0] * hours,
+        "us_aqi_ozone": [value - 5 for value in aqi],
+        "pm2_5": [10.0] * hours,
+        "pm10": [15.0] * hours,
+        "ozone": [140.0] * hours,
+    }
+
+
+def _response(actions: list[str]) -> ForecasterResponse:
+    return ForecasterResponse(
+        sections={"summary": "Hot and sunny.", "actions": actions},
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Bottom line: hot afternoon.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+
+
+def test_categories_and_triggers() -> None:
+    assert [aqi_category(value) for value in (12, 100, 101, 180, 250, 420)] == [
+        "Good",
+        "Moderate",
+        "Unhealthy for Sensitive Groups",
+        "Unhealthy",
+        "Very Unhealthy",
+        "Hazardous",
+    ]
+    assert aqi_category(None) is None
+    assert wants_air_quality("air") and wants_air_quality("Is it too smoky to run tonight?")
+    assert not wants_air_quality("wind") and not wants_air_quality("Flights out of the airport?")
+
+
+def test_pack_finds_elevated_ozone_window() -> None:
+    pack = air_quality_pack(_hourly([60, 80, 110, 135, 120, 90]), now=NOW + timedelta(minutes=20))
+
+    assert pack["now"]["aqi"] == 60 and pack["now"]["category"] == "Moderate"
+    assert pack["now"]["pollutant"] == "ozone"
+    assert pack["peak"]["aqi"] == 135
+    assert pack["elevated"] == {
+        "start_iso": (NOW + timedelta(hours=2)).isoformat(),
+        "end_iso": (NOW + timedelta(hours=4)).isoformat(),
+        "max_aqi": 135,
+        "category": "Unhealthy for Sensitive Groups",
+        "pollutant": "ozone",
+    }
+    assert air_quality_pack(_hourly([40, 45]), now=NOW)["elevated"] is None
+    assert air_quality_pack(None) is None
+
+
+def test_health_decision_added_when_story_misses_it() -> None:
+    pack = air_quality_pack(_hourly([60, 80, 110, 135, 120, 90]), now=NOW)
+    response = _response(["Drink plenty of water."])
+
+    added = apply_air_quality(response, pack, "America/Denver")
+
+    assert added[0] == (
+        "Limit outdoor running and other exertion from Wed 11 AM MDT to Wed 1 PM MDT if you have asthma,"
+        " heart or lung conditions, or are older or a child: ozone AQI up to 135 (Unhealthy for Sensitive Groups)."
+    )
+    assert response.sections["actions"][0] == "Drink plenty of water."
+    assert response.sections["actions"][1:] == added
+    assert response.sections["air_quality"] == (
+        "AQI 60 now (Moderate), mostly ozone; peaking at 135 (Unhealthy for Sensitive Groups) around Wed 12 PM MDT."
+    )
+
+    covered = _response(["Run before 10 AM; ozone builds this afternoon."])
+    assert apply_air_quality(covered, pack) == []
+    assert covered.sections["actions"] == ["Run before 10 AM; ozone builds this afternoon."]
+    assert covered.meta["air_quality"] == {"aqi": 60, "added": []}
+
+
+def test_air_focus_fetches_aqi_into_story(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    start = datetime.now(UTC).replace(minute=0, second=0, microsecond=0)
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Denver", "lat": 39.7, "lon": -105.0}
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: None)
+    monkeypatch.setattr(
+        orchestrator_module, "get_air_quality", lambda lat, lon, offline=False: _hourly([160, 170], start)
+    )
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ('{"actions": []}', "test", None))
+
+    plain = orchestrator.handle_forecast("Denver", when_text=None, horizon="12h", focus="wind", verbose=False)
+    assert "air_quality" not in plain.feature_pack
+
+    result = orchestrator.handle_forecast("Denver", when_text=None, horizon="12h", focus="air", verbose=False)
+    assert result.feature_pack["air_quality"]["elevated"]["max_aqi"] == 170
+    assert result.response.sections["actions"][0].startswith("Move runs and strenuous outdoor activity indoors")
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "Air quality: AQI 160 now (Unhealthy)" in console.file.getvalue()
@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, date, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.astro import astro_day, astro_pack, golden_light, moon_phase
+from wx.clock import clock_label, zone
+
+SEATTLE = (47.61, -122.33)
+
+
+def _near(actual: datetime | None, expected: datetime, minutes: int = 3) -> bool:
+    return actual is not None and abs(actual - expected) <= timedelta(minutes=minutes)
+
+
+def test_seattle_solstice_sun_times() -> None:
+    day = astro_day(*SEATTLE, date(2026, 6, 21), "America/Los_Angeles")
+
+    # NOAA: sunrise 05:11 PDT, sunset 21:11 PDT.
+    assert _near(day.sunrise, datetime(2026, 6, 21, 12, 11, tzinfo=UTC))
+    assert _near(day.sunset, datetime(2026, 6, 22, 4, 11, tzinfo=UTC))
+    assert day.dawn < day.sunrise < day.solar_noon < day.sunset < day.dusk
+    assert day.golden_evening[0] < day.sunset < day.golden_evening[1]
+    assert clock_label(day.solar_noon, zone("America/Los_Angeles"), day=False).startswith("1:1")
+    assert day.polar is None and 15 * 60 < day.day_length.total_seconds() / 60 < 16 * 60
+
+
+def test_polar_day_and_moon_phase() -> None:
+    tromso = astro_day(69.65, 18.96, date(2026, 6, 21), "Europe/Oslo")
+    assert tromso.sunrise is None and tromso.polar == "polar day"
+    assert tromso.day_length == timedelta(hours=24)
+
+    # Full moon on 2026-03-03, new moon on 2026-03-19.
+    assert moon_phase(datetime(2026, 3, 3, 12, tzinfo=UTC))["name"] == "Full Moon"
+    assert moon_phase(datetime(2026, 3, 3, 12, tzinfo=UTC))["illumination"] > 0.97
+    assert moon_phase(datetime(2026, 3, 19, 2, tzinfo=UTC))["name"] == "New Moon"
+
+
+def test_golden_hour_cloud_cover() -> None:
+    day = astro_day(*SEATTLE, date(2026, 10, 16), "America/Los_Angeles")
+    start = day.golden_evening[0].replace(minute=0, second=0, microsecond=0) - timedelta(hours=3)
+    hourly = {
+        "time": [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(8)],
+        "cloud_cover": [100, 100, 100, 40, 60, 100, 100, 100],
+    }
+
+    pack = astro_pack(day, hourly)
+
+    assert pack["golden_evening"]["cloud_cover"] == 50
+    assert pack["golden_evening"]["light"] == "broken clouds, best colour"
+    assert pack["golden_morning"]["cloud_cover"] is None
+    assert [golden_light(value) for value in (5, 95, None)] == ["clear", "overcast", None]
+
+
+def test_sun_command_and_forecast_astro_line(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    monkeypatch.setattr(
+        orchestrator.provider,
+        "geocode",
+        lambda place: {"resolved": "Seattle", "lat": SEATTLE[0], "lon": SEATTLE[1], "tz": "America/Los_Angeles"},
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: None)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    report = orchestrator.handle_sun("Seattle", days=3)
+    assert [day["date"] for day in report.days] == [
+        (datetime.now(zone("America/Los_Angeles")).date() + timedelta(days=offset)).isoformat() for offset in range(3)
+    ]
+    assert report.sources == ["computed locally"]
+    console = Console(file=io.StringIO(), width=200)
+    render.render_sun(report, console=console)
+    output = console.file.getvalue()
+    assert "Sun and moon — Seattle" in output and "Golden PM" in output
+
+    result = orchestrator.handle_forecast("Seattle", when_text=None, horizon="12h", focus=None, verbose=False)
+    assert result.feature_pack["astro"]["sunrise_iso"]
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "golden hour" in console.file.getvalue()
@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import io
+import json
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.attribution import attribution_meta, credit_line, feature_pack_sources
+
+
+def test_feature_pack_sources_follow_blocks() -> None:
+    feature_pack = {"units": {}, "place": {"resolved": "Tulsa"}, "periods": [{}], "spc_outlook": {"category": "slight"}}
+
+    assert feature_pack_sources(feature_pack, ("nws", "open-meteo")) == ["nws", "open-meteo", "geonames", "spc"]
+    assert feature_pack_sources({"place": {}, "alerts_quick": []}) == []
+    assert feature_pack_sources({"normals": {"date": "2026-10-16"}}) == ["nasa-power"]
+
+
+def test_credit_line_cites_licences_that_require_it() -> None:
+    assert credit_line(["nws", "osm", "nws", "unknown"]) == "Data: NWS/NOAA, © OpenStreetMap contributors (ODbL 1.0)"
+    assert credit_line([]) is None
+    (entry,) = attribution_meta(["open-meteo"])
+    assert entry == {
+        "source": "open-meteo",
+        "credit": "Open-Meteo.com",
+        "license": "CC BY 4.0",
+        "url": "https://open-meteo.com/",
+        "cite_license": True,
+    }
+
+
+def test_forecast_json_meta_and_footer(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True, provider="nws")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "UTC"}
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [{"event": "Wind Advisory"}])
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: None)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast("Tulsa", when_text=None, horizon="12h", focus=None, verbose=False)
+
+    assert result.attribution == ["geonames", "nws"]
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=True, debug=False, verbose=False)
+    meta = json.loads(console.file.getvalue())["data"]["meta"]
+    assert [entry["credit"] for entry in meta["attribution"]] == ["GeoNames", "NWS/NOAA"]
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert console.file.getvalue().rstrip().endswith("Data: GeoNames (CC BY 4.0), NWS/NOAA")
@@ -0,0 +1,202 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.aviation import (
+    advisories_at,
+    altitude_band,
+    decode_metar,
+    decode_pirep,
+    decode_taf,
+    flight_category,
+    is_icao,
+    metar_summary,
+    nearby_pireps,
+)
+
+METAR = "METAR KSEA 161753Z 18012G22KT 1 1/2SM -SHRA BR BKN008 OVC015CB 09/08 A2992 RMK AO2 SLP134"
+TAF = (
+    "TAF KSEA 161720Z 1618/1718 18010KT P6SM BKN025 FM162200 20015G25KT 3SM -RA OVC012 "
+    "TEMPO 1700/1704 1SM +RA BR OVC006 PROB30 TEMPO 1706/1710 TSRA BKN020CB"
+)
+NOW = datetime(2026, 1, 14, 18, 0, tzinfo=UTC)
+# A box around Seattle and one over Spokane.
+AROUND_SEATTLE = [{"lat": 48.0, "lon": -123.0}, {"lat": 48.0, "lon": -121.5}, {"lat": 47.0, "lon": -121.5}, {"lat": 47.0, "lon": -123.0}]
+AROUND_SPOKANE = [{"lat": "48.0", "lon": "-118.0"}, {"lat": "48.0", "lon": "-117.0"}, {"lat": "47.0", "lon": "-117.0"}]
+
+
+def test_decode_metar() -> None:
+    metar = decode_metar(METAR)
+
+    assert metar["station"] == "KSEA"
+    assert (metar["wind_dir"], metar["wind_kt"], metar["gust_kt"]) == (180, 12, 22)
+    assert metar["visibility_sm"] == 1.5
+    assert metar["weather"] == ["light showers of rain", "mist"]
+    assert metar["ceiling_ft"] == 800 and metar["category"] == "IFR"
+    assert (metar["temp_c"], metar["dewpoint_c"], metar["altimeter_inhg"]) == (9, 8, 29.92)
+
+    metric = decode_metar("EGLL 161750Z AUTO 24008KT 9999 VCSH FEW030 M02/M05 Q1012 NOSIG")
+    assert metric["visibility_sm"] == 10.0 and metric["weather"] == ["showers nearby"]
+    assert (metric["temp_c"], metric["dewpoint_c"], metric["category"]) == (-2, -5, "VFR")
+    assert metar_summary(metric) == "VFR · wind 240° 8 kt · 10 SM showers nearby · few 3,000 ft · -2/-5°C · A29.88"
+
+
+def test_decode_taf_change_groups() -> None:
+    taf = decode_taf(TAF)
+
+    assert taf["station"] == "KSEA" and taf["valid"] == "day 16 18Z–day 17 18Z"
+    assert [period["change"] for period in taf["periods"]] == [
+        "Initially",
+        "From day 16 22:00Z",
+        "Temporarily day 17 00Z–day 17 04Z",
+        "30% chance temporarily day 17 06Z–day 17 10Z",
+    ]
+    assert [period["category"] for period in taf["periods"]] == ["MVFR", "MVFR", "IFR", "MVFR"]
+    assert taf["periods"][0]["visibility_over"] is True
+    assert taf["periods"][3]["weather"] == ["thunderstorm with rain"]
+
+
+def test_flight_category_and_icao() -> None:
+    assert flight_category(None, None) is None
+    assert flight_category(400, 10) == "LIFR"
+    assert flight_category(None, 2.5) == "IFR"
+    assert flight_category(3000, 10) == "MVFR"
+    assert flight_category(5000, 6) == "VFR"
+    assert is_icao("KSEA") and is_icao("EGLL")
+    assert not is_icao("Rome") and not is_icao("SEA") and not is_icao(None)
+
+
+def test_metar_for_place_uses_nearest_airport(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    requested = []
+
+    def fake_metars(*, station=None, near=None, offline=False):
+        requested.append(station or near)
+        if station:
+            return []
+        return [
+            {"icaoId": "KBFI", "name": "Seattle/Boeing Fld", "lat": 47.53, "lon": -122.30, "rawOb": METAR},
+            {"icaoId": "KPAE", "name": "Everett/Paine Fld", "lat": 47.92, "lon": -122.28, "rawOb": METAR},
+        ]
+
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Seattle", "lat": 47.61, "lon": -122.33}
+    )
+    monkeypatch.setattr(orchestrator_module, "fetch_metars", fake_metars)
+    monkeypatch.setattr(orchestrator_module, "fetch_taf", lambda station, offline=False: TAF)
+
+    report = orchestrator.handle_metar("Rome")
+
+    assert requested == ["ROME", (47.61, -122.33)]
+    assert (report.station, report.place, report.distance_km) == ("KBFI", "Seattle", 9.2)
+    assert report.metar["category"] == "IFR" and len(report.taf["periods"]) == 4
+    assert report.sources == ["aviationweather.gov METAR", "aviationweather.gov TAF"]
+
+    console = Console(file=io.StringIO(), width=160)
+    render.render_metar(report, console=console, raw=True)
+    output = console.file.getvalue()
+    assert "KBFI — Seattle/Boeing Fld (9 km from Seattle)" in output
+    assert "30% chance temporarily" in output and METAR in output
+
+
+def test_aviation_focus_adds_metar_to_feature_pack(monkeypatch) -> None:
+    settings = config.Settings(offline=True, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    station = [{"icaoId": "KSEA", "name": "Seattle-Tacoma Intl", "lat": 47.44, "lon": -122.31, "rawOb": METAR}]
+    monkeypatch.setattr(orchestrator_module, "fetch_metars", lambda **kwargs: station)
+    monkeypatch.setattr(orchestrator_module, "fetch_taf", lambda station, offline=False: None)
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": place, "lat": 47.44, "lon": -122.31}
+    )
+
+    result = orchestrator.handle_forecast("KSEA", when_text=None, horizon="12h", focus="aviation", verbose=False)
+
+    assert result.feature_pack["aviation"]["station"] == "KSEA"
+    assert result.feature_pack["aviation"]["metar"]["category"] == "IFR"
+    assert result.feature_pack["place"]["resolved"] == "KSEA (Seattle-Tacoma Intl)"
+    plain = orchestrator.handle_forecast("KSEA", when_text=None, horizon="12h", focus="wind", verbose=False)
+    assert "aviation" not in plain.feature_pack
+
+
+def test_advisories_over_the_airport_with_altitude_bands() -> None:
+    epoch = int(NOW.timestamp())
+    airsigmets = [
+        {
+            "airSigmetType": "SIGMET", "hazard": "TURB", "severity": "SEV", "altitudeLow1": 24000,
+            "altitudeHi1": 41000, "validTimeFrom": epoch - 600, "validTimeTo": epoch + 3600,
+            "coords": AROUND_SEATTLE, "rawAirSigmet": "WSUS01 KKCI SIGMET NOVEMBER 3",
+        },
+        {"airSigmetType": "SIGMET", "hazard": "CONVECTIVE", "altitudeHi1": 45000, "coords": AROUND_SEATTLE},
+        {"airSigmetType": "AIRMET", "hazard": "ICE", "coords": AROUND_SPOKANE},
+        {"airSigmetType": "AIRMET", "hazard": "IFR", "validTimeTo": epoch - 60, "coords": AROUND_SEATTLE},
+    ]  # fmt: skip
+    gairmets = [
+        {"hazard": "ICE", "validTime": "2026-01-14T15:00:00Z", "base": "FZL", "top": "160", "coords": AROUND_SEATTLE},
+        {"hazard": "ICE", "validTime": "2026-01-14T18:00:00Z", "base": "FZL", "top": "FL200", "coords": AROUND_SEATTLE},
+        {"hazard": "IFR", "validTime": "2026-01-14T18:00:00Z", "coords": AROUND_SPOKANE},
+        {"hazard": "FZLVL", "validTime": "2026-01-14T18:00:00Z", "base": "040", "coords": AROUND_SEATTLE},
+    ]  # fmt: skip
+
+    advisories = advisories_at(airsigmets, gairmets, 47.44, -122.31, now=NOW)
+
+    assert [(item.kind, item.severity, item.hazard, item.band) for item in advisories] == [
+        ("SIGMET", "sev", "turbulence", "FL240–FL410"),
+        ("Convective SIGMET", None, "convection", "up to FL450"),
+        ("G-AIRMET", None, "icing", "FZL–FL200"),
+    ]
+    assert advisories[0].valid_to == "2026-01-14T19:00:00+00:00" and advisories[2].top_ft == 20000
+    assert altitude_band(0, 12000) == "SFC–12,000 ft" and altitude_band(8000, 12000) == "8,000–12,000 ft"
+
+
+def test_pireps_decoded_and_filtered_by_age_and_distance() -> None:
+    raw = "SEA UUA /OV SEA090020/TM 1745/FL080/TP B737/TB LGT-MOD CHOP 080-120/IC LGT RIME/RM SMOOTH ABV"
+    items = [
+        {"rawOb": raw, "lat": 47.44, "lon": -121.9, "obsTime": int((NOW - timedelta(minutes=15)).timestamp())},
+        {"rawOb": "PAE UA /OV PAE/TM 1650/FL350/TP A320/TB NEG", "lat": 47.9, "lon": -122.28, "obsTime": "2026-01-14T16:50:00Z"},
+        {"rawOb": "GEG UA /OV GEG/TM 1740/FL100/TP C172/IC MOD MX", "lat": 47.62, "lon": -117.53, "obsTime": "2026-01-14T17:40:00Z"},
+        {"rawOb": "SEA UA /OV SEA/TM 1500/FL050/TP B738/TB SEV", "lat": 47.44, "lon": -122.31, "obsTime": "2026-01-14T15:00:00Z"},
+    ]  # fmt: skip
+
+    assert decode_pirep(raw)["turbulence"] == "light to moderate chop 8,000–12,000 ft"
+    pireps = nearby_pireps(items, 47.44, -122.31, now=NOW)
+
+    assert [(pirep.aircraft, pirep.altitude_ft, pirep.urgent) for pirep in pireps] == [
+        ("B737", 8000, True),
+        ("A320", 35000, False),
+    ]
+    assert pireps[0].icing == "light rime icing" and pireps[0].distance_km == 30.8
+    assert pireps[1].turbulence == "no turbulence" and pireps[1].observed == "2026-01-14T16:50:00+00:00"
+
+
+def test_metar_lists_advisories_and_pireps(monkeypatch) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    station = [{"icaoId": "KSEA", "name": "Seattle-Tacoma Intl", "lat": 47.44, "lon": -122.31, "rawOb": METAR}]
+    now = datetime.now(UTC)
+    sigmet = {
+        "airSigmetType": "AIRMET", "hazard": "IFR", "altitudeLow1": 0, "altitudeHi1": 8000, "coords": AROUND_SEATTLE,
+        "validTimeTo": int((now + timedelta(hours=2)).timestamp()),
+    }  # fmt: skip
+    pirep = {"rawOb": "SEA UA /OV SEA/TM 1745/FL060/TP DH8D/IC MOD RIME", "lat": 47.5, "lon": -122.3, "obsTime": int(now.timestamp())}
+    requested = []
+    monkeypatch.setattr(orchestrator_module, "fetch_metars", lambda **kwargs: station)
+    monkeypatch.setattr(orchestrator_module, "fetch_taf", lambda station, offline=False: None)
+    monkeypatch.setattr(orchestrator_module, "fetch_airsigmets", lambda offline=False: [sigmet])
+    monkeypatch.setattr(
+        orchestrator_module, "fetch_pireps", lambda station, hours=2, offline=False: requested.append(station) or [pirep]
+    )
+
+    report = orchestrator.handle_metar("KSEA")
+    console = Console(file=io.StringIO(), width=160)
+    render.render_metar(report, console=console)
+    output = console.file.getvalue()
+
+    assert requested == ["KSEA"]
+    assert report.sources[-2:] == ["aviationweather.gov SIGMET/AIRMET", "aviationweather.gov PIREP"]
+    assert "AIRMET IFR · SFC–8,000 ft · until" in output
+    assert "6,000 ft · DH8D · moderate rime icing" in output
+    assert orchestrator.handle_metar("KSEA", advisories=False).advisories == []
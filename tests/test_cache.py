@@ -0,0 +1,151 @@
+"""Tests for the provider fetch cache."""
+
+from __future__ import annotations
+
+import importlib
+
+from wx.cache import FORECAST_TTL, CachingProvider, FetchCache
+from wx.config import Settings
+from wx.forecaster import Forecaster
+
+cache_module = importlib.import_module("wx.cache")
+orchestrator_module = importlib.import_module("wx.orchestrator")
+
+
+class _CountingProvider:
+    name = "open-meteo"
+
+    def __init__(self) -> None:
+        self.calls: list[str] = []
+
+    def geocode(self, place):
+        self.calls.append(f"geocode:{place}")
+        return {"resolved": place, "lat": 30.27, "lon": -97.74} if place != "Nowhere" else None
+
+    def current(self, lat, lon):
+        self.calls.append("current")
+        return {"temp": 21.0}
+
+    def forecast(self, lat, lon, *, hours=48):
+        self.calls.append(f"forecast:{hours}")
+        return {"time": ["2026-10-16T00:00"], "temperature_2m": [20.0]}
+
+    def alerts(self, lat, lon):
+        self.calls.append("alerts")
+        return []
+
+
+def test_repeat_fetches_within_ttl_are_served_from_cache() -> None:
+    inner = _CountingProvider()
+    provider = CachingProvider(inner, FetchCache())
+
+    first = provider.geocode("Austin, TX")
+    assert provider.geocode(" austin, tx ") == first
+    provider.forecast(30.27, -97.74)
+    provider.forecast(30.27, -97.74)
+    provider.forecast(30.27, -97.74, hours=24)
+    provider.geocode("Nowhere")
+    provider.geocode("Nowhere")
+    provider.alerts(30.27, -97.74)
+    provider.alerts(30.27, -97.74)
+
+    assert inner.calls == [
+        "geocode:Austin, TX",
+        "forecast:48",
+        "forecast:24",
+        "geocode:Nowhere",
+        "geocode:Nowhere",
+        "alerts",
+        "alerts",
+    ]
+
+
+def test_geocodes_are_reused_across_refreshes_and_no_cache() -> None:
+    inner = _CountingProvider()
+    refreshed = CachingProvider(inner, FetchCache())
+    refreshed.refresh = True
+    refreshed.geocode("Austin, TX")
+    refreshed.geocode("Austin, TX")
+    refreshed.forecast(30.27, -97.74)
+    refreshed.forecast(30.27, -97.74)
+    uncached = CachingProvider(inner, FetchCache(), geocode_only=True)
+    uncached.geocode("Austin, TX")
+    uncached.geocode("Austin, TX")
+    uncached.alerts(30.27, -97.74)
+    uncached.alerts(30.27, -97.74)
+
+    assert inner.calls == ["geocode:Austin, TX", "forecast:48", "forecast:48", "geocode:Austin, TX", "alerts", "alerts"]
+
+
+def test_entries_expire_and_persist(tmp_path, monkeypatch) -> None:
+    path = tmp_path / "fetch_cache.json"
+    now = [1_000_000.0]
+    monkeypatch.setattr(cache_module.time, "time", lambda: now[0])
+
+    FetchCache(path).put("forecast", "k", {"time": []})
+    assert FetchCache(path).get("forecast", "k", FORECAST_TTL) == {"time": []}
+
+    now[0] += FORECAST_TTL + 1
+    assert FetchCache(path).get("forecast", "k", FORECAST_TTL) is None
+
+
+def test_orchestrator_cache_respects_flags_and_privacy(tmp_path) -> None:
+    state_file = tmp_path / "last_query.json"
+
+    private = orchestrator_module.Orchestrator(Settings(state_file=state_file))
+    shared = orchestrator_module.Orchestrator(Settings(state_file=state_file, privacy_mode=False))
+    uncached = orchestrator_module.Orchestrator(Settings(state_file=state_file, use_cache=False))
+    offline = orchestrator_module.Orchestrator(Settings(state_file=state_file, offline=True))
+
+    assert isinstance(private.provider, CachingProvider) and private.provider.cache.path is None
+    assert shared.provider.cache.path == tmp_path / "fetch_cache.json"
+    assert uncached.provider.geocode_only and uncached.provider.cache.path is None
+    assert not isinstance(offline.provider, CachingProvider)
+    assert private.forecaster.story_cache is private.provider.cache
+    assert uncached.forecaster.story_cache is None and offline.forecaster.story_cache is None
+
+
+def test_repeat_story_prompts_reuse_the_cached_story(monkeypatch) -> None:
+    forecaster = Forecaster(Settings(preferred_backend="ollama", ollama_model="llama3"))
+    forecaster.story_cache = FetchCache()
+    calls: list[str] = []
+
+    def ollama(system_prompt, prompt):
+        calls.append(prompt)
+        return '{"bottom_line": "Dry."}', "ollama:llama3", {"model": "llama3", "usage": {}}
+
+    monkeypatch.setattr(forecaster, "_call_ollama", ollama)
+    payload = {"query": "Austin", "intent": "forecast", "verbose": False, "explain_mode": False}
+    pack = {"location": {"name": "Austin"}, "hourly": {"temp": [20]}}
+
+    first = forecaster._invoke_provider({**payload, "feature_pack": pack})
+    again = forecaster._invoke_provider({**payload, "feature_pack": pack})
+    forecaster._invoke_provider({**payload, "feature_pack": {**pack, "hourly": {"temp": [25]}}})
+    forecaster.settings = Settings(preferred_backend="ollama", ollama_model="mistral")
+    forecaster._invoke_provider({**payload, "feature_pack": pack})
+
+    assert len(calls) == 3
+    assert again[:2] == first[:2] and again[2]["cached"] and again[2]["model"] == "llama3"
+
+
+def test_entries_prune_and_clear(tmp_path, monkeypatch) -> None:
+    path = tmp_path / "fetch_cache.json"
+    now = [1_000_000.0]
+    monkeypatch.setattr(cache_module.time, "time", lambda: now[0])
+    cache = FetchCache(path)
+    cache.put("alerts", "30.270,-97.740", [{"event": "Flood Watch"}])
+    now[0] += 600
+    cache.put("geocode", "austin, tx", {"resolved": "Austin"})
+
+    rows = cache.entries()
+    assert [(row["key"], row["expired"]) for row in rows] == [
+        ("alerts:30.270,-97.740", True),
+        ("geocode:austin, tx", False),
+    ]
+    assert cache.lookup("geocode:austin, tx")["v"] == {"resolved": "Austin"}
+    assert cache.stats()["namespaces"] == {"alerts": 1, "geocode": 1}
+
+    assert cache.prune() == 1
+    assert FetchCache(path).lookup("alerts:30.270,-97.740") is None
+    assert cache.clear() == 1
+    assert not path.exists()
@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from unittest.mock import MagicMock
+from unittest.mock import MagicMock, patch
 
 import pytest
 
@@ -159,3 +159,116 @@ class TestChatInterface:
         assert "What about tomorrow?" in enhanced
         # Should not include conversation context for first exchange
         # Context only added after multiple messages
+
+    def test_feature_pack_reused_between_turns(self, mock_settings, mock_orchestrator, mock_console):
+        """Test the location Feature Pack is fetched once and reused for follow-ups."""
+        from wx.chat import ChatInterface
+
+        mock_orchestrator.location_feature_pack.return_value = {"place": {"resolved": "Austin"}}
+        chat = ChatInterface(mock_settings, mock_orchestrator, mock_console)
+        chat.session.location_context = {"resolved": "Austin", "lat": 30.3, "lon": -97.7}
+
+        with patch("wx.render.render_result"):
+            chat._handle_user_message("Will it rain tonight?")
+            chat._handle_user_message("What about tomorrow evening?")
+
+        mock_orchestrator.location_feature_pack.assert_called_once()
+        for call in mock_orchestrator.handle_question.call_args_list:
+            assert call.kwargs["feature_pack"] == {"place": {"resolved": "Austin"}}
+
+    def test_units_command(self, mock_settings, mock_orchestrator, mock_console):
+        """Test /units switches settings and rejects unknown systems."""
+        from wx.chat import ChatInterface
+
+        mock_settings.units = "imperial"
+        chat = ChatInterface(mock_settings, mock_orchestrator, mock_console)
+        chat._set_units("metric")
+        assert mock_settings.units == "metric"
+        chat._set_units("kelvin")
+        assert mock_settings.units == "metric"
+
+
+class TestSessionRecording:
+    """Test chat --record / --replay."""
+
+    def test_record_and_replay_round_trip(self, tmp_path):
+        """Test recorded turns keep the raw model output and replay without fetching."""
+        from rich.console import Console
+
+        from wx.chat import SessionRecorder, load_recorded_session, replay_chat_session
+        from wx.forecaster import ForecasterResponse
+        from wx.orchestrator import OrchestrationResult
+
+        result = OrchestrationResult(
+            command="question",
+            query="Will it rain tonight?",
+            feature_pack={"place": {"resolved": "Austin"}},
+            response=ForecasterResponse(
+                sections={"answer": "Showers likely after 9 PM."},
+                confidence={"value": 70},
+                used_feature_fields=["hourly"],
+                bottom_line="Bring an umbrella.",
+                raw_text='{"answer": "Showers likely after 9 PM."}',
+                provider="openrouter:test",
+                prompt_summary="question",
+            ),
+            timings={"total": 1.2},
+            debug={},
+        )
+        path = tmp_path / "session.json"
+        recorder = SessionRecorder(path=path, units="metric", provider="nws")
+        recorder.record("/location Austin, TX")
+        recorder.record("Will it rain tonight?", result=result)
+        recorder.record("And tomorrow?", error="timeout")
+
+        data = load_recorded_session(path)
+        assert data["provider"] == "nws"
+        assert [turn["kind"] for turn in data["turns"]] == ["command", "question", "question"]
+        assert data["turns"][1]["result"]["response"]["raw_text"] == '{"answer": "Showers likely after 9 PM."}'
+
+        console = Console(record=True, width=100)
+        replay_chat_session(path, console)
+        output = console.export_text()
+        assert "Showers likely after 9 PM." in output
+        assert "Error processing your question: timeout" in output
+
+    def test_load_rejects_non_recordings(self, tmp_path):
+        """Test replaying an unrelated JSON file fails clearly."""
+        from wx.chat import load_recorded_session
+
+        path = tmp_path / "other.json"
+        path.write_text('{"feature_pack": {}}')
+        with pytest.raises(ValueError, match="not a wx chat recording"):
+            load_recorded_session(path)
+
+    def test_interface_records_commands(self, tmp_path):
+        """Test slash commands typed in chat are recorded."""
+        from wx.chat import ChatInterface, SessionRecorder
+
+        settings = MagicMock()
+        settings.units = "imperial"
+        recorder = SessionRecorder(path=tmp_path / "s.json", units="imperial", provider="open-meteo")
+        chat = ChatInterface(settings, MagicMock(), MagicMock(), recorder=recorder)
+
+        with patch("wx.chat.Prompt.ask", side_effect=["/units metric", "/quit"]):
+            chat.run()
+
+        assert [turn["input"] for turn in recorder.turns] == ["/units metric"]
+
+    def test_fixture_feature_pack_from_recording_or_json(self, tmp_path):
+        """Test --fixture accepts a chat recording or a --json result."""
+        from wx.chat import fixture_feature_pack
+
+        recording = tmp_path / "session.json"
+        recording.write_text(
+            '{"version": 1, "turns": [{"input": "/units metric"},'
+            ' {"input": "rain?", "result": {"feature_pack": {"place": {"resolved": "Omaha"}}}}]}'
+        )
+        result = tmp_path / "result.json"
+        result.write_text('{"command": "forecast", "feature_pack": {"place": {"resolved": "Denver"}}}')
+
+        assert fixture_feature_pack(recording)["place"]["resolved"] == "Omaha"
+        assert fixture_feature_pack(result)["place"]["resolved"] == "Denver"
+        result.write_text('{"turns": []}')
+        with pytest.raises(ValueError, match="no Feature Pack"):
+            fixture_feature_pack(result)
@@ -1,6 +1,11 @@
+from types import SimpleNamespace
+
 import pytest
+import typer
 
 from wx import cli
+from wx.locate import Location
+from wx.profile import Profile
 
 
 @pytest.mark.parametrize(
@@ -14,7 +19,31 @@ from wx import cli
         (["", "forecast", "Paris"], ["", "forecast", "Paris"]),
         (["--", "forecast"], ["--", "forecast"]),
         (["risk", "--hazards", "wind"], ["", "risk", "--hazards", "wind"]),
+        (["--template", "{{temp}}", "forecast", "Paris"], ["--template", "{{temp}}", "", "forecast", "Paris"]),
+        (["cache", "stats"], ["", "cache", "stats"]),
     ],
 )
 def test_normalize_invocation(argv, expected):
     assert cli._normalize_invocation(argv) == expected
+
+
+def test_place_falls_back_to_profile_then_detected_location(monkeypatch):
+    settings = SimpleNamespace(offline=False, privacy_mode=False)
+    ctx = SimpleNamespace(obj={"profile": Profile(default_location="Boise"), "settings": settings})
+    austin = Location(lat=30.2672, lon=-97.7431, label="Austin, Texas", source="ip")
+    monkeypatch.setattr(cli, "current_location", lambda offline, use_ip: austin if use_ip else None)
+    assert cli._place_or_default(ctx, "Paris") == "Paris"
+    assert cli._place_or_default(ctx, None) == "Boise"
+    assert cli._place_or_default(ctx, "Here") == "30.27,-97.74"
+
+    ctx.obj["profile"] = Profile()
+    assert cli._place_or_default(ctx, None) == "30.27,-97.74"
+
+    settings.privacy_mode = True
+    with pytest.raises(typer.BadParameter, match="IP geolocation is off in privacy mode"):
+        cli._place_or_default(ctx, None)
+
+    settings.privacy_mode = False
+    monkeypatch.setattr(cli, "current_location", lambda offline, use_ip: None)
+    with pytest.raises(typer.BadParameter):
+        cli._place_or_default(ctx, None)
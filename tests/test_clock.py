@@ -0,0 +1,41 @@
+from __future__ import annotations
+
+from datetime import UTC, datetime
+from types import SimpleNamespace
+
+from wx.clock import clock_label, localize_sections, localize_text, zone
+
+NOW = datetime(2026, 5, 1, 19, 0, tzinfo=UTC)  # Fri 12 PM PDT
+LA = "America/Los_Angeles"
+
+
+def test_clock_label_uses_local_zone_abbreviation() -> None:
+    assert clock_label(datetime(2026, 5, 1, 22, 30, tzinfo=UTC), zone(LA)) == "Fri 3:30 PM PDT"
+    assert clock_label(datetime(2026, 1, 2, 22, 0), zone(LA), day=False) == "2 PM PST"
+    assert zone("Not/AZone") is UTC
+
+
+def test_localize_text_rewrites_iso_stamps_and_offsets() -> None:
+    assert localize_text("Wind Advisory expires 2026-05-02T03:00:00Z", LA, now=NOW) == (
+        "Wind Advisory expires Fri 8 PM PDT"
+    )
+    assert localize_text("0-6h: showers taper", LA, now=NOW) == "Fri 12 PM PDT–Fri 6 PM PDT: showers taper"
+    assert localize_text("+6h gusts peak", LA, now=NOW) == "~Fri 6 PM PDT gusts peak"
+    assert localize_text("Stay indoors for the next 3 hours", LA, now=NOW) == "Stay indoors until Fri 3 PM PDT"
+    assert localize_text("Rain arrives in 2 hours, 2-3 hours of heavy rain", LA, now=NOW) == (
+        "Rain arrives around Fri 2 PM PDT, 2-3 hours of heavy rain"
+    )
+
+
+def test_localize_sections_covers_timeline_actions_and_bottom_line() -> None:
+    response = SimpleNamespace(
+        sections={"timeline": ["6-12 hours: snow"], "actions": ["Leave within 4 hours"], "summary": ["in 2 hours"]},
+        bottom_line="Bottom line: snow by 2026-05-02T01:00:00+00:00.",
+    )
+
+    localize_sections(response, LA, now=NOW)
+
+    assert response.sections["timeline"] == ["Fri 6 PM PDT–Sat 12 AM PDT: snow"]
+    assert response.sections["actions"] == ["Leave by Fri 4 PM PDT"]
+    assert response.sections["summary"] == ["in 2 hours"]
+    assert response.bottom_line == "Bottom line: snow by Fri 6 PM PDT."
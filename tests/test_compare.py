@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+import io
+import json
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.compare import comparison_pack
+
+
+class _Provider:
+    name = "stub"
+
+    def geocode(self, place):
+        if place == "Atlantis":
+            return None
+        return {"resolved": place.title(), "lat": 47.0, "lon": -122.0}
+
+    def current(self, lat, lon):
+        return {"temp": 20.0, "wind": 16.09, "conditions": "Clear"}
+
+    def alerts(self, lat, lon):
+        return [{"event": "Heat Advisory", "severity": "Moderate"}]
+
+    def forecast(self, lat, lon, *, hours=48):
+        # 30 hours: the 24-hour outlook must ignore the last six.
+        return {
+            "temperature_2m": [15.0] * 12 + [25.0] * 12 + [40.0] * 6,
+            "precipitation": [0.5] * 24 + [10.0] * 6,
+            "precipitation_probability": [10] * 24 + [100] * 6,
+            "wind_gusts_10m": [32.18] * 30,
+        }
+
+
+def _orchestrator() -> orchestrator_module.Orchestrator:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    orchestrator.provider = _Provider()
+    return orchestrator
+
+
+def test_compare_table_covers_next_24_hours() -> None:
+    result = _orchestrator().handle_compare(["seattle", "Atlantis", "tacoma"])
+
+    assert result.response is None
+    assert [report.error for report in result.reports] == [None, "Could not find Atlantis", None]
+    assert result.reports[0].snapshot.outlook == {
+        "temp_max": 25.0,
+        "temp_min": 15.0,
+        "gust_max": 32.18,
+        "precip_prob_max": 10.0,
+        "precip_total": 12.0,
+    }
+    pack = comparison_pack(result.reports, "imperial")
+    assert pack[0]["next_24h"] == {
+        "temp_max": 77,
+        "temp_min": 59,
+        "gust_max": 20,
+        "precip_prob_max": 10.0,
+        "precip_total": 0.47,
+    }
+    assert pack[1] == {"place": "Atlantis", "error": "Could not find Atlantis"}
+
+    console = Console(file=io.StringIO(), width=160)
+    render.render_compare(result, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "Seattle" in output and "Tacoma" in output and "Could not find Atlantis" in output
+    assert "59°F–77°F" in output and "0.47 in" in output and "Heat Advisory" in output
+
+
+def test_compare_ai_paragraph_sees_every_place(monkeypatch) -> None:
+    orchestrator = _orchestrator()
+    seen = {}
+
+    def fake_invoke(payload):
+        seen.update(payload)
+        raw = '{"answer": "Tacoma looks best: same warmth, less wind.", "confidence": {"value": 60, "rationale": "ok"}}'
+        return raw, "test", None
+
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", fake_invoke)
+    result = orchestrator.handle_compare(["seattle", "tacoma"], ai=True)
+
+    assert seen["intent"] == "question"
+    assert [place["place"] for place in seen["feature_pack"]["places"]] == ["Seattle", "Tacoma"]
+    assert seen["feature_pack"]["alerts_quick"][1]["event"] == "Heat Advisory (Tacoma)"
+    console = Console(file=io.StringIO(), width=160)
+    render.render_compare(result, console=console, units="metric", json_mode=True)
+    payload = json.loads(console.file.getvalue())["data"]
+    assert payload["comparison"]["text"] == "Tacoma looks best: same warmth, less wind."
+    assert payload["places"][1]["snapshot"]["outlook_hours"] == 24
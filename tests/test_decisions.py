@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+import io
+import json
+from pathlib import Path
+
+import pytest
+from rich.console import Console
+
+from wx import config, forecaster as forecaster_module, render
+from wx.config import load_settings
+from wx.decisions import apply_decision_floor, parse_decision_floor, parse_decision_floor_mode
+from wx.forecaster import ForecasterResponse
+from wx.orchestrator import OrchestrationResult
+from wx.profile import load_profile
+
+STORY = {
+    "sections": {
+        "summary": ["Showers after noon."],
+        "actions": ["Take an umbrella after noon.", "Move the picnic indoors.", "Expect a dry commute home."],
+        "action_confidence": [85, 40, 55],
+    },
+    "confidence": {"value": 70, "rationale": "Timing uncertain."},
+    "bottom_line": "Showers after noon.",
+}
+
+
+def _response() -> ForecasterResponse:
+    story = json.loads(json.dumps(STORY))
+    return ForecasterResponse(
+        sections=story["sections"],
+        confidence=story["confidence"],
+        used_feature_fields=[],
+        bottom_line=story["bottom_line"],
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+
+
+def test_parse_decision_floor_and_mode() -> None:
+    assert [parse_decision_floor(value) for value in ("60", "60%", "0.6", "1.0", "1", None, "")] == [
+        60, 60, 60, 100, 1, 0, 0
+    ]
+    with pytest.raises(ValueError, match="0 to 100"):
+        parse_decision_floor("high")
+    with pytest.raises(ValueError, match="0 to 100"):
+        parse_decision_floor("140")
+    assert parse_decision_floor_mode(None) == "hide" and parse_decision_floor_mode(" Mark ") == "mark"
+    with pytest.raises(ValueError, match="Choose from: hide, mark"):
+        parse_decision_floor_mode("blur")
+
+
+def test_floor_hides_or_marks_unsure_actions() -> None:
+    hidden = _response()
+    below = apply_decision_floor(hidden, 60)
+
+    assert [item["confidence"] for item in below] == [40, 55]
+    assert hidden.sections["actions"] == ["Take an umbrella after noon."]
+    assert hidden.sections["low_confidence"] == (
+        "Low-confidence considerations: Move the picnic indoors (40%); Expect a dry commute home (55%)."
+    )
+    assert "action_confidence" not in hidden.sections and hidden.meta["decision_floor"]["mode"] == "hide"
+
+    marked = _response()
+    apply_decision_floor(marked, 50, "mark")
+    assert marked.sections["actions"][1] == "Move the picnic indoors. (low confidence, 40%)"
+    assert marked.sections["actions"][2] == "Expect a dry commute home." and "low_confidence" not in marked.sections
+
+    off = _response()
+    assert apply_decision_floor(off, 0) == [] and len(off.sections["actions"]) == 3
+
+
+def test_floor_reads_a_zero_to_one_scale_as_fractions() -> None:
+    fractions = _response()
+    fractions.sections["action_confidence"] = [0.9, 1.0, 0.5]
+    below = apply_decision_floor(fractions, 60)
+
+    assert below == [{"action": "Expect a dry commute home.", "confidence": 50}]
+    assert fractions.sections["actions"] == ["Take an umbrella after noon.", "Move the picnic indoors."]
+
+
+def test_floor_setting_reaches_prompt_story_and_render(config_dir: Path, state_dir, monkeypatch) -> None:
+    monkeypatch.delenv("WX_DECISION_FLOOR", raising=False)
+    monkeypatch.setenv("WX_DECISION_FLOOR_MODE", "hide")
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"decision_floor": 60}))
+    settings = load_settings(profile=load_profile())
+    assert (settings.decision_floor, settings.decision_floor_mode) == (60, "hide")
+
+    forecaster = forecaster_module.Forecaster(config.Settings(privacy_mode=True, decision_floor=60))
+    payload = {"query": "Denver", "intent": "forecast", "verbose": False, "explain_mode": False, "feature_pack": {}}
+    assert "sections.action_confidence" in forecaster._build_prompt(payload)
+    monkeypatch.setattr(forecaster, "_invoke_provider", lambda payload: (json.dumps(STORY), "test", None))
+    alert = {"event": "Flash Flood Warning", "severity": "Severe", "expires": "2026-10-16T23:00:00+00:00"}
+
+    response = forecaster.generate(
+        query="Denver", feature_pack={"alerts_quick": [alert]}, intent="forecast", verbose=False
+    )
+
+    # The safety notice is added after the floor, so it is never hidden.
+    assert response.sections["actions"][0].startswith("Flash Flood Warning in effect")
+    assert "Take an umbrella after noon." in response.sections["actions"]
+    result = OrchestrationResult(
+        command="forecast", query="Denver", feature_pack={}, response=response, timings={}, debug={}
+    )
+    console = Console(file=io.StringIO(), width=160)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "Low-confidence considerations: Move the picnic indoors (40%)" in console.file.getvalue()
@@ -0,0 +1,55 @@
+"""Tests for wx doctor diagnostics and bundles."""
+
+from __future__ import annotations
+
+import json
+import tarfile
+from pathlib import Path
+
+from wx.config import Settings
+from wx.doctor import collect_diagnostics, write_bundle
+from wx.profile import Profile, WorldCity
+
+SECRET = "sk-ant-REDACTED"
+
+
+def _settings(tmp_path: Path) -> Settings:
+    return Settings(anthropic_api_key=SECRET, state_file=tmp_path / "state" / "last_query.json")
+
+
+def test_diagnostics_redact_keys_and_profile_cities(tmp_path: Path) -> None:
+    profile = Profile(world_cities=[WorldCity("Cabin", lat=47.1234, lon=-121.5678)])
+
+    diagnostics = collect_diagnostics(_settings(tmp_path), profile)
+
+    assert diagnostics["settings"]["anthropic_api_key"] == "<redacted>"
+    assert diagnostics["settings"]["openrouter_api_key"] is None
+    assert diagnostics["ai_backends"] == ["anthropic"]
+    assert diagnostics["profile"]["world_cities"] == 1
+    assert SECRET not in json.dumps(diagnostics)
+    assert "47.1234" not in json.dumps(diagnostics)
+
+
+def test_bundle_scrubs_fixtures(tmp_path: Path) -> None:
+    settings = _settings(tmp_path)
+    settings.state_file.parent.mkdir(parents=True)
+    settings.state_file.write_text(
+        json.dumps({"question": "Rain at 38.8977,-77.0365?", "feature_pack": {"place": {"lat": 38.8977}}})
+    )
+    recording = tmp_path / "session.json"
+    recording.write_text(json.dumps({"turns": [{"input": "hi", "result": {"raw_text": f"key {SECRET}"}}]}))
+
+    bundle = write_bundle(tmp_path / "out" / "bundle.tar.gz", settings, Profile(), fixtures=[recording])
+
+    with tarfile.open(bundle) as archive:
+        names = archive.getnames()
+        contents = {name: archive.extractfile(name).read().decode() for name in names}
+    assert sorted(names) == [
+        "wx-doctor/diagnostics.json",
+        "wx-doctor/fixtures/last_query.json",
+        "wx-doctor/fixtures/session.json",
+    ]
+    assert "38.9,-77.0" in contents["wx-doctor/fixtures/last_query.json"]
+    assert '"lat": 38.9' in contents["wx-doctor/fixtures/last_query.json"]
+    assert SECRET not in "".join(contents.values())
+    assert "<redacted>" in contents["wx-doctor/fixtures/session.json"]
@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import app
+from wx.document import document_sections, html_text, markdown_text
+from wx.forecaster import ForecasterResponse
+from wx.orchestrator import OrchestrationResult
+from wx.render import _result_payload
+from wx.template import result_context
+
+
+def _result(sections: dict | None = None) -> OrchestrationResult:
+    response = ForecasterResponse(
+        sections=sections
+        or {
+            "summary": ["Gusty afternoon <with> showers."],
+            "timeline": ["Fri 12 PM PDT: gusts 40 mph."],
+            "risk_cards": [{"hazard": "Wind", "level": "Moderate", "drivers": ["gusts | 45 mph"], "confidence": "70%"}],
+            "actions": ["Secure patio furniture."],
+            "low_confidence": "Low-confidence considerations: Delay the ferry (40%).",
+            "confidence": "Models agree on timing.",
+            "assumptions": ["Front arrives on schedule."],
+        },
+        confidence={"value": 70, "rationale": "Good agreement."},
+        used_feature_fields=[],
+        bottom_line="Tie things down by noon.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+    feature_pack = {
+        "place": {"resolved": "Seattle, WA", "tz": "America/Los_Angeles"},
+        "alerts_quick": [{"event": "Wind Advisory", "severity": "Moderate", "expires_iso": "2026-10-17T04:00:00Z"}],
+    }
+    return OrchestrationResult("forecast", "Seattle", feature_pack, response, {}, {})
+
+
+def test_document_sections_follow_panel_order() -> None:
+    blocks = document_sections(result_context(_result_payload(_result())))
+
+    assert [block["title"] for block in blocks] == [
+        "Alerts",
+        "Summary",
+        "Timeline",
+        "Risks",
+        "Actions",
+        "Confidence (70%)",
+        "Assumptions",
+    ]
+    assert blocks[0]["body"] == ["Wind Advisory (Moderate) until Fri 9 PM PDT"]
+    assert [block["title"] for block in blocks if block["collapsed"]] == ["Risks", "Assumptions"]
+
+    answer = document_sections(result_context(_result_payload(_result({"answer": "Yes, bring a jacket."}))))
+    assert [block["title"] for block in answer] == ["Alerts", "Answer", "Confidence (70%)"]
+    assert answer[-1]["body"] == "Good agreement."
+
+
+def test_markdown_and_html_render_tables_and_collapsed_sections() -> None:
+    context = result_context(_result_payload(_result()))
+
+    markdown = markdown_text(context)
+    html = html_text(context)
+
+    assert markdown.startswith("# Forecast: Seattle, WA\n\n## Alerts\n\n- Wind Advisory")
+    assert "| Wind | Moderate | gusts \\| 45 mph | 70% |" in markdown
+    assert "<details>\n<summary>Assumptions</summary>\n\n- Front arrives on schedule.\n\n</details>" in markdown
+    assert "_Low-confidence considerations: Delay the ferry (40%)._" in markdown
+    assert "**Bottom line:** Tie things down by noon." in markdown and markdown.endswith("_Story by test_\n")
+    assert html.startswith("<!DOCTYPE html>") and "<title>Forecast: Seattle, WA</title>" in html
+    assert "<p>Gusty afternoon &lt;with&gt; showers.</p>" in html
+    assert "<details><summary>Risks</summary><table>" in html and "<td>gusts | 45 mph</td>" in html
+
+
+def test_format_markdown_flag_replaces_panels(cli_runner, config_dir, state_dir, monkeypatch) -> None:
+    monkeypatch.setattr(orchestrator_module.Orchestrator, "handle_forecast", lambda self, place, **kwargs: _result())
+
+    markdown = cli_runner.invoke(app, ["--format", "markdown", "", "forecast", "Seattle"])
+    html = cli_runner.invoke(app, ["--format", "html", "", "forecast", "Seattle"])
+
+    assert markdown.exit_code == 0 and html.exit_code == 0, markdown.output + html.output
+    assert markdown.output.startswith("# Forecast: Seattle, WA\n") and "╭" not in markdown.output
+    assert html.output.rstrip().endswith("</html>")
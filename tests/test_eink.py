@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+from datetime import datetime
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import app
+from wx.eink import big_text, eink_text
+from wx.forecaster import ForecasterResponse
+from wx.orchestrator import OrchestrationResult
+
+NOW = datetime(2026, 10, 16, 7, 5)
+
+
+def _result() -> OrchestrationResult:
+    response = ForecasterResponse(
+        sections={"summary": ["Rain arrives by noon — heavy at times.", "Breezy this afternoon."]},
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Bottom line: take a rain jacket.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+    feature_pack = {
+        "units": {"temp": "°F"},
+        "place": {"resolved": "Seattle, WA"},
+        "obs_quick": {"temp": 52.4, "wind": 12.0, "gust": 24.0, "conditions": "Light Rain"},
+        "alerts_quick": [{"event": "Wind Advisory"}],
+    }
+    return OrchestrationResult("forecast", "Seattle", feature_pack, response, {}, {})
+
+
+def test_big_text_draws_temperature() -> None:
+    assert big_text("52°F") == [
+        "### ### ### ###",
+        "#     # # # #",
+        "### ### ### ##",
+        "  # #       #",
+        "### ###     #",
+    ]
+
+
+def test_eink_text_is_plain_and_narrow() -> None:
+    context = {
+        "place": "Seattle, WA",
+        "unit": "°F",
+        "temp": 52,
+        "wind": 12,
+        "gust": 24,
+        "conditions": "Light Rain",
+        "alerts": ["Wind Advisory"],
+        "summary": "Rain arrives by noon — heavy at times.",
+        "bottom_line": "Bottom line: take a rain jacket.",
+    }
+
+    text = eink_text(context, now=NOW)
+
+    lines = text.splitlines()
+    assert lines[0] == "SEATTLE, WA"
+    assert "Light Rain | Wind 12 G24" in lines and "! WIND ADVISORY" in lines
+    assert "Rain arrives by noon - heavy at times." in lines
+    assert lines[-1] == "Updated Fri 07:05"
+    assert all(len(line) <= 40 for line in lines)
+    assert "\x1b" not in text and "°" not in text
+
+
+def test_format_eink_flag_replaces_panels(cli_runner, config_dir, state_dir, monkeypatch) -> None:
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator, "handle_forecast", lambda self, place, **kwargs: _result()
+    )
+
+    result = cli_runner.invoke(app, ["--format", "eink", "", "forecast", "Seattle"])
+
+    assert result.exit_code == 0, result.output
+    assert result.output.startswith("SEATTLE, WA\n")
+    assert "Bottom line: take a rain jacket." in result.output
+    assert "╭" not in result.output and "Summary" not in result.output
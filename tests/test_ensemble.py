@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, providers as providers_module, render
+from wx.ensemble import apply_model_agreement, ensemble_pack
+from wx.forecaster import ForecasterResponse
+
+START = datetime(2026, 10, 16, 12, tzinfo=UTC)
+
+
+def _members(highs: list[float], wet: int, hours: int = 6) -> dict:
+    """One member per high; the first ``wet`` members rain 0.5 mm an hour."""
+
+    return {
+        "time": [(START + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours + 2)],
+        "temperature_2m": [[high - 3] * (hours - 1) + [high, 40.0, 40.0] for high in highs],
+        "precipitation": [[0.5 if idx < wet else 0.0] * (hours + 2) for idx in range(len(highs))],
+    }
+
+
+def _response(confidence: int) -> ForecasterResponse:
+    return ForecasterResponse(
+        sections={"summary": ["Mild."], "confidence": "Models look consistent."},
+        confidence={"value": confidence, "rationale": "Models look consistent."},
+        used_feature_fields=[],
+        bottom_line="Mild.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+
+
+def test_spread_and_wet_share_set_the_agreement_level() -> None:
+    end = START + timedelta(hours=6)
+    tight = ensemble_pack(_members([15.0, 15.5, 16.0, 15.2, 15.8], wet=0), START, end, "metric")
+    assert tight["agreement"] == "high" and tight["high_spread_c"] == 0.8 and tight["wet_member_pct"] == 0
+    assert tight["label"] == "High model agreement: 5 ensemble members put the high at 15–16°C; 0% of them are wet."
+
+    split = ensemble_pack(_members([15.0, 15.5, 16.0, 15.2, 15.8], wet=2), START, end, "metric")
+    assert split["agreement"] == "low" and split["wet_member_pct"] == 40 and split["precip_median_mm"] == 0.0
+    spread = ensemble_pack(_members([14.0, 15.0, 16.0, 17.0, 18.0], wet=5), START, end, "imperial")
+    assert spread["agreement"] == "moderate" and spread["confidence_cap"] == 75
+    assert ensemble_pack(_members([15.0, 16.0], wet=0), START, end) is None
+
+
+def test_low_agreement_caps_stated_confidence() -> None:
+    pack = {"label": "Low model agreement: ...", "agreement": "low", "confidence_cap": 55}
+    response = _response(85)
+
+    assert apply_model_agreement(response, pack) == 85
+    assert response.confidence["value"] == 55
+    assert response.sections["confidence"].endswith("Capped at 55% because the ensemble members show low agreement.")
+    assert response.sections["model_agreement"] == "Low model agreement: ..."
+    assert response.meta["ensemble"] == {"agreement": "low", "capped_from": 85}
+
+    modest = _response(50)
+    assert apply_model_agreement(modest, pack) is None and modest.confidence["value"] == 50
+
+
+def test_forecast_attaches_ensemble_and_shows_indicator(monkeypatch) -> None:
+    now = datetime.now(UTC).replace(minute=0, second=0, microsecond=0)
+    hours = 30
+    members = {
+        "time": [(now + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)],
+        "temperature_2m": [[10.0 + 2 * idx] * hours for idx in range(5)],
+        "precipitation": [[0.0] * hours for _ in range(5)],
+    }
+    hourly = {"time": members["time"], "temperature_2m": [12.0] * hours, "precipitation": [0.0] * hours}
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Lyon", "lat": 45.76, "lon": 4.84, "tz": "Europe/Paris"},
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+    monkeypatch.setattr(orchestrator_module, "fetch_ensemble_members", lambda lat, lon, **kwargs: members)
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True, units="metric"))
+    story = '{"sections": {"summary": ["Dry."]}, "confidence": {"value": 90, "rationale": "Dry."}}'
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: (story, "test", None))
+
+    result = orchestrator.handle_forecast("Lyon", when_text=None, horizon="24h", focus=None, verbose=False)
+
+    assert result.feature_pack["ensemble"]["agreement"] == "low"
+    assert result.response.confidence["value"] == 55
+    console = Console(file=io.StringIO(), width=140)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    output = console.file.getvalue()
+    assert "Confidence (55%) · models ●○○" in output and "5 ensemble members put the high at 11–17°C" in output
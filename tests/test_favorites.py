@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+import io
+import json
+import threading
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.cli import app
+from wx.favorites import Favorite, merge_favorites, parse_favorites
+
+
+class _Provider:
+    """Answers only once all three places are being fetched at the same time."""
+
+    name = "stub"
+
+    def __init__(self) -> None:
+        self.barrier = threading.Barrier(3, timeout=5)
+
+    def geocode(self, place):
+        if place == "Atlantis":
+            return None
+        return {"resolved": place.title(), "lat": 47.0, "lon": -122.0}
+
+    def current(self, lat, lon):
+        self.barrier.wait()
+        return {"temp": 10.0, "wind": 20.0, "gust": 40.0, "conditions": "Rain"}
+
+    def alerts(self, lat, lon):
+        return [{"event": "Wind Advisory", "severity": "Moderate"}]
+
+    def forecast(self, lat, lon, *, hours=48):
+        return {"temperature_2m": [8.0, 12.0], "precipitation_probability": [40, 90]}
+
+
+def test_parse_favorites_accepts_mapping_and_list_forms() -> None:
+    assert parse_favorites({"home": "Seattle", "cabin": "47.1,-121.5"}) == [
+        Favorite("home", "Seattle"),
+        Favorite("cabin", "47.1,-121.5"),
+    ]
+    assert parse_favorites(["Boise", {"name": "work", "lat": 47.6, "lon": -122.2}, {"name": "empty"}, 3]) == [
+        Favorite("Boise", "Boise"),
+        Favorite("work", "47.6,-122.2"),
+    ]
+    assert parse_favorites("Seattle") == []
+
+
+def test_favorites_fetched_concurrently_and_tabulated() -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    orchestrator.provider = _Provider()
+    favorites = [Favorite("home", "seattle"), Favorite("work", "bellevue"), Favorite("cabin", "snoqualmie")]
+
+    reports = orchestrator.handle_favorites([*favorites, Favorite("lost", "Atlantis")])
+
+    assert [report.error for report in reports] == [None, None, None, "Could not find Atlantis"]
+    assert reports[0].snapshot.place == "Seattle"
+    assert reports[0].snapshot.outlook["precip_prob_max"] == 90
+    console = Console(file=io.StringIO(), width=160)
+    render.render_favorites(reports, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "home" in output and "50°F" in output and "46°F–54°F" in output and "Wind Advisory" in output
+
+
+def test_merge_favorites_shares_places_across_profiles() -> None:
+    merged = merge_favorites(
+        [
+            ("me", [Favorite("home", "Seattle"), Favorite("work", "47.6101,-122.2015")]),
+            ("partner", [Favorite("Home", "seattle "), Favorite("office", "47.61,-122.20")]),
+            ("kids", [Favorite("home", "Seattle"), Favorite("school", "Redmond")]),
+        ]
+    )
+
+    assert merged == [
+        Favorite("home / Home · me, partner, kids", "Seattle"),
+        Favorite("work / office · me, partner", "47.6101,-122.2015"),
+        Favorite("school · kids", "Redmond"),
+    ]
+    assert merge_favorites([("me", [Favorite("home", "Seattle")])]) == [Favorite("home", "Seattle")]
+
+
+def test_favorites_profiles_option_combines_profiles(config_dir, cli_runner, monkeypatch) -> None:
+    for name, favorites in (("me", {"home": "Seattle"}), ("partner", {"home": "Seattle", "gym": "Tacoma"})):
+        (config_dir / "profiles" / f"{name}.json").write_text(json.dumps({"favorites": favorites}))
+    requested = []
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator, "handle_favorites", lambda self, favorites: requested.extend(favorites) or []
+    )
+
+    result = cli_runner.invoke(app, ["", "favorites", "--profiles", "me,partner"])
+    missing = cli_runner.invoke(app, ["", "favorites", "--profiles", "me,nobody"])
+
+    assert result.exit_code == 0, result.output
+    assert requested == [Favorite("home · me, partner", "Seattle"), Favorite("gym · partner", "Tacoma")]
+    assert missing.exit_code == 2 and "No profile named 'nobody'" in missing.output
@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from types import SimpleNamespace
+from unittest.mock import MagicMock
+
+import pytest
+from typer.testing import CliRunner
+
+from wx import cli
+from wx.chat import ChatInterface
+from wx.feedback import FeedbackEntry, FeedbackStore, story_info
+from wx.prompts import DEFAULT_PROMPT_VERSION
+
+CLAUDE = {"provider": "anthropic:claude", "backend": "anthropic", "model": "claude", "prompt_version": "abc"}
+GPT = {"provider": "openrouter:gpt", "backend": "openrouter", "model": "gpt", "prompt_version": "abc"}
+
+
+def test_story_info_reads_backend_model_and_prompt_version() -> None:
+    response = SimpleNamespace(
+        provider="openrouter:x/y",
+        meta={"backend": "openrouter", "model": "x/y", "prompt_version": DEFAULT_PROMPT_VERSION},
+    )
+    assert story_info(response) == {
+        "provider": "openrouter:x/y",
+        "backend": "openrouter",
+        "model": "x/y",
+        "prompt_version": DEFAULT_PROMPT_VERSION,
+    }
+    assert story_info(SimpleNamespace(provider="offline", meta=None))["backend"] == "offline"
+
+
+def test_store_persists_and_ranks_backends(tmp_path: Path) -> None:
+    path = tmp_path / "feedback.json"
+    store = FeedbackStore(path)
+    store.add(FeedbackEntry(story=GPT, rating=2))
+    store.add(FeedbackEntry(story=GPT, inaccurate=True, note="missed the rain"))
+    store.add(FeedbackEntry(story=CLAUDE, rating=5))
+    store.add(FeedbackEntry(story=CLAUDE, rating=4))
+    with pytest.raises(ValueError):
+        store.add(FeedbackEntry(story=CLAUDE, rating=9))
+
+    rows = FeedbackStore(path).summary()
+
+    assert [(row.backend, row.average, row.ratings, row.flagged) for row in rows] == [
+        ("anthropic", 4.5, 2, 0),
+        ("openrouter", 2.0, 1, 1),
+    ]
+    assert path.stat().st_mode & 0o777 == 0o600
+
+
+def test_feedback_command_rates_last_story(cli_runner: CliRunner, state_dir: Path) -> None:
+    env = {"WX_STATE_DIR": str(state_dir), "PRIVACY_MODE": "0"}
+    missing = cli_runner.invoke(cli.app, ["", "feedback", "4"], env=env)
+    assert missing.exit_code == 1
+
+    state_dir.mkdir(parents=True, exist_ok=True)
+    (state_dir / "last_query.json").write_text(json.dumps({"command": "forecast", "story": CLAUDE}))
+    rated = cli_runner.invoke(cli.app, ["", "feedback", "4", "--flag", "too warm"], env=env)
+    summary = cli_runner.invoke(cli.app, ["--json", "", "feedback"], env=env)
+
+    assert rated.exit_code == 0, rated.stdout
+    saved = json.loads((state_dir / "feedback.json").read_text())
+    assert saved[0]["rating"] == 4 and saved[0]["inaccurate"] and saved[0]["note"] == "too warm"
+    assert saved[0]["command"] == "forecast"
+    assert json.loads(summary.stdout)["data"][0]["backend"] == "anthropic"
+
+
+def test_chat_rate_and_flag_last_answer(tmp_path: Path) -> None:
+    settings = SimpleNamespace(state_file=tmp_path / "last_query.json")
+    chat = ChatInterface(settings, MagicMock(), MagicMock())
+    chat._rate_last_answer("/rate 5")
+    assert chat.feedback.entries() == []
+
+    chat.last_result = SimpleNamespace(
+        command="question", response=SimpleNamespace(provider="anthropic:claude", meta=CLAUDE)
+    )
+    chat._rate_last_answer("/rate 5 spot on")
+    chat._rate_last_answer("/flag snow totals were way off")
+    chat._rate_last_answer("/rate great")
+
+    entries = chat.feedback.entries()
+    assert [(entry.rating, entry.inaccurate, entry.note) for entry in entries] == [
+        (5, False, "spot on"),
+        (None, True, "snow totals were way off"),
+    ]
+    assert entries[0].story["model"] == "claude"
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+from datetime import UTC, datetime
+
+from wx.feel import feel_descriptor, feel_metrics, with_article
+from wx.watch import WatchSnapshot, snapshot_line, take_snapshot
+
+# 8 AM and 3 PM in Seattle (PDT).
+MORNING = datetime(2026, 10, 16, 15, tzinfo=UTC)
+AFTERNOON = datetime(2026, 10, 16, 22, tzinfo=UTC)
+SEATTLE = {"lat": 47.6, "tz_name": "America/Los_Angeles"}
+
+
+def _feel(now: datetime = MORNING, **metrics) -> str | None:
+    return feel_descriptor(metrics, now=now, **SEATTLE)
+
+
+def test_descriptors_from_typed_metrics() -> None:
+    assert _feel(temp_c=6.0, dewpoint_c=-1.0, wind_kmh=8.0, cloud_cover=10.0) == "crisp fall morning"
+    assert _feel(AFTERNOON, temp_c=29.0, dewpoint_c=22.0, cape=1800.0) == "steamy pre-storm afternoon"
+    assert _feel(temp_c=6.0, wind_kmh=25.0, cloud_cover=90.0) == "raw fall morning"
+    assert _feel(temp_c=12.0, precip_mm=2.5, conditions="Moderate rain") == "rainy fall morning"
+    assert _feel(temp_c=-1.0, conditions="Slight snow fall") == "snowy fall morning"
+    assert _feel(AFTERNOON, temp_c=15.0, cloud_cover=95.0) == "gray, cool fall afternoon"
+    assert _feel(temp_c=None) is None
+    assert feel_descriptor({"temp_c": 20.0}, lat=-33.9, tz_name="Australia/Sydney", now=MORNING) == (
+        "mild spring night"
+    )
+    assert with_article("icy winter night") == "an icy winter night"
+
+
+def test_feel_metrics_prefer_observations_and_look_ahead() -> None:
+    hourly = {
+        "temperature_2m": [9.0],
+        "dew_point_2m": [2.0, 3.0],
+        "wind_speed_10m": [30.0],
+        "cloud_cover": [20.0],
+        "precipitation_probability": [10, 70, 40, 90],
+        "cape": [None, 300.0],
+    }
+
+    metrics = feel_metrics({"temp": 7.5, "wind": 12.0, "conditions": "Clear sky"}, hourly)
+
+    assert metrics == {
+        "temp_c": 7.5,
+        "dewpoint_c": 2.0,
+        "wind_kmh": 12.0,
+        "cloud_cover": 20.0,
+        "precip_mm": None,
+        "precip_prob": 70.0,
+        "cape": 300.0,
+        "conditions": "Clear sky",
+    }
+    assert feel_metrics({}, hourly)["temp_c"] == 9.0 and feel_metrics({}, hourly)["wind_kmh"] == 30.0
+
+
+def test_snapshot_line_says_what_it_feels_like() -> None:
+    class _Provider:
+        def current(self, lat, lon):
+            return {"temp": 6.0, "wind": 8.0, "conditions": "Clear sky"}
+
+        def alerts(self, lat, lon):
+            return []
+
+        def forecast(self, lat, lon, *, hours=48):
+            return {"temperature_2m": [6.0, 9.0], "dew_point_2m": [-1.0], "cloud_cover": [5.0]}
+
+    place = {"resolved": "Seattle, WA", "lat": 47.6, "lon": -122.3, "tz": "America/Los_Angeles"}
+    snapshot = take_snapshot(_Provider(), place, fresh=False, now=MORNING)
+
+    assert snapshot.feel == "crisp fall morning"
+    assert snapshot_line(snapshot, "metric").endswith("0 alerts · feels like a crisp fall morning")
+    assert "feels like" not in snapshot_line(WatchSnapshot(place="Nowhere"), "metric")
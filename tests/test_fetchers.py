@@ -12,7 +12,160 @@ def test_get_quick_obs_handles_failures(monkeypatch):
     monkeypatch.setattr(fetchers, "_safe_request", fail_request)
     result = fetchers.get_quick_obs(35.0, -97.0, offline=False)
     assert result is None
+    # An unreachable alerts feed is not the same as no alerts.
+    assert fetchers.get_quick_alerts(35.0, -97.0) is None
+    assert fetchers.get_quick_alerts(35.0, -97.0, offline=True) == []
+
+
+def test_place_searches_are_spaced_by_the_geocode_interval(monkeypatch):
+    clock = [100.0]
+    waits: list[float] = []
+    monkeypatch.setattr(fetchers, "GEOCODE_INTERVAL", 1.0)
+    monkeypatch.setattr(fetchers, "_last_geocode", 0.0)
+    monkeypatch.setattr(fetchers.time, "monotonic", lambda: clock[0])
+    monkeypatch.setattr(fetchers.time, "sleep", lambda seconds: waits.append(seconds) or clock.__setitem__(0, clock[0] + seconds))
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: {"results": [{"name": "Tulsa", "latitude": 36.15, "longitude": -95.99}]})
+
+    fetchers.get_point_context("Tulsa")
+    clock[0] += 0.25
+    fetchers.get_point_context("Tulsa")
+    fetchers.get_point_context("36.15,-95.99")
+
+    assert waits == [0.75]
 
 
 def test_get_point_context_offline():
     assert fetchers.get_point_context("35,-97", offline=True) is None
+
+
+def test_fetch_nhc_active_storms_parses_feed(monkeypatch):
+    payload = {
+        "activeStorms": [
+            {
+                "id": "al092026",
+                "name": "Ivan",
+                "classification": "HU",
+                "intensity": "115",
+                "pressure": "945",
+                "latitudeNumeric": 22.1,
+                "longitudeNumeric": -80.4,
+                "movementDir": 330,
+                "movementSpeed": 11,
+            },
+            {"id": "", "name": "Nameless"},
+        ]
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+    storms = fetchers.fetch_nhc_active_storms()
+    assert len(storms) == 1
+    assert storms[0].basin == "Atlantic"
+    assert storms[0].intensity_kt == 115
+    assert storms[0].movement_dir == 330.0
+
+
+def test_find_county_zone_matches_name(monkeypatch):
+    payload = {
+        "features": [
+            {"properties": {"id": "WAC033", "name": "King"}},
+            {"properties": {"id": "WAC053", "name": "Pierce"}},
+        ]
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+    assert fetchers.find_county_zone("wa", "pierce county") == {"id": "WAC053", "name": "Pierce"}
+    assert fetchers.find_county_zone("wa", "Spokane") is None
+
+
+def test_get_quick_alerts_parses_cap_fields(monkeypatch):
+    payload = {
+        "features": [
+            {"properties": {"event": "Wind Advisory", "severity": "Moderate", "urgency": "Expected"}},
+            {
+                "properties": {
+                    "event": "Tornado Warning",
+                    "severity": "Extreme",
+                    "urgency": "Immediate",
+                    "headline": "Tornado Warning issued for Cleveland County",
+                    "areaDesc": "Cleveland, OK; McClain, OK",
+                    "ends": "2026-05-04T21:15:00-05:00",
+                }
+            },
+            {"properties": {"severity": "Minor"}},
+        ]
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+    alerts = fetchers.get_quick_alerts(35.2, -97.4)
+    assert [alert["event"] for alert in alerts] == ["Tornado Warning", "Wind Advisory"]
+    assert alerts[0]["urgency"] == "Immediate"
+    assert alerts[0]["areas"] == ["Cleveland, OK", "McClain, OK"]
+    assert alerts[0]["expires_iso"] == "2026-05-04T21:15:00-05:00"
+
+
+def test_get_nws_snowfall_grid_parses_durations(monkeypatch):
+    responses = iter(
+        [
+            {"properties": {"forecastGridData": "https://api.weather.gov/gridpoints/BOU/62,60"}},
+            {
+                "properties": {
+                    "snowfallAmount": {
+                        "values": [
+                            {"validTime": "2026-01-10T06:00:00+00:00/PT6H", "value": 25.4},
+                            {"validTime": "2026-01-10T12:00:00+00:00/P1DT6H", "value": 0},
+                            {"validTime": "bogus", "value": 3},
+                        ]
+                    }
+                }
+            },
+        ]
+    )
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: next(responses))
+    periods = fetchers.get_nws_snowfall_grid(39.7, -105.0)
+    assert periods == [
+        {"start_iso": "2026-01-10T06:00:00+00:00", "hours": 6, "mm": 25.4},
+        {"start_iso": "2026-01-10T12:00:00+00:00", "hours": 30, "mm": 0.0},
+    ]
+
+
+def test_fetch_spc_outlook_picks_highest_category(monkeypatch):
+    wide = {"type": "Polygon", "coordinates": [[[-100, 30], [-90, 30], [-90, 40], [-100, 40], [-100, 30]]]}
+    inner = {"type": "Polygon", "coordinates": [[[-98, 34], [-96, 34], [-96, 36], [-98, 36], [-98, 34]]]}
+    far = {"type": "Polygon", "coordinates": [[[-80, 40], [-79, 40], [-79, 41], [-80, 40]]]}
+    features = [("TSTM", "General Thunderstorms Risk", wide), ("MRGL", "Marginal Risk", wide)]
+    features += [("ENH", "Enhanced Risk", inner), ("MDT", "Moderate Risk", far)]
+    payload = {
+        "features": [
+            {"properties": {"LABEL": code, "LABEL2": label, "EXPIRE": "202605021200"}, "geometry": geometry}
+            for code, label, geometry in features
+        ]
+    }
+    urls = []
+    monkeypatch.setattr(fetchers, "_safe_request", lambda method, url, **kwargs: urls.append(url) or payload)
+
+    outlook = fetchers.fetch_spc_outlook(35.0, -97.0)
+
+    assert outlook == {
+        "day": 1,
+        "category": "enhanced",
+        "label": "Enhanced Risk",
+        "expire_iso": "2026-05-02T12:00:00+00:00",
+    }
+    assert urls == ["https://www.spc.noaa.gov/products/outlook/day1otlk_cat.nolyr.geojson"]
+    assert fetchers.fetch_spc_outlook(39.0, -85.0)["category"] is None
+    # Outside the contiguous US nothing is fetched.
+    assert fetchers.fetch_spc_outlook(51.5, -0.1) is None and len(urls) == 2
+
+
+def test_fetch_spc_probabilities_point_in_polygon(monkeypatch):
+    square = {"type": "Polygon", "coordinates": [[[-98, 34], [-96, 34], [-96, 36], [-98, 36], [-98, 34]]]}
+    far = {"type": "Polygon", "coordinates": [[[-90, 40], [-89, 40], [-89, 41], [-90, 40]]]}
+    payload = {
+        "features": [
+            {"properties": {"LABEL": "0.02"}, "geometry": square},
+            {"properties": {"LABEL": "0.05"}, "geometry": square},
+            {"properties": {"LABEL": "0.30"}, "geometry": far},
+            {"properties": {"LABEL": "SIGN"}, "geometry": square},
+        ]
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+    result = fetchers.fetch_spc_probabilities(35.0, -97.0)
+    assert result["tornado"] == {"probability": 5, "significant": True}
+    assert fetchers.fetch_spc_probabilities(10.0, 10.0)["hail"] == {"probability": 0, "significant": False}
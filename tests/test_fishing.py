@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, render
+from wx import orchestrator as orchestrator_module
+from wx.fishing import best_windows, score_hours, solunar_periods, summarize_fish
+
+START = datetime(2026, 10, 17, tzinfo=UTC)
+SEATTLE = (47.6, -122.3)
+
+
+def _hourly(hours: int = 48, **series) -> dict[str, list]:
+    hourly = {
+        "time": [(START + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)],
+        "pressure_msl": [1015 - 0.4 * idx for idx in range(hours)],
+        "wind_speed_10m": [12.0] * hours,
+        "precipitation": [0.0] * hours,
+    }
+    return {**hourly, **series}
+
+
+def test_solunar_periods_and_hour_factors() -> None:
+    periods = solunar_periods(*SEATTLE, START, START + timedelta(hours=24))
+
+    assert [(period["kind"], period["event"]) for period in periods] == [
+        ("major", "moon overhead"),
+        ("minor", "moonset"),
+        ("major", "moon underfoot"),
+        ("minor", "moonrise"),
+    ]
+    assert periods[0]["time_iso"] == "2026-10-17T01:00:00+00:00"
+
+    rows = score_hours(*SEATTLE, _hourly(), solunar=periods)
+    assert rows[0]["factors"] == ["major solunar period", "sunset", "falling pressure", "light chop"]
+    assert rows[0]["score"] == 75 and rows[0]["rating"] == "excellent"
+    assert rows[8]["factors"] == ["falling pressure", "light chop"] and rows[8]["rating"] == "fair"
+
+    rising = [1000 + idx for idx in range(48)]
+    stormy = _hourly(wind_speed_10m=[45.0] * 48, precipitation=[6.0] * 48, pressure_msl=rising)
+    tide = {(START + timedelta(hours=idx)).isoformat(): 0.3 * idx for idx in range(10)}
+    row = score_hours(*SEATTLE, stormy, sea_level=tide, solunar=periods)[8]
+    assert row["factors"] == ["rising pressure", "strong wind", "heavy rain", "moving tide"] and row["score"] == 0
+
+
+def test_best_windows_merge_runs_and_rank_by_mean() -> None:
+    def row(hour: int, score: int) -> dict:
+        return {
+            "time_iso": (START + timedelta(hours=hour)).isoformat(),
+            "score": score,
+            "factors": ["major solunar period", "rising pressure"],
+        }
+
+    rows = [row(0, 55), row(1, 65), row(2, 20), row(3, 80), row(4, 74), row(6, 90)]
+    windows = best_windows(rows, limit=2)
+
+    assert [(window["start_iso"][11:16], window["end_iso"][11:16], window["score"]) for window in windows] == [
+        ("06:00", "07:00", 90),
+        ("03:00", "05:00", 77),
+    ]
+    assert windows[1]["rating"] == "excellent" and windows[1]["factors"] == ["major solunar period"]
+    summary = summarize_fish(rows, windows, "America/Los_Angeles")
+    assert summary == "Best window Fri 11 PM PDT–12 AM PDT (90, excellent)."
+    assert summarize_fish(rows[2:3], [], "UTC") == "No good windows; the best hour is Sat 2 AM UTC (20, poor)."
+
+
+def test_handle_fish_scores_forecast_and_renders_windows(monkeypatch) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    seattle = {"resolved": "Seattle, WA", "lat": SEATTLE[0], "lon": SEATTLE[1], "tz": "America/Los_Angeles"}
+    monkeypatch.setattr(orchestrator.provider, "geocode", lambda place: seattle)
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: _hourly())
+    monkeypatch.setattr(orchestrator_module, "get_marine_forecast", lambda lat, lon, **kwargs: None)
+
+    report = orchestrator.handle_fish("Seattle", hours=24)
+    console = Console(file=io.StringIO(), width=120)
+    render.render_fish(report, console=console)
+    output = console.file.getvalue()
+
+    assert len(report.hours) == 24 and not report.tides and report.windows
+    assert report.summary == "Best window Fri 5 PM PDT–7 PM PDT (75, excellent)."
+    assert "Seattle, WA" in output and "75 excellent · major solunar period, sunset" in output
+    assert "No tide here" in output
@@ -2,8 +2,15 @@ from __future__ import annotations
 
 import importlib
 
+import pytest
+
+from wx.httpclient import configure_http
+
 config = importlib.import_module("wx.config")
 forecaster_module = importlib.import_module("wx.forecaster")
+ollama_client = importlib.import_module("wx.ollama_client")
+openrouter_client = importlib.import_module("wx.openrouter_client")
+anthropic_client = importlib.import_module("wx.anthropic_client")
 
 
 def test_forecaster_offline_fallback_sections():
@@ -27,3 +34,390 @@ def test_forecaster_offline_fallback_sections():
     assert required_sections.issubset(response.sections.keys())
     assert response.bottom_line.startswith("Bottom line")
     assert response.confidence["value"] <= 100
+
+
+def test_question_intent_uses_qa_prompt_and_answer(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    forecaster = forecaster_module.Forecaster(settings)
+    seen = {}
+
+    def fake_invoke(payload):
+        seen["intent"] = payload["intent"]
+        seen["prompt"] = forecaster._build_prompt(payload)
+        raw = '{"answer": "Yes, showers are likely after 9 PM.", "confidence": {"value": 70, "rationale": "ok"}}'
+        return raw, "openrouter:test", None
+
+    monkeypatch.setattr(forecaster, "_invoke_provider", fake_invoke)
+    response = forecaster.generate(
+        query="Will it rain tonight in Austin?", feature_pack={}, intent="question", verbose=False
+    )
+
+    assert "Answer the question concisely" in seen["prompt"]
+    assert response.sections["answer"] == "Yes, showers are likely after 9 PM."
+    assert response.summary_text == "Yes, showers are likely after 9 PM."
+
+
+def test_forecast_prompt_carries_focus_and_window():
+    settings = config.Settings(offline=True, privacy_mode=True)
+    forecaster = forecaster_module.Forecaster(settings)
+    payload = {
+        "query": "Forecast request for Denver",
+        "intent": "forecast",
+        "verbose": False,
+        "explain_mode": False,
+        "feature_pack": {"window": {"horizon": "6h"}, "user_context": {"focus": "commuting"}},
+    }
+
+    prompt = forecaster._build_prompt(payload)
+
+    assert "Cover only the Feature Pack window" in prompt
+    assert "The user's focus is commuting" in prompt
+
+
+def test_ollama_used_without_cloud_keys(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, ollama_model="llama3.1")
+    forecaster = forecaster_module.Forecaster(settings)
+    seen = {}
+
+    def fake_chat(messages, *, config):
+        seen["url"] = config.chat_url
+        seen["roles"] = [message["role"] for message in messages]
+        raw = '{"answer": "Dry through the evening.", "confidence": {"value": 65, "rationale": "ok"}}'
+        return ollama_client.OllamaResponse(text=raw, model=config.model, raw={}, usage=None)
+
+    monkeypatch.setattr(forecaster_module, "ollama_chat", fake_chat)
+    response = forecaster.generate(query="Rain tonight?", feature_pack={}, intent="question", verbose=False)
+
+    assert seen == {"url": "http://localhost:11434/api/chat", "roles": ["system", "user"]}
+    assert response.provider == "ollama:llama3.1"
+    assert response.sections["answer"] == "Dry through the evening."
+
+
+def test_ollama_errors_join_fallback_chain(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, ollama_model="llama3.1")
+    forecaster = forecaster_module.Forecaster(settings)
+
+    def unreachable(messages, *, config):
+        raise ollama_client.OllamaError("Ollama unreachable at http://localhost:11434")
+
+    monkeypatch.setattr(forecaster_module, "ollama_chat", unreachable)
+    response = forecaster.generate(query="Rain tonight?", feature_pack={}, intent="question", verbose=False)
+
+    assert response.provider == "fallback:RuntimeError"
+    assert "ollama:Ollama unreachable" in (response.meta or {}).get("error", "")
+
+
+def test_model_override_moves_backend_to_front(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, openrouter_api_key="or-key", anthropic_api_key="a-key")
+    config._apply_model_override(settings, "anthropic:claude-haiku-4-5")
+    forecaster = forecaster_module.Forecaster(settings)
+    seen = {}
+
+    def fake_create_message(system, prompt, *, config):
+        seen["model"] = config.model
+        raw = '{"answer": "No rain.", "confidence": {"value": 80, "rationale": "ok"}}'
+        return anthropic_client.AnthropicResponse(text=raw, model=config.model, raw={}, usage=None)
+
+    def no_openrouter(*args, **kwargs):
+        raise AssertionError("OpenRouter should not be tried first")
+
+    monkeypatch.setattr(forecaster_module, "create_message", fake_create_message)
+    monkeypatch.setattr(forecaster_module, "chat_completion", no_openrouter)
+    response = forecaster.generate(query="Rain today?", feature_pack={}, intent="question", verbose=False)
+
+    assert seen["model"] == "claude-haiku-4-5"
+    assert response.provider == "anthropic:claude-haiku-4-5"
+
+
+def test_openai_requests_json_mode(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, openai_api_key="oa-key")
+    forecaster = forecaster_module.Forecaster(settings)
+    seen = {}
+
+    def fake_chat_completion(messages, *, config):
+        seen["url"] = config.chat_url
+        seen["json_mode"] = config.json_mode
+        raw = '{"answer": "Breezy.", "confidence": {"value": 60, "rationale": "ok"}}'
+        return openrouter_client.OpenRouterResponse(
+            text=raw, model=config.model, raw={}, usage=None, headers={}, attempts=1
+        )
+
+    monkeypatch.setattr(forecaster_module, "chat_completion", fake_chat_completion)
+    response = forecaster.generate(query="Windy?", feature_pack={}, intent="question", verbose=False)
+
+    assert seen == {"url": "https://api.openai.com/v1/chat/completions", "json_mode": True}
+    assert response.provider == "openai:gpt-4o-mini"
+
+
+def test_parse_model_spec():
+    assert config.parse_model_spec("OpenRouter:x-ai/grok-2") == ("openrouter", "x-ai/grok-2")
+    for bad in ("gpt-4o", "mistral:large", "openai:"):
+        with pytest.raises(ValueError, match="Expected provider:model"):
+            config.parse_model_spec(bad)
+
+
+def test_per_command_ai_override():
+    settings = config.Settings(offline=False, privacy_mode=True)
+    config.apply_ai_override(settings, backend="Gemini")
+    assert settings.preferred_backend == "gemini"
+    config.apply_ai_override(settings, model="gemini-2.5-pro")
+    assert (settings.preferred_backend, settings.gemini_model) == ("gemini", "gemini-2.5-pro")
+    config.apply_ai_override(settings, model="meta-llama/llama-3.1-8b:free", backend="openrouter")
+    assert settings.openrouter_models[0] == "meta-llama/llama-3.1-8b:free"
+    config.apply_ai_override(settings, model="anthropic:claude-opus-4-1")
+    assert (settings.preferred_backend, settings.anthropic_model) == ("anthropic", "claude-opus-4-1")
+    with pytest.raises(ValueError, match="Unknown AI provider"):
+        config.apply_ai_override(settings, backend="mistral")
+
+
+def test_anthropic_reply_trimmed_to_json_object():
+    text = 'Here is the briefing:\n```json\n{"answer": "Yes."}\n```'
+    assert anthropic_client.extract_json_object(text) == '{"answer": "Yes."}'
+    assert anthropic_client.extract_json_object("no json") == "no json"
+
+
+def test_privacy_mode_coarsens_prompt_coordinates():
+    payload = {
+        "query": "Forecast request for 38.8977,-77.0365",
+        "intent": "forecast",
+        "verbose": False,
+        "explain_mode": False,
+        "feature_pack": {"place": {"resolved": "38.8977,-77.0365", "lat": 38.8977, "lon": -77.0365}},
+    }
+
+    private = forecaster_module.Forecaster(config.Settings(offline=True, privacy_mode=True))._build_prompt(payload)
+    public = forecaster_module.Forecaster(config.Settings(offline=True, privacy_mode=False))._build_prompt(payload)
+
+    assert "38.8977" not in private and "-77.0365" not in private
+    assert "38.9,-77.0" in private
+    assert '"lat": 38.9' in private
+    assert '"lat": 38.8977' in public
+    assert payload["feature_pack"]["place"]["lat"] == 38.8977
+
+
+def test_openrouter_stream_reports_progress_and_returns_text(monkeypatch):
+    events = [
+        'data: {"model": "m/x", "choices": [{"delta": {"content": "{\\"answer\\": "}}]}',
+        "",
+        ": keep-alive",
+        'data: {"choices": [{"delta": {"content": "\\"Dry.\\"}"}}], "usage": {"total_tokens": 9}}',
+        "data: [DONE]",
+    ]
+
+    def fake_stream(method, url, **kwargs):
+        assert kwargs["json"]["stream"] is True
+        return openrouter_client.httpx.Response(200, text="\n".join(events))
+
+    monkeypatch.setattr(openrouter_client.httpx, "stream", fake_stream)
+    seen: list[str] = []
+    cfg = openrouter_client.OpenRouterConfig(
+        api_key="k", base_url="https://or.test/api/v1", model="m/x", temperature=0.2, max_tokens=50
+    )
+    response = openrouter_client.stream_chat_completion([], config=cfg, on_text=seen.append)
+
+    assert seen == ['{"answer": ', '{"answer": "Dry."}']
+    assert response.text == '{"answer": "Dry."}'
+    assert response.model == "m/x"
+    assert response.usage == {"total_tokens": 9}
+
+
+def test_ai_clients_retry_under_the_shared_http_policy(monkeypatch):
+    request = openrouter_client.httpx.Request("POST", "https://or.test/api/v1/chat/completions")
+    reply = {"model": "m/x", "choices": [{"message": {"content": "{}"}}]}
+    statuses: list[int] = []
+
+    def answers(*codes):
+        def post(url, **kwargs):
+            statuses.append(codes[len(statuses)])
+            body = reply if statuses[-1] == 200 else {"error": "busy"}
+            return openrouter_client.httpx.Response(statuses[-1], json=body, request=request)
+
+        statuses.clear()
+        return post
+
+    cfg = openrouter_client.OpenRouterConfig(
+        api_key="k", base_url="https://or.test/api/v1", model="m/x", temperature=0.2, max_tokens=50
+    )
+    monkeypatch.setattr(openrouter_client.httpx, "post", answers(503, 502, 200))
+    assert openrouter_client.chat_completion([], config=cfg).attempts == 3
+
+    monkeypatch.setattr(openrouter_client.httpx, "post", answers(500, 200))
+    with pytest.raises(openrouter_client.OpenRouterError, match="HTTP 500") as failure:
+        openrouter_client.chat_completion([], config=cfg)
+    assert failure.value.status_code == 500 and statuses == [500]
+
+    ollama_reply = {"model": "llama3.1", "message": {"content": "{}"}}
+    statuses.clear()
+    monkeypatch.setattr(
+        ollama_client.httpx,
+        "post",
+        lambda url, **kwargs: statuses.append(url)
+        or openrouter_client.httpx.Response(503 if len(statuses) == 1 else 200, json=ollama_reply, request=request),
+    )
+    ollama_cfg = ollama_client.OllamaConfig(base_url="http://localhost:11434", model="llama3.1", temperature=0.2, max_tokens=50)
+    assert ollama_client.chat([], config=ollama_cfg).text == "{}" and len(statuses) == 2
+
+    configure_http(retries=0)
+    monkeypatch.setattr(openrouter_client.httpx, "post", answers(503, 200))
+    with pytest.raises(openrouter_client.OpenRouterError, match="HTTP 503"):
+        openrouter_client.chat_completion([], config=cfg)
+
+
+def test_ai_clients_do_not_retry_read_timeouts(monkeypatch):
+    configure_http(retries=2)
+    calls: list[str] = []
+
+    def slow(url, **kwargs):
+        calls.append(url)
+        raise ollama_client.httpx.ReadTimeout("timed out")
+
+    monkeypatch.setattr(ollama_client.httpx, "post", slow)
+    ollama_cfg = ollama_client.OllamaConfig(base_url="http://localhost:11434", model="llama3.1", temperature=0.2, max_tokens=50)
+    with pytest.raises(ollama_client.OllamaError, match="unreachable"):
+        ollama_client.chat([], config=ollama_cfg)
+    assert calls == ["http://localhost:11434/api/chat"]
+
+    cfg = openrouter_client.OpenRouterConfig(
+        api_key="k", base_url="https://or.test/api/v1", model="m/x", temperature=0.2, max_tokens=50
+    )
+    with pytest.raises(openrouter_client.OpenRouterError):
+        openrouter_client.chat_completion([], config=cfg)
+    assert len(calls) == 2
+
+
+def test_forecaster_streams_when_progress_callback_set(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, openrouter_api_key="or-key")
+    forecaster = forecaster_module.Forecaster(settings)
+    updates: list[str | None] = []
+    forecaster.on_progress = updates.append
+
+    def fake_stream(messages, *, config, on_text):
+        raw = '{"answer": "Calm.", "confidence": {"value": 70, "rationale": "ok"}}'
+        on_text(raw)
+        return openrouter_client.OpenRouterResponse(
+            text=raw, model=config.model, raw={}, usage=None, headers={}, attempts=1
+        )
+
+    monkeypatch.setattr(forecaster_module, "stream_chat_completion", fake_stream)
+    response = forecaster.generate(query="Windy?", feature_pack={}, intent="question", verbose=False)
+
+    assert response.sections["answer"] == "Calm."
+    assert updates[-1] is None
+    render = importlib.import_module("wx.render")
+    line = render.streaming_status_line(updates[0]).plain
+    assert line.startswith("writing… ✓ answer  ▸ confidence")
+
+
+def test_rate_limited_backend_falls_through_and_is_recorded(monkeypatch):
+    settings = config.Settings(
+        offline=False,
+        privacy_mode=True,
+        openrouter_api_key="or-key",
+        openrouter_models=("x-ai/grok-2", "openai/gpt-4o-mini"),
+        gemini_api_key="g-key",
+        preferred_backend="gemini",
+    )
+    forecaster = forecaster_module.Forecaster(settings)
+    tried: list[str] = []
+
+    def gemini_busy(prompt, system_prompt):
+        raise RuntimeError("gemini-call:429 RESOURCE_EXHAUSTED")
+
+    def fake_chat_completion(messages, *, config):
+        tried.append(config.model)
+        if config.model == "x-ai/grok-2":
+            raise openrouter_client.OpenRouterError("OpenRouter HTTP 503", status_code=503)
+        raw = '{"answer": "Showers after 3 PM.", "confidence": {"value": 65, "rationale": "ok"}}'
+        return openrouter_client.OpenRouterResponse(
+            text=raw, model=config.model, raw={}, usage=None, headers={}, attempts=1
+        )
+
+    monkeypatch.setattr(forecaster, "_call_gemini", gemini_busy)
+    monkeypatch.setattr(forecaster_module, "chat_completion", fake_chat_completion)
+    response = forecaster.generate(query="Rain later?", feature_pack={}, intent="question", verbose=False)
+
+    assert tried == ["x-ai/grok-2", "openai/gpt-4o-mini"]
+    assert response.provider == "openrouter:openai/gpt-4o-mini"
+    assert response.meta["backend"] == "openrouter"
+    assert response.meta["fallbacks"] == ["gemini:gemini-call:429 RESOURCE_EXHAUSTED"]
+    assert response.meta["skipped_models"] == ["x-ai/grok-2:OpenRouter HTTP 503"]
+
+
+def test_openrouter_auth_error_skips_remaining_models(monkeypatch):
+    settings = config.Settings(
+        offline=False, privacy_mode=True, openrouter_api_key="or-key", openrouter_models=("a/one", "b/two")
+    )
+    forecaster = forecaster_module.Forecaster(settings)
+    tried: list[str] = []
+
+    def unauthorized(messages, *, config):
+        tried.append(config.model)
+        raise openrouter_client.OpenRouterError("OpenRouter HTTP 401", status_code=401)
+
+    monkeypatch.setattr(forecaster_module, "chat_completion", unauthorized)
+    response = forecaster.generate(query="Rain?", feature_pack={}, intent="question", verbose=False)
+
+    assert tried == ["a/one"]
+    assert response.provider == "fallback:RuntimeError"
+
+
+def test_reply_in_wrong_language_gets_one_corrective_retry(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, language="es")
+    forecaster = forecaster_module.Forecaster(settings)
+    prompts: list[str] = []
+    replies = [
+        '{"answer": "Rain moves in after 3 PM and the heaviest showers will be in the evening."}',
+        '{"answer": "La lluvia llega después de las 3 PM y los chubascos más fuertes serán por la tarde."}',
+    ]
+
+    def fake_invoke(payload):
+        prompts.append(forecaster._build_prompt(payload))
+        return replies[len(prompts) - 1], "openrouter:test", {"backend": "openrouter"}
+
+    monkeypatch.setattr(forecaster, "_invoke_provider", fake_invoke)
+    response = forecaster.generate(query="¿Lloverá?", feature_pack={}, intent="question", verbose=False)
+
+    assert len(prompts) == 2
+    assert "Write every text value in Spanish" in prompts[0]
+    assert "Your previous reply was in English, not Spanish." in prompts[1]
+    assert response.sections["answer"].startswith("La lluvia")
+    assert response.meta["language_check"] == {"requested": "es", "detected": "en", "after_retry": None}
+
+
+def test_language_detection_and_parsing():
+    language = importlib.import_module("wx.language")
+    english = "Rain moves in after 3 PM with gusts to 30 mph. It should clear by midnight."
+    french = "La pluie arrive après 15 h avec des rafales. Le ciel se dégage dans la soirée pour minuit."
+    assert language.detect_mismatch(english, "fr") == "en"
+    assert language.detect_mismatch(french, "fr") is None
+    assert language.detect_mismatch(english, "ja") == "en"
+    assert language.detect_mismatch("午後3時以降に雨が降り、夜に最も強いにわか雨となります。", "en") == "ja"
+    assert language.detect_mismatch("Sunny.", "de") is None
+    assert config.parse_language("pt-BR") == "pt"
+    assert config.parse_language("Japanese") == "ja"
+    with pytest.raises(ValueError, match="Unsupported language"):
+        config.parse_language("klingon")
+
+
+def test_prompt_version_selects_system_prompt_and_is_recorded(monkeypatch):
+    prompts = importlib.import_module("wx.prompts")
+    settings = config.Settings(offline=False, privacy_mode=True, anthropic_api_key="a-key", prompt_version="v2")
+    forecaster = forecaster_module.Forecaster(settings)
+    systems = []
+
+    def fake_create_message(system, prompt, *, config):
+        systems.append(system)
+        raw = '{"sections": {"summary": ["Dry."]}, "bottom_line": "Dry."}'
+        return anthropic_client.AnthropicResponse(text=raw, model=config.model, raw={}, usage=None)
+
+    monkeypatch.setattr(forecaster_module, "create_message", fake_create_message)
+    v2 = forecaster.generate(query="Denver", feature_pack={}, intent="forecast", verbose=False)
+    v1 = forecaster.generate(query="Denver", feature_pack={}, intent="forecast", verbose=False, prompt_version="v1")
+
+    assert systems == [prompts.SYSTEM_PROMPT_V2, prompts.SYSTEM_PROMPT]
+    assert v2.meta["prompt_version"] == "v2" and v1.meta["prompt_version"] == "v1"
+    assert prompts.parse_prompt_versions("V2, v1,v2") == ["v2", "v1"]
+    with pytest.raises(ValueError):
+        prompts.parse_prompt_versions("v1,v9")
+    with pytest.raises(ValueError):
+        prompts.parse_prompt_version("v1,v2")
@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import importlib
+from datetime import UTC, datetime
+
+import pytest
+
+from wx import config
+from wx.freshness import issuance_pack, next_update, wait_for_newer
+from wx.providers import NWSProvider
+
+orchestrator_module = importlib.import_module("wx.orchestrator")
+providers_module = importlib.import_module("wx.providers")
+
+NOW = datetime(2026, 10, 16, 21, 5, tzinfo=UTC)
+
+
+def test_issuance_label_and_next_update() -> None:
+    pack = issuance_pack("2026-10-16T20:42:11+00:00", tz_name="America/Chicago", now=NOW)
+
+    assert pack["label"] == "NWS forecast issued 3:42 PM CDT, next update ~5 PM CDT"
+    assert pack["next_update_iso"] == "2026-10-16T22:00:00+00:00"
+    assert pack["age_minutes"] == 23 and pack["overdue"] is False
+    assert next_update(datetime(2026, 10, 16, 20, 0, tzinfo=UTC)) == datetime(2026, 10, 16, 21, 0, tzinfo=UTC)
+
+    stale = issuance_pack("2026-10-15T20:42:00Z", tz_name="America/Chicago", now=NOW)
+    assert stale["label"] == "NWS forecast issued Thu 3:42 PM CDT, next update overdue"
+    assert issuance_pack(None) is None and issuance_pack("soon") is None
+
+
+def test_wait_for_newer_polls_until_a_later_issuance() -> None:
+    answers = iter(["2026-10-16T20:42:11+00:00", None, "2026-10-16T21:48:02+00:00"])
+    naps: list[float] = []
+
+    latest = wait_for_newer(lambda: next(answers), "2026-10-16T20:42:11+00:00", poll_seconds=60, sleep=naps.append)
+
+    assert latest == "2026-10-16T21:48:02+00:00" and naps == [60, 60, 60]
+    unchanged = wait_for_newer(
+        lambda: "2026-10-16T20:42:11Z", "2026-10-16T20:42:11Z", timeout_minutes=3, poll_seconds=60, sleep=naps.append
+    )
+    assert unchanged is None
+    assert len(naps) == 6
+
+
+def test_nws_forecast_keeps_its_issuance_and_wait_for_update_polls(monkeypatch) -> None:
+    nws = {
+        "periods": [{"startTime": "2026-10-16T16:00:00-05:00", "temperature": 61, "temperatureUnit": "F"}],
+        "update_time": "2026-10-16T20:42:11+00:00",
+    }
+    monkeypatch.setattr(providers_module, "fetch_nws_hourly", lambda lat, lon, offline=False: nws)
+    hourly = NWSProvider().forecast(41.9, -87.6)
+    assert hourly["issued"] == "2026-10-16T20:42:11+00:00" and hourly["temperature_2m"] == [16.1]
+
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(provider="nws", privacy_mode=True))
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Chicago, IL", "lat": 41.9, "lon": -87.6}
+    )
+    stamps = iter(["2026-10-16T20:42:11+00:00", "2026-10-16T20:42:11+00:00", "2026-10-16T21:48:02+00:00"])
+    monkeypatch.setattr(orchestrator_module, "fetch_nws_hourly", lambda lat, lon: {"update_time": next(stamps)})
+    seen = []
+
+    issuance = orchestrator.wait_for_update("Chicago", sleep=lambda seconds: None, on_wait=seen.append)
+
+    assert seen[0]["issued_iso"] == "2026-10-16T20:42:11+00:00"
+    assert issuance["issued_iso"] == "2026-10-16T21:48:02+00:00" and orchestrator.provider.refresh is True
+
+    open_meteo = orchestrator_module.Orchestrator(config.Settings(privacy_mode=True))
+    with pytest.raises(ValueError, match="use --provider nws"):
+        open_meteo.wait_for_update("Chicago")
@@ -0,0 +1,73 @@
+"""Tests for frontal passage detection."""
+
+from __future__ import annotations
+
+from wx.fronts import describe_front, detect_fronts
+
+
+def _hourly(temps, dewpoints, directions, pressures=None) -> dict:
+    count = len(temps)
+    return {
+        "time": [f"2026-10-16T{hour:02d}:00" for hour in range(count)],
+        "temperature_2m": temps,
+        "dew_point_2m": dewpoints,
+        "wind_direction_10m": directions,
+        "wind_speed_10m": [20.0] * count,
+        "pressure_msl": pressures or [1010.0] * count,
+    }
+
+
+def test_detects_cold_front_with_pressure_trough() -> None:
+    hourly = _hourly(
+        temps=[20, 20, 20, 20, 19, 18, 16, 13, 12, 11, 11, 11, 11],
+        dewpoints=[15, 15, 15, 15, 14, 12, 10, 7, 6, 5, 5, 5, 5],
+        directions=[200, 200, 200, 200, 210, 230, 270, 300, 310, 315, 315, 315, 315],
+        pressures=[1008, 1007, 1006, 1005, 1004, 1003, 1003, 1004, 1006, 1007, 1008, 1009, 1009],
+    )
+
+    fronts = detect_fronts(hourly)
+
+    assert len(fronts) == 1
+    front = fronts[0]
+    assert front.kind == "cold"
+    assert front.time_iso.startswith("2026-10-16T0")
+    assert front.pressure_rise_hpa is not None
+    assert any(driver.startswith("Pressure trough") for driver in front.drivers)
+
+
+def test_detects_warm_front() -> None:
+    hourly = _hourly(
+        temps=[8, 8, 8, 8, 9, 11, 13, 14, 14, 14],
+        dewpoints=[4, 4, 4, 4, 6, 9, 11, 12, 12, 12],
+        directions=[100, 100, 100, 100, 130, 170, 200, 210, 210, 210],
+    )
+
+    assert [front.kind for front in detect_fronts(hourly)] == ["warm"]
+
+
+def test_ignores_diurnal_cooling_without_wind_shift() -> None:
+    hourly = _hourly(
+        temps=[25, 24, 23, 21, 19, 17, 16, 15, 15, 15],
+        dewpoints=[12, 12, 12, 12, 12, 12, 12, 12, 12, 12],
+        directions=[180] * 10,
+    )
+
+    assert detect_fronts(hourly) == []
+    assert detect_fronts(None) == []
+
+
+def test_describe_front_uses_local_time_and_units() -> None:
+    hourly = _hourly(
+        temps=[20, 20, 20, 20, 19, 18, 16, 13, 12, 11, 11, 11, 11],
+        dewpoints=[15, 15, 15, 15, 14, 12, 10, 7, 6, 5, 5, 5, 5],
+        directions=[200, 200, 200, 200, 210, 230, 270, 300, 310, 315, 315, 315, 315],
+    )
+    front = detect_fronts(hourly)[0]
+    front.time_iso = "2026-10-16T21:00:00+00:00"
+    front.temp_change_c = -6.7
+
+    label = describe_front(front, tz_name="America/Chicago", units="imperial")
+
+    assert label.startswith("Cold front ~Fri 4 PM: wind veers ")
+    assert label.endswith("temp drops 12°")
+    assert describe_front(front, units="metric").endswith("temp drops 7°")
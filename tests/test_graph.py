@@ -0,0 +1,35 @@
+"""Tests for forecast variable charting."""
+
+from __future__ import annotations
+
+from wx.graph import build_series, resolve_variable
+
+
+def test_resolve_variable_accepts_aliases() -> None:
+    assert resolve_variable("Temperature") == "temperature"
+    assert resolve_variable("precip-prob") == "precip_probability"
+    assert resolve_variable("gusts") == "gust"
+    assert resolve_variable("visibility") is None
+
+
+def test_build_series_converts_units_and_trims_to_horizon() -> None:
+    hourly = {
+        "time": ["2026-10-16T00:00", "2026-10-16T01:00", "2026-10-16T02:00"],
+        "wind_gusts_10m": [36.0, None, 72.0],
+    }
+
+    imperial = build_series("Tulsa", "gust", hourly, hours=2, units="imperial")
+    assert imperial.unit == "mph"
+    assert imperial.values == [22.4, None]
+    assert imperial.times == hourly["time"][:2]
+
+    metric = build_series("Tulsa", "gust", hourly, hours=3, units="metric")
+    assert metric.unit == "m/s"
+    assert metric.values == [10.0, None, 20.0]
+
+
+def test_build_series_without_data() -> None:
+    series = build_series("Nowhere", "cape", None, hours=24, units="metric")
+
+    assert series.label == "CAPE"
+    assert not series.has_data
@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+import io
+import json
+import threading
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.cli import app
+from wx.favorites import Favorite
+from wx.groups import group_name, parse_groups
+from wx.profile import load_profile
+
+ALERTS = {
+    "Denver": [{"event": "Wind Advisory", "severity": "Moderate", "expires_iso": "2026-10-17T00:00:00+00:00"}],
+    "Boulder": [{"event": "Blizzard Warning", "severity": "Extreme", "expires_iso": "2026-10-17T06:00:00+00:00"}],
+    "Golden": [],
+}
+
+
+class _Provider:
+    """Answers only once all three known sites are being fetched at the same time."""
+
+    name = "stub"
+
+    def __init__(self) -> None:
+        self.barrier = threading.Barrier(3, timeout=5)
+
+    def geocode(self, place):
+        if place not in ALERTS:
+            return None
+        lat = float(list(ALERTS).index(place))
+        return {"resolved": f"{place}, CO", "lat": lat, "lon": -105.0, "tz": "America/Denver"}
+
+    def alerts(self, lat, lon):
+        self.barrier.wait()
+        return list(ALERTS.values())[int(lat)]
+
+
+def test_parse_groups_and_profile_groups(config_dir) -> None:
+    raw = {"Stores": ["Denver", {"name": "Store 12", "place": "39.7,-105.0"}], "@empty": [], "bad": "Denver"}
+
+    assert parse_groups(raw) == {"stores": [Favorite("Denver", "Denver"), Favorite("Store 12", "39.7,-105.0")]}
+    assert group_name("@Stores") == "stores" and group_name("Denver") is None and group_name("@") is None
+
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"groups": {"stores": {"north": "Boulder"}}}))
+    assert load_profile().groups == {"stores": [Favorite("north", "Boulder")]}
+
+
+def test_group_alerts_fetched_concurrently_most_serious_first() -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    orchestrator.provider = _Provider()
+    sites = [Favorite(f"Store {idx}", place) for idx, place in enumerate(("Denver", "Golden", "Atlantis", "Boulder"))]
+
+    report = orchestrator.handle_group_alerts("stores", sites)
+
+    assert [site.label for site in report.sites] == ["Store 3", "Store 0", "Store 1", "Store 2"]
+    assert report.sites[-1].error == "Could not find Atlantis"
+    assert report.summary == "2 of 4 stores have alerts (1 severe or extreme); 1 could not be checked."
+    console = Console(file=io.StringIO(), width=160)
+    render.render_group_alerts(report, console=console)
+    output = console.file.getvalue()
+    assert output.index("Blizzard Warning until Sat 12 AM MDT") < output.index("Wind Advisory until Fri 6 PM MDT")
+    assert "Could not find Atlantis" in output and "No alerts: Store 1" in output
+
+
+def test_group_commands_resolve_profile_groups(config_dir, cli_runner, monkeypatch) -> None:
+    groups = {"stores": ["Denver", "Golden"]}
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"groups": groups}))
+    requested = []
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator,
+        "handle_group_alerts",
+        lambda self, group, sites: requested.append((group, sites)) or orchestrator_module.GroupAlertsReport(group),
+    )
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator, "handle_favorites", lambda self, favorites: requested.extend(favorites) or []
+    )
+
+    alerts = cli_runner.invoke(app, ["", "alerts", "@stores"])
+    brief = cli_runner.invoke(app, ["", "favorites", "@stores"])
+    unknown = cli_runner.invoke(app, ["", "alerts", "@depots"])
+
+    assert alerts.exit_code == 0 and brief.exit_code == 0, alerts.output + brief.output
+    assert requested == [
+        ("stores", [Favorite("Denver", "Denver"), Favorite("Golden", "Golden")]),
+        Favorite("Denver", "Denver"),
+        Favorite("Golden", "Golden"),
+    ]
+    assert "@stores" in alerts.output and "@stores" in brief.output
+    assert unknown.exit_code == 2 and "No location group 'depots'" in unknown.output and "@stores" in unknown.output
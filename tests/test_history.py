@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+import io
+from datetime import date
+
+import pytest
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.cli import app
+from wx.history import add_normals, history_rows, summarize_history, validate_range
+
+DAILY = {
+    "time": ["2023-07-04", "2023-07-05"],
+    "weather_code": [95, 0],
+    "temperature_2m_max": [31.0, 27.5],
+    "temperature_2m_min": [20.0, 16.0],
+    "temperature_2m_mean": [25.0, 21.5],
+    "precipitation_sum": [12.7, 0.0],
+    "snowfall_sum": [0.0, 0.0],
+    "wind_speed_10m_max": [20.0, 12.0],
+    "wind_gusts_10m_max": [64.4, None],
+}
+# Flat 26/16 °C July normals.
+NORMALS = {"tmax": [26.0] * 12, "tmin": [16.0] * 12}
+
+
+def test_validate_range() -> None:
+    today = date(2026, 10, 16)
+    assert validate_range(date(2023, 7, 4), None, today=today) == (date(2023, 7, 4), date(2023, 7, 4))
+    with pytest.raises(ValueError, match="before the start"):
+        validate_range(date(2023, 7, 4), date(2023, 7, 1), today=today)
+    with pytest.raises(ValueError, match="2026-10-11 or earlier"):
+        validate_range(date(2026, 10, 14), None, today=today)
+    with pytest.raises(ValueError, match="1940-01-01"):
+        validate_range(date(1939, 12, 31), None, today=today)
+    with pytest.raises(ValueError, match="92 days"):
+        validate_range(date(2023, 1, 1), date(2023, 6, 1), today=today)
+
+
+def test_rows_normals_and_summary() -> None:
+    rows = history_rows(DAILY)
+    add_normals(rows, NORMALS)
+
+    assert rows[0]["conditions"] == "Thunderstorms" and rows[1]["gust_max_kmh"] is None
+    assert rows[0]["max_departure_c"] == 5.0 and rows[1]["min_departure_c"] == 0.0
+    assert summarize_history(rows) == {
+        "days": 2,
+        "warmest": "2023-07-04",
+        "coldest": "2023-07-05",
+        "precip_total_mm": 12.7,
+        "wet_days": 1,
+        "snow_total_cm": 0.0,
+        "mean_departure_c": 2.6,
+    }
+
+
+def test_handle_history_renders_departures(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    requested = {}
+
+    def fake_archive(lat, lon, start, end, *, tz_name=None, offline=False):
+        requested.update(start=start, end=end, tz=tz_name)
+        return DAILY
+
+    monkeypatch.setattr(
+        orchestrator.provider,
+        "geocode",
+        lambda place: {"resolved": "Boston", "lat": 42.36, "lon": -71.06, "tz": "America/New_York"},
+    )
+    monkeypatch.setattr(orchestrator_module, "get_archive_daily", fake_archive)
+    monkeypatch.setattr(
+        orchestrator_module, "ensure_normals", lambda cache, points, offline=False: cache.put(*points[0], NORMALS)
+    )
+
+    report = orchestrator.handle_history("Boston", date(2023, 7, 4), date(2023, 7, 5), compare_to_normal=True)
+
+    assert requested == {"start": date(2023, 7, 4), "end": date(2023, 7, 5), "tz": "America/New_York"}
+    assert report.compared_to_normal and report.sources[-1] == "NASA POWER climatology"
+    console = Console(file=io.StringIO(), width=200)
+    render.render_history(report, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "Weather history — Boston, 2023-07-04 to 2023-07-05" in output
+    assert "88°F (+9)" in output and "0.5 in" in output and "40 mph" in output
+    assert "Warmest 2023-07-04 (88°F); coldest 2023-07-05 (61°F); 0.5 in precipitation, 1 wet day." in output
+    assert "On average 4.7°F warmer than normal." in output
+
+
+def test_history_command_rejects_future_dates(cli_runner, config_dir, state_dir) -> None:
+    result = cli_runner.invoke(app, ["", "history", "Boston", "--date", "2099-01-01"])
+    assert result.exit_code == 2
+    assert "days behind" in result.output
+
+    result = cli_runner.invoke(app, ["--json", "", "history", "Boston", "--date", "July 4"])
+    assert result.exit_code == 2 and "YYYY-MM-DD" in result.output
@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import httpx
+import pytest
+
+from wx import httpclient
+from wx.cli import app
+from wx.config import load_settings
+from wx.httpclient import backoff_delay, configure_http, parse_timeout, timeout_for, with_retries
+
+REQUEST = httpx.Request("GET", "https://api.weather.gov/points/40.0000,-105.0000")
+
+
+def _responses(*outcomes):
+    calls = []
+
+    def call() -> httpx.Response:
+        outcome = outcomes[len(calls)]
+        calls.append(outcome)
+        if isinstance(outcome, Exception):
+            raise outcome
+        return httpx.Response(outcome, request=REQUEST)
+
+    return call, calls
+
+
+def test_transient_failures_are_retried_with_backoff(monkeypatch) -> None:
+    sleeps: list[float] = []
+    monkeypatch.setattr(httpclient, "_sleep", sleeps.append)
+
+    call, calls = _responses(httpx.ConnectTimeout("slow", request=REQUEST), 502, 200)
+    assert with_retries(call).status_code == 200
+    assert len(calls) == 3 and 0.25 <= sleeps[0] <= 0.5 and 0.5 <= sleeps[1] <= 1.0
+
+    call, calls = _responses(503, 503, 503, 200)
+    assert with_retries(call).status_code == 503 and len(calls) == 3
+
+    call, calls = _responses(404, 200)
+    assert with_retries(call).status_code == 404 and len(calls) == 1
+
+    configure_http(retries=0)
+    call, calls = _responses(httpx.ConnectError("refused", request=REQUEST))
+    with pytest.raises(httpx.ConnectError):
+        with_retries(call)
+
+
+def test_retry_after_and_timeout_overrides() -> None:
+    limited = httpx.Response(429, headers={"Retry-After": "3"}, request=REQUEST)
+    assert backoff_delay(0, limited) == 3.0
+    assert backoff_delay(10) <= httpclient.BACKOFF_MAX
+
+    assert timeout_for(3.0) == 3.0
+    configure_http(timeout=12.5)
+    assert timeout_for(3.0) == 12.5
+    assert parse_timeout(None) is None and parse_timeout("8") == 8.0
+    with pytest.raises(ValueError, match="HTTP timeout"):
+        parse_timeout("-1")
+
+
+def test_timeout_flag_and_retry_settings(cli_runner, monkeypatch) -> None:
+    monkeypatch.setenv("WX_HTTP_RETRIES", "4")
+    monkeypatch.setenv("WX_HTTP_TIMEOUT", "6")
+
+    settings = load_settings(timeout=9.0)
+    assert (settings.http_timeout, settings.http_retries) == (9.0, 4)
+    assert load_settings().http_timeout == 6.0
+
+    bad = cli_runner.invoke(app, ["--timeout", "0", "", "cache", "stats"])
+    assert bad.exit_code == 2 and "--timeout" in bad.output
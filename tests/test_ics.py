@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+from datetime import UTC, datetime
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import app
+from wx.forecaster import ForecasterResponse
+from wx.ics import fold, forecast_calendar, story_timing
+from wx.orchestrator import OrchestrationResult
+
+NOW = datetime(2026, 10, 16, 14, 0, tzinfo=UTC)  # Fri 7 AM PDT
+
+
+def _result() -> OrchestrationResult:
+    response = ForecasterResponse(
+        sections={
+            "summary": ["Windy afternoon."],
+            "timeline": ["Fri 12 PM PDT–Fri 6 PM PDT: gusts 40-45 mph, strongest mid-afternoon.", "Showers taper."],
+            "actions": ["Secure patio furniture before Fri 11 AM PDT.", "Sat 2 PM PDT: take care over the pass."],
+        },
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Windy.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+    feature_pack = {
+        "place": {"resolved": "Seattle, WA", "tz": "America/Los_Angeles"},
+        "periods": [
+            {
+                "start_iso": "2026-10-16T15:00:00+00:00",
+                "hours": 3,
+                "temp": 54,
+                "feels_like": 52,
+                "gust": 22,
+                "precip_prob": 40,
+                "precip": 0.1,
+            },
+        ],
+        "alerts_quick": [
+            {
+                "event": "Wind Advisory",
+                "severity": "Moderate",
+                "areas": ["Seattle; Bellevue"],
+                "headline": "Wind Advisory until 9 PM PDT by NWS Seattle, WA",
+                "onset_iso": "2026-10-16T12:00:00-07:00",
+                "expires_iso": "2026-10-16T21:00:00-07:00",
+            }
+        ],
+    }
+    return OrchestrationResult("forecast", "Seattle", feature_pack, response, {}, {})
+
+
+def test_story_timing_reads_wx_clock_labels_and_fold_splits_on_octets() -> None:
+    after = NOW
+    assert story_timing("Secure it before Fri 11:30 AM PDT.", "America/Los_Angeles", after=after) == (
+        datetime(2026, 10, 16, 18, 30, tzinfo=UTC),
+        datetime(2026, 10, 16, 19, 30, tzinfo=UTC),
+    )
+    assert story_timing("Fri 10 PM–2 AM PDT: heaviest rain", "America/Los_Angeles", after=after) == (
+        datetime(2026, 10, 17, 5, 0, tzinfo=UTC),
+        datetime(2026, 10, 17, 9, 0, tzinfo=UTC),
+    )
+    assert story_timing("Showers taper overnight.", "America/Los_Angeles", after=after) is None
+
+    lines = fold("SUMMARY:" + "°" * 80)
+    assert all(len(line.encode()) <= 75 for line in lines) and all(line.startswith(" ") for line in lines[1:])
+    assert "".join(line[1:] if idx else line for idx, line in enumerate(lines)) == "SUMMARY:" + "°" * 80
+
+
+def test_forecast_calendar_has_periods_alerts_and_timed_story_items() -> None:
+    text = forecast_calendar(_result(), units="imperial", now=NOW)
+    lines = text.split("\r\n")
+
+    assert lines[0] == "BEGIN:VCALENDAR" and lines[-2] == "END:VCALENDAR" and text.endswith("\r\n")
+    assert text.count("BEGIN:VEVENT") == 5
+    assert "X-WR-CALNAME:Weather: Seattle\\, WA" in lines
+    assert "SUMMARY:54°F · 40% precip · gusts 22 mph" in lines
+    assert "DTSTART:20261016T150000Z" in lines and "DTEND:20261016T180000Z" in lines
+    assert "SUMMARY:⚠ Wind Advisory" in lines and "DTEND:20261017T040000Z" in lines
+    unfolded = text.replace("\r\n ", "")
+    assert "DESCRIPTION:Wind Advisory until 9 PM PDT by NWS Seattle\\, WA\\nSeverity: Moderate" in unfolded
+    # Timeline range, the action's deadline, and Saturday's action.
+    assert "DTEND:20261017T010000Z" in lines and "DTSTART:20261016T180000Z" in lines
+    assert "DTSTART:20261017T210000Z" in lines
+    assert all(len(line.encode()) <= 75 for line in lines)
+
+
+def test_forecast_export_writes_ics_to_stdout(cli_runner, config_dir, state_dir, monkeypatch) -> None:
+    monkeypatch.setattr(orchestrator_module.Orchestrator, "handle_forecast", lambda self, place, **kwargs: _result())
+
+    result = cli_runner.invoke(app, ["", "forecast", "Seattle", "--export", "ics"])
+
+    assert result.exit_code == 0, result.output
+    assert result.output.startswith("BEGIN:VCALENDAR") and "Summary" not in result.output
+
+    result = cli_runner.invoke(app, ["", "forecast", "Seattle", "--export", "pdf"])
+    assert result.exit_code == 2 and "Choose from: ics" in result.output
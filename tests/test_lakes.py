@@ -0,0 +1,84 @@
+"""Tests for lake condition parsing."""
+
+from __future__ import annotations
+
+from datetime import date
+
+from wx.lakes import (
+    GREAT_LAKES,
+    ice_season,
+    nearest_buoy,
+    nearest_great_lake,
+    parse_ice_table,
+    parse_ndbc_latest,
+    parse_open_lakes_forecast,
+)
+
+NDBC_SAMPLE = """\
+#STN       LAT      LON  YYYY MM DD hh mm WDIR WSPD   GST WVHT  DPD APD MWD   PRES  PTDY  ATMP  WTMP  DEWP  VIS   TIDE
+#text      deg      deg   yr mo day hr mn degT  m/s   m/s    m   sec sec degT   hPa   hPa  degC  degC  degC  nmi     ft
+45007    42.674  -87.026 2026 10 16 14 50  200  6.0   8.0  1.1    5  MM  MM 1015.2    MM  14.1  15.3   9.0   MM     MM
+45186    42.368  -87.798 2026 10 16 14 50  190  4.0    MM   MM   MM  MM  MM     MM    MM    MM    MM    MM   MM     MM
+CHII2    41.916  -87.572 2026 10 16 14 50  210  5.1   7.2   MM   MM  MM  MM 1015.0    MM  13.0  14.8    MM   MM     MM
+"""
+
+GLF_SAMPLE = """\
+LMZ080-170300-
+Lake Michigan north of a line from Charlevoix to Point Betsie
+900 AM CDT Fri Oct 16 2026
+
+.TODAY...South wind 10 to 20 kt. Waves 2 to 4 ft.
+.TONIGHT...Southwest wind 15 to 25 kt becoming west
+after midnight. Waves 4 to 7 ft.
+.SATURDAY...West gales to 35 kt. Waves 8 to 12 ft.
+
+$$
+"""
+
+ICE_SAMPLE = """\
+Great Lakes ice cover (percent)
+Year Day  Sup   Mich  Huron Erie  Ont   StClr GLakes
+2027 044  38.2  20.1  45.0  88.3  12.5  97.0  38.4
+2027 045  41.6  21.8  47.2  90.1  13.0  98.2  40.7
+"""
+
+
+def test_nearest_great_lake() -> None:
+    assert nearest_great_lake(41.88, -87.63).name == "Lake Michigan"
+    assert nearest_great_lake(42.89, -78.88).name == "Lake Erie"
+    assert nearest_great_lake(39.74, -104.99) is None
+
+
+def test_parse_ndbc_and_nearest_buoy() -> None:
+    stations = parse_ndbc_latest(NDBC_SAMPLE)
+
+    assert [station["station"] for station in stations] == ["45007", "45186", "CHII2"]
+    assert stations[0]["water_temp_c"] == 15.3
+    assert stations[0]["wave_height_m"] == 1.1
+    assert stations[1]["water_temp_c"] is None
+    assert stations[0]["observed"] == "2026-10-16T14:50:00+00:00"
+
+    # 45186 is closest to Waukegan but reports neither water temperature nor waves.
+    buoy = nearest_buoy(stations, 42.36, -87.83)
+    assert buoy["station"] == "CHII2"
+    assert nearest_buoy(stations, 39.74, -104.99) is None
+
+
+def test_parse_open_lakes_forecast_joins_continuations() -> None:
+    periods = parse_open_lakes_forecast(GLF_SAMPLE)
+
+    assert [period["period"] for period in periods] == ["Today", "Tonight", "Saturday"]
+    assert periods[1]["text"] == "Southwest wind 15 to 25 kt becoming west after midnight. Waves 4 to 7 ft."
+    assert len(parse_open_lakes_forecast(GLF_SAMPLE, limit=2)) == 2
+
+
+def test_parse_ice_table_takes_latest_day() -> None:
+    erie = next(lake for lake in GREAT_LAKES if lake.code == "LE")
+
+    assert parse_ice_table(ICE_SAMPLE, erie) == ("2027-02-14", 90.1)
+    assert parse_ice_table("no data", erie) is None
+
+
+def test_ice_season_spans_winter() -> None:
+    assert ice_season(date(2026, 12, 1)) == "2026_2027"
+    assert ice_season(date(2027, 2, 1)) == "2026_2027"
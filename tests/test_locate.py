@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import pytest
+
+from wx import locate
+from wx.cli import app
+
+
+def test_parse_geoclue_output() -> None:
+    output = "Client object: /org/freedesktop/GeoClue2/Client/1\n\nNew location:\nLatitude:    47.606200°\nLongitude:   -122.332100°\nAccuracy:    25000.000000 meters\n"
+
+    found = locate.parse_geoclue(output)
+
+    assert (found.lat, found.lon, found.source) == (47.6062, -122.3321, "geoclue")
+    assert found.place == "47.61,-122.33"
+    assert locate.parse_geoclue("Client object: /org/freedesktop/GeoClue2/Client/1") is None
+
+
+def test_current_location_prefers_os_then_ip(monkeypatch) -> None:
+    gps = locate.Location(lat=1.0, lon=2.0, label="1,2", source="geoclue")
+    monkeypatch.setattr(locate, "_os_location", lambda: gps)
+    monkeypatch.setattr(
+        locate, "get_ip_location", lambda offline=False: {"label": "Oslo, Norway", "lat": 59.91, "lon": 10.75}
+    )
+
+    assert locate.current_location() is gps
+    assert locate.current_location(use_os=False).label == "Oslo, Norway"
+    assert locate.current_location(offline=True) is None
+    assert locate.current_location(use_os=False, use_ip=False) is None
+    assert locate.is_here(" HERE ") and not locate.is_here("Hereford")
+
+
+def test_here_skips_ip_lookup_in_privacy_mode(cli_runner, config_dir, monkeypatch) -> None:
+    monkeypatch.setenv("PRIVACY_MODE", "1")
+    monkeypatch.setattr(locate, "_os_location", lambda: None)
+    monkeypatch.setattr(locate, "get_ip_location", lambda offline=False: pytest.fail("IP lookup in privacy mode"))
+
+    ip_only = cli_runner.invoke(app, ["", "here", "--ip"])
+    detected = cli_runner.invoke(app, ["", "here"])
+
+    assert ip_only.exit_code == 2 and "PRIVACY_MODE=0" in ip_only.output
+    assert detected.exit_code == 1 and "off in privacy mode" in detected.output
@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+import io
+import math
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.marine import build_marine_report, tide_turns
+
+NOW = datetime(2026, 7, 1, 12, tzinfo=UTC)
+
+
+def _hourly(hours: int = 24) -> dict[str, list]:
+    times = [(NOW + timedelta(hours=idx - 2)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)]
+    return {
+        "time": times,
+        "wave_height": [round(1.0 + idx * 0.1, 1) for idx in range(hours)],
+        "wave_period": [8.0] * hours,
+        "wave_direction": [270.0] * hours,
+        "wind_wave_height": [0.4] * hours,
+        "swell_wave_height": [0.9] * hours,
+        "swell_wave_direction": [250.0] * hours,
+        "swell_wave_period": [12.0] * hours,
+        "sea_surface_temperature": [14.5] * hours,
+        # Semidiurnal tide: a high near every 12.4 hours.
+        "sea_level_height_msl": [round(math.cos(2 * math.pi * idx / 12.4), 3) for idx in range(hours)],
+    }
+
+
+def test_tide_turns_alternate_high_and_low() -> None:
+    rows = [
+        {"time_iso": f"t{idx}", "sea_level_m": level}
+        for idx, level in enumerate([0.1, 0.8, 1.2, 0.9, 0.0, -0.7, -0.9, -0.4, None, 0.3])
+    ]
+    assert tide_turns(rows) == [
+        {"time_iso": "t2", "type": "high", "height_m": 1.2},
+        {"time_iso": "t6", "type": "low", "height_m": -0.9},
+    ]
+
+
+def test_marine_report_starts_now_and_finds_peak() -> None:
+    report = build_marine_report("Newport", _hourly(), now=NOW)
+
+    assert report.now["time_iso"] == NOW.isoformat()
+    assert report.now["wave_height_m"] == 1.2
+    assert len(report.periods) == 8
+    assert report.peak["wave_height_m"] == 3.3
+    assert [turn["type"] for turn in report.tides] == ["low", "high", "low"]
+
+
+def test_handle_marine_and_render(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    monkeypatch.setattr(
+        orchestrator.provider,
+        "geocode",
+        lambda place: {"resolved": "Newport", "lat": 44.63, "lon": -124.05, "tz": "America/Los_Angeles"},
+    )
+    hourly = _hourly(60)
+    hourly["time"] = [
+        (datetime.now(UTC) + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:00") for idx in range(60)
+    ]
+    monkeypatch.setattr(orchestrator_module, "get_marine_forecast", lambda lat, lon, hours, offline: hourly)
+
+    report = orchestrator.handle_marine("Newport, OR")
+
+    assert report.place == "Newport" and report.tz == "America/Los_Angeles"
+    console = Console(file=io.StringIO(), width=160)
+    render.render_marine(report, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "Waves 3.3 ft every 8 s from W" in output and "water 58°F" in output
+    assert "Highest waves" in output and "Tides (modeled): low" in output
+
+    result = orchestrator.handle_forecast("Newport", when_text=None, horizon="12h", focus="sailing", verbose=False)
+    assert result.feature_pack["marine"]["now"]["swell_period_s"] == 12.0
+
+    monkeypatch.setattr(orchestrator_module, "get_marine_forecast", lambda lat, lon, hours, offline: None)
+    inland = orchestrator.handle_marine("Boise")
+    render.render_marine(inland, console=console, units="metric")
+    assert inland.now is None and "No marine forecast for Newport" in console.file.getvalue()
@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+import io
+import json
+
+from rich.console import Console
+
+from wx import config, fetchers, orchestrator as orchestrator_module, render
+from wx.models import describe_divergence, find_divergences, model_runs
+
+TIMES = [f"2026-10-16T{hour:02d}:00" for hour in range(18, 24)] + [f"2026-10-17T{hour:02d}:00" for hour in range(6)]
+
+
+def _forecasts() -> dict:
+    """GFS steady and dry; ICON wet for the first six hours; ECMWF 4 °C warmer for the next six."""
+
+    return {
+        "time": TIMES,
+        "gfs_seamless": {"temperature_2m": [10.0] * 12, "precipitation": [0.0] * 12},
+        "ecmwf_ifs025": {"temperature_2m": [10.0] * 6 + [14.0] * 6, "precipitation": [0.0] * 12},
+        "icon_seamless": {"temperature_2m": [11.0] * 12, "precipitation": [0.5] * 6 + [0.0] * 6},
+    }
+
+
+def test_fetch_model_forecasts_splits_suffixed_series(monkeypatch) -> None:
+    payload = {
+        "hourly": {
+            "time": TIMES[:2],
+            "temperature_2m_gfs_seamless": [10.2, 9.8],
+            "precipitation_gfs_seamless": [0.0, None],
+            "temperature_2m_ecmwf_ifs025": [11.0, 10.5],
+            "precipitation_ecmwf_ifs025": [0.1, 0.4],
+            "temperature_2m_icon_seamless": [None, None],
+        }
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+
+    forecasts = fetchers.fetch_model_forecasts(40.0, -105.0, ("gfs_seamless", "ecmwf_ifs025", "icon_seamless"))
+
+    assert set(forecasts) == {"time", "gfs_seamless", "ecmwf_ifs025"}
+    assert forecasts["gfs_seamless"] == {"temperature_2m": [10.2, 9.8], "precipitation": [0.0, None]}
+    runs = model_runs(forecasts)
+    assert [(run.label, run.high_c, run.low_c, run.precip_mm) for run in runs] == [
+        ("GFS", 10.2, 9.8, 0.0),
+        ("ECMWF", 11.0, 10.5, 0.5),
+    ]
+    assert fetchers.fetch_model_forecasts(40.0, -105.0, ("gfs_seamless",), offline=True) is None
+
+
+def test_divergent_blocks_are_found_and_described() -> None:
+    blocks = find_divergences(TIMES, model_runs(_forecasts()))
+
+    assert [block["reasons"] for block in blocks] == [["precipitation"], ["temperature"]]
+    assert blocks[0]["precip_mm"] == {"GFS": 0.0, "ECMWF": 0.0, "ICON": 3.0}
+    assert describe_divergence(blocks[0], units="metric", tz_name="America/Denver") == (
+        "Fri 12 PM–6 PM MDT: ICON wet (3.0 mm), GFS dry."
+    )
+    assert describe_divergence(blocks[1], units="imperial", tz_name="America/Denver") == (
+        "Fri 6 PM–12 AM MDT: ECMWF 7°F warmer than GFS."
+    )
+
+    steady = _forecasts()
+    steady["ecmwf_ifs025"] = steady["gfs_seamless"]
+    steady["icon_seamless"] = steady["gfs_seamless"]
+    assert find_divergences(TIMES, model_runs(steady)) == []
+
+
+def test_handle_models_renders_table_and_divergences(monkeypatch) -> None:
+    requested = []
+    monkeypatch.setattr(
+        orchestrator_module,
+        "fetch_model_forecasts",
+        lambda lat, lon, models, **kwargs: requested.append((models, kwargs["hours"])) or _forecasts(),
+    )
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    denver = {"resolved": "Denver, CO", "lat": 39.7, "lon": -105.0, "tz": "America/Denver"}
+    monkeypatch.setattr(orchestrator.provider, "geocode", lambda place: denver)
+
+    report = orchestrator.handle_models("Denver", hours=12)
+
+    assert requested == [(("gfs_seamless", "ecmwf_ifs025", "icon_seamless"), 12)]
+    assert report.summary == (
+        "The models diverge in 2 six-hour periods over the next 12 hours; the largest: "
+        "Fri 6 PM–12 AM MDT: ECMWF 7°F warmer than GFS."
+    )
+    console = Console(file=io.StringIO(), width=120)
+    render.render_models(report, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "GFS" in output and "ECMWF" in output and "ICON" in output and "      ▲▲▲▲▲▲" in output
+    assert "50–57" in output and "ICON wet (0.12 in), GFS dry." in output and "Data: Open-Meteo" in output
+
+    console = Console(file=io.StringIO(), width=120)
+    render.render_models(report, console=console, units="imperial", json_mode=True)
+    payload = json.loads(console.file.getvalue())["data"]
+    assert [run["label"] for run in payload["runs"]] == ["GFS", "ECMWF", "ICON"] and len(payload["divergences"]) == 2
+
+    offline = orchestrator_module.Orchestrator(config.Settings(offline=True, privacy_mode=True))
+    monkeypatch.setattr(offline.provider, "geocode", lambda place: denver)
+    assert offline.handle_models("Denver").error == "Model forecasts need the network; wx is offline."
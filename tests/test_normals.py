@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, date, datetime, timedelta
+from pathlib import Path
+
+import pytest
+from rich.console import Console
+
+from wx import config, fetchers, orchestrator as orchestrator_module, render
+from wx.normals import NormalsCache, interpolate_daily_normal, normal_daily_mean, normals_pack
+
+MONTHLY = [float(month) for month in range(1, 13)]
+
+
+def test_interpolation_hits_mid_month_values() -> None:
+    assert interpolate_daily_normal(MONTHLY, date(2025, 1, 16)) == pytest.approx(1.0)
+    assert interpolate_daily_normal(MONTHLY, date(2025, 7, 16)) == pytest.approx(7.0)
+
+
+def test_interpolation_wraps_across_year_end() -> None:
+    value = interpolate_daily_normal(MONTHLY, date(2025, 1, 1))
+    # Between mid-December (12) and mid-January (1)
+    assert 1.0 < value < 12.0
+    assert interpolate_daily_normal(MONTHLY, date(2025, 12, 31)) == pytest.approx(value, rel=0.1)
+
+
+def test_normal_daily_mean_averages_max_and_min() -> None:
+    normals = {"tmax": [20.0] * 12, "tmin": [10.0] * 12}
+    assert normal_daily_mean(normals, date(2025, 5, 5)) == pytest.approx(15.0)
+
+
+def test_cache_round_trip(tmp_path: Path) -> None:
+    path = tmp_path / "normals.json"
+    cache = NormalsCache(path)
+    cache.put(64.13, -21.94, {"tmax": MONTHLY, "tmin": MONTHLY})
+    cache.save()
+
+    reloaded = NormalsCache(path)
+    assert reloaded.get(64.1, -21.9) == {"tmax": MONTHLY, "tmin": MONTHLY}
+    assert reloaded.get(10.0, 10.0) is None
+
+
+def test_fetch_monthly_normals_parses_power_payload(monkeypatch) -> None:
+    months = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"]
+    payload = {
+        "properties": {
+            "parameter": {
+                "T2M_MAX": {month: 10.0 for month in months} | {"ANN": 10.0},
+                "T2M_MIN": {month: 2.0 for month in months} | {"ANN": 2.0},
+            }
+        }
+    }
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+
+    normals = fetchers.fetch_monthly_normals(47.6, -122.3)
+
+    assert normals == {"tmax": [10.0] * 12, "tmin": [2.0] * 12}
+
+
+def test_fetch_monthly_normals_rejects_fill_values(monkeypatch) -> None:
+    payload = {"properties": {"parameter": {"T2M_MAX": {"JAN": -999.0}, "T2M_MIN": {}}}}
+    monkeypatch.setattr(fetchers, "_safe_request", lambda *args, **kwargs: payload)
+
+    assert fetchers.fetch_monthly_normals(0.0, 0.0) is None
+    assert fetchers.fetch_monthly_normals(0.0, 0.0, offline=True) is None
+
+
+def test_normals_pack_compares_forecast_day() -> None:
+    hours = [datetime(2025, 7, 16, 7, tzinfo=UTC) + timedelta(hours=idx) for idx in range(30)]
+    hourly = {
+        "time": [moment.strftime("%Y-%m-%dT%H:%M") for moment in hours],
+        "temperature_2m": [18.0 + idx * 0.5 for idx in range(24)] + [40.0] * 6,
+    }
+    normals = {"tmax": [20.0] * 12, "tmin": [18.2] * 12}
+
+    pack = normals_pack(normals, hourly, date(2025, 7, 16), "America/Los_Angeles")
+
+    # The local day ends at 07:00 UTC, so the 40 °C hours belong to the 17th.
+    assert (pack["high_c"], pack["low_c"]) == (29.5, 18.0)
+    assert (pack["high_departure_c"], pack["low_departure_c"]) == (9.5, -0.2)
+    assert pack["notable"] is True
+    assert pack["label"] == "High 17°F above normal (68°F), low near normal (65°F) for Jul 16"
+    assert normals_pack(normals, hourly, date(2025, 7, 16), "UTC", "metric")["label"].startswith("High 6°C above")
+    assert normals_pack(normals, {"time": [], "temperature_2m": []}, date(2025, 7, 16)) is None
+
+
+def test_forecast_reports_departure_from_normal(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    start = datetime.now(UTC).replace(minute=0, second=0, microsecond=0)
+    hourly = {
+        "time": [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(48)],
+        "temperature_2m": [30.0] * 48,
+    }
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Boise", "lat": 43.6, "lon": -116.2, "tz": "UTC"}
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: hourly)
+    monkeypatch.setattr("wx.normals.fetch_monthly_normals", lambda lat, lon: {"tmax": [22.0] * 12, "tmin": [9.0] * 12})
+    prompts: list[str] = []
+
+    def invoke(payload):
+        prompts.append(orchestrator.forecaster._build_prompt(payload))
+        return "{}", "test", None
+
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", invoke)
+
+    result = orchestrator.handle_forecast("Boise", when_text=None, horizon="12h", focus=None, verbose=False)
+
+    assert result.feature_pack["normals"]["high_departure_c"] == 8.0
+    assert "14°F above normal (72°F)" in result.feature_pack["normals"]["label"]
+    assert "The normals block shows the day running well above" in prompts[0]
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "Normal High 14°F above normal (72°F), low 38°F above normal (48°F)" in console.file.getvalue()
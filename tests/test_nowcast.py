@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.nowcast import nowcast_steps, summarize_nowcast
+
+NOW = datetime(2026, 10, 16, 14, 7, tzinfo=UTC)
+
+
+def _minutely(totals, snow=None) -> dict:
+    """15-minute totals stamped at the end of each interval, starting with the one ending at 14:00."""
+
+    start = datetime(2026, 10, 16, 14, 0)
+    return {
+        "time": [(start + timedelta(minutes=15 * idx)).isoformat(timespec="minutes") for idx in range(len(totals))],
+        "precipitation": totals,
+        "snowfall": snow or [0.0] * len(totals),
+    }
+
+
+def test_nowcast_steps_skip_past_intervals_and_band_intensity() -> None:
+    steps = nowcast_steps(_minutely([0.4, 0.0, 0.3, 1.2, 2.5, None]), minutes=60, now=NOW)
+
+    assert [step["start_iso"] for step in steps] == [
+        "2026-10-16T14:00:00+00:00",
+        "2026-10-16T14:15:00+00:00",
+        "2026-10-16T14:30:00+00:00",
+        "2026-10-16T14:45:00+00:00",
+    ]
+    assert [step["rate_mmh"] for step in steps] == [0.0, 1.2, 4.8, 10.0]
+    assert [step["intensity"] for step in steps] == [None, "light", "moderate", "heavy"]
+
+
+def test_summaries_say_when_rain_starts_and_stops() -> None:
+    def summary(totals, **kwargs) -> str:
+        return summarize_nowcast(nowcast_steps(_minutely(totals, **kwargs), now=NOW), now=NOW)
+
+    assert summary([0.0] * 9) == "No rain or snow expected in the next 2 hours."
+    assert summary([0, 0, 0, 0.2, 1.0, 0.5, 0, 0, 0]) == (
+        "Rain starting in 23 minutes, lasting about 45 minutes, moderate at its heaviest (4.0 mm/h)."
+    )
+    assert summary([0, 0.3, 0.3, 0, 0, 0, 0, 0, 0]) == "Rain stopping in 23 minutes, light at its heaviest (1.2 mm/h)."
+    assert summary([0] + [0.3] * 8, snow=[0] + [0.2] * 8) == (
+        "Snow continuing for at least the next 2 hours, light at its heaviest (1.2 mm/h)."
+    )
+    assert summary([0, 0, 0, 0, 0, 0, 0, 0.1, 0.9]) == (
+        "Rain starting in 1 hour 23 minutes and continuing past 2 hours, moderate at its heaviest (3.6 mm/h)."
+    )
+
+
+def test_handle_rain_renders_summary_and_bars(monkeypatch) -> None:
+    monkeypatch.setattr(
+        orchestrator_module, "get_minutely_precipitation", lambda lat, lon, **kwargs: _minutely([0, 0, 0, 0.5, 2.0])
+    )
+    clock = type("_Clock", (datetime,), {"now": staticmethod(lambda tz=None: NOW)})
+    monkeypatch.setattr(orchestrator_module, "datetime", clock)
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    tulsa = {"resolved": "Tulsa, OK", "lat": 36.2, "lon": -96.0, "tz": "UTC"}
+    monkeypatch.setattr(orchestrator.provider, "geocode", lambda place: tulsa)
+
+    report = orchestrator.handle_rain("Tulsa", minutes=60)
+    console = Console(file=io.StringIO(), width=100)
+    render.render_rain(report, console=console, units="imperial")
+    output = console.file.getvalue()
+
+    assert report.summary.startswith("Rain starting in 23 minutes")
+    assert "Tulsa, OK" in output and "█" in output and "0.31 in/h" in output and "heavy" in output
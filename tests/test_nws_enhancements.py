@@ -4,9 +4,11 @@ from __future__ import annotations
 
 from unittest.mock import MagicMock, patch
 
+import httpx
 import pytest
 
 from wx.fetchers import (
+    fetch_nws_hourly,
     get_comprehensive_nws_data,
     get_nws_forecast_grid,
     get_nws_hourly_forecast,
@@ -66,6 +68,66 @@ class TestNWSForecastGrid:
         assert len(result["periods"]) == 2
 
 
+class TestNWSGridRetry:
+    """Test the nearby-point retry for missing or expired NWS grids."""
+
+    @staticmethod
+    def _points(grid: str) -> MagicMock:
+        response = MagicMock()
+        response.json.return_value = {
+            "properties": {"forecastHourly": f"https://api.weather.gov/gridpoints/{grid}/forecast/hourly"}
+        }
+        return response
+
+    @staticmethod
+    def _failed(status: int) -> MagicMock:
+        response = MagicMock()
+        request = httpx.Request("GET", "https://api.weather.gov/gridpoints/BOU/52,73/forecast/hourly")
+        response.raise_for_status.side_effect = httpx.HTTPStatusError(
+            "Forecast Grid Expired", request=request, response=httpx.Response(status, request=request)
+        )
+        return response
+
+    @patch("wx.fetchers._create_client")
+    def test_expired_grid_retries_nearby_point(self, mock_client_factory):
+        """A 500 expired-grid error, then a non-JSON body, then a healthy neighbouring grid."""
+        mock_client = MagicMock()
+        mock_client_factory.return_value.__enter__.return_value = mock_client
+        not_json = MagicMock()
+        not_json.json.side_effect = ValueError("Expecting value: line 1 column 1 (char 0)")
+        forecast = MagicMock()
+        forecast.json.return_value = {"properties": {"periods": [{"number": 1}], "updateTime": "2026-10-16T12:00Z"}}
+        mock_client.get.side_effect = [
+            self._points("BOU/52,73"),
+            self._failed(500),
+            self._points("BOU/52,73"),  # +0.01° lat lands on the same cell and is skipped
+            self._points("BOU/53,73"),
+            not_json,
+            self._points("BOU/52,72"),
+            forecast,
+        ]
+
+        result = fetch_nws_hourly(40.0, -105.0)
+
+        assert result["periods"] == [{"number": 1}]
+        assert result["note"] == "The NWS grid for this point was unavailable; used 39.99,-105.00 instead."
+        urls = [call.args[0] for call in mock_client.get.call_args_list]
+        assert urls[2:4] == [
+            "https://api.weather.gov/points/40.0100,-105.0000",
+            "https://api.weather.gov/points/40.0000,-104.9900",
+        ]
+
+    @patch("wx.fetchers._create_client")
+    def test_points_outside_coverage_are_not_retried(self, mock_client_factory):
+        """A 404 from the points API itself means no NWS coverage, so no shifted retries."""
+        mock_client = MagicMock()
+        mock_client_factory.return_value.__enter__.return_value = mock_client
+        mock_client.get.return_value = self._failed(404)
+
+        assert fetch_nws_hourly(51.5, -0.12) is None
+        assert mock_client.get.call_count == 1
+
+
 class TestNWSObservationStations:
     """Test NWS observation stations fetching."""
 
@@ -1,9 +1,12 @@
 from __future__ import annotations
 
 import importlib
+import json
+from datetime import UTC, datetime, timedelta
 
 config = importlib.import_module("wx.config")
 orchestrator_module = importlib.import_module("wx.orchestrator")
+providers_module = importlib.import_module("wx.providers")
 
 
 def test_forecast_feature_pack_contains_window():
@@ -26,3 +29,462 @@ def test_forecast_feature_pack_contains_window():
     # Ensure response uses fallback without raising
     assert "summary" in result.response.sections
     assert result.response.bottom_line
+
+
+def test_area_alerts_grouped_by_zone(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    alerts = [
+        {"event": "Wind Advisory", "severity": "Moderate", "areas": ["King", "Pierce"]},
+        {"event": "Flood Warning", "severity": "Severe", "areas": ["Pierce"]},
+    ]
+    calls = {}
+
+    def fake_fetch(state, *, zone=None, offline=False):
+        calls["args"] = (state, zone)
+        return alerts
+
+    monkeypatch.setattr(orchestrator_module, "fetch_area_alerts", fake_fetch)
+    monkeypatch.setattr(
+        orchestrator_module,
+        "find_county_zone",
+        lambda state, county, offline=False: {"id": "WAC053", "name": "Pierce"},
+    )
+
+    result = orchestrator.handle_area_alerts(state="wa", county=None, ai=False, verbose=False)
+    assert calls["args"] == ("wa", None)
+    assert result.query == "WA"
+    assert result.response.sections["zones"] == [
+        "King: Wind Advisory (Moderate)",
+        "Pierce: Flood Warning (Severe); Wind Advisory (Moderate)",
+    ]
+
+    result = orchestrator.handle_area_alerts(state="WA", county="Pierce", ai=False, verbose=False)
+    assert calls["args"] == ("WA", "WAC053")
+    assert result.query == "Pierce, WA"
+
+    result = orchestrator.handle_area_alerts(state=None, county="wac033", ai=False, verbose=False)
+    assert calls["args"] == (None, "WAC033")
+
+
+def test_question_reuses_feature_pack_with_current_units():
+    settings = config.Settings(offline=True, privacy_mode=True, units="metric")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    cached = {"place": {"resolved": "Austin"}, "units": {"temp": "F"}}
+
+    result = orchestrator.handle_question("Rain tonight?", verbose=False, feature_pack=cached)
+
+    assert result.feature_pack["place"] == {"resolved": "Austin"}
+    assert result.feature_pack["units"]["temp"] == "C"
+
+
+def test_risk_uses_engine_without_ai(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, units="metric")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    hourly = {"time": ["2026-07-01T00:00"], "wind_gusts_10m": [100.0], "apparent_temperature": [20.0]}
+
+    monkeypatch.setattr(
+        providers_module, "get_point_context", lambda place, offline=False: {"lat": 1.0, "lon": 2.0}
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+
+    result = orchestrator.handle_risk("Somewhere", hazards=["wind", "heat", "fire"], verbose=False)
+
+    assert result.response.provider == "risk-engine"
+    assert [item["hazard"] for item in result.feature_pack["risk_scores"]] == ["wind", "heat"]
+    assert result.debug["unscored_hazards"] == ["fire"]
+    assert result.response.bottom_line == "Bottom line: wind risk is extreme."
+
+
+def test_risk_shows_spc_outlook(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    hourly = {"time": ["2026-05-01T20:00"], "wind_gusts_10m": [40.0]}
+    outlook = {"day": 1, "category": "moderate", "label": "Moderate Risk", "expire_iso": None}
+    monkeypatch.setattr(
+        providers_module, "get_point_context", lambda place, offline=False: {"lat": 35.2, "lon": -97.4}
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+    monkeypatch.setattr(orchestrator_module, "fetch_spc_outlook", lambda lat, lon, **kwargs: outlook)
+
+    result = orchestrator.handle_risk("Norman", hazards=["wind"], verbose=False)
+
+    assert result.feature_pack["spc_outlook"] == outlook
+    assert result.response.sections["summary"][-1] == (
+        "SPC day 1 outlook: Moderate Risk (4 of 5) of severe thunderstorms."
+    )
+    from rich.console import Console
+
+    from wx.render import render_result
+
+    console = Console(record=True, width=120)
+    render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "Severe weather outlook" in console.export_text()
+
+
+def test_question_extracts_place_and_window(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    lookups = []
+
+    def fake_context(place, offline=False):
+        lookups.append(place)
+        return {"resolved": "Austin", "lat": 30.27, "lon": -97.74, "tz": "America/Chicago"}
+
+    monkeypatch.setattr(providers_module, "get_point_context", fake_context)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_question("will it rain tonight in Austin?", verbose=False)
+
+    assert lookups == ["Austin"]
+    assert result.feature_pack["place"]["resolved"] == "Austin"
+    assert result.feature_pack["window"]["label"] == "tonight"
+    assert result.feature_pack["window"]["timezone"] == "America/Chicago"
+
+
+def test_data_only_question_returns_answer_object(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    seen = {}
+    raw = json.dumps(
+        {
+            "answer": "yes",
+            "supporting_values": [{"field": "hourly.pop", "value": 80, "unit": "%"}, "junk"],
+            "time_window": {"start": "2026-05-01T00:00:00Z", "end": "2026-05-01T06:00:00Z"},
+            "confidence": {"value": 75, "rationale": "High PoP"},
+            "sources": ["alerts_quick", "made_up"],
+        }
+    )
+
+    def fake_invoke(payload):
+        seen["intent"] = payload["intent"]
+        return raw, "openrouter:test", None
+
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Austin", "lat": 30.27, "lon": -97.74, "tz": "America/Chicago"},
+    )
+    monkeypatch.setattr(
+        providers_module,
+        "get_quick_alerts",
+        lambda lat, lon, offline=False: [{"event": "Flood Warning", "severity": "Severe"}],
+    )
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", fake_invoke)
+
+    result = orchestrator.handle_question("will it rain tonight in Austin?", verbose=False, data_only=True)
+
+    assert seen["intent"] == "data"
+    assert result.data["answer"] == "yes"
+    assert result.data["supporting_values"] == [{"field": "hourly.pop", "value": 80, "unit": "%"}]
+    assert result.data["time_window"]["label"] == "tonight"
+    assert result.data["time_window"]["start"] == result.feature_pack["window"]["start_iso"]
+    assert result.data["sources"] == ["alerts_quick"]
+    assert result.data["alerts"] == ["Flood Warning"]
+    assert result.response.sections["answer"] == "yes"
+
+
+def test_lake_report_combines_sources(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    buoys = (
+        "#STN LAT LON YYYY MM DD hh mm WVHT WTMP\n"
+        "45007 42.674 -87.026 2026 10 16 14 50 1.1 15.3\n"
+    )
+    glf_codes = []
+
+    def fake_glf(code, offline=False):
+        glf_codes.append(code)
+        return ".TONIGHT...South wind 10 to 20 kt. Waves 2 to 4 ft.\n"
+
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Milwaukee", "lat": 43.04, "lon": -87.91},
+    )
+    monkeypatch.setattr(orchestrator_module, "fetch_ndbc_latest", lambda offline=False: buoys)
+    monkeypatch.setattr(orchestrator_module, "fetch_open_lakes_forecast", fake_glf)
+    monkeypatch.setattr(orchestrator_module, "fetch_great_lakes_ice", lambda season, offline=False: None)
+
+    report = orchestrator.handle_lake("Milwaukee")
+
+    assert glf_codes == ["LM"]
+    assert report.lake == "Lake Michigan"
+    assert report.buoy["station"] == "45007"
+    assert report.wave_periods == [{"period": "Tonight", "text": "South wind 10 to 20 kt. Waves 2 to 4 ft."}]
+    assert report.ice_cover_pct is None
+    assert report.sources == ["NDBC station 45007", "NWS open lakes forecast"]
+
+
+def test_forecast_timeline_labels_detected_fronts(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, units="imperial")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    now = orchestrator_module.datetime.now(orchestrator_module.UTC).replace(minute=0, second=0, microsecond=0)
+    hours = 13
+    hourly = {
+        "time": [(now + orchestrator_module.timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)],
+        "temperature_2m": [20, 20, 20, 20, 19, 18, 16, 13, 12, 11, 11, 11, 11],
+        "dew_point_2m": [15, 15, 15, 15, 14, 12, 10, 7, 6, 5, 5, 5, 5],
+        "wind_direction_10m": [200, 200, 200, 200, 210, 230, 270, 300, 310, 315, 315, 315, 315],
+        "wind_speed_10m": [20.0] * hours,
+    }
+
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Omaha", "lat": 41.26, "lon": -95.94, "tz": "America/Chicago"},
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast("Omaha", when_text=None, horizon="24h", focus=None, verbose=False)
+
+    assert [front["kind"] for front in result.feature_pack["fronts"]] == ["cold"]
+    first = result.response.sections["timeline"][0]
+    assert first.startswith("Cold front ~")
+    assert first.endswith("(detected from hourly data)")
+
+
+def test_forecast_window_honors_when_and_horizon(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, units="metric")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    now = orchestrator_module.datetime.now(orchestrator_module.UTC).replace(minute=0, second=0, microsecond=0)
+    hourly = {
+        "time": [(now + orchestrator_module.timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(96)],
+        "temperature_2m": [10.0] * 96,
+        "precipitation": [1.0] * 96,
+    }
+    requested = []
+
+    def fake_hourly(lat, lon, hours=48, offline=False):
+        requested.append(hours)
+        return hourly
+
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Denver", "lat": 39.74, "lon": -104.99, "tz": "America/Denver"},
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", fake_hourly)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast(
+        "Denver", when_text="tomorrow morning", horizon="24h", focus="commuting", verbose=False
+    )
+    window = result.feature_pack["window"]
+    assert window["label"] == "tomorrow morning"
+    assert window["start_local"].endswith("T06:00:00-06:00") or window["start_local"].endswith("T06:00:00-07:00")
+    assert window["end_local"][11:19] == "12:00:00"
+    assert len(result.feature_pack["periods"]) == 6
+    assert result.feature_pack["user_context"]["focus"] == "commuting"
+
+    result = orchestrator.handle_forecast("Denver", when_text=None, horizon="3d", focus=None, verbose=False)
+    assert requested[-1] >= 72
+    periods = result.feature_pack["periods"]
+    assert len(periods) == 12
+    assert periods[0]["hours"] == 6 and periods[0]["precip"] == 6.0
+    assert len(result.hourly) == 72
+
+    result = orchestrator.handle_forecast("Denver", when_text="tomorrow 3pm", horizon="6h", focus=None, verbose=False)
+    assert result.feature_pack["window"]["start_local"][11:19] == "15:00:00"
+
+
+def test_long_windows_respect_period_budget_and_keep_extremes():
+    start = datetime(2026, 10, 16, tzinfo=UTC)
+    hours = 240
+    hourly = {
+        "time": [(start + timedelta(hours=h)).strftime("%Y-%m-%dT%H:%M") for h in range(hours)],
+        "temperature_2m": [10.0] * hours,
+        "wind_gusts_10m": [20.0] * hours,
+        "precipitation": [0.0] * hours,
+        "precipitation_probability": [10] * hours,
+    }
+    # A one-hour spike that any sampled period would miss.
+    hourly["temperature_2m"][101] = 30.0
+    hourly["wind_gusts_10m"][57] = 90.0
+    hourly["precipitation"][150:153] = [2.0, 3.0, 1.0]
+    hourly["precipitation_probability"][150:153] = [60, 80, 55]
+    end = start + timedelta(hours=hours)
+
+    periods = orchestrator_module._forecast_periods(hourly, start, end, "metric", max_periods=24)
+    summary = orchestrator_module._window_summary(hourly, start, end, "metric")
+
+    assert len(periods) == 20
+    assert {period["hours"] for period in periods} == {12}
+    assert len(orchestrator_module._forecast_periods(hourly, start, start + timedelta(hours=12), "metric")) == 12
+    assert summary["temp_max"] == {"value": 30.0, "at": "2026-10-20T05:00:00+00:00"}
+    assert summary["gust_max"]["value"] == 25.0
+    assert summary["precip_total"] == 6.0
+    assert summary["precip_prob_max"] == 80
+    assert summary["hours_precip_likely"] == 3
+
+
+def test_forecast_hourly_rows_render_as_table(monkeypatch):
+    from rich.console import Console
+
+    from wx.render import condition_icon, render_result
+
+    settings = config.Settings(offline=False, privacy_mode=True, units="imperial")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    now = orchestrator_module.datetime.now(orchestrator_module.UTC).replace(minute=0, second=0, microsecond=0)
+    hourly = {
+        "time": [(now + orchestrator_module.timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(48)],
+        "temperature_2m": [10.0 + idx % 12 for idx in range(48)],
+        "wind_speed_10m": [16.0] * 48,
+        "wind_direction_10m": [270.0] * 48,
+        "wind_gusts_10m": [40.0] * 48,
+        "precipitation_probability": [80] * 48,
+        "weather_code": [63] * 48,
+    }
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Omaha", "lat": 41.26, "lon": -95.94, "tz": "America/Chicago"},
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast("Omaha", when_text=None, horizon="12h", focus=None, verbose=False)
+    console = Console(record=True, width=120)
+    render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    text = console.export_text()
+
+    assert len(result.hourly) == 12
+    assert result.hourly[0]["conditions"] == "Rain" and result.hourly[0]["wind"] == 10
+    assert "Next 12h" in text and "W 10 mph G25" in text and "🌧 Rain" in text
+    assert "80%" in text and "▁" in text
+    assert condition_icon("Chance Rain Showers") == "🌦"
+    assert condition_icon("Freezing Fog") == "🌫"
+
+
+def test_follow_up_questions_reuse_recent_places(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    lookups = []
+    places = {
+        "Tacoma": {"resolved": "Tacoma, Washington", "lat": 47.25, "lon": -122.44, "tz": "America/Los_Angeles"},
+        "Seattle": {"resolved": "Seattle, Washington", "lat": 47.61, "lon": -122.33, "tz": "America/Los_Angeles"},
+    }
+
+    def fake_context(place, offline=False):
+        lookups.append(place)
+        return places[place]
+
+    monkeypatch.setattr(providers_module, "get_point_context", fake_context)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    orchestrator.handle_question("will it rain in Tacoma tonight?", verbose=False)
+    orchestrator.handle_question("will it rain in Seattle tonight?", verbose=False)
+    saturday = orchestrator.handle_question("what about Saturday?", verbose=False)
+    tacoma = orchestrator.handle_question("and in Tacoma?", verbose=False)
+
+    assert lookups == ["Tacoma", "Seattle"]
+    assert saturday.feature_pack["place"]["resolved"] == "Seattle, Washington"
+    assert saturday.feature_pack["window"]["label"] == "saturday"
+    assert tacoma.debug["context_reused"] == "Tacoma, Washington"
+    assert "window" not in tacoma.feature_pack
+
+
+def test_quick_observation_uses_requested_units(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True, units="metric")
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=True)
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(orchestrator.provider, "current", lambda lat, lon: {"temp": 21.0, "wind": 18.0})
+    monkeypatch.setattr(orchestrator_module, "get_quick_profile", lambda lat, lon, offline=False: None)
+    monkeypatch.setattr(orchestrator_module, "get_air_quality", lambda lat, lon, offline=False: None)
+    feature_pack = orchestrator._base_feature_pack()
+
+    orchestrator._attach_point_data(feature_pack, {"lat": 40.0, "lon": -105.0}, {}, {"fetchers": []})
+
+    assert feature_pack["units"]["temp"] == "C"
+    assert feature_pack["obs_quick"] == {"temp": 21.0, "wind": 5.0}
+
+    orchestrator.settings.units = "imperial"
+    feature_pack = orchestrator._base_feature_pack()
+    orchestrator._attach_point_data(feature_pack, {"lat": 40.0, "lon": -105.0}, {}, {"fetchers": []})
+    assert feature_pack["units"]["temp"] == "F"
+    assert feature_pack["obs_quick"] == {"temp": 70, "wind": 11}
+
+
+def test_compare_prompts_reuses_fixture_feature_pack(monkeypatch):
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    fixture = {"place": {"resolved": "Denver, CO"}, "units": {"temp": "F"}}
+    seen = []
+
+    def no_fetch(place):
+        raise AssertionError("a fixture should not be refetched")
+
+    def fake_invoke(payload):
+        seen.append((payload["prompt_version"], payload["feature_pack"]))
+        return '{"sections": {"summary": ["Dry."]}, "bottom_line": "Dry."}', "test", None
+
+    monkeypatch.setattr(orchestrator.provider, "geocode", no_fetch)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", fake_invoke)
+
+    results = orchestrator.compare_prompts(
+        "Denver", ["v1", "v2"], when_text=None, horizon="24h", focus=None, verbose=False, fixture=fixture
+    )
+
+    assert [version for version, _ in seen] == ["v1", "v2"]
+    assert all(pack is fixture for _, pack in seen)
+    assert [result.response.meta["prompt_version"] for result in results] == ["v1", "v2"]
+
+
+def test_forecast_lists_missing_data_instead_of_failing(monkeypatch):
+    from rich.console import Console
+
+    from wx.render import render_result
+
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    place = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: None)
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: None)
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast("Tulsa", when_text=None, horizon="24h", focus=None, verbose=False)
+    console = Console(record=True, width=120)
+    render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+
+    assert result.feature_pack["warnings"] == [
+        orchestrator_module.MISSING_DATA_WARNINGS["quick_alerts"],
+        orchestrator_module.MISSING_DATA_WARNINGS["hourly_forecast"],
+    ]
+    assert result.feature_pack["place"]["resolved"] == "Tulsa" and result.response.bottom_line
+    output = console.export_text()
+    assert "Partial data" in output and "Active alerts could not be fetched" in output
+    payload = {"query": "Tulsa", "intent": "forecast", "verbose": False, "explain_mode": False}
+    assert "do not guess it" in orchestrator.forecaster._build_prompt({**payload, "feature_pack": result.feature_pack})
+
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: None)
+    risk = orchestrator.handle_risk("Nowhere", hazards=None, verbose=False)
+    assert risk.feature_pack["warnings"] == [orchestrator_module.MISSING_DATA_WARNINGS["point_context"]]
+    offline = orchestrator_module.Orchestrator(config.Settings(offline=True, privacy_mode=True), trust_tools=False)
+    assert "warnings" not in offline.handle_risk("Nowhere", hazards=None, verbose=False).feature_pack
+
+
+def test_unreachable_alert_feed_is_not_reported_as_quiet(monkeypatch):
+    from wx.favorites import Favorite
+
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    place = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: None)
+
+    result = orchestrator.handle_alerts("Tulsa", ai=False, stream=False, verbose=False)
+    report = orchestrator.handle_group_alerts("stores", [Favorite("Store 1", "Tulsa")])
+
+    assert result.response.sections["summary"][0].startswith("Alerts unavailable for Tulsa")
+    assert "No active alerts" not in result.response.raw_text
+    assert result.feature_pack["warnings"] == [orchestrator_module.MISSING_DATA_WARNINGS["quick_alerts"]]
+    assert report.sites[0].error and report.sites[0].error.startswith("Alerts unavailable")
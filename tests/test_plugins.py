@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import sys
+
+import pytest
+
+from wx import config, orchestrator as orchestrator_module, providers as providers_module
+from wx.plugins import DEFAULT_PLUGIN_TIMEOUT, Plugin, PluginError, parse_plugins, plugin_request, run_plugin
+
+TULSA = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+# Echoes the request back with a soil reading, like a sensor script would.
+ECHO = (
+    "import json, sys; request = json.load(sys.stdin); "
+    "print(json.dumps({'soil_moisture_pct': 31, 'place': request['place']['resolved'], 'command': request['command']}))"
+)
+
+
+def _python(code: str, name: str = "soil", timeout: float = DEFAULT_PLUGIN_TIMEOUT) -> Plugin:
+    return Plugin(name, (sys.executable, "-c", code), timeout)
+
+
+def test_plugins_parse_from_profile_entries() -> None:
+    plugins = parse_plugins(
+        {
+            "soil": "~/bin/soil --json",
+            "buoy": {"command": ["buoy-cli", "latest"], "timeout": 4},
+            "broken": {"command": 42},
+            "empty": "",
+        }
+    )
+
+    assert plugins == [
+        Plugin("soil", ("~/bin/soil", "--json"), DEFAULT_PLUGIN_TIMEOUT),
+        Plugin("buoy", ("buoy-cli", "latest"), 4.0),
+    ]
+    assert parse_plugins(["soil"]) == []
+
+
+def test_run_plugin_round_trips_json_and_reports_failures() -> None:
+    request = plugin_request("forecast", TULSA, units="imperial", window=None)
+
+    assert run_plugin(_python(ECHO), request) == {"soil_moisture_pct": 31, "place": "Tulsa", "command": "forecast"}
+    assert request["protocol"] == 1 and request["place"]["lat"] == 36.15
+    failures = {
+        "did not print JSON": _python("print('hello')"),
+        "did not print a JSON object": _python("print('[1, 2]')"),
+        "exited with status 3: sensor offline": _python("import sys; sys.stderr.write('sensor offline'); sys.exit(3)"),
+        "timed out after 0.2s": _python("import time; time.sleep(5)", timeout=0.2),
+        "could not start": Plugin("missing", ("/nonexistent/wx-plugin",)),
+    }
+    for message, plugin in failures.items():
+        with pytest.raises(PluginError, match=message):
+            run_plugin(plugin, request)
+
+
+def test_forecast_adds_plugin_blocks_and_warns_about_failures(monkeypatch) -> None:
+    plugins = [_python(ECHO), _python("import sys; sys.exit(1)", name="flaky")]
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False, plugins=plugins)
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place, offline=False: TULSA)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: {})
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast("Tulsa", when_text=None, horizon="24h", focus=None, verbose=False)
+    prompt = orchestrator.forecaster._build_prompt(
+        {"query": "Tulsa", "intent": "forecast", "verbose": False, "explain_mode": False, "feature_pack": result.feature_pack}
+    )
+
+    assert result.feature_pack["plugins"] == {"soil": {"soil_moisture_pct": 31, "place": "Tulsa", "command": "forecast"}}
+    assert "Plugin 'flaky' exited with status 1, so its data is missing." in result.feature_pack["warnings"]
+    assert "plugin:soil" in result.timings
+    assert "data from the user's own sources" in prompt and '"soil_moisture_pct": 31' in prompt
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.forecaster import ForecasterResponse
+from wx.pollen import apply_pollen, pollen_level, pollen_pack, wants_pollen
+
+NOW = datetime(2026, 4, 20, 6, tzinfo=UTC)
+
+
+def _hourly(birch: list[float | None], start: datetime = NOW) -> dict[str, list]:
+    hours = len(birch)
+    return {
+        "time": [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)],
+        "alder_pollen": [2.0] * hours,
+        "birch_pollen": birch,
+        "olive_pollen": [None] * hours,
+        "grass_pollen": [6.0] * hours,
+        "mugwort_pollen": [0.0] * hours,
+        "ragweed_pollen": [0.0] * hours,
+    }
+
+
+def _response(actions: list[str]) -> ForecasterResponse:
+    return ForecasterResponse(
+        sections={"summary": "Sunny and mild.", "actions": actions},
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Bottom line: a fine spring day.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+
+
+def test_levels_and_triggers() -> None:
+    assert [pollen_level("tree", value) for value in (10, 50, 400, 2000)] == ["Low", "Moderate", "High", "Very High"]
+    assert pollen_level("grass", 20) == "High" and pollen_level("weed", None) is None
+    assert wants_pollen("allergies") and wants_pollen("Will my hay fever be bad today?")
+    assert not wants_pollen("air") and not wants_pollen("wind")
+
+
+def test_pack_groups_types_and_finds_peak() -> None:
+    pack = pollen_pack(_hourly([40.0, 120.0, 300.0, 90.0]), now=NOW + timedelta(minutes=15))
+
+    assert pack["types"]["tree"] == {
+        "peak": 300.0,
+        "level": "High",
+        "source": "birch",
+        "peak_iso": (NOW + timedelta(hours=2)).isoformat(),
+    }
+    assert pack["types"]["grass"]["level"] == "Moderate"
+    assert pack["types"]["weed"] == {"peak": 0.0, "level": "Low", "source": None, "peak_iso": NOW.isoformat()}
+    assert pack["worst"] == "tree" and pack["high"] == ["tree"]
+    empty = {key: [None] * 4 for key in _hourly([None] * 4) if key != "time"}
+    assert pollen_pack({"time": _hourly([None] * 4)["time"], **empty}, now=NOW) is None
+
+
+def test_allergy_decision_added_when_story_misses_it() -> None:
+    pack = pollen_pack(_hourly([40.0, 120.0, 300.0, 90.0]), now=NOW)
+    response = _response(["Wear sunscreen."])
+
+    added = apply_pollen(response, pack, "Europe/Berlin")
+
+    assert added[0] == (
+        "Take allergy medication before heading out and keep windows closed: tree (birch) pollen is high,"
+        " peaking around Mon 10 AM CEST."
+    )
+    assert response.sections["actions"] == ["Wear sunscreen.", *added]
+    assert response.sections["pollen"] == "Tree (birch) high, grass moderate, weed low."
+
+    covered = _response(["Birch pollen peaks mid-morning; take antihistamines early."])
+    assert apply_pollen(covered, pack) == []
+    assert covered.meta["pollen"] == {"worst": "tree", "added": []}
+
+
+def test_allergies_focus_fetches_pollen_into_story(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    start = datetime.now(UTC).replace(minute=0, second=0, microsecond=0)
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Berlin", "lat": 52.5, "lon": 13.4}
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(orchestrator.provider, "forecast", lambda lat, lon, hours=48: None)
+    monkeypatch.setattr(orchestrator_module, "get_pollen", lambda lat, lon, offline=False: _hourly([800.0], start))
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ('{"actions": []}', "test", None))
+
+    plain = orchestrator.handle_forecast("Berlin", when_text=None, horizon="12h", focus="wind", verbose=False)
+    assert "pollen" not in plain.feature_pack
+
+    result = orchestrator.handle_forecast("Berlin", when_text=None, horizon="12h", focus="allergies", verbose=False)
+    assert result.feature_pack["pollen"]["high"] == ["tree"]
+    assert result.response.sections["actions"][0].startswith("Take allergy medication")
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "Pollen: Tree (birch) high, grass moderate, weed low." in console.file.getvalue()
@@ -0,0 +1,163 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from wx import keystore
+from wx.cli import app
+from wx.config import load_settings
+from wx.profile import infer_region, load_profile, normalize_region
+
+
+def _write_profile(config_dir: Path, name: str, payload: dict[str, object]) -> None:
+    (config_dir / "profiles" / f"{name}.json").write_text(json.dumps(payload))
+
+
+def test_missing_profile_is_empty(config_dir: Path) -> None:
+    profile = load_profile()
+    assert profile.name == "default"
+    assert profile.world_cities == []
+
+
+def test_world_cities_accept_names_coordinates_and_mappings(config_dir: Path) -> None:
+    _write_profile(
+        config_dir,
+        "default",
+        {
+            "world_cities": [
+                "Tokyo",
+                "64.15,-21.94",
+                {"name": "Cabin", "lat": 47.1, "lon": -121.5, "group": "family"},
+                {"name": "Paris", "region": "Europe"},
+                {"region": "asia"},
+                42,
+            ]
+        },
+    )
+
+    cities = load_profile().world_cities
+
+    assert [city.name for city in cities] == ["Tokyo", "64.15,-21.94", "Cabin", "Paris"]
+    assert not cities[0].has_coordinates
+    assert (cities[1].lat, cities[1].lon) == (64.15, -21.94)
+    assert cities[2].region == "family"
+    assert cities[3].region == "eu"
+
+
+def test_profile_selected_via_env(config_dir: Path, monkeypatch) -> None:
+    _write_profile(config_dir, "work", {"world_cities": ["Denver"]})
+    monkeypatch.setenv("WX_PROFILE", "work")
+
+    profile = load_profile()
+
+    assert profile.name == "work"
+    assert profile.world_cities[0].name == "Denver"
+
+
+def test_profile_provider(config_dir: Path) -> None:
+    _write_profile(config_dir, "default", {"provider": " NWS "})
+
+    assert load_profile().provider == "nws"
+
+
+def test_region_helpers() -> None:
+    assert normalize_region("Europe") == "eu"
+    assert normalize_region("asia") == "asia"
+    assert infer_region(47.6, -122.3) == "us"
+    assert infer_region(64.1, -21.9) == "eu"
+    assert infer_region(35.7, 139.7) == "asia"
+    assert infer_region(-33.9, 151.2) == "other"
+
+
+class _MemoryKeyring:
+    def __init__(self) -> None:
+        self.store: dict[tuple[str, str], str] = {}
+
+    def set_password(self, service: str, account: str, value: str) -> None:
+        self.store[(service, account)] = value
+
+    def get_password(self, service: str, account: str) -> str | None:
+        return self.store.get((service, account))
+
+    def delete_password(self, service: str, account: str) -> None:
+        self.store.pop((service, account), None)
+
+
+def test_api_keys_resolve_from_keyring_and_plaintext(config_dir: Path, monkeypatch) -> None:
+    backend = _MemoryKeyring()
+    monkeypatch.setattr(keystore, "keyring", backend)
+    reference = keystore.store_secret("default", "gemini_key", "AIza-secret-value-123")
+    _write_profile(config_dir, "default", {"gemini_key": reference, "openai_key": "sk-plain-value-123"})
+
+    profile = load_profile()
+
+    assert reference == {"keyring": "default/gemini_key"}
+    assert profile.api_keys == {"gemini_api_key": "AIza-secret-value-123", "openai_api_key": "sk-plain-value-123"}
+    assert profile.secret_sources == {"gemini_key": "keyring", "openai_key": "plaintext"}
+
+
+def test_profile_set_stores_key_in_keyring(config_dir: Path, cli_runner, state_dir, monkeypatch) -> None:
+    backend = _MemoryKeyring()
+    monkeypatch.setattr(keystore, "keyring", backend)
+
+    result = cli_runner.invoke(app, ["", "profile", "set", "openrouter_key", "sk-or-secret-123456"])
+
+    assert result.exit_code == 0, result.output
+    saved = (config_dir / "profiles" / "default.json").read_text()
+    assert "sk-or-secret" not in saved
+    assert json.loads(saved) == {"openrouter_key": {"keyring": "default/openrouter_key"}}
+    assert backend.store[("wx-cli", "default/openrouter_key")] == "sk-or-secret-123456"
+
+    result = cli_runner.invoke(app, ["", "profile", "unset", "openrouter_key"])
+    assert result.exit_code == 0, result.output
+    assert backend.store == {}
+
+
+def test_profile_set_without_keyring_requires_plaintext(config_dir: Path, cli_runner, state_dir, monkeypatch) -> None:
+    monkeypatch.setattr(keystore, "keyring", None)
+
+    result = cli_runner.invoke(app, ["", "profile", "set", "gemini_key", "AIza-secret-value-123"])
+    assert result.exit_code == 1
+    assert not (config_dir / "profiles" / "default.json").exists()
+
+    result = cli_runner.invoke(app, ["", "profile", "set", "gemini_key", "AIza-secret-value-123", "--plaintext"])
+    assert result.exit_code == 0, result.output
+    assert load_profile().api_keys == {"gemini_api_key": "AIza-secret-value-123"}
+
+
+def test_settings_merge_flags_over_env_over_profile(config_dir: Path, state_dir, monkeypatch) -> None:
+    for name in ("GEMINI_API_KEY", "GOOGLE_API_KEY", "UNITS", "WX_LANG", "WX_PROVIDER"):
+        monkeypatch.delenv(name, raising=False)
+    _write_profile(
+        config_dir,
+        "default",
+        {"gemini_key": "AIza-profile-value-123", "units": "metric", "provider": "nws", "language": "es"},
+    )
+    monkeypatch.setenv("WX_PROVIDER", "open-meteo")
+
+    settings = load_settings(language="fr", profile=load_profile())
+
+    assert settings.gemini_api_key == "AIza-profile-value-123"
+    assert settings.units == "metric"
+    assert settings.provider == "open-meteo"
+    assert settings.language == "fr"
+
+    monkeypatch.setenv("GEMINI_API_KEY", "AIza-env-value-456")
+    assert load_settings(profile=load_profile()).gemini_api_key == "AIza-env-value-456"
+
+
+def test_env_model_wins_over_profile_model(config_dir: Path, monkeypatch) -> None:
+    for name in ("AI_MODEL", "ANTHROPIC_MODEL", "OPENAI_MODEL", "OPENROUTER_MODEL", "OPENROUTER_MODELS"):
+        monkeypatch.delenv(name, raising=False)
+    _write_profile(config_dir, "default", {"model": "anthropic:claude-profile"})
+
+    assert load_settings(profile=load_profile()).anthropic_model == "claude-profile"
+    monkeypatch.setenv("OPENAI_MODEL", "gpt-env")
+    assert load_settings(profile=load_profile()).preferred_backend == "anthropic"
+    monkeypatch.setenv("ANTHROPIC_MODEL", "claude-env")
+    assert load_settings(profile=load_profile()).anthropic_model == "claude-env"
+    monkeypatch.delenv("ANTHROPIC_MODEL")
+    monkeypatch.setenv("AI_MODEL", "env/model")
+    settings = load_settings(profile=load_profile())
+    assert settings.ai_model == "env/model" and settings.anthropic_model != "claude-profile"
+    assert load_settings(model="anthropic:claude-flag", profile=load_profile()).anthropic_model == "claude-flag"
@@ -0,0 +1,96 @@
+"""Tests for the pluggable weather providers."""
+
+from __future__ import annotations
+
+import importlib
+
+import pytest
+
+from wx.providers import NWS_FALLBACK_NOTE, NWSProvider, OpenMeteoProvider, get_provider, nws_periods_to_hourly
+
+providers_module = importlib.import_module("wx.providers")
+
+
+def test_get_provider_by_name() -> None:
+    assert isinstance(get_provider(None), OpenMeteoProvider)
+    assert isinstance(get_provider(" NWS ", offline=True), NWSProvider)
+    assert get_provider("nws", offline=True).offline is True
+    with pytest.raises(ValueError, match="Unknown provider 'metoffice'"):
+        get_provider("metoffice")
+
+
+def test_nws_periods_convert_to_metric_hourly_series() -> None:
+    periods = [
+        {
+            "startTime": "2026-10-16T14:00:00-05:00",
+            "temperature": 50,
+            "temperatureUnit": "F",
+            "dewpoint": {"unitCode": "wmoUnit:degC", "value": 4.4},
+            "windSpeed": "10 to 15 mph",
+            "windDirection": "NW",
+            "probabilityOfPrecipitation": {"unitCode": "wmoUnit:percent", "value": 30},
+        },
+        {"startTime": "not a time", "temperature": 49},
+    ]
+
+    hourly = nws_periods_to_hourly(periods)
+
+    assert hourly["time"] == ["2026-10-16T19:00"]
+    assert hourly["temperature_2m"] == [10.0]
+    assert hourly["dew_point_2m"] == [4.4]
+    assert hourly["wind_speed_10m"] == [24.1]
+    assert hourly["wind_direction_10m"] == [315.0]
+    assert hourly["precipitation_probability"] == [30.0]
+
+
+def test_nws_current_uses_nearest_station(monkeypatch) -> None:
+    monkeypatch.setattr(
+        providers_module,
+        "get_nws_observation_stations",
+        lambda lat, lon, offline=False: [{"station_id": "KBOS"}, {"station_id": "KOWD"}],
+    )
+    monkeypatch.setattr(
+        providers_module,
+        "get_nws_latest_observation",
+        lambda station_id, offline=False: {
+            "station_id": station_id,
+            "temp_c": 2.0,
+            "wind_chill_c": -3.0,
+            "heat_index_c": None,
+            "wind_speed_mps": 5.0,
+            "wind_gust_mps": None,
+            "visibility_m": 16000.0,
+            "cloud_layers": [
+                {"amount": "FEW", "base": {"value": 600}},
+                {"amount": "BKN", "base": {"value": 1500}},
+            ],
+            "text_description": "Mostly Cloudy",
+        },
+    )
+
+    obs = NWSProvider().current(42.36, -71.06)
+
+    assert obs is not None
+    assert obs["station"] == "KBOS"
+    assert obs["feels_like"] == -3.0
+    assert obs["wind"] == 18.0
+    assert obs["gust"] is None
+    assert obs["vis_km"] == 16.0
+    assert obs["ceiling_m"] == 1500.0
+    assert obs["conditions"] == "Mostly Cloudy"
+
+
+def test_nws_forecast_falls_back_to_open_meteo(monkeypatch) -> None:
+    fallback = {"time": ["2026-10-16T00:00"], "temperature_2m": [18.0]}
+    monkeypatch.setattr(providers_module, "fetch_nws_hourly", lambda lat, lon, offline=False: None)
+    monkeypatch.setattr(
+        providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: fallback
+    )
+
+    assert NWSProvider().forecast(51.5, -0.12, hours=12) is fallback
+    assert fallback["note"] == NWS_FALLBACK_NOTE
+
+    london = {"temp": 12.0, "conditions": "Light Rain"}
+    monkeypatch.setattr(providers_module, "get_nws_observation_stations", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_quick_obs", lambda lat, lon, offline=False: london)
+    assert NWSProvider().current(51.5, -0.12) is london
@@ -0,0 +1,48 @@
+"""Tests for freeform question parsing."""
+
+from __future__ import annotations
+
+from datetime import datetime
+
+import pytest
+
+from wx.question import parse_question, time_window
+
+FRIDAY_AFTERNOON = datetime(2026, 10, 16, 14, 30)
+
+
+@pytest.mark.parametrize(
+    ("text", "location", "phrase"),
+    [
+        ("will it rain tonight in Austin?", "Austin", "tonight"),
+        ("weather for New York City tomorrow morning", "New York City", "tomorrow morning"),
+        ("Is it going to snow in denver this weekend", "denver", "this weekend"),
+        ("Will I need a coat at 5pm in Boston?", "Boston", None),
+        ("in Paris for a walk", "Paris", None),
+        ("rain at 35.2, -97.4 on Friday evening", "35.2,-97.4", "on friday evening"),
+        ("Tell me about conditions nationwide", None, None),
+    ],
+)
+def test_parse_question(text, location, phrase) -> None:
+    parsed = parse_question(text)
+    assert parsed.location == location
+    assert parsed.time_phrase == phrase
+
+
+@pytest.mark.parametrize(
+    ("phrase", "start", "end"),
+    [
+        ("tonight", datetime(2026, 10, 16, 18), datetime(2026, 10, 17, 6)),
+        ("tomorrow morning", datetime(2026, 10, 17, 6), datetime(2026, 10, 17, 12)),
+        ("today", FRIDAY_AFTERNOON, datetime(2026, 10, 17)),
+        ("this weekend", datetime(2026, 10, 17), datetime(2026, 10, 19)),
+        ("next friday", datetime(2026, 10, 23), datetime(2026, 10, 24)),
+        ("this morning", datetime(2026, 10, 16, 6), datetime(2026, 10, 16, 12)),
+    ],
+)
+def test_time_window(phrase, start, end) -> None:
+    assert time_window(phrase, FRIDAY_AFTERNOON) == (start, end)
+
+
+def test_time_window_unknown_phrase() -> None:
+    assert time_window("someday", FRIDAY_AFTERNOON) is None
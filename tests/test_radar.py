@@ -0,0 +1,126 @@
+from __future__ import annotations
+
+import io
+import struct
+import zlib
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.radar import (
+    TILE_SIZE,
+    Raster,
+    decode_png,
+    detect_graphics,
+    kitty_image,
+    mosaic,
+    sixel_image,
+    tile_position,
+    view_tiles,
+)
+
+RED = (200, 30, 30, 255)
+CLEAR = (0, 0, 0, 0)
+
+
+def _chunk(kind: bytes, data: bytes) -> bytes:
+    return struct.pack(">I", len(data)) + kind + data + struct.pack(">I", zlib.crc32(kind + data))
+
+
+def _png(header: tuple, rows: list[bytes], *, filters=(0,), bpp: int = 4, extra: bytes = b"") -> bytes:
+    """Encode rows, cycling through PNG filter types so the decoder sees each one."""
+
+    def paeth(a: int, b: int, c: int) -> int:
+        p = a + b - c
+        return min((abs(p - a), 0, a), (abs(p - b), 1, b), (abs(p - c), 2, c))[2]
+
+    raw, previous = bytearray(), bytes(len(rows[0]))
+    for idx, row in enumerate(rows):
+        kind = filters[idx % len(filters)]
+        raw.append(kind)
+        for i, value in enumerate(row):
+            left = row[i - bpp] if i >= bpp else 0
+            upper_left = previous[i - bpp] if i >= bpp else 0
+            predictor = (0, left, previous[i], (left + previous[i]) >> 1, paeth(left, previous[i], upper_left))[kind]
+            raw.append((value - predictor) & 255)
+        previous = row
+    ihdr = _chunk(b"IHDR", struct.pack(">IIBBBBB", *header))
+    return b"\x89PNG\r\n\x1a\n" + ihdr + extra + _chunk(b"IDAT", zlib.compress(bytes(raw))) + _chunk(b"IEND", b"")
+
+
+def _tile(color=RED) -> bytes:
+    """A 256 px RGBA tile, clear except a block of ``color`` in its top-left quarter."""
+
+    rows = [
+        bytes(channel for x in range(256) for channel in (color if x < 128 and y < 128 else CLEAR))
+        for y in range(256)
+    ]
+    return _png((256, 256, 8, 6, 0, 0, 0), rows)
+
+
+def test_decode_png_handles_filters_and_palettes() -> None:
+    pixels = [[(x * 40, y * 60, 90, 255 if (x + y) % 2 else 0) for x in range(5)] for y in range(4)]
+    rows = [bytes(channel for pixel in row for channel in pixel) for row in pixels]
+    image = decode_png(_png((5, 4, 8, 6, 0, 0, 0), rows, filters=(1, 2, 3, 4)))
+    assert [[image.pixel(x, y) for x in range(5)] for y in range(4)] == pixels
+
+    # 4-bit palette with a transparent first entry, as small radar tiles are often stored.
+    palette = _chunk(b"PLTE", bytes((0, 0, 0, 10, 200, 60, 250, 40, 40))) + _chunk(b"tRNS", bytes((0,)))
+    indexed = decode_png(_png((3, 1, 4, 3, 0, 0, 0), [bytes((0x01, 0x20))], bpp=1, extra=palette))
+    assert [indexed.pixel(x, 0) for x in range(3)] == [(0, 0, 0, 0), (10, 200, 60, 255), (250, 40, 40, 255)]
+
+
+def test_view_is_centred_on_the_place_across_tile_edges() -> None:
+    x, y = tile_position(47.6, -122.3)
+    left, top, tiles = view_tiles(47.6, -122.3)
+    assert (left + 128, top + 128) == (round(x), round(y))
+    assert len(tiles) == 4 and tiles[0] == (left // TILE_SIZE, top // TILE_SIZE)
+
+    tile = Raster.blank(TILE_SIZE, TILE_SIZE)
+    tile.put(10, 20, RED)
+    view = mosaic({(1, 1): tile}, left=TILE_SIZE + 10 - 128, top=TILE_SIZE + 20 - 128)
+    assert view.pixel(128, 128) == RED and view.pixel(127, 128) == CLEAR
+
+
+def test_handle_radar_builds_frames_from_tiles(monkeypatch) -> None:
+    maps = {
+        "host": "https://tiles.example",
+        "radar": {"past": [{"time": 1792150800 + 600 * idx, "path": f"/v2/radar/{idx}"} for idx in range(4)]},
+    }
+    fetched = []
+    monkeypatch.setattr(orchestrator_module, "fetch_radar_maps", lambda **kwargs: maps)
+    monkeypatch.setattr(orchestrator_module, "fetch_radar_tile", lambda url, **kwargs: fetched.append(url) or _tile())
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Seattle, WA", "lat": 47.6, "lon": -122.3}
+    )
+
+    report = orchestrator.handle_radar("Seattle", frames=2)
+
+    assert [frame.time_iso for frame in report.frames] == ["2026-10-16T12:00:00+00:00", "2026-10-16T12:10:00+00:00"]
+    assert len(fetched) == 8 and all(url.startswith("https://tiles.example/v2/radar/") for url in fetched)
+    assert 0 < report.frames[0].coverage_pct < 100 and report.error is None
+
+    console = Console(file=io.StringIO(), width=80)
+    render.render_radar(report, console=console)
+    output = console.file.getvalue()
+    assert "Radar — Seattle, WA" in output and "▀" in output and "Data: RainViewer" in output
+
+    offline = orchestrator_module.Orchestrator(config.Settings(offline=True, privacy_mode=True))
+    monkeypatch.setattr(offline.provider, "geocode", lambda place: {"lat": 47.6, "lon": -122.3})
+    assert offline.handle_radar("Seattle").error == "Radar imagery needs the network; wx is offline."
+
+
+def test_graphics_protocols_and_detection() -> None:
+    image = Raster.blank(8, 7)
+    image.put(0, 0, RED)
+    image.put(7, 6, RED)
+
+    kitty = kitty_image(image)
+    assert kitty.startswith("\x1b_Ga=T,f=32,o=z,s=8,v=7,m=0;") and kitty.endswith("\x1b\\")
+    sixel = sixel_image(image)
+    assert sixel == '\x1bP0;1;0q"1;1;8;7#151;2;80;20;20#151@!7?$-#151!7?@$-\x1b\\'
+
+    assert detect_graphics({"TERM": "xterm-kitty"}) == "kitty"
+    assert detect_graphics({"TERM": "foot"}) == "sixel"
+    assert detect_graphics({"TERM": "xterm-256color"}) == "blocks"
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import time
+from pathlib import Path
+
+from wx.retrieval import ContextStore, cosine, embed
+
+
+def _pack(name: str, lat: float, lon: float, alerts: list[str] | None = None) -> dict[str, object]:
+    return {
+        "place": {"resolved": name, "lat": lat, "lon": lon},
+        "alerts_quick": [{"event": event} for event in alerts or []],
+        "window": {"label": "tonight"},
+    }
+
+
+def test_embeddings_tolerate_spelling_and_ignore_filler() -> None:
+    assert cosine(embed("Tacoma"), embed("Tacoma, Washington")) > 0.5
+    assert cosine(embed("tacomma"), embed("Tacoma")) > 0.4
+    assert cosine(embed("what about it?"), embed("Seattle")) == 0.0
+
+
+def test_store_matches_places_and_follow_up_topics() -> None:
+    store = ContextStore()
+    store.add(_pack("Tacoma, Washington", 47.25, -122.44, ["Wind Advisory"]), "windy in Tacoma?")
+    store.add(_pack("Denver, Colorado", 39.74, -104.99), "snow in Denver?")
+
+    assert store.match_place("tacoma").label == "Tacoma, Washington"
+    assert store.match_place("Denver, CO").label == "Denver, Colorado"
+    assert store.match_place("Boston") is None
+    assert store.search("how long does the wind advisory last?").label == "Tacoma, Washington"
+    # No cue in the question: the latest place wins.
+    assert store.search("what about Saturday?").label == "Denver, Colorado"
+    assert "window" not in store.search("and Saturday?").feature_pack
+
+
+def test_store_expires_and_persists(tmp_path: Path) -> None:
+    path = tmp_path / "context_store.json"
+    ContextStore(path).add(_pack("Austin, Texas", 30.27, -97.74), "rain?")
+
+    assert ContextStore(path).match_place("Austin").label == "Austin, Texas"
+    assert path.stat().st_mode & 0o777 == 0o600
+    assert ContextStore(path, ttl=-1).search("rain?") is None
+
+    store = ContextStore(path)
+    store._load()[0].stored = time.time() - 3600
+    assert store.search("rain?") is None
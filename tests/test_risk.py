@@ -0,0 +1,218 @@
+"""Tests for the deterministic risk engine."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+
+from wx import config, orchestrator as orchestrator_module, providers as providers_module
+from wx.cli import app
+from wx.forecaster import ForecasterResponse
+from wx.risk import (
+    HAZARDS,
+    HazardRule,
+    apply_spc_outlook,
+    assess_hazards,
+    level_for,
+    normalize_hazards,
+    parse_hazard_rules,
+    parse_thresholds,
+    register_hazard_rules,
+    spc_outlook_line,
+)
+
+
+def _hourly(**series):
+    hours = len(next(iter(series.values())))
+    return {"time": [f"2026-07-01T{hour:02d}:00" for hour in range(hours)], **series}
+
+
+def test_normalize_hazards_aliases_and_unknown() -> None:
+    known, unknown = normalize_hazards(["Gusts", "flood", "fire", "wind"])
+    assert known == ["wind", "flooding"]
+    assert unknown == ["fire"]
+    assert normalize_hazards(None)[0][0] == "wind"
+
+
+def test_level_boundaries() -> None:
+    assert level_for(0) == "Low"
+    assert level_for(25) == "Moderate"
+    assert level_for(74.9) == "High"
+    assert level_for(100) == "Extreme"
+
+
+def test_wind_and_heat_scores_sorted() -> None:
+    hourly = _hourly(
+        wind_gusts_10m=[20.0, 95.0, 40.0],
+        apparent_temperature=[30.0, 33.0, 31.0],
+    )
+    scores = assess_hazards(hourly, hazards=["heat", "wind"], units="metric")
+    assert [score.hazard for score in scores] == ["wind", "heat"]
+    assert scores[0].level == "Extreme"
+    assert scores[0].peak_iso == "2026-07-01T01:00"
+    assert scores[0].drivers == ["Gusts to 26 m/s"]
+
+
+def test_lightning_needs_a_trigger() -> None:
+    dry = _hourly(cape=[2500.0], precipitation_probability=[0.0])
+    wet = _hourly(cape=[2500.0], precipitation_probability=[60.0])
+    assert assess_hazards(dry, hazards=["lightning"])[0].score == 0
+    assert assess_hazards(wet, hazards=["lightning"])[0].level == "Extreme"
+
+
+def test_alert_sets_score_floor() -> None:
+    hourly = _hourly(precipitation=[1.0, 2.0])
+    alerts = [{"event": "Flash Flood Warning"}, {"event": "Wind Chill Advisory"}]
+    scores = {score.hazard: score for score in assess_hazards(hourly, alerts)}
+    assert scores["flooding"].score == 75
+    assert "Flash Flood Warning" in scores["flooding"].drivers
+    assert scores["wind"].score == 0
+    assert scores["cold"].level == "Moderate"
+
+
+def test_severe_uses_spc_probabilities() -> None:
+    spc = {
+        "tornado": {"probability": 10, "significant": True},
+        "wind": {"probability": 15, "significant": False},
+        "hail": {"probability": 0, "significant": False},
+    }
+    assert normalize_hazards(["tornado"])[0] == ["severe"]
+    (score,) = assess_hazards(None, hazards=["severe"], spc=spc)
+    assert score.hazard == "severe"
+    # Enhanced tornado probability plus the significant-severe hatch.
+    assert score.score == 80
+    assert score.drivers == [
+        "Tornado 10% within 25 mi (enhanced), significant-severe area",
+        "Damaging wind 15% within 25 mi (slight)",
+    ]
+
+
+def test_severe_not_in_default_set() -> None:
+    assert "severe" not in [score.hazard for score in assess_hazards({})]
+
+
+def test_uv_is_opt_in_and_scored_from_uv_index() -> None:
+    hourly = _hourly(uv_index=[0.5, 4.0, 8.6, 6.0])
+    assert "uv" not in [score.hazard for score in assess_hazards(hourly)]
+    assert normalize_hazards(["sunburn"])[0] == ["uv"]
+    (uv,) = assess_hazards(hourly, hazards=["uv"])
+    assert uv.level == "High" and uv.peak_iso == "2026-07-01T02:00"
+    assert uv.drivers == ["UV index 9 (Very High)", "3h at UV 3+ in the next 24h"]
+
+
+def test_spc_outlook_added_to_story_once() -> None:
+    outlook = {"day": 1, "category": "slight", "label": "Slight Risk", "expire_iso": None}
+    assert spc_outlook_line(outlook) == "SPC day 1 outlook: Slight Risk (2 of 5) of severe thunderstorms."
+    general = spc_outlook_line({**outlook, "category": "thunderstorms"})
+    assert general == "SPC day 1 outlook: general thunderstorms, no severe risk."
+
+    response = ForecasterResponse(
+        sections={"summary": ["Storms this evening."]},
+        confidence={},
+        used_feature_fields=[],
+        bottom_line="",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+    assert apply_spc_outlook(response, outlook) is True
+    assert response.sections["summary"][-1] == spc_outlook_line(outlook)
+    assert response.sections["spc_outlook"] == spc_outlook_line(outlook)
+
+    response.sections["summary"] = ["SPC has a slight risk of severe storms today."]
+    assert apply_spc_outlook(response, outlook) is False
+    assert apply_spc_outlook(response, {**outlook, "category": "thunderstorms"}) is False
+
+
+def test_unscoreable_hazard_filter_scores_nothing(monkeypatch) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    place = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(
+        providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: _hourly(temperature_2m=[41.0])
+    )
+
+    assert assess_hazards(_hourly(temperature_2m=[41.0]), hazards=[]) == []
+    result = orchestrator.handle_risk("Tulsa", hazards=["visibility"], verbose=False)
+
+    assert result.feature_pack["risk_scores"] == []
+    assert result.response.sections["summary"] == ["No scoreable hazards in visibility."]
+    assert normalize_hazards([" "]) == (list(HAZARDS), [])
+
+
+def test_hazard_rules_load_from_toml_and_join_the_default_set(tmp_path) -> None:
+    rules_file = tmp_path / "site.toml"
+    rules_file.write_text(
+        '[crane_wind]\nvariable = "wind_gusts_10m"\nlow = 30\nhigh = 55\nlabel = "Crane gusts"\n'
+        'unit = "km/h"\nalerts = ["high wind"]\n\n'
+        '[pour_cold]\nvariable = "temperature_2m"\nlow = 10\nhigh = 0\n\n'
+        '[wind]\nvariable = "wind_speed_10m"\nlow = 0\nhigh = 10\n\n'
+        '[flat]\nvariable = "cape"\nlow = 5\nhigh = 5\n'
+    )
+
+    rules = parse_hazard_rules(str(rules_file))
+    assert rules == [
+        HazardRule("crane_wind", "wind_gusts_10m", 30.0, 55.0, "Crane gusts", "km/h", ("high wind",)),
+        HazardRule("pour_cold", "temperature_2m", 10.0, 0.0),
+    ]
+    assert parse_hazard_rules(str(tmp_path / "missing.toml")) == []
+    assert register_hazard_rules(rules) == ["crane_wind", "pour_cold"]
+    assert normalize_hazards(None)[0] == [*HAZARDS, "crane_wind", "pour_cold"]
+
+    hourly = _hourly(wind_gusts_10m=[20.0, 48.0], temperature_2m=[12.0, 5.0])
+    scores = {score.hazard: score for score in assess_hazards(hourly, [{"event": "High Wind Warning"}], units="metric")}
+    assert scores["crane_wind"].score == 75 and scores["crane_wind"].peak_iso == "2026-07-01T01:00"
+    assert scores["crane_wind"].drivers == ["Crane gusts 48 km/h", "High Wind Warning"]
+    assert scores["pour_cold"].score == 50 and scores["pour_cold"].drivers == ["temperature_2m 5"]
+    assert register_hazard_rules([]) == [] and normalize_hazards(["crane_wind"]) == ([], ["crane_wind"])
+
+
+def test_profile_hazard_rules_are_scored_by_wx_risk(cli_runner, config_dir, monkeypatch) -> None:
+    rules = {"crane_wind": {"variable": "wind_gusts_10m", "low": 30, "high": 55, "label": "Crane gusts"}}
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"hazard_rules": rules}))
+    place = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(
+        providers_module,
+        "get_hourly_forecast",
+        lambda lat, lon, hours=48, offline=False: _hourly(wind_gusts_10m=[60.0]),
+    )
+
+    result = cli_runner.invoke(app, ["", "risk", "Tulsa", "--hazards", "crane_wind"])
+    shown = cli_runner.invoke(app, ["", "profile", "show"])
+
+    assert result.exit_code == 0, result.output
+    assert "crane_wind (Extreme, 100/100)" in result.output and "Crane gusts 60" in result.output
+    assert "hazard_rules.crane_wind" in shown.output
+
+
+def test_threshold_presets_tighten_the_scores(cli_runner, config_dir, monkeypatch) -> None:
+    hourly = _hourly(wind_gusts_10m=[50.0], apparent_temperature=[-5.0])
+    standard = {score.hazard: score.score for score in assess_hazards(hourly, hazards=["wind", "cold"], units="metric")}
+    site = {
+        score.hazard: score.score
+        for score in assess_hazards(hourly, hazards=["wind", "cold"], units="metric", thresholds="construction")
+    }
+
+    assert standard == {"wind": 25, "cold": 14}
+    assert site == {"wind": 62, "cold": 40}
+    assert parse_thresholds(None) == "standard" and parse_thresholds("Athletics") == "school-athletics"
+    with pytest.raises(ValueError, match="Unknown thresholds 'opera'. Choose from: standard, construction"):
+        parse_thresholds("opera")
+
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"thresholds": "film"}))
+    place = {"resolved": "Tulsa", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda place_name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: [])
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: hourly)
+
+    profile_preset = cli_runner.invoke(app, ["", "risk", "Tulsa", "--hazards", "wind"])
+    flag_preset = cli_runner.invoke(app, ["", "risk", "Tulsa", "--hazards", "wind", "--thresholds", "aviation"])
+    bad = cli_runner.invoke(app, ["", "risk", "Tulsa", "--thresholds", "opera"])
+
+    assert "film preset" in profile_preset.output and "wind (Extreme, 75/100)" in profile_preset.output
+    assert "aviation-ground" in flag_preset.output and "wind (Moderate, 40/100)" in flag_preset.output
+    assert bad.exit_code == 2 and "--thresholds" in bad.output
@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+import json
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import _normalize_invocation, app
+from wx.risk import assess_hazards
+from wx.roads import RoadSegment, commute_risks, parse_commute, parse_roads
+
+# A clear, calm night after evening drizzle: the air sits at its dew point just above freezing
+# through the morning commute (America/New_York, 11:00-13:00 UTC), then warms and dries out.
+TIMES = [f"2026-01-14T{hour:02d}:00" for hour in range(0, 24)]
+HOURLY = {
+    "time": TIMES,
+    "temperature_2m": [1.5] * 14 + [6.0] * 10,
+    "dew_point_2m": [1.2] * 14 + [-4.0] * 10,
+    "wind_speed_10m": [4.0] * 24,
+    "precipitation": [0.0] * 7 + [0.5] + [0.0] * 16,
+    "weather_code": [3] * 24,
+}
+
+
+class _Provider:
+    name = "stub"
+
+    def geocode(self, place):
+        if place == "Atlantis":
+            return None
+        return {"resolved": place, "lat": 42.1, "lon": -73.9, "tz": "America/New_York"}
+
+    def forecast(self, lat, lon, *, hours=48):
+        return HOURLY
+
+
+def test_roads_and_commute_parse_from_profile_entries() -> None:
+    assert parse_roads(
+        {"Route 9 bridge": {"place": "42.10,-73.91", "bridge": True}, "Mill Hill": "42.2,-73.8", "empty": {"bridge": True}}
+    ) == [RoadSegment("Route 9 bridge", "42.10,-73.91", bridge=True), RoadSegment("Mill Hill", "42.2,-73.8")]
+    assert parse_roads(["Mill Hill"]) == []
+    assert parse_commute("6:30-8:30, 15:00-17:30") == ("06:30-08:30", "15:00-17:30")
+    assert parse_commute(["07:00-09:00", "25:00-26:00", 7]) == ("07:00-09:00",)
+    assert parse_commute("rush hour") is None
+
+
+def test_bridge_ices_before_the_road_in_the_morning_commute() -> None:
+    road = commute_risks(HOURLY, ("06:00-09:00", "15:00-18:00"), tz_name="America/New_York", units="metric")
+    bridge = commute_risks(
+        HOURLY, ("06:00-09:00",), tz_name="America/New_York", units="metric", bridge=True
+    )
+
+    assert [risk.start_iso for risk in road] == ["2026-01-14T06:00:00-05:00", "2026-01-14T15:00:00-05:00"]
+    morning, evening = road
+    assert morning.fog > 50 and "Dew point within 0.3°C" in morning.drivers[0]
+    assert morning.ice == 0 and evening.fog == 0 and evening.level == "Low"
+    assert bridge[0].ice > 0 and "bridge deck near 0°C" in bridge[0].drivers[-1]
+    assert assess_hazards(HOURLY, units="metric", hazards=["fog"])[0].score == morning.fog
+
+
+def test_roads_command_reports_each_segment(cli_runner, config_dir, monkeypatch) -> None:
+    (config_dir / "profiles" / "default.json").write_text(
+        json.dumps({"roads": {"Route 9 bridge": {"place": "Catskill", "bridge": True}, "Lost": "Atlantis"}})
+    )
+    original = orchestrator_module.Orchestrator.__init__
+
+    def init(self, *args, **kwargs):
+        original(self, *args, **kwargs)
+        self.provider = _Provider()
+
+    monkeypatch.setattr(orchestrator_module.Orchestrator, "__init__", init)
+
+    result = cli_runner.invoke(app, _normalize_invocation(["--json", "roads", "--commute", "06:00-09:00"]))
+    bad = cli_runner.invoke(app, _normalize_invocation(["roads", "--commute", "rush hour"]))
+
+    assert result.exit_code == 0, result.output
+    reports = json.loads(result.output)["data"]
+    assert [report["name"] for report in reports] == ["Route 9 bridge", "Lost"]
+    assert reports[0]["bridge"] and reports[0]["windows"][0]["ice"] > 0
+    assert reports[1]["error"] == "Could not find Atlantis"
+    assert bad.exit_code == 2 and "HH:MM-HH:MM" in bad.output
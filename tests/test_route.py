@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+import pytest
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.geo import haversine_km
+from wx.route import ROAD_FACTOR, great_circle_point, hour_conditions, route_hazards, sample_path
+
+DEPART = datetime(2026, 1, 10, 15, tzinfo=UTC)
+
+
+class _Provider:
+    name = "stub"
+
+    def __init__(self) -> None:
+        self.calls: list[tuple[float, float, int]] = []
+
+    def geocode(self, place):
+        return {
+            "seattle": {"resolved": "Seattle", "lat": 47.6, "lon": -122.3, "tz": "America/Los_Angeles"},
+            "portland": {"resolved": "Portland", "lat": 45.5, "lon": -122.7, "tz": "America/Los_Angeles"},
+        }.get(place.lower())
+
+    def forecast(self, lat, lon, *, hours=48):
+        self.calls.append((lat, lon, hours))
+        times = [(DEPART + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(6)]
+        # Colder and snowier heading south, so each stop's row shows which hour it got.
+        cold = lat < 46.5
+        return {
+            "time": times,
+            "temperature_2m": [-2.0 if cold else 5.0] * 3 + [9.0] * 3,
+            "precipitation": [1.0] * 6,
+            "precipitation_probability": [90] * 6,
+            "wind_gusts_10m": [30.0] * 6,
+            "weather_code": [71 if cold else 61] * 6,
+        }
+
+
+def test_great_circle_sampling_keeps_ends_and_spacing() -> None:
+    seattle, portland = (47.6, -122.3), (45.5, -122.7)
+    assert haversine_km(*great_circle_point(seattle, portland, 0.0), *seattle) < 0.01
+    assert haversine_km(*great_circle_point(seattle, portland, 1.0), *portland) < 0.01
+    samples = sample_path([seattle, portland], 5)
+    assert [fraction for _, _, fraction in samples] == [0.0, 0.25, 0.5, 0.75, 1.0]
+    total = haversine_km(*seattle, *portland)
+    assert haversine_km(*seattle, *samples[1][:2]) == pytest.approx(total / 4, rel=1e-3)
+
+
+def test_hour_conditions_and_hazards() -> None:
+    hourly = _Provider().forecast(45.0, -122.0)
+    conditions = hour_conditions(hourly, DEPART + timedelta(minutes=50))
+    assert conditions["temp"] == -2.0
+    assert conditions["conditions"] == "Light Snow"
+    assert hour_conditions(hourly, DEPART + timedelta(hours=12)) is None
+    assert route_hazards(conditions) == ["snow or ice"]
+    assert route_hazards({"temp": 12.0, "precipitation": 6.0, "gust": 75.0, "conditions": "Thunderstorm"}) == [
+        "heavy rain",
+        "thunderstorms",
+        "strong gusts",
+    ]
+    assert route_hazards(None) == []
+
+
+def test_route_times_each_stop_to_travel_time(monkeypatch) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    provider = _Provider()
+    orchestrator.provider = provider
+    monkeypatch.setattr(orchestrator_module, "get_osrm_route", lambda start, end, offline: None)
+
+    report = orchestrator.handle_route("Seattle", "Portland", depart=DEPART, speed_kmh=80, stops=3)
+
+    assert report.path == "great-circle"
+    assert report.distance_km == pytest.approx(haversine_km(47.6, -122.3, 45.5, -122.7) * ROAD_FACTOR, abs=0.1)
+    assert [stop.label for stop in report.stops] == ["Seattle", None, "Portland"]
+    assert report.stops[-1].eta_iso == (DEPART + timedelta(hours=report.duration_h)).isoformat()
+    assert report.stops[0].conditions["temp"] == 5.0
+    assert report.stops[-1].conditions["temp"] == 9.0
+    assert report.stops[-1].hazards == ["snow or ice"]
+    assert len(provider.calls) == 3
+
+    console = Console(file=io.StringIO(), width=160)
+    render.render_route(report, console=console, units="imperial")
+    output = console.file.getvalue()
+    assert "Seattle → Portland" in output and "Sat 7 AM PST" in output and "snow or ice" in output
+
+
+def test_route_prefers_osrm_drive_time(monkeypatch) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    orchestrator.provider = _Provider()
+    road = {"path": [(47.6, -122.3), (46.6, -122.9), (45.5, -122.7)], "distance_km": 280.0, "duration_h": 2.75}
+    monkeypatch.setattr(orchestrator_module, "get_osrm_route", lambda start, end, offline: road)
+
+    report = orchestrator.handle_route("seattle", "portland", depart=DEPART)
+
+    assert (report.path, report.distance_km, report.duration_h) == ("osrm", 280.0, 2.75)
+    assert len(report.stops) == 6
+    assert orchestrator.handle_route("seattle", "Atlantis").error == "Could not find Atlantis"
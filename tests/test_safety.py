@@ -0,0 +1,78 @@
+from __future__ import annotations
+
+import io
+
+from rich.console import Console
+
+from wx import config, forecaster, render
+from wx.orchestrator import OrchestrationResult
+from wx.safety import SAFETY_FOOTER, apply_alert_safety, urgent_alerts
+
+TORNADO = {
+    "event": "Tornado Warning",
+    "severity": "Extreme",
+    "headline": "Tornado Warning for Travis County",
+    "expires_iso": "2026-05-01T17:45:00-05:00",
+}
+FLOOD = {"event": "Flood Watch", "severity": "Severe"}
+ADVISORY = {"event": "Wind Advisory", "severity": "Moderate"}
+
+
+def _response(**sections) -> forecaster.ForecasterResponse:
+    return forecaster.ForecasterResponse(
+        sections=sections,
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Bottom line: stormy afternoon.",
+        raw_text="",
+        provider="openrouter:test",
+        prompt_summary="",
+    )
+
+
+def test_urgent_alerts_keep_severe_and_extreme_once() -> None:
+    alerts = [ADVISORY, FLOOD, TORNADO, dict(TORNADO, headline="duplicate")]
+    assert [alert["event"] for alert in urgent_alerts(alerts)] == ["Tornado Warning", "Flood Watch"]
+
+
+def test_missing_alerts_are_added_to_actions_and_bottom_line() -> None:
+    response = _response(actions=["Carry an umbrella.", "Flood Watch: avoid low crossings."])
+
+    added = apply_alert_safety(response, [TORNADO, FLOOD, ADVISORY])
+
+    assert added == ["Tornado Warning", "Flood Watch"]
+    assert response.sections["actions"][0] == f"Tornado Warning in effect until Fri 5:45 PM: {SAFETY_FOOTER}"
+    assert response.sections["actions"][1:] == ["Carry an umbrella.", "Flood Watch: avoid low crossings."]
+    assert response.bottom_line.endswith("Tornado Warning, Flood Watch are in effect.")
+    assert response.sections["safety"][0].startswith("Tornado Warning (Extreme) until Fri 5:45 PM")
+    assert response.meta["safety"]["added"] == ["Tornado Warning", "Flood Watch"]
+
+
+def test_story_that_covers_alerts_is_left_alone() -> None:
+    response = _response(actions=["Shelter now: a tornado warning is in effect."])
+    response.bottom_line = "Bottom line: Tornado Warning until 5:45 PM — take cover."
+
+    assert apply_alert_safety(response, [TORNADO, ADVISORY]) == []
+    assert "safety" not in response.sections
+    assert response.meta["safety"] == {"alerts": ["Tornado Warning"], "added": []}
+    assert apply_alert_safety(_response(actions=[]), [ADVISORY]) == []
+
+
+def test_generate_enforces_safety_on_answers_and_renders_panel(monkeypatch) -> None:
+    engine = forecaster.Forecaster(config.Settings(offline=False, privacy_mode=True))
+    raw = '{"answer": "Yes, storms arrive around 4 PM.", "bottom_line": "Bring a jacket.", "confidence": {"value": 70}}'
+    monkeypatch.setattr(engine, "_invoke_provider", lambda payload: (raw, "openrouter:test", None))
+
+    response = engine.generate(
+        query="Will it storm?", feature_pack={"alerts_quick": [TORNADO]}, intent="question", verbose=False
+    )
+
+    assert response.sections["answer"].startswith("Yes, storms arrive around 4 PM. Tornado Warning")
+    assert "Tornado Warning" in response.bottom_line
+    result = OrchestrationResult(
+        command="question", query="", feature_pack={}, response=response, timings={}, debug={}
+    )
+    console = Console(file=io.StringIO(), width=120)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    output = console.file.getvalue()
+    assert "Safety" in output and "Tornado Warning for Travis County" in output
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import json
+from dataclasses import asdict, fields
+from pathlib import Path
+
+import pytest
+
+from wx.cli import app
+from wx.fishing import FishReport
+from wx.schema import OUTPUT_KINDS, SCHEMA_VERSION, envelope, json_document, output_schema, unwrap
+from wx.watch import PlaceReport, WatchSnapshot
+
+
+def test_envelope_wraps_and_unwraps_payloads() -> None:
+    document = json.loads(json_document("fish", {"place": "Seattle"}))
+
+    assert document == {"schema_version": SCHEMA_VERSION, "kind": "fish", "data": {"place": "Seattle"}}
+    assert "\n" not in json_document("watch", {"place": "Denver"}, indent=None)
+    assert unwrap(document) == {"place": "Seattle"}
+    assert unwrap({"feature_pack": {}}) == {"feature_pack": {}}
+    with pytest.raises(ValueError, match="Unknown output kind"):
+        envelope("forecast", {})
+
+
+def test_output_schema_follows_report_dataclasses() -> None:
+    everything = output_schema()
+    fish = output_schema("fish")["properties"]["data"]
+    favorites = output_schema("favorites")["properties"]["data"]
+
+    assert everything["properties"]["kind"]["enum"] == list(OUTPUT_KINDS)
+    assert len(everything["allOf"]) == len(OUTPUT_KINDS)
+    assert fish["required"] == [field.name for field in fields(FishReport)]
+    assert set(fish["required"]) <= set(asdict(FishReport(place="Seattle")))
+    assert fish["properties"]["tz"] == {"type": ["string", "null"]}
+    assert fish["properties"]["sources"] == {"type": "array", "items": {"type": "string"}}
+    snapshot = favorites["items"]["properties"]["snapshot"]["anyOf"]
+    assert snapshot[0]["required"] == [field.name for field in fields(WatchSnapshot)]
+    assert snapshot[1] == {"type": "null"}
+    assert set(favorites["items"]["required"]) == set(asdict(PlaceReport("home", "Seattle")))
+    with pytest.raises(ValueError, match="Choose from: story"):
+        output_schema("forecast")
+
+
+def test_schema_command_and_enveloped_json(cli_runner, state_dir: Path) -> None:
+    env = {"WX_STATE_DIR": str(state_dir)}
+
+    schema = cli_runner.invoke(app, ["", "schema", "story"])
+    kinds = cli_runner.invoke(app, ["", "schema", "--list"])
+    unknown = cli_runner.invoke(app, ["", "schema", "forecast"])
+    feedback = cli_runner.invoke(app, ["--json", "", "feedback"], env=env)
+
+    assert schema.exit_code == 0, schema.output
+    assert json.loads(schema.output)["properties"]["kind"] == {"const": "story"}
+    assert kinds.output.splitlines()[0].startswith("story ") and "fish" in kinds.output
+    assert unknown.exit_code == 2 and "Unknown output kind" in unknown.output
+    assert json.loads(feedback.stdout) == {"schema_version": 2, "kind": "feedback_summary", "data": []}
@@ -0,0 +1,68 @@
+from __future__ import annotations
+
+import json
+from datetime import UTC, datetime
+from pathlib import Path
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import app
+from wx.favorites import Favorite
+from wx.site import site_html
+from wx.watch import PlaceReport, WatchSnapshot
+
+GENERATED = datetime(2026, 10, 16, 14, 30, tzinfo=UTC)
+
+
+def _reports(places) -> list[PlaceReport]:
+    reports = []
+    for favorite in places:
+        if favorite.place == "Atlantis":
+            reports.append(PlaceReport(favorite.label, favorite.place, error="Could not find Atlantis"))
+            continue
+        snapshot = WatchSnapshot(
+            place=f"{favorite.place} </script>",
+            current={"temp": 10.0, "wind": 16.09, "gust": 32.18, "conditions": "Light Rain"},
+            alerts=[{"event": "High Wind Warning", "severity": "Severe"}],
+            outlook={"temp_min": 5.0, "temp_max": 15.0, "precip_prob_max": 80.0},
+        )
+        reports.append(PlaceReport(favorite.label, favorite.place, snapshot=snapshot))
+    return reports
+
+
+def test_site_html_is_self_contained() -> None:
+    reports = _reports([Favorite("home", "Seattle"), Favorite("lost", "Atlantis")])
+
+    page = site_html(reports, units="imperial", generated_at=GENERATED)
+
+    assert "<h2>home</h2>" in page and "50°F" in page and "41°F – 59°F" in page
+    assert '<div class="alert severe">High Wind Warning</div>' in page
+    assert "Seattle &lt;/script&gt;" in page
+    assert "Could not find Atlantis" in page and "Updated 2026-10-16 14:30 UTC" in page
+    assert "http" not in page.split("<script")[0]
+    embedded = page.split('id="wx-data">')[1].split("</script>")[0]
+    assert json.loads(embedded)["places"][0]["snapshot"]["place"] == "Seattle </script>"
+
+
+def test_export_site_resolves_favorites_and_places(
+    config_dir: Path, state_dir, cli_runner, tmp_path, monkeypatch
+) -> None:
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"favorites": {"Home": "Seattle"}}))
+    requested = []
+
+    def fake_favorites(self, favorites):
+        requested.extend(favorites)
+        return _reports(favorites)
+
+    monkeypatch.setattr(orchestrator_module.Orchestrator, "handle_favorites", fake_favorites)
+    out = tmp_path / "dist"
+
+    result = cli_runner.invoke(app, ["", "export", "site", "--locations", "home,Atlantis", "--out", str(out)])
+
+    assert result.exit_code == 0, result.output
+    assert requested == [Favorite("Home", "Seattle"), Favorite("Atlantis", "Atlantis")]
+    assert "<h2>Home</h2>" in (out / "index.html").read_text()
+    data = json.loads((out / "data.json").read_text())
+    assert data["units"] == "imperial" and data["places"][1]["error"] == "Could not find Atlantis"
+    assert [entry["source"] for entry in data["attribution"]] == ["open-meteo", "geonames", "nws"]
+    footer = "Data: Open-Meteo.com (CC BY 4.0), GeoNames (CC BY 4.0), NWS/NOAA</footer>"
+    assert footer in (out / "index.html").read_text()
@@ -0,0 +1,48 @@
+"""Tests for the snowfall accumulation outlook."""
+
+from __future__ import annotations
+
+import pytest
+
+from wx.snow import build_snow_outlook, percentile
+
+
+def _ensemble(totals: list[float]) -> dict:
+    times = [f"2026-01-10T{hour:02d}:00" for hour in range(12)]
+    # Spread each member's total evenly across hours 6-11.
+    members = [[0.0] * 6 + [total / 6] * 6 for total in totals]
+    return {"time": times, "members": members}
+
+
+def test_percentile_interpolates() -> None:
+    assert percentile([0.0, 10.0], 50) == 5.0
+    assert percentile([1.0, 2.0, 3.0, 4.0, 5.0], 90) == pytest.approx(4.6)
+
+
+def test_outlook_from_ensemble_only() -> None:
+    outlook = build_snow_outlook("Denver", None, _ensemble([float(n) for n in range(11)]))
+    assert outlook.expected_cm == 5.0
+    assert outlook.p10_cm == 1.0
+    assert outlook.p90_cm == 9.0
+    # Leading quiet bin is trimmed from the event timeline.
+    assert [entry["start_iso"] for entry in outlook.timeline] == ["2026-01-10T06:00:00+00:00"]
+    assert outlook.sources == ["GFS ensemble (Open-Meteo)"]
+
+
+def test_outlook_prefers_nws_grid_for_expected() -> None:
+    grid = [
+        {"start_iso": "2026-01-10T00:00:00-07:00", "hours": 6, "mm": 20.0},
+        {"start_iso": "2026-01-10T06:00:00-07:00", "hours": 6, "mm": 50.0},
+        {"start_iso": "2026-01-10T12:00:00-07:00", "hours": 6, "mm": 0.0},
+    ]
+    outlook = build_snow_outlook("Denver", grid, _ensemble([2.0, 6.0, 12.0]))
+    assert outlook.expected_cm == 7.0
+    assert [entry["cumulative_cm"] for entry in outlook.timeline] == [2.0, 7.0]
+    assert outlook.timeline[0]["start_iso"] == "2026-01-10T07:00:00+00:00"
+    assert outlook.sources[0] == "NWS gridded forecast"
+
+
+def test_outlook_without_data() -> None:
+    outlook = build_snow_outlook("Miami", None, None)
+    assert not outlook.has_data
+    assert outlook.timeline == []
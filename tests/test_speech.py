@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+
+from wx import speech
+
+
+def test_spoken_summary_uses_bottom_line_decision_and_safety() -> None:
+    story = SimpleNamespace(
+        bottom_line="Bottom line: storms after 3 PM",
+        sections={"actions": ["- Move the picnic indoors", "Charge phones"], "safety": ["Tornado Watch", "Follow officials."]},
+    )
+    answer = SimpleNamespace(bottom_line="Bring a jacket.", sections={"answer": "Yes, rain after 9 PM."})
+
+    assert speech.spoken_summary(story) == "storms after 3 PM. Move the picnic indoors. Follow officials."
+    assert speech.spoken_summary(answer) == "Yes, rain after 9 PM. Bring a jacket."
+
+
+def test_speak_writes_wav_with_installed_engine(monkeypatch, tmp_path: Path) -> None:
+    calls = []
+    monkeypatch.setattr(speech.shutil, "which", lambda name: "/usr/bin/espeak" if name == "espeak" else None)
+    monkeypatch.setattr(speech.subprocess, "run", lambda command, **kwargs: calls.append(command))
+
+    engine = speech.speak_text("Storms after 3 PM.", output=tmp_path / "brief.wav")
+
+    assert engine == "espeak"
+    assert calls == [["espeak", "-w", str(tmp_path / "brief.wav"), "Storms after 3 PM."]]
+
+
+def test_speak_reports_missing_or_failing_engine(monkeypatch) -> None:
+    monkeypatch.setattr(speech.shutil, "which", lambda name: None)
+    with pytest.raises(speech.SpeechError, match="No text-to-speech engine"):
+        speech.speak_text("hello")
+
+    def fail(command, **kwargs):
+        raise subprocess.CalledProcessError(1, command)
+
+    monkeypatch.setattr(speech.subprocess, "run", fail)
+    with pytest.raises(speech.SpeechError, match="say failed"):
+        speech.speak_text("hello", engine="say")
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import json
+from datetime import UTC, datetime
+
+import pytest
+
+from wx import config, orchestrator as orchestrator_module
+from wx.cli import app
+from wx.staleness import STALE_EXIT_CODE, format_age, parse_age_limit, stale_data, stale_warning
+from wx.watch import PlaceReport, WatchSnapshot, snapshot_line, take_snapshot
+
+NOW = datetime(2026, 10, 16, 18, tzinfo=UTC)
+SEATTLE = {"resolved": "Seattle, WA", "lat": 47.6, "lon": -122.3, "tz": "America/Los_Angeles"}
+
+
+class _Provider:
+    name = "stub"
+
+    def __init__(self, observed_iso: str) -> None:
+        self.observed_iso = observed_iso
+
+    def geocode(self, place):
+        return SEATTLE
+
+    def current(self, lat, lon):
+        return {"temp": 12.0, "conditions": "Overcast", "observed_iso": self.observed_iso}
+
+    def alerts(self, lat, lon):
+        return []
+
+    def forecast(self, lat, lon, *, hours=48):
+        return {"temperature_2m": [12.0, 13.0], "issued": "2026-10-16T16:30:00+00:00"}
+
+
+def test_age_limits_and_stale_labels() -> None:
+    assert parse_age_limit(None, 120) == 120
+    assert (parse_age_limit("90", 120), parse_age_limit("2h", 0), parse_age_limit("1.5h", 0)) == (90, 120, 90)
+    assert parse_age_limit("off", 120) == 0
+    with pytest.raises(ValueError, match="Staleness bound"):
+        parse_age_limit("yesterday", 120)
+    assert (format_age(45), format_age(200), format_age(3000)) == ("45m", "3h 20m", "2d 2h")
+
+    stale = stale_data(observed_iso="2026-10-16T14:40", issued_iso="2026-10-16T05:00:00Z", now=NOW)
+
+    assert [(item["source"], item["age_minutes"]) for item in stale] == [("observation", 200), ("forecast", 780)]
+    assert stale_warning(stale) == (
+        "STALE DATA: Observation is 3h 20m old (limit 2h); Forecast issuance is 13h old (limit 6h)"
+    )
+    assert stale_data(observed_iso="2026-10-16T14:40", max_obs_minutes=0, now=NOW) == []
+    assert stale_data(observed_iso="2026-10-16T17:00:00+00:00", issued_iso=None, now=NOW) == []
+    assert stale_warning([]) is None
+
+
+def test_snapshots_and_forecasts_flag_old_observations(monkeypatch) -> None:
+    snapshot = take_snapshot(_Provider("2026-10-16T13:00:00Z"), SEATTLE, fresh=False, now=NOW)
+    fresh = take_snapshot(_Provider("2026-10-16T13:00:00Z"), SEATTLE, fresh=False, now=NOW, stale_after=(360, 360))
+
+    assert [item["source"] for item in snapshot.stale] == ["observation"]
+    assert snapshot_line(snapshot, "metric").startswith("STALE DATA: Observation is 5h old (limit 2h) · 12°C")
+    assert fresh.stale == []
+
+    settings = config.Settings(offline=False, privacy_mode=True, stale_obs_minutes=60)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=True)
+    monkeypatch.setattr(orchestrator, "_now", lambda: NOW)
+    feature_pack: dict = {"obs_quick": {"observed_iso": "2026-10-16T13:00:00Z"}}
+    orchestrator._flag_stale(feature_pack)
+    assert feature_pack["stale"][0]["limit_minutes"] == 60
+    orchestrator.at = NOW
+    archived: dict = {"obs_quick": {"observed_iso": "2026-10-16T13:00:00Z"}}
+    orchestrator._flag_stale(archived)
+    assert "stale" not in archived
+
+
+def test_check_flag_exits_non_zero_on_stale_favorites(cli_runner, config_dir, monkeypatch) -> None:
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"favorites": {"home": "Seattle"}}))
+    stale = stale_data(observed_iso="2026-10-16T13:00:00Z", now=NOW)
+    state = {"reports": [PlaceReport("home", "Seattle", WatchSnapshot(place="Seattle", stale=stale))]}
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator, "handle_favorites", lambda self, favorites: state["reports"]
+    )
+
+    checked = cli_runner.invoke(app, ["--check", "", "favorites"])
+    unchecked = cli_runner.invoke(app, ["", "favorites"])
+    bad_bound = cli_runner.invoke(app, ["", "favorites"], env={"WX_STALE_OBS": "soon"})
+    state["reports"] = [PlaceReport("home", "Seattle", WatchSnapshot(place="Seattle"))]
+    fresh = cli_runner.invoke(app, ["--check", "", "favorites"])
+
+    assert checked.exit_code == STALE_EXIT_CODE, checked.output
+    assert "home: STALE DATA: Observation is 5h old (limit 2h)" in checked.output
+    assert unchecked.exit_code == 0 and "STALE DATA" in unchecked.output
+    assert bad_bound.exit_code == 2 and "WX_STALE_OBS" in bad_bound.output
+    assert fresh.exit_code == 0, fresh.output
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from wx import config
+from wx.cli import app
+from wx.forecaster import Forecaster
+from wx.storyparse import parse_story
+
+# Malformed and edge-case replies collected from real backends; add new ones with an entry in
+# expected.json (check them first with `wx debug parse-story <file>`).
+STORIES = Path(__file__).parent / "fixtures" / "stories"
+EXPECTED = json.loads((STORIES / "expected.json").read_text(encoding="utf-8"))
+
+
+def test_every_fixture_has_expectations() -> None:
+    assert sorted(path.name for path in STORIES.glob("*.txt")) == sorted(EXPECTED)
+
+
+@pytest.mark.parametrize("name", sorted(EXPECTED))
+def test_story_corpus(name: str) -> None:
+    expected = EXPECTED[name]
+
+    parsed = parse_story((STORIES / name).read_text(encoding="utf-8"))
+
+    assert parsed.ok is expected["ok"], parsed.error
+    assert parsed.repairs == expected["repairs"]
+    assert parsed.problems == expected["problems"]
+    if parsed.ok:
+        assert isinstance(parsed.data["sections"], dict)
+        assert all(isinstance(parsed.data["sections"].get(key, []), list) for key in ("summary", "actions"))
+
+
+def test_forecaster_uses_repaired_story() -> None:
+    forecaster = Forecaster(config.Settings(offline=True, privacy_mode=True))
+    raw = (STORIES / "truncated.txt").read_text(encoding="utf-8")
+
+    response = forecaster._parse_response(raw, "", "openrouter:test", {"model": "m"})
+
+    assert response.provider == "openrouter:test"
+    assert response.bottom_line == "Plan for a slow morning comm"
+    assert response.sections["actions"][1] == "Clear sidewalks before the evening freeze"
+    assert response.meta == {"model": "m", "parse_repairs": ["closed truncated JSON"], "parse_problems": []}
+
+    failed = forecaster._parse_response("no json here", "", "openrouter:test", None)
+    assert failed.provider == "fallback:unparseable"
+    assert failed.meta["parse_error"].startswith("Not valid JSON")
+
+
+def test_debug_parse_story_command(cli_runner, state_dir) -> None:
+    result = cli_runner.invoke(app, ["", "debug", "parse-story", str(STORIES / "flat_sections.txt")])
+    assert result.exit_code == 0, result.output
+    assert "Parsed after 4 repairs." in result.output
+    assert "moved top-level sections under sections" in result.output
+
+    result = cli_runner.invoke(app, ["--json", "", "debug", "parse-story", str(STORIES / "prose_only.txt")])
+    assert result.exit_code == 1
+    assert json.loads(result.output)["data"]["ok"] is False
@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from wx import config
+from wx import forecaster as forecaster_module
+from wx.cli import app
+from wx.storyprompt import STORY_TEMPLATE, alert_lines, check_story_template, story_prompt_path
+from wx.template import TemplateError
+
+ALERT = {"event": "Wind Advisory", "severity": "Moderate", "expires_iso": "2026-10-17T04:00:00Z"}
+
+
+def _payload(intent: str) -> dict:
+    feature_pack = {"place": {"resolved": "Denver, CO"}, "user_context": {"focus": "cycling"}, "alerts_quick": [ALERT]}
+    return {"query": "Denver", "intent": intent, "verbose": False, "explain_mode": False, "feature_pack": feature_pack}
+
+
+def test_custom_template_replaces_briefing_prompt_only(config_dir: Path) -> None:
+    forecaster = forecaster_module.Forecaster(config.Settings(privacy_mode=True))
+    built_in = forecaster._build_prompt(_payload("forecast"))
+    path = story_prompt_path()
+    path.parent.mkdir(parents=True)
+    path.write_text("Pirate voice for {{location}} ({{focus}}).\n{{alerts}}\n{{instructions}}", encoding="utf-8")
+
+    prompt = forecaster._build_prompt(_payload("forecast"))
+
+    assert path == config_dir / "prompts" / "story.txt"
+    assert prompt.startswith("Pirate voice for Denver, CO (cycling).\n- Wind Advisory (Moderate) until 2026-10-17")
+    assert "The user's focus is cycling" in prompt and "Feature Pack JSON" not in prompt
+    assert forecaster._build_prompt(_payload("question")).startswith("You are to answer as wx.")
+    path.write_text("  \n", encoding="utf-8")
+    assert forecaster._build_prompt(_payload("forecast")) == built_in
+
+
+def test_template_fields_are_checked() -> None:
+    check_story_template(STORY_TEMPLATE)
+    with pytest.raises(TemplateError):
+        check_story_template("Hello {{nickname}}")
+
+    assert alert_lines([ALERT, {"event": "Fog"}]) == [
+        "- Wind Advisory (Moderate) until 2026-10-17T04:00:00Z",
+        "- Fog (Unknown)",
+    ]
+    assert alert_lines(None) == []
+
+
+def test_prompt_show_edit_and_reset(cli_runner, config_dir: Path) -> None:
+    path = config_dir / "prompts" / "story.txt"
+
+    built_in = cli_runner.invoke(app, ["", "prompt", "show"])
+    edited = cli_runner.invoke(app, ["", "prompt", "edit"], env={"VISUAL": "", "EDITOR": "true"})
+    seeded = path.read_text(encoding="utf-8")
+    path.write_text("Briefly, {{nickname}}", encoding="utf-8")
+    broken = cli_runner.invoke(app, ["", "prompt", "show"])
+    reset = cli_runner.invoke(app, ["", "prompt", "reset"])
+    again = cli_runner.invoke(app, ["", "prompt", "reset"])
+
+    assert built_in.exit_code == 0 and "Built-in story prompt" in built_in.output
+    assert "Query: {{query}}" in built_in.output
+    assert edited.exit_code == 0, edited.output
+    assert seeded == STORY_TEMPLATE and "Saved" in edited.output
+    assert broken.exit_code == 1 and "Custom story prompt" in broken.output
+    assert "Removed" in reset.output and not path.exists()
+    assert "already use the built-in one" in again.output
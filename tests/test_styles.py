@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+import json
+
+import pytest
+
+from wx import config
+from wx import forecaster as forecaster_module
+from wx.cli import app
+from wx.config import DEFAULT_MAX_TOKENS
+from wx.profile import load_profile
+from wx.prompts import STYLE_PRESETS, parse_style
+
+
+def _payload(intent: str) -> dict:
+    feature_pack = {"place": {"resolved": "Denver, CO"}}
+    return {"query": "Denver", "intent": intent, "verbose": False, "explain_mode": False, "feature_pack": feature_pack}
+
+
+def test_style_adjusts_prompt_and_token_budget(monkeypatch) -> None:
+    monkeypatch.delenv("AI_MAX_TOKENS", raising=False)
+    monkeypatch.delenv("WX_STYLE", raising=False)
+    settings = config.load_settings(style="Pilot-Briefing")
+    forecaster = forecaster_module.Forecaster(settings)
+
+    assert settings.style == "pilot-briefing"
+    assert settings.ai_max_tokens == STYLE_PRESETS["pilot-briefing"].max_tokens
+    assert "flight categories (VFR/MVFR/IFR/LIFR) in time order" in forecaster._build_prompt(_payload("forecast"))
+    assert "pilot weather briefing" in forecaster._build_prompt(_payload("question"))
+    assert "pilot weather briefing" not in forecaster._build_prompt(_payload("data"))
+    assert config.load_settings().ai_max_tokens == DEFAULT_MAX_TOKENS
+
+    monkeypatch.setenv("AI_MAX_TOKENS", "2500")
+    assert config.load_settings(style="kids").ai_max_tokens == 2500
+    with pytest.raises(ValueError, match="Unknown style 'haiku'. Choose from: standard, brief"):
+        parse_style("haiku")
+
+
+def test_profile_style_is_the_default(cli_runner, config_dir, monkeypatch) -> None:
+    monkeypatch.delenv("WX_STYLE", raising=False)
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"style": "casual"}))
+
+    assert config.load_settings(profile=load_profile()).style == "casual"
+    assert config.load_settings(style="technical", profile=load_profile()).style == "technical"
+    monkeypatch.setenv("WX_STYLE", "kids")
+    assert config.load_settings(profile=load_profile()).style == "kids"
+
+    bad = cli_runner.invoke(app, ["--style", "haiku", "", "cache", "stats"])
+    assert bad.exit_code == 2 and "--style" in bad.output
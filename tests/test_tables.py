@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import csv
+import io
+import sys
+
+import pytest
+
+from wx import orchestrator as orchestrator_module
+from wx.cli import app
+from wx.forecaster import ForecasterResponse
+from wx.history import HistoryReport
+from wx.orchestrator import OrchestrationResult
+from wx.tables import forecast_rows, history_table, write_table
+from wx.tidy import DataExportError
+
+PERIOD = {
+    "start_iso": "2026-10-16T15:00:00+00:00",
+    "hours": 3,
+    "temp": 54,
+    "feels_like": 52,
+    "dewpoint": 45,
+    "wind": 12,
+    "wind_dir": 200,
+    "gust": 22,
+    "precip_prob": 40,
+    "precip": 0.1,
+}
+
+
+def _result() -> OrchestrationResult:
+    response = ForecasterResponse(
+        sections={"summary": ["Showers."]},
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Showers.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+    feature_pack = {
+        "place": {"resolved": "Seattle, WA", "lat": 47.6, "lon": -122.3},
+        "periods": [PERIOD, {**PERIOD, "start_iso": "2026-10-16T18:00:00+00:00", "temp": 57}],
+    }
+    return OrchestrationResult("forecast", "Seattle", feature_pack, response, {}, {})
+
+
+def test_forecast_rows_name_units_and_history_keeps_archive_columns() -> None:
+    rows = forecast_rows(_result(), units="imperial")
+
+    assert rows[0] == {
+        "place": "Seattle, WA",
+        "lat": 47.6,
+        "lon": -122.3,
+        "start_iso": "2026-10-16T15:00:00+00:00",
+        "hours": 3,
+        "temp_f": 54,
+        "feels_like_f": 52,
+        "dewpoint_f": 45,
+        "wind_mph": 12,
+        "wind_dir_deg": 200,
+        "gust_mph": 22,
+        "precip_prob_pct": 40,
+        "precip_in": 0.1,
+    }
+    assert "temp_c" in forecast_rows(_result(), units="metric")[1] and len(rows) == 2
+
+    report = HistoryReport("Boston, MA", "2023-07-04", "2023-07-04", days=[{"date": "2023-07-04", "temp_max_c": 31.2}])
+    assert history_table(report) == [{"place": "Boston, MA", "date": "2023-07-04", "temp_max_c": 31.2}]
+
+
+def test_write_table_csv_and_parquet_without_pyarrow(monkeypatch) -> None:
+    stream = io.BytesIO()
+
+    write_table(forecast_rows(_result(), units="metric"), "csv", stream)
+
+    parsed = list(csv.DictReader(io.StringIO(stream.getvalue().decode())))
+    assert [row["temp_c"] for row in parsed] == ["54", "57"] and parsed[0]["place"] == "Seattle, WA"
+    monkeypatch.setitem(sys.modules, "pyarrow", None)
+    with pytest.raises(DataExportError, match="pip install"):
+        write_table(parsed, "parquet", io.BytesIO())
+
+
+def test_forecast_export_csv_writes_period_rows(cli_runner, config_dir, state_dir, monkeypatch) -> None:
+    monkeypatch.setattr(orchestrator_module.Orchestrator, "handle_forecast", lambda self, place, **kwargs: _result())
+
+    result = cli_runner.invoke(app, ["", "forecast", "Seattle", "--export", "csv"])
+    history = cli_runner.invoke(app, ["", "history", "Boston", "--date", "2023-07-04", "--export", "xlsx"])
+
+    assert result.exit_code == 0, result.output
+    lines = result.output.splitlines()
+    assert lines[0].startswith("place,lat,lon,start_iso,hours,temp_") and len(lines) == 3
+    assert history.exit_code == 2 and "Choose from: csv, parquet" in history.output
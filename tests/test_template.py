@@ -0,0 +1,57 @@
+"""Tests for --template output rendering."""
+
+from __future__ import annotations
+
+import pytest
+
+from wx.template import TemplateError, load_template, render_template, result_context
+
+
+def test_render_template_resolves_fields_and_paths() -> None:
+    context = {"temp": 72.0, "unit": "F", "place": {"resolved": "Austin"}, "alerts": ["Heat Advisory"], "gust": None}
+
+    assert render_template("{{temp}}°{{ unit }} in {{place.resolved}}", context) == "72°F in Austin"
+    assert render_template("{{alerts}} / {{alerts.0}} / {{alerts.5}} / {{gust}}", context) == (
+        "Heat Advisory / Heat Advisory /  / "
+    )
+
+
+def test_render_template_rejects_unknown_fields() -> None:
+    with pytest.raises(TemplateError, match="Unknown template field 'tmp'"):
+        render_template("{{tmp}}", {"temp": 1})
+
+
+def test_load_template_reads_files_and_inline_escapes(tmp_path) -> None:
+    path = tmp_path / "out.tmpl"
+    path.write_text("{{temp}}\\n", encoding="utf-8")
+
+    assert load_template(str(path)) == "{{temp}}\\n"
+    assert load_template("{{temp}}\\n{{unit}}") == "{{temp}}\n{{unit}}"
+
+
+def test_result_context_shortcuts_use_feature_pack_units() -> None:
+    payload = {
+        "command": "forecast",
+        "query": "Austin",
+        "feature_pack": {
+            "units": {"temp": "F"},
+            "place": {"resolved": "Austin, TX"},
+            "obs_quick": {"temp": 86.0, "wind": 10.0, "conditions": "Clear"},
+            "periods": [{"temp": 90, "gust": 25}],
+        },
+        "response": {
+            "sections": {"summary": ["Hot.", "Dry."]},
+            "bottom_line": "Bottom line: hot.",
+            "confidence": {"value": 80},
+        },
+    }
+
+    context = result_context(payload)
+
+    assert context["place"] == "Austin, TX"
+    assert context["temp"] == 86
+    assert context["wind"] == 10
+    assert context["gust"] == 25
+    assert context["conditions"] == "Clear"
+    assert context["summary"] == "Hot. Dry."
+    assert context["confidence"] == 80
@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import csv
+import io
+import json
+import sys
+
+import pytest
+
+from wx import config, orchestrator as orchestrator_module
+from wx.cli import app
+from wx.favorites import Favorite
+from wx.tidy import DataExportError, SiteSeries, parse_horizon, tidy_rows, write_csv, write_parquet
+
+HOURLY = {
+    "time": ["2026-10-16T15:00", "2026-10-16T16:00"],
+    "temperature_2m": [10.0, 12.5],
+    "precipitation_probability": [40, None],
+    "wind_speed_10m": [16.09, 32.18],
+    "weather_code": [61, 3],
+}
+
+
+class _Provider:
+    name = "stub"
+
+    def __init__(self) -> None:
+        self.hours = []
+
+    def geocode(self, place):
+        return None if place == "Atlantis" else {"resolved": place, "lat": 39.7, "lon": -105.0}
+
+    def forecast(self, lat, lon, *, hours=48):
+        self.hours.append(hours)
+        return HOURLY
+
+
+def test_parse_horizon_and_tidy_rows_convert_units() -> None:
+    assert [parse_horizon(value) for value in ("48h", "7d", "12", " 2D ")] == [48, 168, 12, 48]
+    for bad in ("17d", "0h", "a week"):
+        with pytest.raises(ValueError, match="up to 16d"):
+            parse_horizon(bad)
+
+    rows = tidy_rows([SiteSeries("Store 1", "Denver", hourly=HOURLY), SiteSeries("lost", "Atlantis")], units="imperial")
+
+    assert rows[:2] == [
+        {"location": "Store 1", "time": "2026-10-16T15:00:00Z", "variable": "temperature", "value": 50.0, "unit": "°F"},
+        {"location": "Store 1", "time": "2026-10-16T16:00:00Z", "variable": "temperature", "value": 54.5, "unit": "°F"},
+    ]
+    assert [(row["variable"], row["value"], row["unit"]) for row in rows[2:]] == [
+        ("precipitation_probability", 40, "%"),
+        ("wind_speed", 10.0, "mph"),
+        ("wind_speed", 20.0, "mph"),
+    ]
+
+
+def test_write_csv_and_parquet_needs_pyarrow(tmp_path, monkeypatch) -> None:
+    rows = tidy_rows([SiteSeries("Store 1", "Denver", hourly=HOURLY)], units="metric")
+    stream = io.StringIO()
+
+    write_csv(rows, stream)
+
+    parsed = list(csv.DictReader(io.StringIO(stream.getvalue())))
+    assert stream.getvalue().startswith("location,time,variable,value,unit\n") and len(parsed) == len(rows)
+    assert parsed[-1] == {
+        "location": "Store 1",
+        "time": "2026-10-16T16:00:00Z",
+        "variable": "wind_speed",
+        "value": "8.9",
+        "unit": "m/s",
+    }
+    monkeypatch.setitem(sys.modules, "pyarrow", None)
+    with pytest.raises(DataExportError, match="wx-cli\\[parquet\\]"):
+        write_parquet(rows, tmp_path / "weather.parquet")
+
+
+def test_export_data_writes_group_csv(config_dir, cli_runner, monkeypatch, tmp_path) -> None:
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    orchestrator.provider = _Provider()
+    sites = [Favorite("Store 1", "Denver"), Favorite("Store 2", "Atlantis")]
+    series = orchestrator.handle_data_export(sites, hours=168)
+    assert orchestrator.provider.hours == [168] and series[0].hourly is HOURLY
+    assert series[1].error == "Could not find Atlantis"
+
+    groups = {"stores": {"Store 1": "Denver", "Store 2": "Atlantis"}}
+    (config_dir / "profiles" / "default.json").write_text(json.dumps({"groups": groups}))
+    requested = []
+    monkeypatch.setattr(
+        orchestrator_module.Orchestrator,
+        "handle_data_export",
+        lambda self, sites, hours: requested.append(hours) or series,
+    )
+    out = tmp_path / "weather.csv"
+
+    result = cli_runner.invoke(
+        app, ["", "export", "data", "--locations", "@stores", "--horizon", "7d", "--out", str(out)]
+    )
+    parquet = cli_runner.invoke(app, ["", "export", "data", "--format", "parquet", "--locations", "Denver"])
+
+    assert result.exit_code == 0, result.output
+    assert requested == [168] and out.read_text().count("Store 1,") == 5
+    assert "Wrote 5 rows for 2 locations" in result.output and "No data for Store 2" in result.output
+    assert parquet.exit_code == 2 and "--out" in parquet.output
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+import pytest
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, providers as providers_module, render, timemachine
+from wx.timemachine import lead_days, stitch_previous_runs, validate_at
+
+AT = datetime(2026, 10, 10, 6, 0, tzinfo=UTC)
+
+
+def test_each_hour_comes_from_a_run_issued_before_at() -> None:
+    assert [lead_days(AT, AT + timedelta(hours=hours)) for hours in (-3, 0, 1, 24, 25, 200)] == [0, 0, 1, 1, 2, 7]
+    hourly = {
+        "time": ["2026-10-10T05:00", "2026-10-10T07:00", "2026-10-11T07:00"],
+        "temperature_2m": [10.0, 11.0, 12.0],
+        "temperature_2m_previous_day1": [20.0, 21.0, 22.0],
+        "temperature_2m_previous_day2": [30.0, 31.0],
+    }
+
+    stitched = stitch_previous_runs(hourly, AT, ("temperature_2m", "precipitation"))
+
+    assert stitched == {
+        "time": hourly["time"],
+        "temperature_2m": [10.0, 21.0, None],
+        "precipitation": [None, None, None],
+    }
+
+
+def test_validate_at_rejects_future_and_unarchived_moments() -> None:
+    now = datetime(2026, 10, 16, tzinfo=UTC)
+    assert validate_at(AT, now=now) == AT
+    with pytest.raises(ValueError, match="in the past"):
+        validate_at(now + timedelta(hours=1), now=now)
+    with pytest.raises(ValueError, match="start on 2024-01-01"):
+        validate_at(datetime(2023, 6, 1, tzinfo=UTC), now=now)
+
+
+def test_forecast_at_builds_the_pack_as_of_that_moment(monkeypatch) -> None:
+    requested = {}
+
+    def fake_previous_runs(lat, lon, start, *, hours, days, offline):
+        requested.update(start=start, hours=hours, days=days)
+        times = [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(hours)]
+        return {"time": times, "temperature_2m": [8.0] * hours, "temperature_2m_previous_day1": [9.0] * hours}
+
+    monkeypatch.setattr(timemachine, "get_previous_runs_forecast", fake_previous_runs)
+    monkeypatch.setattr(
+        providers_module,
+        "get_point_context",
+        lambda place, offline=False: {"resolved": "Denver", "lat": 39.7, "lon": -105.0, "tz": "America/Denver"},
+    )
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: pytest.fail("live alerts"))
+    monkeypatch.setattr(orchestrator_module, "fetch_spc_outlook", lambda *args, **kwargs: pytest.fail("live outlook"))
+    orchestrator = orchestrator_module.Orchestrator(config.Settings(offline=False, privacy_mode=True))
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ("{}", "test", None))
+
+    result = orchestrator.handle_forecast(
+        "Denver", when_text=None, horizon="12h", focus=None, verbose=False, at=datetime(2026, 10, 10, 0, 0)
+    )
+
+    assert result.feature_pack["as_of"] == "2026-10-10T00:00:00-06:00"
+    assert result.feature_pack["window"]["start_iso"] == "2026-10-10T06:00:00+00:00"
+    assert requested == {"start": AT, "hours": 48, "days": 2}
+    assert result.feature_pack["periods"] and "alerts_quick" not in result.feature_pack
+    console = Console(file=io.StringIO(), width=120)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "As of Sat Oct 10 2026, 12 AM MDT" in console.file.getvalue()
@@ -0,0 +1,67 @@
+"""Tests for the tropical cyclone basin dashboard."""
+
+from __future__ import annotations
+
+from datetime import date
+
+from wx.fetchers import TropicalCyclone
+from wx.tropics import (
+    compass_point,
+    heading_toward,
+    in_season,
+    saffir_simpson_category,
+    summarize_basins,
+)
+
+PEAK_SEASON = date(2026, 9, 10)
+
+
+def _storm(**overrides) -> TropicalCyclone:
+    values = {
+        "storm_id": "al092026",
+        "name": "Ivan",
+        "basin": "Atlantic",
+        "classification": "HU",
+        "intensity_kt": 115,
+        "lat": 17.0,
+        "lon": -70.0,
+        "movement_dir": 280.0,
+    }
+    values.update(overrides)
+    return TropicalCyclone(**values)
+
+
+def test_in_season_windows() -> None:
+    assert in_season("Eastern Pacific", date(2026, 5, 20))
+    assert not in_season("Atlantic", date(2026, 5, 20))
+    assert in_season("Atlantic", date(2026, 11, 30))
+    assert not in_season("Atlantic", date(2026, 12, 1))
+
+
+def test_saffir_simpson_category_thresholds() -> None:
+    assert saffir_simpson_category(63) is None
+    assert saffir_simpson_category(64) == 1
+    assert saffir_simpson_category(96) == 3
+    assert saffir_simpson_category(137) == 5
+    assert saffir_simpson_category(None) is None
+
+
+def test_heading_toward_nearest_landmass_ahead() -> None:
+    assert compass_point(280.0) == "W"
+    assert heading_toward(_storm()) == "Jamaica"
+    # Moving away from everything in range.
+    assert heading_toward(_storm(lat=30.0, lon=-50.0, movement_dir=45.0)) is None
+
+
+def test_summarize_basins_groups_and_filters() -> None:
+    storms = [
+        _storm(),
+        _storm(storm_id="ep102026", name="Lidia", basin="Eastern Pacific", classification="TS", intensity_kt=50),
+    ]
+    summary = summarize_basins(storms, today=PEAK_SEASON)
+    assert [basin["basin"] for basin in summary] == ["Atlantic", "Eastern Pacific", "Central Pacific"]
+    assert summary[0]["storms"][0]["category"] == 4
+    assert summary[2]["storms"] == []
+
+    severe = summarize_basins(storms, today=PEAK_SEASON, severe_only=True)
+    assert [basin["basin"] for basin in severe] == ["Atlantic"]
@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+from types import SimpleNamespace
+
+from rich.console import Console
+
+from wx.cache import CachingProvider, FetchCache
+from wx.tui import DashboardData, build_dashboard, load_dashboard, sparkline
+
+HOURLY = {
+    "time": [f"2026-10-16T{hour:02d}:00" for hour in range(24)],
+    "temperature_2m": [10.0 + hour / 2 for hour in range(24)],
+    "precipitation_probability": [0] * 12 + [80] * 12,
+}
+
+
+class _Provider:
+    name = "stub"
+
+    def __init__(self) -> None:
+        self.calls = 0
+
+    def geocode(self, place):
+        return {"resolved": "Seattle, WA", "lat": 47.6, "lon": -122.3}
+
+    def current(self, lat, lon):
+        self.calls += 1
+        return {"temp": 12.0, "feels_like": 10.0, "wind": 16.0, "gust": 32.0, "conditions": "Light rain"}
+
+    def forecast(self, lat, lon, *, hours=48):
+        return HOURLY
+
+    def alerts(self, lat, lon):
+        return []
+
+
+def test_sparkline_scales_between_bounds() -> None:
+    assert sparkline([0, 5, 10]) == "▁▅█"
+    assert sparkline([None, 50], low=0, high=100) == " ▅"
+    assert sparkline([]) == ""
+
+
+def test_dashboard_shows_conditions_chart_alerts_and_timeline() -> None:
+    data = DashboardData(
+        place="Seattle",
+        resolved="Seattle, WA",
+        current={"temp": 12.0, "wind": 16.0, "gust": 32.0, "conditions": "Light rain"},
+        hourly=HOURLY,
+        alerts=[{"event": "Wind Advisory", "severity": "Moderate", "headline": "Gusts to 45 mph"}],
+        timeline=["3 PM PDT (22Z): showers begin"],
+        bottom_line="Bring a rain jacket.",
+    )
+    console = Console(record=True, width=140, height=30)
+
+    console.print(build_dashboard(data, places=["Seattle", "Denver"], index=0, units="imperial"))
+    text = console.export_text()
+
+    assert "1 Seattle" in text and "2 Denver" in text
+    assert "54°F" in text and "Light rain" in text and "gusts 20" in text
+    assert "Wind Advisory" in text and "Alerts (1)" in text
+    assert "50–71°F" in text and "max 80%" in text
+    assert "showers begin" in text
+
+
+def test_load_dashboard_refresh_skips_cache() -> None:
+    inner = _Provider()
+    provider = CachingProvider(inner, FetchCache())
+    timeline = ["Tonight: rain"]
+    response = SimpleNamespace(sections={"timeline": timeline}, bottom_line="Wet.")
+
+    def handle_forecast(place, **kwargs):
+        info = provider.geocode(place)
+        return SimpleNamespace(feature_pack={"place": info}, response=response)
+
+    orchestrator = SimpleNamespace(provider=provider, handle_forecast=handle_forecast)
+
+    first = load_dashboard(orchestrator, "Seattle")
+    load_dashboard(orchestrator, "Seattle")
+    assert inner.calls == 1
+    load_dashboard(orchestrator, "Seattle", refresh=True)
+
+    assert inner.calls == 2
+    assert provider.refresh is False
+    assert first.resolved == "Seattle, WA" and first.timeline == timeline
+    assert first.hourly == HOURLY
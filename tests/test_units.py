@@ -0,0 +1,39 @@
+from __future__ import annotations
+
+import pytest
+
+from wx.config import load_settings
+from wx.units import convert, display_observation, parse_units, unit_pack
+
+
+def test_parse_units_accepts_prefixes_and_aliases() -> None:
+    assert parse_units("Metric") == "metric"
+    assert parse_units("imp") == "imperial"
+    assert parse_units("si") == "metric"
+    with pytest.raises(ValueError):
+        parse_units("kelvin")
+
+
+def test_observation_converted_to_display_units() -> None:
+    obs = {"temp": 20.0, "wind": 36.0, "gust": None, "precip_last_hr": 2.54, "vis_km": 10.0, "conditions": "Rain"}
+
+    assert display_observation(obs, "imperial") == {
+        "temp": 68,
+        "wind": 22,
+        "gust": None,
+        "precip_last_hr": 0.1,
+        "vis_km": 10.0,
+        "conditions": "Rain",
+    }
+    metric = display_observation(obs, "metric")
+    assert metric["temp"] == 20.0 and metric["wind"] == 10.0 and metric["precip_last_hr"] == 2.5
+    assert unit_pack("metric") == {"temp": "C", "wind": "mps", "precip": "mm"}
+    assert convert(36.0, "speed", "metric") == 10.0
+
+
+def test_units_option_overrides_env(monkeypatch, state_dir) -> None:
+    monkeypatch.setenv("UNITS", "imperial")
+    assert load_settings(units="metric").units == "metric"
+    assert load_settings().units == "imperial"
+    with pytest.raises(ValueError, match="units"):
+        load_settings(units="furlongs")
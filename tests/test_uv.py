@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import io
+from datetime import UTC, datetime, timedelta
+
+from rich.console import Console
+
+from wx import config, orchestrator as orchestrator_module, render
+from wx.forecaster import ForecasterResponse
+from wx.uv import apply_sun_safety, is_uv_focus, uv_category, uv_pack
+
+NOW = datetime(2026, 7, 1, 15, tzinfo=UTC)
+
+
+def _hourly(uv: list[float], start: datetime = NOW) -> dict[str, list]:
+    return {
+        "time": [(start + timedelta(hours=idx)).strftime("%Y-%m-%dT%H:%M") for idx in range(len(uv))],
+        "uv_index": uv,
+    }
+
+
+def _response(actions: list[str]) -> ForecasterResponse:
+    return ForecasterResponse(
+        sections={"summary": "Hot and sunny.", "actions": actions},
+        confidence={"value": 70},
+        used_feature_fields=[],
+        bottom_line="Bottom line: a beach day.",
+        raw_text="",
+        provider="test",
+        prompt_summary="",
+    )
+
+
+def test_categories_and_focus() -> None:
+    assert [uv_category(value) for value in (1, 2.6, 6.4, 9, 11.2)] == [
+        "Low",
+        "Moderate",
+        "High",
+        "Very High",
+        "Extreme",
+    ]
+    assert is_uv_focus("Outdoor") and is_uv_focus("beach") and not is_uv_focus("wind")
+
+
+def test_pack_finds_protection_window() -> None:
+    pack = uv_pack(_hourly([1.0, 3.5, 7.0, 9.2, 5.0, 2.0, 4.0]), now=NOW + timedelta(minutes=30))
+
+    assert pack["peak"] == {"uv_index": 9.2, "category": "Very High", "time_iso": (NOW + timedelta(hours=3)).isoformat()}
+    assert pack["protect"] == {
+        "start_iso": (NOW + timedelta(hours=1)).isoformat(),
+        "end_iso": (NOW + timedelta(hours=4)).isoformat(),
+        "max_uv": 9.2,
+    }
+    assert uv_pack(_hourly([0.0, 1.0]), now=NOW)["protect"] is None
+    assert uv_pack({"time": [], "uv_index": []}) is None
+
+
+def test_sun_safety_added_when_story_misses_it() -> None:
+    pack = uv_pack(_hourly([1.0, 3.5, 7.0, 9.2, 5.0, 2.0]), now=NOW)
+    response = _response(["Drink plenty of water."])
+
+    added = apply_sun_safety(response, pack, "America/Denver")
+
+    assert added == [
+        "Wear SPF 30+ sunscreen, a hat and sunglasses outdoors from Wed 10 AM MDT to Wed 1 PM MDT: UV index"
+        " up to 9 (Very High). Reapply every 2 hours and after swimming or sweating.",
+        "Seek shade around Wed 12 PM MDT, when the sun is strongest.",
+    ]
+    assert response.sections["actions"] == ["Drink plenty of water.", *added]
+    assert response.sections["uv"] == (
+        "UV index peaks at 9 (Very High) around Wed 12 PM MDT; protection needed Wed 10 AM MDT–Wed 1 PM MDT."
+    )
+
+    covered = _response(["Reapply sunscreen at lunch."])
+    assert apply_sun_safety(covered, pack) == []
+    assert covered.meta["uv"] == {"peak": 9.2, "added": []}
+
+
+def test_outdoor_focus_and_uv_hazard(monkeypatch) -> None:
+    settings = config.Settings(offline=False, privacy_mode=True)
+    orchestrator = orchestrator_module.Orchestrator(settings, trust_tools=False)
+    start = datetime.now(UTC).replace(minute=0, second=0, microsecond=0)
+    monkeypatch.setattr(
+        orchestrator.provider, "geocode", lambda place: {"resolved": "Denver", "lat": 39.7, "lon": -105.0}
+    )
+    monkeypatch.setattr(orchestrator.provider, "alerts", lambda lat, lon: [])
+    monkeypatch.setattr(
+        orchestrator.provider, "forecast", lambda lat, lon, hours=48: _hourly([6.0, 8.5, 7.0, 2.0], start)
+    )
+    monkeypatch.setattr(orchestrator.forecaster, "_invoke_provider", lambda payload: ('{"actions": []}', "test", None))
+
+    plain = orchestrator.handle_forecast("Denver", when_text=None, horizon="12h", focus="wind", verbose=False)
+    assert "uv" not in plain.feature_pack
+
+    result = orchestrator.handle_forecast("Denver", when_text=None, horizon="12h", focus="outdoor", verbose=False)
+    assert result.feature_pack["uv"]["peak"]["uv_index"] == 8.5
+    assert result.response.sections["actions"][0].startswith("Wear SPF 30+ sunscreen")
+    console = Console(file=io.StringIO(), width=200)
+    render.render_result(result, console=console, json_mode=False, debug=False, verbose=False)
+    assert "UV: UV index peaks at 8 (Very High)" in console.file.getvalue()
+
+    risk = orchestrator.handle_risk("Denver", hazards=["uv"], verbose=False)
+    assert risk.feature_pack["risk_scores"][0]["hazard"] == "uv"
+    assert any(action.startswith("Wear SPF 30+ sunscreen") for action in risk.response.sections["actions"])
@@ -0,0 +1,188 @@
+from __future__ import annotations
+
+import json
+import shlex
+import sys
+
+import pytest
+
+from wx import cli, providers as providers_module, watch as watch_module
+from wx.cache import CachingProvider, FetchCache
+from wx.watch import (
+    HookError,
+    WatchSnapshot,
+    diff_snapshots,
+    new_severe_alerts,
+    parse_hook,
+    parse_interval,
+    run_hook,
+    run_watch,
+    snapshot_line,
+)
+
+
+class _Provider:
+    name = "stub"
+
+    def __init__(self, readings: list[tuple[float, list[dict]]]) -> None:
+        self.readings = readings
+        self.calls = 0
+
+    def current(self, lat, lon):
+        temp, _ = self.readings[min(self.calls, len(self.readings) - 1)]
+        self.calls += 1
+        return {"temp": temp, "wind": 10.0, "conditions": "Cloudy"}
+
+    def alerts(self, lat, lon):
+        return self.readings[min(self.calls, len(self.readings)) - 1][1]
+
+    def forecast(self, lat, lon, *, hours=48):
+        return {"temperature_2m": [10.0, 14.0], "precipitation_probability": [10, 30]}
+
+
+def test_parse_interval_units_and_minimum() -> None:
+    assert parse_interval("90s") == 90
+    assert parse_interval("10m") == 600
+    assert parse_interval("15") == 900
+    assert parse_interval("1h") == 3600
+    with pytest.raises(ValueError):
+        parse_interval("30s")
+    with pytest.raises(ValueError):
+        parse_interval("soon")
+
+
+def test_diff_highlights_alerts_temperature_and_outlook() -> None:
+    warning = {"event": "Winter Storm Warning", "severity": "Severe", "expires_iso": "2026-10-17T06:00Z"}
+    advisory = {"event": "Wind Advisory", "severity": "Moderate", "expires_iso": "2026-10-16T20:00Z"}
+    before = WatchSnapshot(
+        place="Denver, CO",
+        current={"temp": 5.0},
+        alerts=[advisory],
+        outlook={"temp_max": 8.0, "precip_prob_max": 20.0},
+    )
+    after = WatchSnapshot(
+        place="Denver, CO",
+        current={"temp": -2.0},
+        alerts=[warning],
+        outlook={"temp_max": 8.5, "precip_prob_max": 70.0},
+    )
+
+    changes = diff_snapshots(before, after, "metric")
+
+    kinds = {change.kind: change for change in changes}
+    assert kinds["alert_new"].important and "Winter Storm Warning" in kinds["alert_new"].message
+    assert "Wind Advisory" in kinds["alert_ended"].message
+    assert kinds["temperature"].message == "Temperature dropped 5°C → -2°C"
+    assert kinds["temperature"].important
+    assert [change.message for change in changes if change.kind == "outlook"] == [
+        "Next-12h precip chance 20% → 70%"
+    ]
+    assert diff_snapshots(None, after, "metric") == []
+    assert diff_snapshots(after, after, "metric") == []
+
+
+def test_run_watch_refetches_past_cache_and_diffs() -> None:
+    inner = _Provider([(10.0, []), (14.0, [{"event": "Flood Watch", "severity": "Moderate"}])])
+    provider = CachingProvider(inner, FetchCache())
+    emitted = []
+    sleeps = []
+
+    run_watch(
+        provider,
+        {"resolved": "Portland, OR", "lat": 45.5, "lon": -122.7},
+        units="metric",
+        interval=120,
+        count=2,
+        emit=lambda snapshot, changes: emitted.append((snapshot, changes)),
+        sleep=sleeps.append,
+    )
+
+    assert inner.calls == 2 and sleeps == [120]
+    assert provider.refresh is False
+    first, second = emitted
+    assert first[0].place == "Portland, OR" and first[1] == []
+    assert first[0].outlook == {"temp_max": 14.0, "temp_min": 10.0, "precip_prob_max": 30.0}
+    assert {change.kind for change in second[1]} == {"alert_new", "temperature"}
+
+
+def test_new_severe_alert_triggers_story_once() -> None:
+    warning = {"event": "Tornado Warning", "severity": "Extreme", "expires_iso": "2026-10-16T21:00Z"}
+    advisory = {"event": "Wind Advisory", "severity": "Moderate"}
+    inner = _Provider([(10.0, []), (10.0, [warning, advisory]), (10.0, [warning, advisory])])
+    regenerated = []
+
+    run_watch(
+        inner,
+        {"resolved": "Norman, OK", "lat": 35.2, "lon": -97.4},
+        units="metric",
+        count=3,
+        emit=lambda snapshot, changes: None,
+        sleep=lambda seconds: None,
+        on_severe=lambda snapshot, alerts: regenerated.append(alerts),
+    )
+
+    assert regenerated == [[warning]]
+    assert new_severe_alerts(None, WatchSnapshot(place="Norman, OK", alerts=[warning])) == []
+
+
+def test_failed_alert_fetch_does_not_end_or_renew_alerts() -> None:
+    warning = {"event": "Flood Warning", "severity": "Severe", "expires_iso": "2026-10-17T06:00Z"}
+    inner = _Provider([(10.0, [warning]), (10.0, None), (10.0, [warning])])
+    emitted = []
+
+    run_watch(
+        inner,
+        {"resolved": "Tulsa, OK", "lat": 36.2, "lon": -96.0},
+        units="metric",
+        count=3,
+        emit=lambda snapshot, changes: emitted.append((snapshot, changes)),
+        sleep=lambda seconds: None,
+        on_severe=lambda snapshot, alerts: pytest.fail("the outage re-announced a known alert"),
+    )
+
+    outage = emitted[1][0]
+    assert outage.alerts_unavailable and "alerts unavailable" in snapshot_line(outage, "metric")
+    assert [changes for _, changes in emitted] == [[], [], []]
+
+
+def test_run_hook_passes_json_on_stdin_and_reports_failures(tmp_path) -> None:
+    out = tmp_path / "payload.json"
+    copy = (sys.executable, "-c", f"import sys; open({str(out)!r}, 'w').write(sys.stdin.read())")
+
+    run_hook(copy, '{"kind": "watch"}')
+
+    assert json.loads(out.read_text()) == {"kind": "watch"}
+    assert parse_hook("~/bin/awning 'retract now'") == ("~/bin/awning", "retract now")
+    with pytest.raises(ValueError):
+        parse_hook("  ")
+    with pytest.raises(HookError, match="exited with status 4: valve stuck"):
+        run_hook((sys.executable, "-c", "import sys; sys.stderr.write('valve stuck'); sys.exit(4)"), "{}")
+    with pytest.raises(HookError, match="timed out after 0.2s"):
+        run_hook((sys.executable, "-c", "import time; time.sleep(5)"), "{}", timeout=0.2)
+
+
+def test_watch_runs_change_and_alert_hooks(cli_runner, config_dir, state_dir, tmp_path, monkeypatch) -> None:
+    warning = {"event": "Flood Warning", "severity": "Severe", "expires_iso": "2026-10-17T06:00Z"}
+    feeds = iter([[], [warning]])
+    place = {"resolved": "Tulsa, OK", "lat": 36.15, "lon": -95.99, "tz": "America/Chicago"}
+    monkeypatch.setattr(providers_module, "get_point_context", lambda name, offline=False: place)
+    monkeypatch.setattr(providers_module, "get_quick_obs", lambda lat, lon, offline=False: {"temp": 10.0})
+    monkeypatch.setattr(providers_module, "get_quick_alerts", lambda lat, lon, offline=False: next(feeds))
+    monkeypatch.setattr(providers_module, "get_hourly_forecast", lambda lat, lon, hours=48, offline=False: {})
+    monkeypatch.setattr(cli, "run_watch", lambda *args, **kwargs: watch_module.run_watch(*args, sleep=lambda s: None, **kwargs))
+    script = tmp_path / "hook.py"
+    script.write_text("import sys; open(sys.argv[1], 'a').write(sys.stdin.read() + '\\n')")
+
+    def hook(name: str) -> str:
+        return shlex.join([sys.executable, str(script), str(tmp_path / name)])
+
+    result = cli_runner.invoke(
+        cli.app,
+        ["", "watch", "Tulsa", "--count", "2", "--no-story", "--on-change", hook("change"), "--on-alert", hook("alert")],
+    )
+
+    assert result.exit_code == 0, result.output
+    (change,) = [json.loads(line) for line in (tmp_path / "change").read_text().splitlines()]
+    assert change["kind"] == "watch" and change["data"]["alerts"] == [warning]
+    assert [item["kind"] for item in change["data"]["changes"]] == ["alert_new"]
+    assert (tmp_path / "alert").read_text() == (tmp_path / "change").read_text()
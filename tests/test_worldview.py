@@ -80,6 +80,7 @@ def test_worldview_online_mocked(settings: Settings) -> None:
         patch("wx.orchestrator.fetch_openmeteo_points") as mock_fetch_points,
         patch("wx.orchestrator.fetch_us_alerts") as mock_fetch_us,
         patch("wx.orchestrator.fetch_eu_alerts") as mock_fetch_eu,
+        patch("wx.orchestrator.fetch_nhc_active_storms", return_value=[]),
     ):
         mock_fetch_points.side_effect = [mock_us_obs, mock_eu_obs]
         mock_fetch_us.return_value = mock_us_alerts
@@ -188,7 +189,9 @@ def test_worldview_json_output(orchestrator: Orchestrator) -> None:
 
     render_worldview(worldview, console=console, json_mode=True, verbose=False)
 
-    output = json.loads(output_buffer.getvalue())
+    document = json.loads(output_buffer.getvalue())
+    assert document["schema_version"] == 2 and document["kind"] == "worldview"
+    output = document["data"]
 
     # Verify JSON schema
     assert "regions" in output
@@ -213,6 +216,7 @@ def test_worldview_timeout_handling(settings: Settings) -> None:
         patch("wx.orchestrator.fetch_openmeteo_points") as mock_fetch_points,
         patch("wx.orchestrator.fetch_us_alerts") as mock_fetch_us,
         patch("wx.orchestrator.fetch_eu_alerts") as mock_fetch_eu,
+        patch("wx.orchestrator.fetch_nhc_active_storms", return_value=[]),
     ):
         # Simulate partial failures
         mock_fetch_points.side_effect = [[], []]  # Empty results simulate failure
@@ -313,6 +317,7 @@ def test_worldview_severe_only(settings: Settings) -> None:
         patch("wx.orchestrator.fetch_openmeteo_points") as mock_fetch_points,
         patch("wx.orchestrator.fetch_us_alerts") as mock_fetch_us,
         patch("wx.orchestrator.fetch_eu_alerts") as mock_fetch_eu,
+        patch("wx.orchestrator.fetch_nhc_active_storms", return_value=[]),
     ):
         mock_fetch_points.side_effect = [mock_us_obs, mock_eu_obs]
 
@@ -341,3 +346,76 @@ def test_worldview_severe_only(settings: Settings) -> None:
         assert len(us_region.alerts) > 0
         for alert_summary in us_region.alerts:
             assert _is_severe_weather(alert_summary["event"])
+
+
+def test_worldview_region_filter_offline(orchestrator: Orchestrator) -> None:
+    """Test --region narrows the synthetic worldview."""
+    worldview = orchestrator.handle_worldview(regions=["asia"])
+
+    assert [region.name for region in worldview.regions] == ["Asia"]
+    assert "samples_asia" in worldview.meta
+
+
+def test_worldview_custom_cities(settings: Settings) -> None:
+    """Test profile-defined cities replace the built-in sample set."""
+    from wx.profile import WorldCity
+
+    settings.offline = False
+    orchestrator = Orchestrator(settings)
+    cities = [
+        WorldCity(name="Reykjavik", lat=64.1, lon=-21.9),
+        WorldCity(name="Cabin", lat=47.1, lon=-121.5, region="family"),
+        WorldCity(name="Atlantis"),
+    ]
+
+    with (
+        patch("wx.orchestrator.fetch_openmeteo_points") as mock_fetch_points,
+        patch("wx.orchestrator.fetch_us_alerts") as mock_fetch_us,
+        patch("wx.orchestrator.fetch_eu_alerts") as mock_fetch_eu,
+        patch("wx.orchestrator.fetch_nhc_active_storms", return_value=[]),
+        patch("wx.providers.get_point_context", return_value=None),
+    ):
+        mock_fetch_points.side_effect = lambda points, **_: [
+            Observation(lat=lat, lon=lon, temp=5.0) for lat, lon in points
+        ]
+        mock_fetch_eu.return_value = []
+
+        worldview = orchestrator.handle_worldview(cities=cities)
+
+        assert [region.name for region in worldview.regions] == ["Europe", "Family"]
+        assert worldview.meta["samples_eu"] == 1
+        assert worldview.meta["unresolved_cities"] == ["Atlantis"]
+        mock_fetch_us.assert_not_called()
+
+        filtered = orchestrator.handle_worldview(cities=cities, regions=["family"])
+        assert [region.name for region in filtered.regions] == ["Family"]
+
+
+def test_worldview_ranks_anomalies(settings: Settings, tmp_path) -> None:
+    """Test cities are ranked by departure from climatological normal."""
+    settings.offline = False
+    settings.state_file = tmp_path / "last_query.json"
+    orchestrator = Orchestrator(settings)
+
+    us_obs = [
+        Observation(lat=47.6, lon=-122.3, temp=20.0, tmax_today=25.0, tmin_today=15.0),
+        Observation(lat=33.4, lon=-112.1, temp=30.0, tmax_today=30.0, tmin_today=20.0),
+    ]
+    eu_obs = [Observation(lat=51.5, lon=-0.1, temp=8.0, tmax_today=10.0, tmin_today=6.0)]
+    flat_normals = {"tmax": [14.0] * 12, "tmin": [6.0] * 12}
+
+    with (
+        patch("wx.orchestrator.fetch_openmeteo_points") as mock_fetch_points,
+        patch("wx.orchestrator.fetch_us_alerts", return_value=[]),
+        patch("wx.orchestrator.fetch_eu_alerts", return_value=[]),
+        patch("wx.orchestrator.fetch_nhc_active_storms", return_value=[]),
+        patch("wx.normals.fetch_monthly_normals", return_value=flat_normals),
+    ):
+        mock_fetch_points.side_effect = [us_obs, eu_obs]
+        worldview = orchestrator.handle_worldview()
+
+    names = [item["name"] for item in worldview.anomalies]
+    assert names == ["Phoenix", "Seattle", "London"]
+    assert worldview.anomalies[0]["anomaly"] == 15.0
+    assert worldview.anomalies[0]["region"] == "US"
+    assert (tmp_path / "normals.json").exists()
@@ -0,0 +1,147 @@
+"""Air quality: US AQI categories and health decisions when the air is unhealthy."""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime
+from typing import Any
+
+from .clock import clock_label, zone
+
+AIR_QUALITY_HOURS = 24
+SENSITIVE_AQI = 101
+UNHEALTHY_AQI = 151
+AQI_BANDS = (
+    (50, "Good"),
+    (100, "Moderate"),
+    (150, "Unhealthy for Sensitive Groups"),
+    (200, "Unhealthy"),
+    (300, "Very Unhealthy"),
+)
+POLLUTANTS = {"us_aqi_pm2_5": "PM2.5", "us_aqi_pm10": "PM10", "us_aqi_ozone": "ozone"}
+_AIR_WORDS = re.compile(r"\b(aqi|air quality|air|smoke|smoky|ozone|pollution|pm2\.?5|asthma)\b", re.I)
+_AIR_DECISION = re.compile(r"\b(aqi|air quality|smoke|ozone|pm2\.?5|pollution)\b", re.I)
+
+
+def wants_air_quality(text: str | None) -> bool:
+    """True when a focus or question is about the air (``--focus air``, "is it smoky?")."""
+    return bool(text) and bool(_AIR_WORDS.search(text))
+
+
+def aqi_category(value: float | None) -> str | None:
+    if value is None:
+        return None
+    return next((name for limit, name in AQI_BANDS if value <= limit), "Hazardous")
+
+
+def air_quality_pack(
+    hourly: dict[str, list[Any]] | None, *, hours: int = AIR_QUALITY_HOURS, now: datetime | None = None
+) -> dict[str, Any] | None:
+    """Current AQI, the next ``hours`` peak and the window above the sensitive-groups threshold."""
+
+    if not hourly:
+        return None
+    start = (now or datetime.now(UTC)).replace(minute=0, second=0, microsecond=0)
+    rows = []
+    for idx, stamp in enumerate(hourly.get("time") or []):
+        moment = datetime.fromisoformat(str(stamp))
+        moment = moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+        row = _row(hourly, idx, moment)
+        if start <= moment and row["aqi"] is not None:
+            rows.append(row)
+    rows = rows[:hours]
+    if not rows:
+        return None
+    peak = max(rows, key=lambda row: row["aqi"])
+    pack: dict[str, Any] = {"now": rows[0], "peak": peak, "elevated": None}
+    elevated = [row for row in rows if row["aqi"] >= SENSITIVE_AQI]
+    if elevated:
+        pack["elevated"] = {
+            "start_iso": elevated[0]["time_iso"],
+            "end_iso": elevated[-1]["time_iso"],
+            "max_aqi": peak["aqi"],
+            "category": peak["category"],
+            "pollutant": peak["pollutant"],
+        }
+    return pack
+
+
+def health_actions(pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Decisions for an elevated AQI window, worded for who should act."""
+
+    elevated = (pack or {}).get("elevated")
+    if not elevated:
+        return []
+    pollutant = elevated["pollutant"] or "air pollution"
+    start, end = _when(elevated["start_iso"], tz_name), _when(elevated["end_iso"], tz_name)
+    window = f"around {start}" if start == end else f"from {start} to {end}"
+    reason = f"{pollutant} AQI up to {elevated['max_aqi']:.0f} ({elevated['category']})"
+    if elevated["max_aqi"] >= UNHEALTHY_AQI:
+        actions = [f"Move runs and strenuous outdoor activity indoors {window}: {reason}."]
+        actions.append("Keep windows closed and run a HEPA filter or AC on recirculate if you have one.")
+    else:
+        actions = [
+            f"Limit outdoor running and other exertion {window} if you have asthma, heart or lung"
+            f" conditions, or are older or a child: {reason}."
+        ]
+    if pollutant == "ozone":
+        actions.append("Ozone peaks in the afternoon; exercise outside in the early morning instead.")
+    return actions
+
+
+def apply_air_quality(response, pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Add health decisions the model left out when the AQI is elevated; returns those added.
+
+    Always records a one-line ``air_quality`` section when air quality data is present.
+    """
+
+    if not pack or not pack.get("now"):
+        return []
+    sections = response.sections
+    sections["air_quality"] = _summary_line(pack, tz_name)
+    decision_key = "answer" if "answer" in sections else "actions"
+    decisions = sections.get(decision_key)
+    decision_text = " ".join(decisions) if isinstance(decisions, list) else str(decisions or "")
+    added = [] if _AIR_DECISION.search(decision_text) else health_actions(pack, tz_name)
+    if added and decision_key == "answer":
+        sections["answer"] = " ".join([decision_text.strip(), added[0]]).strip()
+        added = added[:1]
+    elif added:
+        actions = [item for item in decisions if isinstance(item, str)] if isinstance(decisions, list) else []
+        sections["actions"] = actions + added
+    response.meta = {**(response.meta or {}), "air_quality": {"aqi": pack["now"]["aqi"], "added": added}}
+    return added
+
+
+def _summary_line(pack: dict[str, Any], tz_name: str | None) -> str:
+    now, peak = pack["now"], pack["peak"]
+    text = f"AQI {now['aqi']:.0f} now ({now['category']})"
+    if now["pollutant"]:
+        text += f", mostly {now['pollutant']}"
+    if peak["aqi"] > now["aqi"]:
+        text += f"; peaking at {peak['aqi']:.0f} ({peak['category']}) around {_when(peak['time_iso'], tz_name)}"
+    return text + "."
+
+
+def _when(iso: str, tz_name: str | None) -> str:
+    return clock_label(datetime.fromisoformat(iso), zone(tz_name))
+
+
+def _row(hourly: dict[str, list[Any]], idx: int, moment: datetime) -> dict[str, Any]:
+    def at(key: str) -> float | None:
+        series = hourly.get(key) or []
+        value = series[idx] if idx < len(series) else None
+        return float(value) if isinstance(value, (int, float)) and not isinstance(value, bool) else None
+
+    aqi = at("us_aqi")
+    parts = {name: at(key) for key, name in POLLUTANTS.items()}
+    scored = [(value, name) for name, value in parts.items() if value is not None]
+    return {
+        "time_iso": moment.isoformat(),
+        "aqi": aqi,
+        "category": aqi_category(aqi),
+        "pollutant": max(scored)[1] if scored else None,
+        "pm2_5": at("pm2_5"),
+        "pm10": at("pm10"),
+        "ozone": at("ozone"),
+    }
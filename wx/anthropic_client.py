@@ -0,0 +1,110 @@
+"""Thin Anthropic Messages API client used by wx forecaster."""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from typing import Any
+
+import httpx
+
+from .httpclient import with_retries
+
+ANTHROPIC_VERSION = "2023-06-01"
+DEFAULT_TIMEOUT = 60.0
+
+
+class AnthropicError(RuntimeError):
+    """Raised when the Anthropic API cannot fulfil a request."""
+
+
+@dataclass(frozen=True)
+class AnthropicConfig:
+    """Configuration required to talk to Anthropic."""
+
+    api_key: str
+    model: str
+    temperature: float
+    max_tokens: int
+    base_url: str = "https://api.anthropic.com"
+    timeout: float = DEFAULT_TIMEOUT
+
+    @property
+    def messages_url(self) -> str:
+        return f"{self.base_url.rstrip('/')}/v1/messages"
+
+
+@dataclass(slots=True)
+class AnthropicResponse:
+    """Structured data returned from Anthropic."""
+
+    text: str
+    model: str
+    raw: dict[str, Any]
+    usage: dict[str, Any] | None
+
+
+def create_message(system: str, prompt: str, *, config: AnthropicConfig) -> AnthropicResponse:
+    """Send one system + user turn and return the JSON object the model produced."""
+
+    headers = {
+        "x-api-key": config.api_key,
+        "anthropic-version": ANTHROPIC_VERSION,
+        "content-type": "application/json",
+    }
+    payload = {
+        "model": config.model,
+        "system": system,
+        "messages": [{"role": "user", "content": prompt}],
+        "temperature": config.temperature,
+        "max_tokens": config.max_tokens,
+    }
+    try:
+        response = with_retries(
+            lambda: httpx.post(config.messages_url, headers=headers, json=payload, timeout=config.timeout),
+            retry_read_timeouts=False,
+        )
+        response.raise_for_status()
+        data = response.json()
+    except httpx.HTTPStatusError as exc:
+        raise AnthropicError(f"Anthropic HTTP {exc.response.status_code}") from exc
+    except (httpx.TimeoutException, httpx.TransportError) as exc:
+        raise AnthropicError("Anthropic request failed") from exc
+    except json.JSONDecodeError as exc:
+        raise AnthropicError("Anthropic returned invalid JSON") from exc
+
+    blocks = data.get("content") if isinstance(data, dict) else None
+    text = "".join(
+        block.get("text", "") for block in blocks or [] if isinstance(block, dict) and block.get("type") == "text"
+    ).strip()
+    if not text:
+        raise AnthropicError("Anthropic response missing content")
+    return AnthropicResponse(
+        text=extract_json_object(text),
+        model=data.get("model", config.model),
+        raw=data,
+        usage=data.get("usage"),
+    )
+
+
+def extract_json_object(text: str) -> str:
+    """Trim prose or code fences around the outermost JSON object.
+
+    The Messages API has no JSON mode, so the model occasionally wraps the object
+    it was asked for ("Here is the briefing: {...}").
+    """
+
+    start = text.find("{")
+    end = text.rfind("}")
+    if start == -1 or end <= start:
+        return text
+    return text[start : end + 1]
+
+
+__all__ = [
+    "AnthropicConfig",
+    "AnthropicError",
+    "AnthropicResponse",
+    "create_message",
+    "extract_json_object",
+]
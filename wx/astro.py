@@ -0,0 +1,256 @@
+"""Sun and moon times computed locally: sunrise/sunset, twilight, golden hour and moon phase.
+
+Positions use the Astronomical Almanac's low-precision formulas (about a minute of error for
+the sun, a few minutes for the moon), which is plenty for planning and needs no network.
+"""
+
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass, field
+from datetime import UTC, date, datetime, time, timedelta
+from typing import Any
+
+from .clock import zone
+
+# Apparent altitude (degrees) of the sun's centre at each event, allowing for refraction.
+SUNRISE_ALT = -0.833
+CIVIL_ALT = -6.0
+# Photographers' golden hour: the sun between 4° below and 6° above the horizon.
+GOLDEN_LOW_ALT = -4.0
+GOLDEN_HIGH_ALT = 6.0
+# Geocentric altitude of the moon's centre at rise/set (parallax minus refraction and semidiameter).
+MOONRISE_ALT = 0.125
+SYNODIC_MONTH = 29.530588853
+# Julian date of the new moon on 2000-01-06.
+REFERENCE_NEW_MOON_JD = 2451550.1
+STEP_MINUTES = 5
+MOON_PHASES = (
+    (0.0339, "New Moon"),
+    (0.216, "Waxing Crescent"),
+    (0.284, "First Quarter"),
+    (0.466, "Waxing Gibbous"),
+    (0.534, "Full Moon"),
+    (0.716, "Waning Gibbous"),
+    (0.784, "Last Quarter"),
+    (0.966, "Waning Crescent"),
+)
+# Mean cloud cover (%) during golden hour and what it means for the light.
+GOLDEN_CLOUD_BANDS = ((20, "clear"), (70, "broken clouds, best colour"))
+SUN_MAX_DAYS = 7
+ASTRO_FOCUS = ("photography", "photo", "golden hour", "sunset", "sunrise", "stargazing", "astronomy")
+
+
+@dataclass(slots=True)
+class SunReport:
+    """``wx sun``: one ``astro_pack`` per local day for a place."""
+
+    place: str
+    tz: str | None = None
+    days: list[dict[str, Any]] = field(default_factory=list)
+    sources: list[str] = field(default_factory=list)
+
+
+@dataclass(slots=True)
+class AstroDay:
+    """Sun and moon events for one local calendar day; times are aware datetimes in UTC."""
+
+    day: date
+    dawn: datetime | None = None
+    sunrise: datetime | None = None
+    solar_noon: datetime | None = None
+    sunset: datetime | None = None
+    dusk: datetime | None = None
+    golden_morning: tuple[datetime, datetime] | None = None
+    golden_evening: tuple[datetime, datetime] | None = None
+    moonrise: datetime | None = None
+    moonset: datetime | None = None
+    moon: dict[str, Any] = field(default_factory=dict)
+    # "polar day" or "polar night" when the sun never crosses the horizon.
+    polar: str | None = None
+
+    @property
+    def day_length(self) -> timedelta | None:
+        if self.sunrise and self.sunset and self.sunset > self.sunrise:
+            return self.sunset - self.sunrise
+        return {"polar day": timedelta(hours=24), "polar night": timedelta(0)}.get(self.polar or "")
+
+
+def is_astro_focus(focus: str | None) -> bool:
+    return bool(focus) and focus.strip().lower() in ASTRO_FOCUS
+
+
+def julian_day(when: datetime) -> float:
+    if when.tzinfo is None:
+        when = when.replace(tzinfo=UTC)
+    return when.timestamp() / 86400 + 2440587.5
+
+
+def sun_altitude(lat: float, lon: float, when: datetime) -> float:
+    """Altitude of the sun's centre in degrees (no refraction)."""
+
+    n = julian_day(when) - 2451545.0
+    mean_long = 280.460 + 0.9856474 * n
+    anomaly = math.radians(357.528 + 0.9856003 * n)
+    ecliptic = math.radians(mean_long + 1.915 * math.sin(anomaly) + 0.020 * math.sin(2 * anomaly))
+    return _altitude(lat, lon, when, ecliptic, 0.0)
+
+
+def moon_altitude(lat: float, lon: float, when: datetime) -> float:
+    """Geocentric altitude of the moon's centre in degrees."""
+
+    t = (julian_day(when) - 2451545.0) / 36525
+
+    def sin_deg(degrees: float) -> float:
+        return math.sin(math.radians(degrees))
+
+    longitude = (
+        218.32
+        + 481267.881 * t
+        + 6.29 * sin_deg(135.0 + 477198.87 * t)
+        - 1.27 * sin_deg(259.3 - 413335.36 * t)
+        + 0.66 * sin_deg(235.7 + 890534.22 * t)
+        + 0.21 * sin_deg(269.9 + 954397.74 * t)
+        - 0.19 * sin_deg(357.5 + 35999.05 * t)
+        - 0.11 * sin_deg(186.5 + 966404.03 * t)
+    )
+    latitude = (
+        5.13 * sin_deg(93.3 + 483202.02 * t)
+        + 0.28 * sin_deg(228.2 + 960400.89 * t)
+        - 0.28 * sin_deg(318.3 + 6003.15 * t)
+        - 0.17 * sin_deg(217.6 - 407332.21 * t)
+    )
+    return _altitude(lat, lon, when, math.radians(longitude), math.radians(latitude))
+
+
+def moon_phase(when: datetime) -> dict[str, Any]:
+    """Mean lunar phase: age in days, illuminated fraction (0–1) and its name."""
+
+    age = ((julian_day(when) - REFERENCE_NEW_MOON_JD) / SYNODIC_MONTH) % 1
+    name = next((label for limit, label in MOON_PHASES if age < limit), "New Moon")
+    return {
+        "name": name,
+        "age_days": round(age * SYNODIC_MONTH, 1),
+        "illumination": round((1 - math.cos(2 * math.pi * age)) / 2, 2),
+    }
+
+
+def astro_day(lat: float, lon: float, day: date, tz_name: str | None = None) -> AstroDay:
+    """Sun and moon events between local midnight and the next for ``day``."""
+
+    start = datetime.combine(day, time(0), tzinfo=zone(tz_name)).astimezone(UTC)
+    end = datetime.combine(day + timedelta(days=1), time(0), tzinfo=zone(tz_name)).astimezone(UTC)
+    steps = int((end - start).total_seconds() // (STEP_MINUTES * 60))
+    moments = [start + timedelta(minutes=STEP_MINUTES * idx) for idx in range(steps + 1)]
+    sun = [(moment, sun_altitude(lat, lon, moment)) for moment in moments]
+    moon = [(moment, moon_altitude(lat, lon, moment)) for moment in moments]
+
+    result = AstroDay(day=day, moon=moon_phase(start + (end - start) / 2))
+    result.sunrise = _crossing(sun, SUNRISE_ALT, rising=True)
+    result.sunset = _crossing(sun, SUNRISE_ALT, rising=False)
+    result.dawn = _crossing(sun, CIVIL_ALT, rising=True)
+    result.dusk = _crossing(sun, CIVIL_ALT, rising=False)
+    result.solar_noon = max(sun, key=lambda sample: sample[1])[0]
+    result.moonrise = _crossing(moon, MOONRISE_ALT, rising=True)
+    result.moonset = _crossing(moon, MOONRISE_ALT, rising=False)
+    if result.sunrise is None and result.sunset is None:
+        highest = max(altitude for _, altitude in sun)
+        result.polar = "polar day" if highest > SUNRISE_ALT else "polar night"
+
+    golden_start = _crossing(sun, GOLDEN_LOW_ALT, rising=True)
+    golden_end = _crossing(sun, GOLDEN_HIGH_ALT, rising=True)
+    if golden_start and golden_end:
+        result.golden_morning = (golden_start, golden_end)
+    golden_start = _crossing(sun, GOLDEN_HIGH_ALT, rising=False)
+    golden_end = _crossing(sun, GOLDEN_LOW_ALT, rising=False)
+    if golden_start and golden_end:
+        result.golden_evening = (golden_start, golden_end)
+    return result
+
+
+def golden_cloud_cover(
+    window: tuple[datetime, datetime] | None, hourly: dict[str, list[Any]] | None
+) -> float | None:
+    """Mean forecast cloud cover (%) over the hours a golden-hour window touches."""
+
+    if window is None or not hourly:
+        return None
+    start = window[0].replace(minute=0, second=0, microsecond=0)
+    values = []
+    for stamp, cover in zip(hourly.get("time") or [], hourly.get("cloud_cover") or [], strict=False):
+        moment = datetime.fromisoformat(str(stamp))
+        moment = moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+        if start <= moment <= window[1] and isinstance(cover, (int, float)):
+            values.append(float(cover))
+    return round(sum(values) / len(values)) if values else None
+
+
+def golden_light(cloud_cover: float | None) -> str | None:
+    """What the sky means for golden-hour light: clear, broken clouds or overcast."""
+
+    if cloud_cover is None:
+        return None
+    return next((label for limit, label in GOLDEN_CLOUD_BANDS if cloud_cover <= limit), "overcast")
+
+
+def astro_pack(day: AstroDay, hourly: dict[str, list[Any]] | None = None) -> dict[str, Any]:
+    """Feature Pack ``astro`` block: ISO times, day length, golden hours with cloud cover, moon."""
+
+    def iso(value: datetime | None) -> str | None:
+        return value.isoformat() if value else None
+
+    def golden(window: tuple[datetime, datetime] | None) -> dict[str, Any] | None:
+        if window is None:
+            return None
+        cover = golden_cloud_cover(window, hourly)
+        return {
+            "start_iso": iso(window[0]),
+            "end_iso": iso(window[1]),
+            "cloud_cover": cover,
+            "light": golden_light(cover),
+        }
+
+    length = day.day_length
+    return {
+        "date": day.day.isoformat(),
+        "dawn_iso": iso(day.dawn),
+        "sunrise_iso": iso(day.sunrise),
+        "solar_noon_iso": iso(day.solar_noon),
+        "sunset_iso": iso(day.sunset),
+        "dusk_iso": iso(day.dusk),
+        "day_length_minutes": round(length.total_seconds() / 60) if length is not None else None,
+        "golden_morning": golden(day.golden_morning),
+        "golden_evening": golden(day.golden_evening),
+        "moonrise_iso": iso(day.moonrise),
+        "moonset_iso": iso(day.moonset),
+        "moon": day.moon,
+        "polar": day.polar,
+    }
+
+
+def _altitude(lat: float, lon: float, when: datetime, ecliptic_long: float, ecliptic_lat: float) -> float:
+    obliquity = math.radians(23.439 - 0.0000004 * (julian_day(when) - 2451545.0))
+    sin_dec = math.sin(ecliptic_lat) * math.cos(obliquity) + (
+        math.cos(ecliptic_lat) * math.sin(obliquity) * math.sin(ecliptic_long)
+    )
+    declination = math.asin(sin_dec)
+    right_ascension = math.atan2(
+        math.sin(ecliptic_long) * math.cos(obliquity) - math.tan(ecliptic_lat) * math.sin(obliquity),
+        math.cos(ecliptic_long),
+    )
+    sidereal = math.radians((280.46061837 + 360.98564736629 * (julian_day(when) - 2451545.0) + lon) % 360)
+    hour_angle = sidereal - right_ascension
+    phi = math.radians(lat)
+    sin_alt = math.sin(phi) * sin_dec + math.cos(phi) * math.cos(declination) * math.cos(hour_angle)
+    return math.degrees(math.asin(max(-1.0, min(1.0, sin_alt))))
+
+
+def _crossing(samples: list[tuple[datetime, float]], threshold: float, *, rising: bool) -> datetime | None:
+    """First time the altitude crosses ``threshold`` upward (or downward), interpolated."""
+
+    for (t0, a0), (t1, a1) in zip(samples, samples[1:], strict=False):
+        crossed = a0 < threshold <= a1 if rising else a0 >= threshold > a1
+        if crossed:
+            fraction = (threshold - a0) / (a1 - a0)
+            return t0 + (t1 - t0) * fraction
+    return None
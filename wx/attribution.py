@@ -0,0 +1,79 @@
+"""Data attribution: who supplied the data behind a result, with the credit each licence asks for."""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from dataclasses import asdict, dataclass
+from typing import Any
+
+
+@dataclass(frozen=True, slots=True)
+class Source:
+    """A data provider and the credit its terms ask for."""
+
+    credit: str
+    license: str
+    url: str
+    # Licences that require naming the licence alongside the credit (CC BY, ODbL).
+    cite_license: bool = False
+
+
+SOURCES = {
+    "nws": Source("NWS/NOAA", "US public domain", "https://www.weather.gov/"),
+    "spc": Source("NOAA Storm Prediction Center", "US public domain", "https://www.spc.noaa.gov/"),
+    "awc": Source("NOAA Aviation Weather Center", "US public domain", "https://aviationweather.gov/"),
+    "open-meteo": Source("Open-Meteo.com", "CC BY 4.0", "https://open-meteo.com/", cite_license=True),
+    "geonames": Source("GeoNames", "CC BY 4.0", "https://www.geonames.org/", cite_license=True),
+    "nasa-power": Source("NASA POWER", "US public domain", "https://power.larc.nasa.gov/"),
+    "rainviewer": Source("RainViewer", "RainViewer API terms", "https://www.rainviewer.com/api.html"),
+    "osm": Source(
+        "© OpenStreetMap contributors", "ODbL 1.0", "https://www.openstreetmap.org/copyright", cite_license=True
+    ),
+}
+
+# Feature Pack blocks fetched from a fixed source, whichever weather provider is active.
+FEATURE_SOURCES = {
+    "place": ("geonames",),
+    "alerts_quick": ("nws",),
+    "alerts_area": ("nws",),
+    "spc_outlook": ("spc",),
+    "spc_probabilities": ("spc",),
+    "air_quality": ("open-meteo",),
+    "pollen": ("open-meteo",),
+    "profile_quick": ("open-meteo",),
+    "marine": ("open-meteo",),
+    "aviation": ("awc",),
+    "normals": ("nasa-power",),
+    "ensemble": ("open-meteo",),
+}
+# Blocks built from the active provider's observations or forecast.
+PROVIDER_BLOCKS = ("obs_quick", "periods", "window_summary", "uv")
+
+
+def feature_pack_sources(feature_pack: dict[str, Any], provider: Iterable[str] = ("open-meteo",)) -> list[str]:
+    """Source keys behind a Feature Pack, weather provider first."""
+
+    keys: list[str] = []
+    if any(feature_pack.get(block) for block in PROVIDER_BLOCKS):
+        keys.extend(provider)
+    for block, sources in FEATURE_SOURCES.items():
+        if feature_pack.get(block):
+            keys.extend(sources)
+    return list(dict.fromkeys(key for key in keys if key in SOURCES))
+
+
+def attribution_meta(keys: Iterable[str]) -> list[dict[str, Any]]:
+    """JSON ``meta.attribution`` entries for source keys."""
+
+    return [{"source": key, **asdict(SOURCES[key])} for key in keys if key in SOURCES]
+
+
+def credit_line(keys: Iterable[str]) -> str | None:
+    """Footer such as ``Data: NWS/NOAA, Open-Meteo.com (CC BY 4.0), GeoNames (CC BY 4.0)``."""
+
+    credits = [
+        f"{SOURCES[key].credit} ({SOURCES[key].license})" if SOURCES[key].cite_license else SOURCES[key].credit
+        for key in dict.fromkeys(keys)
+        if key in SOURCES
+    ]
+    return f"Data: {', '.join(credits)}" if credits else None
@@ -0,0 +1,516 @@
+"""Aviation weather: decode METAR and TAF reports and rate flight categories, and pick out the
+SIGMETs, AIRMETs, G-AIRMETs and pilot reports around an airport."""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .geo import haversine_km, point_in_ring
+
+AVIATION_FOCUS = ("aviation", "flying", "pilot")
+
+_ICAO = re.compile(r"^[A-Z][A-Z0-9]{3}$")
+_WIND = re.compile(r"^(\d{3}|VRB)(\d{2,3})(?:G(\d{2,3}))?(KT|MPS)$")
+_VIS_SM = re.compile(r"^(M|P)?(?:(\d+)|(\d+)/(\d+))SM$")
+_VIS_M = re.compile(r"^(\d{4})(?:NDV)?$")
+_SKY = re.compile(r"^(FEW|SCT|BKN|OVC|VV)(\d{3}|///)(CB|TCU)?$")
+_TEMP = re.compile(r"^(M?\d{2})/(M?\d{2})?$")
+_ALTIMETER = re.compile(r"^([AQ])(\d{4})$")
+_TIME = re.compile(r"^(\d{2})(\d{2})(\d{2})Z$")
+_VALID = re.compile(r"^(\d{4})/(\d{4})$")
+_WEATHER = re.compile(
+    r"^(-|\+|VC)?(MI|PR|BC|DR|BL|SH|TS|FZ)?((?:DZ|RA|SN|SG|IC|PL|GR|GS|UP|BR|FG|FU|VA|DU|SA|HZ|PY|PO|SQ|FC|SS|DS)*)$"
+)
+_CHANGE = re.compile(r"^(FM\d{6}|TEMPO|BECMG|PROB\d{2})$")
+
+WEATHER_WORDS = {
+    "MI": "shallow", "PR": "partial", "BC": "patches of", "DR": "drifting", "BL": "blowing",
+    "SH": "showers of", "TS": "thunderstorm with", "FZ": "freezing",
+    "DZ": "drizzle", "RA": "rain", "SN": "snow", "SG": "snow grains", "IC": "ice crystals",
+    "PL": "ice pellets", "GR": "hail", "GS": "small hail", "UP": "unknown precipitation",
+    "BR": "mist", "FG": "fog", "FU": "smoke", "VA": "volcanic ash", "DU": "dust", "SA": "sand",
+    "HZ": "haze", "PY": "spray", "PO": "dust whirls", "SQ": "squalls", "FC": "funnel cloud",
+    "SS": "sandstorm", "DS": "duststorm",
+}  # fmt: skip
+_DESCRIPTOR_ALONE = {"TS": "thunderstorm", "SH": "showers"}
+SKY_WORDS = {"FEW": "few", "SCT": "scattered", "BKN": "broken", "OVC": "overcast", "VV": "vertical visibility"}
+
+ADVISORY_HAZARDS = {
+    "CONVECTIVE": "convection", "TS": "thunderstorms", "TURB": "turbulence",
+    "TURB-HI": "turbulence", "TURB-LO": "turbulence", "ICE": "icing", "IFR": "IFR",
+    "MTN OBSCN": "mountain obscuration", "MT_OBSC": "mountain obscuration",
+    "SFC_WND": "surface wind over 30 kt", "LLWS": "low-level wind shear", "ASH": "volcanic ash",
+    "VA": "volcanic ash", "TC": "tropical cyclone",
+}  # fmt: skip
+# Freezing-level contours are lines, not areas an airport can be inside.
+_CONTOUR_HAZARDS = ("FZLVL", "M_FZLVL")
+# G-AIRMET snapshots are three hours apart; each stands for the 90 minutes either side.
+GAIRMET_SPAN = timedelta(minutes=90)
+PIREP_RADIUS_KM = 185.0  # 100 nm
+PIREP_HOURS = 2
+MAX_PIREPS = 10
+_PIREP_WORDS = {
+    "NEG": "no", "SMTH": "smooth", "LGT": "light", "MOD": "moderate", "SEV": "severe",
+    "EXTRM": "extreme", "TRACE": "trace", "TRC": "trace", "RIME": "rime", "CLR": "clear",
+    "MX": "mixed", "MXD": "mixed", "CAT": "clear-air", "CHOP": "chop", "OCNL": "occasional",
+    "INTMT": "intermittent", "CONS": "continuous",
+}  # fmt: skip
+_PIREP_LEVELS = re.compile(r"^(\d{3})(?:-(\d{3}))?$")
+
+
+@dataclass(slots=True)
+class Advisory:
+    """A SIGMET, AIRMET or G-AIRMET area covering the airport; ``band`` reads like ``SFC–FL180``."""
+
+    kind: str
+    hazard: str
+    band: str
+    base_ft: int | None = None
+    top_ft: int | None = None
+    severity: str | None = None
+    valid_from: str | None = None
+    valid_to: str | None = None
+    raw: str | None = None
+
+
+@dataclass(slots=True)
+class Pirep:
+    """A decoded pilot report; ``observed`` is UTC ISO and ``distance_km`` is from the airport."""
+
+    raw: str
+    observed: str | None = None
+    urgent: bool = False
+    aircraft: str | None = None
+    altitude_ft: int | None = None
+    distance_km: float | None = None
+    turbulence: str | None = None
+    icing: str | None = None
+    weather: str | None = None
+
+
+@dataclass(slots=True)
+class AviationReport:
+    """Nearest (or named) airport's decoded METAR and TAF for ``wx metar``."""
+
+    place: str
+    station: str | None = None
+    name: str | None = None
+    lat: float | None = None
+    lon: float | None = None
+    distance_km: float | None = None
+    metar: dict[str, Any] | None = None
+    taf: dict[str, Any] | None = None
+    advisories: list[Advisory] = field(default_factory=list)
+    pireps: list[Pirep] = field(default_factory=list)
+    sources: list[str] = field(default_factory=list)
+
+
+def is_icao(place: str | None) -> bool:
+    """Four-character upper-case airport identifier such as ``KSEA`` or ``EGLL``."""
+    return bool(place) and bool(_ICAO.match(place.strip()))
+
+
+def is_aviation_focus(focus: str | None) -> bool:
+    return bool(focus) and focus.strip().lower() in AVIATION_FOCUS
+
+
+def flight_category(ceiling_ft: float | None, visibility_sm: float | None) -> str | None:
+    """FAA flight category: LIFR, IFR, MVFR or VFR; None when neither value is known."""
+
+    if ceiling_ft is None and visibility_sm is None:
+        return None
+    ceiling = ceiling_ft if ceiling_ft is not None else float("inf")
+    visibility = visibility_sm if visibility_sm is not None else float("inf")
+    if ceiling < 500 or visibility < 1:
+        return "LIFR"
+    if ceiling < 1000 or visibility < 3:
+        return "IFR"
+    if ceiling <= 3000 or visibility <= 5:
+        return "MVFR"
+    return "VFR"
+
+
+def decode_metar(raw: str) -> dict[str, Any]:
+    """Structured METAR: station, time, wind (kt), visibility (SM), weather, sky, temps, altimeter."""
+
+    tokens = raw.split()
+    if tokens and tokens[0] in ("METAR", "SPECI"):
+        tokens = tokens[1:]
+    decoded: dict[str, Any] = {"raw": raw.strip(), "station": tokens[0] if tokens else None}
+    rest = tokens[1:]
+    if rest and (match := _TIME.match(rest[0])):
+        decoded["observed"] = f"day {match.group(1)} {match.group(2)}:{match.group(3)}Z"
+        rest = rest[1:]
+    if "RMK" in rest:
+        rest = rest[: rest.index("RMK")]
+    decoded.update(_decode_groups(rest))
+    return decoded
+
+
+def decode_taf(raw: str) -> dict[str, Any]:
+    """Structured TAF: station, validity and one decoded entry per forecast change group."""
+
+    tokens = raw.split()
+    while tokens and tokens[0] in ("TAF", "AMD", "COR"):
+        tokens = tokens[1:]
+    decoded: dict[str, Any] = {"raw": raw.strip(), "station": tokens[0] if tokens else None, "periods": []}
+    rest = tokens[1:]
+    if rest and _TIME.match(rest[0]):
+        rest = rest[1:]
+    if rest and (match := _VALID.match(rest[0])):
+        decoded["valid"] = _period_label(match.group(1), match.group(2))
+        rest = rest[1:]
+
+    change, group = "Initially", []
+    for idx, token in enumerate(rest):
+        if not _CHANGE.match(token) or (token == "TEMPO" and idx and rest[idx - 1].startswith("PROB")):
+            group.append(token)
+            continue
+        if group:
+            decoded["periods"].append(_taf_period(change, group))
+        change, group = _change_label(token), []
+    if group:
+        decoded["periods"].append(_taf_period(change, group))
+    return decoded
+
+
+def metar_summary(metar: dict[str, Any]) -> str:
+    """One line for a decoded METAR or TAF period: ``IFR · wind 180° 12G22 kt · 2 SM mist · ...``."""
+
+    parts = [metar.get("category") or "", _wind_text(metar)]
+    visibility = metar.get("visibility_sm")
+    if visibility is not None:
+        over = "+" if metar.get("visibility_over") else ""
+        weather = f" {', '.join(metar['weather'])}" if metar.get("weather") else ""
+        parts.append(f"{visibility:g}{over} SM{weather}")
+    elif metar.get("weather"):
+        parts.append(", ".join(metar["weather"]))
+    parts.append(_sky_text(metar))
+    if metar.get("temp_c") is not None:
+        dewpoint = metar.get("dewpoint_c")
+        parts.append(f"{metar['temp_c']}/{dewpoint if dewpoint is not None else '–'}°C")
+    if metar.get("altimeter_inhg") is not None:
+        parts.append(f"A{metar['altimeter_inhg']:.2f}")
+    return " · ".join(part for part in parts if part)
+
+
+def altitude_band(base_ft: int | None, top_ft: int | None, *, base_label: str | None = None) -> str:
+    """``SFC–FL180``, ``8,000–12,000 ft`` or ``up to FL450``; flight levels from 18,000 ft."""
+
+    base = base_label or (_level_text(base_ft) if base_ft is not None else None)
+    top = _level_text(top_ft) if top_ft is not None else None
+    if base and top:
+        if base_ft and top_ft and top_ft < 18000:
+            return f"{base_ft:,}–{top_ft:,} ft"
+        return f"{base}–{top}"
+    if top:
+        return f"up to {top}"
+    return f"from {base}" if base else "altitude not given"
+
+
+def advisories_at(
+    airsigmets: list[dict[str, Any]],
+    gairmets: list[dict[str, Any]],
+    lat: float,
+    lon: float,
+    *,
+    now: datetime,
+) -> list[Advisory]:
+    """The current SIGMETs, AIRMETs and G-AIRMETs whose area contains ``lat``/``lon``."""
+
+    advisories = []
+    for item in airsigmets:
+        start, end = _utc_time(item.get("validTimeFrom")), _utc_time(item.get("validTimeTo"))
+        if (start and start > now) or (end and end < now) or not _contains(item.get("coords"), lat, lon):
+            continue
+        hazard = str(item.get("hazard") or "").upper()
+        kind = str(item.get("airSigmetType") or "SIGMET").upper()
+        base, top = _feet(item.get("altitudeLow1")), _feet(item.get("altitudeHi1"))
+        advisories.append(
+            Advisory(
+                kind="Convective SIGMET" if hazard == "CONVECTIVE" else kind,
+                hazard=ADVISORY_HAZARDS.get(hazard, hazard.lower() or "unspecified"),
+                band=altitude_band(base, top),
+                base_ft=base,
+                top_ft=top,
+                severity=_severity(item.get("severity")),
+                valid_from=start.isoformat() if start else None,
+                valid_to=end.isoformat() if end else None,
+                raw=item.get("rawAirSigmet"),
+            )
+        )
+
+    # G-AIRMETs come as snapshots; keep the one nearest now for each hazard.
+    current: dict[str, tuple[timedelta, dict[str, Any], datetime]] = {}
+    for item in gairmets:
+        hazard = str(item.get("hazard") or "").upper()
+        valid = _utc_time(item.get("validTime"))
+        if hazard in _CONTOUR_HAZARDS or valid is None or not _contains(item.get("coords"), lat, lon):
+            continue
+        offset = abs(valid - now)
+        if offset <= GAIRMET_SPAN and (hazard not in current or offset < current[hazard][0]):
+            current[hazard] = (offset, item, valid)
+    for hazard, (_, item, valid) in current.items():
+        base_text = str(item.get("base") or "").upper()
+        base, top = _feet(item.get("base"), hundreds=True), _feet(item.get("top"), hundreds=True)
+        advisories.append(
+            Advisory(
+                kind="G-AIRMET",
+                hazard=ADVISORY_HAZARDS.get(hazard, hazard.lower()),
+                band=altitude_band(base, top, base_label="FZL" if base_text == "FZL" else None),
+                base_ft=base,
+                top_ft=top,
+                severity=_severity(item.get("severity")),
+                valid_from=(valid - GAIRMET_SPAN).isoformat(),
+                valid_to=(valid + GAIRMET_SPAN).isoformat(),
+            )
+        )
+    return advisories
+
+
+def decode_pirep(raw: str) -> dict[str, Any]:
+    """Structured PIREP from its ``/XX`` groups: aircraft, flight level, turbulence, icing, weather."""
+
+    groups: dict[str, str] = {}
+    for part in raw.split("/")[1:]:
+        # Two-letter group names: OV, TM, FL, TP, SK, WX, TA, WV, TB, IC, RM.
+        key, value = part.strip()[:2], part.strip()[2:].strip()
+        if value:
+            groups[key] = value
+    level = groups.get("FL", "")
+    return {
+        "raw": raw.strip(),
+        "urgent": " UUA" in f" {raw.split('/')[0]}",
+        "aircraft": groups.get("TP"),
+        "altitude_ft": int(level) * 100 if level.isdigit() else None,
+        "turbulence": _pirep_condition(groups.get("TB"), "turbulence"),
+        "icing": _pirep_condition(groups.get("IC"), "icing"),
+        "weather": groups.get("WX"),
+    }
+
+
+def nearby_pireps(
+    items: list[dict[str, Any]],
+    lat: float,
+    lon: float,
+    *,
+    now: datetime,
+    radius_km: float = PIREP_RADIUS_KM,
+) -> list[Pirep]:
+    """Up to MAX_PIREPS reports from the last PIREP_HOURS within ``radius_km``, newest first."""
+
+    pireps = []
+    for item in items:
+        observed = _utc_time(item.get("obsTime"))
+        plat, plon = item.get("lat"), item.get("lon")
+        if not (isinstance(plat, (int, float)) and isinstance(plon, (int, float))):
+            continue
+        distance = haversine_km(lat, lon, plat, plon)
+        if distance > radius_km or (observed and now - observed > timedelta(hours=PIREP_HOURS)):
+            continue
+        decoded = decode_pirep(str(item["rawOb"]))
+        if decoded["altitude_ft"] is None and isinstance(item.get("fltLvl"), (int, float)):
+            decoded["altitude_ft"] = int(item["fltLvl"]) * 100
+        pireps.append(
+            Pirep(
+                raw=decoded["raw"],
+                observed=observed.isoformat() if observed else None,
+                urgent=decoded["urgent"] or item.get("pirepType") == "Urgent PIREP",
+                aircraft=decoded["aircraft"] or item.get("acType"),
+                altitude_ft=decoded["altitude_ft"],
+                distance_km=round(distance, 1),
+                turbulence=decoded["turbulence"],
+                icing=decoded["icing"],
+                weather=decoded["weather"],
+            )
+        )
+    pireps.sort(key=lambda pirep: pirep.observed or "", reverse=True)
+    return pireps[:MAX_PIREPS]
+
+
+def pirep_summary(pirep: Pirep) -> str:
+    """One line for a PIREP: ``FL080 · B737 · moderate turbulence · light rime icing``."""
+
+    altitude = _level_text(pirep.altitude_ft) if pirep.altitude_ft is not None else None
+    parts = [altitude, pirep.aircraft, pirep.turbulence, pirep.icing, pirep.weather]
+    return " · ".join(part for part in parts if part) or "no details"
+
+
+def _decode_groups(tokens: list[str]) -> dict[str, Any]:
+    decoded: dict[str, Any] = {"weather": [], "sky": []}
+    whole_miles = None
+    for token in tokens:
+        if token in ("AUTO", "COR", "NIL", "NOSIG", "$"):
+            continue
+        if match := _WIND.match(token):
+            factor = 1.944 if match.group(4) == "MPS" else 1.0
+            decoded["wind_dir"] = None if match.group(1) == "VRB" else int(match.group(1))
+            decoded["wind_kt"] = round(int(match.group(2)) * factor)
+            decoded["gust_kt"] = round(int(match.group(3)) * factor) if match.group(3) else None
+        elif token.isdigit() and len(token) == 1:
+            whole_miles = int(token)  # "1 1/2SM" arrives as two tokens
+        elif match := _VIS_SM.match(token):
+            miles = float(match.group(2)) if match.group(2) else int(match.group(3)) / int(match.group(4))
+            decoded["visibility_sm"] = round(miles + (whole_miles or 0), 2)
+            decoded["visibility_over"] = match.group(1) == "P"
+            whole_miles = None
+        elif token == "CAVOK":
+            decoded["visibility_sm"] = 10.0
+            decoded["sky"].append({"cover": "CLR", "base_ft": None})
+        elif (match := _VIS_M.match(token)) and "visibility_sm" not in decoded:
+            meters = int(match.group(1))
+            decoded["visibility_sm"] = 10.0 if meters == 9999 else round(meters / 1609.34, 2)
+        elif match := _SKY.match(token):
+            base = None if match.group(2) == "///" else int(match.group(2)) * 100
+            decoded["sky"].append({"cover": match.group(1), "base_ft": base, "type": match.group(3)})
+        elif token in ("CLR", "SKC", "NSC", "NCD"):
+            decoded["sky"].append({"cover": "CLR", "base_ft": None})
+        elif match := _TEMP.match(token):
+            decoded["temp_c"] = _signed(match.group(1))
+            decoded["dewpoint_c"] = _signed(match.group(2)) if match.group(2) else None
+        elif match := _ALTIMETER.match(token):
+            value = int(match.group(2))
+            decoded["altimeter_inhg"] = value / 100 if match.group(1) == "A" else round(value * 0.02953, 2)
+        elif token == "NSW":
+            decoded["weather"].append("no significant weather")
+        elif (match := _WEATHER.match(token)) and (match.group(2) or match.group(3)):
+            decoded["weather"].append(_weather_text(match))
+    ceilings = [layer["base_ft"] for layer in decoded["sky"] if layer["cover"] in ("BKN", "OVC", "VV")]
+    decoded["ceiling_ft"] = min((base for base in ceilings if base is not None), default=None)
+    decoded["category"] = flight_category(decoded["ceiling_ft"], decoded.get("visibility_sm"))
+    return decoded
+
+
+def _taf_period(change: str, tokens: list[str]) -> dict[str, Any]:
+    if tokens and (match := _VALID.match(tokens[0])):
+        change = f"{change} {_period_label(match.group(1), match.group(2))}"
+        tokens = tokens[1:]
+    if tokens and tokens[0] == "TEMPO":
+        change, tokens = f"{change} temporarily", tokens[1:]
+        if tokens and (match := _VALID.match(tokens[0])):
+            change = f"{change} {_period_label(match.group(1), match.group(2))}"
+            tokens = tokens[1:]
+    decoded = _decode_groups(tokens)
+    decoded["change"] = change
+    return decoded
+
+
+def _change_label(token: str) -> str:
+    if token.startswith("FM"):
+        return f"From day {token[2:4]} {token[4:6]}:{token[6:8]}Z"
+    if token.startswith("PROB"):
+        return f"{token[4:]}% chance"
+    return {"TEMPO": "Temporarily", "BECMG": "Becoming"}[token]
+
+
+def _period_label(start: str, end: str) -> str:
+    return f"day {start[:2]} {start[2:]}Z–day {end[:2]} {end[2:]}Z"
+
+
+def _weather_text(match: re.Match[str]) -> str:
+    intensity = {"-": "light", "+": "heavy"}.get(match.group(1) or "", "")
+    descriptor, codes = match.group(2), match.group(3)
+    words = [WEATHER_WORDS[codes[idx : idx + 2]] for idx in range(0, len(codes), 2)]
+    if descriptor:
+        words.insert(0, WEATHER_WORDS[descriptor] if words else _DESCRIPTOR_ALONE.get(descriptor, descriptor.lower()))
+    text = " ".join([intensity, *words]).strip()
+    return f"{text} nearby" if match.group(1) == "VC" else text
+
+
+def _wind_text(decoded: dict[str, Any]) -> str:
+    if "wind_kt" not in decoded:
+        return ""
+    if decoded["wind_kt"] == 0:
+        return "wind calm"
+    direction = "variable" if decoded["wind_dir"] is None else f"{decoded['wind_dir']:03d}°"
+    gust = f"G{decoded['gust_kt']}" if decoded.get("gust_kt") else ""
+    return f"wind {direction} {decoded['wind_kt']}{gust} kt"
+
+
+def _sky_text(decoded: dict[str, Any]) -> str:
+    layers = []
+    for layer in decoded.get("sky") or []:
+        if layer["cover"] == "CLR":
+            layers.append("clear")
+        else:
+            base = f"{layer['base_ft']:,} ft" if layer["base_ft"] is not None else "unknown base"
+            layers.append(f"{SKY_WORDS[layer['cover']]} {base}{' ' + layer['type'] if layer.get('type') else ''}")
+    return ", ".join(layers)
+
+
+def _signed(value: str) -> int:
+    return -int(value[1:]) if value.startswith("M") else int(value)
+
+
+def _level_text(feet: int) -> str:
+    if feet <= 0:
+        return "SFC"
+    return f"FL{feet // 100:03d}" if feet >= 18000 else f"{feet:,} ft"
+
+
+def _feet(value: Any, *, hundreds: bool = False) -> int | None:
+    """Feet from a number, ``SFC``, ``FL180`` or (G-AIRMET) hundreds of feet such as ``120``."""
+
+    text = str(value).strip().upper() if value is not None else ""
+    if text == "SFC":
+        return 0
+    if text.startswith("FL"):
+        text, hundreds = text[2:], True
+    try:
+        number = float(text)
+    except ValueError:
+        return None
+    return int(number * 100) if hundreds else int(number)
+
+
+def _severity(value: Any) -> str | None:
+    return value.strip().lower() if isinstance(value, str) and value.strip() else None
+
+
+def _utc_time(value: Any) -> datetime | None:
+    """aviationweather.gov times: epoch seconds or ISO strings."""
+
+    if isinstance(value, (int, float)) and not isinstance(value, bool):
+        return datetime.fromtimestamp(value, UTC)
+    if not isinstance(value, str) or not value.strip():
+        return None
+    text = value.strip()
+    if text.isdigit():
+        return datetime.fromtimestamp(int(text), UTC)
+    try:
+        stamp = datetime.fromisoformat(text.replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    return stamp if stamp.tzinfo else stamp.replace(tzinfo=UTC)
+
+
+def _contains(coords: Any, lat: float, lon: float) -> bool:
+    """Whether ``lat``/``lon`` is inside a polygon given as ``[{"lat": .., "lon": ..}, ...]``."""
+
+    ring = []
+    for point in coords if isinstance(coords, list) else []:
+        try:
+            ring.append((float(point["lat"]), float(point["lon"])))
+        except (KeyError, TypeError, ValueError):
+            return False
+    return point_in_ring(lat, lon, ring)
+
+
+def _pirep_condition(text: str | None, noun: str) -> str | None:
+    """``LGT-MOD CHOP 080-120`` → ``light to moderate chop 8,000–12,000 ft``."""
+
+    if not text:
+        return None
+    words, band = [], None
+    for token in text.split():
+        if match := _PIREP_LEVELS.match(token):
+            base = int(match.group(1)) * 100
+            top = int(match.group(2)) * 100 if match.group(2) else None
+            band = altitude_band(base, top) if top is not None else _level_text(base)
+            continue
+        words.append(" to ".join(_PIREP_WORDS.get(part, part.lower()) for part in token.split("-")))
+    phrase = " ".join(words if "chop" in words[-1:] else [*words, noun])
+    return f"{phrase} {band}" if band else phrase
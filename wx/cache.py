@@ -0,0 +1,219 @@
+"""Short-lived cache for provider fetches so repeated queries skip the network."""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import os
+import tempfile
+import time
+from pathlib import Path
+from typing import Any
+
+from .config import Settings
+from .providers import WeatherProvider
+
+# Place names rarely move; forecasts refresh hourly upstream; alerts change fastest.
+GEOCODE_TTL = 30 * 24 * 3600
+CURRENT_TTL = 10 * 60
+FORECAST_TTL = 30 * 60
+ALERTS_TTL = 5 * 60
+# A story is only as fresh as the forecast it was written from.
+STORY_TTL = FORECAST_TTL
+MAX_TTL = GEOCODE_TTL
+NAMESPACE_TTLS = {
+    "geocode": GEOCODE_TTL,
+    "current": CURRENT_TTL,
+    "forecast": FORECAST_TTL,
+    "alerts": ALERTS_TTL,
+    "story": STORY_TTL,
+}
+
+
+def cache_path(settings: Settings) -> Path:
+    """Where the shared fetch cache lives (only written when privacy mode is off)."""
+
+    return settings.state_file.parent / "fetch_cache.json"
+
+
+def story_key(system_prompt: str, prompt: str, settings: Settings) -> str:
+    """Hash of the prompt (Feature Pack, question and style) and the models that would answer it."""
+
+    models = [
+        settings.preferred_backend,
+        list(settings.openrouter_models or (settings.ai_model,)),
+        settings.openai_model,
+        settings.anthropic_model,
+        settings.gemini_model,
+        settings.ollama_model,
+        settings.ai_temperature,
+        settings.ai_max_tokens,
+    ]
+    material = json.dumps([system_prompt, prompt, models], sort_keys=True, default=str)
+    return hashlib.sha256(material.encode()).hexdigest()
+
+
+class FetchCache:
+    """TTL cache keyed by ``namespace`` + ``key``.
+
+    With a ``path`` entries are persisted as JSON (0600) so separate invocations share
+    them; without one the cache lives only as long as the process, e.g. a chat session.
+    """
+
+    def __init__(self, path: Path | None = None) -> None:
+        self.path = path
+        self._data: dict[str, dict[str, Any]] | None = None
+
+    def get(self, namespace: str, key: str, ttl: float) -> Any | None:
+        entry = self._load().get(f"{namespace}:{key}")
+        if not entry or time.time() - entry.get("t", 0) > ttl:
+            return None
+        return entry.get("v")
+
+    def put(self, namespace: str, key: str, value: Any) -> None:
+        self._load()[f"{namespace}:{key}"] = {"t": time.time(), "v": value}
+        self._save()
+
+    def stats(self) -> dict[str, Any]:
+        """Entry counts per namespace, on-disk size and the oldest entry's age."""
+
+        entries = self._load()
+        now = time.time()
+        namespaces: dict[str, int] = {}
+        for key in entries:
+            namespace = key.split(":", 1)[0]
+            namespaces[namespace] = namespaces.get(namespace, 0) + 1
+        ages = [now - entry.get("t", now) for entry in entries.values()]
+        size = self.path.stat().st_size if self.path is not None and self.path.exists() else 0
+        return {
+            "path": str(self.path) if self.path is not None else None,
+            "entries": len(entries),
+            "namespaces": namespaces,
+            "bytes": size,
+            "oldest_age_s": round(max(ages)) if ages else None,
+        }
+
+    def entries(self) -> list[dict[str, Any]]:
+        """One row per entry, oldest first, flagging those past their namespace TTL."""
+
+        now = time.time()
+        rows = []
+        for key, entry in self._load().items():
+            namespace = key.split(":", 1)[0]
+            age = now - entry.get("t", now)
+            rows.append(
+                {
+                    "key": key,
+                    "namespace": namespace,
+                    "age_s": round(age),
+                    "expired": age > NAMESPACE_TTLS.get(namespace, MAX_TTL),
+                    "bytes": len(json.dumps(entry.get("v"), ensure_ascii=True)),
+                }
+            )
+        return sorted(rows, key=lambda row: -row["age_s"])
+
+    def lookup(self, key: str) -> dict[str, Any] | None:
+        """Raw stored entry (``t`` timestamp and ``v`` value) for a full ``namespace:key``."""
+
+        return self._load().get(key)
+
+    def clear(self) -> int:
+        """Drop every entry and the cache file; returns how many entries were removed."""
+
+        removed = len(self._load())
+        self._data = {}
+        if self.path is not None:
+            try:
+                self.path.unlink(missing_ok=True)
+            except OSError:
+                pass
+        return removed
+
+    def prune(self) -> int:
+        """Drop entries past their namespace TTL; returns how many were removed."""
+
+        expired = [row["key"] for row in self.entries() if row["expired"]]
+        for key in expired:
+            del self._load()[key]
+        if expired:
+            self._save()
+        return len(expired)
+
+    def _load(self) -> dict[str, dict[str, Any]]:
+        if self._data is None:
+            self._data = {}
+            if self.path is not None:
+                try:
+                    loaded = json.loads(self.path.read_text())
+                    if isinstance(loaded, dict):
+                        self._data = loaded
+                except (OSError, json.JSONDecodeError):
+                    pass
+        return self._data
+
+    def _save(self) -> None:
+        if self.path is None or self._data is None:
+            return
+        now = time.time()
+        self._data = {key: entry for key, entry in self._data.items() if now - entry.get("t", 0) <= MAX_TTL}
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            fd, temp_path = tempfile.mkstemp(dir=self.path.parent, prefix=".wx_temp_", suffix=".json")
+            with os.fdopen(fd, "w") as handle:
+                handle.write(json.dumps(self._data, ensure_ascii=True))
+            os.chmod(temp_path, 0o600)
+            os.replace(temp_path, self.path)
+        except OSError:
+            # Cache writes are best-effort.
+            pass
+
+
+class CachingProvider:
+    """Wrap a provider so geocodes, observations, forecasts and alerts are reused within their TTL.
+
+    Empty results are not cached so a transient outage is retried on the next call. Setting
+    ``refresh`` fetches fresh weather data (and stores it) without reading existing entries;
+    geocodes are always reused, since place names do not move. With ``geocode_only`` (--no-cache)
+    only geocodes are cached.
+    """
+
+    def __init__(self, inner: WeatherProvider, cache: FetchCache, *, geocode_only: bool = False) -> None:
+        self.inner = inner
+        self.cache = cache
+        self.name = inner.name
+        self.attribution = getattr(inner, "attribution", ())
+        self.geocode_only = geocode_only
+        self.refresh = False
+
+    def geocode(self, place: str) -> dict[str, Any] | None:
+        key = place.strip().lower()
+        hit = self.cache.get("geocode", key, GEOCODE_TTL)
+        if hit is not None:
+            return hit
+        value = self.inner.geocode(place)
+        if value:
+            self.cache.put("geocode", key, value)
+        return value
+
+    def current(self, lat: float, lon: float) -> dict[str, Any] | None:
+        key = f"{self.name}:{lat:.3f},{lon:.3f}"
+        return self._cached("current", key, CURRENT_TTL, lambda: self.inner.current(lat, lon))
+
+    def forecast(self, lat: float, lon: float, *, hours: int = 48) -> dict[str, list[Any]] | None:
+        key = f"{self.name}:{lat:.3f},{lon:.3f}:{hours}"
+        return self._cached("forecast", key, FORECAST_TTL, lambda: self.inner.forecast(lat, lon, hours=hours))
+
+    def alerts(self, lat: float, lon: float) -> list[dict[str, Any]] | None:
+        key = f"{lat:.3f},{lon:.3f}"
+        return self._cached("alerts", key, ALERTS_TTL, lambda: self.inner.alerts(lat, lon))
+
+    def _cached(self, namespace: str, key: str, ttl: float, fetch: Any) -> Any:
+        if self.geocode_only:
+            return fetch()
+        hit = None if self.refresh else self.cache.get(namespace, key, ttl)
+        if hit is not None:
+            return hit
+        value = fetch()
+        if value:
+            self.cache.put(namespace, key, value)
+        return value
@@ -2,9 +2,13 @@
 
 from __future__ import annotations
 
+import json
+import os
 import sys
+import tempfile
 from dataclasses import dataclass, field
-from datetime import UTC, datetime
+from datetime import UTC, datetime, timedelta
+from pathlib import Path
 from typing import Any
 
 from rich.console import Console
@@ -12,7 +16,14 @@ from rich.panel import Panel
 from rich.prompt import Prompt
 
 from .config import Settings
-from .orchestrator import Orchestrator
+from .feedback import FeedbackEntry, FeedbackStore, feedback_path, story_info
+from .forecaster import ForecasterResponse
+from .orchestrator import OrchestrationResult, Orchestrator
+from .schema import json_document, unwrap
+
+# How long a location's Feature Pack is reused across turns before refetching.
+FEATURE_PACK_TTL = timedelta(minutes=15)
+SESSION_FORMAT_VERSION = 1
 
 
 @dataclass(slots=True)
@@ -32,6 +43,8 @@ class ConversationSession:
     messages: list[ConversationMessage] = field(default_factory=list)
     location_context: dict[str, Any] | None = None
     session_start: datetime = field(default_factory=lambda: datetime.now(UTC))
+    feature_pack: dict[str, Any] | None = None
+    feature_pack_fetched: datetime | None = None
 
     def add_message(self, role: str, content: str, metadata: dict[str, Any] | None = None) -> None:
         """Add a message to the conversation history."""
@@ -69,14 +82,156 @@ class ConversationSession:
         return "\n".join(context_parts)
 
 
+@dataclass(slots=True)
+class SessionRecorder:
+    """Writes chat turns to a JSON file as they happen so an interrupted session keeps them."""
+
+    path: Path
+    units: str
+    provider: str
+    started: datetime = field(default_factory=lambda: datetime.now(UTC))
+    turns: list[dict[str, Any]] = field(default_factory=list)
+
+    def record(
+        self,
+        user_input: str,
+        *,
+        result: OrchestrationResult | None = None,
+        error: str | None = None,
+    ) -> None:
+        """Append a turn: a slash command, an answered question, or a failed one."""
+        turn: dict[str, Any] = {
+            "timestamp": datetime.now(UTC).isoformat(timespec="seconds"),
+            "input": user_input,
+            "kind": "command" if user_input.startswith("/") else "question",
+        }
+        if result is not None:
+            turn["result"] = result_to_dict(result)
+        if error is not None:
+            turn["error"] = error
+        self.turns.append(turn)
+        self.save()
+
+    def save(self) -> None:
+        payload = {
+            "version": SESSION_FORMAT_VERSION,
+            "started": self.started.isoformat(timespec="seconds"),
+            "units": self.units,
+            "provider": self.provider,
+            "turns": self.turns,
+        }
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        fd, temp_path = tempfile.mkstemp(dir=self.path.parent, prefix=".wx_temp_", suffix=".json")
+        try:
+            with os.fdopen(fd, "w") as handle:
+                handle.write(json.dumps(payload, ensure_ascii=True, indent=2, default=str))
+            os.chmod(temp_path, 0o600)
+            os.replace(temp_path, self.path)
+        except Exception:  # noqa: BLE001
+            try:
+                os.unlink(temp_path)
+            except OSError:
+                pass
+            raise
+
+
+def result_to_dict(result: OrchestrationResult) -> dict[str, Any]:
+    """Serialise a result with the model's raw text so bad answers can be diagnosed."""
+    response = result.response
+    return {
+        "command": result.command,
+        "query": result.query,
+        "feature_pack": result.feature_pack,
+        "response": {
+            "sections": response.sections,
+            "confidence": response.confidence,
+            "used_feature_fields": response.used_feature_fields,
+            "bottom_line": response.bottom_line,
+            "raw_text": response.raw_text,
+            "provider": response.provider,
+            "prompt_summary": response.prompt_summary,
+            "meta": response.meta,
+        },
+        "timings": result.timings,
+        "debug": result.debug,
+        "attribution": result.attribution,
+    }
+
+
+def result_from_dict(data: dict[str, Any]) -> OrchestrationResult:
+    """Rebuild a recorded result for rendering."""
+    response = data.get("response") or {}
+    return OrchestrationResult(
+        command=data.get("command", "question"),
+        query=data.get("query", ""),
+        feature_pack=data.get("feature_pack") or {},
+        response=ForecasterResponse(
+            sections=response.get("sections") or {},
+            confidence=response.get("confidence") or {},
+            used_feature_fields=response.get("used_feature_fields") or [],
+            bottom_line=response.get("bottom_line", ""),
+            raw_text=response.get("raw_text", ""),
+            provider=response.get("provider", "replay"),
+            prompt_summary=response.get("prompt_summary", ""),
+            meta=response.get("meta"),
+        ),
+        timings=data.get("timings") or {},
+        debug=data.get("debug") or {},
+        attribution=data.get("attribution") or [],
+    )
+
+
+def load_recorded_session(path: Path) -> dict[str, Any]:
+    """Read a ``--record`` file; raises ValueError when it is not a wx chat session."""
+    try:
+        data = json.loads(path.read_text(encoding="utf-8"))
+    except OSError as exc:
+        raise ValueError(f"Cannot read {path}: {exc.strerror or exc}") from exc
+    except json.JSONDecodeError as exc:
+        raise ValueError(f"{path} is not valid JSON") from exc
+    if not isinstance(data, dict) or not isinstance(data.get("turns"), list):
+        raise ValueError(f"{path} is not a wx chat recording")
+    if data.get("version", SESSION_FORMAT_VERSION) > SESSION_FORMAT_VERSION:
+        raise ValueError(f"{path} was recorded by a newer wx (format {data['version']})")
+    return data
+
+
+def fixture_feature_pack(path: Path) -> dict[str, Any]:
+    """Feature Pack from a ``wx --json`` result or the last answered turn of a chat recording."""
+    try:
+        data = json.loads(path.read_text(encoding="utf-8"))
+    except OSError as exc:
+        raise ValueError(f"Cannot read {path}: {exc.strerror or exc}") from exc
+    except json.JSONDecodeError as exc:
+        raise ValueError(f"{path} is not valid JSON") from exc
+    data = unwrap(data)
+    if isinstance(data, dict) and isinstance(data.get("turns"), list):
+        results = [turn.get("result") for turn in data["turns"] if isinstance(turn.get("result"), dict)]
+        data = results[-1] if results else {}
+    feature_pack = data.get("feature_pack") if isinstance(data, dict) else None
+    if not isinstance(feature_pack, dict) or not feature_pack:
+        raise ValueError(f"{path} has no Feature Pack; use a 'wx --json' result or a chat --record file")
+    return feature_pack
+
+
 class ChatInterface:
     """Interactive chat interface for conversational weather queries."""
 
-    def __init__(self, settings: Settings, orchestrator: Orchestrator, console: Console) -> None:
+    def __init__(
+        self,
+        settings: Settings,
+        orchestrator: Orchestrator,
+        console: Console,
+        *,
+        recorder: SessionRecorder | None = None,
+    ) -> None:
         self.settings = settings
         self.orchestrator = orchestrator
         self.console = console
         self.session = ConversationSession()
+        self.recorder = recorder
+        self.feedback = FeedbackStore(feedback_path(settings))
+        self.last_result: OrchestrationResult | None = None
 
     def run(self, *, verbose: bool = False, json_mode: bool = False) -> None:
         """Start the interactive chat session."""
@@ -98,13 +253,24 @@ class ChatInterface:
                     continue
 
                 if user_input.lower() == "/clear":
-                    self.session = ConversationSession()
+                    self.session.messages.clear()
                     self.console.print("[green]Conversation history cleared.[/green]")
+                    self._record(user_input)
+                    continue
+
+                if user_input.lower().split(" ", 1)[0] == "/units":
+                    self._set_units(user_input[6:].strip().lower())
+                    self._record(user_input)
+                    continue
+
+                if user_input.lower().split(" ", 1)[0] in {"/rate", "/flag"}:
+                    self._rate_last_answer(user_input)
                     continue
 
                 if user_input.lower().startswith("/location "):
                     location = user_input[10:].strip()
                     self._set_location_context(location)
+                    self._record(user_input)
                     continue
 
                 if not user_input.strip():
@@ -133,6 +299,8 @@ I can help you with:
 [dim]Commands:[/dim]
   /help      - Show available commands
   /location  - Set your default location (e.g., /location Denver, CO)
+  /units     - Switch units (imperial or metric)
+  /rate      - Rate the last answer 1-5 (/flag to report an inaccuracy)
   /clear     - Clear conversation history
   /quit      - Exit chat
 
@@ -146,6 +314,9 @@ I can help you with:
 [bold]Available Commands:[/bold]
 
   /location <place>  - Set default location context
+  /units <system>    - Switch between imperial and metric
+  /rate <1-5> [note] - Rate the last answer
+  /flag <what>       - Flag the last answer as inaccurate
   /clear            - Clear conversation history
   /help or /?       - Show this help message
   /quit or /exit    - Exit the chat
@@ -164,14 +335,15 @@ I can help you with:
 
     def _set_location_context(self, location: str) -> None:
         """Set the location context for the conversation."""
-        from .fetchers import get_point_context
-
         self.console.print(f"[dim]Looking up location: {location}...[/dim]")
 
         try:
-            context = get_point_context(location, offline=self.settings.offline)
+            context = self.orchestrator.provider.geocode(location)
             if context:
                 self.session.location_context = context
+                self._refresh_feature_pack()
+                # Place-less follow-ups now refer to this location.
+                self.orchestrator.context_store.add(self.session.feature_pack or {})
                 resolved_name = context.get("resolved", location)
                 lat = context.get("lat")
                 lon = context.get("lon")
@@ -197,15 +369,20 @@ I can help you with:
         # Add user message to history
         self.session.add_message("user", user_input)
 
-        # Enhance the query with conversation context
-        enhanced_query = self._enhance_query_with_context(user_input)
+        # Conversation context travels separately so place/time parsing only sees the question
+        context = "\n".join(self._context_lines()) or None
 
         # Show thinking indicator
         self.console.print("[dim]Thinking...[/dim]", end="")
 
         try:
             # Process the question through the orchestrator
-            result = self.orchestrator.handle_question(enhanced_query, verbose=verbose)
+            result = self.orchestrator.handle_question(
+                user_input,
+                verbose=verbose,
+                feature_pack=self._current_feature_pack(),
+                context=context,
+            )
 
             # Clear the thinking indicator
             self.console.print("\r" + " " * 20 + "\r", end="")
@@ -232,13 +409,83 @@ I can help you with:
                 debug=self.settings.debug,
                 verbose=verbose,
             )
+            self._record(user_input, result=result)
+            self.last_result = result
+            if not json_mode:
+                self.console.print("[dim]Rate this answer: /rate 1-5 · /flag what was wrong[/dim]")
 
         except Exception as e:  # noqa: BLE001
             self.console.print("\r" + " " * 20 + "\r", end="")
             self.console.print(f"[red]Error processing your question: {e!s}[/red]")
+            self._record(user_input, error=str(e))
+
+    def _record(
+        self, user_input: str, *, result: OrchestrationResult | None = None, error: str | None = None
+    ) -> None:
+        if self.recorder is None:
+            return
+        try:
+            self.recorder.record(user_input, result=result, error=error)
+        except OSError as exc:
+            self.console.print(f"[yellow]Could not write session recording: {exc}[/yellow]")
+
+    def _rate_last_answer(self, user_input: str) -> None:
+        """Store ``/rate <1-5> [note]`` or ``/flag <what was wrong>`` for the last answer."""
+        command, _, rest = user_input.strip().partition(" ")
+        if self.last_result is None:
+            self.console.print("[yellow]Ask a question first, then rate the answer.[/yellow]")
+            return
+        rest = rest.strip()
+        if command.lower() == "/flag":
+            entry = FeedbackEntry(story={}, inaccurate=True, note=rest or None)
+        else:
+            score, _, note = rest.partition(" ")
+            if not score.isdigit():
+                self.console.print("[yellow]Usage: /rate <1-5> [note][/yellow]")
+                return
+            entry = FeedbackEntry(story={}, rating=int(score), note=note.strip() or None)
+        entry.story = story_info(self.last_result.response)
+        entry.command = self.last_result.command
+        try:
+            self.feedback.add(entry)
+        except ValueError as exc:
+            self.console.print(f"[yellow]{exc}[/yellow]")
+            return
+        self.console.print(f"[green]✓ Thanks! Feedback saved for {entry.story['provider']}.[/green]")
+
+    def _set_units(self, units: str) -> None:
+        """Switch the unit system used for subsequent answers."""
+        if units not in {"imperial", "metric"}:
+            self.console.print(
+                f"[yellow]Units are {self.settings.units}. Use /units imperial or /units metric.[/yellow]"
+            )
+            return
+        self.settings.units = units
+        self.session.add_message("system", f"Units set to {units}")
+        self.console.print(f"[green]✓ Units set to {units}[/green]")
+
+    def _refresh_feature_pack(self) -> None:
+        """Fetch data for the current location so follow-up questions can reuse it."""
+        if not self.session.location_context:
+            return
+        self.session.feature_pack = self.orchestrator.location_feature_pack(self.session.location_context)
+        self.session.feature_pack_fetched = datetime.now(UTC)
+
+    def _current_feature_pack(self) -> dict[str, Any] | None:
+        """Return the cached location Feature Pack, refetching it once it goes stale."""
+        fetched = self.session.feature_pack_fetched
+        if self.session.location_context and (
+            fetched is None or datetime.now(UTC) - fetched > FEATURE_PACK_TTL
+        ):
+            self._refresh_feature_pack()
+        return self.session.feature_pack
 
     def _enhance_query_with_context(self, user_query: str) -> str:
         """Enhance the user query with conversation context."""
+        return "\n".join([*self._context_lines(), user_query])
+
+    def _context_lines(self) -> list[str]:
+        """Conversation and location context to send alongside the question."""
         parts = []
 
         # Add conversation context
@@ -254,10 +501,7 @@ I can help you with:
             if lat and lon:
                 parts.append(f"[User's location context: {loc_name} at {lat:.3f}, {lon:.3f}]")
 
-        # Add the actual user query
-        parts.append(user_query)
-
-        return "\n".join(parts)
+        return parts
 
 
 def start_chat_session(
@@ -267,7 +511,51 @@ def start_chat_session(
     *,
     verbose: bool = False,
     json_mode: bool = False,
+    record: Path | None = None,
 ) -> None:
-    """Start an interactive chat session."""
-    chat = ChatInterface(settings, orchestrator, console)
+    """Start an interactive chat session, optionally recording it to ``record``."""
+    recorder = None
+    if record is not None:
+        recorder = SessionRecorder(path=record, units=settings.units, provider=settings.provider)
+    chat = ChatInterface(settings, orchestrator, console, recorder=recorder)
     chat.run(verbose=verbose, json_mode=json_mode)
+
+
+def replay_chat_session(
+    path: Path,
+    console: Console,
+    *,
+    verbose: bool = False,
+    json_mode: bool = False,
+    debug: bool = False,
+) -> None:
+    """Re-render a recorded session without fetching data or calling a model."""
+    from .render import render_result
+
+    data = load_recorded_session(path)
+    turns = data["turns"]
+    if json_mode:
+        console.print(json_document("chat_session", data))
+        return
+
+    console.print(
+        Panel(
+            f"Recorded {data.get('started', 'unknown')} · {len(turns)} turns · "
+            f"{data.get('provider', 'open-meteo')} data",
+            title=f"Replay: {path.name}",
+            border_style="cyan",
+        )
+    )
+    for turn in turns:
+        console.print(f"\n[bold cyan]You:[/bold cyan] {turn.get('input', '')}")
+        if turn.get("error"):
+            console.print(f"[red]Error processing your question: {turn['error']}[/red]")
+        elif isinstance(turn.get("result"), dict):
+            console.print("[bold green]AI Bot:[/bold green]")
+            render_result(
+                result_from_dict(turn["result"]),
+                console=console,
+                json_mode=False,
+                debug=debug,
+                verbose=verbose,
+            )
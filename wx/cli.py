@@ -3,24 +3,187 @@
 from __future__ import annotations
 
 import json
+import os
+import shlex
+import subprocess
 import sys
 from collections.abc import Sequence
+from dataclasses import asdict
+from datetime import UTC, date, datetime
+from pathlib import Path
+from typing import Any
 
 import typer
+from dateutil import parser as date_parser
 from rich.console import Console
 from rich.panel import Panel
 
-from .chat import start_chat_session
-from .config import PersonaLiteral, StyleLiteral, load_settings
-from .orchestrator import Orchestrator
-from .render import render_result, render_worldview
+from .astro import SUN_MAX_DAYS
+from .cache import FetchCache, cache_path
+from .chat import fixture_feature_pack, replay_chat_session, start_chat_session
+from .config import AI_BACKENDS, OutputFormatLiteral, PersonaLiteral, apply_ai_override, load_settings
+from .doctor import collect_diagnostics, write_bundle
+from .favorites import Favorite, merge_favorites
+from .feedback import FeedbackEntry, FeedbackStore, feedback_path
+from .fishing import FISH_HOURS, MAX_FISH_HOURS
+from .freshness import MAX_WAIT_MINUTES
+from .graph import GRAPH_VARIABLES, resolve_variable
+from .groups import GROUP_PREFIX, group_name
+from .history import validate_range
+from .httpclient import configure_http
+from .ics import EXPORT_FORMATS, forecast_calendar
+from .keystore import (
+    SECRET_KEYS,
+    KeystoreError,
+    delete_secret,
+    keyring_available,
+    secret_source,
+    store_secret,
+)
+from .locate import current_location, is_here
+from .models import BLOCK_HOURS, MAX_MODELS_HOURS, MODELS_HOURS
+from .nowcast import MAX_NOWCAST_MINUTES, NOWCAST_MINUTES, STEP_MINUTES
+from .orchestrator import Orchestrator, is_zone_code
+from .profile import load_profile, normalize_region, read_profile_data, write_profile_data
+from .prompts import PROMPT_VERSIONS, STYLE_PRESETS, parse_prompt_versions
+from .providers import PROVIDERS
+from .radar import GRAPHICS_MODES, MAX_FRAMES, detect_graphics
+from .render import (
+    StreamingProgress,
+    render_cache_entries,
+    render_cache_stats,
+    render_compare,
+    render_doctor,
+    render_favorites,
+    render_feedback_summary,
+    render_fish,
+    render_graph,
+    render_group_alerts,
+    render_history,
+    render_lake,
+    render_marine,
+    render_metar,
+    render_models,
+    render_parse_story,
+    render_prompt_comparison,
+    render_radar,
+    render_rain,
+    render_result,
+    render_roads,
+    render_route,
+    render_snowmap,
+    render_sun,
+    render_watch,
+    render_watch_story,
+    render_worldview,
+)
+from .risk import THRESHOLD_PRESETS, parse_thresholds, register_hazard_rules
+from .roads import DEFAULT_COMMUTE, parse_commute
+from .route import DEFAULT_SPEED_KMH, DEFAULT_STOPS, MAX_STOPS
+from .schema import OUTPUT_KINDS, json_document, output_schema
+from .site import write_site
+from .speech import SpeechError, speak_text, spoken_summary
+from .staleness import STALE_EXIT_CODE, stale_warning
+from .storyparse import parse_story
+from .storyprompt import STORY_TEMPLATE, check_story_template, load_story_template, story_prompt_path
+from .tables import TABLE_FORMATS, forecast_rows, history_table, write_table
+from .template import TemplateError, load_template, render_template
+from .tidy import (
+    DATA_FORMATS,
+    DEFAULT_HORIZON,
+    DataExportError,
+    parse_horizon,
+    tidy_rows,
+    write_csv,
+    write_parquet,
+)
+from .tui import run_dashboard
+from .watch import HookError, parse_hook, parse_interval, run_hook, run_watch, watch_payload
 
-COMMAND_NAMES = {"forecast", "risk", "explain", "alerts", "chat"}
-_OPTIONS_WITH_VALUES = {"--style", "--persona"}
+COMMAND_NAMES = {
+    "ask",
+    "compare",
+    "favorites",
+    "here",
+    "forecast",
+    "risk",
+    "explain",
+    "feedback",
+    "alerts",
+    "chat",
+    "world",
+    "snowmap",
+    "sun",
+    "history",
+    "models",
+    "radar",
+    "rain",
+    "fish",
+    "schema",
+    "lake",
+    "marine",
+    "metar",
+    "route",
+    "roads",
+    "graph",
+    "doctor",
+    "export",
+    "cache",
+    "profile",
+    "prompt",
+    "debug",
+    "tui",
+    "watch",
+}
+_OPTIONS_WITH_VALUES = {
+    "--style",
+    "--persona",
+    "--template",
+    "--format",
+    "--provider",
+    "--model",
+    "--lang",
+    "--prompt-version",
+    "--units",
+    "--timeout",
+}
 
 
 app = typer.Typer(add_completion=False, no_args_is_help=False)
+cache_app = typer.Typer(help="Inspect and manage the fetch cache.")
+app.add_typer(cache_app, name="cache")
+profile_app = typer.Typer(help="View and edit the active profile.")
+app.add_typer(profile_app, name="profile")
+prompt_app = typer.Typer(help="View, edit or reset the story prompt template.")
+app.add_typer(prompt_app, name="prompt")
+export_app = typer.Typer()
+app.add_typer(export_app, name="export")
+debug_app = typer.Typer()
+app.add_typer(debug_app, name="debug")
+# Plain profile fields ``wx profile set`` accepts alongside the API keys in SECRET_KEYS.
+PROFILE_FIELDS = (
+    "provider",
+    "model",
+    "language",
+    "units",
+    "default_location",
+    "ollama_url",
+    "ollama_model",
+    "decision_floor",
+    "decision_floor_mode",
+    "stale_obs",
+    "stale_forecast",
+    "style",
+    "thresholds",
+)
 console = Console()
+# Notes that must not mix with --json output on stdout.
+err_console = Console(stderr=True)
+FORECAST_EXPORTS = (*EXPORT_FORMATS, *TABLE_FORMATS)
+PLACE_HELP = "Place name, lat,lon or 'here'; default: the profile's default_location, else 'here'."
+ALERTS_PLACE_HELP = f"{PLACE_HELP[:-1]} Or {GROUP_PREFIX}group for a profile location group."
+MODEL_HELP = "AI model for this run only; a bare name applies to --ai-provider (e.g. anthropic:claude-opus-4-1)."
+AI_PROVIDER_HELP = f"AI backend to try first for this run only ({', '.join(AI_BACKENDS)})."
 
 
 @app.callback(invoke_without_command=True)
@@ -31,7 +194,9 @@ def cli_root(
     json_mode: bool = typer.Option(False, "--json", help="Emit raw JSON response."),  # noqa: B008
     debug: bool = typer.Option(False, "--debug", help="Show debug timing and metadata."),  # noqa: B008
     offline: bool | None = typer.Option(None, "--offline/--online", help="Skip network fetchers."),  # noqa: B008
-    style: StyleLiteral = typer.Option("standard", "--style", case_sensitive=False),  # noqa: B008
+    style: str | None = typer.Option(
+        None, "--style", help=f"Story style ({', '.join(STYLE_PRESETS)}); default WX_STYLE or the profile."
+    ),  # noqa: B008
     persona: PersonaLiteral = typer.Option("default", "--persona", case_sensitive=False),  # noqa: B008
     trust_tools: bool = typer.Option(
         False, "--trust-tools/--no-trust-tools", help="Allow network micro-fetchers."
@@ -39,19 +204,97 @@ def cli_root(
     severe: bool = typer.Option(
         False, "--severe", help="Filter for severe weather only (floods, tornadoes, severe thunderstorms)."
     ),  # noqa: B008
+    template: str | None = typer.Option(
+        None, "--template", help="Template file or string for output, e.g. '{{temp}}°{{unit}} {{conditions}}'."
+    ),  # noqa: B008
+    output_format: OutputFormatLiteral = typer.Option(  # noqa: B008
+        "rich",
+        "--format",
+        case_sensitive=False,
+        help="rich panels; eink: plain large-type text for e-paper; markdown or html: a document for wikis or the web.",
+    ),
+    provider: str | None = typer.Option(
+        None, "--provider", help=f"Weather data provider ({', '.join(PROVIDERS)})."
+    ),  # noqa: B008
+    model: str | None = typer.Option(
+        None, "--model", help="AI backend and model to try first, e.g. anthropic:claude-sonnet-4-5."
+    ),  # noqa: B008
+    no_cache: bool = typer.Option(
+        False, "--no-cache", help="Fetch fresh data instead of reusing recent results."
+    ),  # noqa: B008
+    no_stream: bool = typer.Option(
+        False, "--no-stream", help="Wait for the full AI response instead of streaming progress."
+    ),  # noqa: B008
+    lang: str | None = typer.Option(
+        None, "--lang", help="Language for AI answers and briefings (ISO code such as es, fr, ja)."
+    ),  # noqa: B008
+    prompt_version: str | None = typer.Option(
+        None, "--prompt-version", help=f"System prompt version ({', '.join(PROMPT_VERSIONS)})."
+    ),  # noqa: B008
+    units: str | None = typer.Option(
+        None, "--units", help="imperial (°F, mph, in) or metric (°C, m/s, mm); default UNITS or the profile."
+    ),  # noqa: B008
+    check: bool = typer.Option(
+        False, "--check", help=f"Exit with status {STALE_EXIT_CODE} when observations or forecasts are stale."
+    ),  # noqa: B008
+    timeout: float | None = typer.Option(
+        None, "--timeout", help="Seconds to wait for each weather data request; default WX_HTTP_TIMEOUT."
+    ),  # noqa: B008
 ):
     """Entry point that also handles freeform questions."""
 
-    settings = load_settings(debug=debug, offline=offline, style=style, persona=persona)
-    orchestrator = Orchestrator(settings, trust_tools=trust_tools)
+    profile = load_profile()
+    try:
+        settings = load_settings(
+            debug=debug,
+            offline=offline,
+            style=style,
+            persona=persona,
+            provider=provider,
+            model=model,
+            no_cache=no_cache,
+            no_stream=no_stream,
+            language=lang,
+            prompt_version=prompt_version,
+            units=units,
+            timeout=timeout,
+            profile=profile,
+        )
+    except ValueError as exc:
+        message = str(exc)
+        hints = {
+            "language": "--lang",
+            "prompt version": "--prompt-version",
+            "style": "--style",
+            "units": "--units",
+            "decision floor mode": "WX_DECISION_FLOOR_MODE",
+            "Decision floor": "WX_DECISION_FLOOR",
+            "Staleness bound": "WX_STALE_OBS/WX_STALE_FORECAST",
+            "HTTP timeout": "--timeout",
+        }
+        hint = next((flag for word, flag in hints.items() if word in message), "--model")
+        raise typer.BadParameter(message, param_hint=hint) from exc
+    if settings.provider not in PROVIDERS:
+        raise typer.BadParameter(
+            f"Unknown provider '{settings.provider}'. Choose from: {', '.join(PROVIDERS)}.", param_hint="--provider"
+        )
+    configure_http(retries=settings.http_retries, timeout=settings.http_timeout)
+    register_hazard_rules(profile.hazard_rules)
+    orchestrator = Orchestrator(settings, trust_tools=trust_tools, plugins=profile.plugins)
+    if settings.stream and not json_mode and console.is_terminal:
+        orchestrator.forecaster.on_progress = StreamingProgress(console)
     ctx.obj = {
         "settings": settings,
+        "profile": profile,
         "orchestrator": orchestrator,
         "json": json_mode,
         "debug": debug,
         "verbose": verbose,
         "trust_tools": trust_tools,
         "severe": severe,
+        "template": load_template(template) if template is not None else None,
+        "format": output_format,
+        "check": check,
     }
 
     if ctx.invoked_subcommand is not None:
@@ -59,45 +302,273 @@ def cli_root(
 
     # If no question provided, default to worldview
     if not question:
-        worldview = orchestrator.handle_worldview(verbose=verbose, severe_only=severe)
-        render_worldview(worldview, console=console, json_mode=json_mode, verbose=verbose or debug)
+        worldview = orchestrator.handle_worldview(
+            verbose=verbose, severe_only=severe, cities=profile.world_cities
+        )
+        render_worldview(
+            worldview,
+            console=console,
+            json_mode=json_mode,
+            verbose=verbose or debug,
+            template=ctx.obj["template"],
+        )
         return
 
     result = orchestrator.handle_question(question, verbose=verbose)
-    render_result(result, console=console, json_mode=json_mode, debug=debug, verbose=verbose)
+    render_result(
+        result,
+        console=console,
+        json_mode=json_mode,
+        debug=debug,
+        verbose=verbose,
+        template=ctx.obj["template"],
+        output_format=ctx.obj["format"],
+    )
+
+
+@app.command()
+def world(
+    ctx: typer.Context,
+    region: str | None = typer.Option(
+        None, "--region", help="Comma-separated regions or profile groups (us, europe, asia)."
+    ),  # noqa: B008
+    verbose: bool = typer.Option(False, "--verbose", help="Show sampling metadata."),  # noqa: B008
+):
+    """Regional weather snapshot using the profile's city list when defined."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    json_mode: bool = ctx.obj["json"]
+    debug: bool = ctx.obj["debug"]
+    regions = [normalize_region(r) for r in region.split(",") if r.strip()] if region else None
+    worldview = orchestrator.handle_worldview(
+        verbose=verbose,
+        severe_only=ctx.obj["severe"],
+        regions=regions,
+        cities=ctx.obj["profile"].world_cities,
+    )
+    render_worldview(
+        worldview,
+        console=console,
+        json_mode=json_mode,
+        verbose=verbose or debug,
+        template=ctx.obj["template"],
+    )
+
+
+@app.command()
+def ask(
+    ctx: typer.Context,
+    question: str = typer.Argument(..., help="Weather question, e.g. 'Will it rain in Austin tonight?'."),
+    data_only: bool = typer.Option(  # noqa: B008
+        False, "--data-only", help="Print a JSON answer object (answer, supporting_values, ...) instead of prose."
+    ),
+    verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
+    model: str | None = typer.Option(None, "--model", help=MODEL_HELP),  # noqa: B008
+    ai_provider: str | None = typer.Option(None, "--ai-provider", help=AI_PROVIDER_HELP),  # noqa: B008
+    speak: bool = typer.Option(False, "--speak", help="Read the bottom line and top decision aloud."),  # noqa: B008
+    speak_to: Path | None = typer.Option(  # noqa: B008
+        None, "--speak-to", help="Write the spoken summary to a WAV file instead of playing it."
+    ),
+):
+    """Answer a weather question, like `wx "<question>"`."""
+    _apply_ai_flags(ctx, model, ai_provider)
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    result = orchestrator.handle_question(question, verbose=verbose, data_only=data_only)
+    render_result(
+        result,
+        console=console,
+        json_mode=ctx.obj["json"],
+        debug=ctx.obj["debug"],
+        verbose=verbose,
+        template=None if data_only else ctx.obj["template"],
+        output_format=ctx.obj["format"],
+    )
+    _speak_result(result, speak, speak_to)
+
+
+@app.command()
+def here(
+    ctx: typer.Context,
+    ip_only: bool = typer.Option(  # noqa: B008
+        False, "--ip", help="Skip the OS location service and use IP geolocation (needs PRIVACY_MODE=0)."
+    ),
+):
+    """Show where wx thinks this machine is (used by the 'here' place)."""
+    settings = ctx.obj["settings"]
+    if ip_only and settings.privacy_mode:
+        raise typer.BadParameter("IP geolocation is off in privacy mode; set PRIVACY_MODE=0 to allow it.")
+    found = current_location(offline=settings.offline, use_os=not ip_only, use_ip=not settings.privacy_mode)
+    if found is None:
+        why = " IP geolocation is off in privacy mode." if settings.privacy_mode else ""
+        console.print(
+            f"[red]Could not detect your location.[/red] Offline, or no location service answered.{why}"
+        )
+        raise typer.Exit(1)
+    if ctx.obj["json"]:
+        console.print(json_document("location", {**asdict(found), "place": found.place}))
+        return
+    console.print(f"{found.label} ({found.lat:.4f}, {found.lon:.4f}) via {found.source}")
+    console.print(f"[dim]Try: wx forecast here   (uses {found.place})[/dim]")
 
 
 @app.command()
 def forecast(
     ctx: typer.Context,
-    place: str = typer.Argument(..., help="Target place name or lat,lon."),
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
     when: str | None = typer.Option(None, "--when", help="Natural language time hint."),  # noqa: B008
     horizon: str = typer.Option("24h", "--horizon", help="Forecast horizon", case_sensitive=False),  # noqa: B008
-    focus: str | None = typer.Option(None, "--focus", help="Primary hazard or interest."),  # noqa: B008
+    focus: str | None = typer.Option(  # noqa: B008
+        None,
+        "--focus",
+        help="Primary hazard or interest; 'aviation' adds METAR/TAF, 'marine' waves and tides, 'allergies' pollen.",
+    ),
     verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
+    compare_prompts: str | None = typer.Option(
+        None, "--compare-prompts", help="Prompt versions to show side by side, e.g. v1,v2."
+    ),  # noqa: B008
+    fixture: Path | None = typer.Option(  # noqa: B008
+        None, "--fixture", help="With --compare-prompts: reuse the Feature Pack from a --json result or chat recording."
+    ),
+    model: str | None = typer.Option(None, "--model", help=MODEL_HELP),  # noqa: B008
+    ai_provider: str | None = typer.Option(None, "--ai-provider", help=AI_PROVIDER_HELP),  # noqa: B008
+    at: str | None = typer.Option(  # noqa: B008
+        None, "--at", help="Forecast as it would have looked at a past moment (local time, e.g. '2026-10-10 06:00')."
+    ),
+    wait_for_update: bool = typer.Option(  # noqa: B008
+        False, "--wait-for-update", help="With --provider nws: wait for the next NWS forecast issuance first."
+    ),
+    export: str | None = typer.Option(  # noqa: B008
+        None,
+        "--export",
+        help=f"Write the forecast to stdout instead: {', '.join(FORECAST_EXPORTS)} (e.g. > weather.ics).",
+    ),
+    speak: bool = typer.Option(False, "--speak", help="Read the bottom line and top decision aloud."),  # noqa: B008
+    speak_to: Path | None = typer.Option(  # noqa: B008
+        None, "--speak-to", help="Write the spoken summary to a WAV file instead of playing it."
+    ),
 ):
     orchestrator: Orchestrator = ctx.obj["orchestrator"]
     json_mode: bool = ctx.obj["json"]
     debug: bool = ctx.obj["debug"]
-    result = orchestrator.handle_forecast(
-        place, when_text=when, horizon=horizon, focus=focus, verbose=verbose
+    _apply_ai_flags(ctx, model, ai_provider)
+    place = _place_or_default(ctx, place)
+    if compare_prompts is not None:
+        try:
+            versions = parse_prompt_versions(compare_prompts)
+        except ValueError as exc:
+            raise typer.BadParameter(str(exc), param_hint="--compare-prompts") from exc
+        try:
+            feature_pack = fixture_feature_pack(fixture) if fixture is not None else None
+        except ValueError as exc:
+            raise typer.BadParameter(str(exc), param_hint="--fixture") from exc
+        results = orchestrator.compare_prompts(
+            place, versions, when_text=when, horizon=horizon, focus=focus, verbose=verbose, fixture=feature_pack
+        )
+        render_prompt_comparison(results, console=console, json_mode=json_mode)
+        return
+    if fixture is not None:
+        raise typer.BadParameter("--fixture only applies with --compare-prompts.", param_hint="--fixture")
+    export = export.strip().lower() if export is not None else None
+    if export is not None and export not in FORECAST_EXPORTS:
+        raise typer.BadParameter(f"Choose from: {', '.join(FORECAST_EXPORTS)}.", param_hint="--export")
+    if wait_for_update:
+        if at is not None:
+            raise typer.BadParameter(
+                "--wait-for-update cannot be combined with --at.", param_hint="--wait-for-update"
+            )
+        _wait_for_update(orchestrator, place, json_mode)
+    as_of = None
+    if at is not None:
+        try:
+            as_of = date_parser.parse(at)
+        except (ValueError, OverflowError) as exc:
+            raise typer.BadParameter(f"'{at}' is not a date and time.", param_hint="--at") from exc
+    try:
+        result = orchestrator.handle_forecast(
+            place, when_text=when, horizon=horizon, focus=focus, verbose=verbose, at=as_of
+        )
+    except ValueError as exc:
+        if as_of is None:
+            raise
+        raise typer.BadParameter(str(exc), param_hint="--at") from exc
+    if export in TABLE_FORMATS:
+        _write_table(forecast_rows(result, units=ctx.obj["settings"].units), export, "forecast periods")
+        return
+    if export is not None:
+        sys.stdout.write(forecast_calendar(result, units=ctx.obj["settings"].units))
+        return
+    render_result(
+        result,
+        console=console,
+        json_mode=json_mode,
+        debug=debug,
+        verbose=verbose,
+        template=ctx.obj["template"],
+        output_format=ctx.obj["format"],
     )
-    render_result(result, console=console, json_mode=json_mode, debug=debug, verbose=verbose)
+    _speak_result(result, speak, speak_to)
+    _check_stale(ctx, result.feature_pack.get("stale"))
+
+
+def _write_table(rows: list[dict[str, Any]], fmt: str, what: str) -> None:
+    if not rows:
+        err_console.print(f"[red]No {what} to export.[/red]")
+        raise typer.Exit(1)
+    if fmt == "parquet" and sys.stdout.isatty():
+        raise typer.BadParameter("Parquet is binary; redirect it to a file, e.g. > weather.parquet.", param_hint="--export")
+    sys.stdout.flush()
+    try:
+        write_table(rows, fmt, sys.stdout.buffer)
+    except DataExportError as exc:
+        err_console.print(f"[red]{exc}[/red]")
+        raise typer.Exit(1) from exc
+    sys.stdout.flush()
+
+
+def _wait_for_update(orchestrator: Orchestrator, place: str, json_mode: bool) -> None:
+    def waiting(issuance: dict[str, Any] | None) -> None:
+        if issuance and not json_mode:
+            err_console.print(f"[dim]{issuance['label']}; waiting for a newer issuance…[/dim]")
+
+    try:
+        issuance = orchestrator.wait_for_update(place, on_wait=waiting)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--wait-for-update") from exc
+    if issuance is None:
+        err_console.print(f"[yellow]No newer NWS forecast within {MAX_WAIT_MINUTES} minutes.[/yellow]")
+        raise typer.Exit(1)
 
 
 @app.command()
 def risk(
     ctx: typer.Context,
-    place: str = typer.Argument(..., help="Target place name or lat,lon."),
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
     hazards: str | None = typer.Option(None, "--hazards", help="Comma-separated hazard list."),  # noqa: B008
+    ai: bool = typer.Option(False, "--ai/--no-ai", help="Ask the AI to explain the top risks."),  # noqa: B008
     verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
+    thresholds: str | None = typer.Option(  # noqa: B008
+        None, "--thresholds", help=f"Threshold preset ({', '.join(THRESHOLD_PRESETS)}); default: the profile's."
+    ),
 ):
     orchestrator: Orchestrator = ctx.obj["orchestrator"]
     json_mode: bool = ctx.obj["json"]
     debug: bool = ctx.obj["debug"]
     hazard_list = [h.strip() for h in hazards.split(",")] if hazards else None
-    result = orchestrator.handle_risk(place, hazards=hazard_list, verbose=verbose)
-    render_result(result, console=console, json_mode=json_mode, debug=debug, verbose=verbose)
+    try:
+        preset = parse_thresholds(thresholds or ctx.obj["profile"].thresholds)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--thresholds") from exc
+    place = _place_or_default(ctx, place)
+    result = orchestrator.handle_risk(place, hazards=hazard_list, verbose=verbose, ai=ai, thresholds=preset)
+    render_result(
+        result,
+        console=console,
+        json_mode=json_mode,
+        debug=debug,
+        verbose=verbose,
+        template=ctx.obj["template"],
+        output_format=ctx.obj["format"],
+    )
+    _check_stale(ctx, result.feature_pack.get("stale"))
 
 
 @app.command()
@@ -112,17 +583,20 @@ def explain(ctx: typer.Context):
         console.print(str(err))
         raise typer.Exit(1) from err
 
+    payload = {
+        "command": outcome.command,
+        "question": outcome.question,
+        "mode": outcome.mode,
+        "text": outcome.text,
+        "meta": outcome.meta,
+        "provider": outcome.response.provider,
+        "feature_pack": outcome.feature_pack,
+    }
+    if ctx.obj["template"] is not None:
+        console.print(render_template(ctx.obj["template"], payload), markup=False, highlight=False, soft_wrap=True)
+        return
     if json_mode:
-        payload = {
-            "command": outcome.command,
-            "question": outcome.question,
-            "mode": outcome.mode,
-            "text": outcome.text,
-            "meta": outcome.meta,
-            "provider": outcome.response.provider,
-            "feature_pack": outcome.feature_pack,
-        }
-        console.print(json.dumps(payload, indent=2))
+        console.print(json_document("explain", payload))
         return
 
     title = f"Explain ({outcome.mode})"
@@ -148,10 +622,42 @@ def explain(ctx: typer.Context):
         )
 
 
+@app.command()
+def feedback(
+    ctx: typer.Context,
+    rating: int | None = typer.Argument(None, min=1, max=5, help="Rate the last story from 1 (poor) to 5."),
+    flag: str | None = typer.Option(None, "--flag", help="Flag the last story as inaccurate and say why."),  # noqa: B008
+    note: str | None = typer.Option(None, "--note", help="Free-text comment stored with the rating."),  # noqa: B008
+):
+    """Rate the last story, or with no arguments show average ratings per backend."""
+    settings = ctx.obj["settings"]
+    store = FeedbackStore(feedback_path(settings))
+    if rating is None and flag is None:
+        render_feedback_summary(store.summary(), console=console, json_mode=ctx.obj["json"])
+        return
+    saved = settings.load_last_query() or {}
+    if not saved.get("story"):
+        console.print("No story to rate. Set PRIVACY_MODE=0 to rate one-shot commands, or use /rate in wx chat.")
+        raise typer.Exit(1)
+    entry = FeedbackEntry(
+        story=saved["story"],
+        rating=rating,
+        inaccurate=flag is not None,
+        note="; ".join(text for text in (flag, note) if text) or None,
+        command=saved.get("command"),
+    )
+    store.add(entry)
+    console.print(f"[green]✓ Feedback saved for {entry.story.get('provider')}.[/green]")
+
+
 @app.command()
 def alerts(
     ctx: typer.Context,
-    place: str = typer.Argument(..., help="Place name or lat,lon."),
+    place: str | None = typer.Argument(None, help=ALERTS_PLACE_HELP),
+    state: str | None = typer.Option(None, "--state", help="Two-letter state code for area-wide alerts."),  # noqa: B008
+    county: str | None = typer.Option(
+        None, "--county", help="County name (with --state) or NWS zone code such as WAC033."
+    ),  # noqa: B008
     ai: bool = typer.Option(False, "--ai/--no-ai", help="Ask the AI to triage alerts."),  # noqa: B008
     stream: bool = typer.Option(False, "--stream", help="Stream headlines (future feature)."),  # noqa: B008
     verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
@@ -159,20 +665,902 @@ def alerts(
     orchestrator: Orchestrator = ctx.obj["orchestrator"]
     json_mode: bool = ctx.obj["json"]
     debug: bool = ctx.obj["debug"]
-    result = orchestrator.handle_alerts(place, ai=ai, stream=stream, verbose=verbose)
-    render_result(result, console=console, json_mode=json_mode, debug=debug, verbose=verbose)
+    group = group_name(place)
+    if group is not None:
+        if state or county or ai:
+            raise typer.BadParameter("--state, --county and --ai work with one place, not a group.", param_hint="PLACE")
+        report = orchestrator.handle_group_alerts(group, _location_group(ctx, group))
+        render_group_alerts(report, console=console, json_mode=json_mode)
+        return
+    if state or county:
+        if county and not state and not is_zone_code(county):
+            raise typer.BadParameter("--county needs --state unless it is a zone code like WAC033.")
+        result = orchestrator.handle_area_alerts(state=state, county=county, ai=ai, verbose=verbose)
+    else:
+        place = _place_or_default(ctx, place)
+        result = orchestrator.handle_alerts(place, ai=ai, stream=stream, verbose=verbose)
+    render_result(
+        result,
+        console=console,
+        json_mode=json_mode,
+        debug=debug,
+        verbose=verbose,
+        template=ctx.obj["template"],
+        output_format=ctx.obj["format"],
+    )
+
+
+@app.command()
+def snowmap(
+    ctx: typer.Context,
+    place: str = typer.Argument(..., help="Place name or lat,lon."),
+):
+    """Expected and 10th/90th percentile snowfall with an accumulation chart."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    outlook = orchestrator.handle_snowmap(place)
+    render_snowmap(
+        outlook,
+        console=console,
+        json_mode=ctx.obj["json"],
+        units=ctx.obj["settings"].units,
+        template=ctx.obj["template"],
+    )
+
+
+@app.command()
+def graph(
+    ctx: typer.Context,
+    variable: str = typer.Argument(..., help="Forecast variable, e.g. temperature, dewpoint, gust, cape."),
+    place: str = typer.Argument(..., help="Place name or lat,lon."),
+    compare: str | None = typer.Option(None, "--compare", help="Second place to overlay."),  # noqa: B008
+    horizon: str = typer.Option("24h", "--horizon", help="Forecast horizon", case_sensitive=False),  # noqa: B008
+):
+    """Chart any hourly forecast variable over the horizon."""
+    resolved = resolve_variable(variable)
+    if resolved is None:
+        raise typer.BadParameter(
+            f"Unknown variable '{variable}'. Choose from: {', '.join(GRAPH_VARIABLES)}.", param_hint="variable"
+        )
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    places = [place, compare] if compare else [place]
+    series = orchestrator.handle_graph(resolved, places, horizon=horizon)
+    render_graph(series, console=console, json_mode=ctx.obj["json"], template=ctx.obj["template"])
+
+
+@app.command()
+def lake(
+    ctx: typer.Context,
+    place: str = typer.Argument(..., help="Place name or lat,lon."),
+):
+    """Water temperature, Great Lakes wave forecast and ice cover near a place."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_lake(place)
+    render_lake(
+        report,
+        console=console,
+        json_mode=ctx.obj["json"],
+        units=ctx.obj["settings"].units,
+        template=ctx.obj["template"],
+    )
 
 
 @app.command()
 def chat(
     ctx: typer.Context,
     verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
+    record: Path | None = typer.Option(  # noqa: B008
+        None, "--record", help="Save each turn, with its data and raw model output, to a JSON file."
+    ),
+    replay: Path | None = typer.Option(  # noqa: B008
+        None, "--replay", help="Show a recorded session offline instead of starting a new one."
+    ),
+    model: str | None = typer.Option(None, "--model", help=MODEL_HELP),  # noqa: B008
+    ai_provider: str | None = typer.Option(None, "--ai-provider", help=AI_PROVIDER_HELP),  # noqa: B008
 ):
     """Start an interactive conversational AI weather bot session."""
+    _apply_ai_flags(ctx, model, ai_provider)
     settings = ctx.obj["settings"]
     orchestrator: Orchestrator = ctx.obj["orchestrator"]
     json_mode: bool = ctx.obj["json"]
-    start_chat_session(settings, orchestrator, console, verbose=verbose, json_mode=json_mode)
+    if record and replay:
+        raise typer.BadParameter("--record and --replay cannot be combined.", param_hint="--replay")
+    if replay:
+        try:
+            replay_chat_session(replay, console, verbose=verbose, json_mode=json_mode, debug=settings.debug)
+        except ValueError as exc:
+            raise typer.BadParameter(str(exc), param_hint="--replay") from exc
+        return
+    start_chat_session(settings, orchestrator, console, verbose=verbose, json_mode=json_mode, record=record)
+
+
+@app.command()
+def watch(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    interval: str = typer.Option("10m", "--interval", help="Time between refreshes, e.g. 90s, 10m, 1h."),  # noqa: B008
+    count: int | None = typer.Option(  # noqa: B008
+        None, "--count", min=1, help="Stop after this many refreshes (default: until Ctrl+C)."
+    ),
+    story: bool = typer.Option(  # noqa: B008
+        True, "--story/--no-story", help="Regenerate the story when a new Severe or Extreme alert arrives."
+    ),
+    on_change: str | None = typer.Option(  # noqa: B008
+        None, "--on-change", help="Command to run, with the refresh's JSON on stdin, whenever something changed."
+    ),
+    on_alert: str | None = typer.Option(  # noqa: B008
+        None, "--on-alert", help="Command to run, with the refresh's JSON on stdin, when a new alert is issued."
+    ),
+):
+    """Re-fetch conditions and alerts on an interval, highlighting what changed."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    settings = ctx.obj["settings"]
+    try:
+        seconds = parse_interval(interval)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--interval") from exc
+    hooks: dict[str, tuple[str, ...]] = {}
+    for flag, value in (("--on-change", on_change), ("--on-alert", on_alert)):
+        if value is not None:
+            try:
+                hooks[flag] = parse_hook(value)
+            except ValueError as exc:
+                raise typer.BadParameter(str(exc), param_hint=flag) from exc
+    place = _place_or_default(ctx, place)
+    place_info = orchestrator.provider.geocode(place)
+    if not place_info or not isinstance(place_info.get("lat"), (int, float)):
+        console.print(f"[red]Could not find location: {place}[/red]")
+        raise typer.Exit(1)
+
+    def emit(snapshot, changes) -> None:
+        render_watch(snapshot, changes, console=console, units=settings.units, json_mode=ctx.obj["json"])
+        if hooks and changes:
+            _run_watch_hooks(hooks, snapshot, changes)
+        # With --check a dashboard's watch stops at the first stale refresh instead of repeating it.
+        _check_stale(ctx, snapshot.stale)
+
+    def regenerate(snapshot, alerts) -> None:
+        # The refresh just stored the new alert in the fetch cache, so the story includes it.
+        result = orchestrator.handle_forecast(place, when_text=None, horizon="24h", focus=None, verbose=False)
+        render_watch_story(result, alerts, console=console, json_mode=ctx.obj["json"])
+
+    try:
+        run_watch(
+            orchestrator.provider,
+            place_info,
+            units=settings.units,
+            interval=seconds,
+            count=count,
+            emit=emit,
+            stale_after=(settings.stale_obs_minutes, settings.stale_forecast_minutes),
+            on_severe=regenerate if story else None,
+        )
+    except KeyboardInterrupt:
+        console.print("[dim]Stopped watching.[/dim]")
+
+
+def _run_watch_hooks(hooks: dict[str, tuple[str, ...]], snapshot, changes) -> None:
+    """Run --on-change for any change and --on-alert for a new alert, each with the refresh's JSON."""
+    new_alert = any(change.kind == "alert_new" for change in changes)
+    document = json_document("watch", watch_payload(snapshot, changes), indent=None)
+    for flag, command in hooks.items():
+        if flag == "--on-alert" and not new_alert:
+            continue
+        try:
+            run_hook(command, document)
+        except HookError as exc:
+            # A broken home-automation script should not stop the watch.
+            err_console.print(f"[yellow]{flag} command {exc}.[/yellow]")
+
+
+@app.command()
+def favorites(
+    ctx: typer.Context,
+    group: str | None = typer.Argument(  # noqa: B008
+        None, help=f"Compare a profile location group instead, e.g. {GROUP_PREFIX}stores."
+    ),
+    profiles: str | None = typer.Option(  # noqa: B008
+        None, "--profiles", help="Combine several profiles' favorites, e.g. me,partner,kids (shared places once)."
+    ),
+):
+    """Compare the profile's favorite places (conditions, 12-hour outlook, alerts) in one table."""
+    favorite_places = ctx.obj["profile"].favorites
+    title = "Favorites"
+    if group is not None:
+        name = group_name(group)
+        if name is None or profiles is not None:
+            raise typer.BadParameter(
+                f"Give a location group as {GROUP_PREFIX}name, without --profiles.", param_hint="GROUP"
+            )
+        favorite_places, title = _location_group(ctx, name, param_hint="GROUP"), f"{GROUP_PREFIX}{name}"
+    elif profiles is not None:
+        groups = []
+        for name in dict.fromkeys(part.strip() for part in profiles.split(",") if part.strip()):
+            profile = load_profile(name)
+            if profile.path is None or not profile.path.exists():
+                raise typer.BadParameter(f"No profile named '{name}'.", param_hint="--profiles")
+            groups.append((name, profile.favorites))
+        favorite_places = merge_favorites(groups)
+    if not favorite_places:
+        console.print(
+            'No favorites yet. Add them to your profile JSON, e.g. "favorites": {"home": "Seattle", "cabin": "47.1,-121.5"}.'
+        )
+        raise typer.Exit(1)
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    reports = orchestrator.handle_favorites(favorite_places)
+    render_favorites(
+        reports, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"], title=title
+    )
+    _check_stale(ctx, [item for report in reports if report.snapshot for item in report.snapshot.stale])
+
+
+@app.command()
+def roads(
+    ctx: typer.Context,
+    commute: str | None = typer.Option(  # noqa: B008
+        None, "--commute", help="Local commute windows, e.g. 06:30-08:30,15:00-17:30 (default: the profile's)."
+    ),
+):
+    """Fog and black-ice risk on the profile's road segments during commute windows."""
+    profile = ctx.obj["profile"]
+    if not profile.roads:
+        console.print(
+            'No road segments yet. Add them to your profile JSON, e.g. '
+            '"roads": {"Route 9 bridge": {"place": "42.10,-73.91", "bridge": true}}.'
+        )
+        raise typer.Exit(1)
+    windows = parse_commute(commute) if commute is not None else profile.commute
+    if commute is not None and windows is None:
+        raise typer.BadParameter("Give windows as HH:MM-HH:MM, comma-separated.", param_hint="--commute")
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    reports = orchestrator.handle_roads(profile.roads, commute=windows or DEFAULT_COMMUTE)
+    render_roads(reports, console=console, json_mode=ctx.obj["json"])
+
+
+@app.command()
+def marine(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+):
+    """Waves, swell, water temperature and tides off a coastal place."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_marine(_place_or_default(ctx, place))
+    render_marine(report, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+
+
+@app.command()
+def history(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    on: str = typer.Option(..., "--date", help="Day to look up, or the first day of a range (YYYY-MM-DD)."),  # noqa: B008
+    to: str | None = typer.Option(None, "--to", help="Last day of the range (YYYY-MM-DD)."),  # noqa: B008
+    compare_to_normal: bool = typer.Option(  # noqa: B008
+        False, "--compare-to-normal", help="Show each day's departure from the climate normal."
+    ),
+    export: str | None = typer.Option(  # noqa: B008
+        None, "--export", help=f"Write the days to stdout as a table instead: {', '.join(TABLE_FORMATS)}."
+    ),
+):
+    """Past daily weather from the Open-Meteo archive (1940 to a few days ago)."""
+    try:
+        start = date.fromisoformat(on)
+    except ValueError as exc:
+        raise typer.BadParameter(f"'{on}' is not a YYYY-MM-DD date.", param_hint="--date") from exc
+    try:
+        end = date.fromisoformat(to) if to else None
+    except ValueError as exc:
+        raise typer.BadParameter(f"'{to}' is not a YYYY-MM-DD date.", param_hint="--to") from exc
+    try:
+        start, end = validate_range(start, end)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--date") from exc
+    export = export.strip().lower() if export is not None else None
+    if export is not None and export not in TABLE_FORMATS:
+        raise typer.BadParameter(f"Choose from: {', '.join(TABLE_FORMATS)}.", param_hint="--export")
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_history(
+        _place_or_default(ctx, place), start, end, compare_to_normal=compare_to_normal
+    )
+    if export is not None:
+        if report.error:
+            err_console.print(f"[red]{report.error}[/red]")
+            raise typer.Exit(1)
+        _write_table(history_table(report), export, "days")
+        return
+    render_history(report, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+
+
+@app.command()
+def rain(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    minutes: int = typer.Option(  # noqa: B008
+        NOWCAST_MINUTES, "--minutes", min=STEP_MINUTES, max=MAX_NOWCAST_MINUTES, help="How far ahead to look."
+    ),
+):
+    """Will it rain in the next hour or two? Start and stop times plus a 15-minute intensity chart."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_rain(_place_or_default(ctx, place), minutes=minutes)
+    render_rain(report, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+    if report.error:
+        raise typer.Exit(1)
+
+
+@app.command()
+def fish(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    hours: int = typer.Option(  # noqa: B008
+        FISH_HOURS, "--hours", min=6, max=MAX_FISH_HOURS, help="How far ahead to score."
+    ),
+):
+    """When to go fishing: an hourly score from solunar periods, pressure, wind, rain and tide."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_fish(_place_or_default(ctx, place), hours=hours)
+    render_fish(report, console=console, json_mode=ctx.obj["json"])
+    if report.error:
+        raise typer.Exit(1)
+
+
+@app.command()
+def models(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    hours: int = typer.Option(  # noqa: B008
+        MODELS_HOURS, "--hours", min=BLOCK_HOURS, max=MAX_MODELS_HOURS, help="How far ahead to compare."
+    ),
+):
+    """GFS, ECMWF and ICON side by side: temperature and precipitation, and where they disagree."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_models(_place_or_default(ctx, place), hours=hours)
+    render_models(report, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+    if report.error:
+        raise typer.Exit(1)
+
+
+@app.command()
+def radar(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    loop: int | None = typer.Option(  # noqa: B008
+        None, "--loop", min=2, max=MAX_FRAMES, help=f"Animate the last N frames (2-{MAX_FRAMES}, about 10 minutes apart)."
+    ),
+    graphics: str = typer.Option(  # noqa: B008
+        "auto", "--graphics", help="auto, blocks (unicode half-blocks), kitty or sixel."
+    ),
+):
+    """Latest precipitation radar around a place, drawn in the terminal."""
+    mode = graphics.strip().lower()
+    if mode not in GRAPHICS_MODES:
+        raise typer.BadParameter(f"Choose from: {', '.join(GRAPHICS_MODES)}.", param_hint="--graphics")
+    if mode == "auto":
+        # Graphics escapes are noise in a pipe or a file.
+        mode = detect_graphics() if console.is_terminal else "blocks"
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_radar(_place_or_default(ctx, place), frames=loop or 1)
+    try:
+        render_radar(report, console=console, json_mode=ctx.obj["json"], graphics=mode, loop=loop is not None)
+    except KeyboardInterrupt:
+        pass
+    if report.error:
+        raise typer.Exit(1)
+
+
+@app.command()
+def sun(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help=PLACE_HELP),
+    days: int = typer.Option(  # noqa: B008
+        1, "--days", min=1, max=SUN_MAX_DAYS, help=f"Days to show, starting today (up to {SUN_MAX_DAYS})."
+    ),
+):
+    """Sunrise, sunset, twilight, golden hours with forecast cloud cover, and the moon."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_sun(_place_or_default(ctx, place), days=days)
+    render_sun(report, console=console, json_mode=ctx.obj["json"])
+
+
+@app.command()
+def metar(
+    ctx: typer.Context,
+    place: str | None = typer.Argument(None, help="ICAO airport code (e.g. KSEA) or a place for its nearest airport."),
+    taf: bool = typer.Option(True, "--taf/--no-taf", help="Include the airport's TAF."),  # noqa: B008
+    advisories: bool = typer.Option(  # noqa: B008
+        True, "--advisories/--no-advisories", help="Include SIGMETs, AIRMETs, G-AIRMETs and recent PIREPs."
+    ),
+    raw: bool = typer.Option(False, "--raw", help="Also print the undecoded reports."),  # noqa: B008
+):
+    """Decoded METAR and TAF with flight categories (VFR/MVFR/IFR/LIFR), advisories and PIREPs."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_metar(_place_or_default(ctx, place), taf=taf, advisories=advisories)
+    render_metar(report, console=console, json_mode=ctx.obj["json"], raw=raw)
+    if report.metar is None:
+        raise typer.Exit(1)
+
+
+@app.command()
+def route(
+    ctx: typer.Context,
+    origin: str = typer.Argument(..., help="Starting place (name, lat,lon or 'here')."),
+    destination: str = typer.Argument(..., help="Destination place."),
+    depart: str | None = typer.Option(  # noqa: B008
+        None, "--depart", help="Departure time, e.g. '2026-10-17 07:30' or '18:00' (local time; default: now)."
+    ),
+    speed: float | None = typer.Option(  # noqa: B008
+        None, "--speed", min=5, help=f"Average speed in km/h (default: OSRM drive time, else {DEFAULT_SPEED_KMH:.0f})."
+    ),
+    stops: int = typer.Option(DEFAULT_STOPS, "--stops", min=2, max=MAX_STOPS, help="Points sampled along the route."),  # noqa: B008
+    straight: bool = typer.Option(  # noqa: B008
+        False, "--straight", help="Skip the OSRM road route and follow the great circle."
+    ),
+):
+    """Conditions along the way: forecasts for points on a drive, timed to when you reach them."""
+    departure = None
+    if depart:
+        try:
+            departure = date_parser.parse(depart)
+        except (ValueError, OverflowError) as exc:
+            raise typer.BadParameter(f"Could not read '{depart}' as a time.", param_hint="--depart") from exc
+        departure = departure.astimezone()
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    report = orchestrator.handle_route(
+        _place_or_default(ctx, origin),
+        _place_or_default(ctx, destination),
+        depart=departure,
+        speed_kmh=speed,
+        stops=stops,
+        roads=not straight,
+    )
+    render_route(report, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+    if report.error:
+        raise typer.Exit(1)
+
+
+@app.command()
+def compare(
+    ctx: typer.Context,
+    places: list[str] = typer.Argument(..., help="Two or more places (names, lat,lon or 'here')."),  # noqa: B008
+    ai: bool = typer.Option(False, "--ai", help="Add a short AI paragraph comparing the places."),  # noqa: B008
+    question: str | None = typer.Option(  # noqa: B008
+        None, "--question", help="What the AI comparison should answer, e.g. 'Where is best for a bike ride?'."
+    ),
+    verbose: bool = typer.Option(False, "--verbose", help="Allow responses beyond 400 words."),  # noqa: B008
+    model: str | None = typer.Option(None, "--model", help=MODEL_HELP),  # noqa: B008
+    ai_provider: str | None = typer.Option(None, "--ai-provider", help=AI_PROVIDER_HELP),  # noqa: B008
+):
+    """Side-by-side current conditions and next-24h highlights for several places."""
+    if len(places) < 2:
+        raise typer.BadParameter("Name at least two places to compare.", param_hint="PLACES")
+    _apply_ai_flags(ctx, model, ai_provider)
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    resolved = [_place_or_default(ctx, place) for place in places]
+    result = orchestrator.handle_compare(resolved, ai=ai or question is not None, question=question, verbose=verbose)
+    render_compare(result, console=console, units=ctx.obj["settings"].units, json_mode=ctx.obj["json"])
+
+
+@app.command()
+def tui(
+    ctx: typer.Context,
+    places: list[str] | None = typer.Argument(  # noqa: B008
+        None, help="Places to switch between (default: the profile's world_cities)."
+    ),
+):
+    """Full-screen dashboard with conditions, hourly chart, alerts and the forecast timeline."""
+    orchestrator: Orchestrator = ctx.obj["orchestrator"]
+    names = list(places or []) or [city.name for city in ctx.obj["profile"].world_cities]
+    if not names:
+        raise typer.BadParameter("Name at least one place or add world_cities to your profile.", param_hint="PLACES")
+    if ctx.obj["json"] or not console.is_terminal:
+        console.print("wx tui needs an interactive terminal; use 'wx forecast' for scripted output.")
+        raise typer.Exit(1)
+    # The dashboard owns the screen, so no separate streaming indicator.
+    orchestrator.forecaster.on_progress = None
+    run_dashboard(orchestrator, names[:9], console)
+
+
+@app.command()
+def doctor(
+    ctx: typer.Context,
+    bundle: bool = typer.Option(False, "--bundle", help="Write a redacted .tar.gz for bug reports."),  # noqa: B008
+    output: Path | None = typer.Option(  # noqa: B008
+        None, "--output", help="Bundle path (default: ./wx-doctor-<timestamp>.tar.gz)."
+    ),
+    fixture: list[Path] | None = typer.Option(  # noqa: B008
+        None, "--fixture", help="Extra file to include, e.g. a chat --record session. Repeatable."
+    ),
+):
+    """Check configuration and optionally bundle redacted diagnostics."""
+    settings = ctx.obj["settings"]
+    profile = ctx.obj["profile"]
+    render_doctor(collect_diagnostics(settings, profile), console=console, json_mode=ctx.obj["json"])
+    if not bundle:
+        return
+    stamp = datetime.now(UTC).strftime("%Y%m%dT%H%M%SZ")
+    path = write_bundle(output or Path(f"wx-doctor-{stamp}.tar.gz"), settings, profile, fixtures=fixture)
+    console.print(f"Wrote {path} (API keys removed, coordinates rounded to ~10 km).")
+
+
+@app.command()
+def schema(
+    kind: str | None = typer.Argument(  # noqa: B008
+        None, help="Only this output kind, e.g. story or fish (default: every kind)."
+    ),
+    list_kinds: bool = typer.Option(False, "--list", help="List the output kinds instead."),  # noqa: B008
+):
+    """Print the JSON Schema of '--json' output (one envelope per command, versioned)."""
+    if list_kinds:
+        for name, (description, _) in OUTPUT_KINDS.items():
+            console.print(f"{name:<18} {description}", markup=False, highlight=False)
+        return
+    try:
+        document = output_schema(kind)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="KIND") from exc
+    console.print(json.dumps(document, indent=2, ensure_ascii=True), markup=False, highlight=False, soft_wrap=True)
+
+
+def _fetch_cache(ctx: typer.Context) -> FetchCache:
+    # Walk up from the cache subcommand to the root context holding settings.
+    return FetchCache(cache_path(ctx.find_root().obj["settings"]))
+
+
+@export_app.callback()
+def export() -> None:
+    """Write wx output to files for publishing or analysis."""
+
+
+@export_app.command("site")
+def export_site(
+    ctx: typer.Context,
+    locations: str | None = typer.Option(  # noqa: B008
+        None, "--locations", help="Comma-separated favorite names or places (default: every favorite)."
+    ),
+    out: Path = typer.Option(Path("dist"), "--out", help="Directory for index.html and data.json."),  # noqa: B008
+):
+    """Static HTML dashboard (conditions, outlook, alerts) with its data embedded, for any static host."""
+    obj = ctx.find_root().obj
+    chosen = _export_locations(ctx, locations)
+    orchestrator: Orchestrator = obj["orchestrator"]
+    reports = orchestrator.handle_favorites(chosen)
+    attribution = [*getattr(orchestrator.provider, "attribution", ()), "geonames"]
+    if any(report.snapshot and report.snapshot.alerts for report in reports):
+        attribution.append("nws")
+    paths = write_site(reports, out, units=obj["settings"].units, attribution=attribution)
+    failed = [report.label for report in reports if report.snapshot is None]
+    console.print(f"Wrote {', '.join(str(path) for path in paths)}")
+    if failed:
+        err_console.print(f"[yellow]No data for {', '.join(failed)}.[/yellow]")
+
+
+@export_app.command("data")
+def export_data(
+    ctx: typer.Context,
+    locations: str | None = typer.Option(  # noqa: B008
+        None, "--locations", help=f"{GROUP_PREFIX}group, or comma-separated favorites or places (default: every favorite)."
+    ),
+    horizon: str = typer.Option(DEFAULT_HORIZON, "--horizon", help="How far ahead, e.g. 48h or 7d (up to 16d)."),  # noqa: B008
+    data_format: str = typer.Option("csv", "--format", help=f"One of: {', '.join(DATA_FORMATS)}."),  # noqa: B008
+    out: Path | None = typer.Option(None, "--out", help="File to write; CSV goes to stdout without it."),  # noqa: B008
+):
+    """Hourly forecasts as a tidy table (location, time, variable, value, unit) for analysis."""
+    obj = ctx.find_root().obj
+    try:
+        hours = parse_horizon(horizon)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--horizon") from exc
+    data_format = data_format.strip().lower()
+    if data_format not in DATA_FORMATS:
+        raise typer.BadParameter(f"Choose from: {', '.join(DATA_FORMATS)}.", param_hint="--format")
+    if data_format == "parquet" and out is None:
+        raise typer.BadParameter("Parquet is binary; give a file with --out.", param_hint="--out")
+    chosen = _export_locations(ctx, locations)
+    orchestrator: Orchestrator = obj["orchestrator"]
+    series = orchestrator.handle_data_export(chosen, hours=hours)
+    rows = tidy_rows(series, units=obj["settings"].units)
+    if out is None:
+        write_csv(rows, sys.stdout)
+    else:
+        try:
+            if data_format == "parquet":
+                write_parquet(rows, out)
+            else:
+                with out.open("w", newline="", encoding="utf-8") as handle:
+                    write_csv(rows, handle)
+        except DataExportError as exc:
+            err_console.print(f"[red]{exc}[/red]")
+            raise typer.Exit(1) from exc
+        err_console.print(f"Wrote {len(rows)} rows for {len(series)} locations to {out}")
+    failed = [site.label for site in series if site.error]
+    if failed:
+        err_console.print(f"[yellow]No data for {', '.join(failed)}.[/yellow]")
+
+
+def _export_locations(ctx: typer.Context, locations: str | None) -> list[Favorite]:
+    """``--locations`` as places: a location group, named favorites or places, else every favorite."""
+    profile = ctx.find_root().obj["profile"]
+    group = group_name(locations)
+    if group is not None:
+        return _location_group(ctx.find_root(), group, param_hint="--locations")
+    favorites = {favorite.label.lower(): favorite for favorite in profile.favorites}
+    if locations:
+        names = [name.strip() for name in locations.split(",") if name.strip()]
+        chosen = [favorites.get(name.lower()) or Favorite(name, name) for name in names]
+    else:
+        chosen = list(favorites.values())
+    if not chosen:
+        raise typer.BadParameter(
+            "Name places with --locations or add favorites to your profile.", param_hint="--locations"
+        )
+    return chosen
+
+
+@cache_app.command("stats")
+def cache_stats(ctx: typer.Context):
+    """Show entry counts, size and the oldest entry."""
+    render_cache_stats(_fetch_cache(ctx).stats(), console=console, json_mode=ctx.find_root().obj["json"])
+
+
+@cache_app.command("list")
+def cache_list(
+    ctx: typer.Context,
+    namespace: str | None = typer.Option(  # noqa: B008
+        None, "--namespace", help="Only geocode, current, forecast or alerts entries."
+    ),
+):
+    """List cached entries, oldest first."""
+    rows = _fetch_cache(ctx).entries()
+    if namespace:
+        rows = [row for row in rows if row["namespace"] == namespace.lower()]
+    render_cache_entries(rows, console=console, json_mode=ctx.find_root().obj["json"])
+
+
+@cache_app.command("get")
+def cache_get(ctx: typer.Context, key: str = typer.Argument(..., help="Full key as shown by 'wx cache list'.")):
+    """Print one cached value as JSON."""
+    entry = _fetch_cache(ctx).lookup(key)
+    if entry is None:
+        console.print(f"No cache entry '{key}'.")
+        raise typer.Exit(1)
+    console.print(json_document("cache_entry", entry))
+
+
+@cache_app.command("prune")
+def cache_prune(ctx: typer.Context):
+    """Remove entries past their time-to-live."""
+    removed = _fetch_cache(ctx).prune()
+    console.print(f"Removed {removed} expired entr{'y' if removed == 1 else 'ies'}.")
+
+
+@cache_app.command("clear")
+def cache_clear(ctx: typer.Context):
+    """Remove every cached entry."""
+    removed = _fetch_cache(ctx).clear()
+    console.print(f"Cleared {removed} entr{'y' if removed == 1 else 'ies'}.")
+
+
+@profile_app.command("show")
+def profile_show():
+    """Show the active profile's settings; API keys are never printed."""
+    data = read_profile_data()
+    for key in PROFILE_FIELDS:
+        if key in data:
+            console.print(f"[cyan]{key}[/cyan]: {data[key]}")
+    for key in SECRET_KEYS:
+        source = secret_source(data.get(key))
+        if source:
+            console.print(f"[cyan]{key}[/cyan]: [dim]set ({source})[/dim]")
+    if "world_cities" in data:
+        console.print(f"[cyan]world_cities[/cyan]: {len(data['world_cities'])} entries")
+    profile = load_profile()
+    if profile.groups:
+        groups = ", ".join(f"{name} ({len(sites)})" for name, sites in profile.groups.items())
+        console.print(f"[cyan]groups[/cyan]: {groups}")
+    for plugin in profile.plugins:
+        timeout = f"[dim](timeout {plugin.timeout:g}s)[/dim]"
+        console.print(f"[cyan]plugins.{plugin.name}[/cyan]: {shlex.join(plugin.command)} {timeout}")
+    for rule in profile.hazard_rules:
+        console.print(
+            f"[cyan]hazard_rules.{rule.name}[/cyan]: {rule.variable} {rule.low:g} → {rule.high:g}"
+            + (f" {rule.unit}" if rule.unit else "")
+        )
+    if profile.roads:
+        roads = ", ".join(road.name + (" (bridge)" if road.bridge else "") for road in profile.roads)
+        console.print(f"[cyan]roads[/cyan]: {roads}")
+    if profile.commute:
+        console.print(f"[cyan]commute[/cyan]: {', '.join(profile.commute)}")
+
+
+@profile_app.command("set")
+def profile_set(
+    key: str = typer.Argument(..., help=f"One of: {', '.join([*PROFILE_FIELDS, *SECRET_KEYS])}."),
+    value: str | None = typer.Argument(None, help="New value; API keys are prompted for when omitted."),
+    plaintext: bool = typer.Option(  # noqa: B008
+        False, "--plaintext", help="Store an API key in the profile JSON instead of the OS keyring."
+    ),
+):
+    """Set a profile field. API keys go to the OS keyring; the JSON keeps only a reference."""
+    key = key.strip().lower()
+    if key not in PROFILE_FIELDS and key not in SECRET_KEYS:
+        raise typer.BadParameter(f"Unknown profile key '{key}'.", param_hint="KEY")
+    if value is None:
+        if key not in SECRET_KEYS:
+            raise typer.BadParameter(f"A value is required for {key}.", param_hint="VALUE")
+        value = typer.prompt(key, hide_input=True)
+    data = read_profile_data()
+    profile = load_profile()
+    if key in SECRET_KEYS:
+        if not plaintext and not keyring_available():
+            console.print(
+                "[red]The keyring package is not installed.[/red] "
+                "Install it with pip install 'wx-cli[keyring]' or pass --plaintext."
+            )
+            raise typer.Exit(1)
+        delete_secret(data.get(key))
+        try:
+            data[key] = value if plaintext else store_secret(profile.name, key, value)
+        except KeystoreError as exc:
+            console.print(f"[red]{exc}[/red]")
+            raise typer.Exit(1) from exc
+        where = "profile JSON (plaintext)" if plaintext else "OS keyring"
+    else:
+        data[key] = value
+        where = "profile JSON"
+    path = write_profile_data(data)
+    console.print(f"Saved {key} to the {where} for profile '{profile.name}' ({path}).")
+
+
+@profile_app.command("unset")
+def profile_unset(key: str = typer.Argument(..., help="Profile field or API key to remove.")):
+    """Remove a profile field, deleting its keyring entry if it has one."""
+    key = key.strip().lower()
+    data = read_profile_data()
+    if key not in data:
+        console.print(f"'{key}' is not set.")
+        raise typer.Exit(1)
+    delete_secret(data.pop(key))
+    write_profile_data(data)
+    console.print(f"Removed {key}.")
+
+
+@prompt_app.command("show")
+def prompt_show(
+    default: bool = typer.Option(False, "--default", help="Show the built-in template even when customized."),  # noqa: B008
+):
+    """Print the story prompt template in use and where it comes from."""
+    path = story_prompt_path()
+    template = None if default else load_story_template()
+    if template is None:
+        console.print(f"[dim]Built-in story prompt (customize with 'wx prompt edit', saved to {path}):[/dim]")
+    else:
+        console.print(f"[dim]Custom story prompt from {path}:[/dim]")
+    console.print(template or STORY_TEMPLATE, markup=False, highlight=False, end="")
+    try:
+        check_story_template(template or STORY_TEMPLATE)
+    except TemplateError as exc:
+        console.print(f"[red]{exc}[/red]")
+        raise typer.Exit(1) from exc
+
+
+@prompt_app.command("edit")
+def prompt_edit():
+    """Open the story prompt in $VISUAL or $EDITOR, starting from the built-in template."""
+    path = story_prompt_path()
+    if not path.exists():
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(STORY_TEMPLATE, encoding="utf-8")
+    editor = os.getenv("VISUAL") or os.getenv("EDITOR") or ("notepad" if os.name == "nt" else "vi")
+    try:
+        subprocess.run([*shlex.split(editor), str(path)], check=True)
+    except (OSError, subprocess.CalledProcessError) as exc:
+        console.print(f"[red]Could not run {editor}: {exc}[/red] Edit {path} directly.")
+        raise typer.Exit(1) from exc
+    template = load_story_template()
+    try:
+        check_story_template(template or STORY_TEMPLATE)
+    except TemplateError as exc:
+        console.print(f"[red]{exc}[/red] Fix {path} or run 'wx prompt reset'.")
+        raise typer.Exit(1) from exc
+    if template is None:
+        console.print(f"{path} is empty; stories use the built-in prompt.")
+        return
+    console.print(f"Saved {path}; forecast, risk and alerts stories use it from now on.")
+
+
+@prompt_app.command("reset")
+def prompt_reset():
+    """Delete the custom story prompt and go back to the built-in one."""
+    path = story_prompt_path()
+    if not path.exists():
+        console.print("No custom story prompt; stories already use the built-in one.")
+        return
+    path.unlink()
+    console.print(f"Removed {path}; stories use the built-in prompt again.")
+
+
+@debug_app.callback()
+def debug() -> None:
+    """Tools for triaging problems reported by users."""
+    # A callback keeps "parse-story" a subcommand while it is the only debug tool.
+
+
+@debug_app.command("parse-story")
+def debug_parse_story(
+    ctx: typer.Context,
+    path: str = typer.Argument(..., help="File holding a raw AI reply, or - for stdin."),
+):
+    """Run a saved AI reply through the story repair and validation steps."""
+    try:
+        raw = sys.stdin.read() if path == "-" else Path(path).read_text(encoding="utf-8")
+    except OSError as exc:
+        raise typer.BadParameter(f"Cannot read {path}: {exc.strerror}.", param_hint="PATH") from exc
+    parsed = parse_story(raw)
+    render_parse_story(parsed, console=console, json_mode=ctx.find_root().obj["json"])
+    if not parsed.ok:
+        raise typer.Exit(1)
+
+
+def _speak_result(result, speak: bool, speak_to: Path | None) -> None:
+    """Read (or save as WAV) the spoken summary of a rendered result."""
+    if not speak and speak_to is None:
+        return
+    try:
+        engine = speak_text(spoken_summary(result.response), output=speak_to)
+    except SpeechError as exc:
+        err_console.print(f"[red]{exc}[/red]")
+        raise typer.Exit(1) from exc
+    if speak_to is not None:
+        err_console.print(f"[dim]Saved spoken summary to {speak_to} ({engine}).[/dim]")
+
+
+def _check_stale(ctx: typer.Context, stale: list[dict[str, Any]] | None) -> None:
+    """With ``--check``, exit non-zero after the output when any data was stale."""
+    if ctx.obj["check"] and stale:
+        err_console.print(f"[red]{stale_warning(stale)}[/red]")
+        raise typer.Exit(STALE_EXIT_CODE)
+
+
+def _apply_ai_flags(ctx: typer.Context, model: str | None, ai_provider: str | None) -> None:
+    """Apply a command's --model/--ai-provider on top of the global settings."""
+    try:
+        apply_ai_override(ctx.obj["settings"], model=model, backend=ai_provider)
+    except ValueError as exc:
+        raise typer.BadParameter(str(exc), param_hint="--ai-provider" if ai_provider else "--model") from exc
+
+
+def _location_group(ctx: typer.Context, name: str, param_hint: str = "PLACE") -> list[Favorite]:
+    """The members of the profile's location group ``name``, or a usage error naming the groups."""
+    profile = ctx.obj["profile"]
+    if name in profile.groups:
+        return profile.groups[name]
+    known = ", ".join(f"{GROUP_PREFIX}{group}" for group in profile.groups)
+    hint = f" Groups: {known}." if known else ' Add one to your profile JSON, e.g. "groups": {"stores": ["Denver"]}.'
+    raise typer.BadParameter(f"No location group '{name}' in profile '{profile.name}'.{hint}", param_hint=param_hint)
+
+
+def _place_or_default(ctx: typer.Context, place: str | None) -> str:
+    """``place`` if given (``here`` is detected), else the profile's default_location, else detected."""
+    if place and not is_here(place):
+        return place
+    profile = ctx.obj["profile"]
+    if not place and profile.default_location:
+        err_console.print(
+            f"[dim]Using default_location '{profile.default_location}' from profile '{profile.name}'.[/dim]"
+        )
+        return profile.default_location
+    settings = ctx.obj["settings"]
+    # Privacy mode keeps this machine's IP address away from the geolocation service.
+    found = current_location(offline=settings.offline, use_ip=not settings.privacy_mode)
+    if found is None:
+        why = " IP geolocation is off in privacy mode (PRIVACY_MODE=0 allows it)." if settings.privacy_mode else ""
+        raise typer.BadParameter(
+            f'Could not detect your location.{why} Give a place, or set a default with: '
+            'wx profile set default_location "<place>".',
+            param_hint="PLACE",
+        )
+    how = "IP geolocation" if found.source == "ip" else f"the OS location service ({found.source})"
+    err_console.print(
+        f"[dim]Using {found.label} from {how}. Set a default with: wx profile set default_location \"<place>\".[/dim]"
+    )
+    return found.place
 
 
 def _normalize_invocation(args: Sequence[str]) -> list[str]:
@@ -212,7 +1600,11 @@ def _normalize_invocation(args: Sequence[str]) -> list[str]:
 def main(argv: Sequence[str] | None = None) -> None:
     args = list(sys.argv[1:] if argv is None else argv)
     normalized = _normalize_invocation(args)
-    app(args=normalized)
+    try:
+        app(args=normalized)
+    except TemplateError as err:
+        console.print(f"Template error: {err}", markup=False)
+        raise SystemExit(2) from err
 
 
 if __name__ == "__main__":  # pragma: no cover
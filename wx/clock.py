@@ -0,0 +1,78 @@
+"""Local clock times for timelines: ISO stamps and "next 6 hours" become "Fri 3 PM PDT"."""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime, timedelta, tzinfo
+from zoneinfo import ZoneInfo
+
+_ISO = re.compile(r"\b\d{4}-\d{2}-\d{2}T\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:Z|[+-]\d{2}:?\d{2})?")
+# Timeline phases that lead with "6-12h"/"0–6 hours"/"+6h", and "in/next/within 6 hours" anywhere;
+# "2-3 hours of rain" mid-sentence is a duration and stays as written.
+_RANGE = re.compile(r"^(\s*)(\d{1,2})\s*[-–]\s*(\d{1,3})\s*(?:h|hrs?|hours?)\b", re.IGNORECASE)
+_OFFSET = re.compile(
+    r"(?:\b(in|within|(?:(?:for|over)\s+)?(?:the\s+)?next)\s+(\d{1,3})\s*(?:h|hrs?|hours?)\b|^(\s*)\+(\d{1,3})\s*h\b)",
+    re.IGNORECASE,
+)
+
+
+def zone(tz_name: str | None) -> tzinfo:
+    """ZoneInfo for an IANA name, UTC when missing or unknown."""
+    try:
+        return ZoneInfo(tz_name) if tz_name else UTC
+    except (KeyError, ValueError):
+        return UTC
+
+
+def clock_label(when: datetime, tz: tzinfo, *, day: bool = True) -> str:
+    """``Fri 3 PM PDT`` (``3:30 PM`` when not on the hour); naive times are taken as UTC."""
+    if when.tzinfo is None:
+        when = when.replace(tzinfo=UTC)
+    local = when.astimezone(tz)
+    time_text = local.strftime("%I:%M %p" if local.minute else "%I %p").lstrip("0")
+    prefix = f"{local:%a} " if day else ""
+    return f"{prefix}{time_text} {local.tzname() or ''}".strip()
+
+
+def localize_text(text: str, tz_name: str | None, *, now: datetime | None = None) -> str:
+    """Rewrite ISO timestamps and hour offsets in ``text`` as local clock times."""
+    tz = zone(tz_name)
+    now = now or datetime.now(UTC)
+
+    def iso(match: re.Match[str]) -> str:
+        try:
+            return clock_label(datetime.fromisoformat(match.group(0).replace("Z", "+00:00")), tz)
+        except ValueError:
+            return match.group(0)
+
+    def span(match: re.Match[str]) -> str:
+        start, end = int(match.group(2)), int(match.group(3))
+        if end <= start:
+            return match.group(0)
+        first, last = (clock_label(now + timedelta(hours=hours), tz) for hours in (start, end))
+        return f"{match.group(1)}{first}–{last}"
+
+    def offset(match: re.Match[str]) -> str:
+        if match.group(4):
+            return f"{match.group(3)}~{clock_label(now + timedelta(hours=int(match.group(4))), tz)}"
+        word, label = match.group(1).lower(), clock_label(now + timedelta(hours=int(match.group(2))), tz)
+        return {"in": f"around {label}", "within": f"by {label}"}.get(word, f"until {label}")
+
+    text = _ISO.sub(iso, text)
+    text = _RANGE.sub(span, text)
+    return _OFFSET.sub(offset, text)
+
+
+def localize_sections(response, tz_name: str | None, *, now: datetime | None = None) -> None:
+    """Local clock times in a response's timeline, actions, answer and bottom line."""
+    sections = response.sections
+    for key in ("timeline", "actions"):
+        items = sections.get(key)
+        if isinstance(items, list):
+            sections[key] = [
+                localize_text(item, tz_name, now=now) if isinstance(item, str) else item for item in items
+            ]
+    if isinstance(sections.get("answer"), str):
+        sections["answer"] = localize_text(sections["answer"], tz_name, now=now)
+    if response.bottom_line:
+        response.bottom_line = localize_text(response.bottom_line, tz_name, now=now)
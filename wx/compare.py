@@ -0,0 +1,47 @@
+"""``wx compare``: several places side by side over the next day."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any
+
+from .forecaster import ForecasterResponse
+from .units import display_depth, display_observation, display_speed, display_temp
+from .watch import PlaceReport
+
+COMPARE_HOURS = 24
+
+
+@dataclass(slots=True)
+class CompareResult:
+    """Per-place reports and, with ``--ai``, the model's comparison."""
+
+    reports: list[PlaceReport] = field(default_factory=list)
+    response: ForecasterResponse | None = None
+
+
+def comparison_pack(reports: list[PlaceReport], units: str) -> list[dict[str, Any]]:
+    """Per-place conditions and next-day highlights in display units, for the AI comparison."""
+
+    places = []
+    for report in reports:
+        if report.snapshot is None:
+            places.append({"place": report.place, "error": report.error})
+            continue
+        outlook = report.snapshot.outlook
+        precip_total = outlook.get("precip_total")
+        places.append(
+            {
+                "place": report.snapshot.place,
+                "current": display_observation(report.snapshot.current, units),
+                f"next_{report.snapshot.outlook_hours}h": {
+                    "temp_max": display_temp(outlook.get("temp_max"), units),
+                    "temp_min": display_temp(outlook.get("temp_min"), units),
+                    "gust_max": display_speed(outlook.get("gust_max"), units),
+                    "precip_prob_max": outlook.get("precip_prob_max"),
+                    "precip_total": display_depth(precip_total, units) if precip_total is not None else None,
+                },
+                "alerts": [alert.get("event") for alert in report.snapshot.alerts],
+            }
+        )
+    return places
@@ -4,16 +4,43 @@ from __future__ import annotations
 
 import json
 import os
+from collections.abc import Mapping
 from dataclasses import dataclass, field
 from pathlib import Path
-from typing import Any, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 from dotenv import load_dotenv
 
+from .decisions import parse_decision_floor, parse_decision_floor_mode
+from .httpclient import parse_timeout
+from .language import LANGUAGES
+from .prompts import DEFAULT_PROMPT_VERSION, STYLE_PRESETS, parse_prompt_version, parse_style
+from .staleness import DEFAULT_FORECAST_MAX_MINUTES, DEFAULT_OBS_MAX_MINUTES, parse_age_limit
+from .units import parse_units
+
+if TYPE_CHECKING:
+    from .profile import Profile
+
 DEFAULT_OPENROUTER_MODELS = ("openrouter/auto",)
 DEFAULT_OPENROUTER_BASE_URL = "https://openrouter.ai/api/v1"
+DEFAULT_OLLAMA_URL = "http://localhost:11434"
+DEFAULT_OPENAI_BASE_URL = "https://api.openai.com/v1"
+DEFAULT_OPENAI_MODEL = "gpt-4o-mini"
+DEFAULT_ANTHROPIC_MODEL = "claude-sonnet-4-5"
+# Default order of the AI fallback chain; --model moves one backend to the front.
+AI_BACKENDS = ("openrouter", "openai", "anthropic", "gemini", "ollama")
+# Environment variables that name each backend's model; a profile "model" yields to them.
+MODEL_ENV_VARS = {
+    "openrouter": ("OPENROUTER_MODEL", "OPENROUTER_MODELS"),
+    "openai": ("OPENAI_MODEL",),
+    "anthropic": ("ANTHROPIC_MODEL",),
+    "gemini": ("GEMINI_MODEL",),
+    "ollama": ("OLLAMA_MODEL",),
+}
 DEFAULT_TEMPERATURE = 0.2
 DEFAULT_MAX_TOKENS = 900
+# Upper bound on forecast periods sent to the model; longer windows are sampled more coarsely.
+DEFAULT_AI_MAX_PERIODS = 24
 DEFAULT_UNITS = "imperial"
 DEFAULT_HTTP_TIMEOUT = 5.0
 DEFAULT_HTTP_RETRIES = 2
@@ -38,96 +65,124 @@ SEVERE_WEATHER_KEYWORDS = {
     "thunderstorm",
 }
 
-# Regional sampling points for US + Europe worldview
+# Regional sampling points (lat, lon, name) for the worldview (US + Europe by default, Asia on request)
 REGIONAL_SAMPLES = {
     "us": [
         # West coast
-        (47.6, -122.3),  # Seattle
-        (45.5, -122.7),  # Portland
-        (37.8, -122.4),  # San Francisco
-        (34.0, -118.2),  # Los Angeles
-        (32.7, -117.2),  # San Diego
+        (47.6, -122.3, "Seattle"),
+        (45.5, -122.7, "Portland"),
+        (37.8, -122.4, "San Francisco"),
+        (34.0, -118.2, "Los Angeles"),
+        (32.7, -117.2, "San Diego"),
         # Southwest
-        (33.4, -112.1),  # Phoenix
-        (36.2, -115.1),  # Las Vegas
-        (35.1, -106.6),  # Albuquerque
+        (33.4, -112.1, "Phoenix"),
+        (36.2, -115.1, "Las Vegas"),
+        (35.1, -106.6, "Albuquerque"),
         # Rockies
-        (39.7, -104.9),  # Denver
-        (40.8, -111.9),  # Salt Lake City
+        (39.7, -104.9, "Denver"),
+        (40.8, -111.9, "Salt Lake City"),
         # Plains
-        (41.3, -96.0),   # Omaha
-        (39.1, -94.6),   # Kansas City
-        (32.8, -96.8),   # Dallas
-        (29.8, -95.4),   # Houston
+        (41.3, -96.0, "Omaha"),
+        (39.1, -94.6, "Kansas City"),
+        (32.8, -96.8, "Dallas"),
+        (29.8, -95.4, "Houston"),
         # Midwest
-        (41.9, -87.6),   # Chicago
-        (42.3, -83.0),   # Detroit
-        (44.9, -93.3),   # Minneapolis
+        (41.9, -87.6, "Chicago"),
+        (42.3, -83.0, "Detroit"),
+        (44.9, -93.3, "Minneapolis"),
         # Southeast
-        (33.7, -84.4),   # Atlanta
-        (30.3, -81.7),   # Jacksonville
-        (25.8, -80.2),   # Miami
-        (30.0, -90.1),   # New Orleans
+        (33.7, -84.4, "Atlanta"),
+        (30.3, -81.7, "Jacksonville"),
+        (25.8, -80.2, "Miami"),
+        (30.0, -90.1, "New Orleans"),
         # Northeast
-        (40.7, -74.0),   # New York
-        (42.4, -71.1),   # Boston
-        (39.9, -75.2),   # Philadelphia
-        (38.9, -77.0),   # DC
+        (40.7, -74.0, "New York"),
+        (42.4, -71.1, "Boston"),
+        (39.9, -75.2, "Philadelphia"),
+        (38.9, -77.0, "DC"),
         # Alaska & Hawaii
-        (61.2, -149.9),  # Anchorage
-        (21.3, -157.9),  # Honolulu
+        (61.2, -149.9, "Anchorage"),
+        (21.3, -157.9, "Honolulu"),
     ],
     "eu": [
         # Iberia
-        (40.4, -3.7),    # Madrid
-        (41.4, 2.2),     # Barcelona
-        (38.7, -9.1),    # Lisbon
+        (40.4, -3.7, "Madrid"),
+        (41.4, 2.2, "Barcelona"),
+        (38.7, -9.1, "Lisbon"),
         # France
-        (48.9, 2.3),     # Paris
-        (43.6, 1.4),     # Toulouse
-        (43.3, 5.4),     # Marseille
+        (48.9, 2.3, "Paris"),
+        (43.6, 1.4, "Toulouse"),
+        (43.3, 5.4, "Marseille"),
         # Benelux
-        (52.4, 4.9),     # Amsterdam
-        (50.8, 4.4),     # Brussels
-        (49.6, 6.1),     # Luxembourg
+        (52.4, 4.9, "Amsterdam"),
+        (50.8, 4.4, "Brussels"),
+        (49.6, 6.1, "Luxembourg"),
         # DACH
-        (52.5, 13.4),    # Berlin
-        (50.1, 8.7),     # Frankfurt
-        (48.1, 11.6),    # Munich
-        (47.4, 8.5),     # Zurich
-        (48.2, 16.4),    # Vienna
+        (52.5, 13.4, "Berlin"),
+        (50.1, 8.7, "Frankfurt"),
+        (48.1, 11.6, "Munich"),
+        (47.4, 8.5, "Zurich"),
+        (48.2, 16.4, "Vienna"),
         # UK & Ireland
-        (51.5, -0.1),    # London
-        (53.5, -2.2),    # Manchester
-        (55.9, -3.2),    # Edinburgh
-        (53.3, -6.3),    # Dublin
+        (51.5, -0.1, "London"),
+        (53.5, -2.2, "Manchester"),
+        (55.9, -3.2, "Edinburgh"),
+        (53.3, -6.3, "Dublin"),
         # Nordics
-        (59.3, 18.1),    # Stockholm
-        (60.2, 24.9),    # Helsinki
-        (59.9, 10.8),    # Oslo
-        (55.7, 12.6),    # Copenhagen
+        (59.3, 18.1, "Stockholm"),
+        (60.2, 24.9, "Helsinki"),
+        (59.9, 10.8, "Oslo"),
+        (55.7, 12.6, "Copenhagen"),
         # Baltics
-        (59.4, 24.8),    # Tallinn
-        (56.9, 24.1),    # Riga
-        (54.7, 25.3),    # Vilnius
+        (59.4, 24.8, "Tallinn"),
+        (56.9, 24.1, "Riga"),
+        (54.7, 25.3, "Vilnius"),
         # Central/Eastern
-        (52.2, 21.0),    # Warsaw
-        (50.1, 14.4),    # Prague
-        (47.5, 19.0),    # Budapest
-        (44.4, 26.1),    # Bucharest
+        (52.2, 21.0, "Warsaw"),
+        (50.1, 14.4, "Prague"),
+        (47.5, 19.0, "Budapest"),
+        (44.4, 26.1, "Bucharest"),
         # Balkans
-        (45.8, 15.9),    # Zagreb
-        (44.8, 20.5),    # Belgrade
-        (42.0, 21.4),    # Skopje
-        (41.3, 19.8),    # Tirana
+        (45.8, 15.9, "Zagreb"),
+        (44.8, 20.5, "Belgrade"),
+        (42.0, 21.4, "Skopje"),
+        (41.3, 19.8, "Tirana"),
         # Mediterranean
-        (41.9, 12.5),    # Rome
-        (45.4, 9.2),     # Milan
-        (40.9, 14.3),    # Naples
-        (37.9, 23.7),    # Athens
+        (41.9, 12.5, "Rome"),
+        (45.4, 9.2, "Milan"),
+        (40.9, 14.3, "Naples"),
+        (37.9, 23.7, "Athens"),
+    ],
+    "asia": [
+        # East Asia
+        (35.7, 139.7, "Tokyo"),
+        (34.7, 135.5, "Osaka"),
+        (37.6, 127.0, "Seoul"),
+        (39.9, 116.4, "Beijing"),
+        (31.2, 121.5, "Shanghai"),
+        (22.3, 114.2, "Hong Kong"),
+        (25.0, 121.6, "Taipei"),
+        # Southeast Asia
+        (14.6, 121.0, "Manila"),
+        (13.8, 100.5, "Bangkok"),
+        (21.0, 105.8, "Hanoi"),
+        (1.4, 103.8, "Singapore"),
+        (3.1, 101.7, "Kuala Lumpur"),
+        (-6.2, 106.8, "Jakarta"),
+        # South Asia
+        (28.6, 77.2, "Delhi"),
+        (19.1, 72.9, "Mumbai"),
+        (22.6, 88.4, "Kolkata"),
+        (23.8, 90.4, "Dhaka"),
+        (24.9, 67.0, "Karachi"),
     ],
 }
 
+# Display names for world-view regions; unknown (user-defined) groups are title-cased
+REGION_NAMES = {"us": "US", "eu": "Europe", "asia": "Asia"}
+REGION_ALIASES = {"europe": "eu", "usa": "us", "america": "us"}
+DEFAULT_WORLD_REGIONS = ("us", "eu")
+
 # Load environment variables from a local .env if present without overriding existing env
 load_dotenv()
 
@@ -136,8 +191,9 @@ STATE_DIR.mkdir(parents=True, exist_ok=True)
 STATE_FILE = STATE_DIR / "last_query.json"
 
 UnitsLiteral = Literal["imperial", "metric"]
-StyleLiteral = Literal["brief", "standard", "verbose"]
+StyleLiteral = Literal["standard", "brief", "verbose", "technical", "casual", "kids", "pilot-briefing"]
 PersonaLiteral = Literal["default", "pilot", "runner", "sailor", "commuter"]
+OutputFormatLiteral = Literal["rich", "eink", "markdown", "html"]
 
 
 @dataclass(slots=True)
@@ -150,6 +206,7 @@ class Settings:
     ai_model: str = field(default=DEFAULT_OPENROUTER_MODELS[0])
     ai_temperature: float = field(default=DEFAULT_TEMPERATURE)
     ai_max_tokens: int = field(default=DEFAULT_MAX_TOKENS)
+    ai_max_periods: int = field(default=DEFAULT_AI_MAX_PERIODS)
     units: UnitsLiteral = field(default=DEFAULT_UNITS)  # type: ignore[assignment]
     privacy_mode: bool = field(default=True)
     offline: bool = field(default=False)
@@ -159,6 +216,28 @@ class Settings:
     state_file: Path = field(default=STATE_FILE)
     gemini_api_key: str | None = field(default=None)
     gemini_model: str = field(default="gemini-2.0-flash-exp")
+    ollama_url: str = field(default=DEFAULT_OLLAMA_URL)
+    ollama_model: str | None = field(default=None)
+    openai_api_key: str | None = field(default=None)
+    openai_model: str = field(default=DEFAULT_OPENAI_MODEL)
+    anthropic_api_key: str | None = field(default=None)
+    anthropic_model: str = field(default=DEFAULT_ANTHROPIC_MODEL)
+    preferred_backend: str | None = field(default=None)
+    use_cache: bool = field(default=True)
+    stream: bool = field(default=True)
+    # ISO 639-1 code for AI prose; None leaves the model's default (English).
+    language: str | None = field(default=None)
+    prompt_version: str = field(default=DEFAULT_PROMPT_VERSION)
+    provider: str = field(default="open-meteo")
+    # Actions the model rates below this confidence (0-100) are hidden or marked; 0 turns it off.
+    decision_floor: int = field(default=0)
+    decision_floor_mode: str = field(default="hide")
+    # Observations and forecast issuances older than these (minutes) are flagged stale; 0 turns it off.
+    stale_obs_minutes: int = field(default=DEFAULT_OBS_MAX_MINUTES)
+    stale_forecast_minutes: int = field(default=DEFAULT_FORECAST_MAX_MINUTES)
+    # Seconds per weather request (None keeps each fetcher's default) and retries after transient failures.
+    http_timeout: float | None = field(default=None)
+    http_retries: int = field(default=DEFAULT_HTTP_RETRIES)
 
     def to_feature_metadata(self) -> dict[str, Any]:
         """Expose select settings that the model may need to know about."""
@@ -183,6 +262,7 @@ class Settings:
             "style": payload.get("style"),
             "persona": payload.get("persona"),
             "timestamp": payload.get("timestamp"),
+            "story": payload.get("story"),
         }
         try:
             # Ensure state directory exists
@@ -257,10 +337,27 @@ def load_settings(
     offline: bool | None = None,
     style: StyleLiteral | None = None,
     persona: PersonaLiteral | None = None,
+    provider: str | None = None,
+    ollama_url: str | None = None,
+    ollama_model: str | None = None,
+    model: str | None = None,
+    no_cache: bool = False,
+    no_stream: bool = False,
+    language: str | None = None,
+    prompt_version: str | None = None,
+    units: str | None = None,
+    timeout: float | None = None,
+    profile: Profile | None = None,
 ) -> Settings:
-    """Load runtime settings from the environment."""
+    """Load runtime settings from CLI arguments, the environment and the profile.
+
+    Each value comes from the first of: the keyword argument (a CLI flag), its environment
+    variable, then the matching ``profile`` field (provider, model, language, units, Ollama
+    server and API keys).
+    """
 
-    openrouter_key = os.getenv("OPENROUTER_API_KEY")
+    api_keys: Mapping[str, str] = profile.api_keys if profile else {}
+    openrouter_key = os.getenv("OPENROUTER_API_KEY") or api_keys.get("openrouter_api_key")
     # Validate OpenRouter API key if provided
     if openrouter_key:
         _validate_api_key(openrouter_key, "OPENROUTER_API_KEY")
@@ -284,16 +381,41 @@ def load_settings(
     else:
         ai_model = ai_model_override or DEFAULT_OPENROUTER_MODELS[0]
     ai_temperature = _float_from_env(os.getenv("AI_TEMPERATURE"), DEFAULT_TEMPERATURE)
-    ai_max_tokens = _int_from_env(os.getenv("AI_MAX_TOKENS"), DEFAULT_MAX_TOKENS)
-    units = os.getenv("UNITS", DEFAULT_UNITS)
+    style = parse_style(style or os.getenv("WX_STYLE") or _from_profile(profile, "style"))
+    # An explicit AI_MAX_TOKENS wins over the style's budget.
+    ai_max_tokens = _int_from_env(os.getenv("AI_MAX_TOKENS"), STYLE_PRESETS[style].max_tokens or DEFAULT_MAX_TOKENS)
+    ai_max_periods = _int_from_env(os.getenv("AI_MAX_PERIODS"), DEFAULT_AI_MAX_PERIODS)
+    # --units and the profile are validated; UNITS keeps its lenient parsing.
+    units_env = os.getenv("UNITS")
+    if units:
+        units = parse_units(units)
+    elif units_env:
+        units = "metric" if units_env.lower().startswith("metric") else DEFAULT_UNITS
+    else:
+        profile_units = _from_profile(profile, "units")
+        units = parse_units(profile_units) if profile_units else DEFAULT_UNITS
     privacy_mode = _bool_from_env(os.getenv("PRIVACY_MODE"), True)
     offline_flag = _bool_from_env(os.getenv("WX_OFFLINE"), False)
-    gemini_key = os.getenv("GEMINI_API_KEY") or os.getenv("GOOGLE_API_KEY")
+    gemini_key = (
+        os.getenv("GEMINI_API_KEY") or os.getenv("GOOGLE_API_KEY") or api_keys.get("gemini_api_key")
+    )
     # Validate Gemini API key if provided
     if gemini_key:
         _validate_api_key(gemini_key, "GEMINI_API_KEY")
 
     gemini_model = os.getenv("GEMINI_MODEL", "gemini-2.0-flash-exp")
+    # Ollama is only used when a model is named; no key is needed for a local server.
+    ollama_model = ollama_model or os.getenv("OLLAMA_MODEL") or _from_profile(profile, "ollama_model") or None
+    ollama_url = ollama_url or os.getenv("OLLAMA_URL") or _from_profile(profile, "ollama_url") or DEFAULT_OLLAMA_URL
+
+    openai_key = os.getenv("OPENAI_API_KEY") or api_keys.get("openai_api_key")
+    if openai_key:
+        _validate_api_key(openai_key, "OPENAI_API_KEY")
+    anthropic_key = os.getenv("ANTHROPIC_API_KEY") or api_keys.get("anthropic_api_key")
+    if anthropic_key:
+        _validate_api_key(anthropic_key, "ANTHROPIC_API_KEY")
+
+    data_provider = provider or os.getenv("WX_PROVIDER") or _from_profile(profile, "provider") or "open-meteo"
 
     state_root = Path(os.getenv("WX_STATE_DIR", str(STATE_DIR)))
     state_root.mkdir(parents=True, exist_ok=True)
@@ -305,20 +427,126 @@ def load_settings(
         ai_model=ai_model,
         ai_temperature=ai_temperature,
         ai_max_tokens=ai_max_tokens,
-        units="metric" if units.lower().startswith("metric") else DEFAULT_UNITS,
+        ai_max_periods=ai_max_periods,
+        units=units,
         privacy_mode=privacy_mode,
         offline=offline if offline is not None else offline_flag,
         debug=debug,
-        style=style or "standard",
+        style=style,
         persona=persona or "default",
         gemini_api_key=gemini_key,
         gemini_model=gemini_model,
+        ollama_url=ollama_url,
+        ollama_model=ollama_model,
+        openai_api_key=openai_key,
+        openai_model=os.getenv("OPENAI_MODEL") or DEFAULT_OPENAI_MODEL,
+        anthropic_api_key=anthropic_key,
+        anthropic_model=os.getenv("ANTHROPIC_MODEL") or DEFAULT_ANTHROPIC_MODEL,
         state_file=state_root / "last_query.json",
+        provider=data_provider.strip().lower(),
+        use_cache=not (no_cache or _bool_from_env(os.getenv("WX_NO_CACHE"), False)),
+        stream=not (no_stream or _bool_from_env(os.getenv("WX_NO_STREAM"), False)),
+        language=parse_language(language or os.getenv("WX_LANG") or _from_profile(profile, "language")),
+        prompt_version=parse_prompt_version(
+            prompt_version or os.getenv("WX_PROMPT_VERSION") or DEFAULT_PROMPT_VERSION
+        ),
+        decision_floor=parse_decision_floor(
+            os.getenv("WX_DECISION_FLOOR") or _from_profile(profile, "decision_floor")
+        ),
+        decision_floor_mode=parse_decision_floor_mode(
+            os.getenv("WX_DECISION_FLOOR_MODE") or _from_profile(profile, "decision_floor_mode")
+        ),
+        stale_obs_minutes=parse_age_limit(
+            os.getenv("WX_STALE_OBS") or _from_profile(profile, "stale_obs"), DEFAULT_OBS_MAX_MINUTES
+        ),
+        stale_forecast_minutes=parse_age_limit(
+            os.getenv("WX_STALE_FORECAST") or _from_profile(profile, "stale_forecast"), DEFAULT_FORECAST_MAX_MINUTES
+        ),
+        http_timeout=parse_timeout(os.getenv("WX_HTTP_TIMEOUT") if timeout is None else timeout),
+        http_retries=max(0, _int_from_env(os.getenv("WX_HTTP_RETRIES"), DEFAULT_HTTP_RETRIES)),
     )
+    profile_model = _from_profile(profile, "model")
+    if model:
+        _apply_model_override(settings, model)
+    elif profile_model and not _model_env_set(parse_model_spec(profile_model)[0]):
+        _apply_model_override(settings, profile_model)
 
     return settings
 
 
+def _model_env_set(backend: str) -> bool:
+    """Whether AI_MODEL or ``backend``'s own model variable already picked a model."""
+
+    return any(os.getenv(name) for name in ("AI_MODEL", *MODEL_ENV_VARS[backend]))
+
+
+def _from_profile(profile: Profile | None, name: str) -> str | None:
+    return getattr(profile, name, None) if profile is not None else None
+
+
+def parse_language(value: str | None) -> str | None:
+    """Normalise ``es``/``pt-BR``/``Spanish`` style values to a supported ISO 639-1 code."""
+
+    if not value or not value.strip():
+        return None
+    text = value.strip().lower().replace("_", "-")
+    code = text.split("-", 1)[0]
+    if code in LANGUAGES:
+        return code
+    for known, name in LANGUAGES.items():
+        if name.lower() == text:
+            return known
+    raise ValueError(f"Unsupported language '{value}'. Choose from: {', '.join(LANGUAGES)}.")
+
+
+def parse_model_spec(spec: str) -> tuple[str, str]:
+    """Split ``provider:model`` (``anthropic:claude-sonnet-4-5``); raises ValueError when malformed."""
+
+    backend, sep, name = spec.strip().partition(":")
+    backend = backend.strip().lower()
+    if not sep or not name.strip() or backend not in AI_BACKENDS:
+        raise ValueError(f"Expected provider:model with provider one of {', '.join(AI_BACKENDS)}; got '{spec}'.")
+    return backend, name.strip()
+
+
+def apply_ai_override(settings: Settings, *, model: str | None = None, backend: str | None = None) -> None:
+    """Point one invocation at another AI backend and/or model; raises ValueError when unknown.
+
+    ``model`` may be a full ``provider:model`` spec. A bare name applies to ``backend``, else to
+    the backend already tried first (OpenRouter by default).
+    """
+
+    if backend is not None:
+        backend = backend.strip().lower()
+        if backend not in AI_BACKENDS:
+            raise ValueError(f"Unknown AI provider '{backend}'. Choose from: {', '.join(AI_BACKENDS)}.")
+    if not model:
+        if backend is not None:
+            settings.preferred_backend = backend
+        return
+    prefix = model.partition(":")[0].strip().lower()
+    if backend is None and prefix in AI_BACKENDS:
+        _apply_model_override(settings, model)
+    else:
+        _apply_model_override(settings, f"{backend or settings.preferred_backend or 'openrouter'}:{model}")
+
+
+def _apply_model_override(settings: Settings, spec: str) -> None:
+    backend, name = parse_model_spec(spec)
+    settings.preferred_backend = backend
+    if backend == "openrouter":
+        settings.openrouter_models = (name, *(m for m in settings.openrouter_models if m != name))
+        settings.ai_model = name
+    elif backend == "openai":
+        settings.openai_model = name
+    elif backend == "anthropic":
+        settings.anthropic_model = name
+    elif backend == "gemini":
+        settings.gemini_model = name
+    else:
+        settings.ollama_model = name
+
+
 def _parse_models(value: str | None) -> tuple[str, ...]:
     if not value:
         return ()
@@ -352,7 +580,6 @@ def _validate_api_key(key: str | None, key_name: str) -> bool:
         "xxx",
         "test",
         "example",
-        "sk-proj-",  # Common prefix for fake keys
     ]
     key_lower = key.lower()
     if any(placeholder in key_lower for placeholder in placeholders):
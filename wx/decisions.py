@@ -0,0 +1,93 @@
+"""Decision confidence floor: hide or mark the story's actions the model itself rates as unsure.
+
+With a floor set, the model is asked for ``sections.action_confidence``, one 0-100 value per
+action. Actions under the floor are dropped into a single "Low-confidence considerations" line,
+or with the ``mark`` mode kept and labelled, so nobody acts on a coin-flip recommendation.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+DECISION_FLOOR_MODES = ("hide", "mark")
+LOW_CONFIDENCE_LABEL = "Low-confidence considerations"
+
+
+def parse_decision_floor(value: str | float | None) -> int:
+    """``60``, ``60%``, ``0.6`` or ``1.0`` as a percentage; empty means no floor. Raises ValueError otherwise.
+
+    A number with a decimal point up to 1 is a fraction, so ``1.0`` is 100% but ``1`` is 1%.
+    """
+
+    if value is None or not str(value).strip():
+        return 0
+    text = str(value).strip().rstrip("%").strip()
+    try:
+        number = float(text)
+    except ValueError:
+        raise ValueError(f"Decision floor must be a confidence from 0 to 100, not '{value}'.") from None
+    percent = _percent(number, fraction="." in text and number <= 1)
+    if percent is None:
+        raise ValueError(f"Decision floor must be a confidence from 0 to 100, not '{value}'.")
+    return percent
+
+
+def parse_decision_floor_mode(value: str | None) -> str:
+    mode = (value or DECISION_FLOOR_MODES[0]).strip().lower()
+    if mode not in DECISION_FLOOR_MODES:
+        raise ValueError(f"Unknown decision floor mode '{value}'. Choose from: {', '.join(DECISION_FLOOR_MODES)}.")
+    return mode
+
+
+def apply_decision_floor(response, floor: int, mode: str = "hide") -> list[dict[str, Any]]:
+    """Hide (or mark) actions rated below ``floor``; returns them with their confidence.
+
+    Run before the safety passes so their notices, which carry no rating, are never affected.
+    """
+
+    scores = response.sections.pop("action_confidence", None)
+    actions = response.sections.get("actions")
+    if floor <= 0 or not isinstance(actions, list) or not isinstance(scores, list):
+        return []
+    kept: list[Any] = []
+    below: list[dict[str, Any]] = []
+    fraction = _fractions(scores)
+    for idx, action in enumerate(actions):
+        score = _percent(scores[idx], fraction=fraction) if idx < len(scores) else None
+        if not isinstance(action, str) or score is None or score >= floor:
+            kept.append(action)
+            continue
+        below.append({"action": action, "confidence": score})
+        if mode == "mark":
+            kept.append(f"{action.rstrip()} (low confidence, {score}%)")
+    if not below:
+        return []
+    response.sections["actions"] = kept
+    if mode == "hide":
+        items = "; ".join(f"{item['action'].rstrip('. ')} ({item['confidence']}%)" for item in below)
+        response.sections["low_confidence"] = f"{LOW_CONFIDENCE_LABEL}: {items}."
+    response.meta = {
+        **(response.meta or {}),
+        "decision_floor": {"floor": floor, "mode": mode, "below": below},
+    }
+    return below
+
+
+def _fractions(scores: list[Any]) -> bool:
+    """Whether the model rated on a 0-1 scale: every rating is at most 1 (``[0.9, 1.0]``)."""
+
+    numbers = [value for value in scores if _is_number(value)]
+    return bool(numbers) and all(value <= 1 for value in numbers)
+
+
+def _percent(value: Any, *, fraction: bool = False) -> int | None:
+    """0-100 confidence; with ``fraction`` the value is on a 0-1 scale (``0.4`` is 40%)."""
+
+    if not _is_number(value):
+        return None
+    number = float(value) * 100 if fraction else float(value)
+    return round(number) if 0 <= number <= 100 else None
+
+
+def _is_number(value: Any) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
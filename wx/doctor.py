@@ -0,0 +1,126 @@
+"""Diagnostics for ``wx doctor`` and redacted bug-report bundles."""
+
+from __future__ import annotations
+
+import io
+import json
+import platform
+import sys
+import tarfile
+from dataclasses import asdict
+from datetime import UTC, datetime
+from importlib import metadata
+from pathlib import Path
+from typing import Any
+
+from .cache import FetchCache, cache_path
+from .config import Settings
+from .privacy import coarsen_coordinates
+from .profile import Profile
+
+SECRET_FIELDS = ("openrouter_api_key", "gemini_api_key", "openai_api_key", "anthropic_api_key")
+REDACTED = "<redacted>"
+
+
+def package_version() -> str:
+    try:
+        return metadata.version("wx-cli")
+    except metadata.PackageNotFoundError:
+        return "unknown"
+
+
+def configured_backends(settings: Settings) -> list[str]:
+    """AI backends that have what they need to be tried, in fallback order."""
+
+    available = {
+        "openrouter": settings.openrouter_api_key,
+        "openai": settings.openai_api_key,
+        "anthropic": settings.anthropic_api_key,
+        "gemini": settings.gemini_api_key,
+        "ollama": settings.ollama_model,
+    }
+    return [name for name, value in available.items() if value]
+
+
+def redacted_settings(settings: Settings) -> dict[str, Any]:
+    data = asdict(settings)
+    for name in SECRET_FIELDS:
+        data[name] = REDACTED if data.get(name) else None
+    return json.loads(json.dumps(data, default=str))
+
+
+def collect_diagnostics(settings: Settings, profile: Profile) -> dict[str, Any]:
+    """Versions, redacted settings and state summary; safe to share publicly."""
+
+    state_dir = settings.state_file.parent
+    return {
+        "generated": datetime.now(UTC).isoformat(timespec="seconds"),
+        "wx_version": package_version(),
+        "python": sys.version.split()[0],
+        "platform": platform.platform(),
+        "settings": redacted_settings(settings),
+        "ai_backends": configured_backends(settings),
+        "profile": {
+            "name": profile.name,
+            "exists": bool(profile.path and profile.path.exists()),
+            "provider": profile.provider,
+            "model": profile.model,
+            # Only where each key lives (keyring/plaintext), never the key itself.
+            "api_keys": profile.secret_sources,
+            # City names and coordinates can identify where someone lives.
+            "world_cities": len(profile.world_cities),
+        },
+        "cache": FetchCache(cache_path(settings)).stats(),
+        "state_files": sorted(path.name for path in state_dir.glob("*.json")) if state_dir.exists() else [],
+        # wx prints diagnostics with --debug instead of keeping log files.
+        "logs": None,
+    }
+
+
+def write_bundle(
+    output: Path,
+    settings: Settings,
+    profile: Profile,
+    *,
+    fixtures: list[Path] | None = None,
+) -> Path:
+    """Write a ``.tar.gz`` with diagnostics and replayable fixtures.
+
+    Fixtures default to the ``wx explain`` state file when one exists. Chat recordings can
+    be added explicitly. Coordinates are rounded to ~10 km and configured API keys are
+    scrubbed from every file.
+    """
+
+    secrets = [value for name in SECRET_FIELDS if (value := getattr(settings, name))]
+    members: dict[str, str] = {
+        "diagnostics.json": json.dumps(collect_diagnostics(settings, profile), indent=2, ensure_ascii=True)
+    }
+    candidates = list(fixtures or [])
+    if settings.state_file.exists():
+        candidates.append(settings.state_file)
+    for path in candidates:
+        members[f"fixtures/{path.name}"] = _redacted_fixture(path, secrets)
+
+    output.parent.mkdir(parents=True, exist_ok=True)
+    with tarfile.open(output, "w:gz") as archive:
+        for name, text in members.items():
+            data = text.encode("utf-8")
+            info = tarfile.TarInfo(f"wx-doctor/{name}")
+            info.size = len(data)
+            info.mtime = int(datetime.now(UTC).timestamp())
+            archive.addfile(info, io.BytesIO(data))
+    return output
+
+
+def _redacted_fixture(path: Path, secrets: list[str]) -> str:
+    try:
+        text = path.read_text(encoding="utf-8")
+    except OSError as exc:
+        return json.dumps({"error": f"could not read {path.name}: {exc.strerror or exc}"})
+    try:
+        text = json.dumps(coarsen_coordinates(json.loads(text)), indent=2, ensure_ascii=True)
+    except json.JSONDecodeError:
+        text = coarsen_coordinates(text)
+    for secret in secrets:
+        text = text.replace(secret, REDACTED)
+    return text
@@ -0,0 +1,187 @@
+"""``--format markdown|html``: stories as documents to paste into wikis and Slack or serve as a page.
+
+Both formats are built from the same list of sections, in the order the rich panels use, so a
+story reads the same whichever way it is published. Assumptions and risk cards are collapsed
+(``<details>``) because readers rarely need them.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from html import escape
+from typing import Any
+
+from .clock import clock_label, zone
+
+DOCUMENT_FORMATS = ("markdown", "html")
+DOCUMENT_CSS = """
+body { font-family: system-ui, sans-serif; max-width: 46rem; margin: 1.5rem auto; padding: 0 1rem; }
+h1 { font-size: 1.5rem; }
+h2 { font-size: 1.1rem; margin-top: 1.4rem; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #bbb; padding: 0.25rem 0.6rem; text-align: left; vertical-align: top; }
+.safety { border-left: 4px solid #b00; padding-left: 0.8rem; }
+.note { color: #555; }
+footer { margin-top: 1.5rem; color: #555; font-size: 0.85rem; }
+"""
+_TITLES = {"forecast": "Forecast", "risk": "Risk", "alerts": "Alerts", "question": "Answer"}
+_RISK_COLUMNS = ("Hazard", "Level", "Drivers", "Confidence")
+
+
+def document_sections(context: dict[str, Any]) -> list[dict[str, Any]]:
+    """The story as ``{"title", "kind", "body", "collapsed", "note"}`` dicts.
+
+    ``kind`` is ``text`` (a string), ``list`` (strings) or ``table`` (risk card rows).
+    ``context`` is :func:`wx.template.result_context` output.
+    """
+
+    response = context.get("response") or {}
+    sections = response.get("sections") or {}
+    feature_pack = context.get("feature_pack") or {}
+    tz = zone((feature_pack.get("place") or {}).get("tz"))
+    blocks: list[dict[str, Any]] = []
+
+    def add(title: str, kind: str, body: Any, *, collapsed: bool = False, note: str | None = None) -> None:
+        if body:
+            blocks.append({"title": title, "kind": kind, "body": body, "collapsed": collapsed, "note": note})
+
+    add("Stale data", "text", context.get("stale"))
+    add("Partial data", "list", context.get("warnings"))
+    add("Safety", "list", _strings(sections.get("safety")))
+    add("Alerts", "list", [_alert_line(alert, tz) for alert in feature_pack.get("alerts_quick") or []])
+    confidence = response.get("confidence") or {}
+    if sections.get("answer"):
+        add("Answer", "text", str(sections["answer"]))
+        add(f"Confidence ({confidence.get('value', '?')}%)", "text", str(confidence.get("rationale") or ""))
+        return blocks
+    add("Summary", "text", " ".join(_strings(sections.get("summary"))))
+    add("Zones", "list", _strings(sections.get("zones")))
+    add("Timeline", "list", _strings(sections.get("timeline")))
+    cards = [card for card in sections.get("risk_cards") or [] if isinstance(card, dict)]
+    rows = [
+        [
+            str(card.get("hazard") or "Unknown"),
+            str(card.get("level") or "Unknown"),
+            ", ".join(_strings(card.get("drivers"))),
+            str(card.get("confidence") or ""),
+        ]
+        for card in cards
+    ]
+    add("Risks", "table", rows, collapsed=True)
+    add("Actions", "list", _strings(sections.get("actions")), note=sections.get("low_confidence"))
+    confidence_title = f"Confidence ({confidence.get('value', '?')}%)"
+    add(confidence_title, "text", str(sections.get("confidence") or ""), note=sections.get("model_agreement"))
+    add("Assumptions", "list", _strings(sections.get("assumptions")), collapsed=True)
+    return blocks
+
+
+def markdown_text(context: dict[str, Any]) -> str:
+    """GitHub-flavoured Markdown; collapsed sections use ``<details>``, which wikis render too."""
+
+    lines = [f"# {_title(context)}", ""]
+    for block in document_sections(context):
+        body = _markdown_body(block)
+        if block["collapsed"]:
+            lines += ["<details>", f"<summary>{escape(block['title'])}</summary>", "", *body, "", "</details>", ""]
+        else:
+            lines += [f"## {block['title']}", "", *body, ""]
+    if context.get("bottom_line") and not context.get("answer"):
+        lines += [f"**Bottom line:** {context['bottom_line']}", ""]
+    footer = _footer(context)
+    if footer:
+        lines += ["---", "", f"_{footer}_", ""]
+    return "\n".join(lines).rstrip() + "\n"
+
+
+def html_text(context: dict[str, Any]) -> str:
+    """A standalone HTML page (inline CSS, no scripts) for a static host."""
+
+    title = escape(_title(context))
+    parts = [f"<h1>{title}</h1>"]
+    for block in document_sections(context):
+        body = _html_body(block)
+        css = ' class="safety"' if block["title"] == "Safety" else ""
+        if block["collapsed"]:
+            parts.append(f"<details><summary>{escape(block['title'])}</summary>{body}</details>")
+        else:
+            parts.append(f"<section{css}><h2>{escape(block['title'])}</h2>{body}</section>")
+    if context.get("bottom_line") and not context.get("answer"):
+        parts.append(f"<p><strong>Bottom line:</strong> {escape(context['bottom_line'])}</p>")
+    footer = _footer(context)
+    if footer:
+        parts.append(f"<footer>{escape(footer)}</footer>")
+    return (
+        "<!DOCTYPE html>\n"
+        '<html lang="en">\n<head>\n<meta charset="utf-8">\n'
+        '<meta name="viewport" content="width=device-width, initial-scale=1">\n'
+        f"<title>{title}</title>\n<style>{DOCUMENT_CSS}</style>\n</head>\n<body>\n"
+        + "\n".join(parts)
+        + "\n</body>\n</html>\n"
+    )
+
+
+def _markdown_body(block: dict[str, Any]) -> list[str]:
+    if block["kind"] == "list":
+        lines = [f"- {item}" for item in block["body"]]
+    elif block["kind"] == "table":
+        lines = [
+            "| " + " | ".join(_RISK_COLUMNS) + " |",
+            "|" + "---|" * len(_RISK_COLUMNS),
+            *("| " + " | ".join(_cell(value) for value in row) + " |" for row in block["body"]),
+        ]
+    else:
+        lines = [block["body"]]
+    if block["note"]:
+        lines += ["", f"_{block['note']}_"]
+    return lines
+
+
+def _html_body(block: dict[str, Any]) -> str:
+    if block["kind"] == "list":
+        body = "<ul>" + "".join(f"<li>{escape(item)}</li>" for item in block["body"]) + "</ul>"
+    elif block["kind"] == "table":
+        head = "".join(f"<th>{column}</th>" for column in _RISK_COLUMNS)
+        cells = ("".join(f"<td>{escape(value)}</td>" for value in row) for row in block["body"])
+        rows = "".join(f"<tr>{row}</tr>" for row in cells)
+        body = f"<table><thead><tr>{head}</tr></thead><tbody>{rows}</tbody></table>"
+    else:
+        body = f"<p>{escape(block['body'])}</p>"
+    if block["note"]:
+        body += f'<p class="note">{escape(block["note"])}</p>'
+    return body
+
+
+def _title(context: dict[str, Any]) -> str:
+    return f"{_TITLES.get(str(context.get('command')), 'Weather')}: {context.get('place') or 'Weather'}"
+
+
+def _footer(context: dict[str, Any]) -> str:
+    feature_pack = context.get("feature_pack") or {}
+    parts = [
+        (feature_pack.get("issuance") or {}).get("label"),
+        feature_pack.get("provider_note"),
+        context.get("credits"),
+        f"Story by {context['provider']}" if context.get("provider") else None,
+    ]
+    return " · ".join(part for part in parts if part)
+
+
+def _alert_line(alert: dict[str, Any], tz) -> str:
+    line = f"{alert.get('event') or 'Alert'} ({alert.get('severity') or 'Unknown'})"
+    if not alert.get("expires_iso"):
+        return line
+    try:
+        expires = datetime.fromisoformat(str(alert["expires_iso"]).replace("Z", "+00:00"))
+    except ValueError:
+        return line
+    return f"{line} until {clock_label(expires, tz)}"
+
+
+def _strings(value: Any) -> list[str]:
+    if isinstance(value, list):
+        return [str(item) for item in value if isinstance(item, str) and item.strip()]
+    return [str(value)] if isinstance(value, str) and value.strip() else []
+
+
+def _cell(value: str) -> str:
+    return value.replace("|", "\\|").replace("\n", " ")
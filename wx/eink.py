@@ -0,0 +1,101 @@
+"""``--format eink``: plain, large-type output for low-refresh e-paper displays."""
+
+from __future__ import annotations
+
+import textwrap
+from datetime import datetime
+from typing import Any
+
+EINK_WIDTH = 40
+EINK_SUMMARY_LINES = 4
+
+# 5-row block glyphs; anything missing renders as a blank column.
+BIG_GLYPHS = {
+    "0": ("###", "# #", "# #", "# #", "###"),
+    "1": (" # ", "## ", " # ", " # ", "###"),
+    "2": ("###", "  #", "###", "#  ", "###"),
+    "3": ("###", "  #", "###", "  #", "###"),
+    "4": ("# #", "# #", "###", "  #", "  #"),
+    "5": ("###", "#  ", "###", "  #", "###"),
+    "6": ("###", "#  ", "###", "# #", "###"),
+    "7": ("###", "  #", "  #", "  #", "  #"),
+    "8": ("###", "# #", "###", "# #", "###"),
+    "9": ("###", "# #", "###", "  #", "###"),
+    "-": ("   ", "   ", "###", "   ", "   "),
+    "°": ("###", "# #", "###", "   ", "   "),
+    "F": ("###", "#  ", "## ", "#  ", "#  "),
+    "C": ("###", "#  ", "#  ", "#  ", "###"),
+}
+
+
+def big_text(text: str) -> list[str]:
+    """Render ``text`` as five rows of ``#`` glyphs, e.g. a temperature like ``52°F``."""
+
+    glyphs = [BIG_GLYPHS.get(char, ("   ",) * 5) for char in text]
+    return [" ".join(glyph[row] for glyph in glyphs).rstrip() for row in range(5)]
+
+
+def eink_text(context: dict[str, Any], *, width: int = EINK_WIDTH, now: datetime | None = None) -> str:
+    """Lay out a forecast, alerts or answer result for an e-paper screen.
+
+    ``context`` is :func:`wx.template.result_context` output. The result is plain text
+    (no ANSI, no box drawing) so a display script can draw it with one fixed-width font.
+    """
+
+    rule = "=" * width
+    thin = "-" * width
+    lines = [_fit(str(context.get("place") or "Weather").upper(), width), rule]
+    notices = ([context["stale"]] if context.get("stale") else []) + list(context.get("warnings") or [])
+    for notice in notices:
+        lines.extend(textwrap.wrap(f"!! {notice}", width))
+    if notices:
+        lines.append(rule)
+    temp = context.get("temp")
+    if isinstance(temp, (int, float)):
+        lines.extend(big_text(f"{temp:.0f}°{_unit_letter(context.get('unit'))}"))
+        lines.append("")
+    details = [str(context["conditions"])] if context.get("conditions") else []
+    if isinstance(context.get("wind"), (int, float)):
+        wind = f"Wind {context['wind']:.0f}"
+        if isinstance(context.get("gust"), (int, float)):
+            wind += f" G{context['gust']:.0f}"
+        details.append(wind)
+    if details:
+        lines.extend(textwrap.wrap(" | ".join(details), width))
+    alerts = [alert for alert in context.get("alerts") or [] if alert]
+    if alerts:
+        lines.append(thin)
+        lines.extend(_fit(f"! {str(alert).upper()}", width) for alert in alerts)
+    body = context.get("answer") or context.get("summary")
+    if body:
+        lines.append(thin)
+        wrapped = textwrap.wrap(str(body), width)
+        if len(wrapped) > EINK_SUMMARY_LINES:
+            wrapped = wrapped[:EINK_SUMMARY_LINES]
+            wrapped[-1] = _fit(wrapped[-1] + " ...", width)
+        lines.extend(wrapped)
+    bottom_line = context.get("bottom_line")
+    if bottom_line and not context.get("answer"):
+        lines.append(thin)
+        lines.extend(textwrap.wrap(str(bottom_line), width))
+    lines.append(rule)
+    stamp = (now or datetime.now().astimezone()).strftime("%a %H:%M")
+    lines.append(f"Updated {stamp}")
+    return "\n".join(_plain(line) for line in lines)
+
+
+def _unit_letter(unit: Any) -> str:
+    text = str(unit or "")
+    return "C" if "C" in text else "F" if "F" in text else ""
+
+
+def _fit(text: str, width: int) -> str:
+    return text if len(text) <= width else text[: width - 3].rstrip() + "..."
+
+
+def _plain(text: str) -> str:
+    # Small e-paper fonts often lack typographic punctuation; the big temperature draws its own degree sign.
+    replacements = {"°": "", "–": "-", "—": "-", "’": "'", "‘": "'", "“": '"', "”": '"', "…": "..."}
+    for old, new in replacements.items():
+        text = text.replace(old, new)
+    return text
@@ -0,0 +1,117 @@
+"""Ensemble spread: how far the GFS ensemble members disagree over the forecast window.
+
+The model's confidence note is otherwise a guess. The spread of member highs and the share of
+members that are wet give an agreement level, which caps the stated confidence.
+"""
+
+from __future__ import annotations
+
+from datetime import UTC, datetime
+from typing import Any
+
+from .units import convert, display_unit
+
+# A member counts as wet with at least this much precipitation over the window.
+WET_MM = 1.0
+# Spread of member highs (10th to 90th percentile, °C) for high and moderate agreement.
+TEMP_SPREAD_LEVELS = (2.0, 4.0)
+# Confidence a story may state at each agreement level.
+CONFIDENCE_CAPS = {"high": 100, "moderate": 75, "low": 55}
+_LEVELS = ("high", "moderate", "low")
+
+
+def ensemble_pack(
+    members: dict[str, Any] | None, start: datetime, end: datetime, units: str = "imperial"
+) -> dict[str, Any] | None:
+    """Spread of member highs and precipitation totals over ``start``..``end``."""
+
+    if not members:
+        return None
+    indices = [idx for idx, stamp in enumerate(members.get("time") or []) if start <= _utc(stamp) < end]
+    temps = [series for series in members.get("temperature_2m") or [] if _window(series, indices)]
+    precips = [series for series in members.get("precipitation") or [] if _window(series, indices)]
+    if len(temps) < 3 or not indices:
+        return None
+
+    highs = sorted(max(_window(series, indices)) for series in temps)
+    totals = sorted(sum(_window(series, indices)) for series in precips)
+    high_p10, high_p90 = _percentile(highs, 0.1), _percentile(highs, 0.9)
+    spread = round(high_p90 - high_p10, 1)
+    wet_pct = round(100 * sum(1 for total in totals if total >= WET_MM) / len(totals)) if totals else None
+
+    temp_level = _LEVELS[sum(spread > limit for limit in TEMP_SPREAD_LEVELS)]
+    if wet_pct is None or wet_pct <= 10 or wet_pct >= 90:
+        precip_level = "high"
+    elif wet_pct <= 25 or wet_pct >= 75:
+        precip_level = "moderate"
+    else:
+        precip_level = "low"
+    agreement = max(temp_level, precip_level, key=_LEVELS.index)
+
+    pack: dict[str, Any] = {
+        "members": len(temps),
+        "high_p10_c": round(high_p10, 1),
+        "high_median_c": round(_percentile(highs, 0.5), 1),
+        "high_p90_c": round(high_p90, 1),
+        "high_spread_c": spread,
+        "precip_p10_mm": round(_percentile(totals, 0.1), 1) if totals else None,
+        "precip_median_mm": round(_percentile(totals, 0.5), 1) if totals else None,
+        "precip_p90_mm": round(_percentile(totals, 0.9), 1) if totals else None,
+        "wet_member_pct": wet_pct,
+        "agreement": agreement,
+        "confidence_cap": CONFIDENCE_CAPS[agreement],
+    }
+    pack["label"] = _label(pack, units)
+    return pack
+
+
+def apply_model_agreement(response, pack: dict[str, Any] | None) -> int | None:
+    """Record the agreement line and cap the stated confidence; returns the original value when capped."""
+
+    if not pack:
+        return None
+    response.sections["model_agreement"] = pack["label"]
+    confidence = response.confidence if isinstance(response.confidence, dict) else {}
+    value = confidence.get("value")
+    cap = pack["confidence_cap"]
+    capped = value if isinstance(value, (int, float)) and value > cap else None
+    if capped is not None:
+        rationale = str(confidence.get("rationale") or "").strip()
+        note = f"Capped at {cap}% because the ensemble members show {pack['agreement']} agreement."
+        response.confidence = {**confidence, "value": cap, "rationale": f"{rationale} {note}".strip()}
+        if isinstance(response.sections.get("confidence"), str):
+            response.sections["confidence"] = f"{response.sections['confidence'].strip()} {note}".strip()
+    response.meta = {
+        **(response.meta or {}),
+        "ensemble": {"agreement": pack["agreement"], "capped_from": capped},
+    }
+    return capped
+
+
+def _label(pack: dict[str, Any], units: str) -> str:
+    unit = display_unit("temp", units)
+    low, high = convert(pack["high_p10_c"], "temp", units), convert(pack["high_p90_c"], "temp", units)
+    text = f"{pack['agreement'].capitalize()} model agreement: {pack['members']} ensemble members put the high at "
+    text += f"{low:.0f}{unit}" if round(low) == round(high) else f"{low:.0f}–{high:.0f}{unit}"
+    if pack["wet_member_pct"] is not None:
+        text += f"; {pack['wet_member_pct']}% of them are wet"
+    return text + "."
+
+
+def _window(series: Any, indices: list[int]) -> list[float]:
+    if not isinstance(series, list):
+        return []
+    values = [series[idx] for idx in indices if idx < len(series)]
+    return [float(value) for value in values if isinstance(value, (int, float)) and not isinstance(value, bool)]
+
+
+def _percentile(values: list[float], share: float) -> float:
+    position = share * (len(values) - 1)
+    lower = int(position)
+    upper = min(lower + 1, len(values) - 1)
+    return values[lower] + (values[upper] - values[lower]) * (position - lower)
+
+
+def _utc(stamp: Any) -> datetime:
+    moment = datetime.fromisoformat(str(stamp))
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
@@ -0,0 +1,71 @@
+"""Favorite places from the profile, compared side by side by ``wx favorites``."""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from dataclasses import dataclass
+from typing import Any
+
+
+@dataclass(slots=True)
+class Favorite:
+    """A labelled place such as ``home`` -> ``Seattle`` or ``cabin`` -> ``47.1,-121.5``."""
+
+    label: str
+    place: str
+
+
+def parse_favorites(raw: Any) -> list[Favorite]:
+    """Accept ``{"home": "Seattle"}``, ``["Seattle"]`` or ``[{"name": "home", "place": "Seattle"}]``."""
+
+    if isinstance(raw, dict):
+        entries = [{"name": label, "place": place} for label, place in raw.items()]
+    elif isinstance(raw, list):
+        entries = raw
+    else:
+        return []
+    favorites: list[Favorite] = []
+    for entry in entries:
+        if isinstance(entry, str):
+            label = place = entry.strip()
+        elif isinstance(entry, dict):
+            place = str(entry.get("place") or "").strip()
+            if not place and entry.get("lat") is not None and entry.get("lon") is not None:
+                place = f"{entry['lat']},{entry['lon']}"
+            label = str(entry.get("name") or place).strip()
+        else:
+            continue
+        if place:
+            favorites.append(Favorite(label=label, place=place))
+    return favorites
+
+
+def merge_favorites(groups: Sequence[tuple[str, Sequence[Favorite]]]) -> list[Favorite]:
+    """Combine several profiles' favorites, fetching a place they share only once.
+
+    Places match case-insensitively (coordinates to two decimals). A shared place keeps every
+    distinct label, and when more than one profile is merged the label names whose it is,
+    e.g. ``home · me, partner``.
+    """
+
+    merged: dict[str, tuple[list[str], list[str], str]] = {}
+    for profile, favorites in groups:
+        for favorite in favorites:
+            labels, owners, _ = merged.setdefault(_place_key(favorite.place), ([], [], favorite.place))
+            if favorite.label not in labels:
+                labels.append(favorite.label)
+            if profile not in owners:
+                owners.append(profile)
+    combined = []
+    for labels, owners, place in merged.values():
+        label = " / ".join(labels)
+        combined.append(Favorite(label=f"{label} · {', '.join(owners)}" if len(groups) > 1 else label, place=place))
+    return combined
+
+
+def _place_key(place: str) -> str:
+    left, _, right = place.partition(",")
+    try:
+        return f"{float(left):.2f},{float(right):.2f}"
+    except ValueError:
+        return " ".join(place.casefold().split())
@@ -0,0 +1,128 @@
+"""Local story ratings so users can compare which AI backend serves them best."""
+
+from __future__ import annotations
+
+import json
+import os
+import tempfile
+from dataclasses import asdict, dataclass, field
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+from .config import Settings
+
+MIN_RATING = 1
+MAX_RATING = 5
+
+
+def feedback_path(settings: Settings) -> Path:
+    """Where ratings live; unlike query history they are kept regardless of privacy mode."""
+
+    return settings.state_file.parent / "feedback.json"
+
+
+def story_info(response) -> dict[str, Any]:
+    """Identify what produced a story: backend, model and system prompt revision."""
+
+    meta = response.meta or {}
+    provider = response.provider or "unknown"
+    return {
+        "provider": provider,
+        "backend": meta.get("backend") or provider.split(":", 1)[0],
+        "model": meta.get("model"),
+        "prompt_version": meta.get("prompt_version"),
+    }
+
+
+@dataclass(slots=True)
+class FeedbackEntry:
+    """One rating or inaccuracy flag for a story."""
+
+    story: dict[str, Any]
+    rating: int | None = None
+    inaccurate: bool = False
+    note: str | None = None
+    command: str | None = None
+    timestamp: str = field(default_factory=lambda: datetime.now(UTC).isoformat())
+
+
+@dataclass(slots=True)
+class FeedbackSummary:
+    """Ratings aggregated per backend, model and prompt version."""
+
+    backend: str
+    model: str | None
+    prompt_version: str | None
+    stories: int
+    ratings: int
+    average: float | None
+    flagged: int
+
+
+class FeedbackStore:
+    """Ratings persisted as JSON (0600); without a ``path`` they last for the process."""
+
+    def __init__(self, path: Path | None = None) -> None:
+        self.path = path
+        self._entries: list[FeedbackEntry] | None = None
+
+    def add(self, entry: FeedbackEntry) -> None:
+        if entry.rating is not None and not MIN_RATING <= entry.rating <= MAX_RATING:
+            raise ValueError(f"Rating must be between {MIN_RATING} and {MAX_RATING}.")
+        self.entries().append(entry)
+        self._save()
+
+    def entries(self) -> list[FeedbackEntry]:
+        if self._entries is None:
+            self._entries = []
+            if self.path is not None:
+                try:
+                    raw = json.loads(self.path.read_text())
+                except (OSError, json.JSONDecodeError):
+                    raw = []
+                for item in raw if isinstance(raw, list) else []:
+                    try:
+                        self._entries.append(FeedbackEntry(**item))
+                    except TypeError:
+                        continue
+        return self._entries
+
+    def summary(self) -> list[FeedbackSummary]:
+        """Best-rated backends first; unrated groups (flags only) last."""
+
+        groups: dict[tuple[Any, ...], list[FeedbackEntry]] = {}
+        for entry in self.entries():
+            story = entry.story if isinstance(entry.story, dict) else {}
+            key = (story.get("backend") or "unknown", story.get("model"), story.get("prompt_version"))
+            groups.setdefault(key, []).append(entry)
+        rows = []
+        for (backend, model, prompt_version), entries in groups.items():
+            ratings = [entry.rating for entry in entries if entry.rating is not None]
+            rows.append(
+                FeedbackSummary(
+                    backend=backend,
+                    model=model,
+                    prompt_version=prompt_version,
+                    stories=len(entries),
+                    ratings=len(ratings),
+                    average=sum(ratings) / len(ratings) if ratings else None,
+                    flagged=sum(1 for entry in entries if entry.inaccurate),
+                )
+            )
+        rows.sort(key=lambda row: (row.average is None, -(row.average or 0.0), -row.ratings))
+        return rows
+
+    def _save(self) -> None:
+        if self.path is None or self._entries is None:
+            return
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            fd, temp_path = tempfile.mkstemp(dir=self.path.parent, prefix=".wx_temp_", suffix=".json")
+            with os.fdopen(fd, "w") as handle:
+                handle.write(json.dumps([asdict(entry) for entry in self._entries], ensure_ascii=True, indent=2))
+            os.chmod(temp_path, 0o600)
+            os.replace(temp_path, self.path)
+        except OSError:
+            # Losing a rating should never crash the CLI.
+            pass
@@ -0,0 +1,132 @@
+"""Deterministic "feels like a…" descriptors such as ``crisp fall morning``.
+
+Built from typed metrics only (temperature, dewpoint, wind, cloud, precipitation, CAPE), so
+one-line modes like ``wx watch`` and ``wx favorites`` can set the mood without an AI call.
+Thresholds are metric: °C, km/h, mm, percent and J/kg.
+"""
+
+from __future__ import annotations
+
+from datetime import UTC, datetime
+from typing import Any
+
+from .clock import zone
+
+# Meteorological seasons for the northern hemisphere; the south is six months out.
+SEASONS = {12: "winter", 1: "winter", 2: "winter", 3: "spring", 4: "spring", 5: "spring"}
+SEASONS |= {6: "summer", 7: "summer", 8: "summer", 9: "fall", 10: "fall", 11: "fall"}
+# Local hour each part of the day starts at.
+PARTS_OF_DAY = ((5, "morning"), (12, "afternoon"), (17, "evening"), (21, "night"))
+LOOKAHEAD_HOURS = 3
+STEAMY_DEWPOINT_C = 21.0
+MUGGY_DEWPOINT_C = 18.0
+# A storm is brewing when it is warm and humid with this much CAPE or this chance of rain soon.
+PRE_STORM_CAPE = 1000.0
+PRE_STORM_PROB = 60.0
+BLUSTERY_KMH = 40.0
+RAW_KMH = 20.0
+CLEAR_CLOUD_PCT = 50.0
+GRAY_CLOUD_PCT = 85.0
+WET_MM = 0.1
+DRIZZLE_MM = 1.0
+# Upper bound (°C) for each temperature word, coldest first.
+TEMPERATURE_WORDS = ((-15, "bitter"), (-5, "frigid"), (2, "freezing"), (10, "chilly"), (17, "cool"), (23, "mild"))
+
+
+def feel_metrics(current: dict[str, Any], hourly: dict[str, list[Any]] | None = None) -> dict[str, Any]:
+    """Typed metrics from a provider's current conditions and the first hours of its forecast."""
+
+    hourly = hourly or {}
+
+    def first(key: str) -> float | None:
+        values = hourly.get(key) or []
+        return _number(values[0]) if values else None
+
+    def peak(key: str) -> float | None:
+        values = [value for value in map(_number, (hourly.get(key) or [])[:LOOKAHEAD_HOURS]) if value is not None]
+        return max(values) if values else None
+
+    temp = _number(current.get("temp"))
+    return {
+        "temp_c": temp if temp is not None else first("temperature_2m"),
+        "dewpoint_c": first("dew_point_2m"),
+        "wind_kmh": _number(current.get("wind")) if current.get("wind") is not None else first("wind_speed_10m"),
+        "cloud_cover": first("cloud_cover"),
+        "precip_mm": _number(current.get("precip_last_hr")),
+        "precip_prob": peak("precipitation_probability"),
+        "cape": peak("cape"),
+        "conditions": str(current.get("conditions") or ""),
+    }
+
+
+def feel_descriptor(
+    metrics: dict[str, Any], *, lat: float | None = None, tz_name: str | None = None, now: datetime | None = None
+) -> str | None:
+    """``crisp fall morning``, ``steamy pre-storm afternoon`` or None without a temperature."""
+
+    temp = metrics.get("temp_c")
+    if temp is None:
+        return None
+    local = (now or datetime.now(UTC)).astimezone(zone(tz_name))
+    month = local.month if (lat or 0) >= 0 else (local.month + 5) % 12 + 1
+    season = SEASONS[month]
+    part = next((label for start, label in reversed(PARTS_OF_DAY) if local.hour >= start), "night")
+    mood = _mood(metrics)
+    if mood == "steamy pre-storm":
+        return f"{mood} {part}"
+    return f"{mood} {season} {part}"
+
+
+def with_article(phrase: str) -> str:
+    return f"{'an' if phrase[:1].lower() in 'aeiou' else 'a'} {phrase}"
+
+
+def _mood(metrics: dict[str, Any]) -> str:
+    temp = metrics["temp_c"]
+    dewpoint = metrics.get("dewpoint_c")
+    wind = metrics.get("wind_kmh") or 0.0
+    cloud = metrics.get("cloud_cover")
+    precip = metrics.get("precip_mm") or 0.0
+    conditions = (metrics.get("conditions") or "").lower()
+    humid = dewpoint is not None and dewpoint >= MUGGY_DEWPOINT_C
+    if "thunder" in conditions:
+        return "stormy"
+    if "snow" in conditions or (precip >= WET_MM and temp <= 0):
+        return "snowy"
+    wet = precip >= WET_MM or any(word in conditions for word in ("rain", "drizzle", "shower"))
+    brewing = (metrics.get("cape") or 0) >= PRE_STORM_CAPE or (metrics.get("precip_prob") or 0) >= PRE_STORM_PROB
+    if temp >= 24 and humid and brewing and not wet:
+        return "steamy pre-storm"
+    if wet:
+        if "drizzle" in conditions or (precip < DRIZZLE_MM and "rain" not in conditions):
+            return "drizzly"
+        return "raw, rainy" if temp < 8 else "rainy"
+    if "fog" in conditions:
+        return "foggy"
+    if temp >= 32:
+        return "scorching"
+    if dewpoint is not None and dewpoint >= STEAMY_DEWPOINT_C:
+        return "steamy"
+    if temp >= 27:
+        return "hot"
+    if humid and temp >= 22:
+        return "muggy"
+    if wind >= BLUSTERY_KMH:
+        return "blustery"
+    clear = cloud is None or cloud < CLEAR_CLOUD_PCT
+    dry = dewpoint is None or temp - dewpoint >= 5
+    word = next((label for ceiling, label in TEMPERATURE_WORDS if temp <= ceiling), "warm")
+    if word == "freezing" and clear and wind < RAW_KMH:
+        return "frosty"
+    if word == "chilly":
+        if wind >= RAW_KMH:
+            return "raw"
+        if clear and dry:
+            return "crisp"
+    if word in ("cool", "mild", "warm") and cloud is not None and cloud >= GRAY_CLOUD_PCT:
+        return f"gray, {word}"
+    return word
+
+
+def _number(value: Any) -> float | None:
+    return float(value) if isinstance(value, (int, float)) and not isinstance(value, bool) else None
@@ -3,15 +3,28 @@
 from __future__ import annotations
 
 import math
+import re
+import threading
 import time
 from concurrent.futures import ThreadPoolExecutor, as_completed
-from dataclasses import dataclass
+from dataclasses import asdict, dataclass
+from datetime import UTC, date, datetime, timedelta
 from typing import Any
 
 import httpx
 
+from .geo import point_in_ring
+from .httpclient import timeout_for, with_retries
+
 DEFAULT_TIMEOUT = 3.0
 USER_AGENT = "wx-cli/0.1 (+https://github.com/Exvin2/claudex-cli)"
+# Seconds between place-name searches, so watch loops, favorites and routes that geocode on every
+# run stay within the geocoder's fair-use policy. Cached lookups never reach it.
+GEOCODE_INTERVAL = 1.0
+_geocode_lock = threading.Lock()
+_last_geocode = 0.0
+# Nearby points (degrees) tried when the NWS grid behind a valid point is missing or expired.
+NWS_GRID_RETRY_OFFSETS = ((0.01, 0.0), (0.0, 0.01), (-0.01, 0.0), (0.0, -0.01))
 
 
 @dataclass(slots=True)
@@ -26,6 +39,8 @@ class Observation:
     gust: float | None = None
     precip_prob: float | None = None
     cloud_cover: float | None = None
+    tmax_today: float | None = None
+    tmin_today: float | None = None
 
 
 @dataclass(slots=True)
@@ -36,6 +51,25 @@ class Alert:
     severity: str
     areas: list[str]
     expires_iso: str | None = None
+    urgency: str | None = None
+    headline: str | None = None
+    onset_iso: str | None = None
+
+
+@dataclass(slots=True)
+class TropicalCyclone:
+    """An active tropical cyclone from the NHC current storms feed."""
+
+    storm_id: str
+    name: str
+    basin: str
+    classification: str
+    intensity_kt: int | None = None
+    pressure_mb: int | None = None
+    lat: float | None = None
+    lon: float | None = None
+    movement_dir: float | None = None
+    movement_speed: float | None = None
 
 
 @dataclass(slots=True)
@@ -49,7 +83,7 @@ class FetchResult:
 
 
 def _create_client(timeout: float) -> httpx.Client:
-    return httpx.Client(timeout=timeout, headers={"User-Agent": USER_AGENT})
+    return httpx.Client(timeout=timeout_for(timeout), headers={"User-Agent": USER_AGENT})
 
 
 def _safe_request(
@@ -57,13 +91,33 @@ def _safe_request(
 ) -> dict[str, Any] | None:
     try:
         with _create_client(timeout) as client:
-            response = client.request(method, url, params=params)
+            response = with_retries(lambda: client.request(method, url, params=params))
             response.raise_for_status()
             return response.json()
     except (httpx.HTTPError, ValueError):
         return None
 
 
+def _safe_text_request(url: str, *, timeout: float = DEFAULT_TIMEOUT) -> str | None:
+    try:
+        with _create_client(timeout) as client:
+            response = with_retries(lambda: client.get(url))
+            response.raise_for_status()
+            return response.text
+    except httpx.HTTPError:
+        return None
+
+
+def _safe_bytes_request(url: str, *, timeout: float = DEFAULT_TIMEOUT) -> bytes | None:
+    try:
+        with _create_client(timeout) as client:
+            response = with_retries(lambda: client.get(url))
+            response.raise_for_status()
+            return response.content
+    except httpx.HTTPError:
+        return None
+
+
 def _parse_latlon(value: str) -> tuple[float, float] | None:
     if "," not in value:
         return None
@@ -90,6 +144,10 @@ def get_point_context(
             "GET", tz_url, params={"latitude": lat, "longitude": lon}, timeout=timeout
         )
         tz_name = tz_data.get("timezone") if tz_data else None
+        if not tz_name:
+            # US points also carry their zone in the NWS points response.
+            points = _safe_request("GET", f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}", timeout=timeout)
+            tz_name = ((points or {}).get("properties") or {}).get("timeZone")
         return {
             "input": place_or_latlon,
             "resolved": place_or_latlon,
@@ -99,6 +157,7 @@ def get_point_context(
         }
 
     geo_url = "https://geocoding-api.open-meteo.com/v1/search"
+    _await_geocode_slot()
     payload = _safe_request(
         "GET",
         geo_url,
@@ -124,6 +183,155 @@ def get_point_context(
     }
 
 
+def _await_geocode_slot() -> None:
+    """Block until GEOCODE_INTERVAL has passed since the last place-name search."""
+
+    global _last_geocode
+    with _geocode_lock:
+        wait = _last_geocode + GEOCODE_INTERVAL - time.monotonic()
+        if wait > 0:
+            time.sleep(wait)
+        _last_geocode = time.monotonic()
+
+
+def get_ip_location(*, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> dict[str, Any] | None:
+    """Approximate location of this machine's public IP (city-level), for a default place."""
+
+    if offline:
+        return None
+    payload = _safe_request("GET", "https://ipapi.co/json/", timeout=timeout)
+    if not payload or payload.get("error"):
+        return None
+    lat = _safe_float(payload.get("latitude"))
+    lon = _safe_float(payload.get("longitude"))
+    if lat is None or lon is None:
+        return None
+    label = ", ".join(
+        str(part) for part in (payload.get("city"), payload.get("region"), payload.get("country_name")) if part
+    )
+    return {"label": label or f"{lat:.2f},{lon:.2f}", "lat": lat, "lon": lon}
+
+
+def get_osrm_route(
+    start: tuple[float, float], end: tuple[float, float], *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, Any] | None:
+    """Driving route from the public OSRM demo server: ``path`` as (lat, lon), distance and duration."""
+
+    if offline:
+        return None
+    coords = f"{start[1]:.5f},{start[0]:.5f};{end[1]:.5f},{end[0]:.5f}"
+    payload = _safe_request(
+        "GET",
+        f"https://router.project-osrm.org/route/v1/driving/{coords}",
+        params={"overview": "simplified", "geometries": "geojson"},
+        timeout=timeout,
+    )
+    routes = (payload or {}).get("routes") or []
+    if not routes or payload.get("code") != "Ok":
+        return None
+    route = routes[0]
+    points = ((route.get("geometry") or {}).get("coordinates")) or []
+    path = [(float(lat), float(lon)) for lon, lat, *_ in points]
+    distance, duration = _safe_float(route.get("distance")), _safe_float(route.get("duration"))
+    if len(path) < 2 or not distance or not duration:
+        return None
+    return {"path": path, "distance_km": distance / 1000, "duration_h": duration / 3600}
+
+
+def fetch_metars(
+    *,
+    station: str | None = None,
+    near: tuple[float, float] | None = None,
+    offline: bool = False,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> list[dict[str, Any]]:
+    """Latest METARs from aviationweather.gov for one station, or every station within ~1° of ``near``.
+
+    Each item has ``icaoId``, ``name``, ``lat``, ``lon`` and the undecoded ``rawOb``.
+    """
+
+    if offline or not (station or near):
+        return []
+    params: dict[str, Any] = {"format": "json"}
+    if station:
+        params["ids"] = station.upper()
+    else:
+        lat, lon = near
+        params["bbox"] = f"{lat - 1:.2f},{lon - 1.3:.2f},{lat + 1:.2f},{lon + 1.3:.2f}"
+    payload = _safe_request("GET", "https://aviationweather.gov/api/data/metar", params=params, timeout=timeout)
+    if not isinstance(payload, list):
+        return []
+    return [item for item in payload if isinstance(item, dict) and item.get("rawOb")]
+
+
+def fetch_taf(station: str, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> str | None:
+    """Latest raw TAF for an ICAO station from aviationweather.gov, if the airport issues one."""
+
+    if offline:
+        return None
+    payload = _safe_request(
+        "GET",
+        "https://aviationweather.gov/api/data/taf",
+        params={"ids": station.upper(), "format": "json"},
+        timeout=timeout,
+    )
+    if not isinstance(payload, list) or not payload or not isinstance(payload[0], dict):
+        return None
+    return payload[0].get("rawTAF") or None
+
+
+def fetch_airsigmets(*, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> list[dict[str, Any]]:
+    """Current US SIGMETs, convective SIGMETs and AIRMETs from aviationweather.gov.
+
+    Each item has ``airSigmetType``, ``hazard``, ``severity``, ``altitudeLow1``/``altitudeHi1``
+    (feet), ``validTimeFrom``/``validTimeTo``, ``coords`` and the undecoded ``rawAirSigmet``.
+    """
+
+    if offline:
+        return []
+    payload = _safe_request(
+        "GET", "https://aviationweather.gov/api/data/airsigmet", params={"format": "json"}, timeout=timeout
+    )
+    return [item for item in payload if isinstance(item, dict)] if isinstance(payload, list) else []
+
+
+def fetch_gairmets(*, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> list[dict[str, Any]]:
+    """Current G-AIRMET snapshots (``forecastHour`` 0, 3, 6...) from aviationweather.gov.
+
+    Each item has ``hazard``, ``validTime``, ``base``/``top`` (``SFC``, ``FZL`` or hundreds of
+    feet) and ``coords``.
+    """
+
+    if offline:
+        return []
+    payload = _safe_request(
+        "GET", "https://aviationweather.gov/api/data/gairmet", params={"format": "json"}, timeout=timeout
+    )
+    return [item for item in payload if isinstance(item, dict)] if isinstance(payload, list) else []
+
+
+def fetch_pireps(
+    station: str,
+    *,
+    distance_nm: int = 100,
+    hours: int = 2,
+    offline: bool = False,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> list[dict[str, Any]]:
+    """Pilot reports within ``distance_nm`` of an ICAO station over the last ``hours``.
+
+    Each item has ``lat``, ``lon``, ``obsTime`` and the undecoded ``rawOb``.
+    """
+
+    if offline:
+        return []
+    params = {"id": station.upper(), "distance": distance_nm, "age": hours, "format": "json"}
+    payload = _safe_request("GET", "https://aviationweather.gov/api/data/pirep", params=params, timeout=timeout)
+    if not isinstance(payload, list):
+        return []
+    return [item for item in payload if isinstance(item, dict) and item.get("rawOb")]
+
+
 def get_quick_obs(
     lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
 ) -> dict[str, Any] | None:
@@ -136,7 +344,7 @@ def get_quick_obs(
     params = {
         "latitude": lat,
         "longitude": lon,
-        "current": "temperature_2m,apparent_temperature,wind_speed_10m,wind_gusts_10m,precipitation,visibility,cloud_base",  # noqa: E501
+        "current": "temperature_2m,apparent_temperature,wind_speed_10m,wind_gusts_10m,precipitation,visibility,cloud_base,weather_code",  # noqa: E501
         "timezone": "UTC",
     }
     payload = _safe_request("GET", url, params=params, timeout=timeout)
@@ -152,39 +360,106 @@ def get_quick_obs(
         "precip_last_hr": _safe_float(current.get("precipitation")),
         "vis_km": _safe_float(current.get("visibility")),
         "ceiling_m": _safe_float(current.get("cloud_base")),
+        "conditions": WMO_WEATHER_CODES.get(_safe_int(current.get("weather_code"))),
+        # Start of the model interval, in UTC without an offset.
+        "observed_iso": current.get("time"),
     }
 
 
 def get_quick_alerts(
     lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
-) -> list[dict[str, Any]]:
-    """Fetch active alert headlines for a point."""
+) -> list[dict[str, Any]] | None:
+    """Fetch active NWS alerts for a point, most severe first; None when the feed could not be reached."""
 
     if offline:
         return []
 
-    url = "https://api.weather.gov/alerts/active"
     params = {"point": f"{lat:.3f},{lon:.3f}"}
-    try:
-        with _create_client(timeout) as client:
-            response = client.get(url, params=params)
-            response.raise_for_status()
-            data = response.json()
-    except (httpx.HTTPError, ValueError):
+    payload = _safe_request("GET", "https://api.weather.gov/alerts/active", params=params, timeout=timeout)
+    if payload is None:
+        return None
+
+    alerts = [alert for alert in map(_parse_nws_alert, payload.get("features", [])) if alert]
+    alerts.sort(key=lambda alert: SEVERITY_RANK.get(alert.severity, len(SEVERITY_RANK)))
+    return [asdict(alert) for alert in alerts]
+
+
+SEVERITY_RANK = {"Extreme": 0, "Severe": 1, "Moderate": 2, "Minor": 3}
+
+
+def _parse_nws_alert(feature: dict[str, Any]) -> Alert | None:
+    """Parse a CAP feature from api.weather.gov into an ``Alert``."""
+
+    props = feature.get("properties") or {}
+    event = props.get("event")
+    if not event:
+        return None
+    return Alert(
+        event=event,
+        severity=props.get("severity") or "Unknown",
+        areas=[a.strip() for a in (props.get("areaDesc") or "").split(";") if a.strip()],
+        expires_iso=props.get("ends") or props.get("expires"),
+        urgency=props.get("urgency"),
+        headline=props.get("headline"),
+        onset_iso=props.get("onset") or props.get("effective"),
+    )
+
+
+def fetch_area_alerts(
+    state: str | None = None,
+    *,
+    zone: str | None = None,
+    offline: bool = False,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> list[dict[str, Any]]:
+    """Fetch active alerts for a whole state or a single NWS county/forecast zone."""
+
+    if offline or not (state or zone):
         return []
 
-    features = data.get("features", [])
-    alerts: list[dict[str, Any]] = []
-    for feature in features[:5]:
+    params = {"status": "actual"}
+    if zone:
+        params["zone"] = zone.upper()
+    else:
+        params["area"] = state.upper()
+    payload = _safe_request("GET", "https://api.weather.gov/alerts/active", params=params, timeout=timeout)
+    if not payload:
+        return []
+
+    return [asdict(alert) for alert in map(_parse_nws_alert, payload.get("features", [])) if alert]
+
+
+def find_county_zone(
+    state: str, county: str, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, str] | None:
+    """Resolve a county name within a state to its NWS county zone (e.g. ``WAC033``)."""
+
+    if offline:
+        return None
+
+    payload = _safe_request(
+        "GET",
+        "https://api.weather.gov/zones",
+        params={"type": "county", "area": state.upper()},
+        timeout=timeout,
+    )
+    if not payload:
+        return None
+
+    wanted = _county_key(county)
+    for feature in payload.get("features", []):
         props = feature.get("properties", {})
-        alerts.append(
-            {
-                "event": props.get("event"),
-                "severity": props.get("severity"),
-                "expires_iso": props.get("ends"),
-            }
-        )
-    return alerts
+        name = props.get("name")
+        if name and props.get("id") and _county_key(name) == wanted:
+            return {"id": props["id"], "name": name}
+    return None
+
+
+def _county_key(name: str) -> str:
+    key = name.strip().lower()
+    for suffix in (" county", " parish", " borough"):
+        key = key.removesuffix(suffix)
+    return key
 
 
 def get_quick_profile(
@@ -220,7 +495,7 @@ def get_quick_profile(
         "cape_jkg": _safe_int(cape),
         "cin_jkg": _safe_int(cin),
         "shear06_kt": _safe_int(shear),
-        "pwat_in": _safe_float(_first_value(hourly.get("precipitable_water"))),
+        "pwat_in": _mm_to_in(_safe_float(_first_value(hourly.get("precipitable_water")))),
         "lcl_m": _safe_int(_first_value(hourly.get("cloud_base"))),
         "lapse_700_500_cpkm": None,
     }
@@ -232,6 +507,11 @@ def _first_value(values: list[Any] | None) -> Any:
     return values[0]
 
 
+def _mm_to_in(value: float | None) -> float | None:
+    # Open-Meteo reports precipitable water in mm.
+    return round(value / 25.4, 2) if value is not None else None
+
+
 def _safe_float(value: Any) -> float | None:
     try:
         if value is None or (isinstance(value, float) and math.isnan(value)):
@@ -267,16 +547,19 @@ def fetch_openmeteo_points(
             "current": "temperature_2m,apparent_temperature,wind_speed_10m,wind_gusts_10m,cloud_cover",
             "hourly": "precipitation_probability",
             "forecast_hours": 6,
-            "timezone": "UTC",
+            "daily": "temperature_2m_max,temperature_2m_min",
+            "forecast_days": 1,
+            "timezone": "auto",
         }
         try:
             with _create_client(timeout) as client:
-                response = client.get(url, params=params)
+                response = with_retries(lambda: client.get(url, params=params))
                 response.raise_for_status()
                 data = response.json()
 
                 current = data.get("current", {})
                 hourly = data.get("hourly", {})
+                daily = data.get("daily", {})
                 precip_probs = hourly.get("precipitation_probability", [])
                 max_precip = max((_safe_float(p) for p in precip_probs if p is not None), default=None)
 
@@ -289,6 +572,8 @@ def fetch_openmeteo_points(
                     gust=_safe_float(current.get("wind_gusts_10m")),
                     precip_prob=max_precip,
                     cloud_cover=_safe_float(current.get("cloud_cover")),
+                    tmax_today=_safe_float(_first_value(daily.get("temperature_2m_max"))),
+                    tmin_today=_safe_float(_first_value(daily.get("temperature_2m_min"))),
                 )
         except (httpx.HTTPError, ValueError, KeyError):
             return None
@@ -304,6 +589,579 @@ def fetch_openmeteo_points(
     return observations
 
 
+_MONTH_KEYS = ("JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC")
+
+
+SPC_OUTLOOK_URL = "https://www.spc.noaa.gov/products/outlook/day{day}otlk_{kind}.nolyr.geojson"
+_SPC_KINDS = {"tornado": "torn", "wind": "wind", "hail": "hail"}
+
+
+def fetch_spc_probabilities(
+    lat: float, lon: float, *, day: int = 1, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, dict[str, Any]] | None:
+    """Probabilistic SPC convective outlook values (percent) covering a point.
+
+    Returns ``{"tornado": {"probability": 5, "significant": False}, "wind": ..., "hail": ...}``
+    or ``None`` when no outlook could be fetched.
+    """
+    if offline:
+        return None
+
+    results: dict[str, dict[str, Any]] = {}
+    for hazard, kind in _SPC_KINDS.items():
+        payload = _safe_request("GET", SPC_OUTLOOK_URL.format(day=day, kind=kind), timeout=timeout)
+        if not payload:
+            continue
+        probability = 0
+        significant = False
+        for feature in payload.get("features", []):
+            if not _point_in_geometry(lat, lon, feature.get("geometry") or {}):
+                continue
+            label = str((feature.get("properties") or {}).get("LABEL", "")).upper()
+            if label.startswith("SIG"):
+                significant = True
+                continue
+            value = _safe_float(label)
+            if value is not None:
+                probability = max(probability, round(value * 100))
+        results[hazard] = {"probability": probability, "significant": significant}
+    return results or None
+
+
+# Categorical outlook labels, lowest first; TSTM is general (non-severe) thunder.
+SPC_CATEGORY_LABELS = {
+    "TSTM": "thunderstorms",
+    "MRGL": "marginal",
+    "SLGT": "slight",
+    "ENH": "enhanced",
+    "MDT": "moderate",
+    "HIGH": "high",
+}
+# SPC outlooks cover the contiguous US only.
+_SPC_DOMAIN = ((24.0, 50.0), (-125.0, -66.0))
+
+
+def fetch_spc_outlook(
+    lat: float, lon: float, *, day: int = 1, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, Any] | None:
+    """Categorical SPC convective outlook covering a point.
+
+    Returns ``{"day": 1, "category": "slight", "label": "Slight Risk", "expire_iso": ...}`` with
+    ``category`` None outside every outlook area, or ``None`` outside the US or when unavailable.
+    """
+    (south, north), (west, east) = _SPC_DOMAIN
+    if offline or not (south <= lat <= north and west <= lon <= east):
+        return None
+    payload = _safe_request("GET", SPC_OUTLOOK_URL.format(day=day, kind="cat"), timeout=timeout)
+    if not payload:
+        return None
+
+    ranks = list(SPC_CATEGORY_LABELS)
+    best: dict[str, Any] | None = None
+    expire_iso = None
+    for feature in payload.get("features", []):
+        properties = feature.get("properties") or {}
+        expire_iso = expire_iso or _spc_time(properties.get("EXPIRE"))
+        code = str(properties.get("LABEL", "")).upper()
+        if code not in SPC_CATEGORY_LABELS or not _point_in_geometry(lat, lon, feature.get("geometry") or {}):
+            continue
+        if best is None or ranks.index(code) > ranks.index(best["code"]):
+            best = {"code": code, "label": properties.get("LABEL2") or code}
+    return {
+        "day": day,
+        "category": SPC_CATEGORY_LABELS[best["code"]] if best else None,
+        "label": best["label"] if best else None,
+        "expire_iso": expire_iso,
+    }
+
+
+def _spc_time(value: Any) -> str | None:
+    """SPC ``YYYYMMDDHHMM`` UTC stamps as ISO 8601."""
+    try:
+        return datetime.strptime(str(value), "%Y%m%d%H%M").replace(tzinfo=UTC).isoformat()
+    except ValueError:
+        return None
+
+
+def _point_in_geometry(lat: float, lon: float, geometry: dict[str, Any]) -> bool:
+    """Ray-casting point-in-polygon test for GeoJSON Polygon/MultiPolygon geometries."""
+    if geometry.get("type") == "Polygon":
+        polygons = [geometry.get("coordinates") or []]
+    elif geometry.get("type") == "MultiPolygon":
+        polygons = geometry.get("coordinates") or []
+    else:
+        return False
+    for rings in polygons:
+        if rings and _in_geojson_ring(lat, lon, rings[0]) and not any(
+            _in_geojson_ring(lat, lon, hole) for hole in rings[1:]
+        ):
+            return True
+    return False
+
+
+def _in_geojson_ring(lat: float, lon: float, ring: list[list[float]]) -> bool:
+    return point_in_ring(lat, lon, [(point[1], point[0]) for point in ring])
+
+
+HOURLY_FORECAST_VARIABLES = (
+    "temperature_2m",
+    "apparent_temperature",
+    "dew_point_2m",
+    "wind_speed_10m",
+    "wind_direction_10m",
+    "wind_gusts_10m",
+    "pressure_msl",
+    "precipitation",
+    "precipitation_probability",
+    "cape",
+    "weather_code",
+    "cloud_cover",
+    "uv_index",
+)
+
+
+def get_hourly_forecast(
+    lat: float, lon: float, *, hours: int = 48, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, list[Any]] | None:
+    """Fetch hourly forecast series (metric units, UTC times) from Open-Meteo."""
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": ",".join(HOURLY_FORECAST_VARIABLES),
+        "forecast_hours": hours,
+        "timezone": "UTC",
+    }
+    payload = _safe_request("GET", "https://api.open-meteo.com/v1/forecast", params=params, timeout=timeout)
+    hourly = (payload or {}).get("hourly")
+    if not isinstance(hourly, dict) or not hourly.get("time"):
+        return None
+    return hourly
+
+
+def get_previous_runs_forecast(
+    lat: float,
+    lon: float,
+    start: datetime,
+    *,
+    hours: int = 48,
+    days: int = 1,
+    offline: bool = False,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> dict[str, list[Any]] | None:
+    """Fetch hourly series from ``start`` (UTC) with each variable also as issued 1..``days`` days earlier."""
+    if offline:
+        return None
+
+    variables = [
+        name if lag == 0 else f"{name}_previous_day{lag}"
+        for name in HOURLY_FORECAST_VARIABLES
+        for lag in range(days + 1)
+    ]
+    end = start + timedelta(hours=hours - 1)
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": ",".join(variables),
+        "start_hour": start.strftime("%Y-%m-%dT%H:%M"),
+        "end_hour": end.strftime("%Y-%m-%dT%H:%M"),
+        "timezone": "UTC",
+    }
+    payload = _safe_request(
+        "GET", "https://previous-runs-api.open-meteo.com/v1/forecast", params=params, timeout=timeout
+    )
+    hourly = (payload or {}).get("hourly")
+    if not isinstance(hourly, dict) or not hourly.get("time"):
+        return None
+    return hourly
+
+
+def get_minutely_precipitation(
+    lat: float, lon: float, *, steps: int = 8, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, list[Any]] | None:
+    """Fetch 15-minute precipitation and snowfall totals (mm, cm; UTC times) from Open-Meteo."""
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "minutely_15": "precipitation,snowfall",
+        # The first step usually ends before now, so ask for one more than needed.
+        "forecast_minutely_15": steps + 1,
+        "timezone": "UTC",
+    }
+    payload = _safe_request("GET", "https://api.open-meteo.com/v1/forecast", params=params, timeout=timeout)
+    minutely = (payload or {}).get("minutely_15")
+    if not isinstance(minutely, dict) or not minutely.get("time"):
+        return None
+    return minutely
+
+
+AIR_QUALITY_VARIABLES = ("us_aqi", "us_aqi_pm2_5", "us_aqi_pm10", "us_aqi_ozone", "pm2_5", "pm10", "ozone")
+
+
+def get_air_quality(
+    lat: float, lon: float, *, hours: int = 48, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, list[Any]] | None:
+    """Hourly US AQI (overall and per pollutant) and PM2.5/PM10/ozone in µg/m³ from Open-Meteo."""
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": ",".join(AIR_QUALITY_VARIABLES),
+        "forecast_hours": hours,
+        "timezone": "UTC",
+    }
+    payload = _safe_request(
+        "GET", "https://air-quality-api.open-meteo.com/v1/air-quality", params=params, timeout=timeout
+    )
+    hourly = (payload or {}).get("hourly")
+    if not isinstance(hourly, dict) or not hourly.get("time"):
+        return None
+    return hourly
+
+
+POLLEN_VARIABLES = (
+    "alder_pollen",
+    "birch_pollen",
+    "olive_pollen",
+    "grass_pollen",
+    "mugwort_pollen",
+    "ragweed_pollen",
+)
+
+
+def get_pollen(
+    lat: float, lon: float, *, hours: int = 48, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, list[Any]] | None:
+    """Hourly tree, grass and weed pollen in grains/m³ from Open-Meteo (CAMS, Europe only).
+
+    Returns None outside the modelled area, where every series comes back empty.
+    """
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": ",".join(POLLEN_VARIABLES),
+        "forecast_hours": hours,
+        "timezone": "UTC",
+    }
+    payload = _safe_request(
+        "GET", "https://air-quality-api.open-meteo.com/v1/air-quality", params=params, timeout=timeout
+    )
+    hourly = (payload or {}).get("hourly")
+    if not isinstance(hourly, dict) or not hourly.get("time"):
+        return None
+    if not any(value is not None for key in POLLEN_VARIABLES for value in hourly.get(key) or []):
+        return None
+    return hourly
+
+
+MARINE_FORECAST_VARIABLES = (
+    "wave_height",
+    "wave_direction",
+    "wave_period",
+    "wind_wave_height",
+    "swell_wave_height",
+    "swell_wave_direction",
+    "swell_wave_period",
+    "sea_surface_temperature",
+    "sea_level_height_msl",
+)
+
+
+def get_marine_forecast(
+    lat: float, lon: float, *, hours: int = 48, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, list[Any]] | None:
+    """Hourly waves, swell, sea temperature and sea level (tide included) from Open-Meteo Marine.
+
+    Returns None inland, where every wave value is null.
+    """
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": ",".join(MARINE_FORECAST_VARIABLES),
+        "forecast_hours": hours,
+        "timezone": "UTC",
+        "cell_selection": "sea",
+    }
+    payload = _safe_request("GET", "https://marine-api.open-meteo.com/v1/marine", params=params, timeout=timeout)
+    hourly = (payload or {}).get("hourly")
+    if not isinstance(hourly, dict) or not hourly.get("time"):
+        return None
+    if not any(value is not None for value in hourly.get("wave_height") or []):
+        return None
+    return hourly
+
+
+ARCHIVE_DAILY_VARIABLES = (
+    "weather_code",
+    "temperature_2m_max",
+    "temperature_2m_min",
+    "temperature_2m_mean",
+    "precipitation_sum",
+    "snowfall_sum",
+    "wind_speed_10m_max",
+    "wind_gusts_10m_max",
+)
+
+
+def get_archive_daily(
+    lat: float,
+    lon: float,
+    start: date,
+    end: date,
+    *,
+    tz_name: str | None = None,
+    offline: bool = False,
+    timeout: float = DEFAULT_TIMEOUT,
+) -> dict[str, list[Any]] | None:
+    """Daily observed-weather reanalysis (metric units, local dates) from the Open-Meteo archive."""
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "start_date": start.isoformat(),
+        "end_date": end.isoformat(),
+        "daily": ",".join(ARCHIVE_DAILY_VARIABLES),
+        "timezone": tz_name or "auto",
+    }
+    payload = _safe_request("GET", "https://archive-api.open-meteo.com/v1/archive", params=params, timeout=timeout)
+    daily = (payload or {}).get("daily")
+    if not isinstance(daily, dict) or not daily.get("time"):
+        return None
+    return daily
+
+
+def fetch_monthly_normals(
+    lat: float, lon: float, *, offline: bool = False, timeout: float = 10.0
+) -> dict[str, list[float]] | None:
+    """Fetch monthly climatological max/min temperatures (°C) from NASA POWER.
+
+    Returns ``{"tmax": [jan..dec], "tmin": [jan..dec]}`` or ``None`` when unavailable.
+    """
+    if offline:
+        return None
+
+    url = "https://power.larc.nasa.gov/api/temporal/climatology/point"
+    params = {
+        "parameters": "T2M_MAX,T2M_MIN",
+        "community": "AG",
+        "latitude": f"{lat:.2f}",
+        "longitude": f"{lon:.2f}",
+        "format": "JSON",
+    }
+    payload = _safe_request("GET", url, params=params, timeout=timeout)
+    if not payload:
+        return None
+
+    parameters = payload.get("properties", {}).get("parameter", {})
+    normals: dict[str, list[float]] = {}
+    for key, source in (("tmax", "T2M_MAX"), ("tmin", "T2M_MIN")):
+        monthly = parameters.get(source) or {}
+        values = [_safe_float(monthly.get(month)) for month in _MONTH_KEYS]
+        # POWER uses -999 as its fill value
+        if any(value is None or value <= -999 for value in values):
+            return None
+        normals[key] = values  # type: ignore[assignment]
+    return normals
+
+
+_ISO_DURATION = re.compile(r"^P(?:(\d+)D)?(?:T(?:(\d+)H)?(?:(\d+)M)?)?$")
+
+
+def get_nws_snowfall_grid(
+    lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> list[dict[str, Any]] | None:
+    """Fetch the NWS gridded snowfall forecast (mm per period) for a point."""
+    if offline:
+        return None
+
+    points = _safe_request("GET", f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}", timeout=timeout)
+    grid_url = (points or {}).get("properties", {}).get("forecastGridData")
+    if not grid_url:
+        return None
+    grid = _safe_request("GET", grid_url, timeout=timeout)
+    if not grid:
+        return None
+
+    periods: list[dict[str, Any]] = []
+    for entry in grid.get("properties", {}).get("snowfallAmount", {}).get("values", []):
+        start, _, duration = str(entry.get("validTime", "")).partition("/")
+        match = _ISO_DURATION.match(duration)
+        value = _safe_float(entry.get("value"))
+        if not start or not match or value is None:
+            continue
+        days, hours, minutes = (int(part or 0) for part in match.groups())
+        periods.append({"start_iso": start, "hours": days * 24 + hours + minutes / 60, "mm": value})
+    return periods
+
+
+def fetch_snowfall_ensemble(
+    lat: float, lon: float, *, offline: bool = False, timeout: float = 10.0, days: int = 3
+) -> dict[str, Any] | None:
+    """Fetch hourly snowfall (cm) for every GFS ensemble member from Open-Meteo.
+
+    Returns ``{"time": [...], "members": [[...], ...]}`` or ``None`` when unavailable.
+    """
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": "snowfall",
+        "models": "gfs_seamless",
+        "forecast_days": days,
+        "timezone": "UTC",
+    }
+    payload = _safe_request("GET", "https://ensemble-api.open-meteo.com/v1/ensemble", params=params, timeout=timeout)
+    hourly = (payload or {}).get("hourly") or {}
+    times = hourly.get("time") or []
+    members = [
+        [_safe_float(value) or 0.0 for value in series]
+        for key, series in sorted(hourly.items())
+        if key.startswith("snowfall") and isinstance(series, list)
+    ]
+    if not times or not members:
+        return None
+    return {"time": times, "members": members}
+
+
+def fetch_ensemble_members(
+    lat: float, lon: float, *, days: int = 3, offline: bool = False, timeout: float = 10.0
+) -> dict[str, Any] | None:
+    """Fetch hourly temperature (°C) and precipitation (mm) for every GFS ensemble member.
+
+    Returns ``{"time": [...], "temperature_2m": [[...], ...], "precipitation": [[...], ...]}``.
+    """
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": "temperature_2m,precipitation",
+        "models": "gfs_seamless",
+        "forecast_days": days,
+        "timezone": "UTC",
+    }
+    payload = _safe_request("GET", "https://ensemble-api.open-meteo.com/v1/ensemble", params=params, timeout=timeout)
+    hourly = (payload or {}).get("hourly") or {}
+    times = hourly.get("time") or []
+    members: dict[str, Any] = {"time": times}
+    for variable in ("temperature_2m", "precipitation"):
+        members[variable] = [
+            [_safe_float(value) for value in series]
+            for key, series in sorted(hourly.items())
+            if (key == variable or key.startswith(f"{variable}_member")) and isinstance(series, list)
+        ]
+    if not times or not members["temperature_2m"]:
+        return None
+    return members
+
+
+def fetch_model_forecasts(
+    lat: float,
+    lon: float,
+    models: tuple[str, ...],
+    *,
+    hours: int = 48,
+    offline: bool = False,
+    timeout: float = 10.0,
+) -> dict[str, Any] | None:
+    """Fetch hourly temperature (°C) and precipitation (mm) from each named Open-Meteo model.
+
+    Returns ``{"time": [...], model: {"temperature_2m": [...], "precipitation": [...]}, ...}``;
+    models the API has no data for are left out.
+    """
+    if offline:
+        return None
+
+    params = {
+        "latitude": lat,
+        "longitude": lon,
+        "hourly": "temperature_2m,precipitation",
+        "models": ",".join(models),
+        "forecast_hours": hours,
+        "timezone": "UTC",
+    }
+    payload = _safe_request("GET", "https://api.open-meteo.com/v1/forecast", params=params, timeout=timeout)
+    hourly = (payload or {}).get("hourly") or {}
+    times = hourly.get("time") or []
+    forecasts: dict[str, Any] = {"time": times}
+    for model in models:
+        series = {
+            variable: [_safe_float(value) for value in hourly.get(f"{variable}_{model}") or []]
+            for variable in ("temperature_2m", "precipitation")
+        }
+        if any(value is not None for value in series["temperature_2m"]):
+            forecasts[model] = series
+    if not times or len(forecasts) == 1:
+        return None
+    return forecasts
+
+
+RAINVIEWER_MAPS_URL = "https://api.rainviewer.com/public/weather-maps.json"
+
+
+def fetch_radar_maps(*, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> dict[str, Any] | None:
+    """Fetch RainViewer's list of radar frames (``host`` plus ``radar.past`` paths, oldest first)."""
+    if offline:
+        return None
+    return _safe_request("GET", RAINVIEWER_MAPS_URL, timeout=timeout)
+
+
+def fetch_radar_tile(url: str, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT) -> bytes | None:
+    """Fetch one radar tile PNG."""
+    if offline:
+        return None
+    return _safe_bytes_request(url, timeout=timeout)
+
+
+NDBC_LATEST_URL = "https://www.ndbc.noaa.gov/data/latest_obs/latest_obs.txt"
+GLERL_ICE_URL = "https://coastwatch.glerl.noaa.gov/statistic/ice/dat/g{season}_ice.dat"
+
+
+def fetch_ndbc_latest(*, offline: bool = False, timeout: float = 10.0) -> str | None:
+    """Fetch the NDBC latest-observations table for every reporting buoy and C-MAN station."""
+    if offline:
+        return None
+    return _safe_text_request(NDBC_LATEST_URL, timeout=timeout)
+
+
+def fetch_open_lakes_forecast(
+    lake_code: str, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> str | None:
+    """Fetch the latest NWS open lakes forecast (GLF) text for a Great Lake."""
+    if offline:
+        return None
+    product = _safe_request(
+        "GET", f"https://api.weather.gov/products/types/GLF/locations/{lake_code}/latest", timeout=timeout
+    )
+    text = (product or {}).get("productText")
+    return text if isinstance(text, str) else None
+
+
+def fetch_great_lakes_ice(season: str, *, offline: bool = False, timeout: float = 10.0) -> str | None:
+    """Fetch the GLERL daily ice cover table for a winter season (``2026_2027``)."""
+    if offline:
+        return None
+    return _safe_text_request(GLERL_ICE_URL.format(season=season), timeout=timeout)
+
+
 def fetch_us_alerts(
     *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT, severe_only: bool = False
 ) -> list[Alert]:
@@ -314,7 +1172,7 @@ def fetch_us_alerts(
     url = "https://api.weather.gov/alerts/active"
     try:
         with _create_client(timeout) as client:
-            response = client.get(url, params={"status": "actual"})
+            response = with_retries(lambda: client.get(url, params={"status": "actual"}))
             response.raise_for_status()
             data = response.json()
     except (httpx.HTTPError, ValueError):
@@ -324,18 +1182,13 @@ def fetch_us_alerts(
     alerts: list[Alert] = []
 
     for feature in features:
-        props = feature.get("properties", {})
-        event = props.get("event")
-        severity = props.get("severity", "Unknown")
-        areas_data = props.get("areaDesc", "")
-        areas = [a.strip() for a in areas_data.split(";") if a.strip()][:3]  # Limit to 3 areas
-        expires = props.get("ends")
-
-        if event:
+        alert = _parse_nws_alert(feature)
+        if alert:
             # Filter for severe weather if requested
-            if severe_only and not _is_severe_weather(event):
+            if severe_only and not _is_severe_weather(alert.event):
                 continue
-            alerts.append(Alert(event=event, severity=severity, areas=areas, expires_iso=expires))
+            alert.areas = alert.areas[:3]  # Limit to 3 areas
+            alerts.append(alert)
 
     return alerts
 
@@ -362,7 +1215,7 @@ def fetch_eu_alerts(
 
     try:
         with _create_client(timeout) as client:
-            response = client.get(url)
+            response = with_retries(lambda: client.get(url))
             response.raise_for_status()
             xml_content = response.text
     except (httpx.HTTPError, ValueError):
@@ -471,6 +1324,45 @@ def fetch_eu_alerts(
     return alerts
 
 
+_NHC_BASINS = {"al": "Atlantic", "ep": "Eastern Pacific", "cp": "Central Pacific"}
+
+
+def fetch_nhc_active_storms(
+    *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> list[TropicalCyclone]:
+    """Fetch active tropical cyclones in the NHC/CPHC basins."""
+    if offline:
+        return []
+
+    payload = _safe_request(
+        "GET", "https://www.nhc.noaa.gov/CurrentStorms.json", timeout=timeout
+    )
+    if not payload:
+        return []
+
+    storms: list[TropicalCyclone] = []
+    for entry in payload.get("activeStorms") or []:
+        storm_id = str(entry.get("id") or "")
+        name = entry.get("name")
+        if not storm_id or not name:
+            continue
+        storms.append(
+            TropicalCyclone(
+                storm_id=storm_id,
+                name=str(name),
+                basin=_NHC_BASINS.get(storm_id[:2].lower(), "Unknown"),
+                classification=str(entry.get("classification") or "").upper(),
+                intensity_kt=_safe_int(entry.get("intensity")),
+                pressure_mb=_safe_int(entry.get("pressure")),
+                lat=_safe_float(entry.get("latitudeNumeric")),
+                lon=_safe_float(entry.get("longitudeNumeric")),
+                movement_dir=_safe_float(entry.get("movementDir")),
+                movement_speed=_safe_float(entry.get("movementSpeed")),
+            )
+        )
+    return storms
+
+
 def get_nws_forecast_grid(
     lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
 ) -> dict[str, Any] | None:
@@ -478,47 +1370,22 @@ def get_nws_forecast_grid(
     if offline:
         return None
 
-    # First, get the grid point metadata
-    points_url = f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}"
-    try:
-        with _create_client(timeout) as client:
-            response = client.get(points_url)
-            response.raise_for_status()
-            points_data = response.json()
-    except (httpx.HTTPError, ValueError):
-        return None
-
-    # Extract forecast URL
-    properties = points_data.get("properties", {})
-    forecast_url = properties.get("forecast")
-    forecast_hourly_url = properties.get("forecastHourly")
-    grid_id = properties.get("gridId")
-    grid_x = properties.get("gridX")
-    grid_y = properties.get("gridY")
-
-    if not forecast_url:
-        return None
-
-    # Fetch the forecast
-    try:
-        with _create_client(timeout) as client:
-            forecast_response = client.get(forecast_url)
-            forecast_response.raise_for_status()
-            forecast_data = forecast_response.json()
-    except (httpx.HTTPError, ValueError):
+    properties, forecast_data, note = _nws_grid_product(lat, lon, "forecast", timeout=timeout)
+    if forecast_data is None:
         return None
 
     # Extract periods
     periods = forecast_data.get("properties", {}).get("periods", [])
 
     return {
-        "grid_id": grid_id,
-        "grid_x": grid_x,
-        "grid_y": grid_y,
-        "forecast_url": forecast_url,
-        "forecast_hourly_url": forecast_hourly_url,
+        "grid_id": properties.get("gridId"),
+        "grid_x": properties.get("gridX"),
+        "grid_y": properties.get("gridY"),
+        "forecast_url": properties.get("forecast"),
+        "forecast_hourly_url": properties.get("forecastHourly"),
         "periods": periods[:7],  # Next 7 periods (roughly 3-4 days)
         "updated": forecast_data.get("properties", {}).get("updated"),
+        "note": note,
     }
 
 
@@ -533,7 +1400,7 @@ def get_nws_observation_stations(
     url = f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}/stations"
     try:
         with _create_client(timeout) as client:
-            response = client.get(url)
+            response = with_retries(lambda: client.get(url))
             response.raise_for_status()
             data = response.json()
     except (httpx.HTTPError, ValueError):
@@ -570,7 +1437,7 @@ def get_nws_latest_observation(
     url = f"https://api.weather.gov/stations/{station_id}/observations/latest"
     try:
         with _create_client(timeout) as client:
-            response = client.get(url)
+            response = with_retries(lambda: client.get(url))
             response.raise_for_status()
             data = response.json()
     except (httpx.HTTPError, ValueError):
@@ -593,41 +1460,114 @@ def get_nws_latest_observation(
         "wind_chill_c": _safe_float(props.get("windChill", {}).get("value")),
         "cloud_layers": props.get("cloudLayers", []),
         "present_weather": props.get("presentWeather", []),
+        "text_description": props.get("textDescription"),
     }
 
 
 def get_nws_hourly_forecast(
-    lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+    lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT, hours: int = 24
 ) -> list[dict[str, Any]]:
     """Fetch NWS hourly forecast for a location."""
+    forecast = fetch_nws_hourly(lat, lon, offline=offline, timeout=timeout)
+    return (forecast or {}).get("periods", [])[:hours]  # Next 24 hours by default
+
+
+def fetch_nws_hourly(
+    lat: float, lon: float, *, offline: bool = False, timeout: float = DEFAULT_TIMEOUT
+) -> dict[str, Any] | None:
+    """Fetch the NWS hourly forecast with its issuance: ``{"periods", "update_time", "generated_at"}``.
+
+    ``update_time`` is when forecasters last edited the grids behind it.
+    """
     if offline:
-        return []
+        return None
 
-    # Get the grid point first
-    points_url = f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}"
-    try:
-        with _create_client(timeout) as client:
-            response = client.get(points_url)
-            response.raise_for_status()
-            points_data = response.json()
-    except (httpx.HTTPError, ValueError):
-        return []
+    _, forecast_data, note = _nws_grid_product(lat, lon, "forecastHourly", timeout=timeout)
+    if forecast_data is None:
+        return None
 
-    forecast_hourly_url = points_data.get("properties", {}).get("forecastHourly")
-    if not forecast_hourly_url:
-        return []
+    properties = forecast_data.get("properties", {})
+    return {
+        "periods": properties.get("periods", []),
+        "update_time": properties.get("updateTime"),
+        "generated_at": properties.get("generatedAt"),
+        "note": note,
+    }
 
-    # Fetch hourly forecast
+
+def _nws_grid_product(
+    lat: float, lon: float, product: str, *, timeout: float = DEFAULT_TIMEOUT
+) -> tuple[dict[str, Any], dict[str, Any] | None, str | None]:
+    """``(points properties, product JSON, note)`` for a points link such as ``forecastHourly``.
+
+    The NWS sometimes answers a valid point's grid with a 404, a 5xx "forecast grid expired"
+    problem or a body that is not JSON. Those retry from points NWS_GRID_RETRY_OFFSETS away,
+    which usually land on a healthy grid cell; ``note`` says when that happened. Points the
+    NWS does not cover at all (404 from the points API) are not retried.
+    """
+    original = _nws_points(lat, lon, timeout)
+    if not original.get(product):
+        return original, None, None
+    for dlat, dlon in ((0.0, 0.0), *NWS_GRID_RETRY_OFFSETS):
+        properties = _nws_points(lat + dlat, lon + dlon, timeout) if dlat or dlon else original
+        if (dlat or dlon) and properties.get(product) in (None, original[product]):
+            # Outside coverage, or the same grid cell that just failed.
+            continue
+        data = _nws_json(properties[product], timeout)
+        if data is not None and isinstance(data.get("properties"), dict):
+            shifted = f"{lat + dlat:.2f},{lon + dlon:.2f}"
+            note = f"The NWS grid for this point was unavailable; used {shifted} instead." if dlat or dlon else None
+            return properties, data, note
+    return original, None, None
+
+
+def _nws_points(lat: float, lon: float, timeout: float) -> dict[str, Any]:
+    points = _nws_json(f"https://api.weather.gov/points/{lat:.4f},{lon:.4f}", timeout) or {}
+    return points.get("properties") or {}
+
+
+def _nws_json(url: str, timeout: float) -> dict[str, Any] | None:
     try:
         with _create_client(timeout) as client:
-            forecast_response = client.get(forecast_hourly_url)
-            forecast_response.raise_for_status()
-            forecast_data = forecast_response.json()
+            response = with_retries(lambda: client.get(url))
+            response.raise_for_status()
+            data = response.json()
     except (httpx.HTTPError, ValueError):
-        return []
-
-    periods = forecast_data.get("properties", {}).get("periods", [])
-    return periods[:24]  # Next 24 hours
+        return None
+    return data if isinstance(data, dict) else None
+
+
+# WMO weather interpretation codes used by Open-Meteo, worded like NWS short forecasts.
+WMO_WEATHER_CODES = {
+    0: "Clear",
+    1: "Mostly Clear",
+    2: "Partly Cloudy",
+    3: "Cloudy",
+    45: "Fog",
+    48: "Freezing Fog",
+    51: "Light Drizzle",
+    53: "Drizzle",
+    55: "Heavy Drizzle",
+    56: "Freezing Drizzle",
+    57: "Freezing Drizzle",
+    61: "Light Rain",
+    63: "Rain",
+    65: "Heavy Rain",
+    66: "Freezing Rain",
+    67: "Freezing Rain",
+    71: "Light Snow",
+    73: "Snow",
+    75: "Heavy Snow",
+    77: "Snow Grains",
+    80: "Rain Showers",
+    81: "Rain Showers",
+    82: "Heavy Rain Showers",
+    85: "Snow Showers",
+    86: "Heavy Snow Showers",
+    95: "Thunderstorms",
+    96: "Thunderstorms With Hail",
+    99: "Thunderstorms With Hail",
+}
 
 
 def get_comprehensive_nws_data(
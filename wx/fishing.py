@@ -0,0 +1,294 @@
+"""``wx fish``: an hourly fishing index from solunar periods, light, pressure, wind, rain and tide.
+
+Solunar theory holds that fish feed most around the moon's transit and underfoot (major periods,
+about two hours) and its rise and set (minor periods, about an hour), more so near new and full
+moon. The almanac tradition adds dawn and dusk, a slowly falling barometer, a light chop and a
+moving tide. Each contributes points to a 0–100 score; the score is a guide, not a promise.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .astro import MOONRISE_ALT, SUNRISE_ALT, SYNODIC_MONTH, moon_altitude, moon_phase, sun_altitude
+from .clock import clock_label, zone
+
+FISH_HOURS = 48
+MAX_FISH_HOURS = 168
+SAMPLE_MINUTES = 10
+MAJOR_MINUTES = 60  # either side of transit and underfoot
+MINOR_MINUTES = 30  # either side of moonrise and moonset
+LIGHT_MINUTES = 30  # either side of the hour, for sunrise and sunset
+BASE_SCORE = 10
+# Points per factor; each hour gets at most one factor of each kind.
+POINTS = {
+    "major solunar period": 30,
+    "minor solunar period": 15,
+    "sunrise": 10,
+    "sunset": 10,
+    "new moon": 10,
+    "full moon": 10,
+    "falling pressure": 15,
+    "steady pressure": 5,
+    "dropping fast": 0,
+    "rising pressure": -5,
+    "light chop": 10,
+    "calm": 5,
+    "breezy": 0,
+    "strong wind": -15,
+    "light rain": 5,
+    "heavy rain": -10,
+    "moving tide": 10,
+    "slack tide": 0,
+}
+# hPa over three hours.
+PRESSURE_STEADY_HPA = 0.5
+PRESSURE_FAST_HPA = 3.0
+# km/h.
+CALM_KMH = 5.0
+CHOP_KMH = 20.0
+STRONG_KMH = 30.0
+# mm per hour.
+LIGHT_RAIN_MM = 0.1
+HEAVY_RAIN_MM = 4.0
+# Metres per hour of sea-level change below which the tide counts as slack.
+SLACK_TIDE_M = 0.05
+# New and full moon count this many days either side.
+MOON_DAYS = 2.0
+RATINGS = ((70, "excellent"), (50, "good"), (30, "fair"), (0, "poor"))
+WINDOW_SCORE = 50
+MAX_WINDOWS = 3
+
+
+@dataclass(slots=True)
+class FishReport:
+    """Hourly fishing scores for a place, the solunar periods behind them and the best windows."""
+
+    place: str
+    tz: str | None = None
+    hours: list[dict[str, Any]] = field(default_factory=list)
+    solunar: list[dict[str, Any]] = field(default_factory=list)
+    windows: list[dict[str, Any]] = field(default_factory=list)
+    moon: dict[str, Any] = field(default_factory=dict)
+    tides: bool = False
+    summary: str | None = None
+    sources: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def solunar_periods(lat: float, lon: float, start: datetime, end: datetime) -> list[dict[str, Any]]:
+    """Major (transit, underfoot) and minor (moonrise, moonset) periods overlapping ``start``..``end``."""
+
+    first = start - timedelta(minutes=MAJOR_MINUTES)
+    steps = int((end - first).total_seconds() // (SAMPLE_MINUTES * 60)) + MAJOR_MINUTES // SAMPLE_MINUTES + 1
+    samples = [first + timedelta(minutes=SAMPLE_MINUTES * idx) for idx in range(steps)]
+    altitudes = [moon_altitude(lat, lon, moment) for moment in samples]
+    events = []
+    for idx in range(1, len(samples) - 1):
+        before, here, after = altitudes[idx - 1], altitudes[idx], altitudes[idx + 1]
+        if here > before and here >= after:
+            events.append(("major", "moon overhead", samples[idx]))
+        elif here < before and here <= after:
+            events.append(("major", "moon underfoot", samples[idx]))
+        if before < MOONRISE_ALT <= here:
+            events.append(("minor", "moonrise", samples[idx]))
+        elif before >= MOONRISE_ALT > here:
+            events.append(("minor", "moonset", samples[idx]))
+    periods = []
+    for kind, event, moment in events:
+        half = timedelta(minutes=MAJOR_MINUTES if kind == "major" else MINOR_MINUTES)
+        if moment + half > start and moment - half < end:
+            periods.append(
+                {
+                    "kind": kind,
+                    "event": event,
+                    "time_iso": moment.isoformat(),
+                    "start_iso": (moment - half).isoformat(),
+                    "end_iso": (moment + half).isoformat(),
+                }
+            )
+    return sorted(periods, key=lambda period: period["start_iso"])
+
+
+def score_hours(
+    lat: float,
+    lon: float,
+    hourly: dict[str, list[Any]],
+    *,
+    sea_level: dict[str, float] | None = None,
+    solunar: list[dict[str, Any]] | None = None,
+) -> list[dict[str, Any]]:
+    """One ``{"time_iso", "score", "rating", "factors"}`` row per forecast hour.
+
+    ``sea_level`` maps ISO hours to modeled sea level (m) where the place has a tide.
+    """
+
+    times = [_utc(stamp) for stamp in hourly.get("time") or []]
+    if not times:
+        return []
+    solunar = solunar if solunar is not None else solunar_periods(lat, lon, times[0], times[-1] + timedelta(hours=1))
+    pressure = hourly.get("pressure_msl") or []
+    rows = []
+    for idx, moment in enumerate(times):
+        factors = []
+        factors += _solunar_factor(moment, solunar)
+        factors += _light_factor(lat, lon, moment)
+        factors += _moon_factor(moment)
+        factors += _pressure_factor(pressure, idx)
+        factors += _wind_factor(_at(hourly, "wind_speed_10m", idx))
+        factors += _rain_factor(_at(hourly, "precipitation", idx))
+        factors += _tide_factor(sea_level, moment)
+        score = max(0, min(100, BASE_SCORE + sum(POINTS[factor] for factor in factors)))
+        rows.append({"time_iso": moment.isoformat(), "score": score, "rating": rating(score), "factors": factors})
+    return rows
+
+
+def rating(score: float) -> str:
+    return next(label for floor, label in RATINGS if score >= floor)
+
+
+def best_windows(rows: list[dict[str, Any]], *, limit: int = MAX_WINDOWS) -> list[dict[str, Any]]:
+    """Runs of consecutive hours scoring WINDOW_SCORE or more, best mean score first."""
+
+    runs: list[list[dict[str, Any]]] = []
+    for row in rows:
+        if row["score"] < WINDOW_SCORE:
+            continue
+        previous = runs[-1][-1] if runs else None
+        if previous and _utc(row["time_iso"]) - _utc(previous["time_iso"]) == timedelta(hours=1):
+            runs[-1].append(row)
+        else:
+            runs.append([row])
+    windows = []
+    for run in runs:
+        mean = round(sum(row["score"] for row in run) / len(run))
+        peak = max(run, key=lambda row: row["score"])
+        windows.append(
+            {
+                "start_iso": run[0]["time_iso"],
+                "end_iso": (_utc(run[-1]["time_iso"]) + timedelta(hours=1)).isoformat(),
+                "score": mean,
+                "rating": rating(mean),
+                "factors": [factor for factor in peak["factors"] if POINTS[factor] > 0],
+            }
+        )
+    return sorted(windows, key=lambda window: -window["score"])[:limit]
+
+
+def summarize_fish(rows: list[dict[str, Any]], windows: list[dict[str, Any]], tz_name: str | None) -> str:
+    """``Best window Sat 5 AM–8 AM PDT (78, excellent).`` or the best single hour when none qualify."""
+
+    tz = zone(tz_name)
+    if windows:
+        best = windows[0]
+        start = clock_label(datetime.fromisoformat(best["start_iso"]), tz)
+        end = clock_label(datetime.fromisoformat(best["end_iso"]), tz, day=False)
+        return f"Best window {start}–{end} ({best['score']}, {best['rating']})."
+    if not rows:
+        return "No forecast hours to score."
+    peak = max(rows, key=lambda row: row["score"])
+    when = clock_label(datetime.fromisoformat(peak["time_iso"]), tz)
+    return f"No good windows; the best hour is {when} ({peak['score']}, {rating(peak['score'])})."
+
+
+def sea_levels(hourly: dict[str, list[Any]] | None) -> dict[str, float] | None:
+    """Open-Meteo Marine ``sea_level_height_msl`` keyed by ISO hour, or None without a tide."""
+
+    if not hourly:
+        return None
+    levels = {
+        _utc(stamp).isoformat(): float(level)
+        for stamp, level in zip(hourly.get("time") or [], hourly.get("sea_level_height_msl") or [], strict=False)
+        if isinstance(level, (int, float))
+    }
+    return levels or None
+
+
+def _solunar_factor(moment: datetime, periods: list[dict[str, Any]]) -> list[str]:
+    end = moment + timedelta(hours=1)
+    kinds = {
+        period["kind"]
+        for period in periods
+        if datetime.fromisoformat(period["start_iso"]) < end and datetime.fromisoformat(period["end_iso"]) > moment
+    }
+    if "major" in kinds:
+        return ["major solunar period"]
+    return ["minor solunar period"] if "minor" in kinds else []
+
+
+def _light_factor(lat: float, lon: float, moment: datetime) -> list[str]:
+    """Sunrise or sunset within LIGHT_MINUTES of the hour."""
+
+    window = timedelta(minutes=LIGHT_MINUTES)
+    before = sun_altitude(lat, lon, moment - window)
+    after = sun_altitude(lat, lon, moment + timedelta(hours=1) + window)
+    if before < SUNRISE_ALT <= after:
+        return ["sunrise"]
+    if before >= SUNRISE_ALT > after:
+        return ["sunset"]
+    return []
+
+
+def _moon_factor(moment: datetime) -> list[str]:
+    age = moon_phase(moment)["age_days"]
+    if min(age, SYNODIC_MONTH - age) <= MOON_DAYS:
+        return ["new moon"]
+    if abs(age - SYNODIC_MONTH / 2) <= MOON_DAYS:
+        return ["full moon"]
+    return []
+
+
+def _pressure_factor(pressure: list[Any], idx: int) -> list[str]:
+    """Three-hour change: the past three hours, or the next three at the start of the series."""
+
+    known = [value if isinstance(value, (int, float)) else None for value in pressure]
+    start, end = (idx - 3, idx) if idx >= 3 else (idx, idx + 3)
+    if end >= len(known) or known[start] is None or known[end] is None:
+        return []
+    change = known[end] - known[start]
+    if change <= -PRESSURE_FAST_HPA:
+        return ["dropping fast"]
+    if change <= -PRESSURE_STEADY_HPA:
+        return ["falling pressure"]
+    if change < PRESSURE_STEADY_HPA:
+        return ["steady pressure"]
+    return ["rising pressure"]
+
+
+def _wind_factor(kmh: float | None) -> list[str]:
+    if kmh is None:
+        return []
+    if kmh < CALM_KMH:
+        return ["calm"]
+    if kmh <= CHOP_KMH:
+        return ["light chop"]
+    return ["breezy"] if kmh <= STRONG_KMH else ["strong wind"]
+
+
+def _rain_factor(mm: float | None) -> list[str]:
+    if mm is None or mm < LIGHT_RAIN_MM:
+        return []
+    return ["heavy rain"] if mm >= HEAVY_RAIN_MM else ["light rain"]
+
+
+def _tide_factor(sea_level: dict[str, float] | None, moment: datetime) -> list[str]:
+    if not sea_level:
+        return []
+    here = sea_level.get(moment.isoformat())
+    after = sea_level.get((moment + timedelta(hours=1)).isoformat())
+    if here is None or after is None:
+        return []
+    return ["slack tide"] if abs(after - here) < SLACK_TIDE_M else ["moving tide"]
+
+
+def _at(hourly: dict[str, list[Any]], key: str, idx: int) -> float | None:
+    series = hourly.get(key) or []
+    value = series[idx] if idx < len(series) else None
+    return float(value) if isinstance(value, (int, float)) and not isinstance(value, bool) else None
+
+
+def _utc(stamp: Any) -> datetime:
+    moment = datetime.fromisoformat(str(stamp))
+    return moment.astimezone(UTC) if moment.tzinfo else moment.replace(tzinfo=UTC)
@@ -5,58 +5,55 @@ from __future__ import annotations
 import json
 import logging
 import textwrap
-from dataclasses import dataclass
+from collections.abc import Callable
+from dataclasses import dataclass, replace
 from typing import Any
 
-from .config import DEFAULT_OPENROUTER_BASE_URL, DEFAULT_OPENROUTER_MODELS, Settings
-from .openrouter_client import OpenRouterConfig, OpenRouterError, chat_completion
+from .airquality import apply_air_quality
+from .anthropic_client import AnthropicConfig, create_message
+from .astro import is_astro_focus
+from .cache import STORY_TTL, FetchCache, story_key
+from .clock import localize_sections
+from .config import (
+    AI_BACKENDS,
+    DEFAULT_OPENAI_BASE_URL,
+    DEFAULT_OPENROUTER_BASE_URL,
+    DEFAULT_OPENROUTER_MODELS,
+    Settings,
+)
+from .decisions import apply_decision_floor
+from .ensemble import apply_model_agreement
+from .language import detect_mismatch, language_name, prose_text
+from .ollama_client import OllamaConfig
+from .ollama_client import chat as ollama_chat
+from .openrouter_client import (
+    RETRYABLE_STATUS_CODES,
+    OpenRouterConfig,
+    OpenRouterError,
+    chat_completion,
+    stream_chat_completion,
+)
+from .pollen import apply_pollen
+from .privacy import coarsen_coordinates
+from .prompts import DATA_SYSTEM_PROMPT, STYLE_PRESETS, SYSTEM_PROMPT, get_prompt
+from .risk import SPC_CATEGORIES, apply_spc_outlook
+from .safety import apply_alert_safety
+from .storyparse import parse_story
+from .storyprompt import alert_lines, load_story_template, render_story_prompt
+from .uv import apply_sun_safety
 
 try:  # pragma: no cover - optional dependency
     import google.genai as genai  # type: ignore
 except ImportError:  # pragma: no cover - optional dependency
     genai = None  # type: ignore
 
-SYSTEM_PROMPT = textwrap.dedent(
-    """
-    You are wx, an expert operational meteorologist providing concise, actionable briefings.
-    Follow this contract strictly:
-    - Quantify uncertainty and avoid sensational language.
-    - Use both local and UTC times when possible.
-    - Never fabricate specific values; rely on provided Feature Pack or clearly state limitations.
-    - Reference which Feature Pack fields you used.
-    - Output JSON matching the schema discussed below.
-
-    Response schema (JSON object):
-    {
-      "sections": {
-        "summary": ["2-4 sentences"],
-        "timeline": ["Bullet timeline items with local and UTC times"],
-        "risk_cards": [
-          {
-            "hazard": "Severe|Flooding|Winter|Wind|Heat|Cold|Fire|Aviation",
-            "level": "Low|Moderate|High",
-            "drivers": ["key drivers"],
-            "confidence": "short rationale"
-          }
-        ],
-        "confidence": "Explain uncertainties and what could change.",
-        "actions": ["Actionable advice tied to user context"],
-        "assumptions": ["Key assumptions you made"]
-      },
-      "confidence": {"value": 0-100, "rationale": "One-line confidence summary"},
-      "used_feature_fields": ["list of Feature Pack keys you relied on"],
-      "bottom_line": "Single sentence takeaway"
-    }
-
-    Keep output \u2264 400 words unless explicitly told verbose. If information is missing,
-    speak qualitatively and acknowledge the gap. If explain_mode is true, focus on
-    clarifying which inputs drove the previous answer and why confidence is set.
-    """
-).strip()
-
-
 logger = logging.getLogger(__name__)
 
+# OpenRouter failures that move on to the next model in OPENROUTER_MODELS (None: timeouts).
+MODEL_FALLBACK_STATUS_CODES = {*RETRYABLE_STATUS_CODES, None}
+# Reply fields of DATA_SYSTEM_PROMPT kept in sections besides the answer itself.
+DATA_ANSWER_FIELDS = ("supporting_values", "time_window", "sources")
+
 
 @dataclass(slots=True)
 class ForecasterResponse:
@@ -79,12 +76,16 @@ class ForecasterResponse:
 
 
 class Forecaster:
-    """Dispatch AI requests via OpenRouter (Grok/ChatGPT OSS) with Gemini fallback."""
+    """Dispatch AI requests through OpenRouter, OpenAI, Anthropic, Gemini and local Ollama in turn."""
 
     def __init__(self, settings: Settings) -> None:
         self.settings = settings
         self._gemini_client: Any | None = None
         self._warned_missing_openrouter_key = False
+        # Called with the text so far while a backend streams, then with None once it is done.
+        self.on_progress: Callable[[str | None], None] | None = None
+        # Set by the orchestrator unless --no-cache: repeat prompts within STORY_TTL reuse the story.
+        self.story_cache: FetchCache | None = None
 
     def generate(
         self,
@@ -94,10 +95,12 @@ class Forecaster:
         intent: str,
         verbose: bool,
         explain: bool = False,
+        prompt_version: str | None = None,
     ) -> ForecasterResponse:
         prompt_summary = self._compose_prompt_summary(query, intent, verbose, explain)
         payload = {
             "intent": intent,
+            "prompt_version": prompt_version or self.settings.prompt_version,
             "style": self.settings.style,
             "persona": self.settings.persona,
             "verbose": verbose,
@@ -107,13 +110,31 @@ class Forecaster:
         }
 
         if self.settings.offline:
-            return self._fallback_response(
+            response = self._fallback_response(
                 payload, provider="offline", prompt_summary=prompt_summary
             )
-
+        else:
+            response = self._generate_online(payload, prompt_summary)
+        # Safety content must not depend on the model following the prompt. Structured answers
+        # carry alerts as a field instead of appended prose.
+        tz_name = (feature_pack.get("place") or {}).get("tz")
+        if not explain and intent != "data":
+            apply_decision_floor(response, self.settings.decision_floor, self.settings.decision_floor_mode)
+            apply_alert_safety(response, feature_pack.get("alerts_quick"))
+            apply_spc_outlook(response, feature_pack.get("spc_outlook"))
+            apply_air_quality(response, feature_pack.get("air_quality"), tz_name)
+            apply_pollen(response, feature_pack.get("pollen"), tz_name)
+            apply_sun_safety(response, feature_pack.get("uv"), tz_name)
+            apply_model_agreement(response, feature_pack.get("ensemble"))
+        if tz_name:
+            localize_sections(response, tz_name)
+        return response
+
+    def _generate_online(self, payload: dict[str, Any], prompt_summary: str) -> ForecasterResponse:
         try:
             raw, provider, meta = self._invoke_provider(payload)
-            return self._parse_response(raw, prompt_summary, provider, meta)
+            response = self._parse_response(raw, prompt_summary, provider, meta)
+            return self._enforce_language(response, payload, prompt_summary)
         except Exception as exc:  # noqa: BLE001
             return self._fallback_response(
                 payload,
@@ -122,54 +143,164 @@ class Forecaster:
                 raw_text=str(exc),
                 meta={"error": str(exc)},
             )
+        finally:
+            if self.on_progress is not None:
+                self.on_progress(None)
 
     def _invoke_provider(self, payload: dict[str, Any]) -> tuple[str, str, dict[str, Any] | None]:
         errors: list[str] = []
         prompt = self._build_prompt(payload)
+        version = get_prompt(payload.get("prompt_version"))
+        if payload["explain_mode"]:
+            system_prompt = version.system
+        elif payload["intent"] == "data":
+            system_prompt = DATA_SYSTEM_PROMPT
+        elif payload["intent"] == "question":
+            system_prompt = version.qa_system
+        else:
+            system_prompt = version.system
+        key = story_key(system_prompt, prompt, self.settings) if self.story_cache is not None else None
+        if key is not None:
+            cached = self.story_cache.get("story", key, STORY_TTL)
+            if cached:
+                return cached["raw"], cached["provider"], {**cached["meta"], "cached": True}
+
+        backends = {
+            "openrouter": self._call_openrouter,
+            "openai": self._call_openai,
+            "anthropic": self._call_anthropic,
+            "gemini": self._call_gemini_backend,
+            "ollama": self._call_ollama,
+        }
+        preferred = self.settings.preferred_backend
+        order = [preferred] if preferred in backends else []
+        order += [name for name in AI_BACKENDS if name != preferred]
+        for name in order:
+            try:
+                outcome = backends[name](system_prompt, prompt)
+            except RuntimeError as exc:
+                errors.append(f"{name}:{exc}")
+                continue
+            if outcome is not None:
+                raw, provider, meta = outcome
+                # Record who answered and what failed first so --json/--debug can show it.
+                meta = {**(meta or {}), "backend": name, "fallbacks": errors, "prompt_version": version.name}
+                if key is not None:
+                    kept = {field: meta[field] for field in ("model", "backend", "prompt_version") if field in meta}
+                    self.story_cache.put("story", key, {"raw": raw, "provider": provider, "meta": kept})
+                return raw, provider, meta
+
+        reason = "; ".join(errors) if errors else "no-provider-configured"
+        raise RuntimeError(reason)
 
+    def _call_openrouter(self, system_prompt: str, prompt: str) -> tuple[str, str, dict[str, Any]] | None:
         config = self._build_openrouter_config()
-        if config:
+        if not config:
+            return None
+        models = self.settings.openrouter_models or DEFAULT_OPENROUTER_MODELS
+        skipped: list[str] = []
+        for index, model in enumerate(models):
             try:
-                response = chat_completion(
+                response = self._chat_completion(
                     [
-                        {"role": "system", "content": SYSTEM_PROMPT},
+                        {"role": "system", "content": system_prompt},
                         {"role": "user", "content": prompt},
                     ],
-                    config=config,
+                    replace(config, model=model),
                 )
-                meta: dict[str, Any] = {
-                    "model": response.model,
-                    "usage": response.usage,
-                    "attempts": response.attempts,
-                    "headers": dict(response.headers),
-                }
-                return response.text, f"openrouter:{response.model}", meta
             except OpenRouterError as exc:
-                errors.append(f"openrouter:{exc}")
+                # Rate limits and outages are often per model; other errors (auth) are not.
+                if exc.status_code not in MODEL_FALLBACK_STATUS_CODES or index == len(models) - 1:
+                    raise
+                skipped.append(f"{model}:{exc}")
+                continue
+            meta: dict[str, Any] = {
+                "model": response.model,
+                "usage": response.usage,
+                "attempts": response.attempts,
+                "headers": dict(response.headers),
+            }
+            if skipped:
+                meta["skipped_models"] = skipped
+            return response.text, f"openrouter:{response.model}", meta
+        return None
+
+    def _call_openai(self, system_prompt: str, prompt: str) -> tuple[str, str, dict[str, Any]] | None:
+        if not self.settings.openai_api_key:
+            return None
+        # OpenAI speaks the same chat completions protocol; JSON mode guarantees an object back.
+        config = OpenRouterConfig(
+            api_key=self.settings.openai_api_key,
+            base_url=DEFAULT_OPENAI_BASE_URL,
+            model=self.settings.openai_model,
+            temperature=self.settings.ai_temperature,
+            max_tokens=self.settings.ai_max_tokens,
+            label="OpenAI",
+            json_mode=True,
+        )
+        response = self._chat_completion(
+            [
+                {"role": "system", "content": system_prompt},
+                {"role": "user", "content": prompt},
+            ],
+            config,
+        )
+        meta = {"model": response.model, "usage": response.usage, "attempts": response.attempts}
+        return response.text, f"openai:{response.model}", meta
 
-        if self.settings.gemini_api_key:
-            try:
-                text = self._call_gemini(prompt)
-                if text:
-                    meta = {"model": self.settings.gemini_model}
-                    return text, "gemini", meta
-                errors.append("gemini:no-response")
-            except RuntimeError as exc:
-                errors.append(f"gemini:{exc}")
+    def _call_anthropic(self, system_prompt: str, prompt: str) -> tuple[str, str, dict[str, Any]] | None:
+        if not self.settings.anthropic_api_key:
+            return None
+        config = AnthropicConfig(
+            api_key=self.settings.anthropic_api_key,
+            model=self.settings.anthropic_model,
+            temperature=self.settings.ai_temperature,
+            max_tokens=self.settings.ai_max_tokens,
+        )
+        response = create_message(system_prompt, prompt, config=config)
+        meta = {"model": response.model, "usage": response.usage}
+        return response.text, f"anthropic:{response.model}", meta
 
-        reason = "; ".join(errors) if errors else "no-provider-configured"
-        raise RuntimeError(reason)
+    def _call_gemini_backend(self, system_prompt: str, prompt: str) -> tuple[str, str, dict[str, Any]] | None:
+        if not self.settings.gemini_api_key:
+            return None
+        text = self._call_gemini(prompt, system_prompt)
+        if not text:
+            raise RuntimeError("no-response")
+        return text, "gemini", {"model": self.settings.gemini_model}
+
+    def _call_ollama(self, system_prompt: str, prompt: str) -> tuple[str, str, dict[str, Any]] | None:
+        if not self.settings.ollama_model:
+            return None
+        local = ollama_chat(
+            [
+                {"role": "system", "content": system_prompt},
+                {"role": "user", "content": prompt},
+            ],
+            config=self._build_ollama_config(),
+        )
+        return local.text, f"ollama:{local.model}", {"model": local.model, "usage": local.usage}
+
+    def _chat_completion(self, messages: list[dict[str, str]], config: OpenRouterConfig) -> Any:
+        if self.on_progress is None:
+            return chat_completion(messages, config=config)
+        return stream_chat_completion(messages, config=config, on_text=self.on_progress)
 
     def _build_openrouter_config(self) -> OpenRouterConfig | None:
         api_key = self.settings.openrouter_api_key
         if not api_key:
-            if not self._warned_missing_openrouter_key:
+            others = (
+                self.settings.openai_api_key,
+                self.settings.anthropic_api_key,
+                self.settings.gemini_api_key,
+                self.settings.ollama_model,
+            )
+            if not self._warned_missing_openrouter_key and not any(others):
                 logger.warning("OPENROUTER_API_KEY not configured; using offline fallback.")
                 self._warned_missing_openrouter_key = True
             return None
 
-        models = self.settings.openrouter_models or DEFAULT_OPENROUTER_MODELS
-        model = models[0]
+        model = (self.settings.openrouter_models or DEFAULT_OPENROUTER_MODELS)[0]
         base_url = self.settings.openrouter_base_url or DEFAULT_OPENROUTER_BASE_URL
 
         return OpenRouterConfig(
@@ -180,7 +311,15 @@ class Forecaster:
             max_tokens=self.settings.ai_max_tokens,
         )
 
-    def _call_gemini(self, prompt: str) -> str | None:
+    def _build_ollama_config(self) -> OllamaConfig:
+        return OllamaConfig(
+            base_url=self.settings.ollama_url,
+            model=self.settings.ollama_model or "",
+            temperature=self.settings.ai_temperature,
+            max_tokens=self.settings.ai_max_tokens,
+        )
+
+    def _call_gemini(self, prompt: str, system_prompt: str = SYSTEM_PROMPT) -> str | None:
         if genai is None:
             raise RuntimeError("google-genai-not-installed")
         if not self.settings.gemini_api_key:
@@ -192,10 +331,13 @@ class Forecaster:
             except Exception as exc:  # pragma: no cover - defensive
                 raise RuntimeError(f"gemini-client:{exc}") from exc
 
+        contents = f"{system_prompt}\n\n{prompt}"
         try:
+            if self.on_progress is not None:
+                return self._stream_gemini(contents)
             response = self._gemini_client.models.generate_content(
                 model=self.settings.gemini_model,
-                contents=f"{SYSTEM_PROMPT}\n\n{prompt}",
+                contents=contents,
             )
         except Exception as exc:  # pragma: no cover - defensive
             raise RuntimeError(f"gemini-call:{exc}") from exc
@@ -203,27 +345,183 @@ class Forecaster:
         text = getattr(response, "text", None)
         return text.strip() if isinstance(text, str) else None
 
+    def _stream_gemini(self, contents: str) -> str | None:
+        assert self.on_progress is not None and self._gemini_client is not None
+        parts: list[str] = []
+        for chunk in self._gemini_client.models.generate_content_stream(
+            model=self.settings.gemini_model,
+            contents=contents,
+        ):
+            text = getattr(chunk, "text", None)
+            if isinstance(text, str) and text:
+                parts.append(text)
+                self.on_progress("".join(parts))
+        return "".join(parts).strip() or None
+
     def _build_prompt(self, payload: dict[str, Any]) -> str:
+        query = payload["query"]
+        feature_pack = payload["feature_pack"]
+        if self.settings.privacy_mode:
+            # Models only need the area, not the user's exact point.
+            query = coarsen_coordinates(query)
+            feature_pack = coarsen_coordinates(feature_pack)
         content = textwrap.dedent(
             f"""
             You are to answer as wx.
-            Query: {payload["query"]}
+            Query: {query}
             Intent: {payload["intent"]}
             Style: {self.settings.style}
             Persona: {self.settings.persona}
             Verbose: {payload["verbose"]}
             Explain mode: {payload["explain_mode"]}
             Feature Pack JSON:
-            {json.dumps(payload["feature_pack"], ensure_ascii=True, indent=2)}
+            {json.dumps(feature_pack, ensure_ascii=True, indent=2)}
             """
         ).strip()
-        instructions = (
-            "Focus on explaining feature usage and confidence rationale."
-            if payload["explain_mode"]
-            else "Provide a meteorological briefing."
-        )
+        if payload["explain_mode"]:
+            instructions = "Focus on explaining feature usage and confidence rationale."
+        elif payload["intent"] == "question":
+            instructions = "Answer the question concisely; do not write a full briefing."
+        elif payload["intent"] == "data":
+            instructions = "Return only the structured answer object; do not write prose."
+        else:
+            instructions = "Provide a meteorological briefing."
+            if feature_pack.get("window"):
+                instructions += (
+                    " Cover only the Feature Pack window; use its periods for timing and"
+                    " window_summary for extremes and totals."
+                )
+            if self.settings.decision_floor:
+                instructions += (
+                    " Also return sections.action_confidence: a list with your 0-100 confidence in each"
+                    " action, in the same order as actions."
+                )
+            if feature_pack.get("as_of"):
+                instructions += (
+                    " Write the briefing as of the as_of time, treating it as now; the data are archived"
+                    " model runs issued before then, with no observations or alerts."
+                )
+            focus = (feature_pack.get("user_context") or {}).get("focus")
+            if focus:
+                instructions += (
+                    f" The user's focus is {focus}: lead the summary, risk cards, and actions with"
+                    f" what matters for {focus}."
+                )
+            if feature_pack.get("aviation"):
+                instructions += (
+                    " Use the aviation METAR/TAF for ceilings, visibility, winds in knots and flight"
+                    " categories (VFR/MVFR/IFR/LIFR), citing the station."
+                )
+                if feature_pack["aviation"].get("advisories") or feature_pack["aviation"].get("pireps"):
+                    instructions += (
+                        " Name any SIGMET, AIRMET or G-AIRMET over the airport with its altitude band,"
+                        " and what recent PIREPs report for turbulence and icing."
+                    )
+            if (feature_pack.get("spc_outlook") or {}).get("category") in SPC_CATEGORIES:
+                instructions += (
+                    " The place is inside an SPC severe thunderstorm outlook (spc_outlook): name the"
+                    " category in the summary and add a severe-storms risk card."
+                )
+            if (feature_pack.get("air_quality") or {}).get("elevated"):
+                instructions += (
+                    " The air_quality block shows an elevated AQI: add a health-oriented action naming"
+                    " the hours, the pollutant and who should limit outdoor exertion."
+                )
+            if feature_pack.get("astro") and is_astro_focus(focus):
+                instructions += (
+                    " Use the astro block for sunrise, sunset and golden-hour times; say whether the"
+                    " forecast cloud cover favours golden-hour light and, for stargazing, mention the moon."
+                )
+            if (feature_pack.get("pollen") or {}).get("high"):
+                instructions += (
+                    " The pollen block shows high levels: add an allergy action naming the pollen type,"
+                    " the peak time and what to do (medication, windows, timing outdoor activity)."
+                )
+            if (feature_pack.get("uv") or {}).get("protect"):
+                instructions += (
+                    " The uv block shows UV 3 or higher: add a sun-safety action with the hours,"
+                    " sunscreen and when to reapply."
+                )
+            if feature_pack.get("ensemble"):
+                instructions += (
+                    " Base the confidence value and rationale on the ensemble block: cite the spread of"
+                    " member highs and the share of wet members, and stay at or below its confidence_cap."
+                )
+            if (feature_pack.get("normals") or {}).get("notable"):
+                instructions += (
+                    " The normals block shows the day running well above or below the climate normal:"
+                    " say so in the summary using its label (e.g. '12°F above normal for this date')."
+                )
+            if feature_pack.get("marine"):
+                instructions += (
+                    " Use the marine block for wave height, swell period and direction, water"
+                    " temperature and tide times; flag waves above rough_wave_threshold_m."
+                )
+        if feature_pack.get("warnings") and payload["intent"] != "data":
+            instructions += (
+                " Some data could not be fetched (see warnings): say what is missing and do not guess it."
+            )
+        if feature_pack.get("plugins") and payload["intent"] != "data":
+            instructions += (
+                " The plugins block holds data from the user's own sources, keyed by source: use it where"
+                " it matters and name the source."
+            )
+        style = STYLE_PRESETS.get(self.settings.style)
+        if style and style.instruction and payload["intent"] != "data":
+            instructions += " " + style.instruction
+        language = self.settings.language
+        if language:
+            name = language_name(language)
+            instructions += f" Write every text value in {name}; keep JSON keys and hazard/level values in English."
+            detected = payload.get("language_correction")
+            if detected:
+                instructions += (
+                    f" Your previous reply was in {language_name(detected)}, not {name}."
+                    f" Rewrite it entirely in {name}."
+                )
+        briefing = not payload["explain_mode"] and payload["intent"] not in ("question", "data")
+        template = load_story_template() if briefing else None
+        if template is not None:
+            return render_story_prompt(
+                template,
+                {
+                    "query": query,
+                    "location": (feature_pack.get("place") or {}).get("resolved"),
+                    "focus": (feature_pack.get("user_context") or {}).get("focus"),
+                    "style": self.settings.style,
+                    "persona": self.settings.persona,
+                    "verbose": payload["verbose"],
+                    "alerts": "\n".join(alert_lines(feature_pack.get("alerts_quick"))) or "None",
+                    "forecast": json.dumps(feature_pack, ensure_ascii=True, indent=2),
+                    "instructions": instructions,
+                },
+            )
         return f"{content}\nAdditional instructions: {instructions}"
 
+    def _enforce_language(
+        self, response: ForecasterResponse, payload: dict[str, Any], prompt_summary: str
+    ) -> ForecasterResponse:
+        """Retry once with a correction when the model ignored the requested language."""
+
+        language = self.settings.language
+        if not language or response.provider.startswith("fallback"):
+            return response
+        detected = detect_mismatch(prose_text(response.sections, response.bottom_line), language)
+        if detected is None:
+            return response
+        check: dict[str, Any] = {"requested": language, "detected": detected}
+        try:
+            raw, provider, meta = self._invoke_provider({**payload, "language_correction": detected})
+            retried = self._parse_response(raw, prompt_summary, provider, meta)
+        except RuntimeError as exc:
+            check["retry_error"] = str(exc)
+            retried = None
+        if retried is not None and not retried.provider.startswith("fallback"):
+            check["after_retry"] = detect_mismatch(prose_text(retried.sections, retried.bottom_line), language)
+            response = retried
+        response.meta = {**(response.meta or {}), "language_check": check}
+        return response
+
     def _parse_response(
         self,
         raw_text: str,
@@ -231,21 +529,28 @@ class Forecaster:
         provider: str,
         meta: dict[str, Any] | None,
     ) -> ForecasterResponse:
-        cleaned = raw_text.strip()
-        if cleaned.startswith("```"):
-            cleaned = self._strip_fence(cleaned)
-        try:
-            data = json.loads(cleaned)
-        except json.JSONDecodeError:
+        parsed = parse_story(raw_text)
+        if not parsed.ok:
             return self._fallback_response(
                 {"feature_pack": {}, "intent": "parse_error"},
                 provider="fallback:unparseable",
                 prompt_summary=prompt_summary,
                 raw_text=raw_text,
-                meta=meta,
+                meta={**(meta or {}), "parse_error": parsed.error, "parse_repairs": parsed.repairs},
             )
-
-        sections = data.get("sections") or {}
+        if parsed.repairs or parsed.problems:
+            meta = {**(meta or {}), "parse_repairs": parsed.repairs, "parse_problems": parsed.problems}
+
+        data = parsed.data
+        sections = data["sections"]
+        answer = data.get("answer")
+        if isinstance(answer, str) and answer.strip():
+            sections = {**sections, "answer": answer.strip()}
+            sections.setdefault("summary", [answer.strip()])
+        # Structured (--data-only) answers keep their fields for the answer object.
+        for key in DATA_ANSWER_FIELDS:
+            if key in data:
+                sections = {**sections, key: data[key]}
         confidence = data.get("confidence") or {
             "value": 30,
             "rationale": "Model confidence not supplied.",
@@ -336,7 +641,7 @@ class Forecaster:
         )
 
         provider = response.provider or "offline"
-        if provider.startswith("openrouter") or provider.startswith("gemini"):
+        if provider.startswith(AI_BACKENDS):
             mode = "online"
         elif provider.startswith("offline"):
             mode = "offline"
@@ -381,11 +686,3 @@ class Forecaster:
             else:
                 keys.append(key)
         return sorted(set(keys))
-
-    def _strip_fence(self, text: str) -> str:
-        lines = text.splitlines()
-        if lines and lines[0].startswith("```"):
-            lines = lines[1:]
-        if lines and lines[-1].startswith("```"):
-            lines = lines[:-1]
-        return "\n".join(lines)
@@ -0,0 +1,90 @@
+"""Which forecast issuance a story is built on, and when the next one is due.
+
+NWS forecasters edit the gridded forecast through the day and the hourly product is rebuilt from the
+grids; ``updateTime`` on it is the latest edit. Edits land roughly hourly, so the next one is
+estimated as an hour after the last, rounded up to the hour.
+"""
+
+from __future__ import annotations
+
+import time
+from collections.abc import Callable
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .clock import clock_label, zone
+
+UPDATE_INTERVAL = timedelta(hours=1)
+# --wait-for-update polls this often, and gives up after MAX_WAIT_MINUTES.
+POLL_SECONDS = 300
+MAX_WAIT_MINUTES = 90
+
+
+def next_update(issued: datetime) -> datetime:
+    """Estimated time of the issuance after ``issued``: an hour later, rounded up to the hour."""
+
+    due = issued + UPDATE_INTERVAL
+    on_hour = due.replace(minute=0, second=0, microsecond=0)
+    return on_hour if on_hour == due else on_hour + timedelta(hours=1)
+
+
+def issuance_pack(
+    issued_iso: str | None, *, source: str = "NWS", tz_name: str | None = None, now: datetime | None = None
+) -> dict[str, Any] | None:
+    """``{"issued_iso", "next_update_iso", "age_minutes", "overdue", "label"}`` for an issuance stamp."""
+
+    issued = _parse(issued_iso)
+    if issued is None:
+        return None
+    now = now or datetime.now(UTC)
+    due = next_update(issued)
+    tz = zone(tz_name)
+    overdue = now > due + UPDATE_INTERVAL
+    other_day = issued.astimezone(tz).date() != now.astimezone(tz).date()
+    label = f"{source} forecast issued {clock_label(issued, tz, day=other_day)}"
+    label += ", next update overdue" if overdue else f", next update ~{clock_label(due, tz, day=False)}"
+    return {
+        "source": source,
+        "issued_iso": issued.isoformat(),
+        "next_update_iso": due.isoformat(),
+        "age_minutes": max(0, round((now - issued).total_seconds() / 60)),
+        "overdue": overdue,
+        "label": label,
+    }
+
+
+def wait_for_newer(
+    check: Callable[[], str | None],
+    baseline: str | None,
+    *,
+    timeout_minutes: int = MAX_WAIT_MINUTES,
+    poll_seconds: float = POLL_SECONDS,
+    sleep: Callable[[float], None] = time.sleep,
+    on_poll: Callable[[int], None] | None = None,
+) -> str | None:
+    """Call ``check`` every ``poll_seconds`` until it reports an issuance newer than ``baseline``.
+
+    Returns the newer issuance stamp, or None when ``timeout_minutes`` pass without one.
+    """
+
+    first = _parse(baseline)
+    polls = max(1, int(timeout_minutes * 60 // poll_seconds))
+    for attempt in range(1, polls + 1):
+        sleep(poll_seconds)
+        if on_poll:
+            on_poll(attempt)
+        latest = check()
+        stamp = _parse(latest)
+        if stamp is not None and (first is None or stamp > first):
+            return latest
+    return None
+
+
+def _parse(iso: str | None) -> datetime | None:
+    if not iso:
+        return None
+    try:
+        moment = datetime.fromisoformat(str(iso).replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
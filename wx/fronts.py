@@ -0,0 +1,176 @@
+"""Rule-based frontal passage detection from hourly forecast series."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+from zoneinfo import ZoneInfo
+
+from .tropics import compass_point
+
+# Hours either side of a candidate passage to compare.
+FRONT_WINDOW_HOURS = 3
+# Passages closer together than this are treated as the same front.
+MIN_SEPARATION_HOURS = 6
+# Below this wind speed (km/h) the direction is too noisy to call a shift.
+MIN_WIND_KMH = 5.0
+MIN_VEER_DEGREES = 40.0
+
+
+@dataclass(slots=True)
+class FrontPassage:
+    """A likely frontal passage and the signals behind it."""
+
+    kind: str  # "cold" or "warm"
+    time_iso: str
+    wind_from: float | None = None
+    wind_to: float | None = None
+    temp_change_c: float | None = None
+    dewpoint_change_c: float | None = None
+    pressure_rise_hpa: float | None = None
+    strength: float = 0.0
+    drivers: list[str] = field(default_factory=list)
+
+
+def detect_fronts(hourly: dict[str, list[Any]] | None) -> list[FrontPassage]:
+    """Find likely cold and warm frontal passages in an Open-Meteo hourly series.
+
+    A passage needs a veering wind shift plus a thermal signal: cold fronts bring a
+    dewpoint or temperature drop, warm fronts a joint dewpoint and temperature rise.
+    A pressure trough at the passage is reported as supporting evidence.
+    """
+
+    if not hourly:
+        return []
+    times = hourly.get("time") or []
+    temps = _series(hourly, "temperature_2m")
+    dewpoints = _series(hourly, "dew_point_2m")
+    directions = _series(hourly, "wind_direction_10m")
+    speeds = _series(hourly, "wind_speed_10m")
+    pressures = _series(hourly, "pressure_msl")
+
+    candidates: list[tuple[int, FrontPassage]] = []
+    for idx in range(FRONT_WINDOW_HOURS, len(times) - FRONT_WINDOW_HOURS):
+        before, after = idx - FRONT_WINDOW_HOURS, idx + FRONT_WINDOW_HOURS
+        wind_from, wind_to = _at(directions, before), _at(directions, after)
+        if wind_from is None or wind_to is None:
+            continue
+        if (_at(speeds, before) or 0) < MIN_WIND_KMH or (_at(speeds, after) or 0) < MIN_WIND_KMH:
+            continue
+        veer = _veer(wind_from, wind_to)
+        if veer < MIN_VEER_DEGREES:
+            continue
+
+        temp_change = _change(temps, before, after)
+        dew_change = _change(dewpoints, before, after)
+        kind = None
+        if _is_cold_sector(wind_to) and ((dew_change or 0) <= -3 or (temp_change or 0) <= -4):
+            kind = "cold"
+        elif _is_warm_sector(wind_to) and (dew_change or 0) >= 3 and (temp_change or 0) >= 2:
+            kind = "warm"
+        if kind is None:
+            continue
+
+        passage = FrontPassage(
+            kind=kind,
+            time_iso=_utc_iso(times[idx]),
+            wind_from=wind_from,
+            wind_to=wind_to,
+            temp_change_c=temp_change,
+            dewpoint_change_c=dew_change,
+            pressure_rise_hpa=_trough_rise(pressures, before, idx, after),
+            strength=abs(temp_change or 0) + abs(dew_change or 0) + veer / 15,
+        )
+        passage.drivers = _drivers(passage)
+        candidates.append((idx, passage))
+
+    # Keep the strongest hour of each passage.
+    chosen: list[tuple[int, FrontPassage]] = []
+    for idx, passage in sorted(candidates, key=lambda item: item[1].strength, reverse=True):
+        if all(abs(idx - other) >= MIN_SEPARATION_HOURS for other, _ in chosen):
+            chosen.append((idx, passage))
+    return [passage for _, passage in sorted(chosen, key=lambda item: item[0])]
+
+
+def describe_front(front: FrontPassage, *, tz_name: str | None = None, units: str = "imperial") -> str:
+    """Timeline label such as ``Cold front ~Fri 4 PM: wind veers NW, temp drops 12°``."""
+
+    when = datetime.fromisoformat(front.time_iso)
+    if tz_name:
+        try:
+            when = when.astimezone(ZoneInfo(tz_name))
+        except (KeyError, ValueError):
+            pass
+    hour = when.strftime("%I %p").lstrip("0")
+    parts = []
+    if front.wind_to is not None:
+        parts.append(f"wind veers {compass_point(front.wind_to)}")
+    if front.temp_change_c:
+        degrees = front.temp_change_c * 9 / 5 if units == "imperial" else front.temp_change_c
+        if round(abs(degrees)):
+            verb = "drops" if degrees < 0 else "rises"
+            parts.append(f"temp {verb} {abs(degrees):.0f}°")
+    return f"{front.kind.title()} front ~{when:%a} {hour}: {', '.join(parts)}"
+
+
+def _series(hourly: dict[str, list[Any]], key: str) -> list[float | None]:
+    return [value if isinstance(value, (int, float)) else None for value in hourly.get(key) or []]
+
+
+def _at(values: list[float | None], idx: int) -> float | None:
+    return values[idx] if idx < len(values) else None
+
+
+def _change(values: list[float | None], before: int, after: int) -> float | None:
+    start, end = _at(values, before), _at(values, after)
+    if start is None or end is None:
+        return None
+    return round(end - start, 1)
+
+
+def _veer(wind_from: float, wind_to: float) -> float:
+    """Clockwise change in wind direction, negative when the wind backs."""
+
+    return (wind_to - wind_from + 540) % 360 - 180
+
+
+def _is_cold_sector(direction: float) -> bool:
+    # West through north.
+    return direction >= 225 or direction <= 20
+
+
+def _is_warm_sector(direction: float) -> bool:
+    # South-southeast through west-southwest.
+    return 150 <= direction <= 250
+
+
+def _trough_rise(pressures: list[float | None], before: int, idx: int, after: int) -> float | None:
+    """Pressure rise after a trough at ``idx``, or None when there is no trough."""
+
+    window = [_at(pressures, before), _at(pressures, idx), _at(pressures, after)]
+    if any(value is None for value in window):
+        return None
+    start, trough, end = window
+    if trough <= start and trough <= end and end - trough >= 1.0:
+        return round(end - trough, 1)
+    return None
+
+
+def _drivers(front: FrontPassage) -> list[str]:
+    drivers = [f"Wind {compass_point(front.wind_from)} → {compass_point(front.wind_to)}"]
+    if front.temp_change_c is not None:
+        drivers.append(f"Temperature {front.temp_change_c:+.1f}°C over {FRONT_WINDOW_HOURS * 2}h")
+    if front.dewpoint_change_c is not None:
+        drivers.append(f"Dewpoint {front.dewpoint_change_c:+.1f}°C over {FRONT_WINDOW_HOURS * 2}h")
+    if front.pressure_rise_hpa is not None:
+        drivers.append(f"Pressure trough, then +{front.pressure_rise_hpa:.1f} hPa")
+    return drivers
+
+
+def _utc_iso(value: str) -> str:
+    # Open-Meteo times are naive UTC.
+    parsed = datetime.fromisoformat(value)
+    if parsed.tzinfo is None:
+        parsed = parsed.replace(tzinfo=UTC)
+    return parsed.astimezone(UTC).isoformat()
@@ -0,0 +1,103 @@
+"""Forecast variables that ``wx graph`` can chart and their unit handling."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any
+
+from .units import convert, display_unit
+
+
+@dataclass(frozen=True, slots=True)
+class GraphVariable:
+    """A chartable Open-Meteo hourly variable."""
+
+    key: str  # Open-Meteo hourly variable
+    label: str
+    kind: str  # temp | speed | percent | depth | energy | pressure
+
+
+GRAPH_VARIABLES = {
+    "temperature": GraphVariable("temperature_2m", "Temperature", "temp"),
+    "feels_like": GraphVariable("apparent_temperature", "Feels like", "temp"),
+    "dewpoint": GraphVariable("dew_point_2m", "Dewpoint", "temp"),
+    "wind": GraphVariable("wind_speed_10m", "Wind speed", "speed"),
+    "gust": GraphVariable("wind_gusts_10m", "Wind gust", "speed"),
+    "precip_probability": GraphVariable("precipitation_probability", "Precip probability", "percent"),
+    "precipitation": GraphVariable("precipitation", "Precipitation", "depth"),
+    "pressure": GraphVariable("pressure_msl", "Sea-level pressure", "pressure"),
+    "cape": GraphVariable("cape", "CAPE", "energy"),
+}
+
+SPARK_BLOCKS = "▁▂▃▄▅▆▇█"
+
+GRAPH_ALIASES = {
+    "temp": "temperature",
+    "dew": "dewpoint",
+    "dewpoint_2m": "dewpoint",
+    "feels": "feels_like",
+    "apparent": "feels_like",
+    "gusts": "gust",
+    "wind_gust": "gust",
+    "pop": "precip_probability",
+    "precip_prob": "precip_probability",
+    "precip": "precipitation",
+    "rain": "precipitation",
+    "mslp": "pressure",
+}
+
+
+@dataclass(slots=True)
+class GraphSeries:
+    """One location's values for a charted variable, converted to display units."""
+
+    place: str
+    variable: str
+    label: str
+    unit: str
+    times: list[str] = field(default_factory=list)
+    values: list[float | None] = field(default_factory=list)
+
+    @property
+    def has_data(self) -> bool:
+        return any(value is not None for value in self.values)
+
+
+def resolve_variable(name: str) -> str | None:
+    """Canonical variable name for user input, or None when it cannot be charted."""
+
+    key = name.strip().lower().replace("-", "_").replace(" ", "_")
+    key = GRAPH_ALIASES.get(key, key)
+    return key if key in GRAPH_VARIABLES else None
+
+
+def sparkline(values: list[float | None], *, low: float | None = None, high: float | None = None) -> str:
+    """One block character per value, scaled between ``low`` and ``high`` (default: the data range)."""
+
+    known = [value for value in values if value is not None]
+    if not known:
+        return ""
+    low = min(known) if low is None else low
+    high = max(known) if high is None else high
+    span = (high - low) or 1.0
+    top = len(SPARK_BLOCKS) - 1
+    return "".join(
+        " " if value is None else SPARK_BLOCKS[round((value - low) / span * top)] for value in values
+    )
+
+
+def build_series(
+    place: str, variable: str, hourly: dict[str, list[Any]] | None, *, hours: int, units: str
+) -> GraphSeries:
+    """Extract ``variable`` for the first ``hours`` hours of an Open-Meteo hourly payload."""
+
+    spec = GRAPH_VARIABLES[variable]
+    series = GraphSeries(place=place, variable=variable, label=spec.label, unit=display_unit(spec.kind, units))
+    if not hourly:
+        return series
+    raw = hourly.get(spec.key) or []
+    series.times = list((hourly.get("time") or [])[:hours])
+    for idx in range(len(series.times)):
+        value = raw[idx] if idx < len(raw) else None
+        series.values.append(convert(value, spec.kind, units) if isinstance(value, (int, float)) else None)
+    return series
@@ -0,0 +1,83 @@
+"""Location groups from the profile (``"stores": [...]``), addressed as ``@stores`` on the command line."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any
+
+from .favorites import Favorite, parse_favorites
+from .safety import SAFETY_SEVERITIES
+
+GROUP_PREFIX = "@"
+# Alert severities from most to least serious, for ordering sites.
+SEVERITY_ORDER = (*SAFETY_SEVERITIES, "Moderate", "Minor", "Unknown")
+
+
+@dataclass(slots=True)
+class SiteAlerts:
+    """One group member's active alerts, or why they could not be fetched."""
+
+    label: str
+    place: str
+    resolved: str | None = None
+    tz: str | None = None
+    alerts: list[dict[str, Any]] = field(default_factory=list)
+    error: str | None = None
+
+
+@dataclass(slots=True)
+class GroupAlertsReport:
+    """Alerts for every site in a location group, most serious sites first."""
+
+    group: str
+    sites: list[SiteAlerts] = field(default_factory=list)
+    summary: str | None = None
+
+
+def parse_groups(raw: Any) -> dict[str, list[Favorite]]:
+    """``{"stores": ["Denver", {"name": "Store 12", "place": "39.7,-105.0"}]}``; members take any
+    form ``favorites`` accepts. Empty groups are dropped."""
+
+    if not isinstance(raw, dict):
+        return {}
+    groups = {}
+    for name, members in raw.items():
+        key = str(name).strip().lstrip(GROUP_PREFIX).lower()
+        sites = parse_favorites(members)
+        if key and sites:
+            groups[key] = sites
+    return groups
+
+
+def group_name(token: str | None) -> str | None:
+    """``stores`` for ``@stores``; None when ``token`` is not a group reference."""
+
+    if not token or not token.startswith(GROUP_PREFIX):
+        return None
+    return token[len(GROUP_PREFIX) :].strip().lower() or None
+
+
+def severity_rank(site: SiteAlerts) -> int:
+    """Position of the site's most serious alert in SEVERITY_ORDER; quiet and failed sites last."""
+
+    severities = [alert.get("severity") for alert in site.alerts]
+    severities = [severity if severity in SEVERITY_ORDER else "Unknown" for severity in severities]
+    return min((SEVERITY_ORDER.index(severity) for severity in severities), default=len(SEVERITY_ORDER))
+
+
+def summarize_group_alerts(report: GroupAlertsReport) -> str:
+    """``3 of 12 stores have alerts (1 severe or extreme); 1 could not be checked.``"""
+
+    total = len(report.sites)
+    alerted = [site for site in report.sites if site.alerts]
+    urgent = [site for site in alerted if severity_rank(site) < len(SAFETY_SEVERITIES)]
+    failed = [site for site in report.sites if site.error]
+    if alerted:
+        text = f"{len(alerted)} of {total} {report.group} {'has' if len(alerted) == 1 else 'have'} alerts"
+        if urgent:
+            text += f" ({len(urgent)} severe or extreme)"
+    else:
+        text = f"No alerts across {total} {report.group}"
+    if failed:
+        text += f"; {len(failed)} could not be checked"
+    return f"{text}."
@@ -0,0 +1,115 @@
+"""``wx history``: past daily weather from the Open-Meteo archive, optionally against normals."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import date, timedelta
+from typing import Any
+
+from .fetchers import WMO_WEATHER_CODES
+from .normals import interpolate_daily_normal
+
+# Longest range one request covers; the archive starts in 1940.
+HISTORY_MAX_DAYS = 92
+ARCHIVE_START = date(1940, 1, 1)
+# The reanalysis behind the archive lags real time by a few days.
+ARCHIVE_LAG_DAYS = 5
+
+
+@dataclass(slots=True)
+class HistoryReport:
+    """Daily summaries for ``start``..``end`` at a place."""
+
+    place: str
+    start: str
+    end: str
+    days: list[dict[str, Any]] = field(default_factory=list)
+    summary: dict[str, Any] = field(default_factory=dict)
+    compared_to_normal: bool = False
+    sources: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def validate_range(start: date, end: date | None, *, today: date | None = None) -> tuple[date, date]:
+    """Check a requested date range; raises ValueError with a user-facing message."""
+
+    end = end or start
+    latest = (today or date.today()) - timedelta(days=ARCHIVE_LAG_DAYS)
+    if end < start:
+        raise ValueError("The end date is before the start date.")
+    if start < ARCHIVE_START:
+        raise ValueError(f"The archive starts on {ARCHIVE_START.isoformat()}.")
+    if end > latest:
+        raise ValueError(
+            f"The archive runs about {ARCHIVE_LAG_DAYS} days behind; pick {latest.isoformat()} or earlier."
+        )
+    if (end - start).days + 1 > HISTORY_MAX_DAYS:
+        raise ValueError(f"Ranges are limited to {HISTORY_MAX_DAYS} days.")
+    return start, end
+
+
+def history_rows(daily: dict[str, list[Any]]) -> list[dict[str, Any]]:
+    """One metric row per archive day."""
+
+    def at(key: str, idx: int) -> float | None:
+        series = daily.get(key) or []
+        value = series[idx] if idx < len(series) else None
+        return float(value) if isinstance(value, (int, float)) and not isinstance(value, bool) else None
+
+    rows = []
+    for idx, day in enumerate(daily.get("time") or []):
+        code = at("weather_code", idx)
+        rows.append(
+            {
+                "date": str(day),
+                "temp_max_c": at("temperature_2m_max", idx),
+                "temp_min_c": at("temperature_2m_min", idx),
+                "temp_mean_c": at("temperature_2m_mean", idx),
+                "precip_mm": at("precipitation_sum", idx),
+                "snow_cm": at("snowfall_sum", idx),
+                "wind_max_kmh": at("wind_speed_10m_max", idx),
+                "gust_max_kmh": at("wind_gusts_10m_max", idx),
+                "conditions": WMO_WEATHER_CODES.get(int(code)) if code is not None else None,
+            }
+        )
+    return rows
+
+
+def add_normals(rows: list[dict[str, Any]], normals: dict[str, list[float]]) -> None:
+    """Attach each day's normal high/low and how far the observed values departed from them."""
+
+    for row in rows:
+        day = date.fromisoformat(row["date"])
+        normal_max = round(interpolate_daily_normal(normals["tmax"], day), 1)
+        normal_min = round(interpolate_daily_normal(normals["tmin"], day), 1)
+        row["normal_max_c"], row["normal_min_c"] = normal_max, normal_min
+        row["max_departure_c"] = _departure(row["temp_max_c"], normal_max)
+        row["min_departure_c"] = _departure(row["temp_min_c"], normal_min)
+
+
+def summarize_history(rows: list[dict[str, Any]]) -> dict[str, Any]:
+    """Warmest and coldest days, precipitation totals and the mean departure from normal."""
+
+    highs = [row for row in rows if row["temp_max_c"] is not None]
+    lows = [row for row in rows if row["temp_min_c"] is not None]
+    summary: dict[str, Any] = {
+        "days": len(rows),
+        "warmest": max(highs, key=lambda row: row["temp_max_c"])["date"] if highs else None,
+        "coldest": min(lows, key=lambda row: row["temp_min_c"])["date"] if lows else None,
+        "precip_total_mm": round(sum(row["precip_mm"] or 0 for row in rows), 1),
+        "wet_days": sum(1 for row in rows if (row["precip_mm"] or 0) >= 1.0),
+        "snow_total_cm": round(sum(row["snow_cm"] or 0 for row in rows), 1),
+    }
+    departures = [
+        value
+        for row in rows
+        for value in (row.get("max_departure_c"), row.get("min_departure_c"))
+        if value is not None
+    ]
+    if departures:
+        summary["mean_departure_c"] = round(sum(departures) / len(departures), 1)
+    return summary
+
+
+def _departure(observed: float | None, normal: float) -> float | None:
+    return round(observed - normal, 1) if observed is not None else None
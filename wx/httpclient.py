@@ -0,0 +1,104 @@
+"""Shared retry, backoff and timeout policy for wx's HTTP calls.
+
+Weather APIs (the NWS in particular) answer the odd request with a 502 or a dropped connection
+that succeeds a second later. ``with_retries`` repeats such calls with exponential backoff and
+jitter instead of failing the command; ``--timeout``/``WX_HTTP_TIMEOUT`` and ``WX_HTTP_RETRIES``
+tune it. The AI backend clients retry through it too, but keep their own, longer timeouts and
+never retry a read timeout: a model that took that long will not answer faster the second time,
+and the next backend in the fallback chain should get its turn.
+"""
+
+from __future__ import annotations
+
+import random
+import time
+from collections.abc import Callable
+from dataclasses import dataclass
+
+import httpx
+
+DEFAULT_RETRIES = 2
+BACKOFF_BASE = 0.5
+BACKOFF_MAX = 8.0
+# Statuses that are usually gone on the next attempt. A 500 is left out: the NWS uses it for
+# expired grids, which wx.fetchers retries from a nearby point instead.
+RETRY_STATUSES = frozenset({429, 502, 503, 504})
+
+
+@dataclass(frozen=True, slots=True)
+class HttpPolicy:
+    """Retries after the first attempt, and a timeout in seconds overriding each caller's default."""
+
+    retries: int = DEFAULT_RETRIES
+    timeout: float | None = None
+
+
+_policy = HttpPolicy()
+_sleep: Callable[[float], None] = time.sleep
+
+
+def configure_http(*, retries: int | None = None, timeout: float | None = None) -> HttpPolicy:
+    """Set the process-wide policy (from the CLI settings) and return it."""
+
+    global _policy
+    _policy = HttpPolicy(retries=DEFAULT_RETRIES if retries is None else max(0, retries), timeout=timeout)
+    return _policy
+
+
+def parse_timeout(value: str | float | None) -> float | None:
+    """Seconds as a positive number; None or an empty value keeps each caller's default."""
+
+    if value is None or not str(value).strip():
+        return None
+    try:
+        seconds = float(value)
+    except ValueError:
+        seconds = 0.0
+    if not seconds > 0:
+        raise ValueError(f"HTTP timeout must be a positive number of seconds, not '{value}'.")
+    return seconds
+
+
+def timeout_for(default: float) -> float:
+    """The configured timeout, or ``default`` (the caller's per-provider timeout) when unset."""
+
+    return _policy.timeout or default
+
+
+def backoff_delay(attempt: int, response: httpx.Response | None = None) -> float:
+    """Seconds before retry ``attempt`` (0-based): exponential with jitter, or ``Retry-After``."""
+
+    retry_after = response.headers.get("Retry-After") if response is not None else None
+    if retry_after and retry_after.strip().isdigit():
+        return min(float(retry_after), BACKOFF_MAX)
+    ceiling = min(BACKOFF_MAX, BACKOFF_BASE * 2**attempt)
+    return random.uniform(ceiling / 2, ceiling)
+
+
+def with_retries(
+    call: Callable[[], httpx.Response],
+    *,
+    retries: int | None = None,
+    retry_read_timeouts: bool = True,
+) -> httpx.Response:
+    """Run ``call``, retrying timeouts, dropped connections and RETRY_STATUSES answers.
+
+    The last response is returned either way (callers still ``raise_for_status``); a transport
+    error on the final attempt propagates, as does a read timeout with ``retry_read_timeouts=False``.
+    """
+
+    for attempt in range(_policy.retries if retries is None else retries):
+        try:
+            response = call()
+        except httpx.ReadTimeout:
+            if not retry_read_timeouts:
+                raise
+            _sleep(backoff_delay(attempt))
+            continue
+        except httpx.TransportError:
+            _sleep(backoff_delay(attempt))
+            continue
+        if response.status_code not in RETRY_STATUSES:
+            return response
+        _sleep(backoff_delay(attempt, response))
+    return call()
@@ -0,0 +1,183 @@
+"""``wx forecast --export ics``: forecast periods, alert windows and story timings as iCalendar events.
+
+Periods and alerts come from the Feature Pack. Timeline and action items are matched for the local
+clock times wx writes into them (``Fri 3 PM PDT``, optionally ``–Fri 9 PM PDT``), so only items the
+story actually timed become events.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import re
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .clock import zone
+from .units import display_unit
+
+EXPORT_FORMATS = ("ics",)
+PRODID = "-//wx-cli//wx forecast//EN"
+# Timed story items without an end become events this long.
+TIMING_MINUTES = 60
+# RFC 5545 content lines are folded at 75 octets.
+LINE_OCTETS = 75
+_WEEKDAYS = ("Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun")
+_CLOCK = r"(Mon|Tue|Wed|Thu|Fri|Sat|Sun) (\d{1,2})(?::(\d{2}))? (AM|PM)(?: [A-Z][A-Za-z+\d-]{1,5})?"
+_TIMING = re.compile(rf"{_CLOCK}(?:\s*[–-]\s*(?:{_CLOCK}|(\d{{1,2}})(?::(\d{{2}}))? (AM|PM)))?")
+
+
+def forecast_calendar(result, *, units: str, now: datetime | None = None) -> str:
+    """The forecast ``result`` as a VCALENDAR with CRLF line endings."""
+
+    feature_pack = result.feature_pack or {}
+    place_info = feature_pack.get("place") or {}
+    place = place_info.get("resolved") or result.query
+    tz_name = place_info.get("tz")
+    now = now or datetime.now(UTC)
+    anchor = datetime.fromisoformat(feature_pack["as_of"]) if feature_pack.get("as_of") else now
+    stamp = _utc_text(now)
+
+    events: list[list[str]] = []
+    for period in feature_pack.get("periods") or []:
+        start = _aware(period.get("start_iso"))
+        if start is not None:
+            end = start + timedelta(hours=period.get("hours") or 1)
+            summary, details = _period_summary(period, units), _period_details(period, units)
+            events.append(_event("period", place, start, end, summary, details, "Weather"))
+    for alert in feature_pack.get("alerts_quick") or []:
+        start = _aware(alert.get("onset_iso")) or anchor
+        end = _aware(alert.get("expires_iso")) or start + timedelta(hours=1)
+        details = [alert.get("headline"), f"Severity: {alert.get('severity') or 'Unknown'}"]
+        if alert.get("areas"):
+            details.append(f"Areas: {', '.join(alert['areas'])}")
+        summary = f"⚠ {alert.get('event')}"
+        events.append(_event("alert", place, start, max(end, start), summary, details, "Weather alert"))
+    sections = result.response.sections if result.response else {}
+    for key, category in (("timeline", "Weather timeline"), ("actions", "Weather action")):
+        items = sections.get(key)
+        for item in items if isinstance(items, list) else []:
+            if not isinstance(item, str):
+                continue
+            window = story_timing(item, tz_name, after=anchor)
+            if window:
+                events.append(_event(key, place, *window, item, [item], category))
+
+    lines = [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        f"PRODID:{PRODID}",
+        "CALSCALE:GREGORIAN",
+        "METHOD:PUBLISH",
+        f"X-WR-CALNAME:{_escape(f'Weather: {place}')}",
+    ]
+    for event in events:
+        lines += ["BEGIN:VEVENT", f"DTSTAMP:{stamp}", *event, "END:VEVENT"]
+    lines.append("END:VCALENDAR")
+    return "".join(f"{folded}\r\n" for line in lines for folded in fold(line))
+
+
+def story_timing(text: str, tz_name: str | None, *, after: datetime) -> tuple[datetime, datetime] | None:
+    """Start and end of the first local clock time (or range) in ``text``, on or after ``after``."""
+
+    match = _TIMING.search(text)
+    if not match:
+        return None
+    tz = zone(tz_name)
+    start = _resolve(match.group(1), match.group(2), match.group(3), match.group(4), tz, after)
+    if match.group(5):
+        end = _resolve(match.group(5), match.group(6), match.group(7), match.group(8), tz, start)
+    elif match.group(9):
+        end = _resolve(_WEEKDAYS[start.weekday()], match.group(9), match.group(10), match.group(11), tz, start)
+        # "Fri 10 PM–2 AM" runs past midnight.
+        end = end if end > start else end + timedelta(days=1)
+    else:
+        end = start + timedelta(minutes=TIMING_MINUTES)
+    return start, max(end, start + timedelta(minutes=1))
+
+
+def fold(line: str) -> list[str]:
+    """Split a content line into RFC 5545 chunks of at most 75 octets, never inside a character."""
+
+    chunks, current, size = [], "", 0
+    for char in line:
+        width = len(char.encode("utf-8"))
+        limit = LINE_OCTETS if not chunks else LINE_OCTETS - 1
+        if size + width > limit:
+            chunks.append(current)
+            current, size = "", 0
+        current += char
+        size += width
+    chunks.append(current)
+    return [chunks[0], *(f" {chunk}" for chunk in chunks[1:])]
+
+
+def _event(
+    kind: str, place: str, start: datetime, end: datetime, summary: str, details: list[Any], category: str
+) -> list[str]:
+    digest = hashlib.sha1(f"{kind}|{place}|{start.isoformat()}|{summary}".encode()).hexdigest()[:16]
+    description = "\n".join(str(line) for line in details if line)
+    lines = [
+        f"UID:{digest}@wx-cli",
+        f"DTSTART:{_utc_text(start)}",
+        f"DTEND:{_utc_text(end)}",
+        f"SUMMARY:{_escape(summary)}",
+        f"LOCATION:{_escape(place)}",
+        f"CATEGORIES:{_escape(category)}",
+        "TRANSP:TRANSPARENT",
+    ]
+    if description:
+        lines.insert(4, f"DESCRIPTION:{_escape(description)}")
+    return lines
+
+
+def _period_summary(period: dict[str, Any], units: str) -> str:
+    parts = []
+    if period.get("temp") is not None:
+        parts.append(f"{period['temp']:.0f}{display_unit('temp', units)}")
+    if period.get("precip_prob") is not None:
+        parts.append(f"{period['precip_prob']:.0f}% precip")
+    if period.get("gust") is not None:
+        parts.append(f"gusts {period['gust']:.0f} {display_unit('speed', units)}")
+    return " · ".join(parts) or "Forecast"
+
+
+def _period_details(period: dict[str, Any], units: str) -> list[str]:
+    temp, speed, depth = (display_unit(kind, units) for kind in ("temp", "speed", "depth"))
+    fields = (
+        ("Temperature", "temp", temp),
+        ("Feels like", "feels_like", temp),
+        ("Dew point", "dewpoint", temp),
+        ("Wind", "wind", f" {speed}"),
+        ("Gusts", "gust", f" {speed}"),
+        ("Precipitation chance", "precip_prob", "%"),
+        ("Precipitation", "precip", f" {depth}"),
+    )
+    return [f"{label}: {period[key]}{unit}" for label, key, unit in fields if period.get(key) is not None]
+
+
+def _resolve(day: str, hour: str, minute: str | None, meridiem: str, tz, after: datetime) -> datetime:
+    """The first ``day`` at ``hour:minute meridiem`` local time that is not before ``after``'s date."""
+
+    local_after = after.astimezone(tz)
+    clock = int(hour) % 12 + (12 if meridiem == "PM" else 0)
+    days_ahead = (_WEEKDAYS.index(day) - local_after.weekday()) % 7
+    date = (local_after + timedelta(days=days_ahead)).date()
+    return datetime(date.year, date.month, date.day, clock, int(minute or 0), tzinfo=tz).astimezone(UTC)
+
+
+def _aware(iso: str | None) -> datetime | None:
+    if not iso:
+        return None
+    try:
+        moment = datetime.fromisoformat(str(iso).replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+
+
+def _utc_text(moment: datetime) -> str:
+    return moment.astimezone(UTC).strftime("%Y%m%dT%H%M%SZ")
+
+
+def _escape(text: str) -> str:
+    return text.replace("\\", "\\\\").replace(";", "\\;").replace(",", "\\,").replace("\n", "\\n")
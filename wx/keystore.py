@@ -0,0 +1,89 @@
+"""Optional OS keyring storage for API keys referenced from profile JSON."""
+
+from __future__ import annotations
+
+from typing import Any
+
+try:  # pragma: no cover - optional dependency
+    import keyring  # type: ignore
+    from keyring.errors import KeyringError  # type: ignore
+except ImportError:  # pragma: no cover - optional dependency
+    keyring = None  # type: ignore
+
+    class KeyringError(Exception):  # type: ignore[no-redef]
+        pass
+
+
+KEYRING_SERVICE = "wx-cli"
+# Profile field name -> Settings attribute it supplies.
+SECRET_KEYS = {
+    "openrouter_key": "openrouter_api_key",
+    "openai_key": "openai_api_key",
+    "anthropic_key": "anthropic_api_key",
+    "gemini_key": "gemini_api_key",
+}
+
+
+class KeystoreError(RuntimeError):
+    """Raised when a secret cannot be stored or removed."""
+
+
+def keyring_available() -> bool:
+    return keyring is not None
+
+
+def store_secret(profile: str, key: str, value: str) -> dict[str, str]:
+    """Save ``value`` in the OS keyring and return the reference kept in the profile JSON."""
+
+    if keyring is None:
+        raise KeystoreError("The keyring package is not installed (pip install 'wx-cli[keyring]').")
+    account = f"{profile}/{key}"
+    try:
+        keyring.set_password(KEYRING_SERVICE, account, value)
+    except KeyringError as exc:
+        raise KeystoreError(f"Could not save {key} to the keyring: {exc}") from exc
+    return {"keyring": account}
+
+
+def delete_secret(reference: Any) -> None:
+    """Remove a keyring entry; plaintext values and missing entries are ignored."""
+
+    account = _account(reference)
+    if account is None or keyring is None:
+        return
+    try:
+        keyring.delete_password(KEYRING_SERVICE, account)
+    except KeyringError:
+        pass
+
+
+def resolve_secret(value: Any) -> str | None:
+    """Return the secret for a profile value: a plaintext string or a keyring reference."""
+
+    if isinstance(value, str):
+        return value.strip() or None
+    account = _account(value)
+    if account is None or keyring is None:
+        return None
+    try:
+        secret = keyring.get_password(KEYRING_SERVICE, account)
+    except KeyringError:
+        # A locked or missing keyring should not stop weather lookups.
+        return None
+    return secret or None
+
+
+def secret_source(value: Any) -> str | None:
+    """``keyring`` or ``plaintext`` for a stored profile value, ``None`` when unset."""
+
+    if _account(value) is not None:
+        return "keyring"
+    if isinstance(value, str) and value.strip():
+        return "plaintext"
+    return None
+
+
+def _account(reference: Any) -> str | None:
+    if isinstance(reference, dict) and isinstance(reference.get("keyring"), str):
+        return reference["keyring"]
+    return None
@@ -0,0 +1,175 @@
+"""Lake conditions: nearest Great Lake, buoy water temperatures, open-lakes waves, ice cover."""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+from datetime import date
+from typing import Any
+
+from .geo import haversine_km
+
+
+@dataclass(frozen=True, slots=True)
+class GreatLake:
+    """Static metadata for one of the Great Lakes."""
+
+    code: str  # NWS open lakes forecast (GLF) location id
+    name: str
+    axis: tuple[tuple[float, float], ...]  # points along the lake's long axis
+    reach_km: float  # distance from the axis still treated as "on" the lake
+    ice_column: int  # column in the GLERL daily ice cover table
+
+
+GREAT_LAKES = (
+    GreatLake("LS", "Lake Superior", ((46.6, -92.0), (47.3, -89.5), (47.6, -87.0), (46.9, -85.0)), 150, 2),
+    GreatLake("LM", "Lake Michigan", ((41.8, -87.2), (43.0, -87.3), (44.5, -87.0), (45.7, -85.8)), 120, 3),
+    GreatLake("LH", "Lake Huron", ((43.3, -82.1), (44.5, -82.3), (45.3, -83.0), (45.8, -84.2), (45.0, -80.8)), 120, 4),
+    GreatLake("LE", "Lake Erie", ((41.7, -83.2), (42.2, -81.2), (42.8, -79.2)), 100, 5),
+    GreatLake("LO", "Lake Ontario", ((43.3, -79.6), (43.6, -77.9), (43.9, -76.5)), 100, 6),
+)
+
+BUOY_MAX_KM = 100.0
+
+
+@dataclass(slots=True)
+class LakeReport:
+    """Everything ``wx lake`` knows about the water near a place."""
+
+    place: str
+    lake: str | None = None
+    buoy: dict[str, Any] | None = None
+    wave_periods: list[dict[str, str]] = field(default_factory=list)
+    ice_cover_pct: float | None = None
+    ice_date: str | None = None
+    sources: list[str] = field(default_factory=list)
+
+
+def nearest_great_lake(lat: float, lon: float) -> GreatLake | None:
+    """Return the Great Lake closest to the point, if it lies within that lake's reach."""
+
+    best: tuple[float, GreatLake] | None = None
+    for lake in GREAT_LAKES:
+        distance = min(haversine_km(lat, lon, *point) for point in lake.axis)
+        if distance <= lake.reach_km and (best is None or distance < best[0]):
+            best = (distance, lake)
+    return best[1] if best else None
+
+
+def parse_ndbc_latest(text: str) -> list[dict[str, Any]]:
+    """Parse NDBC ``latest_obs.txt`` into station dicts (metric units, ``None`` for MM)."""
+
+    lines = [line for line in text.splitlines() if line.strip()]
+    if not lines or not lines[0].startswith("#"):
+        return []
+    header = lines[0].lstrip("#").split()
+    stations = []
+    for line in lines[1:]:
+        if line.startswith("#"):
+            continue
+        values = dict(zip(header, line.split(), strict=False))
+        try:
+            lat, lon = float(values["LAT"]), float(values["LON"])
+        except (KeyError, ValueError):
+            continue
+        stations.append(
+            {
+                "station": values.get("STN"),
+                "lat": lat,
+                "lon": lon,
+                "water_temp_c": _number(values.get("WTMP")),
+                "wave_height_m": _number(values.get("WVHT")),
+                "wind_speed_ms": _number(values.get("WSPD")),
+                "observed": _ndbc_time(values),
+            }
+        )
+    return stations
+
+
+def nearest_buoy(
+    stations: list[dict[str, Any]], lat: float, lon: float, *, max_km: float = BUOY_MAX_KM
+) -> dict[str, Any] | None:
+    """Closest station reporting water temperature or waves within ``max_km``."""
+
+    best: tuple[float, dict[str, Any]] | None = None
+    for station in stations:
+        if station["water_temp_c"] is None and station["wave_height_m"] is None:
+            continue
+        distance = haversine_km(lat, lon, station["lat"], station["lon"])
+        if distance <= max_km and (best is None or distance < best[0]):
+            best = (distance, station)
+    if best is None:
+        return None
+    return {**best[1], "distance_km": round(best[0], 1)}
+
+
+_GLF_PERIOD = re.compile(r"^\.([A-Z][A-Z ]+?)\.\.\.(.*)$")
+
+
+def parse_open_lakes_forecast(text: str, *, limit: int = 4) -> list[dict[str, str]]:
+    """Pull the first forecast periods (".TONIGHT...NW wind...") from a GLF product."""
+
+    periods: list[dict[str, str]] = []
+    current: dict[str, str] | None = None
+    for raw in text.splitlines():
+        line = raw.strip()
+        match = _GLF_PERIOD.match(line)
+        if match:
+            if current:
+                periods.append(current)
+                if len(periods) >= limit:
+                    return periods
+            current = {"period": match.group(1).title(), "text": match.group(2).strip()}
+        elif current and line and not line.startswith("$$"):
+            current["text"] = f"{current['text']} {line}"
+        elif current:
+            periods.append(current)
+            if len(periods) >= limit:
+                return periods
+            current = None
+    if current:
+        periods.append(current)
+    return periods[:limit]
+
+
+def parse_ice_table(text: str, lake: GreatLake) -> tuple[str, float] | None:
+    """Latest (date, percent) ice cover for ``lake`` from a GLERL daily ice table."""
+
+    latest: tuple[str, float] | None = None
+    for line in text.splitlines():
+        parts = line.split()
+        if len(parts) <= lake.ice_column or not parts[0].isdigit():
+            continue
+        try:
+            year, day_of_year = int(parts[0]), int(parts[1])
+            value = float(parts[lake.ice_column])
+        except ValueError:
+            continue
+        day = date.fromordinal(date(year, 1, 1).toordinal() + day_of_year - 1)
+        latest = (day.isoformat(), value)
+    return latest
+
+
+def ice_season(today: date) -> str:
+    """GLERL ice tables run by winter season, e.g. ``2026_2027``."""
+
+    start = today.year if today.month >= 7 else today.year - 1
+    return f"{start}_{start + 1}"
+
+
+def _number(value: str | None) -> float | None:
+    if value in (None, "MM"):
+        return None
+    try:
+        return float(value)
+    except ValueError:
+        return None
+
+
+def _ndbc_time(values: dict[str, str]) -> str | None:
+    try:
+        return (
+            f"{values['YYYY']}-{values['MM']}-{values['DD']}T{values['hh']}:{values['mm']}:00+00:00"
+        )
+    except KeyError:
+        return None
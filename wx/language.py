@@ -0,0 +1,119 @@
+"""Response language names and a small dependency-free language check."""
+
+from __future__ import annotations
+
+import re
+import unicodedata
+from typing import Any
+
+LANGUAGES = {
+    "en": "English",
+    "es": "Spanish",
+    "fr": "French",
+    "de": "German",
+    "it": "Italian",
+    "pt": "Portuguese",
+    "nl": "Dutch",
+    "ru": "Russian",
+    "uk": "Ukrainian",
+    "el": "Greek",
+    "ar": "Arabic",
+    "he": "Hebrew",
+    "hi": "Hindi",
+    "ja": "Japanese",
+    "ko": "Korean",
+    "zh": "Chinese",
+}
+
+# Frequent function words; enough to tell which Latin-script language a paragraph is in.
+_STOPWORDS = {
+    "en": {"the", "and", "is", "are", "of", "to", "in", "with", "will", "be", "for", "this", "it", "at", "by"},
+    "es": {"el", "la", "los", "las", "de", "y", "en", "con", "que", "por", "para", "una", "es", "del", "se"},
+    "fr": {"le", "la", "les", "de", "des", "et", "en", "avec", "est", "une", "pour", "que", "du", "dans", "sur"},
+    "de": {"der", "die", "das", "und", "mit", "ist", "ein", "eine", "im", "von", "zu", "den", "nicht", "auf", "wird"},
+    "it": {"il", "la", "di", "e", "con", "che", "per", "una", "del", "della", "sono", "nel", "si", "le", "è"},
+    "pt": {"o", "a", "os", "as", "de", "e", "em", "com", "que", "para", "uma", "do", "da", "no", "na"},
+    "nl": {"de", "het", "een", "en", "van", "met", "is", "in", "op", "voor", "niet", "dat", "wordt", "zijn", "te"},
+}
+# Unicode name prefix of the script each non-Latin language is written in.
+_SCRIPTS = {
+    "ru": "CYRILLIC",
+    "uk": "CYRILLIC",
+    "el": "GREEK",
+    "ar": "ARABIC",
+    "he": "HEBREW",
+    "hi": "DEVANAGARI",
+    "ja": ("HIRAGANA", "KATAKANA", "CJK"),
+    "ko": "HANGUL",
+    "zh": "CJK",
+}
+_WORD = re.compile(r"[^\W\d_]+", re.UNICODE)
+# Below this many words the stopword scores are too noisy to second-guess the model.
+MIN_WORDS = 8
+# Another language must score this many times higher before the reply counts as wrong.
+MISMATCH_MARGIN = 2.0
+
+
+def language_name(code: str) -> str:
+    return LANGUAGES.get(code, code)
+
+
+def prose_text(sections: dict[str, Any], bottom_line: str | None = None) -> str:
+    """Join the free-text parts of a response (skipping risk cards and JSON keys)."""
+
+    parts: list[str] = []
+    for value in sections.values():
+        if isinstance(value, str):
+            parts.append(value)
+        elif isinstance(value, list):
+            parts.extend(item for item in value if isinstance(item, str))
+    if bottom_line:
+        parts.append(bottom_line)
+    return "\n".join(parts)
+
+
+def detect_mismatch(text: str, expected: str) -> str | None:
+    """Return the language ``text`` appears to be in when it is clearly not ``expected``.
+
+    ``None`` means the text matches or is too short to judge.
+    """
+
+    letters = [char for char in text if char.isalpha()]
+    if not letters:
+        return None
+    latin_share = sum(1 for char in letters if _script(char) == "LATIN") / len(letters)
+
+    if expected in _SCRIPTS:
+        # Words like place names stay in Latin letters; only flag replies that are mostly Latin.
+        if latin_share < 0.5:
+            return None
+        scores = _stopword_scores(text)
+        return max(scores, key=scores.get) if scores and max(scores.values()) > 0 else "en"
+
+    if latin_share < 0.5:
+        for code, script in _SCRIPTS.items():
+            prefixes = script if isinstance(script, tuple) else (script,)
+            share = sum(1 for char in letters if _script(char) in prefixes) / len(letters)
+            if share >= 0.5:
+                return code
+        return None
+
+    scores = _stopword_scores(text)
+    if not scores:
+        return None
+    best = max(scores, key=scores.get)
+    if best != expected and scores[best] > MISMATCH_MARGIN * scores.get(expected, 0.0):
+        return best
+    return None
+
+
+def _stopword_scores(text: str) -> dict[str, float]:
+    words = [word.lower() for word in _WORD.findall(text)]
+    if len(words) < MIN_WORDS:
+        return {}
+    return {code: sum(1 for word in words if word in stopwords) / len(words) for code, stopwords in _STOPWORDS.items()}
+
+
+def _script(char: str) -> str:
+    name = unicodedata.name(char, "")
+    return name.split(" ", 1)[0]
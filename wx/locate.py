@@ -0,0 +1,101 @@
+"""Find where this machine is, for ``wx here`` and the ``here`` place keyword."""
+
+from __future__ import annotations
+
+import re
+import shutil
+import subprocess
+import sys
+from dataclasses import dataclass
+
+from .fetchers import get_ip_location
+
+HERE = "here"
+LOCATE_TIMEOUT = 10.0
+# geoclue's demo agent prints "Latitude:  47.6062°"; CoreLocationCLI is told to print "lat lon".
+_GEOCLUE_VALUE = re.compile(r"^\s*(Latitude|Longitude):\s*(-?\d+(?:\.\d+)?)", re.MULTILINE)
+
+
+@dataclass(slots=True)
+class Location:
+    """A detected position and how it was found."""
+
+    lat: float
+    lon: float
+    label: str
+    source: str  # corelocation | geoclue | ip
+
+    @property
+    def place(self) -> str:
+        """``lat,lon`` string accepted wherever a place is; IP lookups are city-level anyway."""
+        return f"{self.lat:.2f},{self.lon:.2f}"
+
+
+def is_here(place: str | None) -> bool:
+    return bool(place) and place.strip().lower() == HERE
+
+
+def current_location(*, offline: bool = False, use_os: bool = True, use_ip: bool = True) -> Location | None:
+    """Best available position: the OS location service when a helper is installed, else IP.
+
+    macOS needs ``CoreLocationCLI`` and Linux the geoclue ``where-am-i`` demo agent; both may
+    prompt for permission the first time. Nothing is looked up when ``offline``, and the IP
+    lookup (which sends this machine's address to ipapi.co) is skipped unless ``use_ip``.
+    """
+
+    if offline:
+        return None
+    if use_os:
+        found = _os_location()
+        if found is not None:
+            return found
+    if not use_ip:
+        return None
+    data = get_ip_location(offline=offline)
+    if data is None:
+        return None
+    return Location(lat=data["lat"], lon=data["lon"], label=data["label"], source="ip")
+
+
+def _os_location() -> Location | None:
+    if sys.platform == "darwin" and shutil.which("CoreLocationCLI"):
+        output = _run(["CoreLocationCLI", "-once", "-format", "%latitude %longitude"])
+        parts = (output or "").split()
+        if len(parts) == 2:
+            try:
+                lat, lon = float(parts[0]), float(parts[1])
+            except ValueError:
+                return None
+            return Location(lat=lat, lon=lon, label=f"{lat:.4f},{lon:.4f}", source="corelocation")
+        return None
+    agent = shutil.which("where-am-i") or _geoclue_demo()
+    if agent:
+        return parse_geoclue(_run([agent, "-t", str(int(LOCATE_TIMEOUT))]) or "")
+    return None
+
+
+def parse_geoclue(output: str) -> Location | None:
+    """Position from geoclue ``where-am-i`` output."""
+
+    values = {name.lower(): float(value) for name, value in _GEOCLUE_VALUE.findall(output)}
+    if "latitude" not in values or "longitude" not in values:
+        return None
+    lat, lon = values["latitude"], values["longitude"]
+    return Location(lat=lat, lon=lon, label=f"{lat:.4f},{lon:.4f}", source="geoclue")
+
+
+def _geoclue_demo() -> str | None:
+    for path in ("/usr/libexec/geoclue-2.0/demos/where-am-i", "/usr/lib/geoclue-2.0/demos/where-am-i"):
+        if shutil.which(path):
+            return path
+    return None
+
+
+def _run(command: list[str]) -> str | None:
+    try:
+        completed = subprocess.run(
+            command, capture_output=True, text=True, timeout=LOCATE_TIMEOUT + 2, check=False
+        )
+    except (OSError, subprocess.SubprocessError):
+        return None
+    return completed.stdout if completed.returncode == 0 else None
@@ -0,0 +1,105 @@
+"""Marine conditions: waves, swell, sea temperature and tide turns from the Open-Meteo Marine API."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+MARINE_FOCUS = ("marine", "sailing", "boating", "surf", "surfing", "fishing")
+MARINE_HOURS = 48
+# Rows shown in the wave table; the full hourly series stays in the JSON.
+TABLE_STEP_HOURS = 3
+# Small-craft guidance: significant wave height (m) above which conditions get rough.
+ROUGH_WAVE_M = 2.0
+
+
+@dataclass(slots=True)
+class MarineReport:
+    """Everything ``wx marine`` knows about the sea off a place."""
+
+    place: str
+    tz: str | None = None
+    now: dict[str, Any] | None = None
+    periods: list[dict[str, Any]] = field(default_factory=list)
+    peak: dict[str, Any] | None = None
+    tides: list[dict[str, Any]] = field(default_factory=list)
+    sources: list[str] = field(default_factory=list)
+
+
+def is_marine_focus(focus: str | None) -> bool:
+    return bool(focus) and focus.strip().lower() in MARINE_FOCUS
+
+
+def marine_rows(hourly: dict[str, list[Any]]) -> list[dict[str, Any]]:
+    """One metric row per forecast hour."""
+
+    def at(key: str, idx: int) -> Any:
+        series = hourly.get(key) or []
+        return series[idx] if idx < len(series) else None
+
+    rows = []
+    for idx, stamp in enumerate(hourly.get("time") or []):
+        rows.append(
+            {
+                "time_iso": _utc(stamp).isoformat(),
+                "wave_height_m": at("wave_height", idx),
+                "wave_period_s": at("wave_period", idx),
+                "wave_dir": at("wave_direction", idx),
+                "wind_wave_height_m": at("wind_wave_height", idx),
+                "swell_height_m": at("swell_wave_height", idx),
+                "swell_period_s": at("swell_wave_period", idx),
+                "swell_dir": at("swell_wave_direction", idx),
+                "water_temp_c": at("sea_surface_temperature", idx),
+                "sea_level_m": at("sea_level_height_msl", idx),
+            }
+        )
+    return rows
+
+
+def tide_turns(rows: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """High and low water from the modeled hourly sea level (local extremes)."""
+
+    levels = [(row["time_iso"], row["sea_level_m"]) for row in rows if isinstance(row.get("sea_level_m"), (int, float))]
+    turns = []
+    for idx in range(1, len(levels) - 1):
+        before, (stamp, level), after = levels[idx - 1][1], levels[idx], levels[idx + 1][1]
+        if level > before and level >= after:
+            turns.append({"time_iso": stamp, "type": "high", "height_m": round(level, 2)})
+        elif level < before and level <= after:
+            turns.append({"time_iso": stamp, "type": "low", "height_m": round(level, 2)})
+    return turns
+
+
+def build_marine_report(place: str, hourly: dict[str, list[Any]], *, now: datetime | None = None) -> MarineReport:
+    """Current sea state, 3-hourly periods, the peak waves and tide turns from now on."""
+
+    now = (now or datetime.now(UTC)).replace(minute=0, second=0, microsecond=0)
+    rows = [row for row in marine_rows(hourly) if datetime.fromisoformat(row["time_iso"]) >= now]
+    report = MarineReport(place=place, sources=["Open-Meteo Marine"])
+    if not rows:
+        return report
+    report.now = rows[0]
+    report.periods = rows[::TABLE_STEP_HOURS]
+    waves = [row for row in rows if isinstance(row["wave_height_m"], (int, float))]
+    if waves:
+        report.peak = max(waves, key=lambda row: row["wave_height_m"])
+    report.tides = tide_turns(rows)
+    return report
+
+
+def marine_pack(report: MarineReport) -> dict[str, Any]:
+    """Compact metric summary for the Feature Pack when the story's focus is marine."""
+
+    return {
+        "now": report.now,
+        "peak_waves": report.peak,
+        "periods": report.periods[:8],
+        "tides": report.tides[:6],
+        "rough_wave_threshold_m": ROUGH_WAVE_M,
+    }
+
+
+def _utc(stamp: Any) -> datetime:
+    moment = datetime.fromisoformat(str(stamp))
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
@@ -0,0 +1,157 @@
+"""``wx models``: GFS, ECMWF and ICON side by side, and where they part ways.
+
+Open-Meteo serves each model from the same endpoint via ``models=``. The hours are grouped into
+six-hour blocks; a block diverges when the models' mean temperatures are 3 °C or more apart, or
+when one model has it wet while another keeps it dry.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .clock import clock_label, zone
+from .units import convert, display_unit
+
+# Open-Meteo model name and the label shown for it.
+MODELS = (("gfs_seamless", "GFS"), ("ecmwf_ifs025", "ECMWF"), ("icon_seamless", "ICON"))
+MODELS_HOURS = 48
+MAX_MODELS_HOURS = 168
+BLOCK_HOURS = 6
+TEMP_DIVERGENCE_C = 3.0
+# Over a block, a model is wet with at least WET_MM of precipitation and dry under DRY_MM.
+WET_MM = 1.0
+DRY_MM = 0.2
+
+
+@dataclass(slots=True)
+class ModelRun:
+    """One model's hourly temperature (°C) and precipitation (mm) over the report's hours."""
+
+    model: str
+    label: str
+    temperature: list[float | None] = field(default_factory=list)
+    precipitation: list[float | None] = field(default_factory=list)
+    high_c: float | None = None
+    low_c: float | None = None
+    precip_mm: float = 0.0
+
+
+@dataclass(slots=True)
+class ModelsReport:
+    """Each model's run for a place and the six-hour blocks where they disagree."""
+
+    place: str
+    tz: str | None = None
+    times: list[str] = field(default_factory=list)
+    runs: list[ModelRun] = field(default_factory=list)
+    divergences: list[dict[str, Any]] = field(default_factory=list)
+    summary: str | None = None
+    sources: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def model_runs(forecasts: dict[str, Any], models: tuple[tuple[str, str], ...] = MODELS) -> list[ModelRun]:
+    """``fetch_model_forecasts`` output as ModelRuns, in ``models`` order, skipping missing ones."""
+
+    runs = []
+    for model, label in models:
+        series = forecasts.get(model)
+        if not isinstance(series, dict):
+            continue
+        temps = list(series.get("temperature_2m") or [])
+        precip = list(series.get("precipitation") or [])
+        known = [value for value in temps if value is not None]
+        runs.append(
+            ModelRun(
+                model=model,
+                label=label,
+                temperature=temps,
+                precipitation=precip,
+                high_c=max(known) if known else None,
+                low_c=min(known) if known else None,
+                precip_mm=round(sum(value for value in precip if value is not None), 1),
+            )
+        )
+    return runs
+
+
+def find_divergences(times: list[str], runs: list[ModelRun]) -> list[dict[str, Any]]:
+    """Six-hour blocks where mean temperatures differ by TEMP_DIVERGENCE_C or wet meets dry."""
+
+    blocks = []
+    for start in range(0, len(times), BLOCK_HOURS):
+        hours = range(start, min(start + BLOCK_HOURS, len(times)))
+        means: dict[str, float] = {}
+        totals: dict[str, float] = {}
+        for run in runs:
+            temps = [run.temperature[idx] for idx in hours if idx < len(run.temperature)]
+            temps = [value for value in temps if value is not None]
+            if temps:
+                means[run.label] = sum(temps) / len(temps)
+            precip = [run.precipitation[idx] for idx in hours if idx < len(run.precipitation)]
+            totals[run.label] = round(sum(value for value in precip if value is not None), 1)
+        if len(means) < 2:
+            continue
+        warmest, coolest = max(means, key=means.get), min(means, key=means.get)
+        wettest, driest = max(totals, key=totals.get), min(totals, key=totals.get)
+        reasons = []
+        if means[warmest] - means[coolest] >= TEMP_DIVERGENCE_C:
+            reasons.append("temperature")
+        if totals[wettest] >= WET_MM and totals[driest] < DRY_MM:
+            reasons.append("precipitation")
+        if reasons:
+            blocks.append(
+                {
+                    "start_iso": _utc(times[start]).isoformat(),
+                    "end_iso": (_utc(times[hours[-1]]) + timedelta(hours=1)).isoformat(),
+                    "first_hour": start,
+                    "hours": len(hours),
+                    "reasons": reasons,
+                    "temp_spread_c": round(means[warmest] - means[coolest], 1),
+                    "warmest": warmest,
+                    "coolest": coolest,
+                    "precip_mm": totals,
+                    "wettest": wettest,
+                    "driest": driest,
+                }
+            )
+    return blocks
+
+
+def describe_divergence(block: dict[str, Any], *, units: str, tz_name: str | None) -> str:
+    """``Sat 6 PM–12 AM PDT: ECMWF 7°F warmer than ICON; GFS wet (0.30 in), ICON dry.``"""
+
+    tz = zone(tz_name)
+    start, end = datetime.fromisoformat(block["start_iso"]), datetime.fromisoformat(block["end_iso"])
+    text = f"{clock_label(start, tz).rsplit(' ', 1)[0]}–{clock_label(end, tz, day=False)}:"
+    parts = []
+    if "temperature" in block["reasons"]:
+        spread = block["temp_spread_c"] * (9 / 5 if units == "imperial" else 1)
+        parts.append(f"{block['warmest']} {spread:.0f}{display_unit('temp', units)} warmer than {block['coolest']}")
+    if "precipitation" in block["reasons"]:
+        wet = convert(block["precip_mm"][block["wettest"]], "depth", units)
+        amount = f"{wet:.2f} in" if units == "imperial" else f"{wet:.1f} mm"
+        parts.append(f"{block['wettest']} wet ({amount}), {block['driest']} dry")
+    return f"{text} {'; '.join(parts)}."
+
+
+def summarize_models(report: ModelsReport, *, units: str) -> str:
+    """One line: the models agree, or how many blocks they diverge in and the largest split."""
+
+    hours = len(report.times)
+    if not report.divergences:
+        return f"{', '.join(run.label for run in report.runs)} agree over the next {hours} hours."
+    count = len(report.divergences)
+    largest = max(report.divergences, key=lambda block: (len(block["reasons"]), block["temp_spread_c"]))
+    noun = "period" if count == 1 else "periods"
+    return (
+        f"The models diverge in {count} six-hour {noun} over the next {hours} hours; the largest: "
+        + describe_divergence(largest, units=units, tz_name=report.tz)
+    )
+
+
+def _utc(stamp: str) -> datetime:
+    moment = datetime.fromisoformat(str(stamp))
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
@@ -0,0 +1,151 @@
+"""Climatological normals lookup with a small on-disk cache."""
+
+from __future__ import annotations
+
+import json
+import os
+import tempfile
+from concurrent.futures import ThreadPoolExecutor
+from datetime import UTC, date, datetime
+from pathlib import Path
+from typing import Any
+
+from .clock import zone
+from .fetchers import fetch_monthly_normals
+from .units import display_unit
+
+# Day-of-year (non-leap) at the middle of each month, used as interpolation anchors.
+_MID_MONTH_DOY = (16, 45, 75, 105, 136, 166, 197, 228, 258, 289, 319, 350)
+# Departures (°C) at or beyond this are worth a sentence in the forecast story.
+NOTABLE_DEPARTURE_C = 3.0
+
+
+def interpolate_daily_normal(monthly: list[float], day: date) -> float:
+    """Linearly interpolate a monthly climatology to a specific calendar day."""
+
+    doy = min(day.timetuple().tm_yday, 365)
+    for idx, anchor in enumerate(_MID_MONTH_DOY):
+        if doy < anchor:
+            prev_idx = idx - 1
+            prev_anchor = _MID_MONTH_DOY[prev_idx] - (365 if prev_idx < 0 else 0)
+            break
+    else:
+        idx, prev_idx = 0, 11
+        anchor, prev_anchor = _MID_MONTH_DOY[0] + 365, _MID_MONTH_DOY[11]
+    weight = (doy - prev_anchor) / (anchor - prev_anchor)
+    return monthly[prev_idx] + (monthly[idx] - monthly[prev_idx]) * weight
+
+
+class NormalsCache:
+    """JSON cache of monthly normals keyed by rounded coordinates."""
+
+    def __init__(self, path: Path) -> None:
+        self.path = path
+        self._data: dict[str, dict[str, list[float]]] | None = None
+
+    @staticmethod
+    def key(lat: float, lon: float) -> str:
+        return f"{lat:.1f},{lon:.1f}"
+
+    def get(self, lat: float, lon: float) -> dict[str, list[float]] | None:
+        return self._load().get(self.key(lat, lon))
+
+    def put(self, lat: float, lon: float, normals: dict[str, list[float]]) -> None:
+        self._load()[self.key(lat, lon)] = normals
+
+    def save(self) -> None:
+        if self._data is None:
+            return
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            fd, temp_path = tempfile.mkstemp(dir=self.path.parent, prefix=".wx_temp_", suffix=".json")
+            with os.fdopen(fd, "w") as handle:
+                handle.write(json.dumps(self._data, ensure_ascii=True))
+            os.chmod(temp_path, 0o600)
+            os.replace(temp_path, self.path)
+        except OSError:
+            # Cache writes are best-effort.
+            pass
+
+    def _load(self) -> dict[str, dict[str, list[float]]]:
+        if self._data is None:
+            try:
+                loaded = json.loads(self.path.read_text())
+                self._data = loaded if isinstance(loaded, dict) else {}
+            except (OSError, json.JSONDecodeError):
+                self._data = {}
+        return self._data
+
+
+def ensure_normals(
+    cache: NormalsCache, points: list[tuple[float, float]], *, offline: bool = False
+) -> None:
+    """Fetch normals for any points missing from the cache, in parallel."""
+
+    missing = [point for point in points if cache.get(*point) is None]
+    if not missing or offline:
+        return
+    with ThreadPoolExecutor(max_workers=8) as executor:
+        fetched = list(executor.map(lambda point: fetch_monthly_normals(*point), missing))
+    for point, normals in zip(missing, fetched, strict=True):
+        if normals is not None:
+            cache.put(*point, normals)
+
+
+def normal_daily_mean(normals: dict[str, list[float]], day: date) -> float:
+    """Normal daily mean temperature (°C) for ``day`` from monthly max/min normals."""
+
+    tmax = interpolate_daily_normal(normals["tmax"], day)
+    tmin = interpolate_daily_normal(normals["tmin"], day)
+    return (tmax + tmin) / 2
+
+
+def normals_pack(
+    normals: dict[str, list[float]],
+    hourly: dict[str, list[Any]] | None,
+    day: date,
+    tz_name: str | None = None,
+    units: str = "imperial",
+) -> dict[str, Any] | None:
+    """Feature Pack ``normals`` block: ``day``'s forecast high/low against the climate normal."""
+
+    tz = zone(tz_name)
+    temps = []
+    for stamp, value in zip((hourly or {}).get("time") or [], (hourly or {}).get("temperature_2m") or [], strict=False):
+        moment = datetime.fromisoformat(str(stamp))
+        moment = moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+        if moment.astimezone(tz).date() == day and isinstance(value, (int, float)):
+            temps.append(float(value))
+    if not temps:
+        return None
+    normal_high = round(interpolate_daily_normal(normals["tmax"], day), 1)
+    normal_low = round(interpolate_daily_normal(normals["tmin"], day), 1)
+    pack: dict[str, Any] = {
+        "date": day.isoformat(),
+        "high_c": round(max(temps), 1),
+        "low_c": round(min(temps), 1),
+        "normal_high_c": normal_high,
+        "normal_low_c": normal_low,
+        "high_departure_c": round(max(temps) - normal_high, 1),
+        "low_departure_c": round(min(temps) - normal_low, 1),
+    }
+    pack["notable"] = max(abs(pack["high_departure_c"]), abs(pack["low_departure_c"])) >= NOTABLE_DEPARTURE_C
+    pack["label"] = describe_departures(pack, units)
+    return pack
+
+
+def describe_departures(pack: dict[str, Any], units: str = "imperial") -> str:
+    """``High 12°F above normal (66°F), low near normal (48°F) for Oct 16``."""
+
+    unit = display_unit("temp", units)
+
+    def part(name: str, key: str) -> str:
+        normal = pack[f"normal_{key}_c"] * 9 / 5 + 32 if units == "imperial" else pack[f"normal_{key}_c"]
+        departure = pack[f"{key}_departure_c"] * (9 / 5 if units == "imperial" else 1)
+        if round(departure) == 0:
+            return f"{name} near normal ({normal:.0f}{unit})"
+        side = "above" if departure > 0 else "below"
+        return f"{name} {abs(departure):.0f}{unit} {side} normal ({normal:.0f}{unit})"
+
+    day = date.fromisoformat(pack["date"])
+    return f"{part('High', 'high')}, {part('low', 'low')} for {day:%b} {day.day}"
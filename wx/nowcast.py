@@ -0,0 +1,111 @@
+"""``wx rain``: when precipitation starts or stops over the next hour or two, in 15-minute steps."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .units import convert
+
+STEP_MINUTES = 15
+NOWCAST_MINUTES = 120
+MAX_NOWCAST_MINUTES = 240
+# AMS rain-rate bands in mm/h; below WET_RATE counts as dry.
+WET_RATE = 0.1
+INTENSITY_BANDS = ((2.5, "light"), (7.6, "moderate"))
+
+
+@dataclass(slots=True)
+class RainReport:
+    """Precipitation in 15-minute steps from now, with a one-line start/stop summary."""
+
+    place: str
+    tz: str | None = None
+    steps: list[dict[str, Any]] = field(default_factory=list)
+    summary: str | None = None
+    sources: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def intensity(rate_mmh: float) -> str | None:
+    if rate_mmh < WET_RATE:
+        return None
+    return next((name for limit, name in INTENSITY_BANDS if rate_mmh < limit), "heavy")
+
+
+def nowcast_steps(
+    minutely: dict[str, list[Any]], *, minutes: int = NOWCAST_MINUTES, now: datetime | None = None
+) -> list[dict[str, Any]]:
+    """Steps still to come; Open-Meteo stamps each 15-minute total at the end of its interval."""
+
+    now = now or datetime.now(UTC)
+    steps = []
+    series = zip(
+        minutely.get("time") or [],
+        minutely.get("precipitation") or [],
+        minutely.get("snowfall") or [],
+        strict=False,
+    )
+    for stamp, precip, snow in series:
+        end = datetime.fromisoformat(str(stamp))
+        end = end if end.tzinfo else end.replace(tzinfo=UTC)
+        if end <= now or not isinstance(precip, (int, float)):
+            continue
+        rate = round(float(precip) * 60 / STEP_MINUTES, 1)
+        steps.append(
+            {
+                "start_iso": (end - timedelta(minutes=STEP_MINUTES)).isoformat(),
+                "precip_mm": round(float(precip), 2),
+                "rate_mmh": rate,
+                "snow": isinstance(snow, (int, float)) and snow > 0,
+                "intensity": intensity(rate),
+            }
+        )
+    return steps[: max(1, minutes // STEP_MINUTES)]
+
+
+def summarize_nowcast(
+    steps: list[dict[str, Any]], *, units: str = "metric", now: datetime | None = None
+) -> str:
+    """``Rain starting in 23 minutes, lasting about 45 minutes.`` and the like."""
+
+    if not steps:
+        return "No minute-by-minute forecast is available."
+    now = now or datetime.now(UTC)
+    span = _duration(len(steps) * STEP_MINUTES)
+    wet = [step["intensity"] is not None for step in steps]
+    kind = "Snow" if any(step["snow"] for step in steps if step["intensity"]) else "Rain"
+    if not any(wet):
+        return f"No rain or snow expected in the next {span}."
+    peak = max(steps, key=lambda step: step["rate_mmh"])
+    peak_note = f", {peak['intensity']} at its heaviest ({rate_label(peak['rate_mmh'], units)})"
+    if wet[0]:
+        stop = wet.index(False) if False in wet else None
+        if stop is None:
+            return f"{kind} continuing for at least the next {span}{peak_note}."
+        return f"{kind} stopping in {_duration(_minutes_until(steps[stop]['start_iso'], now))}{peak_note}."
+    start = wet.index(True)
+    begins = max(1, _minutes_until(steps[start]["start_iso"], now))
+    stop = wet.index(False, start) if False in wet[start:] else None
+    if stop is None:
+        return f"{kind} starting in {_duration(begins)} and continuing past {span}{peak_note}."
+    lasting = (stop - start) * STEP_MINUTES
+    return f"{kind} starting in {_duration(begins)}, lasting about {_duration(lasting)}{peak_note}."
+
+
+def rate_label(rate_mmh: float, units: str) -> str:
+    value = convert(rate_mmh, "depth", units)
+    return f"{value:.2f} in/h" if units == "imperial" else f"{value:.1f} mm/h"
+
+
+def _minutes_until(iso: str, now: datetime) -> int:
+    return max(0, round((datetime.fromisoformat(iso) - now).total_seconds() / 60))
+
+
+def _duration(minutes: int) -> str:
+    if minutes < 60:
+        return f"{minutes} minute{'s' if minutes != 1 else ''}"
+    hours, rest = divmod(minutes, 60)
+    text = f"{hours} hour{'s' if hours != 1 else ''}"
+    return f"{text} {rest} minutes" if rest else text
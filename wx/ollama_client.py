@@ -0,0 +1,89 @@
+"""Thin Ollama HTTP client so wx can run against a local model."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterable, Mapping
+from dataclasses import dataclass
+from typing import Any
+
+import httpx
+
+from .httpclient import with_retries
+
+# Local models on CPU can take a while to produce a full JSON response.
+DEFAULT_TIMEOUT = 120.0
+
+
+class OllamaError(RuntimeError):
+    """Raised when the Ollama server cannot fulfil a request."""
+
+
+@dataclass(frozen=True)
+class OllamaConfig:
+    """Configuration required to talk to an Ollama server."""
+
+    base_url: str
+    model: str
+    temperature: float
+    max_tokens: int
+    timeout: float = DEFAULT_TIMEOUT
+
+    @property
+    def chat_url(self) -> str:
+        return f"{self.base_url.rstrip('/')}/api/chat"
+
+
+@dataclass(slots=True)
+class OllamaResponse:
+    """Structured data returned from Ollama."""
+
+    text: str
+    model: str
+    raw: dict[str, Any]
+    usage: dict[str, Any] | None
+
+
+def chat(messages: Iterable[Mapping[str, str]], *, config: OllamaConfig) -> OllamaResponse:
+    """Call Ollama's non-streaming chat endpoint and return the text payload."""
+
+    payload = {
+        "model": config.model,
+        "messages": list(messages),
+        "stream": False,
+        "options": {"temperature": config.temperature, "num_predict": config.max_tokens},
+    }
+    try:
+        response = with_retries(
+            lambda: httpx.post(config.chat_url, json=payload, timeout=config.timeout),
+            retry_read_timeouts=False,
+        )
+        response.raise_for_status()
+        data = response.json()
+    except httpx.HTTPStatusError as exc:
+        raise OllamaError(f"Ollama HTTP {exc.response.status_code}") from exc
+    except (httpx.TimeoutException, httpx.TransportError) as exc:
+        raise OllamaError(f"Ollama unreachable at {config.base_url}") from exc
+    except json.JSONDecodeError as exc:
+        raise OllamaError("Ollama returned invalid JSON") from exc
+
+    message = data.get("message") if isinstance(data, Mapping) else None
+    text = message.get("content") if isinstance(message, Mapping) else None
+    if not isinstance(text, str) or not text.strip():
+        raise OllamaError("Ollama response missing content")
+
+    usage = None
+    if "prompt_eval_count" in data or "eval_count" in data:
+        usage = {
+            "prompt_tokens": data.get("prompt_eval_count"),
+            "completion_tokens": data.get("eval_count"),
+        }
+    return OllamaResponse(text=text.strip(), model=data.get("model", config.model), raw=data, usage=usage)
+
+
+__all__ = [
+    "OllamaConfig",
+    "OllamaError",
+    "OllamaResponse",
+    "chat",
+]
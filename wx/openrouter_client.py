@@ -3,17 +3,24 @@
 from __future__ import annotations
 
 import json
-import time
-from collections.abc import Iterable, Mapping
+from collections.abc import Callable, Iterable, Mapping
 from dataclasses import dataclass
 from typing import Any
 
 import httpx
 
+from .httpclient import with_retries
+
+# Failures that are often specific to one model (rate limits, outages); retries of a single
+# request follow wx.httpclient.
 RETRYABLE_STATUS_CODES = {429, 500, 502, 503, 504}
 DEFAULT_TIMEOUT = 30.0
 
 
+class _StreamInterrupted(Exception):
+    """The connection dropped after text had streamed, so the request is not retried."""
+
+
 class OpenRouterError(RuntimeError):
     """Raised when OpenRouter cannot fulfil a request."""
 
@@ -39,8 +46,9 @@ class OpenRouterConfig:
     temperature: float
     max_tokens: int
     timeout: float = DEFAULT_TIMEOUT
-    retries: int = 3
-    backoff_factor: float = 0.75
+    # The client also talks to OpenAI's compatible endpoint; label names it in errors.
+    label: str = "OpenRouter"
+    json_mode: bool = False
 
     @property
     def chat_url(self) -> str:
@@ -82,75 +90,156 @@ def chat_completion(
         "temperature": config.temperature,
         "max_tokens": config.max_tokens,
     }
+    if config.json_mode:
+        payload["response_format"] = {"type": "json_object"}
 
-    last_error: Exception | None = None
-    last_status: int | None = None
     attempts = 0
-    backoff = config.backoff_factor
-
-    for attempt in range(1, config.retries + 1):
-        attempts = attempt
-        try:
-            response = httpx.post(
-                config.chat_url,
-                headers=headers,
-                json=payload,
-                timeout=config.timeout,
-            )
-            response.raise_for_status()
-        except httpx.HTTPStatusError as exc:
-            last_error = exc
-            last_status = exc.response.status_code
-            if last_status in RETRYABLE_STATUS_CODES and attempt < config.retries:
-                time.sleep(backoff)
-                backoff *= 2
-                continue
-            raise OpenRouterError(
-                f"OpenRouter HTTP {last_status}",
-                status_code=last_status,
-                payload=_safe_json(exc.response),
-            ) from exc
-        except (httpx.TimeoutException, httpx.TransportError) as exc:
-            last_error = exc
-            if attempt < config.retries:
-                time.sleep(backoff)
-                backoff *= 2
-                continue
-            raise OpenRouterError("OpenRouter request failed", status_code=None) from exc
-
-        try:
-            data = response.json()
-        except json.JSONDecodeError as exc:
-            last_error = exc
-            if attempt < config.retries:
-                time.sleep(backoff)
-                backoff *= 2
-                continue
-            raise OpenRouterError(
-                "OpenRouter returned invalid JSON", status_code=response.status_code
-            ) from exc
-
-        text = _extract_first_message(data)
-        if not text:
-            raise OpenRouterError(
-                "OpenRouter response missing content",
-                status_code=response.status_code,
-                payload=data,
-            )
-
-        return OpenRouterResponse(
-            text=text,
-            model=data.get("model", config.model),
-            raw=data,
-            usage=data.get("usage"),
-            headers=response.headers,
-            attempts=attempts,
+
+    def send() -> httpx.Response:
+        nonlocal attempts
+        attempts += 1
+        return httpx.post(config.chat_url, headers=headers, json=payload, timeout=config.timeout)
+
+    try:
+        response = with_retries(send, retry_read_timeouts=False)
+        response.raise_for_status()
+        data = response.json()
+    except httpx.HTTPStatusError as exc:
+        status = exc.response.status_code
+        raise OpenRouterError(
+            f"{config.label} HTTP {status}", status_code=status, payload=_safe_json(exc.response)
+        ) from exc
+    except (httpx.TimeoutException, httpx.TransportError) as exc:
+        raise OpenRouterError(f"{config.label} request failed", status_code=None) from exc
+    except json.JSONDecodeError as exc:
+        raise OpenRouterError(f"{config.label} returned invalid JSON", status_code=response.status_code) from exc
+
+    text = _extract_first_message(data)
+    if not text:
+        raise OpenRouterError(
+            f"{config.label} response missing content",
+            status_code=response.status_code,
+            payload=data,
         )
 
-    # Should not reach here; raise informative fallback error.
-    raise OpenRouterError(
-        "OpenRouter request exhausted retries", status_code=last_status
-    ) from last_error
+    return OpenRouterResponse(
+        text=text,
+        model=data.get("model", config.model),
+        raw=data,
+        usage=data.get("usage"),
+        headers=response.headers,
+        attempts=attempts,
+    )
+
+
+def stream_chat_completion(
+    messages: Iterable[Mapping[str, str]],
+    *,
+    config: OpenRouterConfig,
+    on_text: Callable[[str], None],
+    extra_headers: Mapping[str, str] | None = None,
+) -> OpenRouterResponse:
+    """Like :func:`chat_completion` but reads server-sent events as they arrive.
+
+    ``on_text`` receives the accumulated text after every delta. Failures before the first
+    delta are retried under the wx.httpclient policy; once text has streamed the error is raised.
+    """
+
+    headers = {
+        "Authorization": f"Bearer {config.api_key}",
+        "Content-Type": "application/json",
+        "HTTP-Referer": "https://github.com/Exvin2/claudex-cli",
+        "X-Title": "wx CLI",
+    }
+    if extra_headers:
+        headers.update(extra_headers)
+
+    payload = {
+        "model": config.model,
+        "messages": list(messages),
+        "temperature": config.temperature,
+        "max_tokens": config.max_tokens,
+        "stream": True,
+    }
+    if config.json_mode:
+        payload["response_format"] = {"type": "json_object"}
+
+    parts: list[str] = []
+    model = config.model
+    usage: dict[str, Any] | None = None
+    attempts = 0
+
+    def send() -> httpx.Response:
+        nonlocal attempts, model, usage
+        attempts += 1
+        parts.clear()
+        with httpx.stream("POST", config.chat_url, headers=headers, json=payload, timeout=config.timeout) as response:
+            if not response.is_success:
+                return response
+            try:
+                for line in response.iter_lines():
+                    event = _parse_sse_line(line)
+                    if event is None:
+                        continue
+                    if event == "[DONE]":
+                        break
+                    model = event.get("model") or model
+                    usage = event.get("usage") or usage
+                    delta = _extract_delta(event)
+                    if delta:
+                        parts.append(delta)
+                        on_text("".join(parts))
+            except httpx.TransportError as exc:
+                if parts:
+                    raise _StreamInterrupted from exc
+                raise
+        return response
+
+    try:
+        response = with_retries(send, retry_read_timeouts=False)
+        response.raise_for_status()
+    except httpx.HTTPStatusError as exc:
+        status = exc.response.status_code
+        raise OpenRouterError(f"{config.label} HTTP {status}", status_code=status) from exc
+    except (httpx.TransportError, _StreamInterrupted) as exc:
+        raise OpenRouterError(f"{config.label} stream interrupted", status_code=None) from exc
+
+    text = "".join(parts).strip()
+    if not text:
+        raise OpenRouterError(f"{config.label} stream missing content", status_code=response.status_code)
+    return OpenRouterResponse(
+        text=text,
+        model=model,
+        raw={"streamed": True},
+        usage=usage,
+        headers=response.headers,
+        attempts=attempts,
+    )
+
+
+def _parse_sse_line(line: str) -> dict[str, Any] | str | None:
+    if not line.startswith("data:"):
+        # Blank separators and ": keep-alive" comments carry no data.
+        return None
+    data = line[len("data:"):].strip()
+    if data == "[DONE]":
+        return data
+    try:
+        event = json.loads(data)
+    except json.JSONDecodeError:
+        return None
+    return event if isinstance(event, dict) else None
+
+
+def _extract_delta(event: Mapping[str, Any]) -> str | None:
+    choices = event.get("choices")
+    if not isinstance(choices, list) or not choices or not isinstance(choices[0], Mapping):
+        return None
+    delta = choices[0].get("delta")
+    if not isinstance(delta, Mapping):
+        return None
+    content = delta.get("content")
+    return content if isinstance(content, str) else None
 
 
 def _extract_first_message(data: Mapping[str, Any]) -> str | None:
@@ -182,4 +271,5 @@ __all__ = [
     "OpenRouterError",
     "OpenRouterResponse",
     "chat_completion",
+    "stream_chat_completion",
 ]
@@ -3,29 +3,145 @@
 from __future__ import annotations
 
 import json
+import math
+import re
 import time
-from collections.abc import Iterable
+import zlib
+from collections.abc import Callable, Iterable, Sequence
 from concurrent.futures import ThreadPoolExecutor, as_completed
-from dataclasses import asdict, dataclass
-from datetime import UTC, datetime, timedelta
+from dataclasses import asdict, dataclass, field
+from datetime import UTC, date, datetime, timedelta
 from typing import Any
 
 from dateutil import parser as date_parser
 
-from .config import REGIONAL_SAMPLES, Settings
+from .airquality import air_quality_pack, wants_air_quality
+from .astro import SUN_MAX_DAYS, SunReport, astro_day, astro_pack, moon_phase
+from .attribution import feature_pack_sources
+from .aviation import (
+    PIREP_HOURS,
+    AviationReport,
+    advisories_at,
+    decode_metar,
+    decode_taf,
+    is_aviation_focus,
+    is_icao,
+    nearby_pireps,
+)
+from .cache import CachingProvider, FetchCache, cache_path
+from .clock import localize_sections, zone
+from .compare import COMPARE_HOURS, CompareResult, comparison_pack
+from .config import (
+    DEFAULT_AI_MAX_PERIODS,
+    DEFAULT_WORLD_REGIONS,
+    REGION_NAMES,
+    REGIONAL_SAMPLES,
+    Settings,
+)
+from .ensemble import ensemble_pack
+from .favorites import Favorite
+from .feedback import story_info
 from .fetchers import (
+    SEVERITY_RANK,
+    WMO_WEATHER_CODES,
     Alert,
     FetchResult,
     Observation,
+    fetch_airsigmets,
+    fetch_area_alerts,
+    fetch_ensemble_members,
     fetch_eu_alerts,
+    fetch_gairmets,
+    fetch_great_lakes_ice,
+    fetch_metars,
+    fetch_model_forecasts,
+    fetch_ndbc_latest,
+    fetch_nhc_active_storms,
+    fetch_nws_hourly,
+    fetch_open_lakes_forecast,
     fetch_openmeteo_points,
+    fetch_pireps,
+    fetch_radar_maps,
+    fetch_radar_tile,
+    fetch_snowfall_ensemble,
+    fetch_spc_outlook,
+    fetch_spc_probabilities,
+    fetch_taf,
     fetch_us_alerts,
-    get_point_context,
-    get_quick_alerts,
-    get_quick_obs,
+    find_county_zone,
+    get_air_quality,
+    get_archive_daily,
+    get_marine_forecast,
+    get_minutely_precipitation,
+    get_nws_snowfall_grid,
+    get_osrm_route,
+    get_pollen,
     get_quick_profile,
 )
+from .fishing import (
+    FISH_HOURS,
+    FishReport,
+    best_windows,
+    score_hours,
+    sea_levels,
+    solunar_periods,
+    summarize_fish,
+)
 from .forecaster import Forecaster, ForecasterResponse
+from .freshness import MAX_WAIT_MINUTES, issuance_pack, wait_for_newer
+from .fronts import FRONT_WINDOW_HOURS, describe_front, detect_fronts
+from .geo import haversine_km
+from .graph import GraphSeries, build_series
+from .groups import GroupAlertsReport, SiteAlerts, severity_rank, summarize_group_alerts
+from .history import HistoryReport, add_normals, history_rows, summarize_history
+from .lakes import (
+    LakeReport,
+    ice_season,
+    nearest_buoy,
+    nearest_great_lake,
+    parse_ice_table,
+    parse_ndbc_latest,
+    parse_open_lakes_forecast,
+)
+from .marine import MARINE_HOURS, MarineReport, build_marine_report, is_marine_focus, marine_pack
+from .models import MODELS, MODELS_HOURS, ModelsReport, find_divergences, model_runs, summarize_models
+from .normals import NormalsCache, ensure_normals, normal_daily_mean, normals_pack
+from .nowcast import NOWCAST_MINUTES, STEP_MINUTES, RainReport, nowcast_steps, summarize_nowcast
+from .plugins import Plugin, plugin_request, run_plugin
+from .pollen import pollen_pack, wants_pollen
+from .profile import WorldCity, infer_region
+from .providers import NWSProvider, get_provider
+from .question import parse_question, time_window
+from .radar import RadarFrame, RadarReport, coverage, decode_png, mosaic, tile_url, view_tiles
+from .retrieval import ContextStore, context_store_path
+from .risk import (
+    DEFAULT_THRESHOLDS,
+    OPT_IN_HAZARDS,
+    HazardScore,
+    apply_spc_outlook,
+    assess_hazards,
+    default_hazards,
+    normalize_hazards,
+)
+from .roads import DEFAULT_COMMUTE, ROAD_HOURS, RoadReport, RoadSegment, commute_risks
+from .route import (
+    DEFAULT_SPEED_KMH,
+    DEFAULT_STOPS,
+    ROAD_FACTOR,
+    RouteReport,
+    hour_conditions,
+    plan_stops,
+    route_hazards,
+)
+from .safety import urgent_alerts
+from .snow import SnowOutlook, build_snow_outlook
+from .staleness import stale_data
+from .tidy import SiteSeries
+from .timemachine import ArchivedProvider, validate_at
+from .tropics import any_basin_in_season, summarize_basins
+from .units import display_depth, display_observation, display_speed, display_temp, unit_pack
+from .uv import apply_sun_safety, is_uv_focus, uv_pack
+from .watch import OUTLOOK_HOURS, PlaceReport, take_snapshot
 
 
 @dataclass(slots=True)
@@ -38,6 +154,12 @@ class OrchestrationResult:
     response: ForecasterResponse
     timings: dict[str, float]
     debug: dict[str, Any]
+    # Per-hour rows in display units for the forecast table; too long to send to the model.
+    hourly: list[dict[str, Any]] = field(default_factory=list)
+    # Machine-readable answer object for `wx ask --data-only`.
+    data: dict[str, Any] | None = None
+    # Keys of wx.attribution.SOURCES for the data behind the result.
+    attribution: list[str] = field(default_factory=list)
 
 
 @dataclass(slots=True)
@@ -72,43 +194,327 @@ class RegionView:
     summary: str
     stats: RegionStats
     alerts: list[dict[str, Any]]
+    samples: int = 0
 
 
 @dataclass(slots=True)
 class Worldview:
-    """Aggregate view of US + Europe weather."""
+    """Aggregate view of regional weather."""
 
     regions: list[RegionView]
     meta: dict[str, Any]
-
-
-def _unit_pack(units: str) -> dict[str, str]:
-    if units == "metric":
-        return {"temp": "C", "wind": "mps", "precip": "mm"}
-    return {"temp": "F", "wind": "mph", "precip": "in"}
+    anomalies: list[dict[str, Any]] = field(default_factory=list)
+    tropics: list[dict[str, Any]] = field(default_factory=list)
+
+
+_UGC_PATTERN = re.compile(r"^[A-Z]{2}[CZ]\d{3}$")
+# Open-Meteo serves at most 16 days of hourly data.
+MAX_HOURLY_FORECAST_HOURS = 384
+# Fetches whose failure is listed under the Feature Pack's ``warnings`` so the story can go on without them.
+MISSING_DATA_WARNINGS = {
+    "point_context": "The place could not be looked up, so there is no local data.",
+    "quick_alerts": "Active alerts could not be fetched; check local warnings before relying on this.",
+    "hourly_forecast": "The hourly forecast could not be fetched, so timing and totals are missing.",
+}
+
+
+def is_zone_code(value: str) -> bool:
+    """Return True for NWS county/forecast zone codes such as ``WAC033``."""
+
+    return bool(_UGC_PATTERN.match(value.strip().upper()))
+
+
+def group_alerts_by_zone(alerts: Iterable[dict[str, Any]]) -> dict[str, list[str]]:
+    """Map each affected zone name to the alerts covering it, most severe first."""
+
+    zones: dict[str, list[str]] = {}
+    for alert in sorted(alerts, key=lambda a: SEVERITY_RANK.get(a.get("severity") or "", len(SEVERITY_RANK))):
+        label = alert.get("event", "Alert")
+        if alert.get("severity") not in (None, "Unknown"):
+            label += f" ({alert['severity']})"
+        for area in alert.get("areas") or ["Unspecified area"]:
+            if label not in zones.setdefault(area, []):
+                zones[area].append(label)
+    return dict(sorted(zones.items()))
+
+
+def _annotate_fronts(response: ForecasterResponse, fronts: list[dict[str, Any]] | None) -> None:
+    """Add rule-detected frontal passages to the timeline as a check on the narrative."""
+    if not fronts:
+        return
+    timeline = [
+        item for item in response.sections.get("timeline") or [] if not str(item).startswith("No timeline")
+    ]
+    detected = [f"{front['label']} (detected from hourly data)" for front in fronts]
+    response.sections["timeline"] = detected + timeline
+
+
+def data_answer(response: ForecasterResponse, feature_pack: dict[str, Any]) -> dict[str, Any]:
+    """Structured answer for scripts: the model's reply checked against the Feature Pack.
+
+    The window comes from the parsed question when there is one, sources are limited to Feature
+    Pack keys that exist, and active Severe/Extreme alerts are always listed.
+    """
+    sections = response.sections
+    answer = sections.get("answer")
+    supporting = sections.get("supporting_values")
+    window = feature_pack.get("window") or {}
+    model_window = sections.get("time_window") if isinstance(sections.get("time_window"), dict) else {}
+    if window.get("start_iso"):
+        time_window = {"start": window["start_iso"], "end": window.get("end_iso"), "label": window.get("label")}
+    else:
+        time_window = {"start": model_window.get("start"), "end": model_window.get("end"), "label": None}
+    claimed = sections.get("sources") if isinstance(sections.get("sources"), list) else response.used_feature_fields
+    sources = [
+        source
+        for source in claimed
+        if isinstance(source, str) and source.split(".", 1)[0] in feature_pack
+    ]
+    return {
+        "answer": answer if isinstance(answer, str) and answer.strip() else None,
+        "supporting_values": [item for item in supporting if isinstance(item, dict)]
+        if isinstance(supporting, list)
+        else [],
+        "time_window": time_window,
+        "confidence": response.confidence,
+        "sources": list(dict.fromkeys(sources)),
+        "place": (feature_pack.get("place") or {}).get("resolved"),
+        "alerts": [alert["event"] for alert in urgent_alerts(feature_pack.get("alerts_quick"))],
+        "provider": response.provider,
+    }
+
+
+def _alert_timeline_entry(record: dict[str, Any]) -> str:
+    event = record.get("event", "Alert")
+    if record.get("urgency") and record["urgency"] != "Unknown":
+        event += f" ({record['urgency']})"
+    return f"{event} expires {record.get('expires_iso') or 'unknown'}"
+
+
+def _region_display_name(key: str) -> str:
+    return REGION_NAMES.get(key, key.replace("_", " ").title())
+
+
+PERIOD_STEPS = (1, 3, 6, 12, 24)
+
+
+def _series_at(hourly: dict[str, list[Any]], key: str, idx: int) -> float | None:
+    series = hourly.get(key) or []
+    item = series[idx] if idx < len(series) else None
+    return item if isinstance(item, (int, float)) else None
+
+
+def _window_indices(
+    hourly: dict[str, list[Any]], start: datetime, end: datetime
+) -> list[tuple[int, datetime]]:
+    indices = []
+    for idx, raw in enumerate(hourly.get("time") or []):
+        stamp = datetime.fromisoformat(raw)
+        stamp = stamp.replace(tzinfo=UTC) if stamp.tzinfo is None else stamp
+        if start <= stamp < end:
+            indices.append((idx, stamp))
+    return indices
+
+
+def _forecast_periods(
+    hourly: dict[str, list[Any]] | None,
+    start: datetime,
+    end: datetime,
+    units: str,
+    *,
+    max_periods: int = DEFAULT_AI_MAX_PERIODS,
+) -> list[dict[str, Any]]:
+    """Condense Open-Meteo hourly data inside the window into periods sized by its length.
+
+    Short windows stay hourly; longer ones widen the step until at most ``max_periods``
+    periods remain so long horizons do not flood the prompt.
+    """
+    if not hourly:
+        return []
+    window_hours = (end - start).total_seconds() / 3600
+    step = 1 if window_hours <= 12 else 3 if window_hours <= 24 else 6
+    for candidate in PERIOD_STEPS:
+        if candidate >= step and math.ceil(window_hours / candidate) <= max(max_periods, 1):
+            step = candidate
+            break
+    else:
+        step = PERIOD_STEPS[-1]
+
+    indices = _window_indices(hourly, start, end)
+    periods = []
+    for offset in range(0, len(indices), step):
+        idx, stamp = indices[offset]
+        bucket = [item for item, _ in indices[offset : offset + step]]
+        precip = sum(_series_at(hourly, "precipitation", item) or 0.0 for item in bucket)
+        probs = [_series_at(hourly, "precipitation_probability", item) for item in bucket]
+        gusts = [_series_at(hourly, "wind_gusts_10m", item) for item in bucket]
+        periods.append(
+            {
+                "start_iso": stamp.isoformat(),
+                "hours": len(bucket),
+                "temp": display_temp(_series_at(hourly, "temperature_2m", idx), units),
+                "feels_like": display_temp(_series_at(hourly, "apparent_temperature", idx), units),
+                "dewpoint": display_temp(_series_at(hourly, "dew_point_2m", idx), units),
+                "wind": display_speed(_series_at(hourly, "wind_speed_10m", idx), units),
+                "wind_dir": _series_at(hourly, "wind_direction_10m", idx),
+                "gust": display_speed(max((gust for gust in gusts if gust is not None), default=None), units),
+                "precip_prob": max((prob for prob in probs if prob is not None), default=None),
+                "precip": display_depth(precip, units),
+            }
+        )
+    return periods
+
+
+def _hourly_rows(
+    hourly: dict[str, list[Any]] | None, start: datetime, end: datetime, units: str
+) -> list[dict[str, Any]]:
+    """One row per forecast hour inside the window, in display units."""
+    if not hourly:
+        return []
+    worded = hourly.get("conditions") or []
+    rows = []
+    for idx, stamp in _window_indices(hourly, start, end):
+        code = _series_at(hourly, "weather_code", idx)
+        conditions = worded[idx] if idx < len(worded) else None
+        rows.append(
+            {
+                "time_iso": stamp.isoformat(),
+                "temp": display_temp(_series_at(hourly, "temperature_2m", idx), units),
+                "feels_like": display_temp(_series_at(hourly, "apparent_temperature", idx), units),
+                "wind": display_speed(_series_at(hourly, "wind_speed_10m", idx), units),
+                "wind_dir": _series_at(hourly, "wind_direction_10m", idx),
+                "gust": display_speed(_series_at(hourly, "wind_gusts_10m", idx), units),
+                "precip_prob": _series_at(hourly, "precipitation_probability", idx),
+                "conditions": conditions or (WMO_WEATHER_CODES.get(int(code)) if code is not None else None),
+            }
+        )
+    return rows
+
+
+def _window_summary(
+    hourly: dict[str, list[Any]] | None, start: datetime, end: datetime, units: str
+) -> dict[str, Any] | None:
+    """Aggregates over every hour in the window, so extremes survive period sampling."""
+    if not hourly:
+        return None
+    indices = _window_indices(hourly, start, end)
+    if not indices:
+        return None
+
+    def extreme(key: str, pick: Any) -> tuple[float, str] | None:
+        values = [
+            (value, stamp) for idx, stamp in indices if (value := _series_at(hourly, key, idx)) is not None
+        ]
+        if not values:
+            return None
+        value, stamp = pick(values, key=lambda item: item[0])
+        return value, stamp.isoformat()
+
+    summary: dict[str, Any] = {"hours": len(indices)}
+    low = extreme("temperature_2m", min)
+    high = extreme("temperature_2m", max)
+    gust = extreme("wind_gusts_10m", max)
+    if low and high:
+        summary["temp_min"] = {"value": display_temp(low[0], units), "at": low[1]}
+        summary["temp_max"] = {"value": display_temp(high[0], units), "at": high[1]}
+    if gust:
+        summary["gust_max"] = {"value": display_speed(gust[0], units), "at": gust[1]}
+    precip = [_series_at(hourly, "precipitation", idx) for idx, _ in indices]
+    summary["precip_total"] = display_depth(sum(value or 0.0 for value in precip), units)
+    probs = [_series_at(hourly, "precipitation_probability", idx) for idx, _ in indices]
+    known = [prob for prob in probs if prob is not None]
+    if known:
+        summary["precip_prob_max"] = max(known)
+        summary["hours_precip_likely"] = sum(1 for prob in known if prob >= 50)
+    return summary
 
 
 class Orchestrator:
     """Build Feature Packs and invoke the AI forecaster."""
 
-    def __init__(self, settings: Settings, *, trust_tools: bool = False) -> None:
+    def __init__(self, settings: Settings, *, trust_tools: bool = False, plugins: Sequence[Plugin] = ()) -> None:
         self.settings = settings
         self.trust_tools = trust_tools
+        # The profile's external data plugins; see wx.plugins.
+        self.plugins = list(plugins)
         self.forecaster = Forecaster(settings)
+        self.provider = get_provider(settings.provider, offline=settings.offline)
+        if not settings.offline:
+            # Persisting looked-up places would be query history, so privacy mode keeps it in memory.
+            # --no-cache still reuses geocodes within the run, so loops never re-geocode a place.
+            path = None if settings.privacy_mode or not settings.use_cache else cache_path(settings)
+            self.provider = CachingProvider(self.provider, FetchCache(path), geocode_only=not settings.use_cache)
+            if settings.use_cache:
+                self.forecaster.story_cache = self.provider.cache
+        self.context_store = ContextStore(None if settings.privacy_mode else context_store_path(settings))
+        # Set by ``wx forecast --at``: the moment treated as now, served from archived model runs.
+        self.at: datetime | None = None
+
+    def handle_question(
+        self,
+        question: str,
+        *,
+        verbose: bool,
+        feature_pack: dict[str, Any] | None = None,
+        context: str | None = None,
+        data_only: bool = False,
+    ) -> OrchestrationResult:
+        """Answer a freeform question, optionally reusing a previously built Feature Pack.
 
-    def handle_question(self, question: str, *, verbose: bool) -> OrchestrationResult:
-        feature_pack = self._base_feature_pack()
+        ``context`` (e.g. chat history) is sent to the model but not parsed for place/time.
+        ``data_only`` asks for a structured answer object (see ``data_answer``) instead of prose.
+        """
+        feature_pack = {**(feature_pack or {}), **self._base_feature_pack()}
         timings: dict[str, float] = {}
-        debug_info = {"fetchers": []}
+        debug_info: dict[str, Any] = {"fetchers": []}
+
+        parsed = parse_question(question)
+        debug_info["parsed"] = {"location": parsed.location, "time_phrase": parsed.time_phrase}
+        # Follow-ups ("and in Tacoma?", "what about Saturday?") reuse a recent place's data.
+        reused = (
+            self.context_store.match_place(parsed.location)
+            if parsed.location
+            else self.context_store.search(question)
+        )
+        if reused is not None:
+            feature_pack = {**reused.feature_pack, **self._base_feature_pack()}
+            debug_info["context_reused"] = reused.label
+        elif parsed.location:
+            place_info = self._maybe_fetch(
+                "point_context",
+                lambda: self.provider.geocode(parsed.location),
+                timings,
+                debug_info,
+            )
+            if place_info:
+                # A newly named place replaces whatever the cached pack described.
+                for key in ("alerts_quick", "obs_quick", "profile_quick", "air_quality", "pollen", "window"):
+                    feature_pack.pop(key, None)
+                feature_pack["place"] = place_info
+                self._attach_point_data(
+                    feature_pack,
+                    place_info,
+                    timings,
+                    debug_info,
+                    air_quality=wants_air_quality(question),
+                    pollen=wants_pollen(question),
+                )
+        if parsed.time_phrase:
+            window = self._question_window(feature_pack.get("place"), parsed.time_phrase)
+            if window:
+                feature_pack["window"] = window
+        self.context_store.add(feature_pack, question)
 
         response = self.forecaster.generate(
-            query=question,
+            query=f"{context}\n{question}" if context else question,
             feature_pack=feature_pack,
-            intent="question",
+            intent="data" if data_only else "question",
             verbose=verbose,
         )
 
-        self._persist_state(command="question", query=question, feature_pack=feature_pack)
+        self._persist_state(
+            command="question", query=question, feature_pack=feature_pack, response=response
+        )
         return OrchestrationResult(
             command="question",
             query=question,
@@ -116,6 +522,8 @@ class Orchestrator:
             response=response,
             timings=timings,
             debug=debug_info,
+            attribution=self._attribution(feature_pack),
+            data=data_answer(response, feature_pack) if data_only else None,
         )
 
     def handle_forecast(
@@ -126,59 +534,15 @@ class Orchestrator:
         horizon: str,
         focus: str | None,
         verbose: bool,
+        at: datetime | None = None,
     ) -> OrchestrationResult:
         timings: dict[str, float] = {}
         debug_info: dict[str, Any] = {"fetchers": []}
-
-        feature_pack = self._base_feature_pack()
-        place_info = self._maybe_fetch(
-            "point_context",
-            lambda: get_point_context(place, offline=self.settings.offline),
-            timings,
-            debug_info,
+        feature_pack, hourly = self._forecast_feature_pack(
+            place, when_text, horizon, focus, verbose, timings, debug_info, at=at
         )
-        if place_info:
-            feature_pack["place"] = place_info
-        window = self._build_window(place_info, when_text, horizon)
-        if window:
-            feature_pack["window"] = window
-
-        if place_info:
-            lat = place_info.get("lat")
-            lon = place_info.get("lon")
-            if isinstance(lat, (int, float)) and isinstance(lon, (int, float)) and self.trust_tools:
-                obs = self._maybe_fetch(
-                    "quick_obs",
-                    lambda: get_quick_obs(lat, lon, offline=self.settings.offline),
-                    timings,
-                    debug_info,
-                )
-                if obs:
-                    feature_pack["obs_quick"] = obs
-                profile = self._maybe_fetch(
-                    "quick_profile",
-                    lambda: get_quick_profile(lat, lon, offline=self.settings.offline),
-                    timings,
-                    debug_info,
-                )
-                if profile:
-                    feature_pack["profile_quick"] = profile
-                alerts = self._maybe_fetch(
-                    "quick_alerts",
-                    lambda: get_quick_alerts(lat, lon, offline=self.settings.offline),
-                    timings,
-                    debug_info,
-                )
-                if alerts:
-                    feature_pack["alerts_quick"] = alerts
-
-        user_context: dict[str, Any] = {"use_case": "forecast"}
-        if focus:
-            user_context["constraints"] = [f"focus:{focus}"]
-        if verbose:
-            user_context["constraints"] = (user_context.get("constraints") or []) + ["verbose"]
-        if user_context:
-            feature_pack["user_context"] = user_context
+        if self.at is None:
+            self.context_store.add(feature_pack, place)
 
         response = self.forecaster.generate(
             query=self._compose_forecast_query(place, when_text, horizon, focus),
@@ -186,11 +550,13 @@ class Orchestrator:
             intent="forecast",
             verbose=verbose,
         )
+        _annotate_fronts(response, feature_pack.get("fronts"))
 
         self._persist_state(
             command="forecast",
             query=response.prompt_summary,
             feature_pack=feature_pack,
+            response=response,
         )
         return OrchestrationResult(
             command="forecast",
@@ -199,14 +565,192 @@ class Orchestrator:
             response=response,
             timings=timings,
             debug=debug_info,
+            attribution=self._attribution(feature_pack),
+            hourly=hourly,
         )
 
+    def wait_for_update(
+        self,
+        place: str,
+        *,
+        timeout_minutes: int = MAX_WAIT_MINUTES,
+        sleep: Callable[[float], None] = time.sleep,
+        on_wait: Callable[[dict[str, Any] | None], None] | None = None,
+    ) -> dict[str, Any] | None:
+        """Block until NWS issues a newer forecast for ``place`` than the one available now.
+
+        Returns the new issuance, or None when none arrives within ``timeout_minutes``. Later
+        fetches skip the cache so the story is built on it.
+        """
+        if getattr(self.provider, "name", None) != NWSProvider.name:
+            raise ValueError("--wait-for-update follows NWS forecast issuances; use --provider nws.")
+        if self.settings.offline:
+            raise ValueError("--wait-for-update needs the network; wx is offline.")
+        place_info = self.provider.geocode(place) or {}
+        lat, lon = place_info.get("lat"), place_info.get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            raise ValueError(f"Could not find '{place}'.")
+
+        def issued() -> str | None:
+            return (fetch_nws_hourly(lat, lon) or {}).get("update_time")
+
+        baseline = issued()
+        if baseline is None:
+            raise ValueError(f"NWS has no hourly forecast for {place_info.get('resolved') or place}.")
+        if on_wait:
+            on_wait(issuance_pack(baseline, tz_name=place_info.get("tz")))
+        latest = wait_for_newer(issued, baseline, timeout_minutes=timeout_minutes, sleep=sleep)
+        if latest is None:
+            return None
+        if isinstance(self.provider, CachingProvider):
+            self.provider.refresh = True
+        return issuance_pack(latest, tz_name=place_info.get("tz"))
+
+    def compare_prompts(
+        self,
+        place: str,
+        versions: Sequence[str],
+        *,
+        when_text: str | None,
+        horizon: str,
+        focus: str | None,
+        verbose: bool,
+        fixture: dict[str, Any] | None = None,
+    ) -> list[OrchestrationResult]:
+        """Forecasts for one Feature Pack from each prompt version, for side-by-side review.
+
+        A ``fixture`` Feature Pack (e.g. from a chat recording) replaces the fetch so runs are
+        repeatable and only the model calls differ.
+        """
+        timings: dict[str, float] = {}
+        debug_info: dict[str, Any] = {"fetchers": []}
+        hourly: list[dict[str, Any]] = []
+        if fixture is not None:
+            feature_pack = fixture
+            debug_info["fixture"] = True
+        else:
+            feature_pack, hourly = self._forecast_feature_pack(
+                place, when_text, horizon, focus, verbose, timings, debug_info
+            )
+        query = self._compose_forecast_query(place, when_text, horizon, focus)
+
+        results = []
+        for version in versions:
+            start = time.perf_counter()
+            response = self.forecaster.generate(
+                query=query,
+                feature_pack=feature_pack,
+                intent="forecast",
+                verbose=verbose,
+                prompt_version=version,
+            )
+            _annotate_fronts(response, feature_pack.get("fronts"))
+            # Offline and fallback responses carry no prompt version; label the column anyway.
+            response.meta = {"prompt_version": version, **(response.meta or {})}
+            results.append(
+                OrchestrationResult(
+                    command="forecast",
+                    query=place,
+                    feature_pack=feature_pack,
+                    response=response,
+                    timings={**timings, "forecaster": time.perf_counter() - start},
+                    debug=debug_info,
+                    attribution=self._attribution(feature_pack),
+                    hourly=hourly,
+                )
+            )
+        return results
+
+    def _forecast_feature_pack(
+        self,
+        place: str,
+        when_text: str | None,
+        horizon: str,
+        focus: str | None,
+        verbose: bool,
+        timings: dict[str, float],
+        debug_info: dict[str, Any],
+        *,
+        at: datetime | None = None,
+    ) -> tuple[dict[str, Any], list[dict[str, Any]]]:
+        """Feature Pack for ``wx forecast`` plus the window's per-hour table rows.
+
+        With ``at`` (naive times are taken in the place's timezone) the pack is built as of that
+        past moment from archived model runs.
+        """
+        feature_pack = self._base_feature_pack()
+        place_info = self._maybe_fetch(
+            "point_context",
+            lambda: self.provider.geocode(place),
+            timings,
+            debug_info,
+        )
+        if not place_info:
+            self._warn_missing(feature_pack, "point_context")
+        if at is not None:
+            self.at = validate_at(at if at.tzinfo else at.replace(tzinfo=zone((place_info or {}).get("tz"))))
+            self.provider = ArchivedProvider(self.provider, self.at, offline=self.settings.offline)
+            feature_pack["as_of"] = self.at.isoformat()
+        if is_aviation_focus(focus):
+            aviation = self._maybe_fetch(
+                "aviation",
+                lambda: self.handle_metar(place, place_info=None if is_icao(place) else place_info),
+                timings,
+                debug_info,
+            )
+            if aviation and aviation.metar:
+                feature_pack["aviation"] = asdict(aviation)
+                if is_icao(place) and aviation.lat is not None:
+                    # Forecast for the airport itself, not whatever the geocoder made of its code.
+                    place_info = self.provider.geocode(f"{aviation.lat},{aviation.lon}") or place_info
+                    if place_info:
+                        place_info = {**place_info, "resolved": f"{aviation.station} ({aviation.name or place})"}
+        if place_info:
+            feature_pack["place"] = place_info
+            if is_marine_focus(focus):
+                marine = self._maybe_fetch(
+                    "marine", lambda: self.handle_marine(place, place_info=place_info), timings, debug_info
+                )
+                if marine and marine.now:
+                    feature_pack["marine"] = marine_pack(marine)
+        window = self._build_window(place_info, when_text, horizon)
+        if window:
+            feature_pack["window"] = window
+
+        hourly: list[dict[str, Any]] = []
+        if place_info:
+            self._attach_point_data(
+                feature_pack,
+                place_info,
+                timings,
+                debug_info,
+                air_quality=wants_air_quality(focus),
+                pollen=wants_pollen(focus),
+            )
+            hourly = self._attach_hourly(
+                feature_pack, place_info, window, timings, debug_info, uv=is_uv_focus(focus)
+            )
+            self._flag_stale(feature_pack)
+            self._attach_plugins(feature_pack, place_info, "forecast", timings, debug_info)
+
+        user_context: dict[str, Any] = {"use_case": "forecast"}
+        if focus:
+            user_context["focus"] = focus
+            user_context["constraints"] = [f"focus:{focus}"]
+        if verbose:
+            user_context["constraints"] = (user_context.get("constraints") or []) + ["verbose"]
+        if user_context:
+            feature_pack["user_context"] = user_context
+        return feature_pack, hourly
+
     def handle_risk(
         self,
         place: str,
         *,
         hazards: Iterable[str] | None,
         verbose: bool,
+        ai: bool = False,
+        thresholds: str | None = None,
     ) -> OrchestrationResult:
         timings: dict[str, float] = {}
         debug_info = {"fetchers": []}
@@ -214,40 +758,107 @@ class Orchestrator:
         feature_pack = self._base_feature_pack()
         place_info = self._maybe_fetch(
             "point_context",
-            lambda: get_point_context(place, offline=self.settings.offline),
+            lambda: self.provider.geocode(place),
             timings,
             debug_info,
         )
+        hourly = None
+        point: tuple[float, float] | None = None
+        if not place_info:
+            self._warn_missing(feature_pack, "point_context")
         if place_info:
             feature_pack["place"] = place_info
-        if place_info and self.trust_tools:
             lat = place_info.get("lat")
             lon = place_info.get("lon")
             if isinstance(lat, (int, float)) and isinstance(lon, (int, float)):
+                point = (lat, lon)
                 alerts = self._maybe_fetch(
                     "quick_alerts",
-                    lambda: get_quick_alerts(lat, lon, offline=self.settings.offline),
+                    lambda: self.provider.alerts(lat, lon),
                     timings,
                     debug_info,
                 )
+                if alerts is None:
+                    self._warn_missing(feature_pack, "quick_alerts")
                 if alerts:
                     feature_pack["alerts_quick"] = alerts
+                outlook = self._maybe_fetch(
+                    "spc_outlook",
+                    lambda: fetch_spc_outlook(lat, lon, offline=self.settings.offline),
+                    timings,
+                    debug_info,
+                )
+                if outlook:
+                    feature_pack["spc_outlook"] = outlook
+                hourly = self._maybe_fetch(
+                    "hourly_forecast",
+                    lambda: self.provider.forecast(lat, lon),
+                    timings,
+                    debug_info,
+                )
+                if hourly is None:
+                    self._warn_missing(feature_pack, "hourly_forecast")
+                self._attach_plugins(feature_pack, place_info, "risk", timings, debug_info)
+
+        selected, unscored = normalize_hazards(hazards)
+        spc = None
+        if "severe" in selected and point:
+            spc = self._maybe_fetch(
+                "spc_probabilities",
+                lambda: fetch_spc_probabilities(*point, offline=self.settings.offline),
+                timings,
+                debug_info,
+            )
+            if spc:
+                feature_pack["spc_probabilities"] = spc
+        self._flag_stale(feature_pack, issued_iso=(hourly or {}).get("issued"))
+        if (hourly or {}).get("note"):
+            feature_pack["provider_note"] = hourly["note"]
         if hazards:
             feature_pack.setdefault("user_context", {})["constraints"] = [
                 f"hazards:{','.join(hazards)}"
             ]
-
-        response = self.forecaster.generate(
-            query=self._compose_risk_query(place, hazards),
-            feature_pack=feature_pack,
-            intent="risk",
-            verbose=verbose,
-        )
+        scores: list[HazardScore] = []
+        if hourly or spc or feature_pack.get("alerts_quick"):
+            scores = assess_hazards(
+                hourly,
+                feature_pack.get("alerts_quick"),
+                hazards=selected,
+                units=self.settings.units,
+                spc=spc,
+                thresholds=thresholds,
+            )
+            feature_pack["risk_scores"] = [asdict(score) for score in scores]
+            if thresholds and thresholds != DEFAULT_THRESHOLDS:
+                feature_pack["risk_thresholds"] = thresholds
+        uv_outlook = uv_pack(hourly) if "uv" in selected else None
+        if uv_outlook:
+            feature_pack["uv"] = uv_outlook
+        if unscored:
+            debug_info["unscored_hazards"] = unscored
+
+        if not selected and not ai:
+            response = self._unscoreable_response(place, unscored)
+        elif ai or not scores:
+            query = self._compose_risk_query(place, hazards)
+            if scores:
+                query += ". Explain the highest-scored hazards in risk_scores."
+            response = self.forecaster.generate(
+                query=query,
+                feature_pack=feature_pack,
+                intent="risk",
+                verbose=verbose,
+            )
+        else:
+            response = self._risk_response(place, scores, unscored, thresholds=thresholds)
+            apply_sun_safety(response, feature_pack.get("uv"), (place_info or {}).get("tz"))
+            apply_spc_outlook(response, feature_pack.get("spc_outlook"))
 
         self._persist_state(
             command="risk",
             query=response.prompt_summary,
             feature_pack=feature_pack,
+            response=response,
         )
         return OrchestrationResult(
             command="risk",
@@ -256,6 +867,82 @@ class Orchestrator:
             response=response,
             timings=timings,
             debug=debug_info,
+            attribution=self._attribution(feature_pack),
+        )
+
+    def _unscoreable_response(self, place: str, unscored: list[str]) -> ForecasterResponse:
+        """``--hazards`` named only hazards the engine cannot score; say so rather than score others."""
+        sections = {
+            "summary": [f"No scoreable hazards in {', '.join(unscored)}."],
+            "timeline": [],
+            "risk_cards": [],
+            "confidence": "Nothing was scored.",
+            "actions": [
+                f"Choose from {', '.join([*default_hazards(), *OPT_IN_HAZARDS])}, or add --ai to discuss "
+                f"{', '.join(unscored)} near {place}."
+            ],
+            "assumptions": ["No AI triage performed."],
+        }
+        return ForecasterResponse(
+            sections=sections,
+            confidence={"value": 0, "rationale": "No hazards scored."},
+            used_feature_fields=[],
+            bottom_line="Bottom line: the risk engine cannot score the requested hazards.",
+            raw_text=json.dumps(sections, ensure_ascii=True),
+            provider="risk-engine",
+            prompt_summary=f"risk | {place}",
+            meta={"hazards": []},
+        )
+
+    def _risk_response(
+        self, place: str, scores: list[HazardScore], unscored: list[str], *, thresholds: str | None = None
+    ) -> ForecasterResponse:
+        top = scores[0]
+        elevated = [score for score in scores if score.level != "Low"]
+        if elevated:
+            summary = [
+                f"Highest risk near {place}: {top.hazard} ({top.level}, {top.score:.0f}/100)."
+            ]
+        else:
+            summary = [f"No elevated weather hazards near {place} in the next 48 hours."]
+        if unscored:
+            summary.append(f"Not scored by the risk engine: {', '.join(unscored)}.")
+
+        sections = {
+            "summary": summary,
+            "timeline": [
+                f"{score.hazard.title()} peaks {score.peak_iso} UTC" for score in elevated if score.peak_iso
+            ]
+            or ["No hazard peaks above Low."],
+            "risk_cards": [
+                {
+                    "hazard": score.hazard,
+                    "level": score.level,
+                    "drivers": score.drivers,
+                    "confidence": "Rule-based score",
+                }
+                for score in scores
+            ],
+            "confidence": "Scores derived from the hourly forecast and active alerts; add --ai for context.",
+            "actions": ["Review the highest-rated hazards and official alerts before planning."],
+            "assumptions": [
+                "48-hour Open-Meteo hourly forecast; "
+                + (
+                    f"thresholds from the {thresholds} preset."
+                    if thresholds and thresholds != DEFAULT_THRESHOLDS
+                    else "thresholds are general-purpose."
+                )
+            ],
+        }
+        return ForecasterResponse(
+            sections=sections,
+            confidence={"value": 60, "rationale": "Deterministic hazard thresholds."},
+            used_feature_fields=["risk_scores"],
+            bottom_line=f"Bottom line: {top.hazard} risk is {top.level.lower()}.",
+            raw_text=json.dumps(sections, ensure_ascii=True),
+            provider="risk-engine",
+            prompt_summary=f"risk | {place}",
+            meta={"hazards": [score.hazard for score in scores]},
         )
 
     def handle_alerts(
@@ -272,27 +959,27 @@ class Orchestrator:
         feature_pack = self._base_feature_pack()
         place_info = self._maybe_fetch(
             "point_context",
-            lambda: get_point_context(place, offline=self.settings.offline),
+            lambda: self.provider.geocode(place),
             timings,
             debug_info,
         )
         if place_info:
             feature_pack["place"] = place_info
 
-        alerts: list[dict[str, Any]] = []
+        # None means the feed could not be reached, which must not read as a quiet day.
+        alerts: list[dict[str, Any]] | None = []
         if place_info:
             lat = place_info.get("lat")
             lon = place_info.get("lon")
             if isinstance(lat, (int, float)) and isinstance(lon, (int, float)):
-                alerts = (
-                    self._maybe_fetch(
-                        "quick_alerts",
-                        lambda: get_quick_alerts(lat, lon, offline=self.settings.offline),
-                        timings,
-                        debug_info,
-                    )
-                    or []
+                alerts = self._maybe_fetch(
+                    "quick_alerts",
+                    lambda: self.provider.alerts(lat, lon),
+                    timings,
+                    debug_info,
                 )
+                if alerts is None:
+                    self._warn_missing(feature_pack, "quick_alerts")
         if alerts:
             feature_pack["alerts_quick"] = alerts
 
@@ -308,6 +995,8 @@ class Orchestrator:
             )
         else:
             response = self._alerts_response(place, alerts)
+            if place_info and place_info.get("tz"):
+                localize_sections(response, place_info["tz"])
 
         return OrchestrationResult(
             command="alerts",
@@ -316,6 +1005,7 @@ class Orchestrator:
             response=response,
             timings=timings,
             debug=debug_info,
+            attribution=self._attribution(feature_pack),
         )
 
     def handle_explain(self) -> ExplainResult:
@@ -361,8 +1051,231 @@ class Orchestrator:
             feature_pack=feature_pack,
         )
 
+    def location_feature_pack(self, place_info: dict[str, Any]) -> dict[str, Any]:
+        """Build a reusable Feature Pack for an already resolved place (used by chat)."""
+        feature_pack = self._base_feature_pack()
+        feature_pack["place"] = place_info
+        self._attach_point_data(feature_pack, place_info, {}, {"fetchers": []})
+        return feature_pack
+
+    def _attach_point_data(
+        self,
+        feature_pack: dict[str, Any],
+        place_info: dict[str, Any],
+        timings: dict[str, float],
+        debug_info: dict[str, Any],
+        *,
+        air_quality: bool = False,
+        pollen: bool = False,
+    ) -> None:
+        """Alerts and the SPC categorical outlook always; air quality with --trust-tools or when asked
+        about; pollen when asked about; other micro-fetchers with --trust-tools."""
+        lat = place_info.get("lat")
+        lon = place_info.get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return
+
+        # Official warnings are always included; only the micro-fetchers need --trust-tools.
+        alerts = self._maybe_fetch(
+            "quick_alerts",
+            lambda: self.provider.alerts(lat, lon),
+            timings,
+            debug_info,
+        )
+        if alerts is None:
+            self._warn_missing(feature_pack, "quick_alerts")
+        if alerts:
+            feature_pack["alerts_quick"] = alerts
+        if self.at is not None:
+            # Outlooks, air quality, pollen and observations are only published for now.
+            return
+        outlook = self._maybe_fetch(
+            "spc_outlook",
+            lambda: fetch_spc_outlook(lat, lon, offline=self.settings.offline),
+            timings,
+            debug_info,
+        )
+        if outlook and outlook.get("category"):
+            feature_pack["spc_outlook"] = outlook
+        if self.trust_tools or air_quality:
+            air = self._maybe_fetch(
+                "air_quality",
+                lambda: air_quality_pack(get_air_quality(lat, lon, offline=self.settings.offline)),
+                timings,
+                debug_info,
+            )
+            if air:
+                feature_pack["air_quality"] = air
+        if pollen:
+            pollen_levels = self._maybe_fetch(
+                "pollen",
+                lambda: pollen_pack(get_pollen(lat, lon, offline=self.settings.offline)),
+                timings,
+                debug_info,
+            )
+            if pollen_levels:
+                feature_pack["pollen"] = pollen_levels
+        if not self.trust_tools:
+            return
+
+        obs = self._maybe_fetch(
+            "quick_obs",
+            lambda: self.provider.current(lat, lon),
+            timings,
+            debug_info,
+        )
+        if obs:
+            feature_pack["obs_quick"] = display_observation(obs, self.settings.units)
+        profile = self._maybe_fetch(
+            "quick_profile",
+            lambda: get_quick_profile(lat, lon, offline=self.settings.offline),
+            timings,
+            debug_info,
+        )
+        if profile:
+            feature_pack["profile_quick"] = profile
+
+    def _attach_plugins(
+        self,
+        feature_pack: dict[str, Any],
+        place_info: dict[str, Any],
+        command: str,
+        timings: dict[str, float],
+        debug_info: dict[str, Any],
+    ) -> None:
+        """Add each plugin's JSON under ``plugins``; a failing plugin is listed under ``warnings``."""
+        if not self.plugins or self.settings.offline or self.at is not None:
+            return
+        request = plugin_request(command, place_info, units=self.settings.units, window=feature_pack.get("window"))
+        fragments: dict[str, Any] = {}
+        for plugin in self.plugins:
+            fragment = self._maybe_fetch(
+                f"plugin:{plugin.name}", lambda plugin=plugin: run_plugin(plugin, request), timings, debug_info
+            )
+            if fragment is None:
+                detail = debug_info["fetchers"][-1]["detail"]
+                warning = f"Plugin '{plugin.name}' {detail}, so its data is missing."
+                feature_pack.setdefault("warnings", []).append(warning)
+            elif fragment:
+                fragments[plugin.name] = fragment
+        if fragments:
+            feature_pack["plugins"] = fragments
+
+    def _warn_missing(self, feature_pack: dict[str, Any], fetcher: str) -> None:
+        """Note a failed fetch under ``warnings``; offline runs skip the network on purpose."""
+        if self.settings.offline:
+            return
+        warnings = feature_pack.setdefault("warnings", [])
+        if MISSING_DATA_WARNINGS[fetcher] not in warnings:
+            warnings.append(MISSING_DATA_WARNINGS[fetcher])
+
+    def _flag_stale(self, feature_pack: dict[str, Any], *, issued_iso: str | None = None) -> None:
+        """Record observations and forecast issuances older than the configured bounds as ``stale``."""
+        if self.at is not None:
+            # Forecasts rebuilt as of a past moment are old on purpose.
+            return
+        stale = stale_data(
+            observed_iso=(feature_pack.get("obs_quick") or {}).get("observed_iso"),
+            issued_iso=issued_iso or (feature_pack.get("issuance") or {}).get("issued_iso"),
+            max_obs_minutes=self.settings.stale_obs_minutes,
+            max_forecast_minutes=self.settings.stale_forecast_minutes,
+            now=self._now(),
+        )
+        if stale:
+            feature_pack["stale"] = stale
+
+    def _attribution(self, feature_pack: dict[str, Any]) -> list[str]:
+        return feature_pack_sources(feature_pack, getattr(self.provider, "attribution", ()))
+
+    def _now(self) -> datetime:
+        return self.at.astimezone(UTC) if self.at else datetime.now(UTC)
+
     def _base_feature_pack(self) -> dict[str, Any]:
-        return {"units": _unit_pack(self.settings.units)}
+        return {"units": unit_pack(self.settings.units)}
+
+    def _attach_hourly(
+        self,
+        feature_pack: dict[str, Any],
+        place_info: dict[str, Any],
+        window: dict[str, Any] | None,
+        timings: dict[str, float],
+        debug_info: dict[str, Any],
+        *,
+        uv: bool = False,
+    ) -> list[dict[str, Any]]:
+        """Attach hourly periods for the forecast window, detected frontal passages, sun/moon times and
+        the day's high/low against normal, plus the UV outlook when ``uv`` is set.
+
+        Returns the window's per-hour rows for the forecast table.
+        """
+        lat = place_info.get("lat")
+        lon = place_info.get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return []
+        hours = 48
+        if window:
+            end = date_parser.isoparse(window["end_iso"])
+            # Cover the whole window plus the lookahead front detection needs.
+            needed = math.ceil((end - self._now()).total_seconds() / 3600) + FRONT_WINDOW_HOURS
+            hours = min(max(hours, needed), MAX_HOURLY_FORECAST_HOURS)
+        hourly = self._maybe_fetch(
+            "hourly_forecast",
+            lambda: self.provider.forecast(lat, lon, hours=hours),
+            timings,
+            debug_info,
+        )
+        if hourly is None:
+            self._warn_missing(feature_pack, "hourly_forecast")
+        fronts = detect_fronts(hourly)
+        issuance = issuance_pack((hourly or {}).get("issued"), tz_name=place_info.get("tz"), now=self._now())
+        if (hourly or {}).get("note"):
+            # The NWS grid was retried from a nearby point or replaced by Open-Meteo.
+            feature_pack["provider_note"] = hourly["note"]
+        if issuance:
+            feature_pack["issuance"] = issuance
+        rows: list[dict[str, Any]] = []
+        if window:
+            start = date_parser.isoparse(window["start_iso"])
+            end = date_parser.isoparse(window["end_iso"])
+            fronts = [front for front in fronts if start <= date_parser.isoparse(front.time_iso) <= end]
+            periods = _forecast_periods(
+                hourly, start, end, self.settings.units, max_periods=self.settings.ai_max_periods
+            )
+            if periods:
+                feature_pack["periods"] = periods
+                feature_pack["window_summary"] = _window_summary(hourly, start, end, self.settings.units)
+            rows = _hourly_rows(hourly, start, end, self.settings.units)
+            if self.at is None:
+                # The ensemble is only available for current runs, not archived ones.
+                days = min(16, math.ceil((end - self._now()).total_seconds() / 86400) + 1)
+                members = self._maybe_fetch(
+                    "ensemble",
+                    lambda: fetch_ensemble_members(lat, lon, days=days, offline=self.settings.offline),
+                    timings,
+                    debug_info,
+                )
+                spread = ensemble_pack(members, start, end, self.settings.units)
+                if spread:
+                    feature_pack["ensemble"] = spread
+        if fronts:
+            feature_pack["fronts"] = [
+                {
+                    **asdict(front),
+                    "label": describe_front(front, tz_name=place_info.get("tz"), units=self.settings.units),
+                }
+                for front in fronts
+            ]
+        tz = zone(place_info.get("tz"))
+        day = (date_parser.isoparse(window["start_iso"]) if window else self._now()).astimezone(tz).date()
+        feature_pack["astro"] = astro_pack(astro_day(lat, lon, day, place_info.get("tz")), hourly)
+        normals = self._maybe_fetch("normals", lambda: self._climate_normals(lat, lon), timings, debug_info)
+        departures = normals_pack(normals, hourly, day, place_info.get("tz"), self.settings.units) if normals else None
+        if departures:
+            feature_pack["normals"] = departures
+        uv_outlook = uv_pack(hourly, now=self._now()) if uv else None
+        if uv_outlook:
+            feature_pack["uv"] = uv_outlook
+        return rows
 
     def _build_window(
         self,
@@ -372,13 +1285,15 @@ class Orchestrator:
     ) -> dict[str, Any] | None:
         horizon_hours = self._parse_horizon(horizon)
         tz_name = (place_info or {}).get("tz")
-        now_utc = datetime.now(UTC)
-        start = now_utc
+        start = self._now()
+        end = None
         if when_text:
-            parsed = self._safe_parse_time(when_text, tz_name)
+            parsed = self._parse_when(when_text, tz_name)
             if parsed:
-                start = parsed.astimezone(UTC)
-        end = start + timedelta(hours=horizon_hours)
+                start, end = parsed
+                start = start.astimezone(UTC)
+        # A phrase that names a span ("tomorrow morning") sets the end; otherwise the horizon does.
+        end = end.astimezone(UTC) if end else start + timedelta(hours=horizon_hours)
 
         # Include both UTC and local timezone information
         window = {
@@ -386,6 +1301,8 @@ class Orchestrator:
             "end_iso": end.isoformat(),
             "horizon": f"{horizon_hours}h",
         }
+        if when_text:
+            window["label"] = when_text
 
         # Add local timezone information if available
         if tz_name:
@@ -404,18 +1321,67 @@ class Orchestrator:
 
         return window
 
+    def _question_window(
+        self, place_info: dict[str, Any] | None, phrase: str
+    ) -> dict[str, Any] | None:
+        tz_name = (place_info or {}).get("tz")
+        try:
+            from zoneinfo import ZoneInfo
+
+            tz = ZoneInfo(tz_name) if tz_name else UTC
+        except Exception:  # noqa: BLE001
+            tz = UTC
+        span = time_window(phrase, self._now().astimezone(tz))
+        if span is None:
+            return None
+        start, end = span
+        window = {
+            "label": phrase,
+            "start_iso": start.astimezone(UTC).isoformat(),
+            "end_iso": end.astimezone(UTC).isoformat(),
+            "horizon": f"{round((end - start).total_seconds() / 3600)}h",
+        }
+        if tz is not UTC:
+            window.update(
+                {"start_local": start.isoformat(), "end_local": end.isoformat(), "timezone": tz_name}
+            )
+        return window
+
     def _parse_horizon(self, horizon: str) -> int:
         mapping = {"6h": 6, "12h": 12, "24h": 24, "3d": 72}
         return mapping.get(horizon.lower(), 24)
 
-    def _safe_parse_time(self, when_text: str, tz_name: str | None) -> datetime | None:
+    def _parse_when(self, when_text: str, tz_name: str | None) -> tuple[datetime, datetime | None] | None:
+        """Resolve ``--when`` to a start (and end, for spans) in the place's timezone.
+
+        Relative phrases ("tonight", "tomorrow morning") become spans; clock times
+        ("3pm", "tomorrow 15:00", "2026-07-04 18:00") become a start.
+        """
+        try:
+            from zoneinfo import ZoneInfo
+
+            tz = ZoneInfo(tz_name) if tz_name else UTC
+        except Exception:  # noqa: BLE001
+            tz = UTC
+        now = self._now().astimezone(tz)
+        text = when_text.strip()
+
+        if not re.search(r"\d", text):
+            return time_window(text, now)
+
+        day = now.replace(hour=0, minute=0, second=0, microsecond=0, tzinfo=None)
+        match = re.match(r"^(today|tomorrow)\s+(.+)$", text, re.IGNORECASE)
+        if match:
+            if match.group(1).lower() == "tomorrow":
+                day += timedelta(days=1)
+            text = match.group(2)
         try:
-            parsed = date_parser.parse(when_text)
-            if parsed.tzinfo is None:
-                parsed = parsed.replace(tzinfo=UTC)
-            return parsed
+            parsed = date_parser.parse(text, default=day)
         except (ValueError, TypeError, OverflowError):
             return None
+        if parsed.tzinfo is None:
+            parsed = parsed.replace(tzinfo=tz)
+        return parsed, None
 
     def _compose_forecast_query(
         self, place: str, when_text: str | None, horizon: str, focus: str | None
@@ -457,7 +1423,14 @@ class Orchestrator:
         )
         return result
 
-    def _persist_state(self, *, command: str, query: str, feature_pack: dict[str, Any]) -> None:
+    def _persist_state(
+        self,
+        *,
+        command: str,
+        query: str,
+        feature_pack: dict[str, Any],
+        response: ForecasterResponse | None = None,
+    ) -> None:
         payload = {
             "command": command,
             "question": query,
@@ -465,10 +1438,126 @@ class Orchestrator:
             "style": self.settings.style,
             "persona": self.settings.persona,
             "timestamp": datetime.now(UTC).isoformat(),
+            # Lets `wx feedback` rate this story against the backend that wrote it.
+            "story": story_info(response) if response is not None else None,
         }
         self.settings.save_last_query(payload)
 
-    def _alerts_response(self, place: str, alerts: Iterable[dict[str, Any]]) -> ForecasterResponse:
+    def handle_area_alerts(
+        self,
+        *,
+        state: str | None,
+        county: str | None = None,
+        ai: bool,
+        verbose: bool,
+    ) -> OrchestrationResult:
+        """Active alerts for a whole state or county, grouped by zone."""
+        timings: dict[str, float] = {}
+        debug_info = {"fetchers": []}
+
+        zone: str | None = None
+        label = (state or "").upper()
+        if county:
+            if is_zone_code(county):
+                zone = county.strip().upper()
+                label = zone
+            elif state:
+                resolved = self._maybe_fetch(
+                    "county_zone",
+                    lambda: find_county_zone(state, county, offline=self.settings.offline),
+                    timings,
+                    debug_info,
+                )
+                if resolved:
+                    zone = resolved["id"]
+                    # NWS names parishes and boroughs too, so its name is used as given.
+                    label = f"{resolved['name']}, {state.upper()}"
+                else:
+                    debug_info["unresolved_county"] = county
+                    label = f"{county}, {state.upper()}"
+            if not zone:
+                return self._area_alerts_result(label, [], timings, debug_info)
+
+        alerts = (
+            self._maybe_fetch(
+                "area_alerts",
+                lambda: fetch_area_alerts(state, zone=zone, offline=self.settings.offline),
+                timings,
+                debug_info,
+            )
+            or []
+        )
+
+        if ai and alerts:
+            feature_pack = self._base_feature_pack()
+            feature_pack["alerts_area"] = {"area": label, "alerts": alerts}
+            response = self.forecaster.generate(
+                query=f"Alert triage for {label}.",
+                feature_pack=feature_pack,
+                intent="alerts",
+                verbose=verbose,
+            )
+            return OrchestrationResult(
+                command="alerts",
+                query=label,
+                feature_pack=feature_pack,
+                response=response,
+                timings=timings,
+                debug=debug_info,
+                attribution=self._attribution(feature_pack),
+            )
+        return self._area_alerts_result(label, alerts, timings, debug_info)
+
+    def _area_alerts_result(
+        self,
+        label: str,
+        alerts: list[dict[str, Any]],
+        timings: dict[str, float],
+        debug_info: dict[str, Any],
+    ) -> OrchestrationResult:
+        feature_pack = self._base_feature_pack()
+        feature_pack["alerts_area"] = {"area": label, "alerts": alerts}
+        response = self._alerts_response(label, alerts)
+        if alerts:
+            zones = group_alerts_by_zone(alerts)
+            response.sections["summary"] = [
+                f"{len(alerts)} active alerts across {label} ({len(zones)} zones affected)."
+            ]
+            response.sections["zones"] = [
+                f"{zone_name}: {'; '.join(events)}" for zone_name, events in zones.items()
+            ]
+            response.used_feature_fields = ["alerts_area"]
+            response.raw_text = json.dumps(response.sections, ensure_ascii=True)
+        return OrchestrationResult(
+            command="alerts",
+            query=label,
+            feature_pack=feature_pack,
+            response=response,
+            timings=timings,
+            debug=debug_info,
+            attribution=self._attribution(feature_pack),
+        )
+
+    def _alerts_response(self, place: str, alerts: Iterable[dict[str, Any]] | None) -> ForecasterResponse:
+        if alerts is None:
+            sections = {
+                "summary": [f"Alerts unavailable for {place}: the NOAA alert feed could not be reached."],
+                "timeline": ["Alert status unknown."],
+                "risk_cards": [],
+                "confidence": "No alert data was received.",
+                "actions": ["Check weather.gov or local warnings directly until the feed is back."],
+                "assumptions": ["No AI triage performed."],
+            }
+            return ForecasterResponse(
+                sections=sections,
+                confidence={"value": 0, "rationale": "Alert feed unavailable."},
+                used_feature_fields=[],
+                bottom_line="Bottom line: alerts unavailable, not necessarily quiet.",
+                raw_text=json.dumps(sections, ensure_ascii=True),
+                provider="alerts-manual",
+                prompt_summary=f"alerts | {place}",
+                meta={"records": None},
+            )
         records = list(alerts)
         if not records:
             sections = {
@@ -481,15 +1570,12 @@ class Orchestrator:
             }
         else:
             summary_lines = [f"{len(records)} active alerts near {place}."]
-            timeline = [
-                f"{record.get('event', 'Alert')} expires {record.get('expires_iso', 'unknown')}"
-                for record in records
-            ]
+            timeline = [_alert_timeline_entry(record) for record in records]
             risk_cards = [
                 {
                     "hazard": record.get("event", "Alert"),
                     "level": record.get("severity", "Unknown"),
-                    "drivers": ["Official alert headline"],
+                    "drivers": [record.get("headline") or "Official alert headline"],
                     "confidence": "Official source",
                 }
                 for record in records
@@ -518,80 +1604,689 @@ class Orchestrator:
             meta={"records": len(records)},
         )
 
-    def handle_worldview(self, *, verbose: bool = False, severe_only: bool = False) -> Worldview:
-        """Fetch and aggregate US + Europe weather overview."""
+    def handle_snowmap(self, place: str) -> SnowOutlook:
+        """Expected and 10th/90th percentile snowfall for a place."""
+        place_info = self.provider.geocode(place)
+        lat = (place_info or {}).get("lat")
+        lon = (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return SnowOutlook(place=place)
+
+        with ThreadPoolExecutor(max_workers=2) as executor:
+            grid_future = executor.submit(get_nws_snowfall_grid, lat, lon, offline=self.settings.offline)
+            ensemble_future = executor.submit(fetch_snowfall_ensemble, lat, lon, offline=self.settings.offline)
+            grid = grid_future.result()
+            ensemble = ensemble_future.result()
+
+        return build_snow_outlook(place_info.get("resolved") or place, grid, ensemble)
+
+    def handle_graph(self, variable: str, places: Sequence[str], *, horizon: str) -> list[GraphSeries]:
+        """Hourly values of one forecast variable for each place over the horizon."""
+        hours = self._parse_horizon(horizon)
+
+        def fetch(place: str) -> GraphSeries:
+            place_info = self.provider.geocode(place)
+            lat = (place_info or {}).get("lat")
+            lon = (place_info or {}).get("lon")
+            hourly = None
+            if isinstance(lat, (int, float)) and isinstance(lon, (int, float)):
+                hourly = self.provider.forecast(lat, lon, hours=hours)
+            name = (place_info or {}).get("resolved") or place
+            return build_series(name, variable, hourly, hours=hours, units=self.settings.units)
+
+        with ThreadPoolExecutor(max_workers=max(1, len(places))) as executor:
+            return list(executor.map(fetch, places))
+
+    def handle_favorites(self, favorites: Sequence[Favorite]) -> list[PlaceReport]:
+        """Conditions, alerts and the 12-hour outlook for every favorite, fetched concurrently."""
+        return self._place_reports([(favorite.label, favorite.place) for favorite in favorites])
+
+    def handle_group_alerts(self, group: str, sites: Sequence[Favorite]) -> GroupAlertsReport:
+        """Active alerts for every site in a location group, fetched concurrently."""
+
+        def fetch(site: Favorite) -> SiteAlerts:
+            place_info = self.provider.geocode(site.place)
+            if not place_info or not isinstance(place_info.get("lat"), (int, float)):
+                return SiteAlerts(site.label, site.place, error=f"Could not find {site.place}")
+            result = SiteAlerts(site.label, site.place, resolved=place_info.get("resolved"), tz=place_info.get("tz"))
+            try:
+                alerts = self.provider.alerts(place_info["lat"], place_info["lon"])
+            except Exception as exc:  # noqa: BLE001 - one bad site should not hide the rest
+                result.error = str(exc) or exc.__class__.__name__
+                return result
+            if alerts is None:
+                result.error = "Alerts unavailable: the alert feed could not be reached"
+            else:
+                result.alerts = alerts
+            return result
+
+        with ThreadPoolExecutor(max_workers=max(1, min(len(sites), 8))) as executor:
+            results = list(executor.map(fetch, sites))
+        report = GroupAlertsReport(group=group, sites=sorted(results, key=severity_rank))
+        report.summary = summarize_group_alerts(report)
+        return report
+
+    def handle_data_export(self, sites: Sequence[Favorite], *, hours: int) -> list[SiteSeries]:
+        """Each site's hourly forecast for the next ``hours``, fetched concurrently."""
+
+        def fetch(site: Favorite) -> SiteSeries:
+            place_info = self.provider.geocode(site.place)
+            if not place_info or not isinstance(place_info.get("lat"), (int, float)):
+                return SiteSeries(site.label, site.place, error=f"Could not find {site.place}")
+            try:
+                hourly = self.provider.forecast(place_info["lat"], place_info["lon"], hours=hours)
+            except Exception as exc:  # noqa: BLE001 - one bad site should not hide the rest
+                return SiteSeries(site.label, site.place, error=str(exc) or exc.__class__.__name__)
+            if not hourly:
+                return SiteSeries(site.label, site.place, error="No hourly forecast available")
+            return SiteSeries(site.label, site.place, hourly=hourly)
+
+        with ThreadPoolExecutor(max_workers=max(1, min(len(sites), 8))) as executor:
+            return list(executor.map(fetch, sites))
+
+    def handle_roads(
+        self, roads: Sequence[RoadSegment], *, commute: tuple[str, ...] = DEFAULT_COMMUTE
+    ) -> list[RoadReport]:
+        """Fog and black-ice risk for each road segment's commute windows, fetched concurrently."""
+
+        def fetch(road: RoadSegment) -> RoadReport:
+            report = RoadReport(road.name, road.place, bridge=road.bridge)
+            place_info = self.provider.geocode(road.place)
+            if not place_info or not isinstance(place_info.get("lat"), (int, float)):
+                report.error = f"Could not find {road.place}"
+                return report
+            report.resolved, report.tz = place_info.get("resolved"), place_info.get("tz")
+            try:
+                hourly = self.provider.forecast(place_info["lat"], place_info["lon"], hours=ROAD_HOURS)
+            except Exception as exc:  # noqa: BLE001 - one bad segment should not hide the rest
+                report.error = str(exc) or exc.__class__.__name__
+                return report
+            if not hourly:
+                report.error = "No hourly forecast available"
+                return report
+            report.windows = commute_risks(
+                hourly, commute, tz_name=report.tz, units=self.settings.units, bridge=road.bridge
+            )
+            return report
+
+        with ThreadPoolExecutor(max_workers=max(1, min(len(roads), 8))) as executor:
+            return list(executor.map(fetch, roads))
+
+    def handle_compare(
+        self, places: Sequence[str], *, ai: bool = False, question: str | None = None, verbose: bool = False
+    ) -> CompareResult:
+        """Side-by-side conditions and 24-hour highlights, plus an AI comparison when ``ai``."""
+        reports = self._place_reports([(place, place) for place in places], hours=COMPARE_HOURS)
+        result = CompareResult(reports=reports)
+        if ai:
+            feature_pack = {
+                **self._base_feature_pack(),
+                "places": comparison_pack(reports, self.settings.units),
+                "alerts_quick": [
+                    {**alert, "event": f"{alert.get('event') or 'Alert'} ({report.snapshot.place})"}
+                    for report in reports
+                    if report.snapshot is not None
+                    for alert in report.snapshot.alerts
+                ],
+            }
+            query = question or (
+                f"Compare the weather in {', '.join(places)} over the next {COMPARE_HOURS} hours "
+                "in one short paragraph and say which looks best."
+            )
+            result.response = self.forecaster.generate(
+                query=query, feature_pack=feature_pack, intent="question", verbose=verbose
+            )
+        return result
+
+    def _place_reports(
+        self, places: Sequence[tuple[str, str]], *, hours: int = OUTLOOK_HOURS
+    ) -> list[PlaceReport]:
+        stale_after = (self.settings.stale_obs_minutes, self.settings.stale_forecast_minutes)
+
+        def fetch(item: tuple[str, str]) -> PlaceReport:
+            label, place = item
+            place_info = self.provider.geocode(place)
+            if not place_info or not isinstance(place_info.get("lat"), (int, float)):
+                return PlaceReport(label, place, error=f"Could not find {place}")
+            try:
+                snapshot = take_snapshot(
+                    self.provider, place_info, fresh=False, hours=hours, stale_after=stale_after
+                )
+            except Exception as exc:  # noqa: BLE001 - one bad place should not hide the rest
+                return PlaceReport(label, place, error=str(exc) or exc.__class__.__name__)
+            return PlaceReport(label, place, snapshot=snapshot)
+
+        with ThreadPoolExecutor(max_workers=max(1, len(places))) as executor:
+            return list(executor.map(fetch, places))
+
+    def handle_route(
+        self,
+        origin: str,
+        destination: str,
+        *,
+        depart: datetime | None = None,
+        speed_kmh: float | None = None,
+        stops: int = DEFAULT_STOPS,
+        roads: bool = True,
+    ) -> RouteReport:
+        """Forecast at evenly spaced points along a drive, each for the hour it is reached.
+
+        Uses the OSRM driving route and travel time when reachable (and ``roads``), otherwise
+        the great circle at ``speed_kmh`` with distance padded by ``ROAD_FACTOR``.
+        """
+        report = RouteReport(origin=origin, destination=destination)
+        with ThreadPoolExecutor(max_workers=2) as executor:
+            start_info, end_info = executor.map(self.provider.geocode, (origin, destination))
+        for name, info in ((origin, start_info), (destination, end_info)):
+            if not info or not isinstance(info.get("lat"), (int, float)):
+                report.error = f"Could not find {name}"
+                return report
+        report.origin = start_info.get("resolved") or origin
+        report.destination = end_info.get("resolved") or destination
+        report.tz = start_info.get("tz")
+        start, end = (start_info["lat"], start_info["lon"]), (end_info["lat"], end_info["lon"])
+
+        road = get_osrm_route(start, end, offline=self.settings.offline) if roads else None
+        if road:
+            path, report.path, report.distance_km = road["path"], "osrm", road["distance_km"]
+            report.duration_h = report.distance_km / speed_kmh if speed_kmh else road["duration_h"]
+        else:
+            path, report.distance_km = [start, end], haversine_km(*start, *end) * ROAD_FACTOR
+            report.duration_h = report.distance_km / (speed_kmh or DEFAULT_SPEED_KMH)
+        report.distance_km, report.duration_h = round(report.distance_km, 1), round(report.duration_h, 2)
+
+        depart = (depart or datetime.now(UTC)).astimezone(UTC)
+        report.depart_iso = depart.isoformat()
+        report.stops = plan_stops(
+            path, distance_km=report.distance_km, duration_h=report.duration_h, depart=depart, count=stops
+        )
+        report.stops[0].label, report.stops[-1].label = report.origin, report.destination
+        lead_hours = (depart - datetime.now(UTC)).total_seconds() / 3600 + report.duration_h
+        hours = min(max(48, math.ceil(lead_hours) + 2), 384)
+
+        def fetch(stop) -> None:
+            hourly = self.provider.forecast(stop.lat, stop.lon, hours=hours)
+            stop.conditions = hour_conditions(hourly, datetime.fromisoformat(stop.eta_iso))
+            stop.hazards = route_hazards(stop.conditions)
+
+        with ThreadPoolExecutor(max_workers=len(report.stops)) as executor:
+            list(executor.map(fetch, report.stops))
+        report.attribution = [*getattr(self.provider, "attribution", ()), "geonames"]
+        if report.path == "osrm":
+            # OSRM's public server routes over OpenStreetMap data (ODbL).
+            report.attribution.append("osm")
+        return report
+
+    def handle_metar(
+        self,
+        place: str,
+        *,
+        place_info: dict[str, Any] | None = None,
+        taf: bool = True,
+        advisories: bool = True,
+    ) -> AviationReport:
+        """Decoded METAR and TAF for an ICAO code, or for the reporting airport nearest a place.
+
+        With ``advisories``, also the SIGMETs, AIRMETs and G-AIRMETs covering the airport and the
+        recent PIREPs around it.
+        """
+        offline = self.settings.offline
+        report = AviationReport(place=place)
+        metars = fetch_metars(station=place.strip().upper(), offline=offline) if is_icao(place.upper()) else []
+        if not metars:
+            place_info = place_info or self.provider.geocode(place)
+            lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+            if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+                return report
+            report.place = place_info.get("resolved") or place
+            nearby = [
+                (haversine_km(lat, lon, item["lat"], item["lon"]), item)
+                for item in fetch_metars(near=(lat, lon), offline=offline)
+                if isinstance(item.get("lat"), (int, float)) and isinstance(item.get("lon"), (int, float))
+            ]
+            if not nearby:
+                return report
+            distance, nearest = min(nearby, key=lambda pair: pair[0])
+            report.distance_km = round(distance, 1)
+            metars = [nearest]
+
+        latest = metars[0]
+        report.station = latest.get("icaoId")
+        report.name = latest.get("name")
+        report.lat, report.lon = latest.get("lat"), latest.get("lon")
+        report.metar = decode_metar(latest["rawOb"])
+        report.sources.append("aviationweather.gov METAR")
+        raw_taf = fetch_taf(report.station, offline=offline) if taf and report.station else None
+        if raw_taf:
+            report.taf = decode_taf(raw_taf)
+            report.sources.append("aviationweather.gov TAF")
+        located = isinstance(report.lat, (int, float)) and isinstance(report.lon, (int, float))
+        if advisories and report.station and located:
+            with ThreadPoolExecutor(max_workers=3) as executor:
+                airsigmets = executor.submit(fetch_airsigmets, offline=offline)
+                gairmets = executor.submit(fetch_gairmets, offline=offline)
+                pireps = executor.submit(fetch_pireps, report.station, hours=PIREP_HOURS, offline=offline)
+            now = datetime.now(UTC)
+            report.advisories = advisories_at(
+                airsigmets.result(), gairmets.result(), report.lat, report.lon, now=now
+            )
+            report.pireps = nearby_pireps(pireps.result(), report.lat, report.lon, now=now)
+            if report.advisories:
+                report.sources.append("aviationweather.gov SIGMET/AIRMET")
+            if report.pireps:
+                report.sources.append("aviationweather.gov PIREP")
+        return report
+
+    def handle_marine(self, place: str, *, place_info: dict[str, Any] | None = None) -> MarineReport:
+        """Waves, swell, sea temperature and tide turns off a coastal place."""
+        place_info = place_info or self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return MarineReport(place=place)
+        resolved = place_info.get("resolved") or place
+        hourly = get_marine_forecast(lat, lon, hours=MARINE_HOURS, offline=self.settings.offline)
+        if not hourly:
+            return MarineReport(place=resolved, tz=place_info.get("tz"))
+        report = build_marine_report(resolved, hourly)
+        report.tz = place_info.get("tz")
+        return report
+
+    def handle_sun(self, place: str, *, days: int = 1) -> SunReport:
+        """Sunrise, sunset, twilight, golden hours with forecast cloud cover, and the moon."""
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return SunReport(place=place)
+        tz_name = place_info.get("tz")
+        report = SunReport(place=place_info.get("resolved") or place, tz=tz_name, sources=["computed locally"])
+        days = max(1, min(days, SUN_MAX_DAYS))
+        hourly = self.provider.forecast(lat, lon, hours=(days + 1) * 24)
+        if hourly and any(value is not None for value in hourly.get("cloud_cover") or []):
+            report.sources.append(f"{self.provider.name} cloud cover")
+        today = datetime.now(UTC).astimezone(zone(tz_name)).date()
+        for offset in range(days):
+            report.days.append(astro_pack(astro_day(lat, lon, today + timedelta(days=offset), tz_name), hourly))
+        return report
+
+    def handle_fish(self, place: str, *, hours: int = FISH_HOURS) -> FishReport:
+        """Hourly fishing scores from solunar periods, sunrise/sunset, pressure, wind, rain and tide."""
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return FishReport(place=place, error=f"Could not find {place}.")
+        report = FishReport(place=place_info.get("resolved") or place, tz=place_info.get("tz"))
+        if self.settings.offline:
+            report.error = "The fishing index needs the forecast; wx is offline."
+            return report
+        hourly = self.provider.forecast(lat, lon, hours=hours)
+        times = (hourly or {}).get("time") or []
+        if not times:
+            report.error = f"{self.provider.name} returned no hourly forecast."
+            return report
+        hourly = {key: values[:hours] for key, values in hourly.items() if isinstance(values, list)}
+        sea_level = sea_levels(get_marine_forecast(lat, lon, hours=hours))
+        start = datetime.fromisoformat(str(hourly["time"][0]))
+        start = start if start.tzinfo else start.replace(tzinfo=UTC)
+        report.solunar = solunar_periods(lat, lon, start, start + timedelta(hours=len(hourly["time"])))
+        report.hours = score_hours(lat, lon, hourly, sea_level=sea_level, solunar=report.solunar)
+        report.windows = best_windows(report.hours)
+        report.moon = moon_phase(start)
+        report.tides = sea_level is not None
+        report.summary = summarize_fish(report.hours, report.windows, report.tz)
+        report.sources = ["computed locally", f"{self.provider.name} forecast"]
+        if report.tides:
+            report.sources.append("Open-Meteo Marine sea level")
+        return report
+
+    def handle_rain(self, place: str, *, minutes: int = NOWCAST_MINUTES) -> RainReport:
+        """Precipitation in 15-minute steps for the next ``minutes``, and when it starts or stops."""
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return RainReport(place=place, error=f"Could not find {place}.")
+        report = RainReport(place=place_info.get("resolved") or place, tz=place_info.get("tz"))
+        if self.settings.offline:
+            report.error = "The minute-by-minute forecast needs the network; wx is offline."
+            return report
+        minutely = get_minutely_precipitation(lat, lon, steps=minutes // STEP_MINUTES)
+        now = datetime.now(UTC)
+        report.steps = nowcast_steps(minutely or {}, minutes=minutes, now=now)
+        if not report.steps:
+            report.error = "Open-Meteo returned no minute-by-minute forecast."
+            return report
+        report.summary = summarize_nowcast(report.steps, units=self.settings.units, now=now)
+        report.sources.append("Open-Meteo 15-minute forecast")
+        return report
+
+    def handle_models(self, place: str, *, hours: int = MODELS_HOURS) -> ModelsReport:
+        """GFS, ECMWF and ICON hourly temperature and precipitation, and where they diverge."""
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return ModelsReport(place=place, error=f"Could not find {place}.")
+        report = ModelsReport(place=place_info.get("resolved") or place, tz=place_info.get("tz"))
+        if self.settings.offline:
+            report.error = "Model forecasts need the network; wx is offline."
+            return report
+        forecasts = fetch_model_forecasts(lat, lon, tuple(model for model, _ in MODELS), hours=hours)
+        report.runs = model_runs(forecasts or {})
+        if len(report.runs) < 2:
+            report.error = "Open-Meteo returned fewer than two models for this place."
+            return report
+        report.times = list(forecasts["time"])
+        report.divergences = find_divergences(report.times, report.runs)
+        report.summary = summarize_models(report, units=self.settings.units)
+        report.sources.append(f"Open-Meteo models: {', '.join(run.label for run in report.runs)}")
+        return report
+
+    def handle_radar(self, place: str, *, frames: int = 1) -> RadarReport:
+        """The latest ``frames`` RainViewer radar scans around a place, oldest first."""
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return RadarReport(place=place, error=f"Could not find {place}.")
+        report = RadarReport(place=place_info.get("resolved") or place, lat=lat, lon=lon, tz=place_info.get("tz"))
+        if self.settings.offline:
+            report.error = "Radar imagery needs the network; wx is offline."
+            return report
+        maps = fetch_radar_maps() or {}
+        past = [
+            frame
+            for frame in (maps.get("radar") or {}).get("past") or []
+            if isinstance(frame, dict) and isinstance(frame.get("time"), int) and isinstance(frame.get("path"), str)
+        ][-frames:]
+        host = maps.get("host")
+        if not host or not past:
+            report.error = "RainViewer did not return any radar frames."
+            return report
+        left, top, tiles = view_tiles(lat, lon, zoom=report.zoom)
+        jobs = [(frame, tile) for frame in past for tile in tiles]
+        with ThreadPoolExecutor(max_workers=min(8, len(jobs))) as executor:
+            images = list(
+                executor.map(
+                    lambda job: fetch_radar_tile(tile_url(host, job[0]["path"], *job[1], zoom=report.zoom)), jobs
+                )
+            )
+        for idx, frame in enumerate(past):
+            decoded = {}
+            for tile, data in zip(tiles, images[idx * len(tiles) : (idx + 1) * len(tiles)], strict=True):
+                try:
+                    decoded[tile] = decode_png(data) if data else None
+                except (ValueError, zlib.error):
+                    decoded[tile] = None
+            if not any(decoded.values()):
+                continue
+            view = mosaic({tile: image for tile, image in decoded.items() if image}, left, top)
+            stamp = datetime.fromtimestamp(frame["time"], UTC).isoformat()
+            report.frames.append(RadarFrame(time_iso=stamp, coverage_pct=coverage(view), image=view))
+        if not report.frames:
+            report.error = "Could not download the radar tiles."
+        else:
+            report.sources.append("RainViewer radar composite")
+        return report
+
+    def handle_history(
+        self, place: str, start: date, end: date, *, compare_to_normal: bool = False
+    ) -> HistoryReport:
+        """Daily past weather for a date range, optionally against NASA POWER normals."""
+        report = HistoryReport(place=place, start=start.isoformat(), end=end.isoformat())
+        place_info = self.provider.geocode(place)
+        lat, lon = (place_info or {}).get("lat"), (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            report.error = f"Could not find {place}."
+            return report
+        report.place = place_info.get("resolved") or place
+        daily = get_archive_daily(
+            lat, lon, start, end, tz_name=place_info.get("tz"), offline=self.settings.offline
+        )
+        if not daily:
+            report.error = "The Open-Meteo archive returned no data for these dates."
+            return report
+        report.days = history_rows(daily)
+        report.sources.append("Open-Meteo historical weather (ERA5)")
+        if compare_to_normal:
+            normals = self._climate_normals(lat, lon)
+            if normals is not None:
+                add_normals(report.days, normals)
+                report.compared_to_normal = True
+                report.sources.append("NASA POWER climatology")
+        report.summary = summarize_history(report.days)
+        return report
+
+    def _climate_normals(self, lat: float, lon: float) -> dict[str, list[float]] | None:
+        """Monthly NASA POWER normals for a point, cached on disk unless privacy mode is on."""
+        cache = NormalsCache(self.settings.state_file.parent / "normals.json")
+        if cache.get(lat, lon) is None:
+            ensure_normals(cache, [(lat, lon)], offline=self.settings.offline)
+            if not self.settings.privacy_mode:
+                cache.save()
+        return cache.get(lat, lon)
+
+    def handle_lake(self, place: str) -> LakeReport:
+        """Water temperature, open-lakes wave forecast and ice cover near a place."""
+        place_info = self.provider.geocode(place)
+        lat = (place_info or {}).get("lat")
+        lon = (place_info or {}).get("lon")
+        if not (isinstance(lat, (int, float)) and isinstance(lon, (int, float))):
+            return LakeReport(place=place)
+
+        report = LakeReport(place=place_info.get("resolved") or place)
+        lake = nearest_great_lake(lat, lon)
+        offline = self.settings.offline
+        with ThreadPoolExecutor(max_workers=3) as executor:
+            buoys_future = executor.submit(fetch_ndbc_latest, offline=offline)
+            glf_future = executor.submit(fetch_open_lakes_forecast, lake.code, offline=offline) if lake else None
+            ice_future = (
+                executor.submit(fetch_great_lakes_ice, ice_season(datetime.now(UTC).date()), offline=offline)
+                if lake
+                else None
+            )
+            buoys_text = buoys_future.result()
+            glf_text = glf_future.result() if glf_future else None
+            ice_text = ice_future.result() if ice_future else None
+
+        if lake:
+            report.lake = lake.name
+        if buoys_text:
+            report.buoy = nearest_buoy(parse_ndbc_latest(buoys_text), lat, lon)
+            if report.buoy:
+                report.sources.append(f"NDBC station {report.buoy['station']}")
+        if glf_text and lake:
+            report.wave_periods = parse_open_lakes_forecast(glf_text)
+            if report.wave_periods:
+                report.sources.append("NWS open lakes forecast")
+        if ice_text and lake:
+            latest = parse_ice_table(ice_text, lake)
+            if latest:
+                report.ice_date, report.ice_cover_pct = latest
+                report.sources.append("NOAA GLERL ice cover")
+        return report
+
+    def handle_worldview(
+        self,
+        *,
+        verbose: bool = False,
+        severe_only: bool = False,
+        regions: Sequence[str] | None = None,
+        cities: Sequence[WorldCity] | None = None,
+    ) -> Worldview:
+        """Fetch and aggregate a regional weather overview (US + Europe by default)."""
         start_time = time.perf_counter()
 
         if self.settings.offline:
-            return self._synthetic_worldview(severe_only=severe_only)
+            return self._synthetic_worldview(severe_only=severe_only, regions=regions)
+
+        unresolved: list[str] = []
+        if cities:
+            samples = self._resolve_world_cities(cities, unresolved)
+            region_keys = [key for key in samples if not regions or key in regions]
+        else:
+            samples = REGIONAL_SAMPLES
+            region_keys = list(regions or DEFAULT_WORLD_REGIONS)
+
+        alert_fetchers = {"us": fetch_us_alerts, "eu": fetch_eu_alerts}
+        today = datetime.now(UTC).date()
+        # NHC basins border the Americas, so the dashboard rides along with the US view.
+        want_tropics = "us" in region_keys and any_basin_in_season(today)
 
         # Fetch all data in parallel
         with ThreadPoolExecutor(max_workers=4) as executor:
-            us_obs_future = executor.submit(
-                fetch_openmeteo_points,
-                REGIONAL_SAMPLES["us"],
-                offline=self.settings.offline,
-            )
-            eu_obs_future = executor.submit(
-                fetch_openmeteo_points,
-                REGIONAL_SAMPLES["eu"],
-                offline=self.settings.offline,
-            )
-            us_alerts_future = executor.submit(
-                fetch_us_alerts,
-                offline=self.settings.offline,
-                severe_only=severe_only,
-            )
-            eu_alerts_future = executor.submit(
-                fetch_eu_alerts,
-                offline=self.settings.offline,
-                severe_only=severe_only,
+            obs_futures = {
+                key: executor.submit(
+                    fetch_openmeteo_points,
+                    [(lat, lon) for lat, lon, _ in samples.get(key, [])],
+                    offline=self.settings.offline,
+                )
+                for key in region_keys
+            }
+            alert_futures = {
+                key: executor.submit(
+                    alert_fetchers[key],
+                    offline=self.settings.offline,
+                    severe_only=severe_only,
+                )
+                for key in region_keys
+                if key in alert_fetchers
+            }
+            storms_future = (
+                executor.submit(fetch_nhc_active_storms, offline=self.settings.offline)
+                if want_tropics
+                else None
             )
 
-            us_obs = us_obs_future.result()
-            eu_obs = eu_obs_future.result()
-            us_alerts = us_alerts_future.result()
-            eu_alerts = eu_alerts_future.result()
+            observations = {key: future.result() for key, future in obs_futures.items()}
+            region_alerts = {key: future.result() for key, future in alert_futures.items()}
+            storms = storms_future.result() if storms_future else []
 
-        # Compute region stats
-        us_stats = self._compute_region_stats(us_obs)
-        eu_stats = self._compute_region_stats(eu_obs)
+        region_views: list[RegionView] = []
+        for key in region_keys:
+            name = _region_display_name(key)
+            obs = observations[key]
+            alerts = region_alerts.get(key, [])
+            region_views.append(
+                RegionView(
+                    name=name,
+                    summary=self._generate_region_summary(name, obs, alerts),
+                    stats=self._compute_region_stats(obs),
+                    alerts=self._summarize_alerts(alerts),
+                    samples=len(obs),
+                )
+            )
 
-        # Generate summaries
-        us_summary = self._generate_region_summary("US", us_obs, us_alerts)
-        eu_summary = self._generate_region_summary("Europe", eu_obs, eu_alerts)
+        labels = {
+            (lat, lon): (name, _region_display_name(key))
+            for key in region_keys
+            for lat, lon, name in samples.get(key, [])
+        }
+        # Normals for built-in samples are public data; user cities honour privacy mode.
+        anomalies = self._compute_anomalies(
+            observations, labels, persist=not cities or not self.settings.privacy_mode
+        )
 
-        # Prepare alert summaries
-        us_alert_summary = self._summarize_alerts(us_alerts)
-        eu_alert_summary = self._summarize_alerts(eu_alerts)
+        tropics = (
+            summarize_basins(storms, today=today, severe_only=severe_only) if want_tropics else []
+        )
 
         elapsed = time.perf_counter() - start_time
 
-        return Worldview(
-            regions=[
-                RegionView(
-                    name="US",
-                    summary=us_summary,
-                    stats=us_stats,
-                    alerts=us_alert_summary,
-                ),
-                RegionView(
-                    name="Europe",
-                    summary=eu_summary,
-                    stats=eu_stats,
-                    alerts=eu_alert_summary,
-                ),
-            ],
-            meta={
-                "samples_us": len(us_obs),
-                "samples_eu": len(eu_obs),
+        sources = ["Open-Meteo"]
+        if "us" in region_keys:
+            sources.append("NWS CAP")
+        if "eu" in region_keys:
+            sources.append("MeteoAlarm")
+        if anomalies:
+            sources.append("NASA POWER climatology")
+        if want_tropics:
+            sources.append("NHC")
+
+        meta: dict[str, Any] = {f"samples_{key}": len(observations[key]) for key in region_keys}
+        meta.update(
+            {
                 "fetch_ms": round(elapsed * 1000),
-                "sources": ["Open-Meteo", "NWS CAP", "MeteoAlarm"],
+                "sources": sources,
                 "severe_only": severe_only,
-            },
+            }
         )
+        if cities:
+            meta["custom_cities"] = True
+        if unresolved:
+            meta["unresolved_cities"] = unresolved
+
+        return Worldview(regions=region_views, meta=meta, anomalies=anomalies, tropics=tropics)
+
+    def _compute_anomalies(
+        self,
+        observations: dict[str, list[Observation]],
+        labels: dict[tuple[float, float], tuple[str, str]],
+        *,
+        persist: bool,
+        limit: int = 5,
+    ) -> list[dict[str, Any]]:
+        """Rank sampled cities by departure of today's mean temperature from normal."""
+        candidates = [
+            obs
+            for region_obs in observations.values()
+            for obs in region_obs
+            if obs.tmax_today is not None and obs.tmin_today is not None
+        ]
+        if not candidates:
+            return []
+
+        cache = NormalsCache(self.settings.state_file.parent / "normals.json")
+        ensure_normals(cache, [(obs.lat, obs.lon) for obs in candidates])
+        if persist:
+            cache.save()
+
+        today = datetime.now(UTC).date()
+        anomalies: list[dict[str, Any]] = []
+        for obs in candidates:
+            normals = cache.get(obs.lat, obs.lon)
+            if normals is None:
+                continue
+            observed = (obs.tmax_today + obs.tmin_today) / 2  # type: ignore[operator]
+            normal = normal_daily_mean(normals, today)
+            name, region = labels.get((obs.lat, obs.lon), (f"{obs.lat},{obs.lon}", ""))
+            anomalies.append(
+                {
+                    "name": name,
+                    "region": region,
+                    "anomaly": round(observed - normal, 1),
+                    "observed_mean": round(observed, 1),
+                    "normal_mean": round(normal, 1),
+                }
+            )
 
-    def _synthetic_worldview(self, severe_only: bool = False) -> Worldview:
+        anomalies.sort(key=lambda item: abs(item["anomaly"]), reverse=True)
+        return anomalies[:limit]
+
+    def _resolve_world_cities(
+        self, cities: Sequence[WorldCity], unresolved: list[str]
+    ) -> dict[str, list[tuple[float, float, str]]]:
+        """Group user-defined cities into region sample lists, geocoding names as needed."""
+
+        def resolve(city: WorldCity) -> tuple[float, float] | None:
+            if city.has_coordinates:
+                return city.lat, city.lon  # type: ignore[return-value]
+            context = self.provider.geocode(city.name)
+            if not context:
+                return None
+            return context["lat"], context["lon"]
+
+        with ThreadPoolExecutor(max_workers=8) as executor:
+            resolved = list(executor.map(resolve, cities))
+
+        samples: dict[str, list[tuple[float, float, str]]] = {}
+        for city, coords in zip(cities, resolved, strict=True):
+            if coords is None:
+                unresolved.append(city.name)
+                continue
+            region = city.region or infer_region(*coords)
+            samples.setdefault(region, []).append((*coords, city.name))
+        return samples
+
+    def _synthetic_worldview(
+        self, severe_only: bool = False, regions: Sequence[str] | None = None
+    ) -> Worldview:
         """Return deterministic synthetic data for offline mode."""
         if severe_only:
             # Severe weather only: floods, tornadoes, severe thunderstorms
@@ -613,29 +2308,31 @@ class Orchestrator:
             ]
             sources = ["Offline synthetic data"]
 
-        return Worldview(
-            regions=[
-                RegionView(
-                    name="US",
-                    summary="Varied conditions coast to coast; warm South, cooler North",
-                    stats=RegionStats(tmin=45.0, tmax=85.0, pop_max=40.0, wind_max=15.0, gust_max=25.0),
-                    alerts=us_alerts,
-                ),
-                RegionView(
-                    name="Europe",
-                    summary="Mixed weather across continent; wet northwest, dry south",
-                    stats=RegionStats(tmin=10.0, tmax=25.0, pop_max=60.0, wind_max=20.0, gust_max=35.0),
-                    alerts=eu_alerts,
-                ),
-            ],
-            meta={
-                "samples_us": 0,
-                "samples_eu": 0,
-                "fetch_ms": 0,
-                "sources": sources,
-                "severe_only": severe_only,
-            },
-        )
+        synthetic = {
+            "us": RegionView(
+                name="US",
+                summary="Varied conditions coast to coast; warm South, cooler North",
+                stats=RegionStats(tmin=45.0, tmax=85.0, pop_max=40.0, wind_max=15.0, gust_max=25.0),
+                alerts=us_alerts,
+            ),
+            "eu": RegionView(
+                name="Europe",
+                summary="Mixed weather across continent; wet northwest, dry south",
+                stats=RegionStats(tmin=10.0, tmax=25.0, pop_max=60.0, wind_max=20.0, gust_max=35.0),
+                alerts=eu_alerts,
+            ),
+            "asia": RegionView(
+                name="Asia",
+                summary="Humid and showery in the tropics; drier and cooler in the north",
+                stats=RegionStats(tmin=12.0, tmax=33.0, pop_max=70.0, wind_max=12.0, gust_max=22.0),
+                alerts=[],
+            ),
+        }
+        region_keys = [key for key in (regions or DEFAULT_WORLD_REGIONS) if key in synthetic]
+
+        meta: dict[str, Any] = {f"samples_{key}": 0 for key in region_keys}
+        meta.update({"fetch_ms": 0, "sources": sources, "severe_only": severe_only})
+        return Worldview(regions=[synthetic[key] for key in region_keys], meta=meta)
 
     def _compute_region_stats(self, observations: list[Observation]) -> RegionStats:
         """Compute aggregate statistics from observations."""
@@ -0,0 +1,100 @@
+"""External data plugins: user commands that add their own block to the Feature Pack.
+
+A plugin is any executable named in the profile's ``plugins``. For each forecast or risk
+briefing wx runs it with a JSON request on stdin (the place, the command, units and the
+forecast window) and expects one JSON object on stdout, which lands under
+``feature_pack["plugins"][name]``. That lets a backyard station, a private sensor network or a
+paid API feed the story without changes to wx.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import shlex
+import subprocess
+from dataclasses import dataclass
+from typing import Any
+
+DEFAULT_PLUGIN_TIMEOUT = 10.0
+# Bumped if the request wx writes to a plugin's stdin changes incompatibly.
+PLUGIN_PROTOCOL = 1
+# A fragment this large would crowd the rest of the Feature Pack out of the prompt.
+MAX_FRAGMENT_BYTES = 16_000
+
+
+class PluginError(RuntimeError):
+    """A plugin failed to run or returned something other than a JSON object."""
+
+
+@dataclass(frozen=True, slots=True)
+class Plugin:
+    """``command`` is an argv list; ``timeout`` is in seconds."""
+
+    name: str
+    command: tuple[str, ...]
+    timeout: float = DEFAULT_PLUGIN_TIMEOUT
+
+
+def parse_plugins(raw: Any) -> list[Plugin]:
+    """Accept ``{"soil": "~/bin/soil --json"}`` or ``{"soil": {"command": [...], "timeout": 5}}``."""
+
+    if not isinstance(raw, dict):
+        return []
+    plugins: list[Plugin] = []
+    for name, entry in raw.items():
+        spec = entry if isinstance(entry, dict) else {"command": entry}
+        command = spec.get("command")
+        if isinstance(command, str):
+            command = shlex.split(command)
+        if not isinstance(command, list) or not command or not all(isinstance(part, str) for part in command):
+            continue
+        timeout = spec.get("timeout")
+        numeric = isinstance(timeout, (int, float)) and not isinstance(timeout, bool) and timeout > 0
+        plugins.append(
+            Plugin(str(name), tuple(command), float(timeout) if numeric else DEFAULT_PLUGIN_TIMEOUT)
+        )
+    return plugins
+
+
+def plugin_request(command: str, place: dict[str, Any], *, units: str, window: dict[str, Any] | None) -> dict:
+    """The JSON document written to every plugin's stdin."""
+
+    return {
+        "protocol": PLUGIN_PROTOCOL,
+        "command": command,
+        "place": {key: place.get(key) for key in ("resolved", "lat", "lon", "tz")},
+        "units": units,
+        "window": window,
+    }
+
+
+def run_plugin(plugin: Plugin, request: dict[str, Any]) -> dict[str, Any]:
+    """Run ``plugin`` with ``request`` on stdin and return the JSON object it printed."""
+
+    try:
+        completed = subprocess.run(
+            [os.path.expanduser(plugin.command[0]), *plugin.command[1:]],
+            input=json.dumps(request),
+            capture_output=True,
+            text=True,
+            timeout=plugin.timeout,
+            check=False,
+        )
+    except subprocess.TimeoutExpired as exc:
+        raise PluginError(f"timed out after {plugin.timeout:g}s") from exc
+    except OSError as exc:
+        raise PluginError(f"could not start ({exc.strerror or exc})") from exc
+    if completed.returncode != 0:
+        stderr = completed.stderr.strip().splitlines()
+        reason = f"exited with status {completed.returncode}"
+        raise PluginError(f"{reason}: {stderr[-1]}" if stderr else reason)
+    if len(completed.stdout.encode()) > MAX_FRAGMENT_BYTES:
+        raise PluginError(f"printed more than {MAX_FRAGMENT_BYTES} bytes")
+    try:
+        fragment = json.loads(completed.stdout)
+    except json.JSONDecodeError as exc:
+        raise PluginError("did not print JSON") from exc
+    if not isinstance(fragment, dict):
+        raise PluginError("did not print a JSON object")
+    return fragment
@@ -0,0 +1,133 @@
+"""Pollen: tree/grass/weed levels and allergy decisions for ``--focus allergies``."""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime
+from typing import Any
+
+from .clock import clock_label, zone
+
+POLLEN_HOURS = 24
+# Open-Meteo pollen series (grains/m³) grouped the way allergy forecasts report them.
+POLLEN_TYPES = {
+    "tree": ("alder_pollen", "birch_pollen", "olive_pollen"),
+    "grass": ("grass_pollen",),
+    "weed": ("mugwort_pollen", "ragweed_pollen"),
+}
+# Upper bounds for Low/Moderate/High (grains/m³) from the National Allergy Bureau scale.
+POLLEN_BANDS = {"tree": (14, 89, 1499), "grass": (4, 19, 199), "weed": (9, 49, 499)}
+POLLEN_LEVELS = ("Low", "Moderate", "High", "Very High")
+HIGH_LEVELS = ("High", "Very High")
+_POLLEN_WORDS = re.compile(r"\b(allerg\w*|pollen|hay ?fever|sneez\w*|antihistamines?)\b", re.I)
+_POLLEN_DECISION = re.compile(r"\b(pollen|allerg\w*|antihistamines?|hay ?fever)\b", re.I)
+
+
+def wants_pollen(text: str | None) -> bool:
+    """True when a focus or question is about allergies (``--focus allergies``, "pollen today?")."""
+    return bool(text) and bool(_POLLEN_WORDS.search(text))
+
+
+def pollen_level(kind: str, grains: float | None) -> str | None:
+    if grains is None:
+        return None
+    return next(
+        (name for limit, name in zip(POLLEN_BANDS[kind], POLLEN_LEVELS, strict=False) if grains <= limit),
+        POLLEN_LEVELS[-1],
+    )
+
+
+def pollen_pack(
+    hourly: dict[str, list[Any]] | None, *, hours: int = POLLEN_HOURS, now: datetime | None = None
+) -> dict[str, Any] | None:
+    """Peak level per pollen type over the next ``hours`` and the worst one; None without data.
+
+    Open-Meteo only models pollen for Europe, so elsewhere every series is empty.
+    """
+
+    if not hourly:
+        return None
+    start = (now or datetime.now(UTC)).replace(minute=0, second=0, microsecond=0)
+    indexes = []
+    for idx, stamp in enumerate(hourly.get("time") or []):
+        moment = datetime.fromisoformat(str(stamp))
+        moment = moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+        if moment >= start:
+            indexes.append((idx, moment))
+    indexes = indexes[:hours]
+    types: dict[str, dict[str, Any]] = {}
+    for kind, keys in POLLEN_TYPES.items():
+        best: tuple[float, str, datetime] | None = None
+        for key in keys:
+            series = hourly.get(key) or []
+            for idx, moment in indexes:
+                value = series[idx] if idx < len(series) else None
+                if isinstance(value, (int, float)) and not isinstance(value, bool):
+                    if best is None or value > best[0]:
+                        best = (float(value), key.removesuffix("_pollen"), moment)
+        if best is not None:
+            grains, source, moment = best
+            types[kind] = {
+                "peak": round(grains, 1),
+                "level": pollen_level(kind, grains),
+                "source": source if grains > 0 else None,
+                "peak_iso": moment.isoformat(),
+            }
+    if not types:
+        return None
+    worst = max(types, key=lambda kind: (POLLEN_LEVELS.index(types[kind]["level"]), types[kind]["peak"]))
+    high = [kind for kind, info in types.items() if info["level"] in HIGH_LEVELS]
+    return {"types": types, "worst": worst, "high": high}
+
+
+def allergy_actions(pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Decisions for allergy sufferers when any pollen type reaches High."""
+
+    high = (pack or {}).get("high") or []
+    if not high:
+        return []
+    types = pack["types"]
+    named = ", ".join(_type_label(kind, types[kind]) for kind in high)
+    peak = max(high, key=lambda kind: types[kind]["peak"])
+    return [
+        f"Take allergy medication before heading out and keep windows closed: {named} pollen is"
+        f" {types[peak]['level'].lower()}, peaking around {_when(types[peak]['peak_iso'], tz_name)}.",
+        "Plan outdoor time for early morning or after rain, and shower and change clothes afterwards.",
+    ]
+
+
+def apply_pollen(response, pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Add allergy decisions the model left out when pollen is high; returns those added.
+
+    Always records a one-line ``pollen`` section when pollen data is present.
+    """
+
+    if not pack or not pack.get("types"):
+        return []
+    sections = response.sections
+    sections["pollen"] = _summary_line(pack)
+    decision_key = "answer" if "answer" in sections else "actions"
+    decisions = sections.get(decision_key)
+    decision_text = " ".join(decisions) if isinstance(decisions, list) else str(decisions or "")
+    added = [] if _POLLEN_DECISION.search(decision_text) else allergy_actions(pack, tz_name)
+    if added and decision_key == "answer":
+        sections["answer"] = " ".join([decision_text.strip(), added[0]]).strip()
+        added = added[:1]
+    elif added:
+        actions = [item for item in decisions if isinstance(item, str)] if isinstance(decisions, list) else []
+        sections["actions"] = actions + added
+    response.meta = {**(response.meta or {}), "pollen": {"worst": pack["worst"], "added": added}}
+    return added
+
+
+def _summary_line(pack: dict[str, Any]) -> str:
+    text = ", ".join(f"{_type_label(kind, info)} {info['level'].lower()}" for kind, info in pack["types"].items())
+    return text[:1].upper() + text[1:] + "."
+
+
+def _type_label(kind: str, info: dict[str, Any]) -> str:
+    return f"{kind} ({info['source']})" if info.get("source") and kind != "grass" else kind
+
+
+def _when(iso: str, tz_name: str | None) -> str:
+    return clock_label(datetime.fromisoformat(iso), zone(tz_name))
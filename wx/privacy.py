@@ -0,0 +1,45 @@
+"""Helpers that keep precise locations out of data leaving the machine in privacy mode."""
+
+from __future__ import annotations
+
+import re
+from typing import Any
+
+# One decimal place is roughly 11 km, enough for a forecast but not an address.
+COORDINATE_DECIMALS = 1
+COORDINATE_KEYS = {"lat", "lon", "latitude", "longitude"}
+# "38.8977, -77.0365" style pairs inside free text (queries, chat context, resolved names).
+_COORDINATE_PAIR = re.compile(r"(-?\d{1,3}\.\d{2,})(\s*,\s*)(-?\d{1,3}\.\d{2,})")
+
+
+def coarsen_coordinates(value: Any) -> Any:
+    """Return a copy of ``value`` with coordinates rounded to ~10 km.
+
+    Numeric ``lat``/``lon``/``latitude``/``longitude`` fields are rounded wherever they
+    appear, as are coordinate pairs embedded in strings.
+    """
+
+    if isinstance(value, dict):
+        return {
+            key: _round(item) if key in COORDINATE_KEYS else coarsen_coordinates(item)
+            for key, item in value.items()
+        }
+    if isinstance(value, list):
+        return [coarsen_coordinates(item) for item in value]
+    if isinstance(value, tuple):
+        return tuple(coarsen_coordinates(item) for item in value)
+    if isinstance(value, str):
+        return _COORDINATE_PAIR.sub(_round_pair, value)
+    return value
+
+
+def _round(value: Any) -> Any:
+    if isinstance(value, (int, float)) and not isinstance(value, bool):
+        return round(float(value), COORDINATE_DECIMALS)
+    return coarsen_coordinates(value)
+
+
+def _round_pair(match: re.Match[str]) -> str:
+    lat = round(float(match.group(1)), COORDINATE_DECIMALS)
+    lon = round(float(match.group(3)), COORDINATE_DECIMALS)
+    return f"{lat}{match.group(2)}{lon}"
@@ -0,0 +1,245 @@
+"""User profiles stored as JSON under the wx config directory."""
+
+from __future__ import annotations
+
+import json
+import os
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from .config import REGION_ALIASES
+from .favorites import Favorite, parse_favorites
+from .groups import parse_groups
+from .keystore import SECRET_KEYS, resolve_secret, secret_source
+from .plugins import Plugin, parse_plugins
+from .risk import HazardRule, parse_hazard_rules
+from .roads import RoadSegment, parse_commute, parse_roads
+
+CONFIG_DIR = Path(os.getenv("WX_CONFIG_DIR", Path.home() / ".config" / "wx"))
+DEFAULT_PROFILE = "default"
+
+
+@dataclass(slots=True)
+class WorldCity:
+    """A user-defined sampling point for the world view."""
+
+    name: str
+    region: str | None = None
+    lat: float | None = None
+    lon: float | None = None
+
+    @property
+    def has_coordinates(self) -> bool:
+        return self.lat is not None and self.lon is not None
+
+
+@dataclass(slots=True)
+class Profile:
+    """Per-user preferences loaded from ``<config>/profiles/<name>.json``."""
+
+    name: str = DEFAULT_PROFILE
+    world_cities: list[WorldCity] = field(default_factory=list)
+    favorites: list[Favorite] = field(default_factory=list)
+    # Named location groups such as "stores", addressed as @stores.
+    groups: dict[str, list[Favorite]] = field(default_factory=dict)
+    provider: str | None = None
+    ollama_url: str | None = None
+    ollama_model: str | None = None
+    model: str | None = None
+    language: str | None = None
+    units: str | None = None
+    # Place used by forecast/risk/alerts when none is given.
+    default_location: str | None = None
+    # Confidence floor for the story's actions and whether to hide or mark those below it.
+    decision_floor: str | None = None
+    decision_floor_mode: str | None = None
+    # Staleness bounds such as "2h"; see wx.staleness.
+    stale_obs: str | None = None
+    stale_forecast: str | None = None
+    # Story style preset such as "brief" or "pilot-briefing"; see wx.prompts.STYLE_PRESETS.
+    style: str | None = None
+    # wx risk threshold preset such as "construction"; see wx.risk.THRESHOLD_PRESETS.
+    thresholds: str | None = None
+    # External commands that add data to forecast and risk Feature Packs; see wx.plugins.
+    plugins: list[Plugin] = field(default_factory=list)
+    # Extra hazards for wx risk, scored from hourly forecast thresholds; see wx.risk.HazardRule.
+    hazard_rules: list[HazardRule] = field(default_factory=list)
+    # Road segments and local commute windows for wx roads; see wx.roads.
+    roads: list[RoadSegment] = field(default_factory=list)
+    commute: tuple[str, ...] | None = None
+    # Settings attribute -> API key, resolved from plaintext or the OS keyring.
+    api_keys: dict[str, str] = field(default_factory=dict)
+    # Profile key name -> "keyring" or "plaintext", for diagnostics.
+    secret_sources: dict[str, str] = field(default_factory=dict)
+    path: Path | None = None
+
+
+def profiles_dir() -> Path:
+    """Return the directory holding profile JSON files."""
+
+    return Path(os.getenv("WX_CONFIG_DIR", str(CONFIG_DIR))) / "profiles"
+
+
+def active_profile_name() -> str:
+    """Return the profile selected via ``WX_PROFILE`` or the default."""
+
+    return (os.getenv("WX_PROFILE") or DEFAULT_PROFILE).strip() or DEFAULT_PROFILE
+
+
+def load_profile(name: str | None = None) -> Profile:
+    """Load a profile by name, returning an empty profile when none exists."""
+
+    profile_name = name or active_profile_name()
+    path = profiles_dir() / f"{profile_name}.json"
+    profile = Profile(name=profile_name, path=path)
+    if not path.exists():
+        return profile
+    try:
+        data = json.loads(path.read_text())
+    except (OSError, json.JSONDecodeError):
+        return profile
+    if not isinstance(data, dict):
+        return profile
+
+    profile.world_cities = _parse_world_cities(data.get("world_cities"))
+    profile.favorites = parse_favorites(data.get("favorites"))
+    profile.groups = parse_groups(data.get("groups"))
+    profile.plugins = parse_plugins(data.get("plugins"))
+    profile.hazard_rules = parse_hazard_rules(data.get("hazard_rules"))
+    profile.roads = parse_roads(data.get("roads"))
+    profile.commute = parse_commute(data.get("commute"))
+    provider = data.get("provider")
+    profile.provider = provider.strip().lower() if isinstance(provider, str) and provider.strip() else None
+    profile.ollama_url = _optional_str(data.get("ollama_url"))
+    profile.ollama_model = _optional_str(data.get("ollama_model"))
+    profile.model = _optional_str(data.get("model"))
+    profile.language = _optional_str(data.get("language"))
+    profile.units = _optional_str(data.get("units"))
+    profile.default_location = _optional_str(data.get("default_location"))
+    floor = data.get("decision_floor")
+    numeric = isinstance(floor, (int, float)) and not isinstance(floor, bool)
+    profile.decision_floor = str(floor) if numeric else _optional_str(floor)
+    profile.decision_floor_mode = _optional_str(data.get("decision_floor_mode"))
+    profile.style = _optional_str(data.get("style"))
+    profile.thresholds = _optional_str(data.get("thresholds"))
+    for key in ("stale_obs", "stale_forecast"):
+        value = data.get(key)
+        numeric = isinstance(value, (int, float)) and not isinstance(value, bool)
+        setattr(profile, key, str(value) if numeric else _optional_str(value))
+    for key, attribute in SECRET_KEYS.items():
+        source = secret_source(data.get(key))
+        if source is None:
+            continue
+        profile.secret_sources[key] = source
+        secret = resolve_secret(data.get(key))
+        if secret:
+            profile.api_keys[attribute] = secret
+    return profile
+
+
+def read_profile_data(name: str | None = None) -> dict[str, Any]:
+    """Raw JSON object for a profile (empty when missing or unreadable)."""
+
+    path = profiles_dir() / f"{name or active_profile_name()}.json"
+    try:
+        data = json.loads(path.read_text())
+    except (OSError, json.JSONDecodeError):
+        return {}
+    return data if isinstance(data, dict) else {}
+
+
+def write_profile_data(data: dict[str, Any], name: str | None = None) -> Path:
+    """Replace a profile's JSON, readable only by the owner since it may hold keys."""
+
+    path = profiles_dir() / f"{name or active_profile_name()}.json"
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(json.dumps(data, indent=2) + "\n")
+    os.chmod(path, 0o600)
+    return path
+
+
+def _optional_str(value: Any) -> str | None:
+    return value.strip() if isinstance(value, str) and value.strip() else None
+
+
+def normalize_region(value: str) -> str:
+    """Map user-facing region names (``europe``, ``usa``) onto internal keys."""
+
+    key = value.strip().lower()
+    return REGION_ALIASES.get(key, key)
+
+
+def infer_region(lat: float, lon: float) -> str:
+    """Best-effort region bucket for coordinates without an explicit region."""
+
+    if 24 <= lat <= 50 and -125 <= lon <= -66:
+        return "us"
+    if 51 <= lat <= 72 and -170 <= lon <= -130:
+        return "us"  # Alaska
+    if 18 <= lat <= 23 and -161 <= lon <= -154:
+        return "us"  # Hawaii
+    if 35 <= lat <= 72 and -25 <= lon <= 45:
+        return "eu"
+    if -11 <= lat <= 78 and 45 < lon <= 180:
+        return "asia"
+    return "other"
+
+
+def _parse_world_cities(raw: Any) -> list[WorldCity]:
+    if not isinstance(raw, list):
+        return []
+
+    cities: list[WorldCity] = []
+    for entry in raw:
+        if isinstance(entry, str):
+            city = _city_from_string(entry)
+        elif isinstance(entry, dict):
+            city = _city_from_mapping(entry)
+        else:
+            city = None
+        if city is not None:
+            cities.append(city)
+    return cities
+
+
+def _city_from_string(value: str) -> WorldCity | None:
+    text = value.strip()
+    if not text:
+        return None
+    coords = _parse_coordinates(text)
+    if coords:
+        return WorldCity(name=text, lat=coords[0], lon=coords[1])
+    return WorldCity(name=text)
+
+
+def _city_from_mapping(entry: dict[str, Any]) -> WorldCity | None:
+    name = str(entry.get("name") or "").strip()
+    lat = _coerce_float(entry.get("lat"))
+    lon = _coerce_float(entry.get("lon"))
+    if not name and (lat is None or lon is None):
+        return None
+    region = entry.get("region") or entry.get("group")
+    return WorldCity(
+        name=name or f"{lat},{lon}",
+        region=normalize_region(str(region)) if region else None,
+        lat=lat if lon is not None else None,
+        lon=lon if lat is not None else None,
+    )
+
+
+def _parse_coordinates(value: str) -> tuple[float, float] | None:
+    if "," not in value:
+        return None
+    left, right = value.split(",", maxsplit=1)
+    try:
+        return float(left.strip()), float(right.strip())
+    except ValueError:
+        return None
+
+
+def _coerce_float(value: Any) -> float | None:
+    try:
+        return float(value) if value is not None else None
+    except (TypeError, ValueError):
+        return None
@@ -0,0 +1,212 @@
+"""Weather data providers behind a common geocode/current/forecast/alerts interface."""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime
+from typing import Any, Protocol
+
+from .fetchers import (
+    fetch_nws_hourly,
+    get_hourly_forecast,
+    get_nws_latest_observation,
+    get_nws_observation_stations,
+    get_point_context,
+    get_quick_alerts,
+    get_quick_obs,
+)
+from .tropics import compass_degrees
+
+DEFAULT_PROVIDER = "open-meteo"
+# Shown when the NWS provider's forecast comes from Open-Meteo instead.
+NWS_FALLBACK_NOTE = (
+    "No NWS hourly forecast for this point (outside NWS coverage or its grid expired); using Open-Meteo."
+)
+
+
+class WeatherProvider(Protocol):
+    """Source of point weather data.
+
+    ``current`` returns the quick-observation layout (``temp``/``feels_like`` in °C,
+    ``wind``/``gust`` in km/h); ``forecast`` returns Open-Meteo-style hourly series
+    (metric, naive UTC ``time``) so the risk engine and front detection work with
+    any provider.
+    """
+
+    name: str
+    # Keys of wx.attribution.SOURCES credited for current conditions and forecasts.
+    attribution: tuple[str, ...]
+
+    def geocode(self, place: str) -> dict[str, Any] | None: ...
+
+    def current(self, lat: float, lon: float) -> dict[str, Any] | None: ...
+
+    def forecast(self, lat: float, lon: float, *, hours: int = 48) -> dict[str, list[Any]] | None: ...
+
+    # None, unlike [], means the alerts feed could not be reached.
+    def alerts(self, lat: float, lon: float) -> list[dict[str, Any]] | None: ...
+
+
+class OpenMeteoProvider:
+    """Open-Meteo geocoding, current conditions and hourly forecasts (worldwide).
+
+    Open-Meteo has no warnings feed, so alerts come from the NWS for US points.
+    """
+
+    name = "open-meteo"
+    attribution: tuple[str, ...] = ("open-meteo",)
+
+    def __init__(self, *, offline: bool = False) -> None:
+        self.offline = offline
+
+    def geocode(self, place: str) -> dict[str, Any] | None:
+        return get_point_context(place, offline=self.offline)
+
+    def current(self, lat: float, lon: float) -> dict[str, Any] | None:
+        return get_quick_obs(lat, lon, offline=self.offline)
+
+    def forecast(self, lat: float, lon: float, *, hours: int = 48) -> dict[str, list[Any]] | None:
+        return get_hourly_forecast(lat, lon, hours=hours, offline=self.offline)
+
+    def alerts(self, lat: float, lon: float) -> list[dict[str, Any]] | None:
+        return get_quick_alerts(lat, lon, offline=self.offline)
+
+
+class NWSProvider(OpenMeteoProvider):
+    """National Weather Service station observations, hourly forecast and alerts (US only).
+
+    The NWS has no geocoder, so place names still resolve through Open-Meteo, and
+    points outside NWS coverage (where the points API 404s) fall back to Open-Meteo
+    conditions and forecasts.
+    """
+
+    name = "nws"
+    # Open-Meteo fills in outside NWS coverage.
+    attribution = ("nws", "open-meteo")
+
+    def current(self, lat: float, lon: float) -> dict[str, Any] | None:
+        stations = get_nws_observation_stations(lat, lon, offline=self.offline)
+        station_id = stations[0].get("station_id") if stations else None
+        if not station_id:
+            # No NWS stations means the point is outside NWS coverage.
+            return super().current(lat, lon)
+        obs = get_nws_latest_observation(station_id, offline=self.offline)
+        if not obs:
+            return None
+        feels_like = obs.get("heat_index_c")
+        if feels_like is None:
+            feels_like = obs.get("wind_chill_c")
+        if feels_like is None:
+            feels_like = obs.get("temp_c")
+        return {
+            "temp": obs.get("temp_c"),
+            "feels_like": feels_like,
+            "wind": _mps_to_kmh(obs.get("wind_speed_mps")),
+            "gust": _mps_to_kmh(obs.get("wind_gust_mps")),
+            "precip_last_hr": None,
+            "vis_km": obs["visibility_m"] / 1000 if obs.get("visibility_m") is not None else None,
+            "ceiling_m": _ceiling_m(obs.get("cloud_layers") or []),
+            "conditions": obs.get("text_description"),
+            "station": station_id,
+            "observed_iso": obs.get("timestamp"),
+        }
+
+    def forecast(self, lat: float, lon: float, *, hours: int = 48) -> dict[str, list[Any]] | None:
+        nws = fetch_nws_hourly(lat, lon, offline=self.offline)
+        periods = (nws or {}).get("periods", [])[:hours]
+        if not periods:
+            fallback = super().forecast(lat, lon, hours=hours)
+            if fallback and not self.offline:
+                fallback["note"] = NWS_FALLBACK_NOTE
+            return fallback
+        hourly: dict[str, Any] = nws_periods_to_hourly(periods)
+        if nws.get("update_time"):
+            # When forecasters last touched the grids; see wx.freshness.
+            hourly["issued"] = nws["update_time"]
+        if nws.get("note"):
+            hourly["note"] = nws["note"]
+        return hourly
+
+
+PROVIDERS: dict[str, type[OpenMeteoProvider]] = {
+    OpenMeteoProvider.name: OpenMeteoProvider,
+    NWSProvider.name: NWSProvider,
+}
+
+
+def get_provider(name: str | None, *, offline: bool = False) -> WeatherProvider:
+    """Instantiate a provider by name; raises ValueError for unknown names."""
+
+    key = (name or DEFAULT_PROVIDER).strip().lower()
+    if key not in PROVIDERS:
+        raise ValueError(f"Unknown provider '{name}'. Choose from: {', '.join(PROVIDERS)}.")
+    return PROVIDERS[key](offline=offline)
+
+
+def nws_periods_to_hourly(periods: list[dict[str, Any]]) -> dict[str, list[Any]]:
+    """Convert NWS hourly forecast periods into Open-Meteo hourly series."""
+
+    hourly: dict[str, list[Any]] = {
+        "time": [],
+        "temperature_2m": [],
+        "dew_point_2m": [],
+        "wind_speed_10m": [],
+        "wind_direction_10m": [],
+        "precipitation_probability": [],
+        # NWS words conditions itself rather than using WMO weather codes.
+        "conditions": [],
+    }
+    for period in periods:
+        try:
+            start = datetime.fromisoformat(str(period.get("startTime")))
+        except ValueError:
+            continue
+        hourly["time"].append(start.astimezone(UTC).strftime("%Y-%m-%dT%H:%M"))
+        hourly["temperature_2m"].append(_period_temp_c(period))
+        hourly["dew_point_2m"].append(_quantity(period.get("dewpoint")))
+        hourly["wind_speed_10m"].append(_wind_kmh(period.get("windSpeed")))
+        direction = period.get("windDirection")
+        hourly["wind_direction_10m"].append(compass_degrees(direction) if isinstance(direction, str) else None)
+        hourly["precipitation_probability"].append(_quantity(period.get("probabilityOfPrecipitation")))
+        hourly["conditions"].append(period.get("shortForecast") or None)
+    return hourly
+
+
+def _period_temp_c(period: dict[str, Any]) -> float | None:
+    value = period.get("temperature")
+    if not isinstance(value, (int, float)):
+        return None
+    if period.get("temperatureUnit") == "F":
+        return round((value - 32) * 5 / 9, 1)
+    return float(value)
+
+
+def _quantity(value: Any) -> float | None:
+    # NWS wraps values as {"unitCode": ..., "value": ...}
+    if isinstance(value, dict):
+        value = value.get("value")
+    return float(value) if isinstance(value, (int, float)) else None
+
+
+def _wind_kmh(text: Any) -> float | None:
+    # "10 mph" or "10 to 15 mph"; use the upper figure.
+    numbers = re.findall(r"\d+(?:\.\d+)?", str(text or ""))
+    if not numbers:
+        return None
+    return round(float(numbers[-1]) * 1.609, 1)
+
+
+def _mps_to_kmh(value: float | None) -> float | None:
+    return round(value * 3.6, 1) if value is not None else None
+
+
+def _ceiling_m(layers: list[dict[str, Any]]) -> float | None:
+    """Lowest broken or overcast cloud base."""
+
+    bases = [
+        _quantity(layer.get("base"))
+        for layer in layers
+        if str(layer.get("amount", "")).upper() in {"BKN", "OVC", "VV"}
+    ]
+    known = [base for base in bases if base is not None]
+    return min(known) if known else None
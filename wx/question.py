@@ -0,0 +1,114 @@
+"""Extract the place and time window from freeform weather questions."""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from datetime import datetime, timedelta
+
+WEEKDAYS = ("monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday")
+
+# Local-hour spans for parts of the day; the end may run past midnight.
+DAY_PARTS = {
+    "morning": (6, 12),
+    "afternoon": (12, 18),
+    "evening": (17, 22),
+    "night": (18, 30),
+}
+
+_TIME_PHRASE = re.compile(
+    r"\b(?:right now|now|tonight|today|this (?:morning|afternoon|evening|weekend)|"
+    r"tomorrow(?: (?:morning|afternoon|evening|night))?|"
+    r"(?:this |next |on )?(?:" + "|".join(WEEKDAYS) + r")(?: (?:morning|afternoon|evening|night))?)\b",
+    re.IGNORECASE,
+)
+_LATLON = re.compile(r"-?\d{1,2}(?:\.\d+)?\s*,\s*-?\d{1,3}(?:\.\d+)?")
+_PLACE = re.compile(
+    r"\b(?:in|at|for|near|around|over)\s+(.+?)"
+    r"(?=\s+(?:tonight|today|tomorrow|this|next|on|at|by|in|during|around|over|for)\b|[?!]|\.(?:\s|$)|$)",
+    re.IGNORECASE,
+)
+# Phrases the place pattern can catch that are really times or filler.
+_NOT_PLACES = re.compile(
+    r"^(?:the (?:morning|afternoon|evening|night|weekend)|noon|midnight|\d{1,2}(?::\d{2})?\s*(?:am|pm)?|"
+    r"all|work|school|home|my area|the area|here|a bit|a while|now)$",
+    re.IGNORECASE,
+)
+
+
+@dataclass(slots=True)
+class ParsedQuestion:
+    """Location and time hints pulled out of a question."""
+
+    text: str
+    location: str | None = None
+    time_phrase: str | None = None
+
+
+def parse_question(text: str) -> ParsedQuestion:
+    """Find the place and relative time phrase mentioned in ``text``, if any."""
+
+    parsed = ParsedQuestion(text=text)
+    time_match = _TIME_PHRASE.search(text)
+    if time_match:
+        parsed.time_phrase = time_match.group(0).lower()
+
+    latlon = _LATLON.search(text)
+    if latlon:
+        parsed.location = re.sub(r"\s+", "", latlon.group(0))
+        return parsed
+
+    candidates = [
+        candidate
+        for candidate in (match.group(1).strip(" ,") for match in _PLACE.finditer(text))
+        if candidate and not _NOT_PLACES.match(candidate) and not _TIME_PHRASE.fullmatch(candidate)
+    ]
+    # Prefer capitalised names ("in Paris for a walk"), otherwise the last mention.
+    capitalised = [candidate for candidate in candidates if candidate[0].isupper()]
+    if capitalised or candidates:
+        parsed.location = (capitalised or candidates)[-1]
+    return parsed
+
+
+def time_window(phrase: str, now: datetime) -> tuple[datetime, datetime] | None:
+    """Map a relative phrase ("tonight", "tomorrow morning") onto a window in ``now``'s timezone."""
+
+    words = phrase.lower().replace("right now", "now").split()
+    if not words:
+        return None
+    midnight = now.replace(hour=0, minute=0, second=0, microsecond=0)
+
+    if words == ["now"]:
+        return now, now + timedelta(hours=3)
+    if words == ["today"]:
+        return now, midnight + timedelta(days=1)
+    if words == ["tonight"]:
+        return _span(midnight, DAY_PARTS["night"], now)
+    if words == ["this", "weekend"]:
+        saturday = midnight + timedelta(days=(5 - now.weekday()) % 7)
+        if now.weekday() == 6:
+            saturday -= timedelta(days=1)
+        return max(now, saturday), saturday + timedelta(days=2)
+    if words[0] == "this" and len(words) > 1 and words[1] in DAY_PARTS:
+        return _span(midnight, DAY_PARTS[words[1]], now)
+
+    day_word = words[0] if words[0] not in {"this", "next", "on"} else (words[1] if len(words) > 1 else "")
+    part = words[-1] if words[-1] in DAY_PARTS else None
+    if day_word == "tomorrow":
+        day = midnight + timedelta(days=1)
+    elif day_word in WEEKDAYS:
+        ahead = (WEEKDAYS.index(day_word) - now.weekday()) % 7
+        if words[0] == "next" and ahead == 0:
+            ahead = 7
+        day = midnight + timedelta(days=ahead)
+    else:
+        return None
+    if part:
+        return _span(day, DAY_PARTS[part], now)
+    return max(now, day), day + timedelta(days=1)
+
+
+def _span(day: datetime, hours: tuple[int, int], now: datetime) -> tuple[datetime, datetime]:
+    start = day + timedelta(hours=hours[0])
+    end = day + timedelta(hours=hours[1])
+    return max(start, now) if end > now else start, end
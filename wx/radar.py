@@ -0,0 +1,365 @@
+"""``wx radar``: the RainViewer precipitation radar mosaic around a place, drawn in the terminal.
+
+Tiles are small PNGs decoded here (wx has no imaging dependency). Terminals that speak the kitty
+or sixel graphics protocols get real pixels; everything else gets unicode half-blocks, two
+pixels per character cell.
+"""
+
+from __future__ import annotations
+
+import base64
+import math
+import os
+import struct
+import zlib
+from collections.abc import Mapping
+from dataclasses import dataclass, field
+
+from rich.text import Text
+
+RADAR_ZOOM = 6
+TILE_SIZE = 256
+# Pixels of mosaic around the place: about 430 km across at mid-latitudes at zoom 6.
+VIEW_PIXELS = 256
+MAX_FRAMES = 12
+# RainViewer "Universal Blue" colours, smoothed, with snow shown in its own colours.
+COLOR_SCHEME = 2
+TILE_OPTIONS = "1_1"
+GRAPHICS_MODES = ("auto", "blocks", "kitty", "sixel")
+MARKER_RGBA = (255, 255, 255, 255)
+_PNG_SIGNATURE = b"\x89PNG\r\n\x1a\n"
+_PNG_CHANNELS = {0: 1, 2: 3, 3: 1, 4: 2, 6: 4}
+
+
+@dataclass(slots=True)
+class Raster:
+    """An RGBA image, row-major; alpha 0 means no echo."""
+
+    width: int
+    height: int
+    pixels: bytearray
+
+    @classmethod
+    def blank(cls, width: int, height: int) -> Raster:
+        return cls(width, height, bytearray(width * height * 4))
+
+    def pixel(self, x: int, y: int) -> tuple[int, int, int, int]:
+        idx = (y * self.width + x) * 4
+        red, green, blue, alpha = self.pixels[idx : idx + 4]
+        return red, green, blue, alpha
+
+    def put(self, x: int, y: int, rgba: tuple[int, int, int, int]) -> None:
+        if 0 <= x < self.width and 0 <= y < self.height:
+            idx = (y * self.width + x) * 4
+            self.pixels[idx : idx + 4] = bytes(rgba)
+
+
+@dataclass(slots=True)
+class RadarFrame:
+    """One radar scan: when it was taken, how much of the view has echoes, and the image."""
+
+    time_iso: str
+    coverage_pct: float = 0.0
+    image: Raster | None = field(default=None, repr=False)
+
+
+@dataclass(slots=True)
+class RadarReport:
+    """Radar frames around a place, oldest first."""
+
+    place: str
+    lat: float | None = None
+    lon: float | None = None
+    tz: str | None = None
+    zoom: int = RADAR_ZOOM
+    frames: list[RadarFrame] = field(default_factory=list)
+    sources: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def tile_position(lat: float, lon: float, zoom: int = RADAR_ZOOM) -> tuple[float, float]:
+    """Web Mercator pixel position of a point at ``zoom``."""
+
+    scale = TILE_SIZE * 2**zoom
+    lat = max(-85.05112878, min(85.05112878, lat))
+    x = (lon + 180.0) / 360.0 * scale
+    sin_lat = math.sin(math.radians(lat))
+    y = (0.5 - math.log((1 + sin_lat) / (1 - sin_lat)) / (4 * math.pi)) * scale
+    return x, y
+
+
+def view_width_km(lat: float, *, zoom: int = RADAR_ZOOM, size: int = VIEW_PIXELS) -> float:
+    """Ground distance across the view at a latitude."""
+
+    return 40075.016 * math.cos(math.radians(lat)) * size / (TILE_SIZE * 2**zoom)
+
+
+def view_tiles(
+    lat: float, lon: float, *, zoom: int = RADAR_ZOOM, size: int = VIEW_PIXELS
+) -> tuple[int, int, list[tuple[int, int]]]:
+    """Top-left pixel of the view centred on a point and the tiles it overlaps."""
+
+    x, y = tile_position(lat, lon, zoom)
+    left, top = round(x) - size // 2, round(y) - size // 2
+    tiles = [
+        (tx, ty)
+        for ty in range(top // TILE_SIZE, (top + size - 1) // TILE_SIZE + 1)
+        for tx in range(left // TILE_SIZE, (left + size - 1) // TILE_SIZE + 1)
+    ]
+    return left, top, tiles
+
+
+def tile_url(host: str, path: str, tx: int, ty: int, *, zoom: int = RADAR_ZOOM) -> str:
+    """RainViewer tile URL; x wraps around the antimeridian."""
+
+    return f"{host}{path}/{TILE_SIZE}/{zoom}/{tx % 2**zoom}/{ty}/{COLOR_SCHEME}/{TILE_OPTIONS}.png"
+
+
+def mosaic(tiles: Mapping[tuple[int, int], Raster], left: int, top: int, size: int = VIEW_PIXELS) -> Raster:
+    """Cut the ``size`` square at ``left``/``top`` out of the fetched tiles; missing tiles stay clear."""
+
+    view = Raster.blank(size, size)
+    for (tx, ty), tile in tiles.items():
+        for row in range(tile.height):
+            y = ty * TILE_SIZE + row - top
+            if not 0 <= y < size:
+                continue
+            start_x = tx * TILE_SIZE - left
+            lo, hi = max(0, -start_x), min(tile.width, size - start_x)
+            if lo >= hi:
+                continue
+            src = (row * tile.width + lo) * 4
+            dst = (y * size + start_x + lo) * 4
+            view.pixels[dst : dst + (hi - lo) * 4] = tile.pixels[src : src + (hi - lo) * 4]
+    return view
+
+
+def coverage(raster: Raster) -> float:
+    """Share of the view with radar echoes, in percent."""
+
+    echoes = sum(1 for alpha in raster.pixels[3::4] if alpha)
+    return round(100 * echoes / max(1, raster.width * raster.height), 1)
+
+
+def mark_center(raster: Raster, radius: int = 3) -> Raster:
+    """Copy of the raster with a small cross on the place."""
+
+    marked = Raster(raster.width, raster.height, bytearray(raster.pixels))
+    cx, cy = raster.width // 2, raster.height // 2
+    for offset in range(-radius, radius + 1):
+        marked.put(cx + offset, cy, MARKER_RGBA)
+        marked.put(cx, cy + offset, MARKER_RGBA)
+    return marked
+
+
+def downsample(raster: Raster, width: int, height: int) -> Raster:
+    """Shrink to ``width`` x ``height``, keeping the strongest echo in each block so small cells survive."""
+
+    small = Raster.blank(width, height)
+    for y in range(height):
+        y0, y1 = y * raster.height // height, max(y * raster.height // height + 1, (y + 1) * raster.height // height)
+        for x in range(width):
+            x0, x1 = x * raster.width // width, max(x * raster.width // width + 1, (x + 1) * raster.width // width)
+            best = max((raster.pixel(px, py) for py in range(y0, y1) for px in range(x0, x1)), key=lambda p: p[3])
+            small.put(x, y, best)
+    return small
+
+
+def scale_up(raster: Raster, factor: int) -> Raster:
+    """Nearest-neighbour enlargement for graphics protocols, where 256 px is tiny on modern screens."""
+
+    if factor <= 1:
+        return raster
+    scaled = bytearray()
+    for y in range(raster.height):
+        row = raster.pixels[y * raster.width * 4 : (y + 1) * raster.width * 4]
+        wide = b"".join(bytes(row[idx : idx + 4]) * factor for idx in range(0, len(row), 4))
+        scaled += wide * factor
+    return Raster(raster.width * factor, raster.height * factor, scaled)
+
+
+def half_block_lines(raster: Raster) -> list[Text]:
+    """Two pixel rows per line: ``▀`` shows the upper pixel, ``▄`` the lower, clear pixels stay blank."""
+
+    lines = []
+    for y in range(0, raster.height, 2):
+        line = Text()
+        for x in range(raster.width):
+            top = raster.pixel(x, y)
+            bottom = raster.pixel(x, y + 1) if y + 1 < raster.height else (0, 0, 0, 0)
+            if top[3] and bottom[3]:
+                line.append("▀", style=f"{_hex(top)} on {_hex(bottom)}")
+            elif top[3]:
+                line.append("▀", style=_hex(top))
+            elif bottom[3]:
+                line.append("▄", style=_hex(bottom))
+            else:
+                line.append(" ")
+        lines.append(line)
+    return lines
+
+
+def kitty_image(raster: Raster) -> str:
+    """Kitty graphics protocol escape that draws the raster at the cursor."""
+
+    payload = base64.standard_b64encode(zlib.compress(bytes(raster.pixels))).decode("ascii")
+    chunks = [payload[idx : idx + 4096] for idx in range(0, len(payload), 4096)] or [""]
+    parts = []
+    for idx, chunk in enumerate(chunks):
+        more = 1 if idx < len(chunks) - 1 else 0
+        control = f"a=T,f=32,o=z,s={raster.width},v={raster.height},m={more}" if idx == 0 else f"m={more}"
+        parts.append(f"\x1b_G{control};{chunk}\x1b\\")
+    return "".join(parts)
+
+
+def sixel_image(raster: Raster) -> str:
+    """Sixel escape for the raster, colours reduced to a 6x6x6 cube; clear pixels are left unpainted."""
+
+    def level(value: int) -> int:
+        return (value * 5 + 127) // 255
+
+    indices = [
+        level(raster.pixels[idx]) * 36 + level(raster.pixels[idx + 1]) * 6 + level(raster.pixels[idx + 2])
+        if raster.pixels[idx + 3]
+        else -1
+        for idx in range(0, len(raster.pixels), 4)
+    ]
+    used = sorted({index for index in indices if index >= 0})
+    out = [f'\x1bP0;1;0q"1;1;{raster.width};{raster.height}']
+    for index in used:
+        red, green, blue = index // 36, index // 6 % 6, index % 6
+        out.append(f"#{index};2;{red * 20};{green * 20};{blue * 20}")
+    for band in range(0, raster.height, 6):
+        rows = range(band, min(band + 6, raster.height))
+        colours = sorted({indices[y * raster.width + x] for y in rows for x in range(raster.width)} - {-1})
+        for colour in colours:
+            sixels = [
+                chr(63 + sum(1 << (y - band) for y in rows if indices[y * raster.width + x] == colour))
+                for x in range(raster.width)
+            ]
+            out.append(f"#{colour}{_run_length(''.join(sixels))}$")
+        out.append("-")
+    out.append("\x1b\\")
+    return "".join(out)
+
+
+def detect_graphics(env: Mapping[str, str] | None = None) -> str:
+    """``kitty`` or ``sixel`` when the terminal announces support, else ``blocks``."""
+
+    env = os.environ if env is None else env
+    term, program = env.get("TERM", ""), env.get("TERM_PROGRAM", "")
+    if env.get("KITTY_WINDOW_ID") or term == "xterm-kitty" or program in ("WezTerm", "ghostty"):
+        return "kitty"
+    if "sixel" in term or term.startswith(("foot", "mlterm")):
+        return "sixel"
+    return "blocks"
+
+
+def decode_png(data: bytes) -> Raster:
+    """Decode a non-interlaced PNG (any colour type, 8-bit or palette/grey below 8) to RGBA."""
+
+    if not data.startswith(_PNG_SIGNATURE):
+        raise ValueError("not a PNG image")
+    pos, header, palette, transparency, compressed = 8, None, b"", b"", bytearray()
+    while pos + 8 <= len(data):
+        length, kind = struct.unpack(">I4s", data[pos : pos + 8])
+        chunk = data[pos + 8 : pos + 8 + length]
+        pos += 12 + length
+        if kind == b"IHDR":
+            header = struct.unpack(">IIBBBBB", chunk)
+        elif kind == b"PLTE":
+            palette = chunk
+        elif kind == b"tRNS":
+            transparency = chunk
+        elif kind == b"IDAT":
+            compressed += chunk
+        elif kind == b"IEND":
+            break
+    if header is None:
+        raise ValueError("PNG has no header")
+    width, height, depth, colour, _, _, interlace = header
+    if interlace or colour not in _PNG_CHANNELS or depth > 8:
+        raise ValueError(f"unsupported PNG (colour type {colour}, depth {depth}, interlace {interlace})")
+    channels = _PNG_CHANNELS[colour]
+    stride = (width * channels * depth + 7) // 8
+    rows = _unfilter(zlib.decompress(bytes(compressed)), height, stride, max(1, channels * depth // 8))
+
+    image = Raster.blank(width, height)
+    for y, row in enumerate(rows):
+        samples = row if depth == 8 else _unpack_bits(row, depth, width * channels)
+        for x in range(width):
+            values = samples[x * channels : (x + 1) * channels]
+            image.put(x, y, _to_rgba(colour, values, depth, palette, transparency))
+    return image
+
+
+def _unfilter(raw: bytes, height: int, stride: int, bpp: int) -> list[bytearray]:
+    rows: list[bytearray] = []
+    previous = bytearray(stride)
+    pos = 0
+    for _ in range(height):
+        kind, row = raw[pos], bytearray(raw[pos + 1 : pos + 1 + stride])
+        pos += 1 + stride
+        if kind == 2:
+            row = bytearray((value + up) & 255 for value, up in zip(row, previous, strict=True))
+        elif kind in (1, 3, 4):
+            for idx in range(stride):
+                left = row[idx - bpp] if idx >= bpp else 0
+                up = previous[idx]
+                if kind == 1:
+                    row[idx] = (row[idx] + left) & 255
+                elif kind == 3:
+                    row[idx] = (row[idx] + ((left + up) >> 1)) & 255
+                else:
+                    upper_left = previous[idx - bpp] if idx >= bpp else 0
+                    row[idx] = (row[idx] + _paeth(left, up, upper_left)) & 255
+        elif kind != 0:
+            raise ValueError(f"unknown PNG filter {kind}")
+        rows.append(row)
+        previous = row
+    return rows
+
+
+def _paeth(left: int, up: int, upper_left: int) -> int:
+    estimate = left + up - upper_left
+    distances = (abs(estimate - left), abs(estimate - up), abs(estimate - upper_left))
+    return (left, up, upper_left)[distances.index(min(distances))]
+
+
+def _unpack_bits(row: bytearray, depth: int, count: int) -> list[int]:
+    per_byte, mask = 8 // depth, (1 << depth) - 1
+    return [(row[idx // per_byte] >> (8 - depth * (idx % per_byte + 1))) & mask for idx in range(count)]
+
+
+def _to_rgba(colour: int, values, depth: int, palette: bytes, transparency: bytes) -> tuple[int, int, int, int]:
+    if colour == 3:
+        index = values[0]
+        red, green, blue = palette[index * 3 : index * 3 + 3] or b"\0\0\0"
+        return red, green, blue, transparency[index] if index < len(transparency) else 255
+    grey_scale = 255 // ((1 << depth) - 1)
+    if colour == 0:
+        grey = values[0] * grey_scale
+        clear = len(transparency) >= 2 and values[0] == struct.unpack(">H", transparency[:2])[0]
+        return grey, grey, grey, 0 if clear else 255
+    if colour == 4:
+        return values[0], values[0], values[0], values[1]
+    if colour == 2:
+        clear = len(transparency) >= 6 and tuple(values) == struct.unpack(">HHH", transparency[:6])
+        return values[0], values[1], values[2], 0 if clear else 255
+    return values[0], values[1], values[2], values[3]
+
+
+def _run_length(sixels: str) -> str:
+    out, idx = [], 0
+    while idx < len(sixels):
+        end = idx
+        while end < len(sixels) and sixels[end] == sixels[idx]:
+            end += 1
+        run = end - idx
+        out.append(f"!{run}{sixels[idx]}" if run > 3 else sixels[idx] * run)
+        idx = end
+    return "".join(out)
+
+
+def _hex(rgba: tuple[int, int, int, int]) -> str:
+    return f"#{rgba[0]:02x}{rgba[1]:02x}{rgba[2]:02x}"
@@ -3,14 +3,101 @@
 from __future__ import annotations
 
 import json
-from collections.abc import Iterable
+import math
+import time
+from collections.abc import Callable, Iterable
+from dataclasses import asdict
+from datetime import UTC, date, datetime
 from typing import Any
+from zoneinfo import ZoneInfo
 
-from rich.console import Console
+from rich.console import Console, Group
+from rich.live import Live
+from rich.markup import escape
 from rich.panel import Panel
 from rich.table import Table
 from rich.text import Text
 
+from .attribution import attribution_meta, credit_line
+from .aviation import PIREP_HOURS, metar_summary, pirep_summary
+from .clock import clock_label, zone
+from .document import DOCUMENT_FORMATS, html_text, markdown_text
+from .eink import eink_text
+from .graph import sparkline
+from .language import prose_text
+from .marine import ROUGH_WAVE_M
+from .models import describe_divergence
+from .nowcast import rate_label
+from .radar import (
+    downsample,
+    half_block_lines,
+    kitty_image,
+    mark_center,
+    scale_up,
+    sixel_image,
+    view_width_km,
+)
+from .risk import level_for, spc_outlook_line
+from .schema import json_document
+from .staleness import stale_warning
+from .template import render_template, result_context
+from .tropics import compass_point
+from .units import convert, display_unit
+from .watch import snapshot_line, watch_payload
+
+# Departures from normal (°C) at or beyond this are highlighted in the world view
+ANOMALY_FLAG_DEGREES = 8.0
+# Bar colours for 6-hour snowfall rates, light to heavy (one step per 2.5 cm).
+SNOW_GRADIENT = ("cyan", "deep_sky_blue1", "dodger_blue1", "blue", "medium_purple", "magenta")
+SNOW_CHART_WIDTH = 24
+GRAPH_HEIGHT = 10
+RAIN_CHART_WIDTH = 40
+# Bars reach full width at heavy rain (mm/h) unless something heavier is forecast.
+RAIN_CHART_FULL_MMH = 10.0
+RAIN_INTENSITY_STYLES = {"light": "cyan", "moderate": "blue", "heavy": "magenta"}
+FISH_RATING_STYLES = {"excellent": "bold green", "good": "green", "fair": "yellow", "poor": "dim"}
+# Longer `wx models` runs are averaged (temperature) or summed (precipitation) to fit this width.
+MODELS_COLUMNS = 48
+MODELS_DIVERGENCE_STYLE = "yellow"
+RADAR_COLUMNS = 64
+# Graphics-protocol radar is drawn at twice the tile resolution; loops play three times.
+RADAR_PIXEL_SCALE = 2
+RADAR_FRAME_SECONDS = 0.5
+RADAR_LOOP_PASSES = 3
+GRAPH_MARKERS = (("●", "cyan"), ("◆", "magenta"))
+RISK_LEVEL_STYLES = {"Low": "green", "Moderate": "yellow", "High": "dark_orange", "Extreme": "bold red"}
+# SPC's own map colours for each categorical risk.
+# Ensemble agreement indicator in the confidence panel title.
+AGREEMENT_MARKERS = {"high": "[green]●●●[/green]", "moderate": "[yellow]●●○[/yellow]", "low": "[red]●○○[/red]"}
+SPC_OUTLOOK_STYLES = {
+    "marginal": "green",
+    "slight": "yellow",
+    "enhanced": "dark_orange",
+    "moderate": "red",
+    "high": "bold magenta",
+}
+# Longer horizons show every 2nd/3rd hour so the table stays about a screen tall.
+HOURLY_TABLE_ROWS = 24
+# First keyword found in the conditions text picks the icon (fog before freezing, partly before cloud).
+CONDITION_ICONS = (
+    ("thunder", "⛈"),
+    ("fog", "🌫"),
+    ("haze", "🌫"),
+    ("snow", "❄"),
+    ("freezing", "🌨"),
+    ("sleet", "🌨"),
+    ("shower", "🌦"),
+    ("drizzle", "🌦"),
+    ("rain", "🌧"),
+    ("partly", "⛅"),
+    ("mostly clear", "🌤"),
+    ("mostly sunny", "🌤"),
+    ("cloud", "☁"),
+    ("overcast", "☁"),
+    ("clear", "☀"),
+    ("sunny", "☀"),
+)
+
 
 def render_result(
     result,
@@ -19,12 +106,32 @@ def render_result(
     json_mode: bool,
     debug: bool,
     verbose: bool,
+    template: str | None = None,
+    output_format: str = "rich",
 ) -> None:
+    if template is not None:
+        _print_template(console, template, result_context(_result_payload(result)))
+        return
+    if output_format == "eink" and not json_mode and getattr(result, "data", None) is None:
+        console.print(eink_text(result_context(_result_payload(result))), markup=False, highlight=False, soft_wrap=True)
+        return
+    if output_format in DOCUMENT_FORMATS and not json_mode and getattr(result, "data", None) is None:
+        context = result_context(_result_payload(result))
+        text = markdown_text(context) if output_format == "markdown" else html_text(context)
+        console.print(text, markup=False, highlight=False, soft_wrap=True, end="")
+        return
     if json_mode:
         console.print(_result_to_json(result))
         return
+    if getattr(result, "data", None) is not None:
+        console.print(json.dumps(result.data, indent=2, ensure_ascii=True))
+        return
 
     response = result.response
+    if response.sections.get("answer"):
+        _render_answer(result, console=console, debug=debug)
+        return
+
     word_limit = None if verbose else 400
     limiter = _WordLimiter(word_limit)
 
@@ -44,30 +151,49 @@ def render_result(
         expand=False,
     )
 
+    # Zones section (area alerts only)
+    zones_panel = None
+    if response.sections.get("zones"):
+        limiter.set_section_budget("zones")
+        zones_panel = Panel(
+            limiter.join_bullets(response.sections["zones"], default="No zones affected."),
+            title="Zones",
+            expand=False,
+        )
+
     # Risk section
     limiter.set_section_budget("risk")
-    risk_content = _build_risk_cards(response.sections.get("risk_cards"), limiter)
-    risk_panel = Panel(
-        risk_content,
-        title="Risk Cards",
-        expand=False,
-    )
+    risk_scores = result.feature_pack.get("risk_scores") if result.command == "risk" else None
+    if risk_scores:
+        risk_panel = Panel(_build_risk_matrix(risk_scores), title="Risk Matrix", expand=False)
+    else:
+        risk_content = _build_risk_cards(response.sections.get("risk_cards"), limiter)
+        risk_panel = Panel(
+            risk_content,
+            title="Risk Cards",
+            expand=False,
+        )
 
     # Confidence section
     limiter.set_section_budget("confidence")
+    agreement = (result.feature_pack.get("ensemble") or {}).get("agreement")
+    confidence_text = limiter.consume(str(response.sections.get("confidence", "Confidence not available.")))
+    if response.sections.get("model_agreement"):
+        confidence_text += f"\n[dim]{response.sections['model_agreement']}[/dim]"
     confidence_panel = Panel(
-        limiter.consume(str(response.sections.get("confidence", "Confidence not available."))),
-        title=f"Confidence ({response.confidence.get('value', '?')}%)",
+        confidence_text,
+        title=f"Confidence ({response.confidence.get('value', '?')}%)"
+        + (f" · models {AGREEMENT_MARKERS[agreement]}" if agreement in AGREEMENT_MARKERS else ""),
         expand=False,
     )
 
     # Actions section
     limiter.set_section_budget("actions")
-    actions_panel = Panel(
-        limiter.join_bullets(response.sections.get("actions"), default="No actions provided."),
-        title="Actions",
-        expand=False,
-    )
+    actions_text = limiter.join_bullets(response.sections.get("actions"), default="No actions provided.")
+    if response.sections.get("low_confidence"):
+        # Never trimmed: it stands in for the actions the decision floor held back.
+        actions_text += f"\n[dim]{escape(response.sections['low_confidence'])}[/dim]"
+    actions_panel = Panel(actions_text, title="Actions", expand=False)
 
     # Assumptions section
     limiter.set_section_budget("assumptions")
@@ -79,8 +205,24 @@ def render_result(
         expand=False,
     )
 
+    # Never trimmed by the word limit.
+    _print_as_of(console, result.feature_pack)
+    _print_stale(console, result.feature_pack)
+    _print_missing(console, result.feature_pack)
+    _print_safety(console, response)
+    _print_spc_outlook(console, result)
+    _print_air_quality(console, response, result.feature_pack)
+    _print_pollen(console, response, result.feature_pack)
+    _print_uv(console, response, result.feature_pack)
     console.print(summary_panel)
+    if zones_panel is not None:
+        console.print(zones_panel)
     console.print(timeline_panel)
+    hourly = getattr(result, "hourly", None)
+    if result.command == "forecast" and hourly:
+        console.print(_hourly_panel(hourly, result.feature_pack))
+    if result.command == "forecast" and result.feature_pack.get("astro"):
+        _print_astro(console, result.feature_pack)
     console.print(risk_panel)
     console.print(confidence_panel)
     console.print(actions_panel)
@@ -88,6 +230,8 @@ def render_result(
 
     bottom_line_text = limiter.consume(response.bottom_line or "Bottom line unavailable.")
     console.print(Text(bottom_line_text, style="bold"))
+    _print_issuance(console, result.feature_pack)
+    _print_credits(console, result)
 
     if debug:
         console.print(Panel(json.dumps(result.debug, indent=2), title="Debug"))
@@ -96,6 +240,7 @@ def render_result(
                 json.dumps(
                     {
                         "provider": response.provider,
+                        "fallbacks": (response.meta or {}).get("fallbacks", []),
                         "confidence": response.confidence,
                         "used_feature_fields": response.used_feature_fields,
                     },
@@ -106,6 +251,260 @@ def render_result(
         )
 
 
+def render_prompt_comparison(results, *, console: Console, json_mode: bool = False) -> None:
+    """Show forecasts written from the same Feature Pack by several prompt versions side by side."""
+    if json_mode:
+        payload = {
+            "feature_pack": results[0].feature_pack if results else {},
+            "versions": [
+                {"prompt_version": (result.response.meta or {}).get("prompt_version"), **_result_payload(result)["response"]}
+                for result in results
+            ],
+        }
+        console.print(json_document("prompt_comparison", payload))
+        return
+
+    table = Table(show_lines=True, expand=True)
+    table.add_column("", style="bold", no_wrap=True)
+    for result in results:
+        version = (result.response.meta or {}).get("prompt_version") or "–"
+        table.add_column(f"{version} · {result.response.provider}", ratio=1)
+
+    def bullets(key: str) -> list[str]:
+        return ["\n".join(f"• {item}" for item in _as_list(result.response.sections.get(key))) for result in results]
+
+    table.add_row("Summary", *[" ".join(_as_list(result.response.sections.get("summary"))) for result in results])
+    table.add_row("Timeline", *bullets("timeline"))
+    table.add_row("Actions", *bullets("actions"))
+    table.add_row("Bottom line", *[result.response.bottom_line or "–" for result in results])
+    table.add_row(
+        "Confidence", *[f"{result.response.confidence.get('value', '?')}%" for result in results]
+    )
+    table.add_row(
+        "Words",
+        *[str(len(prose_text(result.response.sections, result.response.bottom_line).split())) for result in results],
+    )
+    table.add_row("Time", *[f"{result.timings.get('forecaster', 0.0):.1f}s" for result in results])
+    console.print(table)
+
+
+def _as_list(value: Any) -> list[str]:
+    if isinstance(value, list):
+        return [str(item) for item in value if isinstance(item, str)]
+    return [str(value)] if value else []
+
+
+def _render_answer(result, *, console: Console, debug: bool) -> None:
+    """Concise rendering for direct question answers."""
+    response = result.response
+    place = (result.feature_pack.get("place") or {}).get("resolved")
+    window = (result.feature_pack.get("window") or {}).get("label")
+    title = " · ".join(part for part in (place, window) if part) or "Answer"
+    console.print(Panel(str(response.sections["answer"]), title=title, expand=False))
+    _print_safety(console, response)
+    _print_spc_outlook(console, result)
+    _print_air_quality(console, response, result.feature_pack)
+    _print_pollen(console, response, result.feature_pack)
+    _print_uv(console, response, result.feature_pack)
+    confidence = response.confidence or {}
+    console.print(
+        f"[dim]Confidence {confidence.get('value', '?')}% — {confidence.get('rationale', '')}[/dim]"
+    )
+    _print_credits(console, result)
+    if debug:
+        console.print(Panel(json.dumps(result.debug, indent=2), title="Debug"))
+
+
+def _print_as_of(console: Console, feature_pack: dict[str, Any]) -> None:
+    as_of = feature_pack.get("as_of")
+    if as_of:
+        tz = zone((feature_pack.get("place") or {}).get("tz"))
+        local = datetime.fromisoformat(as_of).astimezone(tz)
+        when = f"{local:%a %b} {local.day} {local.year}, {clock_label(local, tz, day=False)}"
+        console.print(
+            f"[bold magenta]As of {when}[/bold magenta] [dim]· archived model runs issued before then;"
+            " no observations or alerts[/dim]"
+        )
+
+
+def _print_stale(console: Console, feature_pack: dict[str, Any]) -> None:
+    warning = stale_warning(feature_pack.get("stale"))
+    if warning:
+        console.print(Panel(Text(warning, style="bold red"), border_style="red", expand=False))
+
+
+def _print_missing(console: Console, feature_pack: dict[str, Any]) -> None:
+    warnings = feature_pack.get("warnings")
+    if warnings:
+        body = Text("\n".join(f"• {warning}" for warning in warnings), style="yellow")
+        console.print(Panel(body, title="Partial data", border_style="yellow", expand=False))
+
+
+def _print_issuance(console: Console, feature_pack: dict[str, Any]) -> None:
+    issuance = feature_pack.get("issuance")
+    if issuance:
+        console.print(Text(issuance["label"], style="yellow" if issuance.get("overdue") else "dim"))
+    if feature_pack.get("provider_note"):
+        console.print(Text(feature_pack["provider_note"], style="yellow"))
+
+
+def _print_credits(console: Console, result) -> None:
+    line = credit_line(getattr(result, "attribution", None) or [])
+    if line:
+        console.print(Text(line, style="dim"))
+
+
+def _print_safety(console: Console, response) -> None:
+    safety = _as_list(response.sections.get("safety"))
+    if safety:
+        console.print(
+            Panel("\n".join(safety), title="Safety", border_style="bold red", expand=False)
+        )
+
+
+def _print_spc_outlook(console: Console, result) -> None:
+    """Boxed SPC severe outlook when the place is in a risk area; ``wx risk`` also notes when it isn't."""
+    outlook = result.feature_pack.get("spc_outlook")
+    line = spc_outlook_line(outlook)
+    if not line:
+        return
+    style = SPC_OUTLOOK_STYLES.get(outlook.get("category"))
+    if style:
+        console.print(Panel(line, title="Severe weather outlook", border_style=style, expand=False))
+    elif result.command == "risk":
+        console.print(Text(line, style="dim"))
+
+
+def _print_air_quality(console: Console, response, feature_pack: dict[str, Any]) -> None:
+    line = response.sections.get("air_quality")
+    if not isinstance(line, str) or not line:
+        return
+    elevated = (feature_pack.get("air_quality") or {}).get("elevated")
+    style = "bold yellow" if elevated else "dim"
+    console.print(Text(f"Air quality: {line}", style=style))
+
+
+def _print_pollen(console: Console, response, feature_pack: dict[str, Any]) -> None:
+    line = response.sections.get("pollen")
+    if not isinstance(line, str) or not line:
+        return
+    high = (feature_pack.get("pollen") or {}).get("high")
+    console.print(Text(f"Pollen: {line}", style="bold yellow" if high else "dim"))
+
+
+def _print_uv(console: Console, response, feature_pack: dict[str, Any]) -> None:
+    line = response.sections.get("uv")
+    if not isinstance(line, str) or not line:
+        return
+    protect = (feature_pack.get("uv") or {}).get("protect")
+    console.print(Text(f"UV: {line}", style="bold yellow" if protect else "dim"))
+
+
+def _print_astro(console: Console, feature_pack: dict[str, Any]) -> None:
+    """One line of sun, golden-hour and moon times under the hourly table."""
+    astro = feature_pack["astro"]
+    tz = zone((feature_pack.get("place") or {}).get("tz"))
+    parts = []
+    if astro.get("sunrise_iso") and astro.get("sunset_iso"):
+        parts.append(
+            f"Sun {_clock(astro['sunrise_iso'], tz)}–{_clock(astro['sunset_iso'], tz)}"
+            f" ({_day_length(astro['day_length_minutes'])})"
+        )
+    elif astro.get("polar"):
+        parts.append(astro["polar"].capitalize())
+    golden = astro.get("golden_evening")
+    if golden:
+        light = f", {golden['light']}" if golden.get("light") else ""
+        parts.append(f"golden hour {_clock(golden['start_iso'], tz)}–{_clock(golden['end_iso'], tz)}{light}")
+    moon = astro.get("moon") or {}
+    if moon:
+        moon_text = f"Moon {moon['name'].lower()} {moon['illumination'] * 100:.0f}%"
+        if astro.get("moonrise_iso"):
+            moon_text += f", rises {_clock(astro['moonrise_iso'], tz)}"
+        parts.append(moon_text)
+    console.print(Text(" · ".join(parts), style="dim"))
+
+
+def _clock(iso: str | None, tz) -> str:
+    if not iso:
+        return "–"
+    local = datetime.fromisoformat(iso).astimezone(tz)
+    return local.strftime("%I:%M %p").lstrip("0")
+
+
+def _day_length(minutes: int | None) -> str:
+    return "–" if minutes is None else f"{minutes // 60}h {minutes % 60:02d}m"
+
+
+def condition_icon(conditions: str | None) -> str:
+    """Weather icon for NWS/WMO conditions text ("Chance Rain Showers" → 🌦)."""
+    text = (conditions or "").lower()
+    return next((icon for keyword, icon in CONDITION_ICONS if keyword in text), " ")
+
+
+def _hourly_panel(rows: list[dict[str, Any]], feature_pack: dict[str, Any]) -> Panel:
+    """Table of the forecast window hour by hour, under temperature and precip sparklines and the
+    day's departure from normal."""
+    units = feature_pack.get("units") or {}
+    temp_unit = f"°{units.get('temp', 'F')}"
+    speed_unit = "m/s" if units.get("wind") == "mps" else units.get("wind", "mph")
+    try:
+        tz = ZoneInfo((feature_pack.get("place") or {}).get("tz") or "UTC")
+    except (KeyError, ValueError):
+        tz = ZoneInfo("UTC")
+
+    temps = [row.get("temp") for row in rows]
+    probs = [row.get("precip_prob") for row in rows]
+    known = [value for value in temps if value is not None]
+    trends = Text()
+    if known:
+        trends.append("Temp   ", style="bold")
+        trends.append(sparkline(temps), style="red")
+        trends.append(f"  {min(known):.0f}–{max(known):.0f}{temp_unit}\n")
+    if any(value is not None for value in probs):
+        trends.append("Precip ", style="bold")
+        trends.append(sparkline(probs, low=0, high=100), style="blue")
+        trends.append(f"  max {max(value for value in probs if value is not None):.0f}%")
+    normals = feature_pack.get("normals")
+    if normals:
+        if trends.plain and not trends.plain.endswith("\n"):
+            trends.append("\n")
+        trends.append("Normal ", style="bold")
+        warm = normals["high_departure_c"] + normals["low_departure_c"] > 0
+        trends.append(normals["label"], style=("red" if warm else "blue") if normals.get("notable") else "")
+
+    table = Table(show_edge=False, pad_edge=False)
+    table.add_column("Time")
+    table.add_column("Temp", justify="right")
+    table.add_column("Wind", justify="right")
+    table.add_column("Precip", justify="right")
+    table.add_column("Conditions")
+    step = -(-len(rows) // HOURLY_TABLE_ROWS)
+    previous_day = None
+    for row in rows[::step]:
+        stamp = datetime.fromisoformat(row["time_iso"]).astimezone(tz)
+        label = stamp.strftime("%I %p").lstrip("0")
+        if stamp.date() != previous_day:
+            label = f"{stamp.strftime('%a')} {label}"
+            previous_day = stamp.date()
+        temp = f"{row['temp']:.0f}{temp_unit}" if row.get("temp") is not None else "–"
+        wind = "–"
+        if row.get("wind") is not None:
+            direction = compass_point(row["wind_dir"]) if row.get("wind_dir") is not None else ""
+            wind = f"{direction} {row['wind']:.0f} {speed_unit}".lstrip()
+            if row.get("gust") is not None:
+                wind += f" G{row['gust']:.0f}"
+        prob = row.get("precip_prob")
+        precip = f"{prob:.0f}%" if prob is not None else "–"
+        if prob is not None and prob >= 50:
+            precip = f"[blue]{precip}[/blue]"
+        conditions = row.get("conditions") or ""
+        table.add_row(label, temp, wind, precip, f"{condition_icon(conditions)} {conditions}".rstrip())
+
+    title = f"Next {len(rows)}h" + (f" (every {step}h)" if step > 1 else "")
+    return Panel(Group(trends, table) if trends else table, title=title, expand=False)
+
+
 class _WordLimiter:
     """Apply a global word cap across sections with fair allocation."""
 
@@ -117,6 +516,7 @@ class _WordLimiter:
         self.section_allocations = {
             "summary": 0.30,
             "timeline": 0.25,
+            "zones": 0.25,
             "risk": 0.20,
             "confidence": 0.10,
             "actions": 0.10,
@@ -231,7 +631,64 @@ def _build_risk_cards(cards: Any, limiter: _WordLimiter) -> str:
     return "\n".join(lines).rstrip()
 
 
+def _build_risk_matrix(scores: list[dict[str, Any]]) -> Table:
+    """Colour-coded hazard matrix from risk engine scores."""
+    table = Table(box=None, show_edge=False, pad_edge=False)
+    table.add_column("Hazard")
+    table.add_column("Level")
+    table.add_column("Score", justify="right")
+    table.add_column("Peak")
+    table.add_column("Drivers")
+    for item in scores:
+        style = RISK_LEVEL_STYLES.get(item.get("level"), "")
+        bar = "■" * round(item.get("score", 0) / 10)
+        peak = item.get("peak_iso") or "—"
+        if peak != "—":
+            peak = datetime.fromisoformat(peak).strftime("%a %HZ")
+        table.add_row(
+            str(item.get("hazard", "")).title(),
+            Text(str(item.get("level", "")), style=style),
+            Text(f"{bar} {item.get('score', 0):.0f}", style=style),
+            peak,
+            ", ".join(item.get("drivers") or []),
+        )
+    return table
+
+
+def _worldview_payload(worldview) -> dict[str, Any]:
+    return {
+        "regions": [
+            {
+                "name": region.name,
+                "summary": region.summary,
+                "stats": {
+                    "tmin": region.stats.tmin,
+                    "tmax": region.stats.tmax,
+                    "pop_max": region.stats.pop_max,
+                    "wind_max": region.stats.wind_max,
+                    "gust_max": region.stats.gust_max,
+                },
+                "alerts": region.alerts,
+                "samples": region.samples,
+            }
+            for region in worldview.regions
+        ],
+        "anomalies": worldview.anomalies,
+        "tropics": worldview.tropics,
+        "meta": worldview.meta,
+    }
+
+
+def _print_template(console: Console, template: str, context: dict[str, Any]) -> None:
+    """Print user template output verbatim (no Rich markup or highlighting)."""
+    console.print(render_template(template, context), markup=False, highlight=False, soft_wrap=True)
+
+
 def _result_to_json(result) -> str:
+    return json_document("story", _result_payload(result))
+
+
+def _result_payload(result) -> dict[str, Any]:
     payload = {
         "command": result.command,
         "query": result.query,
@@ -242,35 +699,29 @@ def _result_to_json(result) -> str:
             "used_feature_fields": result.response.used_feature_fields,
             "bottom_line": result.response.bottom_line,
             "provider": result.response.provider,
+            "backend": (result.response.meta or {}).get("backend"),
+            "fallbacks": (result.response.meta or {}).get("fallbacks", []),
+            "language_check": (result.response.meta or {}).get("language_check"),
         },
+        "hourly": getattr(result, "hourly", []),
         "timings": result.timings,
         "debug": result.debug,
+        "meta": {"attribution": attribution_meta(getattr(result, "attribution", None) or [])},
     }
-    return json.dumps(payload, indent=2)
+    if getattr(result, "data", None) is not None:
+        payload["data"] = result.data
+    return payload
 
 
-def render_worldview(worldview, *, console: Console, json_mode: bool = False, verbose: bool = False) -> None:
+def render_worldview(
+    worldview, *, console: Console, json_mode: bool = False, verbose: bool = False, template: str | None = None
+) -> None:
     """Render worldview aggregate summary."""
+    if template is not None:
+        _print_template(console, template, _worldview_payload(worldview))
+        return
     if json_mode:
-        payload = {
-            "regions": [
-                {
-                    "name": region.name,
-                    "summary": region.summary,
-                    "stats": {
-                        "tmin": region.stats.tmin,
-                        "tmax": region.stats.tmax,
-                        "pop_max": region.stats.pop_max,
-                        "wind_max": region.stats.wind_max,
-                        "gust_max": region.stats.gust_max,
-                    },
-                    "alerts": region.alerts,
-                }
-                for region in worldview.regions
-            ],
-            "meta": worldview.meta,
-        }
-        console.print(json.dumps(payload, indent=2, ensure_ascii=True))
+        console.print(json_document("worldview", _worldview_payload(worldview)))
         return
 
     # Check if severe weather mode
@@ -280,6 +731,16 @@ def render_worldview(worldview, *, console: Console, json_mode: bool = False, ve
     for region in worldview.regions:
         console.print(f"[bold]{region.name}[/bold] — {region.summary}")
 
+    if worldview.anomalies:
+        unusual = "; ".join(_format_anomaly(item) for item in worldview.anomalies)
+        console.print(f"\n[bold]Most unusual[/bold] — {unusual}")
+
+    if worldview.tropics:
+        console.print("\n[bold]Tropics[/bold]")
+        for basin in worldview.tropics:
+            storms = "; ".join(_format_storm(storm) for storm in basin["storms"]) or "quiet"
+            console.print(f"  {basin['basin']} — {storms}")
+
     # Top risks with severe weather highlighting
     all_alerts = []
     for region in worldview.regions:
@@ -303,13 +764,1045 @@ def render_worldview(worldview, *, console: Console, json_mode: bool = False, ve
 
     if verbose:
         # Show metadata
-        meta_text = f"Samples: US={worldview.meta.get('samples_us', 0)}, EU={worldview.meta.get('samples_eu', 0)} | "
+        samples = ", ".join(f"{region.name}={region.samples}" for region in worldview.regions)
+        meta_text = f"Samples: {samples} | "
         meta_text += f"Fetch time: {worldview.meta.get('fetch_ms', 0)}ms | "
         meta_text += f"Sources: {', '.join(worldview.meta.get('sources', []))}"
         if severe_only:
             meta_text += " | Filter: SEVERE ONLY"
         console.print(f"\n[dim]{meta_text}[/dim]")
 
+    unresolved = worldview.meta.get("unresolved_cities")
+    if unresolved:
+        console.print(f"[yellow]Could not locate profile cities: {', '.join(unresolved)}[/yellow]")
+
+
+def render_snowmap(
+    outlook, *, console: Console, json_mode: bool = False, units: str = "imperial", template: str | None = None
+) -> None:
+    """Render expected/percentile snowfall and an accumulation chart for the event."""
+    payload = {
+        "place": outlook.place,
+        "expected_cm": outlook.expected_cm,
+        "p10_cm": outlook.p10_cm,
+        "p90_cm": outlook.p90_cm,
+        "timeline": outlook.timeline,
+        "sources": outlook.sources,
+    }
+    if template is not None:
+        _print_template(console, template, payload)
+        return
+    if json_mode:
+        console.print(json_document("snowmap", payload))
+        return
+
+    if not outlook.has_data:
+        console.print(f"No snowfall forecast available for {outlook.place}.")
+        return
+
+    def amount(cm: float) -> str:
+        return f"{cm / 2.54:.1f} in" if units == "imperial" else f"{cm:.1f} cm"
+
+    console.print(f"[bold]Snowfall outlook[/bold] — {outlook.place}")
+    headline = f"Expected {amount(outlook.expected_cm)}"
+    if outlook.p10_cm is not None and outlook.p90_cm is not None:
+        headline += f" · 10th pct {amount(outlook.p10_cm)} · 90th pct {amount(outlook.p90_cm)}"
+    console.print(headline)
+
+    if not outlook.timeline:
+        console.print("[dim]No accumulating snow in the forecast window.[/dim]")
+    else:
+        peak = max(entry["cumulative_cm"] for entry in outlook.timeline) or 1.0
+        console.print()
+        for entry in outlook.timeline:
+            width = max(1, round(entry["cumulative_cm"] / peak * SNOW_CHART_WIDTH))
+            color = SNOW_GRADIENT[min(int(entry["amount_cm"] / 2.5), len(SNOW_GRADIENT) - 1)]
+            label = datetime.fromisoformat(entry["start_iso"]).strftime("%a %HZ")
+            bar = "█" * width if entry["cumulative_cm"] else "·"
+            total = amount(entry["cumulative_cm"])
+            console.print(f"  {label:<7} [{color}]{bar:<{SNOW_CHART_WIDTH}}[/{color}] {total}")
+
+    console.print(f"\n[dim]Sources: {', '.join(outlook.sources)}[/dim]")
+
+
+def render_favorites(
+    reports, *, console: Console, units: str, json_mode: bool = False, title: str = "Favorites"
+) -> None:
+    """Compact comparison table of the profile's favorite places (or a location group's)."""
+    if json_mode:
+        console.print(json_document("favorites", [asdict(report) for report in reports]))
+        return
+
+    columns = ("Now", "Conditions", "Wind", "Next 12h", "Precip", "Alerts")
+    table = Table(title=title, expand=False)
+    for column in ("Favorite" if title == "Favorites" else "Site", "Place", *columns):
+        table.add_column(column, justify="right" if column in ("Now", "Precip") else "left")
+    for report in reports:
+        if report.snapshot is None:
+            table.add_row(report.label, report.place, "–", f"[red]{report.error or 'unavailable'}[/red]", "", "", "", "")
+            continue
+        cells = _place_cells(report.snapshot, units)
+        table.add_row(report.label, report.snapshot.place, *(cells[column] for column in columns))
+    console.print(table)
+    for report in reports:
+        warning = stale_warning(report.snapshot.stale) if report.snapshot is not None else None
+        if warning:
+            console.print(Text(f"{report.label}: {warning}", style="bold red"))
+
+
+def render_roads(reports, *, console: Console, json_mode: bool = False) -> None:
+    """One row per road segment and commute window with its fog and black-ice scores."""
+    if json_mode:
+        console.print(json_document("roads", [asdict(report) for report in reports]), soft_wrap=True)
+        return
+
+    table = Table(title="Commute roads", expand=False)
+    for column in ("Road", "Commute", "Fog", "Ice", "Why"):
+        table.add_column(column, justify="right" if column in ("Fog", "Ice") else "left")
+    for report in reports:
+        name = escape(report.name) + (" (bridge)" if report.bridge else "")
+        if report.error or not report.windows:
+            reason = report.error or "No commute window in the forecast"
+            table.add_row(name, f"[red]{escape(reason)}[/red]", "", "", "")
+            continue
+        tz = zone(report.tz)
+        for idx, window in enumerate(report.windows):
+            start = clock_label(datetime.fromisoformat(window.start_iso), tz)
+            end = clock_label(datetime.fromisoformat(window.end_iso), tz, day=False)
+            table.add_row(
+                name if idx == 0 else "",
+                f"{start}–{end}",
+                _road_score(window.fog),
+                _road_score(window.ice),
+                escape("; ".join(window.drivers)),
+            )
+    console.print(table)
+
+
+def _road_score(score: int) -> str:
+    level = level_for(score)
+    style = RISK_LEVEL_STYLES.get(level, "")
+    return f"[{style}]{score} {level}[/{style}]"
+
+
+def render_group_alerts(report, *, console: Console, json_mode: bool = False) -> None:
+    """The group's alert count, then one row per alerted site (most serious first); quiet sites
+    are listed on one line."""
+    if json_mode:
+        console.print(json_document("group_alerts", asdict(report)), soft_wrap=True)
+        return
+
+    console.print(f"[bold]@{escape(report.group)}[/bold] · {escape(report.summary or '')}")
+    flagged = [site for site in report.sites if site.alerts or site.error]
+    if flagged:
+        table = Table(expand=False)
+        for column in ("Site", "Place", "Alerts"):
+            table.add_column(column)
+        for site in flagged:
+            if site.error:
+                table.add_row(escape(site.label), escape(site.place), f"[red]{escape(site.error)}[/red]")
+                continue
+            lines = []
+            for alert in site.alerts:
+                style = "bold red" if alert.get("severity") in ("Extreme", "Severe") else "yellow"
+                line = f"[{style}]{escape(str(alert.get('event') or 'Alert'))}[/]"
+                expires = alert.get("expires_iso")
+                if expires:
+                    try:
+                        line += f" until {clock_label(datetime.fromisoformat(expires), zone(site.tz))}"
+                    except ValueError:
+                        pass
+                lines.append(line)
+            table.add_row(escape(site.label), escape(site.resolved or site.place), "\n".join(lines))
+        console.print(table)
+    quiet = [site.label for site in report.sites if not site.alerts and not site.error]
+    if quiet and flagged:
+        console.print(f"[dim]No alerts: {escape(', '.join(quiet))}[/dim]")
+
+
+def render_compare(result, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """Places as columns: conditions now, next-day highlights and alerts, then the AI comparison."""
+    response = result.response
+    if json_mode:
+        payload: dict[str, Any] = {"places": [asdict(report) for report in result.reports]}
+        if response is not None:
+            payload["comparison"] = {
+                "text": _comparison_text(response),
+                "provider": response.provider,
+                "confidence": response.confidence,
+            }
+        console.print(json_document("compare", payload))
+        return
+
+    table = Table(title="Compare", show_lines=True, expand=False)
+    table.add_column("", style="bold", no_wrap=True)
+    cells = []
+    for report in result.reports:
+        table.add_column(report.snapshot.place if report.snapshot else report.place)
+        cells.append(_place_cells(report.snapshot, units) if report.snapshot else {})
+    hours = next((report.snapshot.outlook_hours for report in result.reports if report.snapshot), 24)
+    rows = ("Now", "Conditions", "Wind", f"Next {hours}h", "Precip", "Rain total", "Peak gust", "Alerts")
+    for row in rows:
+        key = "Next 12h" if row.startswith("Next") else row
+        values = []
+        for report, place_cells in zip(result.reports, cells, strict=True):
+            if report.snapshot is None:
+                values.append(f"[red]{report.error or 'unavailable'}[/red]" if row == "Now" else "")
+            else:
+                values.append(place_cells[key])
+        table.add_row(row, *values)
+    console.print(table)
+    if response is not None:
+        console.print(Panel(_comparison_text(response), title=f"Comparison · {response.provider}", expand=False))
+
+
+def _comparison_text(response) -> str:
+    answer = response.sections.get("answer")
+    return str(answer) if answer else response.summary_text or "No comparison available."
+
+
+def _place_cells(snapshot, units: str) -> dict[str, str]:
+    """Formatted table cells for one place's snapshot."""
+
+    def temp(value: Any) -> str:
+        if not isinstance(value, (int, float)):
+            return "–"
+        return f"{convert(value, 'temp', units):.0f}{display_unit('temp', units)}"
+
+    def speed(value: Any) -> str:
+        if not isinstance(value, (int, float)):
+            return "–"
+        return f"{convert(value, 'speed', units):.0f} {display_unit('speed', units)}"
+
+    current, outlook, alerts = snapshot.current, snapshot.outlook, snapshot.alerts
+    wind = speed(current.get("wind"))
+    if isinstance(current.get("wind"), (int, float)) and isinstance(current.get("gust"), (int, float)):
+        wind += f" G{convert(current['gust'], 'speed', units):.0f}"
+    precip = outlook.get("precip_prob_max")
+    total = outlook.get("precip_total")
+    severe = any(alert.get("severity") in ("Extreme", "Severe") for alert in alerts)
+    alert_text = ", ".join(str(alert.get("event") or "Alert") for alert in alerts[:2])
+    if len(alerts) > 2:
+        alert_text += f" +{len(alerts) - 2}"
+    feel = f"\n[dim]{snapshot.feel}[/dim]" if snapshot.feel else ""
+    return {
+        "Now": temp(current.get("temp")) + ("\n[bold red]stale[/bold red]" if snapshot.stale else ""),
+        "Conditions": str(current.get("conditions") or "–") + feel,
+        "Wind": wind,
+        "Next 12h": f"{temp(outlook.get('temp_min'))}–{temp(outlook.get('temp_max'))}" if outlook else "–",
+        "Precip": f"{precip:.0f}%" if isinstance(precip, (int, float)) else "–",
+        "Rain total": (
+            f"{convert(total, 'depth', units):g} {display_unit('depth', units)}"
+            if isinstance(total, (int, float))
+            else "–"
+        ),
+        "Peak gust": speed(outlook.get("gust_max")),
+        "Alerts": f"[{'bold red' if severe else 'yellow'}]{alert_text}[/]" if alerts else "[dim]none[/dim]",
+    }
+
+
+def render_graph(series_list, *, console: Console, json_mode: bool = False, template: str | None = None) -> None:
+    """Plot one forecast variable per hour as a terminal chart, overlaying compared places."""
+    if template is not None:
+        first = series_list[0]
+        context = {
+            "place": first.place,
+            "variable": first.variable,
+            "label": first.label,
+            "unit": first.unit,
+            "times": first.times,
+            "values": first.values,
+            "series": [asdict(series) for series in series_list],
+        }
+        _print_template(console, template, context)
+        return
+    if json_mode:
+        console.print(json_document("graph", [asdict(series) for series in series_list]))
+        return
+
+    charted = [series for series in series_list if series.has_data]
+    for series in series_list:
+        if not series.has_data:
+            console.print(f"No {series.label.lower()} forecast available for {series.place}.")
+    if not charted:
+        return
+
+    first = charted[0]
+    values = [value for series in charted for value in series.values if value is not None]
+    low, high = min(values), max(values)
+    span = (high - low) or 1.0
+    columns = max(len(series.values) for series in charted)
+
+    grid = [[" "] * columns for _ in range(GRAPH_HEIGHT)]
+    for (marker, color), series in zip(GRAPH_MARKERS, charted, strict=False):
+        for col, value in enumerate(series.values):
+            if value is None:
+                continue
+            row = GRAPH_HEIGHT - 1 - round((value - low) / span * (GRAPH_HEIGHT - 1))
+            # Markers where compared places coincide are merged into one white dot.
+            grid[row][col] = "[white]◉[/white]" if grid[row][col] != " " else f"[{color}]{marker}[/{color}]"
+
+    console.print(f"[bold]{first.label}[/bold] ({first.unit}) — next {columns}h")
+    for row_idx, row in enumerate(grid):
+        if row_idx in (0, GRAPH_HEIGHT // 2, GRAPH_HEIGHT - 1):
+            value = high - span * row_idx / (GRAPH_HEIGHT - 1)
+            axis = f"{value:>8.1f} ┤"
+        else:
+            axis = " " * 8 + " │"
+        console.print(axis + "".join(row))
+    console.print(" " * 9 + "└" + "─" * columns)
+
+    ticks = [" "] * (columns + 8)
+    for col in range(0, columns, 12):
+        label = datetime.fromisoformat(first.times[col]).strftime("%a %HZ")
+        ticks[col : col + len(label)] = list(label)
+    console.print(" " * 10 + "".join(ticks).rstrip())
+
+    legend = []
+    for (marker, color), series in zip(GRAPH_MARKERS, charted, strict=False):
+        known = [value for value in series.values if value is not None]
+        legend.append(f"[{color}]{marker}[/{color}] {series.place} ({min(known):g}–{max(known):g} {series.unit})")
+    console.print("\n" + "   ".join(legend))
+
+
+def render_marine(report, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """Sea state now, a 3-hourly wave/swell table, the peak and tide turns for ``wx marine``."""
+    if json_mode:
+        console.print(json_document("marine", asdict(report)))
+        return
+    if report.now is None:
+        console.print(f"No marine forecast for {report.place}; try a coastal place or lat,lon offshore.")
+        return
+
+    tz = zone(report.tz)
+    imperial = units == "imperial"
+
+    def height(meters: Any) -> str:
+        if not isinstance(meters, (int, float)):
+            return "–"
+        return f"{meters * 3.281:.1f} ft" if imperial else f"{meters:.1f} m"
+
+    def water(celsius: Any) -> str:
+        if not isinstance(celsius, (int, float)):
+            return "–"
+        return f"{celsius * 9 / 5 + 32:.0f}°F" if imperial else f"{celsius:.1f}°C"
+
+    def period(seconds: Any) -> str:
+        return f"{seconds:.0f} s" if isinstance(seconds, (int, float)) else "–"
+
+    def direction(degrees: Any) -> str:
+        return f" from {compass_point(degrees)}" if isinstance(degrees, (int, float)) else ""
+
+    now = report.now
+    console.print(f"[bold]Marine conditions[/bold] — {report.place}")
+    console.print(
+        f"Waves {height(now['wave_height_m'])} every {period(now['wave_period_s'])}{direction(now['wave_dir'])}; "
+        f"swell {height(now['swell_height_m'])} at {period(now['swell_period_s'])}{direction(now['swell_dir'])}; "
+        f"water {water(now['water_temp_c'])}"
+    )
+    if report.peak:
+        rough = report.peak["wave_height_m"] >= ROUGH_WAVE_M
+        style = "bold yellow" if rough else "dim"
+        peak_time = clock_label(datetime.fromisoformat(report.peak["time_iso"]), tz)
+        console.print(f"[{style}]Highest waves {height(report.peak['wave_height_m'])} around {peak_time}[/{style}]")
+
+    table = Table(expand=False)
+    for column in ("Time", "Waves", "Period", "Wind waves", "Swell", "Swell period", "Water"):
+        table.add_column(column, justify="left" if column == "Time" else "right")
+    for row in report.periods:
+        table.add_row(
+            clock_label(datetime.fromisoformat(row["time_iso"]), tz),
+            height(row["wave_height_m"]),
+            period(row["wave_period_s"]),
+            height(row["wind_wave_height_m"]),
+            height(row["swell_height_m"]) + direction(row["swell_dir"]),
+            period(row["swell_period_s"]),
+            water(row["water_temp_c"]),
+        )
+    console.print(table)
+    if report.tides:
+        turns = [
+            f"{turn['type']} {clock_label(datetime.fromisoformat(turn['time_iso']), tz)} ({height(turn['height_m'])})"
+            for turn in report.tides
+        ]
+        console.print(f"Tides (modeled): {'; '.join(turns)}")
+    console.print(f"\n[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def render_sun(report, *, console: Console, json_mode: bool = False) -> None:
+    """Sun, twilight, golden-hour and moon times per day for ``wx sun``."""
+    if json_mode:
+        console.print(json_document("sun", asdict(report)))
+        return
+    if not report.days:
+        console.print(f"Could not find {report.place}.")
+        return
+
+    tz = zone(report.tz)
+    table = Table(show_edge=False, pad_edge=False)
+    for name in ("Day", "Dawn", "Sunrise", "Golden AM", "Golden PM", "Sunset", "Dusk", "Daylight", "Moon"):
+        table.add_column(name, justify="left" if name in ("Day", "Golden AM", "Golden PM", "Moon") else "right")
+
+    def golden(window: dict[str, Any] | None) -> str:
+        if not window:
+            return "–"
+        text = f"{_clock(window['start_iso'], tz)}–{_clock(window['end_iso'], tz)}"
+        if window.get("cloud_cover") is not None:
+            text += f"\n[dim]{window['cloud_cover']:.0f}% cloud, {window['light']}[/dim]"
+        return text
+
+    for day in report.days:
+        moon = day["moon"]
+        rise_set = " ".join(
+            f"{arrow}{_clock(day[key], tz)}" for arrow, key in (("↑", "moonrise_iso"), ("↓", "moonset_iso")) if day[key]
+        )
+        table.add_row(
+            datetime.fromisoformat(day["date"]).strftime("%a %b %d"),
+            _clock(day["dawn_iso"], tz),
+            _clock(day["sunrise_iso"], tz),
+            golden(day["golden_morning"]),
+            golden(day["golden_evening"]),
+            _clock(day["sunset_iso"], tz),
+            _clock(day["dusk_iso"], tz),
+            day["polar"] or _day_length(day["day_length_minutes"]),
+            f"{moon['name']} {moon['illumination'] * 100:.0f}%" + (f"\n[dim]{rise_set}[/dim]" if rise_set else ""),
+        )
+    zone_name = datetime.fromisoformat(report.days[0]["date"]).replace(tzinfo=tz).tzname() or "UTC"
+    console.print(f"[bold]Sun and moon[/bold] — {report.place} (times in {zone_name})")
+    console.print(table)
+    console.print(f"\n[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def render_metar(report, *, console: Console, json_mode: bool = False, raw: bool = False) -> None:
+    """Decoded METAR and TAF for ``wx metar``, with the flight category up front."""
+    if json_mode:
+        console.print(json_document("metar", asdict(report)))
+        return
+    if report.metar is None:
+        console.print(f"No METAR found for {report.place}.")
+        return
+
+    title = f"[bold]{report.station}[/bold]"
+    if report.name:
+        title += f" — {report.name}"
+    if report.distance_km is not None:
+        title += f" ({report.distance_km:.0f} km from {report.place})"
+    console.print(title)
+    metar = report.metar
+    console.print(f"METAR {metar.get('observed') or ''}: {_category_text(metar.get('category'))} {metar_summary(metar)}")
+    if raw:
+        console.print(f"[dim]{metar['raw']}[/dim]")
+    if report.taf:
+        console.print(f"\n[bold]TAF[/bold] {report.taf.get('valid') or ''}")
+        for period in report.taf["periods"]:
+            console.print(f"  [cyan]{period['change']}[/cyan]: {_category_text(period.get('category'))} {metar_summary(period)}")
+        if raw:
+            console.print(f"[dim]{report.taf['raw']}[/dim]")
+    if report.advisories:
+        console.print("\n[bold]Advisories[/bold] over the airport")
+        for advisory in report.advisories:
+            until = datetime.fromisoformat(advisory.valid_to).strftime(" · until %H%MZ") if advisory.valid_to else ""
+            severity = f"{advisory.severity} " if advisory.severity else ""
+            console.print(
+                f"  [yellow]{escape(advisory.kind)}[/yellow] {escape(severity + advisory.hazard)} · {advisory.band}{until}"
+            )
+            if raw and advisory.raw:
+                console.print(f"[dim]{escape(advisory.raw)}[/dim]")
+    if report.pireps:
+        console.print(f"\n[bold]PIREPs[/bold] in the last {PIREP_HOURS} h")
+        for pirep in report.pireps:
+            when = datetime.fromisoformat(pirep.observed).strftime("%H%MZ") if pirep.observed else "time n/a"
+            urgent = "[bold red]Urgent[/bold red] " if pirep.urgent else ""
+            distance = f" {pirep.distance_km:.0f} km away" if pirep.distance_km is not None else ""
+            console.print(f"  [cyan]{when}[/cyan]{distance}: {urgent}{escape(pirep_summary(pirep))}")
+            if raw:
+                console.print(f"[dim]{escape(pirep.raw)}[/dim]")
+    console.print(f"\n[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def _category_text(category: str | None) -> str:
+    color = {"VFR": "green", "MVFR": "blue", "IFR": "red", "LIFR": "magenta"}.get(category or "")
+    return f"[bold {color}]●[/]" if color else ""
+
+
+def render_lake(
+    report, *, console: Console, json_mode: bool = False, units: str = "imperial", template: str | None = None
+) -> None:
+    """Render water temperature, open-lakes wave periods and ice cover for boaters and anglers."""
+    if template is not None:
+        _print_template(console, template, asdict(report))
+        return
+    if json_mode:
+        console.print(json_document("lake", asdict(report)))
+        return
+
+    if not (report.buoy or report.wave_periods or report.ice_cover_pct is not None):
+        console.print(f"No lake observations or forecasts available near {report.place}.")
+        return
+
+    title = f"[bold]Lake conditions[/bold] — {report.place}"
+    if report.lake:
+        title += f" ({report.lake})"
+    console.print(title)
+
+    buoy = report.buoy
+    if buoy:
+        readings = []
+        if buoy["water_temp_c"] is not None:
+            celsius = buoy["water_temp_c"]
+            readings.append(f"water {celsius * 9 / 5 + 32:.0f}°F" if units == "imperial" else f"water {celsius:.1f}°C")
+        if buoy["wave_height_m"] is not None:
+            meters = buoy["wave_height_m"]
+            readings.append(f"waves {meters * 3.281:.1f} ft" if units == "imperial" else f"waves {meters:.1f} m")
+        distance = buoy["distance_km"]
+        away = f"{distance / 1.609:.0f} mi" if units == "imperial" else f"{distance:.0f} km"
+        console.print(f"Buoy {buoy['station']} ({away} away): {', '.join(readings)}")
+
+    if report.wave_periods:
+        console.print("\n[bold]Open lakes forecast[/bold]")
+        for period in report.wave_periods:
+            console.print(f"  [cyan]{period['period']}[/cyan]: {period['text']}")
+
+    if report.ice_cover_pct is not None:
+        console.print(f"\nIce cover: {report.ice_cover_pct:.1f}% (as of {report.ice_date})")
+
+    console.print(f"\n[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def render_route(report, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """Conditions along the way: one row per stop, timed to when the drive reaches it."""
+    if json_mode:
+        console.print(json_document("route", asdict(report)))
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    tz = zone(report.tz)
+    imperial = units == "imperial"
+
+    def distance(km: float) -> str:
+        return f"{km / 1.609:.0f} mi" if imperial else f"{km:.0f} km"
+
+    hours, minutes = divmod(round(report.duration_h * 60), 60)
+    how = "by road" if report.path == "osrm" else "straight-line estimate"
+    console.print(
+        f"[bold]{report.origin} → {report.destination}[/bold]: {distance(report.distance_km)}, "
+        f"about {hours}h {minutes:02d}m ({how})"
+    )
+    table = Table(title=f"Leaving {clock_label(datetime.fromisoformat(report.depart_iso), tz)}", expand=False)
+    for column in ("Arrive", "Distance", "Point", "Temp", "Conditions", "Precip", "Gust", "Watch for"):
+        table.add_column(column, justify="right" if column in ("Distance", "Temp", "Precip", "Gust") else "left")
+    for stop in report.stops:
+        conditions = stop.conditions or {}
+        temp, gust, chance = conditions.get("temp"), conditions.get("gust"), conditions.get("precip_prob")
+        table.add_row(
+            clock_label(datetime.fromisoformat(stop.eta_iso), tz),
+            distance(stop.km),
+            stop.label or f"{stop.lat:.2f},{stop.lon:.2f}",
+            f"{convert(temp, 'temp', units):.0f}{display_unit('temp', units)}" if isinstance(temp, (int, float)) else "–",
+            str(conditions.get("conditions") or ("–" if conditions else "[dim]beyond forecast[/dim]")),
+            f"{chance:.0f}%" if isinstance(chance, (int, float)) else "–",
+            f"{convert(gust, 'speed', units):.0f} {display_unit('speed', units)}" if isinstance(gust, (int, float)) else "–",
+            f"[bold yellow]{', '.join(stop.hazards)}[/bold yellow]" if stop.hazards else "",
+        )
+    console.print(table)
+    if not any(stop.hazards for stop in report.stops):
+        console.print("[green]No weather hazards flagged along the way.[/green]")
+    credits = credit_line(report.attribution)
+    if credits:
+        console.print(Text(credits, style="dim"))
+
+
+def render_rain(report, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """The start/stop summary, then one bar per 15 minutes scaled to precipitation rate."""
+    if json_mode:
+        console.print(json_document("rain", asdict(report)))
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    tz = zone(report.tz)
+    console.print(f"[bold]{report.place}[/bold]: {report.summary}")
+    top = max(RAIN_CHART_FULL_MMH, *(step["rate_mmh"] for step in report.steps))
+    table = Table(show_header=False, show_edge=False, pad_edge=False, box=None)
+    for justify in ("right", "left", "right", "left"):
+        table.add_column(justify=justify)
+    for step in report.steps:
+        rate = step["rate_mmh"]
+        style = "white" if step["snow"] else RAIN_INTENSITY_STYLES.get(step["intensity"], "dim")
+        bar = "█" * round(rate / top * RAIN_CHART_WIDTH) if step["intensity"] else "·"
+        amount = rate_label(rate, units) if step["intensity"] else ""
+        kind = f"{step['intensity']} snow" if step["snow"] and step["intensity"] else step["intensity"] or ""
+        table.add_row(
+            clock_label(datetime.fromisoformat(step["start_iso"]), tz, day=False),
+            f"[{style}]{bar}[/{style}]",
+            amount,
+            f"[dim]{kind}[/dim]",
+        )
+    console.print(table)
+    console.print(Text(credit_line(["open-meteo"]) or "", style="dim"))
+
+
+def render_fish(report, *, console: Console, json_mode: bool = False) -> None:
+    """The best windows with their reasons, under an hourly score sparkline that highlights them."""
+    if json_mode:
+        console.print(json_document("fish", asdict(report)), soft_wrap=True)
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    tz = zone(report.tz)
+    console.print(f"[bold]Fishing[/bold] — {report.place}: {report.summary}")
+    moon = report.moon
+    console.print(f"[dim]{moon['name']}, {moon['illumination'] * 100:.0f}% lit[/dim]")
+    best = {
+        row["time_iso"]
+        for window in report.windows
+        for row in report.hours
+        if window["start_iso"] <= row["time_iso"] < window["end_iso"]
+    }
+    bars = sparkline([row["score"] for row in report.hours], low=0, high=100)
+    chart = Text()
+    for row, bar in zip(report.hours, bars, strict=False):
+        chart.append(bar, style="bold green" if row["time_iso"] in best else "dim")
+    first = clock_label(datetime.fromisoformat(report.hours[0]["time_iso"]), tz)
+    console.print(Text.assemble(f"{first} ", chart, f" {len(report.hours)}h"))
+    for window in report.windows:
+        style = FISH_RATING_STYLES.get(window["rating"], "dim")
+        start = clock_label(datetime.fromisoformat(window["start_iso"]), tz)
+        end = clock_label(datetime.fromisoformat(window["end_iso"]), tz, day=False)
+        reasons = ", ".join(window["factors"])
+        console.print(f"  {start}–{end} · [{style}]{window['score']} {window['rating']}[/{style}] · {reasons}")
+    periods = [
+        f"{period['event']} {clock_label(datetime.fromisoformat(period['time_iso']), tz)}"
+        for period in report.solunar
+        if period["kind"] == "major"
+    ]
+    if periods:
+        console.print(f"[dim]Major periods: {'; '.join(periods)}[/dim]")
+    if not report.tides:
+        console.print("[dim]No tide here; scored without it.[/dim]")
+    console.print(f"[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def render_models(report, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """One row per model: temperature and precipitation sparklines on shared scales, with the hours
+    they diverge marked underneath and listed."""
+    if json_mode:
+        payload = {**asdict(report), "meta": {"attribution": attribution_meta(["open-meteo"])}}
+        console.print(json_document("models", payload), soft_wrap=True)
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    size = max(1, math.ceil(len(report.times) / MODELS_COLUMNS))
+    temps = {run.label: _buckets(run.temperature, size, average=True) for run in report.runs}
+    precip = {run.label: _buckets(run.precipitation, size, average=False) for run in report.runs}
+    known_temps = [value for series in temps.values() for value in series if value is not None]
+    wettest = max((value for series in precip.values() for value in series if value is not None), default=0.0)
+    temp_unit, depth_unit = display_unit("temp", units), display_unit("depth", units)
+
+    console.print(f"[bold]{report.place}[/bold]: {report.summary}")
+    table = Table(box=None, pad_edge=False)
+    table.add_column("Model", style="bold")
+    table.add_column(f"Temperature ({temp_unit})")
+    table.add_column("Low–high", justify="right")
+    table.add_column(f"Precipitation ({depth_unit})")
+    table.add_column("Total", justify="right")
+    for run in report.runs:
+        low = convert(run.low_c, "temp", units) if run.low_c is not None else None
+        high = convert(run.high_c, "temp", units) if run.high_c is not None else None
+        total = convert(run.precip_mm, "depth", units)
+        table.add_row(
+            run.label,
+            f"[red]{sparkline(temps[run.label], low=min(known_temps), high=max(known_temps))}[/red]",
+            "–" if low is None else f"{low:.0f}–{high:.0f}",
+            f"[blue]{sparkline(precip[run.label], low=0.0, high=max(wettest, 1.0))}[/blue]",
+            f"{total:.2f}" if units == "imperial" else f"{total:.1f}",
+        )
+    if report.divergences:
+        strips = []
+        for reason in ("temperature", "precipitation"):
+            marks = [" "] * math.ceil(len(report.times) / size)
+            for block in report.divergences:
+                if reason in block["reasons"]:
+                    for hour in range(block["first_hour"], block["first_hour"] + block["hours"]):
+                        marks[hour // size] = "▲"
+            strips.append(f"[{MODELS_DIVERGENCE_STYLE}]{''.join(marks)}[/{MODELS_DIVERGENCE_STYLE}]")
+        table.add_row("[dim]diverge[/dim]", strips[0], "", strips[1], "")
+    console.print(table)
+    start = datetime.fromisoformat(report.times[0]).replace(tzinfo=UTC)
+    step = "hour" if size == 1 else f"{size} hours"
+    console.print(Text(f"From {clock_label(start, zone(report.tz))}, one column per {step}.", style="dim"))
+    for block in report.divergences:
+        line = describe_divergence(block, units=units, tz_name=report.tz)
+        console.print(f"[{MODELS_DIVERGENCE_STYLE}]▲[/{MODELS_DIVERGENCE_STYLE}] {escape(line)}")
+    console.print(Text(credit_line(["open-meteo"]) or "", style="dim"))
+
+
+def _buckets(values: list[float | None], size: int, *, average: bool) -> list[float | None]:
+    """Group hourly values ``size`` at a time, averaging or summing the known ones."""
+    grouped: list[float | None] = []
+    for start in range(0, len(values), size):
+        known = [value for value in values[start : start + size] if value is not None]
+        if not known:
+            grouped.append(None)
+        else:
+            grouped.append(sum(known) / len(known) if average else sum(known))
+    return grouped
+
+
+def render_radar(
+    report,
+    *,
+    console: Console,
+    json_mode: bool = False,
+    graphics: str = "blocks",
+    loop: bool = False,
+    sleep: Callable[[float], None] = time.sleep,
+) -> None:
+    """Radar around the place as half-blocks or kitty/sixel pixels; ``loop`` animates every frame."""
+    if json_mode:
+        payload = {
+            "place": report.place,
+            "lat": report.lat,
+            "lon": report.lon,
+            "zoom": report.zoom,
+            "frames": [{"time_iso": frame.time_iso, "coverage_pct": frame.coverage_pct} for frame in report.frames],
+            "sources": report.sources,
+            "error": report.error,
+            "meta": {"attribution": attribution_meta(["rainviewer"])},
+        }
+        console.print(json_document("radar", payload))
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    tz = zone(report.tz)
+    footer = Text(
+        f"White cross: {report.place} · about {view_width_km(report.lat, zoom=report.zoom):.0f} km across · "
+        f"{credit_line(['rainviewer'])}",
+        style="dim",
+    )
+
+    def header(frame) -> Text:
+        when = clock_label(datetime.fromisoformat(frame.time_iso), tz)
+        echoes = f"echoes over {frame.coverage_pct:.0f}% of the view" if frame.coverage_pct else "no echoes nearby"
+        return Text.assemble(("Radar", "bold"), f" — {report.place} · {when} · {echoes}")
+
+    def blocks(frame) -> Group:
+        cols = max(8, min(RADAR_COLUMNS, console.width) // 2 * 2)
+        small = mark_center(downsample(frame.image, cols, cols), radius=1)
+        return Group(header(frame), *half_block_lines(small), footer)
+
+    def draw(frame) -> None:
+        console.print(header(frame))
+        image = scale_up(mark_center(frame.image), RADAR_PIXEL_SCALE)
+        console.file.write((kitty_image(image) if graphics == "kitty" else sixel_image(image)) + "\n")
+        console.file.flush()
+        console.print(footer)
+
+    frames = report.frames if loop else report.frames[-1:]
+    if graphics == "blocks" and len(frames) > 1:
+        with Live(console=console, auto_refresh=False) as live:
+            for _ in range(RADAR_LOOP_PASSES):
+                for frame in frames:
+                    live.update(blocks(frame), refresh=True)
+                    sleep(RADAR_FRAME_SECONDS)
+    elif graphics == "blocks":
+        console.print(blocks(frames[0]))
+    elif len(frames) > 1:
+        # Redraw each frame from the top of a cleared screen so the image stays in place.
+        console.file.write("\x1b[2J")
+        for _ in range(RADAR_LOOP_PASSES):
+            for frame in frames:
+                console.file.write("\x1b[H")
+                draw(frame)
+                sleep(RADAR_FRAME_SECONDS)
+    else:
+        draw(frames[0])
+
+
+def render_history(report, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """Daily highs, lows, precipitation and wind for ``wx history``, with departures from normal."""
+    if json_mode:
+        console.print(json_document("history", asdict(report)))
+        return
+    if report.error:
+        console.print(f"[red]{report.error}[/red]")
+        return
+
+    def temp(celsius: Any) -> str:
+        return f"{convert(celsius, 'temp', units):.0f}{display_unit('temp', units)}" if celsius is not None else "–"
+
+    def departure(celsius: Any) -> str:
+        if celsius is None:
+            return ""
+        value = celsius * 9 / 5 if units == "imperial" else celsius
+        color = "red" if value >= 0.5 else "blue" if value <= -0.5 else "dim"
+        return f" [{color}]({value:+.0f})[/{color}]"
+
+    def depth(mm: Any) -> str:
+        return f"{convert(mm, 'depth', units):g} {display_unit('depth', units)}" if mm else "–"
+
+    table = Table(show_edge=False, pad_edge=False)
+    for name in ("Date", "High", "Low", "Precip", "Snow", "Gust", "Conditions"):
+        table.add_column(name, justify="left" if name in ("Date", "Conditions") else "right")
+    for row in report.days:
+        snow = row["snow_cm"]
+        table.add_row(
+            date.fromisoformat(row["date"]).strftime("%a %b %d %Y"),
+            temp(row["temp_max_c"]) + departure(row.get("max_departure_c")),
+            temp(row["temp_min_c"]) + departure(row.get("min_departure_c")),
+            depth(row["precip_mm"]),
+            (f"{snow / 2.54:.1f} in" if units == "imperial" else f"{snow:.1f} cm") if snow else "–",
+            f"{convert(row['gust_max_kmh'], 'speed', units):.0f} {display_unit('speed', units)}"
+            if row["gust_max_kmh"] is not None
+            else "–",
+            row["conditions"] or "–",
+        )
+    span = report.start if report.start == report.end else f"{report.start} to {report.end}"
+    console.print(f"[bold]Weather history[/bold] — {report.place}, {span}")
+    console.print(table)
+
+    summary = report.summary
+    if len(report.days) > 1:
+        by_date = {row["date"]: row for row in report.days}
+        parts = []
+        if summary.get("warmest"):
+            parts.append(f"warmest {summary['warmest']} ({temp(by_date[summary['warmest']]['temp_max_c'])})")
+        if summary.get("coldest"):
+            parts.append(f"coldest {summary['coldest']} ({temp(by_date[summary['coldest']]['temp_min_c'])})")
+        if summary["precip_total_mm"]:
+            wet = summary["wet_days"]
+            parts.append(f"{depth(summary['precip_total_mm'])} precipitation, {wet} wet day{'' if wet == 1 else 's'}")
+        else:
+            parts.append("no precipitation")
+        text = "; ".join(parts)
+        console.print(f"{text[:1].upper()}{text[1:]}.")
+    if "mean_departure_c" in summary:
+        value = summary["mean_departure_c"] * (9 / 5 if units == "imperial" else 1)
+        unit = display_unit("temp", units)
+        console.print(f"On average {abs(value):.1f}{unit} {'warmer' if value >= 0 else 'colder'} than normal.")
+    console.print(f"\n[dim]Sources: {', '.join(report.sources)}[/dim]")
+
+
+def render_doctor(diagnostics: dict[str, Any], *, console: Console, json_mode: bool = False) -> None:
+    """Render the ``wx doctor`` environment summary."""
+    if json_mode:
+        console.print(json_document("doctor", diagnostics))
+        return
+
+    settings = diagnostics["settings"]
+    cache = diagnostics["cache"]
+    profile = diagnostics["profile"]
+    table = Table(show_header=False, box=None, pad_edge=False)
+    table.add_column(style="cyan")
+    table.add_column()
+    backends = ", ".join(diagnostics["ai_backends"]) or "[yellow]none — offline summaries only[/yellow]"
+    table.add_row("wx", f"{diagnostics['wx_version']} (Python {diagnostics['python']})")
+    table.add_row("Platform", diagnostics["platform"])
+    table.add_row("AI backends", backends)
+    table.add_row("Weather data", settings["provider"])
+    table.add_row("Units", settings["units"])
+    table.add_row("Privacy mode", "on" if settings["privacy_mode"] else "off")
+    table.add_row("Offline", "yes" if settings["offline"] else "no")
+    table.add_row("Profile", f"{profile['name']}" + ("" if profile["exists"] else " (not found)"))
+    table.add_row("Cache", f"{cache['entries']} entries, {cache['bytes']} bytes")
+    table.add_row("State files", ", ".join(diagnostics["state_files"]) or "none")
+    console.print(Panel(table, title="wx doctor", expand=False))
+
+
+def render_cache_stats(stats: dict[str, Any], *, console: Console, json_mode: bool = False) -> None:
+    """Render fetch cache totals per namespace."""
+    if json_mode:
+        console.print(json_document("cache_stats", stats))
+        return
+    if not stats["entries"]:
+        console.print(f"Cache is empty ({stats['path']}).")
+        return
+    namespaces = ", ".join(f"{name} {count}" for name, count in sorted(stats["namespaces"].items()))
+    console.print(f"[bold]{stats['entries']} entries[/bold] ({namespaces}), {stats['bytes']} bytes")
+    console.print(f"Oldest entry: {_format_age(stats['oldest_age_s'])} old")
+    console.print(f"[dim]{stats['path']}[/dim]")
+
+
+def render_cache_entries(rows: list[dict[str, Any]], *, console: Console, json_mode: bool = False) -> None:
+    """Render one line per fetch cache entry with its age and staleness."""
+    if json_mode:
+        console.print(json_document("cache_entries", rows))
+        return
+    if not rows:
+        console.print("Cache is empty.")
+        return
+    table = Table(show_edge=False)
+    table.add_column("Key", overflow="fold")
+    table.add_column("Age", justify="right")
+    table.add_column("Bytes", justify="right")
+    for row in rows:
+        age = _format_age(row["age_s"])
+        table.add_row(row["key"], f"[dim]{age} (expired)[/dim]" if row["expired"] else age, str(row["bytes"]))
+    console.print(table)
+
+
+def render_parse_story(parsed, *, console: Console, json_mode: bool = False) -> None:
+    """Show how ``wx debug parse-story`` repaired and validated a saved AI reply."""
+    if json_mode:
+        console.print(json_document("parsed_story", {"ok": parsed.ok, **asdict(parsed)}))
+        return
+    if not parsed.ok:
+        console.print(f"[red]Unparseable:[/red] {parsed.error}", highlight=False)
+    elif parsed.repairs:
+        count = len(parsed.repairs)
+        console.print(f"[green]Parsed[/green] after {count} repair{'s' if count > 1 else ''}.")
+    else:
+        console.print("[green]Parsed[/green] without repairs.")
+    for label in parsed.repairs:
+        console.print(f"  • {label}")
+    if parsed.problems:
+        console.print("[yellow]Problems:[/yellow]")
+        for problem in parsed.problems:
+            console.print(f"  • {problem}", markup=False)
+    if parsed.ok:
+        console.print(json.dumps(parsed.data, indent=2, ensure_ascii=False), markup=False, highlight=False)
+
+
+def render_feedback_summary(rows, *, console: Console, json_mode: bool = False) -> None:
+    """Render average ratings per backend, model and prompt version, best first."""
+    if json_mode:
+        console.print(json_document("feedback_summary", [asdict(row) for row in rows]))
+        return
+    if not rows:
+        console.print("No feedback yet. Rate the last story with 'wx feedback 1-5'.")
+        return
+    table = Table(show_edge=False)
+    table.add_column("Backend")
+    table.add_column("Model", overflow="fold")
+    table.add_column("Prompt")
+    table.add_column("Avg", justify="right")
+    table.add_column("Ratings", justify="right")
+    table.add_column("Flagged", justify="right")
+    for row in rows:
+        average = f"{row.average:.1f}" if row.average is not None else "–"
+        flagged = f"[red]{row.flagged}[/red]" if row.flagged else "0"
+        table.add_row(row.backend, row.model or "–", row.prompt_version or "–", average, str(row.ratings), flagged)
+    console.print(table)
+
+
+def render_watch(snapshot, changes, *, console: Console, units: str, json_mode: bool = False) -> None:
+    """Print one ``wx watch`` refresh: a conditions line plus highlighted changes."""
+    if json_mode:
+        # One object per line so the stream can be piped into jq or a log.
+        console.print(json_document("watch", watch_payload(snapshot, changes), indent=None), soft_wrap=True)
+        return
+    stamp = datetime.fromtimestamp(snapshot.fetched_at).strftime("%H:%M")
+    line = Text(snapshot_line(snapshot, units), style="bold red" if snapshot.stale else "")
+    console.print(Text.assemble((stamp, "dim"), " ", (snapshot.place, "bold"), " ", line))
+    for change in changes:
+        style = "bold red" if change.important else "yellow"
+        console.print(f"      [{style}]▲ {change.message}[/{style}]")
+
+
+def render_watch_story(result, alerts, *, console: Console, json_mode: bool = False) -> None:
+    """Print the story ``wx watch`` regenerated after new Severe/Extreme ``alerts``."""
+    if json_mode:
+        console.print(json_document("story", _result_payload(result), indent=None), soft_wrap=True)
+        return
+    events = ", ".join(str(alert.get("event") or "alert") for alert in alerts)
+    console.print(f"      [bold red]⚠ Story updated for {events}[/bold red]")
+    console.print(Text("      " + (result.response.bottom_line or "Bottom line unavailable."), style="bold"))
+
+
+# Response keys in the order the model writes them, with the label shown while streaming.
+STREAM_SECTIONS = (
+    ("answer", "answer"),
+    ("summary", "summary"),
+    ("timeline", "timeline"),
+    ("risk_cards", "risks"),
+    ("actions", "actions"),
+    ("assumptions", "assumptions"),
+    ("confidence", "confidence"),
+    ("bottom_line", "bottom line"),
+)
+
+
+class StreamingProgress:
+    """Live "writing…" line fed by ``Forecaster.on_progress`` while a backend streams.
+
+    Sections the model has started are listed as they appear; the line is transient, so
+    the normal rendered answer replaces it once the response is complete.
+    """
+
+    def __init__(self, console: Console) -> None:
+        self.console = console
+        self._live: Live | None = None
+
+    def __call__(self, text: str | None) -> None:
+        if text is None:
+            if self._live is not None:
+                self._live.stop()
+                self._live = None
+            return
+        line = streaming_status_line(text)
+        if self._live is None:
+            self._live = Live(line, console=self.console, transient=True, refresh_per_second=8)
+            self._live.start()
+        else:
+            self._live.update(line)
+
+
+def streaming_status_line(text: str) -> Text:
+    """Summarise partial model output as written sections plus the one in progress."""
+    started = [label for key, label in STREAM_SECTIONS if f'"{key}"' in text]
+    line = Text("writing… ", style="bold cyan")
+    for label in started[:-1]:
+        line.append(f"✓ {label}  ", style="green")
+    if started:
+        line.append(f"▸ {started[-1]}", style="bold")
+    line.append(f"  ({len(text)} chars)", style="dim")
+    return line
+
+
+def _format_age(seconds: int | None) -> str:
+    if seconds is None:
+        return "–"
+    if seconds < 60:
+        return f"{seconds}s"
+    if seconds < 3600:
+        return f"{seconds // 60}m"
+    if seconds < 86400:
+        return f"{seconds // 3600}h"
+    return f"{seconds // 86400}d"
+
+
+def _format_anomaly(item: dict[str, Any]) -> str:
+    """Format a city's departure from normal, flagging large swings."""
+    anomaly = item["anomaly"]
+    text = f"{item['name']} {anomaly:+.0f}° vs normal"
+    if abs(anomaly) >= ANOMALY_FLAG_DEGREES:
+        color = "red" if anomaly > 0 else "blue"
+        return f"[bold {color}]{text}[/bold {color}]"
+    return text
+
+
+def _format_storm(storm: dict[str, Any]) -> str:
+    """Format one active tropical cyclone for the basin dashboard."""
+    label = f"{storm['classification']} {storm['name']}"
+    if storm.get("category"):
+        label = f"[bold red]{label} (Cat {storm['category']})[/bold red]"
+    if storm.get("intensity_kt") is not None:
+        label += f" {storm['intensity_kt']} kt"
+    if storm.get("heading"):
+        label += f", moving {storm['heading']}"
+    if storm.get("toward"):
+        label += f" toward {storm['toward']}"
+    return label
+
 
 def _is_severe_alert(event: str) -> bool:
     """Check if alert is severe weather."""
@@ -0,0 +1,173 @@
+"""Local text embeddings for reusing recently fetched Feature Packs in follow-up questions."""
+
+from __future__ import annotations
+
+import json
+import math
+import os
+import re
+import tempfile
+import time
+from collections import Counter
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from .config import Settings
+
+# Matches the forecast cache TTL: older data would be refetched anyway.
+CONTEXT_TTL = 30 * 60
+CONTEXT_LIMIT = 20
+# Cosine similarity a named place needs to reuse a stored place ("tacoma" vs "Tacoma, WA").
+PLACE_MATCH = 0.5
+# Below this a question has no clear cue and refers to the most recent place.
+TOPIC_MATCH = 0.2
+# Window-specific keys are rebuilt for each question, so they are not stored.
+_WINDOW_KEYS = ("window", "periods", "window_summary", "user_context")
+_WORD = re.compile(r"[a-z0-9]+")
+_STOPWORDS = set("a about and any at be do for how in is it of on the there this to what when will with".split())
+
+
+def context_store_path(settings: Settings) -> Path:
+    """Where recent Feature Packs persist between runs (only when privacy mode is off)."""
+
+    return settings.state_file.parent / "context_store.json"
+
+
+def embed(text: str) -> dict[str, float]:
+    """Sparse unit vector of words and character trigrams; trigrams tolerate typos and plurals."""
+
+    features: Counter[str] = Counter()
+    for word in _WORD.findall(text.lower()):
+        if word in _STOPWORDS:
+            continue
+        features[f"w:{word}"] += 1.0
+        padded = f"#{word}#"
+        for idx in range(len(padded) - 2):
+            features[f"c:{padded[idx:idx + 3]}"] += 0.5
+    norm = math.sqrt(sum(value * value for value in features.values()))
+    return {key: value / norm for key, value in features.items()} if norm else {}
+
+
+def cosine(left: dict[str, float], right: dict[str, float]) -> float:
+    if len(left) > len(right):
+        left, right = right, left
+    return sum(value * right.get(key, 0.0) for key, value in left.items())
+
+
+@dataclass(slots=True)
+class ContextEntry:
+    """One place's Feature Pack plus the text it is retrieved by."""
+
+    key: str
+    label: str
+    text: str
+    feature_pack: dict[str, Any]
+    stored: float = field(default_factory=time.time)
+    vector: dict[str, float] = field(default_factory=dict)
+
+
+class ContextStore:
+    """Recently used Feature Packs, retrievable by place name or question similarity.
+
+    Like :class:`~wx.cache.FetchCache`, a ``path`` persists entries (0600) so one-shot
+    questions can follow up on earlier ones; without it entries last for the process.
+    """
+
+    def __init__(self, path: Path | None = None, *, ttl: float = CONTEXT_TTL) -> None:
+        self.path = path
+        self.ttl = ttl
+        self._entries: list[ContextEntry] | None = None
+
+    def add(self, feature_pack: dict[str, Any], question: str | None = None) -> ContextEntry | None:
+        """Store ``feature_pack`` as the most recent entry for its place."""
+
+        place = feature_pack.get("place") or {}
+        label = place.get("resolved")
+        if not label:
+            return None
+        lat, lon = place.get("lat"), place.get("lon")
+        key = f"{lat:.2f},{lon:.2f}" if isinstance(lat, (int, float)) and isinstance(lon, (int, float)) else label
+        alerts = [alert.get("event") or "" for alert in feature_pack.get("alerts_quick") or []]
+        text = " ".join([label, question or "", *alerts]).strip()
+        stored = {name: value for name, value in feature_pack.items() if name not in _WINDOW_KEYS}
+        entry = ContextEntry(key=key, label=label, text=text, feature_pack=stored, vector=embed(text))
+        entries = [item for item in self._fresh() if item.key != key]
+        entries.append(entry)
+        self._entries = entries[-CONTEXT_LIMIT:]
+        self._save()
+        return entry
+
+    def match_place(self, name: str) -> ContextEntry | None:
+        """Stored entry whose place name best matches ``name``, if close enough."""
+
+        query = embed(name)
+        # "Tacoma" should match "Tacoma, Washington" as well as "Tacoma, WA" does.
+        scored = [
+            (max(cosine(query, embed(entry.label)), cosine(query, embed(entry.label.split(",")[0]))), entry)
+            for entry in self._fresh()
+        ]
+        best = max(scored, key=lambda item: item[0], default=None)
+        return best[1] if best and best[0] >= PLACE_MATCH else None
+
+    def search(self, question: str) -> ContextEntry | None:
+        """Entry most similar to ``question``, else the most recent one."""
+
+        entries = self._fresh()
+        if not entries:
+            return None
+        query = embed(question)
+        # Later entries win ties so an ambiguous follow-up sticks with the latest place.
+        score, best = max((cosine(query, entry.vector), idx) for idx, entry in enumerate(entries))
+        return entries[best] if score >= TOPIC_MATCH else entries[-1]
+
+    def _fresh(self) -> list[ContextEntry]:
+        now = time.time()
+        return [entry for entry in self._load() if now - entry.stored <= self.ttl]
+
+    def _load(self) -> list[ContextEntry]:
+        if self._entries is None:
+            self._entries = []
+            if self.path is not None:
+                try:
+                    raw = json.loads(self.path.read_text())
+                except (OSError, json.JSONDecodeError):
+                    raw = []
+                for item in raw if isinstance(raw, list) else []:
+                    try:
+                        entry = ContextEntry(
+                            key=item["key"],
+                            label=item["label"],
+                            text=item["text"],
+                            feature_pack=item["feature_pack"],
+                            stored=float(item["stored"]),
+                        )
+                    except (KeyError, TypeError, ValueError):
+                        continue
+                    entry.vector = embed(entry.text)
+                    self._entries.append(entry)
+        return self._entries
+
+    def _save(self) -> None:
+        if self.path is None or self._entries is None:
+            return
+        payload = [
+            {
+                "key": entry.key,
+                "label": entry.label,
+                "text": entry.text,
+                "feature_pack": entry.feature_pack,
+                "stored": entry.stored,
+            }
+            for entry in self._entries
+        ]
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            fd, temp_path = tempfile.mkstemp(dir=self.path.parent, prefix=".wx_temp_", suffix=".json")
+            with os.fdopen(fd, "w") as handle:
+                handle.write(json.dumps(payload, ensure_ascii=True))
+            os.chmod(temp_path, 0o600)
+            os.replace(temp_path, self.path)
+        except OSError:
+            # Like the fetch cache, persistence is best-effort.
+            pass
@@ -0,0 +1,649 @@
+"""Deterministic hazard scoring for ``wx risk``."""
+
+from __future__ import annotations
+
+import json
+import re
+import tomllib
+from collections.abc import Callable, Iterable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from .uv import PROTECT_UV, uv_category
+
+HAZARDS = ("wind", "precipitation", "heat", "cold", "lightning", "flooding")
+# Scored only when asked for explicitly: severe needs extra fetches, UV only matters for outdoor plans,
+# and fog and road ice are for drivers (see wx.roads).
+OPT_IN_HAZARDS = ("severe", "uv", "fog", "ice")
+LEVELS = ("Low", "Moderate", "High", "Extreme")
+
+HAZARD_ALIASES = {
+    "gust": "wind",
+    "gusts": "wind",
+    "rain": "precipitation",
+    "precip": "precipitation",
+    "snow": "precipitation",
+    "hot": "heat",
+    "freeze": "cold",
+    "thunder": "lightning",
+    "thunderstorm": "lightning",
+    "thunderstorms": "lightning",
+    "storms": "lightning",
+    "flood": "flooding",
+    "tornado": "severe",
+    "hail": "severe",
+    "svr": "severe",
+    "sun": "uv",
+    "sunburn": "uv",
+    "mist": "fog",
+    "black_ice": "ice",
+    "black-ice": "ice",
+    "icy": "ice",
+}
+
+# SPC probability thresholds (percent) for each categorical risk, per hazard.
+SPC_CATEGORIES = ("marginal", "slight", "enhanced", "moderate", "high")
+SPC_THRESHOLDS = {
+    "tornado": (2, 5, 10, 15, 30),
+    "wind": (5, 15, 30, 45, 60),
+    "hail": (5, 15, 30, 45, 60),
+}
+SPC_LABELS = {"tornado": "Tornado", "wind": "Damaging wind", "hail": "Large hail"}
+_SPC_MENTION = re.compile(r"\bSPC\b|\b(marginal|slight|enhanced|moderate|high) risk\b", re.I)
+
+# Alert event keywords that corroborate each hazard.
+HAZARD_ALERT_KEYWORDS = {
+    "wind": ("high wind", "wind advisory", "extreme wind", "hurricane", "tropical storm", "gale"),
+    "precipitation": ("rain", "snow", "winter storm", "ice storm", "blizzard"),
+    "heat": ("heat",),
+    "cold": ("cold", "freeze", "frost", "wind chill"),
+    "lightning": ("thunderstorm", "tornado"),
+    "flooding": ("flood",),
+    "severe": ("tornado", "severe thunderstorm"),
+    "uv": ("uv", "ultraviolet"),
+    "fog": ("fog",),
+    "ice": ("freezing rain", "freezing drizzle", "ice storm", "winter weather", "black ice"),
+}
+# Minimum score implied by an active alert of each type.
+ALERT_FLOORS = (("warning", 75.0), ("watch", 50.0), ("advisory", 40.0))
+
+# Values scoring 0 and 100 for each threshold hazard, in the forecast's metric units: gust km/h,
+# precipitation mm over 48h, feels-like °C (cold runs downward), CAPE J/kg, rolling 24h mm, UV index.
+THRESHOLDS = {
+    "wind": (30.0, 110.0),
+    "precipitation": (2.0, 50.0),
+    "heat": (27.0, 46.0),
+    "cold": (0.0, -35.0),
+    "lightning": (300.0, 3000.0),
+    "flooding": (25.0, 150.0),
+    "uv": (0.0, 12.0),
+}
+DEFAULT_THRESHOLDS = "standard"
+# Tighter limits for work that stops well before the general public would: crane and lift wind
+# limits and concrete pours, ramp and fuelling shutdowns, athletic heat and lightning policies,
+# and rigging and sound on outdoor sets. Hazards a preset leaves out keep THRESHOLDS.
+THRESHOLD_PRESETS: dict[str, dict[str, tuple[float, float]]] = {
+    "standard": {},
+    "construction": {
+        "wind": (25.0, 65.0),
+        "precipitation": (1.0, 25.0),
+        "heat": (24.0, 40.0),
+        "cold": (5.0, -20.0),
+        "lightning": (200.0, 1500.0),
+    },
+    "aviation-ground": {
+        "wind": (30.0, 80.0),
+        "heat": (30.0, 45.0),
+        "cold": (2.0, -25.0),
+        "lightning": (100.0, 1000.0),
+    },
+    "school-athletics": {
+        "wind": (30.0, 80.0),
+        "heat": (24.0, 38.0),
+        "cold": (5.0, -15.0),
+        "lightning": (100.0, 1000.0),
+        "uv": (0.0, 10.0),
+    },
+    "film": {
+        "wind": (20.0, 60.0),
+        "precipitation": (0.5, 10.0),
+        "heat": (27.0, 40.0),
+        "lightning": (200.0, 1500.0),
+    },
+}
+THRESHOLD_ALIASES = {
+    "general": "standard",
+    "default": "standard",
+    "aviation": "aviation-ground",
+    "ground-ops": "aviation-ground",
+    "athletics": "school-athletics",
+    "sports": "school-athletics",
+    "filming": "film",
+}
+
+# Fog forms as the air cools to its dew point under a light wind; WMO codes 45/48 are forecast fog.
+FOG_SPREAD_C = 3.0
+FOG_CODES = {45: "Fog forecast", 48: "Freezing fog forecast"}
+# Black ice needs a surface at or below freezing and moisture on it: recent rain or snow, freezing
+# fog, or frost where the air reaches its dew point. Bridges lose heat from above and below, so
+# they run about BRIDGE_COOLING_C colder than the road leading to them.
+ICE_SURFACE_C = 1.0
+BRIDGE_COOLING_C = 1.5
+WET_HOURS = 6
+WET_MM = 0.2
+
+
+# Hazard -> (value scoring 0, value scoring 100); see thresholds_for.
+Limits = dict[str, tuple[float, float]]
+# Scores a hazard from the hourly series: (0-100 score, peak hour index, drivers).
+Scorer = Callable[[dict[str, list[Any]], str, Limits], tuple[float, int | None, list[str]]]
+
+
+@dataclass(slots=True)
+class HazardScore:
+    """Score (0-100) and supporting evidence for a single hazard."""
+
+    hazard: str
+    score: float
+    level: str
+    peak_iso: str | None = None
+    drivers: list[str] = field(default_factory=list)
+
+
+@dataclass(frozen=True, slots=True)
+class HazardRule:
+    """A user hazard scored from one hourly variable: 0 at ``low``, 100 at ``high``.
+
+    Values are in the forecast's metric units (km/h, °C, mm, %). ``high`` below ``low`` scores
+    falling values, as for cold. ``alerts`` are alert event keywords that set a minimum level.
+    """
+
+    name: str
+    variable: str
+    low: float
+    high: float
+    label: str | None = None
+    unit: str | None = None
+    alerts: tuple[str, ...] = ()
+
+
+# Names of the hazards register_hazard_rules added; scored by default after HAZARDS.
+_CUSTOM_HAZARDS: list[str] = []
+
+
+def default_hazards() -> list[str]:
+    """HAZARDS plus the registered rule hazards."""
+
+    return [*HAZARDS, *_CUSTOM_HAZARDS]
+
+
+def normalize_hazards(hazards: Iterable[str] | None) -> tuple[list[str], list[str]]:
+    """Split a user hazard filter into scoreable hazards and ones the engine cannot score."""
+
+    if not hazards:
+        return default_hazards(), []
+    known: list[str] = []
+    unknown: list[str] = []
+    for raw in hazards:
+        key = raw.strip().lower()
+        if not key:
+            continue
+        key = HAZARD_ALIASES.get(key, key)
+        target = known if key in HAZARDS or key in OPT_IN_HAZARDS or key in _CUSTOM_HAZARDS else unknown
+        if key not in target:
+            target.append(key)
+    if not known and not unknown:
+        return default_hazards(), []
+    return known, unknown
+
+
+def parse_thresholds(value: str | None) -> str:
+    """Canonical preset name for ``value`` (None is DEFAULT_THRESHOLDS)."""
+
+    name = (value or DEFAULT_THRESHOLDS).strip().lower().replace("_", "-").replace(" ", "-")
+    name = THRESHOLD_ALIASES.get(name, name)
+    if name not in THRESHOLD_PRESETS:
+        raise ValueError(f"Unknown thresholds '{value}'. Choose from: {', '.join(THRESHOLD_PRESETS)}.")
+    return name
+
+
+def thresholds_for(preset: str | None) -> dict[str, tuple[float, float]]:
+    """THRESHOLDS with ``preset``'s overrides applied."""
+
+    return {**THRESHOLDS, **THRESHOLD_PRESETS[parse_thresholds(preset)]}
+
+
+def level_for(score: float) -> str:
+    return LEVELS[min(int(score // 25), len(LEVELS) - 1)]
+
+
+def assess_hazards(
+    hourly: dict[str, list[Any]] | None,
+    alerts: list[dict[str, Any]] | None = None,
+    *,
+    hazards: Iterable[str] | None = None,
+    units: str = "imperial",
+    spc: dict[str, dict[str, Any]] | None = None,
+    thresholds: str | None = None,
+) -> list[HazardScore]:
+    """Score each requested hazard from hourly forecast data and active alerts.
+
+    ``hourly`` follows the Open-Meteo layout (metric units): parallel lists keyed by
+    variable plus ``time``. ``hazards`` are names already passed through
+    ``normalize_hazards`` (None scores ``default_hazards()``); an empty list scores nothing. ``spc`` holds
+    SPC day-1 probabilities for the ``severe`` hazard; ``thresholds`` names a THRESHOLD_PRESETS
+    entry. Results are sorted from highest to lowest score.
+    """
+
+    selected = default_hazards() if hazards is None else list(hazards)
+    limits = thresholds_for(thresholds)
+    series = hourly or {}
+    scores = []
+    for hazard in selected:
+        if hazard == "severe":
+            score, peak, drivers = _score_severe(spc, units)
+        else:
+            score, peak, drivers = _SCORERS[hazard](series, units, limits)
+        score, alert_drivers = _apply_alert_floor(hazard, score, alerts or [])
+        peak_iso = _value_at(series.get("time"), peak)
+        scores.append(
+            HazardScore(
+                hazard=hazard,
+                score=round(score),
+                level=level_for(score),
+                peak_iso=peak_iso,
+                drivers=drivers + alert_drivers,
+            )
+        )
+    scores.sort(key=lambda item: item.score, reverse=True)
+    return scores
+
+
+def _scale(value: float, low: float, high: float) -> float:
+    """Map ``value`` onto 0-100 between ``low`` (0) and ``high`` (100)."""
+
+    if high == low:
+        return 0.0
+    return max(0.0, min(100.0, (value - low) / (high - low) * 100))
+
+
+def _values(series: dict[str, list[Any]], key: str) -> list[float | None]:
+    return [value if isinstance(value, (int, float)) else None for value in series.get(key) or []]
+
+
+def _peak(values: list[float | None], *, lowest: bool = False) -> tuple[float | None, int | None]:
+    indexed = [(value, idx) for idx, value in enumerate(values) if value is not None]
+    if not indexed:
+        return None, None
+    value, idx = (min if lowest else max)(indexed)
+    return value, idx
+
+
+def _value_at(values: list[Any] | None, idx: int | None) -> Any:
+    if values is None or idx is None or idx >= len(values):
+        return None
+    return values[idx]
+
+
+def _fmt_temp(celsius: float, units: str) -> str:
+    return f"{celsius * 9 / 5 + 32:.0f}°F" if units == "imperial" else f"{celsius:.0f}°C"
+
+
+def _fmt_speed(kmh: float, units: str) -> str:
+    return f"{kmh / 1.609:.0f} mph" if units == "imperial" else f"{kmh / 3.6:.0f} m/s"
+
+
+def _fmt_depth(mm: float, units: str) -> str:
+    return f"{mm / 25.4:.2f} in" if units == "imperial" else f"{mm:.0f} mm"
+
+
+def _score_wind(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    gust, idx = _peak(_values(series, "wind_gusts_10m"))
+    if gust is None:
+        return 0.0, None, []
+    return _scale(gust, *limits["wind"]), idx, [f"Gusts to {_fmt_speed(gust, units)}"]
+
+
+def _score_precipitation(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    precip = _values(series, "precipitation")
+    total = sum(value for value in precip if value)
+    prob, prob_idx = _peak(_values(series, "precipitation_probability"))
+    _, rate_idx = _peak(precip)
+    drivers = []
+    if prob is not None:
+        drivers.append(f"Peak chance {prob:.0f}%")
+    if total:
+        drivers.append(f"{_fmt_depth(total, units)} total")
+    score = max(_scale(total, *limits["precipitation"]), _scale(prob or 0, 30, 100) * 0.5)
+    return score, rate_idx if total else prob_idx, drivers
+
+
+def _score_heat(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    feels, idx = _peak(_values(series, "apparent_temperature"))
+    if feels is None:
+        return 0.0, None, []
+    return _scale(feels, *limits["heat"]), idx, [f"Feels like {_fmt_temp(feels, units)}"]
+
+
+def _score_cold(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    feels, idx = _peak(_values(series, "apparent_temperature"), lowest=True)
+    if feels is None:
+        return 0.0, None, []
+    return _scale(feels, *limits["cold"]), idx, [f"Feels like {_fmt_temp(feels, units)}"]
+
+
+def _score_lightning(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    cape_values = _values(series, "cape")
+    probs = _values(series, "precipitation_probability")
+    best, best_idx, best_cape = 0.0, None, None
+    for idx, cape in enumerate(cape_values):
+        if cape is None:
+            continue
+        prob = _value_at(probs, idx)
+        # Instability only matters when something is likely to trigger convection.
+        trigger = 1.0 if prob is None else min(1.0, prob / 50)
+        score = _scale(cape, *limits["lightning"]) * trigger
+        if score > best:
+            best, best_idx, best_cape = score, idx, cape
+    if best_cape is None:
+        return 0.0, None, []
+    return best, best_idx, [f"CAPE {best_cape:.0f} J/kg"]
+
+
+def _score_flooding(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    precip = _values(series, "precipitation")
+    if not precip:
+        return 0.0, None, []
+    # Worst rolling 24-hour total.
+    best, best_idx = 0.0, None
+    for start in range(len(precip)):
+        window = sum(value for value in precip[start : start + 24] if value)
+        if window > best:
+            best, best_idx = window, start
+    if not best:
+        return 0.0, None, []
+    return _scale(best, *limits["flooding"]), best_idx, [f"{_fmt_depth(best, units)} over 24h"]
+
+
+def _score_uv(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    values = _values(series, "uv_index")
+    index, idx = _peak(values)
+    if index is None:
+        return 0.0, None, []
+    drivers = [f"UV index {index:.0f} ({uv_category(index)})"]
+    protected = sum(1 for value in values[:24] if value is not None and value >= PROTECT_UV)
+    if protected:
+        drivers.append(f"{protected}h at UV {PROTECT_UV}+ in the next 24h")
+    # UV 3 → Moderate, 6 → High, 9 → Extreme.
+    return _scale(index, *limits["uv"]), idx, drivers
+
+
+def fog_hour(series: dict[str, list[Any]], idx: int, units: str) -> tuple[float, str | None]:
+    """Fog score (0-100) for hour ``idx`` and what drives it."""
+
+    code = _value_at(_values(series, "weather_code"), idx)
+    if code in FOG_CODES:
+        return 90.0, FOG_CODES[int(code)]
+    temp = _value_at(_values(series, "temperature_2m"), idx)
+    dew = _value_at(_values(series, "dew_point_2m"), idx)
+    if temp is None or dew is None:
+        return 0.0, None
+    spread = max(0.0, temp - dew)
+    score = _scale(FOG_SPREAD_C - spread, 0, FOG_SPREAD_C)
+    wind = _value_at(_values(series, "wind_speed_10m"), idx)
+    if wind is not None:
+        # A breeze mixes the saturated layer away.
+        score *= 1 - 0.7 * _scale(wind, 8, 25) / 100
+    if not score:
+        return 0.0, None
+    shown = f"{spread * 9 / 5:.0f}°F" if units == "imperial" else f"{spread:.1f}°C"
+    return score, f"Dew point within {shown} of the temperature"
+
+
+def ice_hour(
+    series: dict[str, list[Any]], idx: int, units: str, *, bridge: bool = False
+) -> tuple[float, str | None]:
+    """Black-ice score (0-100) for hour ``idx``; ``bridge`` scores a surface that cools faster."""
+
+    temp = _value_at(_values(series, "temperature_2m"), idx)
+    if temp is None:
+        return 0.0, None
+    surface = temp - (BRIDGE_COOLING_C if bridge else 0.0)
+    cold = _scale(ICE_SURFACE_C - surface, 0, 3)
+    if not cold:
+        return 0.0, None
+    precip = _values(series, "precipitation")
+    recent = sum(value for value in precip[max(0, idx - WET_HOURS + 1) : idx + 1] if value)
+    dew = _value_at(_values(series, "dew_point_2m"), idx)
+    if recent >= WET_MM:
+        wet, why = 1.0, f"{_fmt_depth(recent, units)} of precipitation in {WET_HOURS}h"
+    elif _value_at(_values(series, "weather_code"), idx) == 48:
+        wet, why = 1.0, "Freezing fog"
+    elif dew is not None and temp - dew <= 1.0:
+        wet, why = 0.7, "Frost likely"
+    else:
+        return 0.0, None
+    where = "bridge deck" if bridge else "road"
+    return cold * wet, f"{why}, {where} near {_fmt_temp(surface, units)}"
+
+
+def _score_fog(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    return _worst_hour(series, lambda idx: fog_hour(series, idx, units))
+
+
+def _score_ice(
+    series: dict[str, list[Any]], units: str, limits: Limits
+) -> tuple[float, int | None, list[str]]:
+    return _worst_hour(series, lambda idx: ice_hour(series, idx, units))
+
+
+def _worst_hour(
+    series: dict[str, list[Any]], score_hour: Callable[[int], tuple[float, str | None]]
+) -> tuple[float, int | None, list[str]]:
+    best, best_idx, driver = 0.0, None, None
+    for idx in range(len(series.get("time") or [])):
+        score, why = score_hour(idx)
+        if score > best:
+            best, best_idx, driver = score, idx, why
+    return best, best_idx, [driver] if driver else []
+
+
+def spc_category(hazard: str, probability: int) -> str | None:
+    """Categorical SPC risk implied by a probability, or None below marginal."""
+
+    category = None
+    for name, threshold in zip(SPC_CATEGORIES, SPC_THRESHOLDS[hazard], strict=True):
+        if probability >= threshold:
+            category = name
+    return category
+
+
+def spc_outlook_line(outlook: dict[str, Any] | None) -> str | None:
+    """``SPC day 1 outlook: Slight Risk (2 of 5) of severe thunderstorms.`` for a categorical outlook."""
+
+    if not outlook:
+        return None
+    prefix = f"SPC day {outlook.get('day', 1)} outlook"
+    category = outlook.get("category")
+    if category in SPC_CATEGORIES:
+        level = SPC_CATEGORIES.index(category) + 1
+        return f"{prefix}: {outlook.get('label') or category.title()} ({level} of 5) of severe thunderstorms."
+    if category == "thunderstorms":
+        return f"{prefix}: general thunderstorms, no severe risk."
+    return f"{prefix}: no thunderstorm risk area."
+
+
+def apply_spc_outlook(response, outlook: dict[str, Any] | None) -> bool:
+    """Mention a marginal-or-higher SPC outlook in the story if the model left it out.
+
+    Records the line as the ``spc_outlook`` section; returns whether the summary was extended.
+    """
+
+    if not outlook or outlook.get("category") not in SPC_CATEGORIES:
+        return False
+    line = spc_outlook_line(outlook)
+    sections = response.sections
+    sections["spc_outlook"] = line
+    key = "answer" if "answer" in sections else "summary"
+    current = sections.get(key)
+    text = " ".join(current) if isinstance(current, list) else str(current or "")
+    if _SPC_MENTION.search(text):
+        return False
+    if key == "answer":
+        sections["answer"] = f"{text.strip()} {line}".strip()
+    elif isinstance(current, list):
+        sections["summary"] = [item for item in current if isinstance(item, str)] + [line]
+    else:
+        sections["summary"] = [text, line] if text else [line]
+    return True
+
+
+def _score_severe(
+    spc: dict[str, dict[str, Any]] | None, units: str
+) -> tuple[float, int | None, list[str]]:
+    if not spc:
+        return 0.0, None, ["No SPC outlook available"]
+    radius = "25 mi" if units == "imperial" else "40 km"
+    best = 0.0
+    drivers = []
+    for hazard, label in SPC_LABELS.items():
+        entry = spc.get(hazard) or {}
+        probability = int(entry.get("probability") or 0)
+        category = spc_category(hazard, probability)
+        # Each category step is worth 20 points; a significant-severe area adds one step.
+        steps = SPC_CATEGORIES.index(category) + 1 if category else 0
+        if category and entry.get("significant"):
+            steps += 1
+        best = max(best, min(100.0, steps * 20.0))
+        if probability:
+            text = f"{label} {probability}% within {radius} ({category or 'below marginal'})"
+            if entry.get("significant"):
+                text += ", significant-severe area"
+            drivers.append(text)
+    return best, None, drivers or ["Outside SPC severe probabilities today"]
+
+
+_SCORERS: dict[str, Scorer] = {
+    "wind": _score_wind,
+    "precipitation": _score_precipitation,
+    "heat": _score_heat,
+    "cold": _score_cold,
+    "lightning": _score_lightning,
+    "flooding": _score_flooding,
+    "uv": _score_uv,
+    "fog": _score_fog,
+    "ice": _score_ice,
+}
+
+
+def parse_hazard_rules(raw: Any) -> list[HazardRule]:
+    """Rules from ``{"crane_wind": {"variable": "wind_gusts_10m", "low": 30, "high": 55}}``.
+
+    ``raw`` may instead be the path of a JSON or TOML file holding that mapping. Entries without
+    a variable or with equal bounds are skipped, as are names of built-in hazards.
+    """
+
+    if isinstance(raw, str) and raw.strip():
+        path = Path(raw.strip()).expanduser()
+        try:
+            text = path.read_text()
+            raw = tomllib.loads(text) if path.suffix.lower() == ".toml" else json.loads(text)
+        except (OSError, ValueError):
+            return []
+    if not isinstance(raw, dict):
+        return []
+    rules = []
+    for name, spec in raw.items():
+        key = str(name).strip().lower()
+        if not isinstance(spec, dict) or not key or key in _BUILTIN_HAZARDS or key in HAZARD_ALIASES:
+            continue
+        variable, low, high = spec.get("variable"), spec.get("low"), spec.get("high")
+        numeric = all(isinstance(value, (int, float)) and not isinstance(value, bool) for value in (low, high))
+        if not isinstance(variable, str) or not variable.strip() or not numeric or low == high:
+            continue
+        alerts = spec.get("alerts")
+        keywords = [alerts] if isinstance(alerts, str) else alerts if isinstance(alerts, list) else []
+        rules.append(
+            HazardRule(
+                name=key,
+                variable=variable.strip(),
+                low=float(low),
+                high=float(high),
+                label=_optional_text(spec.get("label")),
+                unit=_optional_text(spec.get("unit")),
+                alerts=tuple(word.strip().lower() for word in keywords if isinstance(word, str) and word.strip()),
+            )
+        )
+    return rules
+
+
+def register_hazard_rules(rules: Iterable[HazardRule]) -> list[str]:
+    """Make ``rules`` scoreable (and scored by default), replacing any registered before."""
+
+    for name in _CUSTOM_HAZARDS:
+        _SCORERS.pop(name, None)
+        HAZARD_ALERT_KEYWORDS.pop(name, None)
+    _CUSTOM_HAZARDS.clear()
+    for rule in rules:
+        if rule.name in _SCORERS:
+            continue
+        _SCORERS[rule.name] = _rule_scorer(rule)
+        HAZARD_ALERT_KEYWORDS[rule.name] = rule.alerts
+        _CUSTOM_HAZARDS.append(rule.name)
+    return list(_CUSTOM_HAZARDS)
+
+
+def _rule_scorer(rule: HazardRule) -> Scorer:
+    def score(
+        series: dict[str, list[Any]], units: str, limits: Limits
+    ) -> tuple[float, int | None, list[str]]:
+        values = _values(series, rule.variable)
+
+        def hour(idx: int) -> tuple[float, str | None]:
+            value = _value_at(values, idx)
+            if value is None:
+                return 0.0, None
+            text = f"{rule.label or rule.variable} {value:g}"
+            return _scale(value, rule.low, rule.high), f"{text} {rule.unit}" if rule.unit else text
+
+        return _worst_hour(series, hour)
+
+    return score
+
+
+def _optional_text(value: Any) -> str | None:
+    return value.strip() if isinstance(value, str) and value.strip() else None
+
+
+_BUILTIN_HAZARDS = frozenset((*_SCORERS, *HAZARDS, *OPT_IN_HAZARDS))
+
+
+def _apply_alert_floor(hazard: str, score: float, alerts: list[dict[str, Any]]) -> tuple[float, list[str]]:
+    drivers = []
+    keywords = HAZARD_ALERT_KEYWORDS[hazard]
+    for alert in alerts:
+        event = str(alert.get("event") or "")
+        lowered = event.lower()
+        if not any(keyword in lowered for keyword in keywords):
+            continue
+        floor = next((value for word, value in ALERT_FLOORS if word in lowered), 25.0)
+        score = max(score, floor)
+        drivers.append(event)
+    return score, drivers
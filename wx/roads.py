@@ -0,0 +1,150 @@
+"""``wx roads``: fog and black-ice risk on the profile's road segments during commute windows.
+
+Segments are named points in the profile, such as a bridge or a hollow that fogs in:
+``"roads": {"Route 9 bridge": {"place": "42.10,-73.91", "bridge": true}, "Mill Hill": "42.2,-73.8"}``.
+Commute windows are local times from the profile's ``commute`` (``["06:30-08:30", "15:00-17:30"]``),
+else DEFAULT_COMMUTE. Every window in the next ROAD_HOURS gets the worst hour's fog and ice
+scores from wx.risk.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, time, timedelta
+from typing import Any
+
+from .clock import zone
+from .risk import fog_hour, ice_hour, level_for
+
+ROAD_HOURS = 48
+DEFAULT_COMMUTE = ("06:00-09:00", "15:00-18:00")
+_WINDOW = re.compile(r"^\s*(\d{1,2}):(\d{2})\s*-\s*(\d{1,2}):(\d{2})\s*$")
+
+
+@dataclass(frozen=True, slots=True)
+class RoadSegment:
+    """A named stretch of road; ``bridge`` ices up before the road around it."""
+
+    name: str
+    place: str
+    bridge: bool = False
+
+
+@dataclass(slots=True)
+class CommuteRisk:
+    """The worst fog and ice hour within one commute window (local ISO times)."""
+
+    start_iso: str
+    end_iso: str
+    fog: int
+    ice: int
+    # Level of the higher of the two scores, and the hour it peaks.
+    level: str
+    peak_iso: str | None = None
+    drivers: list[str] = field(default_factory=list)
+
+
+@dataclass(slots=True)
+class RoadReport:
+    """One segment's commute windows, or why they could not be scored."""
+
+    name: str
+    place: str
+    bridge: bool = False
+    resolved: str | None = None
+    tz: str | None = None
+    windows: list[CommuteRisk] = field(default_factory=list)
+    error: str | None = None
+
+
+def parse_roads(raw: Any) -> list[RoadSegment]:
+    """``{"Route 9 bridge": {"place": "42.1,-73.9", "bridge": true}, "Mill Hill": "42.2,-73.8"}``."""
+
+    if not isinstance(raw, dict):
+        return []
+    roads = []
+    for name, entry in raw.items():
+        spec = entry if isinstance(entry, dict) else {"place": entry}
+        place = spec.get("place")
+        if isinstance(place, str) and place.strip():
+            roads.append(RoadSegment(str(name), place.strip(), bool(spec.get("bridge"))))
+    return roads
+
+
+def parse_commute(raw: Any) -> tuple[str, ...] | None:
+    """Valid ``HH:MM-HH:MM`` windows from a list or comma-separated string; None when there are none."""
+
+    items = raw.split(",") if isinstance(raw, str) else raw if isinstance(raw, list) else []
+    windows = []
+    for item in items:
+        bounds = _window_bounds(item) if isinstance(item, str) else None
+        if bounds is not None:
+            windows.append(f"{bounds[0]:%H:%M}-{bounds[1]:%H:%M}")
+    return tuple(windows) or None
+
+
+def commute_risks(
+    hourly: dict[str, list[Any]],
+    windows: tuple[str, ...] = DEFAULT_COMMUTE,
+    *,
+    tz_name: str | None,
+    units: str,
+    bridge: bool = False,
+) -> list[CommuteRisk]:
+    """Fog and ice for each commute window the hourly forecast (UTC ``time``) covers, in order."""
+
+    tz = zone(tz_name)
+    stamps = [_utc(value) for value in hourly.get("time") or []]
+    if not stamps:
+        return []
+    spans = []
+    day = stamps[0].astimezone(tz).date()
+    while datetime.combine(day, time(), tz) <= stamps[-1]:
+        for window in windows:
+            bounds = _window_bounds(window)
+            if bounds is None:
+                continue
+            start = datetime.combine(day, bounds[0], tz)
+            end = datetime.combine(day, bounds[1], tz)
+            spans.append((start, end + timedelta(days=1) if end <= start else end))
+        day += timedelta(days=1)
+
+    risks = []
+    for start, end in sorted(spans):
+        hours = [idx for idx, stamp in enumerate(stamps) if start <= stamp < end]
+        if not hours:
+            continue
+        fog = [(fog_hour(hourly, idx, units), idx) for idx in hours]
+        ice = [(ice_hour(hourly, idx, units, bridge=bridge), idx) for idx in hours]
+        (fog_score, fog_why), fog_idx = max(fog, key=lambda item: item[0][0])
+        (ice_score, ice_why), ice_idx = max(ice, key=lambda item: item[0][0])
+        peak_idx = fog_idx if fog_score >= ice_score else ice_idx
+        worst = max(fog_score, ice_score)
+        risks.append(
+            CommuteRisk(
+                start_iso=start.isoformat(),
+                end_iso=end.isoformat(),
+                fog=round(fog_score),
+                ice=round(ice_score),
+                level=level_for(worst),
+                peak_iso=stamps[peak_idx].astimezone(tz).isoformat() if worst else None,
+                drivers=[why for why in (fog_why, ice_why) if why],
+            )
+        )
+    return risks
+
+
+def _window_bounds(text: str) -> tuple[time, time] | None:
+    match = _WINDOW.match(text)
+    if not match:
+        return None
+    start_h, start_m, end_h, end_m = (int(part) for part in match.groups())
+    if start_h > 23 or end_h > 23 or start_m > 59 or end_m > 59:
+        return None
+    return time(start_h, start_m), time(end_h, end_m)
+
+
+def _utc(value: Any) -> datetime:
+    stamp = datetime.fromisoformat(str(value))
+    return stamp if stamp.tzinfo else stamp.replace(tzinfo=UTC)
@@ -0,0 +1,172 @@
+"""Route weather: forecast conditions at points along a drive, timed to when you pass them."""
+
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass, field
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .fetchers import WMO_WEATHER_CODES
+from .geo import EARTH_RADIUS_KM, haversine_km
+
+DEFAULT_SPEED_KMH = 90.0
+DEFAULT_STOPS = 6
+MAX_STOPS = 20
+# Straight-line distance understates the drive; roads run roughly this much longer.
+ROAD_FACTOR = 1.2
+# Hazard thresholds (metric, as fetched): gust km/h, hourly precipitation mm, chance %.
+GUST_KMH = 60.0
+HEAVY_PRECIP_MM = 4.0
+PRECIP_CHANCE = 60
+
+
+@dataclass(slots=True)
+class RouteStop:
+    """One sampled point on the route and the forecast hour when it is reached."""
+
+    km: float
+    lat: float
+    lon: float
+    eta_iso: str
+    label: str | None = None
+    conditions: dict[str, Any] | None = None  # metric, as fetched
+    hazards: list[str] = field(default_factory=list)
+
+
+@dataclass(slots=True)
+class RouteReport:
+    """Everything ``wx route`` knows about a drive."""
+
+    origin: str
+    destination: str
+    distance_km: float = 0.0
+    duration_h: float = 0.0
+    path: str = "great-circle"  # osrm | great-circle
+    depart_iso: str | None = None
+    tz: str | None = None
+    stops: list[RouteStop] = field(default_factory=list)
+    # Keys of wx.attribution.SOURCES for the route and forecasts.
+    attribution: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+def great_circle_point(start: tuple[float, float], end: tuple[float, float], fraction: float) -> tuple[float, float]:
+    """Point ``fraction`` of the way from ``start`` to ``end`` along the great circle."""
+
+    lat1, lon1, lat2, lon2 = map(math.radians, (*start, *end))
+    delta = haversine_km(*start, *end) / EARTH_RADIUS_KM
+    if delta == 0:
+        return start
+    a = math.sin((1 - fraction) * delta) / math.sin(delta)
+    b = math.sin(fraction * delta) / math.sin(delta)
+    x = a * math.cos(lat1) * math.cos(lon1) + b * math.cos(lat2) * math.cos(lon2)
+    y = a * math.cos(lat1) * math.sin(lon1) + b * math.cos(lat2) * math.sin(lon2)
+    z = a * math.sin(lat1) + b * math.sin(lat2)
+    return math.degrees(math.atan2(z, math.hypot(x, y))), math.degrees(math.atan2(y, x))
+
+
+def sample_path(path: list[tuple[float, float]], count: int) -> list[tuple[float, float, float]]:
+    """``count`` evenly spaced ``(lat, lon, fraction)`` points along a polyline, ends included."""
+
+    legs = [haversine_km(*path[idx], *path[idx + 1]) for idx in range(len(path) - 1)]
+    total = sum(legs)
+    if total == 0 or count < 2:
+        return [(*path[0], 0.0), (*path[-1], 1.0)]
+    samples = []
+    for step in range(count):
+        fraction = step / (count - 1)
+        target, leg = fraction * total, 0
+        while leg < len(legs) - 1 and target > legs[leg]:
+            target -= legs[leg]
+            leg += 1
+        part = target / legs[leg] if legs[leg] else 0.0
+        lat, lon = great_circle_point(path[leg], path[leg + 1], min(part, 1.0))
+        samples.append((lat, lon, fraction))
+    return samples
+
+
+def hour_conditions(hourly: dict[str, list[Any]] | None, when: datetime) -> dict[str, Any] | None:
+    """The forecast hour closest to ``when`` (naive UTC ``time`` series), or None if out of range."""
+
+    times = (hourly or {}).get("time") or []
+    best: tuple[float, int] | None = None
+    for idx, stamp in enumerate(times):
+        try:
+            moment = datetime.fromisoformat(str(stamp))
+        except ValueError:
+            continue
+        if moment.tzinfo is None:
+            moment = moment.replace(tzinfo=UTC)
+        gap = abs((moment - when).total_seconds())
+        if best is None or gap < best[0]:
+            best = (gap, idx)
+    if best is None or best[0] > 5400:
+        return None
+
+    def at(key: str) -> Any:
+        series = hourly.get(key) or []
+        return series[best[1]] if best[1] < len(series) else None
+
+    code = at("weather_code")
+    worded = at("conditions")
+    return {
+        "temp": at("temperature_2m"),
+        "wind": at("wind_speed_10m"),
+        "gust": at("wind_gusts_10m"),
+        "precip_prob": at("precipitation_probability"),
+        "precipitation": at("precipitation"),
+        "conditions": worded or (WMO_WEATHER_CODES.get(int(code)) if isinstance(code, (int, float)) else None),
+    }
+
+
+def route_hazards(conditions: dict[str, Any] | None) -> list[str]:
+    """Driving concerns at one stop: ice, snow, heavy rain, strong gusts, storms."""
+
+    if not conditions:
+        return []
+    hazards = []
+    temp = _number(conditions.get("temp"))
+    precip = _number(conditions.get("precipitation")) or 0.0
+    chance = _number(conditions.get("precip_prob")) or 0.0
+    gust = _number(conditions.get("gust"))
+    text = str(conditions.get("conditions") or "").lower()
+    wet = precip > 0 or chance >= PRECIP_CHANCE
+    if "snow" in text or (wet and temp is not None and temp <= 0):
+        hazards.append("snow or ice")
+    elif "freezing" in text:
+        hazards.append("freezing rain")
+    elif precip >= HEAVY_PRECIP_MM:
+        hazards.append("heavy rain")
+    if "thunder" in text:
+        hazards.append("thunderstorms")
+    if "fog" in text:
+        hazards.append("fog")
+    if gust is not None and gust >= GUST_KMH:
+        hazards.append("strong gusts")
+    return hazards
+
+
+def plan_stops(
+    path: list[tuple[float, float]],
+    *,
+    distance_km: float,
+    duration_h: float,
+    depart: datetime,
+    count: int = DEFAULT_STOPS,
+) -> list[RouteStop]:
+    """Evenly spaced stops with the time each is reached, assuming a steady pace."""
+
+    return [
+        RouteStop(
+            km=round(fraction * distance_km, 1),
+            lat=round(lat, 4),
+            lon=round(lon, 4),
+            eta_iso=(depart + timedelta(hours=fraction * duration_h)).isoformat(),
+        )
+        for lat, lon, fraction in sample_path(path, count)
+    ]
+
+
+def _number(value: Any) -> float | None:
+    return float(value) if isinstance(value, (int, float)) and not isinstance(value, bool) else None
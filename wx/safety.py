@@ -0,0 +1,87 @@
+"""Deterministic check that stories never leave out Severe or Extreme alerts."""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import Any
+
+SAFETY_SEVERITIES = ("Extreme", "Severe")
+SAFETY_FOOTER = "Follow instructions from local officials and your national weather service."
+
+
+def urgent_alerts(alerts: list[dict[str, Any]] | None) -> list[dict[str, Any]]:
+    """Severe and Extreme alerts, one per event name, most severe first."""
+
+    candidates = [
+        alert
+        for alert in alerts or []
+        if alert.get("severity") in SAFETY_SEVERITIES and str(alert.get("event") or "").strip()
+    ]
+    candidates.sort(key=lambda alert: SAFETY_SEVERITIES.index(alert["severity"]))
+    seen: set[str] = set()
+    urgent = []
+    for alert in candidates:
+        key = str(alert["event"]).strip().lower()
+        if key not in seen:
+            seen.add(key)
+            urgent.append(alert)
+    return urgent
+
+
+def apply_alert_safety(response, alerts: list[dict[str, Any]] | None) -> list[str]:
+    """Make sure every urgent alert appears in the story's decisions and bottom line.
+
+    Decisions are the ``actions`` of a briefing or the ``answer`` to a question. Whatever the
+    model left out is added along with a standard ``safety`` section; returns those events.
+    """
+
+    urgent = urgent_alerts(alerts)
+    if not urgent:
+        return []
+    sections = response.sections
+    decision_key = "answer" if "answer" in sections else "actions"
+    decisions = sections.get(decision_key)
+    decision_text = " ".join(decisions) if isinstance(decisions, list) else str(decisions or "")
+
+    missing_decisions = [alert for alert in urgent if not _mentions(decision_text, alert)]
+    missing_bottom = [alert for alert in urgent if not _mentions(response.bottom_line or "", alert)]
+    if missing_decisions:
+        notices = [f"{alert['event']} in effect{_until(alert)}: {SAFETY_FOOTER}" for alert in missing_decisions]
+        if decision_key == "answer":
+            sections["answer"] = " ".join([decision_text.strip(), *notices]).strip()
+        else:
+            actions = [item for item in decisions if isinstance(item, str)] if isinstance(decisions, list) else []
+            sections["actions"] = notices + actions
+    if missing_bottom:
+        events = ", ".join(alert["event"] for alert in missing_bottom)
+        verb = "is" if len(missing_bottom) == 1 else "are"
+        response.bottom_line = f"{(response.bottom_line or '').rstrip()} {events} {verb} in effect.".strip()
+
+    added = [alert["event"] for alert in urgent if alert in missing_decisions or alert in missing_bottom]
+    if added:
+        sections["safety"] = [_safety_line(alert) for alert in urgent] + [SAFETY_FOOTER]
+    response.meta = {
+        **(response.meta or {}),
+        "safety": {"alerts": [alert["event"] for alert in urgent], "added": added},
+    }
+    return added
+
+
+def _mentions(text: str, alert: dict[str, Any]) -> bool:
+    return str(alert["event"]).lower() in text.lower()
+
+
+def _until(alert: dict[str, Any]) -> str:
+    raw = alert.get("expires_iso")
+    if not raw:
+        return ""
+    try:
+        expires = datetime.fromisoformat(str(raw).replace("Z", "+00:00"))
+    except ValueError:
+        return ""
+    return f" until {expires.strftime('%a %I:%M %p').replace(' 0', ' ')}"
+
+
+def _safety_line(alert: dict[str, Any]) -> str:
+    line = f"{alert['event']} ({alert.get('severity')}){_until(alert)}"
+    return f"{line}: {alert['headline']}" if alert.get("headline") else line
@@ -0,0 +1,239 @@
+"""Versioned ``--json`` output: one envelope, the same across every command.
+
+Every command prints ``{"schema_version": 2, "kind": "story", "data": ...}``. Within a schema
+version fields are only ever added; renaming or removing one, or changing its type, bumps
+``SCHEMA_VERSION``. Version 1 was the bare payload printed before the envelope existed.
+``wx schema`` prints the JSON Schema, derived from the report dataclasses where there is one.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import json
+import sys
+from typing import Any
+
+from .astro import SunReport
+from .aviation import AviationReport
+from .feedback import FeedbackSummary
+from .fishing import FishReport
+from .graph import GraphSeries
+from .groups import GroupAlertsReport
+from .history import HistoryReport
+from .lakes import LakeReport
+from .locate import Location
+from .marine import MarineReport
+from .models import ModelsReport
+from .nowcast import RainReport
+from .roads import RoadReport
+from .route import RouteReport
+from .storyparse import ParsedStory
+from .watch import PlaceReport, WatchChange, WatchSnapshot
+
+SCHEMA_VERSION = 2
+SCHEMA_DIALECT = "https://json-schema.org/draft/2020-12/schema"
+SCHEMA_ID = f"urn:wx-cli:output:{SCHEMA_VERSION}"
+_JSON_TYPES = {
+    "str": "string",
+    "int": "integer",
+    "float": "number",
+    "bool": "boolean",
+    "list": "array",
+    "tuple": "array",
+    "set": "array",
+    "Sequence": "array",
+    "dict": "object",
+    "None": "null",
+}
+_ATTRIBUTION = {"type": "object", "required": ["attribution"]}
+
+
+def envelope(kind: str, data: Any) -> dict[str, Any]:
+    """Wrap a command's payload; ``kind`` must be one of :data:`OUTPUT_KINDS`."""
+
+    if kind not in OUTPUT_KINDS:
+        raise ValueError(f"Unknown output kind '{kind}'")
+    return {"schema_version": SCHEMA_VERSION, "kind": kind, "data": data}
+
+
+def json_document(kind: str, data: Any, *, indent: int | None = 2, ensure_ascii: bool = True) -> str:
+    """The enveloped payload as JSON text; ``indent=None`` for one line (``wx watch`` streams)."""
+
+    return json.dumps(envelope(kind, data), indent=indent, ensure_ascii=ensure_ascii, default=str)
+
+
+def unwrap(document: Any) -> Any:
+    """The payload of an enveloped document; older bare payloads pass through unchanged."""
+
+    if isinstance(document, dict) and "schema_version" in document and "kind" in document and "data" in document:
+        return document["data"]
+    return document
+
+
+def output_schema(kind: str | None = None) -> dict[str, Any]:
+    """JSON Schema for every envelope, or for one ``kind``'s."""
+
+    if kind is not None and kind not in OUTPUT_KINDS:
+        raise ValueError(f"Unknown output kind '{kind}'. Choose from: {', '.join(OUTPUT_KINDS)}")
+    kinds = [kind] if kind else list(OUTPUT_KINDS)
+    schema: dict[str, Any] = {
+        "$schema": SCHEMA_DIALECT,
+        "$id": SCHEMA_ID if kind is None else f"{SCHEMA_ID}:{kind}",
+        "title": "wx --json output" if kind is None else f"wx --json output: {kind}",
+        "type": "object",
+        "required": ["schema_version", "kind", "data"],
+        "properties": {
+            "schema_version": {"const": SCHEMA_VERSION},
+            "kind": {"const": kind} if kind else {"enum": kinds},
+            "data": {},
+        },
+        "additionalProperties": False,
+    }
+    if kind:
+        description, data = OUTPUT_KINDS[kind]
+        schema["description"] = description
+        schema["properties"]["data"] = data
+        return schema
+    schema["allOf"] = [
+        {
+            "if": {"properties": {"kind": {"const": name}}},
+            "then": {"properties": {"data": {"description": description, **data}}},
+        }
+        for name, (description, data) in OUTPUT_KINDS.items()
+    ]
+    return schema
+
+
+def dataclass_schema(cls: type, **extra: dict[str, Any]) -> dict[str, Any]:
+    """Object schema for ``dataclasses.asdict(cls(...))``, plus ``extra`` properties.
+
+    Every field is required because ``asdict`` always writes it; unknown properties are allowed so
+    that adding a field stays backward compatible.
+    """
+
+    module = sys.modules[cls.__module__]
+    properties = {field.name: _annotation_schema(str(field.type), module) for field in dataclasses.fields(cls)}
+    properties |= extra
+    return {"type": "object", "required": list(properties), "properties": properties}
+
+
+def _annotation_schema(annotation: str, module) -> dict[str, Any]:
+    alternatives = []
+    for part in _split_top_level(annotation, "|"):
+        base, _, inner = part.partition("[")
+        inner = inner[:-1] if inner.endswith("]") else inner
+        named = getattr(module, base, None)
+        if dataclasses.is_dataclass(named):
+            alternatives.append(dataclass_schema(named))
+            continue
+        json_type = _JSON_TYPES.get(base)
+        if json_type is None:
+            return {}
+        schema: dict[str, Any] = {"type": json_type}
+        args = _split_top_level(inner, ",") if inner else []
+        if json_type == "array" and len(args) == 1:
+            items = _annotation_schema(args[0], module)
+            if items:
+                schema["items"] = items
+        alternatives.append(schema)
+    if len(alternatives) == 1:
+        return alternatives[0]
+    if all(list(schema) == ["type"] for schema in alternatives):
+        return {"type": [schema["type"] for schema in alternatives]}
+    return {"anyOf": alternatives}
+
+
+def _split_top_level(text: str, separator: str) -> list[str]:
+    parts, depth, current = [], 0, ""
+    for char in text:
+        depth += {"[": 1, "]": -1}.get(char, 0)
+        if char == separator and depth == 0:
+            parts.append(current.strip())
+            current = ""
+        else:
+            current += char
+    return [*parts, current.strip()] if current.strip() else parts
+
+
+def _object(*required: str, **properties: dict[str, Any]) -> dict[str, Any]:
+    schema: dict[str, Any] = {"type": "object", "required": list(required)}
+    if properties:
+        schema["properties"] = properties
+    return schema
+
+
+def _array(items: dict[str, Any]) -> dict[str, Any]:
+    return {"type": "array", "items": items}
+
+
+# kind -> (description, schema of ``data``). Kinds are never renamed within a schema version.
+OUTPUT_KINDS: dict[str, tuple[str, dict[str, Any]]] = {
+    "story": (
+        "forecast, risk, alerts and ask results: the AI story, its Feature Pack and timings",
+        _object(
+            "command",
+            "query",
+            "feature_pack",
+            "response",
+            "hourly",
+            "timings",
+            "debug",
+            "meta",
+            command={"type": "string"},
+            query={"type": ["string", "null"]},
+            feature_pack={"type": ["object", "null"]},
+            response=_object("sections", "confidence", "used_feature_fields", "bottom_line", "provider"),
+            hourly={"type": ["array", "object"]},
+            meta=_ATTRIBUTION,
+        ),
+    ),
+    "prompt_comparison": (
+        "forecast --compare-prompts: one response per prompt version on one Feature Pack",
+        _object("feature_pack", "versions", versions=_array({"type": "object"})),
+    ),
+    "explain": (
+        "wx explain: why the last story said what it did",
+        _object("mode", "text", "meta", "provider", "feature_pack"),
+    ),
+    "location": ("wx here: the detected position", dataclass_schema(Location, place={"type": "string"})),
+    "worldview": ("wx world: regional summaries, anomalies and tropics", _object("regions", "anomalies", "meta")),
+    "snowmap": (
+        "wx snowmap: expected and percentile snowfall with its timeline",
+        _object("place", "expected_cm", "p10_cm", "p90_cm", "timeline", "sources"),
+    ),
+    "favorites": ("wx favorites: one snapshot per favorite or group site", _array(dataclass_schema(PlaceReport))),
+    "group_alerts": ("wx alerts @group: active alerts per site", dataclass_schema(GroupAlertsReport)),
+    "compare": (
+        "wx compare: one snapshot per place and the optional AI comparison",
+        _object("places", places=_array(dataclass_schema(PlaceReport)), comparison={"type": "object"}),
+    ),
+    "graph": ("wx graph: one hourly series per place", _array(dataclass_schema(GraphSeries))),
+    "marine": ("wx marine: waves, swell, water temperature and tides", dataclass_schema(MarineReport)),
+    "sun": ("wx sun: sun, twilight, golden hours and moon per day", dataclass_schema(SunReport)),
+    "metar": ("wx metar: decoded METAR and TAF", dataclass_schema(AviationReport)),
+    "lake": ("wx lake: water temperature, waves and ice cover", dataclass_schema(LakeReport)),
+    "roads": ("wx roads: fog and black-ice risk per road segment and commute window", _array(dataclass_schema(RoadReport))),
+    "route": ("wx route: conditions at each stop when the drive reaches it", dataclass_schema(RouteReport)),
+    "rain": ("wx rain: 15-minute precipitation nowcast", dataclass_schema(RainReport)),
+    "fish": ("wx fish: hourly fishing scores and the best windows", dataclass_schema(FishReport)),
+    "models": ("wx models: GFS, ECMWF and ICON runs and divergences", dataclass_schema(ModelsReport, meta=_ATTRIBUTION)),
+    "radar": (
+        "wx radar: frame times and precipitation coverage",
+        _object("place", "lat", "lon", "zoom", "frames", "sources", "error", "meta", meta=_ATTRIBUTION),
+    ),
+    "history": ("wx history: past days from the archive", dataclass_schema(HistoryReport)),
+    "watch": (
+        "wx watch: one line-delimited document per refresh; a story document follows a new severe alert",
+        dataclass_schema(WatchSnapshot, changes=_array(dataclass_schema(WatchChange))),
+    ),
+    "doctor": ("wx doctor: versions, settings and connectivity checks", {"type": "object"}),
+    "cache_stats": ("wx cache stats", _object("path")),
+    "cache_entries": ("wx cache list", _array(_object("key", "namespace", "age_s"))),
+    "cache_entry": ("wx cache get: the stored timestamp (t) and value (v)", _object("t", "v")),
+    "parsed_story": (
+        "wx debug parse-story: the repaired story or why it failed",
+        dataclass_schema(ParsedStory, ok={"type": "boolean"}),
+    ),
+    "feedback_summary": ("wx feedback: average ratings per backend", _array(dataclass_schema(FeedbackSummary))),
+    "chat_session": ("wx chat --replay: a recorded session", _object("turns", turns={"type": "array"})),
+}
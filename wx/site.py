@@ -0,0 +1,140 @@
+"""``wx export site``: a self-contained static HTML dashboard with its data embedded as JSON."""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Sequence
+from dataclasses import asdict
+from datetime import UTC, datetime
+from html import escape
+from pathlib import Path
+from typing import Any
+
+from .attribution import attribution_meta, credit_line
+from .safety import SAFETY_SEVERITIES
+from .units import convert, display_unit
+from .watch import PlaceReport
+
+SITE_CSS = """
+body { font-family: system-ui, sans-serif; margin: 1.5rem; background: #fff; color: #111; }
+h1 { font-size: 1.4rem; margin: 0 0 1rem; }
+main { display: grid; gap: 1rem; grid-template-columns: repeat(auto-fill, minmax(16rem, 1fr)); }
+section { border: 2px solid #111; border-radius: 6px; padding: 0.8rem 1rem; }
+h2 { font-size: 1.1rem; margin: 0; }
+.place { color: #555; font-size: 0.85rem; }
+.temp { font-size: 2.6rem; font-weight: 700; margin: 0.3rem 0; }
+dl { display: grid; grid-template-columns: auto 1fr; gap: 0.15rem 0.8rem; margin: 0.4rem 0; }
+dt { color: #555; }
+dd { margin: 0; }
+.alert { border-left: 4px solid #111; padding-left: 0.5rem; margin: 0.3rem 0; }
+.severe { font-weight: 700; }
+.error { font-style: italic; }
+footer { margin-top: 1rem; color: #555; font-size: 0.8rem; }
+"""
+
+
+def site_html(
+    reports: list[PlaceReport],
+    *,
+    units: str,
+    generated_at: datetime | None = None,
+    attribution: Sequence[str] = (),
+) -> str:
+    """One page with a card per place and the raw reports embedded for scripts and e-ink pipelines.
+
+    ``attribution`` holds ``wx.attribution.SOURCES`` keys credited in the footer.
+    """
+
+    generated_at = generated_at or datetime.now(UTC)
+    cards = "\n".join(_card(report, units) for report in reports)
+    # "</" inside the JSON would end the script element early.
+    data = json.dumps(_site_data(reports, units, generated_at, attribution), ensure_ascii=True)
+    data = data.replace("</", "<\\/")
+    line = credit_line(attribution)
+    credits = f" · {escape(line)}" if line else ""
+    return (
+        "<!DOCTYPE html>\n"
+        '<html lang="en">\n<head>\n<meta charset="utf-8">\n'
+        '<meta name="viewport" content="width=device-width, initial-scale=1">\n'
+        "<title>wx dashboard</title>\n"
+        f"<style>{SITE_CSS}</style>\n</head>\n<body>\n"
+        "<h1>Weather</h1>\n"
+        f"<main>\n{cards}\n</main>\n"
+        f"<footer>Updated {escape(generated_at.strftime('%Y-%m-%d %H:%M UTC'))} by wx{credits}</footer>\n"
+        f'<script type="application/json" id="wx-data">{data}</script>\n'
+        "</body>\n</html>\n"
+    )
+
+
+def write_site(
+    reports: list[PlaceReport],
+    out: Path,
+    *,
+    units: str,
+    generated_at: datetime | None = None,
+    attribution: Sequence[str] = (),
+) -> list[Path]:
+    """Write ``index.html`` and ``data.json`` into ``out``; returns the written paths."""
+
+    generated_at = generated_at or datetime.now(UTC)
+    out.mkdir(parents=True, exist_ok=True)
+    index = out / "index.html"
+    page = site_html(reports, units=units, generated_at=generated_at, attribution=attribution)
+    index.write_text(page, encoding="utf-8")
+    data = out / "data.json"
+    payload = json.dumps(_site_data(reports, units, generated_at, attribution), indent=2, ensure_ascii=True)
+    data.write_text(payload + "\n", encoding="utf-8")
+    return [index, data]
+
+
+def _site_data(
+    reports: list[PlaceReport], units: str, generated_at: datetime, attribution: Sequence[str] = ()
+) -> dict[str, Any]:
+    return {
+        "generated_at": generated_at.isoformat(),
+        "units": units,
+        "places": [asdict(report) for report in reports],
+        "attribution": attribution_meta(attribution),
+    }
+
+
+def _card(report: PlaceReport, units: str) -> str:
+    title = f"<h2>{escape(report.label)}</h2>"
+    if report.snapshot is None:
+        return f'<section>{title}<p class="error">{escape(report.error or "unavailable")}</p></section>'
+    snapshot = report.snapshot
+    current, outlook = snapshot.current, snapshot.outlook
+    rows: list[tuple[str, str]] = [("Now", str(current.get("conditions") or "–"))]
+    if _number(current.get("wind")):
+        wind = f"{convert(current['wind'], 'speed', units):.0f} {display_unit('speed', units)}"
+        if _number(current.get("gust")):
+            wind += f", gusts {convert(current['gust'], 'speed', units):.0f}"
+        rows.append(("Wind", wind))
+    if _number(outlook.get("temp_min")) and _number(outlook.get("temp_max")):
+        low, high = _temp(outlook["temp_min"], units), _temp(outlook["temp_max"], units)
+        rows.append((f"Next {snapshot.outlook_hours}h", f"{low} – {high}"))
+    if _number(outlook.get("precip_prob_max")):
+        rows.append(("Precip", f"{outlook['precip_prob_max']:.0f}%"))
+    details = "".join(f"<dt>{escape(name)}</dt><dd>{escape(value)}</dd>" for name, value in rows)
+    alerts = "".join(_alert(alert) for alert in snapshot.alerts)
+    return (
+        f"<section>{title}"
+        f'<div class="place">{escape(snapshot.place)}</div>'
+        f'<div class="temp">{escape(_temp(current.get("temp"), units))}</div>'
+        f"<dl>{details}</dl>{alerts}</section>"
+    )
+
+
+def _alert(alert: dict[str, Any]) -> str:
+    severe = " severe" if alert.get("severity") in SAFETY_SEVERITIES else ""
+    return f'<div class="alert{severe}">{escape(str(alert.get("event") or "Alert"))}</div>'
+
+
+def _temp(celsius: Any, units: str) -> str:
+    if not _number(celsius):
+        return "–"
+    return f"{convert(celsius, 'temp', units):.0f}{display_unit('temp', units)}"
+
+
+def _number(value: Any) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
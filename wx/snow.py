@@ -0,0 +1,108 @@
+"""Snowfall accumulation outlook built from NWS grids and ensemble spread."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import UTC, datetime
+from typing import Any
+
+BIN_HOURS = 6
+MAX_BINS = 12  # three days of 6-hour periods
+
+
+@dataclass(slots=True)
+class SnowOutlook:
+    """Expected and percentile snowfall for a point plus its accumulation timeline."""
+
+    place: str
+    expected_cm: float | None = None
+    p10_cm: float | None = None
+    p90_cm: float | None = None
+    timeline: list[dict[str, Any]] = field(default_factory=list)
+    sources: list[str] = field(default_factory=list)
+
+    @property
+    def has_data(self) -> bool:
+        return self.expected_cm is not None
+
+
+def percentile(values: list[float], pct: float) -> float:
+    """Linear-interpolated percentile (0-100) of a non-empty list."""
+
+    ordered = sorted(values)
+    rank = (len(ordered) - 1) * pct / 100
+    low = int(rank)
+    high = min(low + 1, len(ordered) - 1)
+    return ordered[low] + (ordered[high] - ordered[low]) * (rank - low)
+
+
+def build_snow_outlook(
+    place: str,
+    grid: list[dict[str, Any]] | None,
+    ensemble: dict[str, Any] | None,
+) -> SnowOutlook:
+    """Combine the NWS snowfall grid (expected) with ensemble member totals (spread).
+
+    The NWS grid drives the expected amount and timeline when available; otherwise
+    the ensemble median stands in for both.
+    """
+
+    outlook = SnowOutlook(place=place)
+    bins: list[tuple[str, float]] = []
+
+    if ensemble:
+        totals = [sum(member) for member in ensemble["members"]]
+        outlook.p10_cm = round(percentile(totals, 10), 1)
+        outlook.p90_cm = round(percentile(totals, 90), 1)
+        outlook.expected_cm = round(percentile(totals, 50), 1)
+        bins = _ensemble_median_bins(ensemble)
+        outlook.sources.append("GFS ensemble (Open-Meteo)")
+
+    if grid:
+        bins = [(period["start_iso"], period["mm"] / 10) for period in grid][:MAX_BINS]
+        outlook.expected_cm = round(sum(amount for _, amount in bins), 1)
+        outlook.sources.insert(0, "NWS gridded forecast")
+
+    outlook.timeline = _event_timeline(bins)
+    return outlook
+
+
+def _ensemble_median_bins(ensemble: dict[str, Any]) -> list[tuple[str, float]]:
+    times: list[str] = ensemble["time"]
+    bins: list[tuple[str, float]] = []
+    for start in range(0, min(len(times), MAX_BINS * BIN_HOURS), BIN_HOURS):
+        sums = [sum(member[start : start + BIN_HOURS]) for member in ensemble["members"]]
+        bins.append((times[start], percentile(sums, 50)))
+    return bins
+
+
+def _event_timeline(bins: list[tuple[str, float]]) -> list[dict[str, Any]]:
+    """Trim quiet periods on either side of the event and accumulate totals."""
+
+    active = [idx for idx, (_, amount) in enumerate(bins) if amount >= 0.05]
+    if not active:
+        return []
+
+    timeline: list[dict[str, Any]] = []
+    cumulative = 0.0
+    for start_iso, amount in bins[active[0] : active[-1] + 1]:
+        cumulative += amount
+        timeline.append(
+            {
+                "start_iso": _normalize_start(start_iso),
+                "amount_cm": round(amount, 1),
+                "cumulative_cm": round(cumulative, 1),
+            }
+        )
+    return timeline
+
+
+def _normalize_start(value: str) -> str:
+    # Open-Meteo times are naive UTC ("2026-01-10T06:00"); NWS carries an offset.
+    try:
+        parsed = datetime.fromisoformat(value)
+    except ValueError:
+        return value
+    if parsed.tzinfo is None:
+        parsed = parsed.replace(tzinfo=UTC)
+    return parsed.astimezone(UTC).isoformat()
@@ -0,0 +1,73 @@
+"""Read a story's bottom line and top decision aloud through a local text-to-speech engine."""
+
+from __future__ import annotations
+
+import re
+import shutil
+import subprocess
+from pathlib import Path
+
+SPEAK_TIMEOUT = 120
+# Engine name -> (command to speak text, command to write a WAV file); "{text}"/"{out}" filled in.
+TTS_ENGINES: dict[str, tuple[list[str], list[str]]] = {
+    "say": (["say", "{text}"], ["say", "-o", "{out}", "--data-format=LEI16@22050", "{text}"]),
+    "espeak-ng": (["espeak-ng", "{text}"], ["espeak-ng", "-w", "{out}", "{text}"]),
+    "espeak": (["espeak", "{text}"], ["espeak", "-w", "{out}", "{text}"]),
+    "pico2wave": ([], ["pico2wave", "-w", "{out}", "{text}"]),
+    "spd-say": (["spd-say", "--wait", "{text}"], []),
+}
+_BOTTOM_LINE_PREFIX = re.compile(r"^\s*bottom line:\s*", re.IGNORECASE)
+
+
+class SpeechError(RuntimeError):
+    """Raised when no engine can speak or the engine fails."""
+
+
+def spoken_summary(response) -> str:
+    """Bottom line, then the first action (or the answer), then any safety notice."""
+
+    sections = response.sections
+    parts = [_BOTTOM_LINE_PREFIX.sub("", response.bottom_line or "").strip()]
+    answer = sections.get("answer")
+    actions = sections.get("actions")
+    if isinstance(answer, str) and answer.strip():
+        parts.insert(0, answer.strip())
+    elif isinstance(actions, list) and actions and isinstance(actions[0], str):
+        parts.append(actions[0])
+    safety = sections.get("safety")
+    if isinstance(safety, list) and safety:
+        parts.append(str(safety[-1]))
+    sentences = [_sentence(part) for part in parts if part]
+    return " ".join(dict.fromkeys(sentences))
+
+
+def find_engine(*, to_file: bool = False) -> str | None:
+    """First installed engine that can speak (or write a WAV when ``to_file``)."""
+
+    for name, (speak_cmd, file_cmd) in TTS_ENGINES.items():
+        if (file_cmd if to_file else speak_cmd) and shutil.which(name):
+            return name
+    return None
+
+
+def speak_text(text: str, *, output: Path | None = None, engine: str | None = None) -> str:
+    """Speak ``text``, or write it to ``output`` as WAV; returns the engine used."""
+
+    engine = engine or find_engine(to_file=output is not None)
+    if engine is None:
+        wanted = "write audio files" if output is not None else "speak"
+        raise SpeechError(f"No text-to-speech engine found that can {wanted}; install espeak-ng (or use macOS say).")
+    speak_cmd, file_cmd = TTS_ENGINES[engine]
+    template = file_cmd if output is not None else speak_cmd
+    command = [part.replace("{text}", text).replace("{out}", str(output)) for part in template]
+    try:
+        subprocess.run(command, check=True, capture_output=True, timeout=SPEAK_TIMEOUT)
+    except (OSError, subprocess.SubprocessError) as exc:
+        raise SpeechError(f"{engine} failed: {exc}") from exc
+    return engine
+
+
+def _sentence(text: str) -> str:
+    # A leading "-" would be read as an engine option.
+    text = " ".join(text.split()).lstrip("-• ")
+    return text if text.endswith((".", "!", "?")) else f"{text}."
@@ -0,0 +1,96 @@
+"""Stale-data watchdog: flag observations and forecasts older than a configurable bound.
+
+Station observations can lag by hours when a station stops reporting, and cached or archived
+feeds can be older than they look, so dashboards would otherwise keep showing yesterday's
+weather without a hint. Bounds come from ``WX_STALE_OBS``/``WX_STALE_FORECAST`` or the profile;
+``wx --check`` turns a stale result into a non-zero exit.
+"""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime
+from typing import Any
+
+DEFAULT_OBS_MAX_MINUTES = 120
+DEFAULT_FORECAST_MAX_MINUTES = 360
+# Exit status of ``wx --check`` when data is stale (1 is an error, 2 a usage error).
+STALE_EXIT_CODE = 3
+_DURATION = re.compile(r"^\s*(\d+(?:\.\d+)?)\s*([mh]?)\s*$", re.IGNORECASE)
+
+
+def parse_age_limit(value: str | int | None, default: int) -> int:
+    """Minutes for ``90m``/``2h`` (bare numbers are minutes); ``0`` or ``off`` turns the check off."""
+
+    if value is None or not str(value).strip():
+        return default
+    text = str(value).strip().lower()
+    if text in ("off", "none", "never"):
+        return 0
+    match = _DURATION.match(text)
+    if not match:
+        raise ValueError(f"Staleness bound must be a duration such as 90m or 2h, not '{value}'.")
+    amount, unit = float(match.group(1)), match.group(2) or "m"
+    return round(amount * (60 if unit == "h" else 1))
+
+
+def stale_data(
+    *,
+    observed_iso: str | None = None,
+    issued_iso: str | None = None,
+    max_obs_minutes: int = DEFAULT_OBS_MAX_MINUTES,
+    max_forecast_minutes: int = DEFAULT_FORECAST_MAX_MINUTES,
+    now: datetime | None = None,
+) -> list[dict[str, Any]]:
+    """``{"source", "age_minutes", "limit_minutes", "label"}`` for each input past its bound."""
+
+    now = now or datetime.now(UTC)
+    stale = []
+    for source, iso, limit in (
+        ("observation", observed_iso, max_obs_minutes),
+        ("forecast", issued_iso, max_forecast_minutes),
+    ):
+        moment = _parse_time(iso)
+        if moment is None or limit <= 0:
+            continue
+        age = max(0, round((now - moment).total_seconds() / 60))
+        if age <= limit:
+            continue
+        noun = "Observation" if source == "observation" else "Forecast issuance"
+        stale.append(
+            {
+                "source": source,
+                "age_minutes": age,
+                "limit_minutes": limit,
+                "label": f"{noun} is {format_age(age)} old (limit {format_age(limit)})",
+            }
+        )
+    return stale
+
+
+def stale_warning(stale: list[dict[str, Any]] | None) -> str | None:
+    """``STALE DATA: Observation is 3h 20m old (limit 2h)`` or None when everything is fresh."""
+
+    if not stale:
+        return None
+    return "STALE DATA: " + "; ".join(item["label"] for item in stale)
+
+
+def format_age(minutes: int) -> str:
+    if minutes < 60:
+        return f"{minutes}m"
+    if minutes >= 48 * 60:
+        return f"{minutes // 1440}d {minutes % 1440 // 60}h"
+    hours, rest = divmod(minutes, 60)
+    return f"{hours}h {rest}m" if rest else f"{hours}h"
+
+
+def _parse_time(iso: str | None) -> datetime | None:
+    if not iso:
+        return None
+    try:
+        moment = datetime.fromisoformat(str(iso).replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    # Open-Meteo reports times in the requested zone without an offset; wx requests UTC.
+    return moment if moment.tzinfo else moment.replace(tzinfo=UTC)
@@ -0,0 +1,214 @@
+"""Repair and validate AI story replies before they become a ``ForecasterResponse``.
+
+Models wrap JSON in fences or prose, leave trailing commas, run out of tokens mid-object and
+flatten the ``sections`` object. Each repair is tried only when the text still fails to parse,
+and every repair or remaining problem is recorded so ``wx debug parse-story`` can explain it.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import dataclass, field
+from typing import Any
+
+LIST_SECTIONS = ("summary", "timeline", "actions", "assumptions")
+SECTION_KEYS = (*LIST_SECTIONS, "risk_cards")
+_FENCE = re.compile(r"```[a-zA-Z]*\s*\n(.*?)(?:\n```|$)", re.S)
+_TRAILING_COMMA = re.compile(r",(\s*[}\]])")
+_PYTHON_LITERALS = {"True": "true", "False": "false", "None": "null"}
+
+
+@dataclass(slots=True)
+class ParsedStory:
+    """Result of ``parse_story``: the story object, or ``error`` when nothing could be parsed."""
+
+    data: dict[str, Any] | None = None
+    repairs: list[str] = field(default_factory=list)
+    problems: list[str] = field(default_factory=list)
+    error: str | None = None
+
+    @property
+    def ok(self) -> bool:
+        return self.data is not None
+
+
+def parse_story(raw: str) -> ParsedStory:
+    """Parse a model reply, repairing common JSON damage and normalising the story shape."""
+
+    result = ParsedStory()
+    text = raw.strip()
+    if not text:
+        result.error = "The reply is empty."
+        return result
+    data, error = _loads(text)
+    for label, repair in _REPAIRS:
+        if error is None:
+            break
+        repaired = repair(text)
+        if repaired != text:
+            text = repaired
+            result.repairs.append(label)
+            data, error = _loads(text)
+    if error is not None:
+        result.error = f"Not valid JSON: {error}"
+        return result
+    if not isinstance(data, dict):
+        result.error = f"The reply is a JSON {type(data).__name__}, not an object."
+        return result
+    result.data = _normalize(data, result)
+    return result
+
+
+def _loads(text: str) -> tuple[Any, str | None]:
+    try:
+        return json.loads(text), None
+    except json.JSONDecodeError as exc:
+        return None, f"{exc.msg} (line {exc.lineno}, column {exc.colno})"
+
+
+def _strip_fence(text: str) -> str:
+    match = _FENCE.search(text)
+    return match.group(1).strip() if match else text
+
+
+def _extract_object(text: str) -> str:
+    start = text.find("{")
+    if start == -1:
+        return text
+    try:
+        _, end = json.JSONDecoder().raw_decode(text, start)
+        return text[start:end]
+    except json.JSONDecodeError:
+        pass
+    # A damaged object: drop trailing prose, but not the tail of a reply cut off mid-string.
+    end = text.rfind("}")
+    tail = text[end + 1 :]
+    return text[start : end + 1] if end > start and '"' not in tail else text[start:]
+
+
+def _replace_curly_quotes(text: str) -> str:
+    return text.replace("“", '"').replace("”", '"')
+
+
+def _outside_strings(text: str, replace) -> str:
+    """Apply ``replace`` to each run of text that is not inside a JSON string."""
+
+    out, chunk, in_string, escaped = [], [], False, False
+    for char in text:
+        if in_string:
+            out.append(char)
+            if escaped:
+                escaped = False
+            elif char == "\\":
+                escaped = True
+            elif char == '"':
+                in_string = False
+        elif char == '"':
+            out.append(replace("".join(chunk)))
+            chunk = []
+            out.append(char)
+            in_string = True
+        else:
+            chunk.append(char)
+    out.append(replace("".join(chunk)))
+    return "".join(out)
+
+
+def _remove_trailing_commas(text: str) -> str:
+    return _outside_strings(text, lambda chunk: _TRAILING_COMMA.sub(r"\1", chunk))
+
+
+def _convert_python_literals(text: str) -> str:
+    pattern = re.compile(r"\b(True|False|None)\b")
+    return _outside_strings(text, lambda chunk: pattern.sub(lambda m: _PYTHON_LITERALS[m.group(1)], chunk))
+
+
+def _close_truncated(text: str) -> str:
+    """Close an unterminated string and any open arrays/objects, dropping a dangling key or comma."""
+
+    stack: list[str] = []
+    in_string = escaped = False
+    for char in text:
+        if in_string:
+            if escaped:
+                escaped = False
+            elif char == "\\":
+                escaped = True
+            elif char == '"':
+                in_string = False
+        elif char == '"':
+            in_string = True
+        elif char in "{[":
+            stack.append("}" if char == "{" else "]")
+        elif char in "}]" and stack:
+            stack.pop()
+    if not stack and not in_string:
+        return text
+    closed = text + ('"' if in_string else "")
+    closed = re.sub(r',?\s*"[^"]*"\s*:\s*$', "", closed)
+    closed = re.sub(r"[,:]\s*$", "", closed.rstrip())
+    return closed + "".join(reversed(stack))
+
+
+# Tried in order, each only while the text still fails to parse.
+_REPAIRS = (
+    ("stripped code fence", _strip_fence),
+    ("removed text around the JSON object", _extract_object),
+    ("replaced curly quotes", _replace_curly_quotes),
+    ("removed trailing commas", _remove_trailing_commas),
+    ("converted Python literals", _convert_python_literals),
+    ("closed truncated JSON", _close_truncated),
+)
+
+
+def _normalize(data: dict[str, Any], result: ParsedStory) -> dict[str, Any]:
+    """Coerce the story into the shape the renderer expects, noting what had to change."""
+
+    sections = data.get("sections")
+    if not isinstance(sections, dict):
+        flat = {key: data[key] for key in SECTION_KEYS if key in data}
+        if flat:
+            data = {**{key: value for key, value in data.items() if key not in flat}, "sections": flat}
+            result.repairs.append("moved top-level sections under sections")
+        elif sections is not None:
+            result.problems.append("sections is not an object")
+        sections = data.get("sections") if isinstance(data.get("sections"), dict) else {}
+
+    sections = dict(sections)
+    for key in LIST_SECTIONS:
+        value = sections.get(key)
+        if isinstance(value, str):
+            sections[key] = [value]
+            result.repairs.append(f"wrapped {key} in a list")
+        elif value is not None and not isinstance(value, list):
+            result.problems.append(f"{key} is a {type(value).__name__}, not a list")
+    cards = sections.get("risk_cards")
+    if cards is not None:
+        if not isinstance(cards, list):
+            result.problems.append("risk_cards is not a list")
+        else:
+            for idx, card in enumerate(cards, start=1):
+                if not isinstance(card, dict):
+                    result.problems.append(f"risk card {idx} is not an object")
+                    continue
+                missing = [key for key in ("hazard", "level") if not card.get(key)]
+                if missing:
+                    result.problems.append(f"risk card {idx} has no {' or '.join(missing)}")
+    if not sections.get("summary") and not data.get("answer"):
+        result.problems.append("no summary or answer")
+
+    confidence = data.get("confidence")
+    if isinstance(confidence, (int, float)) and not isinstance(confidence, bool):
+        data = {**data, "confidence": {"value": confidence, "rationale": ""}}
+        result.repairs.append("wrapped numeric confidence")
+    confidence = data.get("confidence")
+    if isinstance(confidence, dict):
+        value = confidence.get("value")
+        if not isinstance(value, (int, float)) or not 0 <= value <= 100:
+            result.problems.append(f"confidence value {value!r} is not 0-100")
+    elif confidence is not None:
+        result.problems.append("confidence is not an object")
+    if "bottom_line" in data and not isinstance(data["bottom_line"], str):
+        result.problems.append("bottom_line is not a string")
+    return {**data, "sections": sections}
@@ -0,0 +1,73 @@
+"""Custom story prompts: ``prompts/story.txt`` under the config directory, via ``wx prompt``.
+
+The template replaces the user message of forecast, risk and alerts briefings; ``{{field}}``
+placeholders are filled per request. The system prompt still fixes the JSON reply, so a custom
+template changes tone and emphasis without breaking story parsing.
+"""
+
+from __future__ import annotations
+
+import os
+import textwrap
+from pathlib import Path
+from typing import Any
+
+from .profile import CONFIG_DIR
+from .template import render_template
+
+# Starting point for ``wx prompt edit``: the built-in briefing message as a template.
+STORY_TEMPLATE = textwrap.dedent(
+    """
+    You are to answer as wx.
+    Query: {{query}}
+    Location: {{location}}
+    Focus: {{focus}}
+    Style: {{style}}
+    Persona: {{persona}}
+    Verbose: {{verbose}}
+    Active alerts:
+    {{alerts}}
+    Feature Pack JSON:
+    {{forecast}}
+    Additional instructions: {{instructions}}
+    """
+).lstrip()
+STORY_FIELDS = ("query", "location", "focus", "style", "persona", "verbose", "alerts", "forecast", "instructions")
+
+
+def story_prompt_path() -> Path:
+    """``prompts/story.txt`` under the wx config directory."""
+
+    return Path(os.getenv("WX_CONFIG_DIR", str(CONFIG_DIR))) / "prompts" / "story.txt"
+
+
+def load_story_template() -> str | None:
+    """The user's story template, or None to use the built-in prompt."""
+
+    try:
+        text = story_prompt_path().read_text(encoding="utf-8")
+    except (FileNotFoundError, IsADirectoryError):
+        return None
+    return text if text.strip() else None
+
+
+def check_story_template(template: str) -> None:
+    """Raise :class:`TemplateError` when ``template`` uses a field stories do not provide."""
+
+    render_template(template, dict.fromkeys(STORY_FIELDS, ""))
+
+
+def render_story_prompt(template: str, fields: dict[str, Any]) -> str:
+    return render_template(template, {name: fields.get(name) for name in STORY_FIELDS})
+
+
+def alert_lines(alerts: list[dict[str, Any]] | None) -> list[str]:
+    """``- Wind Advisory (Moderate) until 2026-10-17T04:00:00Z`` per alert for ``{{alerts}}``."""
+
+    lines = []
+    for alert in alerts or []:
+        line = f"- {alert.get('event') or 'Alert'} ({alert.get('severity') or 'Unknown'})"
+        if alert.get("expires_iso"):
+            line += f" until {alert['expires_iso']}"
+        lines.append(line)
+    return lines
@@ -0,0 +1,73 @@
+"""``--export csv|parquet``: forecast periods and history days as one wide row each.
+
+Column names carry their unit (``temp_f``, ``wind_mph``, ``precip_mm``) so a table read into
+pandas or DuckDB needs no side channel to say what the numbers mean.
+"""
+
+from __future__ import annotations
+
+import csv
+import io
+from typing import Any, BinaryIO
+
+from .tidy import pyarrow_modules
+
+TABLE_FORMATS = ("csv", "parquet")
+# Feature Pack period key and its unit kind; kinds map to a column suffix per unit system.
+PERIOD_FIELDS = (
+    ("temp", "temp"),
+    ("feels_like", "temp"),
+    ("dewpoint", "temp"),
+    ("wind", "speed"),
+    ("wind_dir", "deg"),
+    ("gust", "speed"),
+    ("precip_prob", "pct"),
+    ("precip", "depth"),
+)
+_SUFFIXES = {
+    "imperial": {"temp": "f", "speed": "mph", "depth": "in"},
+    "metric": {"temp": "c", "speed": "ms", "depth": "mm"},
+}
+
+
+def forecast_rows(result, *, units: str) -> list[dict[str, Any]]:
+    """One row per Feature Pack period: place, start, length and every numeric field."""
+
+    feature_pack = result.feature_pack or {}
+    place_info = feature_pack.get("place") or {}
+    place = place_info.get("resolved") or result.query
+    suffixes = _SUFFIXES.get(units, _SUFFIXES["metric"])
+    rows = []
+    for period in feature_pack.get("periods") or []:
+        row: dict[str, Any] = {
+            "place": place,
+            "lat": place_info.get("lat"),
+            "lon": place_info.get("lon"),
+            "start_iso": period.get("start_iso"),
+            "hours": period.get("hours"),
+        }
+        for key, kind in PERIOD_FIELDS:
+            row[f"{key}_{suffixes.get(kind, kind)}"] = period.get(key)
+        rows.append(row)
+    return rows
+
+
+def history_table(report) -> list[dict[str, Any]]:
+    """``wx history`` days; the archive's metric column names already name their units."""
+
+    return [{"place": report.place, **day} for day in report.days]
+
+
+def write_table(rows: list[dict[str, Any]], fmt: str, stream: BinaryIO) -> None:
+    """Write ``rows`` as CSV (UTF-8) or Parquet to a binary stream such as ``sys.stdout.buffer``."""
+
+    if fmt == "parquet":
+        pa, pq = pyarrow_modules()
+        pq.write_table(pa.Table.from_pylist(rows), stream)
+        return
+    text = io.TextIOWrapper(stream, encoding="utf-8", newline="", write_through=True)
+    columns = list(rows[0]) if rows else []
+    writer = csv.DictWriter(text, fieldnames=columns, lineterminator="\n")
+    writer.writeheader()
+    writer.writerows(rows)
+    text.detach()
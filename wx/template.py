@@ -0,0 +1,120 @@
+"""Minimal ``{{name}}`` templating over command output for ``--template``."""
+
+from __future__ import annotations
+
+import json
+import re
+from pathlib import Path
+from typing import Any
+
+from .attribution import credit_line
+from .staleness import stale_warning
+
+_PLACEHOLDER = re.compile(r"\{\{\s*([A-Za-z_][\w.]*)\s*\}\}")
+
+
+class TemplateError(ValueError):
+    """Raised when a template references a field the command does not produce."""
+
+
+def load_template(value: str) -> str:
+    """Read ``value`` as a template file when it names one, otherwise use it inline.
+
+    Inline templates may use ``\\n`` and ``\\t`` escapes since shells make literal
+    newlines awkward.
+    """
+
+    path = Path(value).expanduser()
+    try:
+        if path.is_file():
+            return path.read_text(encoding="utf-8")
+    except OSError:
+        pass
+    return value.replace("\\n", "\n").replace("\\t", "\t")
+
+
+def render_template(template: str, context: dict[str, Any]) -> str:
+    """Substitute ``{{field}}`` and dotted ``{{a.b.0}}`` paths from ``context``.
+
+    Unknown top-level fields raise :class:`TemplateError`; missing nested values and
+    ``None`` render as empty strings so scripts keep working when data is unavailable.
+    """
+
+    def substitute(match: re.Match[str]) -> str:
+        head, *rest = match.group(1).split(".")
+        if head not in context:
+            raise TemplateError(
+                f"Unknown template field '{head}'. Available: {', '.join(sorted(context))}."
+            )
+        value: Any = context[head]
+        for part in rest:
+            if isinstance(value, dict):
+                value = value.get(part)
+            elif isinstance(value, list) and part.isdigit() and int(part) < len(value):
+                value = value[int(part)]
+            else:
+                value = None
+            if value is None:
+                break
+        return _format_value(value)
+
+    return _PLACEHOLDER.sub(substitute, template)
+
+
+def _format_value(value: Any) -> str:
+    if value is None:
+        return ""
+    if isinstance(value, bool):
+        return "yes" if value else "no"
+    if isinstance(value, float):
+        return f"{value:g}"
+    if isinstance(value, list):
+        if all(not isinstance(item, (dict, list)) for item in value):
+            return ", ".join(_format_value(item) for item in value)
+        return json.dumps(value, ensure_ascii=False)
+    if isinstance(value, dict):
+        return json.dumps(value, ensure_ascii=False)
+    return str(value)
+
+
+def result_context(payload: dict[str, Any]) -> dict[str, Any]:
+    """Template fields for forecast/risk/alerts/question results.
+
+    Alongside the full ``--json`` payload this adds flat shortcuts such as ``temp``,
+    ``unit``, ``conditions`` and ``bottom_line`` in the Feature Pack's units.
+    """
+
+    feature_pack = payload.get("feature_pack") or {}
+    unit_pack = feature_pack.get("units") or {}
+    response = payload.get("response") or {}
+    sections = response.get("sections") or {}
+    obs = feature_pack.get("obs_quick") or {}
+    first_period = (feature_pack.get("periods") or [{}])[0]
+
+    def current(key: str) -> float | None:
+        # Prefer the quick observation; fall back to the first forecast period.
+        value = obs.get(key)
+        if isinstance(value, (int, float)):
+            return round(value)
+        return first_period.get(key)
+
+    summary = sections.get("summary")
+    return {
+        **payload,
+        "place": (feature_pack.get("place") or {}).get("resolved") or payload.get("query"),
+        "unit": unit_pack.get("temp"),
+        "temp": current("temp"),
+        "feels_like": current("feels_like"),
+        "wind": current("wind"),
+        "gust": current("gust"),
+        "conditions": obs.get("conditions"),
+        "summary": " ".join(summary) if isinstance(summary, list) else summary,
+        "answer": sections.get("answer"),
+        "bottom_line": response.get("bottom_line"),
+        "confidence": (response.get("confidence") or {}).get("value"),
+        "provider": response.get("provider"),
+        "alerts": [alert.get("event") for alert in feature_pack.get("alerts_quick") or []],
+        "stale": stale_warning(feature_pack.get("stale")),
+        "warnings": feature_pack.get("warnings") or [],
+        "credits": credit_line(entry["source"] for entry in (payload.get("meta") or {}).get("attribution") or []),
+    }
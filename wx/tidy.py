@@ -0,0 +1,128 @@
+"""``wx export data``: hourly forecasts as tidy long-format tables for joining onto other data.
+
+Every row is one location, hour and variable: ``location, time, variable, value, unit``. Times are
+UTC ISO 8601 and values are in the active units, so the file reads the same in any spreadsheet,
+pandas or a warehouse loader.
+"""
+
+from __future__ import annotations
+
+import csv
+import re
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+from typing import Any, TextIO
+
+from .units import convert, display_unit
+
+DATA_FORMATS = ("csv", "parquet")
+DEFAULT_HORIZON = "48h"
+# Open-Meteo forecasts reach 16 days.
+MAX_HORIZON_HOURS = 16 * 24
+COLUMNS = ("location", "time", "variable", "value", "unit")
+# Open-Meteo hourly key, exported variable name, and the wx.units kind (or a fixed unit).
+TIDY_VARIABLES = (
+    ("temperature_2m", "temperature", "temp"),
+    ("apparent_temperature", "feels_like", "temp"),
+    ("dew_point_2m", "dew_point", "temp"),
+    ("precipitation", "precipitation", "depth"),
+    ("precipitation_probability", "precipitation_probability", "percent"),
+    ("wind_speed_10m", "wind_speed", "speed"),
+    ("wind_gusts_10m", "wind_gust", "speed"),
+    ("wind_direction_10m", "wind_direction", "°"),
+    ("pressure_msl", "pressure", "pressure"),
+    ("cloud_cover", "cloud_cover", "percent"),
+    ("uv_index", "uv_index", "index"),
+    ("cape", "cape", "energy"),
+)
+_UNIT_KINDS = ("temp", "speed", "percent", "depth", "energy", "pressure")
+_HORIZON = re.compile(r"^\s*(\d+)\s*([hd]?)\s*$", re.IGNORECASE)
+
+
+class DataExportError(RuntimeError):
+    """The table could not be written (e.g. pyarrow is missing for Parquet)."""
+
+
+@dataclass(slots=True)
+class SiteSeries:
+    """A location's hourly forecast (Open-Meteo keys, metric), or why it could not be fetched."""
+
+    label: str
+    place: str
+    hourly: dict[str, list[Any]] | None = None
+    error: str | None = None
+
+
+def parse_horizon(value: str) -> int:
+    """Hours for ``48h``/``7d`` (bare numbers are hours), up to MAX_HORIZON_HOURS."""
+
+    match = _HORIZON.match(value)
+    hours = int(match.group(1)) * (24 if match.group(2).lower() == "d" else 1) if match else 0
+    if not 1 <= hours <= MAX_HORIZON_HOURS:
+        raise ValueError(f"Horizon must be like 48h or 7d, up to {MAX_HORIZON_HOURS // 24}d, not '{value}'.")
+    return hours
+
+
+def tidy_rows(series: list[SiteSeries], *, units: str) -> list[dict[str, Any]]:
+    """One row per location, hour and variable with a value; variables a provider lacks are skipped."""
+
+    rows = []
+    for site in series:
+        hourly = site.hourly or {}
+        times = hourly.get("time") or []
+        for key, variable, kind in TIDY_VARIABLES:
+            values = hourly.get(key)
+            if not isinstance(values, list):
+                continue
+            unit = display_unit(kind, units) if kind in _UNIT_KINDS else kind
+            for stamp, value in zip(times, values, strict=False):
+                if not isinstance(value, (int, float)) or isinstance(value, bool):
+                    continue
+                number = convert(value, kind, units) if kind in _UNIT_KINDS else value
+                rows.append(dict(zip(COLUMNS, (site.label, _utc_stamp(stamp), variable, number, unit), strict=True)))
+    return rows
+
+
+def write_csv(rows: list[dict[str, Any]], stream: TextIO) -> None:
+    writer = csv.DictWriter(stream, fieldnames=COLUMNS, lineterminator="\n")
+    writer.writeheader()
+    writer.writerows(rows)
+
+
+def write_parquet(rows: list[dict[str, Any]], path: Path) -> None:
+    """Write ``rows`` with a fixed schema; needs the optional pyarrow dependency."""
+
+    pa, pq = pyarrow_modules()
+    schema = pa.schema(
+        [
+            ("location", pa.string()),
+            ("time", pa.timestamp("s", tz="UTC")),
+            ("variable", pa.string()),
+            ("value", pa.float64()),
+            ("unit", pa.string()),
+        ]
+    )
+    columns = {name: [row[name] for row in rows] for name in COLUMNS}
+    columns["time"] = [datetime.fromisoformat(stamp.replace("Z", "+00:00")) for stamp in columns["time"]]
+    pq.write_table(pa.table(columns, schema=schema), path)
+
+
+def pyarrow_modules():
+    """``pyarrow`` and ``pyarrow.parquet``, or a DataExportError saying how to install them."""
+
+    try:
+        import pyarrow as pa
+        import pyarrow.parquet as pq
+    except ImportError as exc:
+        raise DataExportError("Parquet export needs pyarrow: pip install 'wx-cli[parquet]'.") from exc
+    return pa, pq
+
+
+def _utc_stamp(stamp: Any) -> str:
+    """Open-Meteo's ``2026-10-16T15:00`` (UTC) as ``2026-10-16T15:00:00Z``."""
+
+    text = str(stamp)
+    if text.endswith("Z") or re.search(r"[+-]\d{2}:\d{2}$", text):
+        return text
+    return f"{text}:00Z" if len(text) == 16 else f"{text}Z"
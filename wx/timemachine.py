@@ -0,0 +1,80 @@
+"""``wx forecast --at``: the forecast as it would have looked at a past moment.
+
+Open-Meteo's Previous Runs API keeps, for every hour, the value from the model runs issued one to
+seven days earlier. An hour ``lead`` hours after ``--at`` takes the run from ceil(lead / 24) days
+before it, so nothing issued after ``--at`` leaks into the story.
+"""
+
+from __future__ import annotations
+
+import math
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from .fetchers import HOURLY_FORECAST_VARIABLES, get_previous_runs_forecast
+
+# The Previous Runs archive starts in January 2024 and keeps runs up to seven days back.
+EARLIEST_AT = datetime(2024, 1, 1, tzinfo=UTC)
+MAX_LEAD_DAYS = 7
+
+
+def validate_at(at: datetime, *, now: datetime | None = None) -> datetime:
+    """Check an aware ``--at`` moment; raises ValueError with a user-facing message."""
+
+    if at > (now or datetime.now(UTC)):
+        raise ValueError("--at must be in the past.")
+    if at < EARLIEST_AT:
+        raise ValueError(f"Archived model runs start on {EARLIEST_AT.date().isoformat()}.")
+    return at
+
+
+def lead_days(at: datetime, moment: datetime) -> int:
+    """How many days before ``moment`` the run used for it was issued (0 for hours up to ``at``)."""
+
+    hours = (moment - at).total_seconds() / 3600
+    return min(MAX_LEAD_DAYS, max(0, math.ceil(hours / 24)))
+
+
+def stitch_previous_runs(
+    hourly: dict[str, list[Any]], at: datetime, variables: tuple[str, ...] = HOURLY_FORECAST_VARIABLES
+) -> dict[str, list[Any]]:
+    """Hourly series in the ``get_hourly_forecast`` shape, each hour from the newest run before ``at``."""
+
+    stitched: dict[str, list[Any]] = {"time": list(hourly.get("time") or [])}
+    for name in variables:
+        stitched[name] = []
+    for idx, stamp in enumerate(stitched["time"]):
+        moment = datetime.fromisoformat(str(stamp))
+        lead = lead_days(at, moment if moment.tzinfo else moment.replace(tzinfo=UTC))
+        for name in variables:
+            series = hourly.get(name if lead == 0 else f"{name}_previous_day{lead}") or []
+            stitched[name].append(series[idx] if idx < len(series) else None)
+    return stitched
+
+
+class ArchivedProvider:
+    """Serve forecasts from model runs issued before ``at``; observations and alerts are not replayed."""
+
+    attribution: tuple[str, ...] = ("open-meteo",)
+
+    def __init__(self, inner, at: datetime, *, offline: bool = False) -> None:
+        self.inner = inner
+        self.at = at
+        self.offline = offline
+        self.name = f"{inner.name} archive"
+
+    def geocode(self, place: str) -> dict[str, Any] | None:
+        return self.inner.geocode(place)
+
+    def current(self, lat: float, lon: float) -> dict[str, Any] | None:
+        return None
+
+    def forecast(self, lat: float, lon: float, *, hours: int = 48) -> dict[str, list[Any]] | None:
+        start = self.at.astimezone(UTC).replace(minute=0, second=0, microsecond=0)
+        hours = min(hours, MAX_LEAD_DAYS * 24)
+        days = lead_days(self.at, start + timedelta(hours=hours))
+        hourly = get_previous_runs_forecast(lat, lon, start, hours=hours, days=days, offline=self.offline)
+        return stitch_previous_runs(hourly, self.at) if hourly else None
+
+    def alerts(self, lat: float, lon: float) -> list[dict[str, Any]]:
+        return []
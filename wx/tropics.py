@@ -0,0 +1,150 @@
+"""Tropical cyclone basin summaries for the world view."""
+
+from __future__ import annotations
+
+import math
+from datetime import date
+from typing import Any
+
+from .fetchers import TropicalCyclone
+from .geo import haversine_km
+
+# Official season windows (month, day) per basin, inclusive.
+BASIN_SEASONS = {
+    "Atlantic": ((6, 1), (11, 30)),
+    "Eastern Pacific": ((5, 15), (11, 30)),
+    "Central Pacific": ((6, 1), (11, 30)),
+}
+
+CLASSIFICATION_NAMES = {
+    "HU": "Hurricane",
+    "TS": "Tropical Storm",
+    "TD": "Tropical Depression",
+    "STS": "Subtropical Storm",
+    "STD": "Subtropical Depression",
+    "PTC": "Potential Tropical Cyclone",
+    "PC": "Post-tropical Cyclone",
+}
+
+# Coarse reference points used to describe where a storm is heading.
+LANDMASSES = (
+    ("the Lesser Antilles", 15.0, -61.0),
+    ("Puerto Rico", 18.2, -66.5),
+    ("Hispaniola", 19.0, -71.0),
+    ("Jamaica", 18.1, -77.3),
+    ("Cuba", 21.8, -79.5),
+    ("the Bahamas", 24.5, -77.5),
+    ("Florida", 27.8, -81.7),
+    ("the central Gulf Coast", 29.5, -91.0),
+    ("the Texas coast", 28.0, -96.5),
+    ("the Carolinas", 34.5, -78.0),
+    ("the US Northeast", 41.0, -71.0),
+    ("Atlantic Canada", 45.0, -63.0),
+    ("Bermuda", 32.3, -64.8),
+    ("the Yucatán Peninsula", 20.5, -88.5),
+    ("Central America", 15.0, -85.0),
+    ("the Azores", 38.5, -28.0),
+    ("Baja California", 26.0, -111.5),
+    ("Mexico's Pacific coast", 18.0, -103.0),
+    ("Hawaii", 20.8, -156.5),
+)
+
+_TOWARD_MAX_KM = 1500.0
+_TOWARD_MAX_OFFSET_DEG = 45.0
+_COMPASS = ("N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW")
+
+
+def in_season(basin: str, today: date) -> bool:
+    """Return True when ``today`` falls inside the basin's official season."""
+
+    window = BASIN_SEASONS.get(basin)
+    if window is None:
+        return False
+    start, end = window
+    return start <= (today.month, today.day) <= end
+
+
+def any_basin_in_season(today: date) -> bool:
+    return any(in_season(basin, today) for basin in BASIN_SEASONS)
+
+
+def saffir_simpson_category(intensity_kt: int | None) -> int | None:
+    """Map sustained wind (kt) to a Saffir-Simpson category, or None below hurricane force."""
+
+    if intensity_kt is None or intensity_kt < 64:
+        return None
+    for category, floor in ((5, 137), (4, 113), (3, 96), (2, 83)):
+        if intensity_kt >= floor:
+            return category
+    return 1
+
+
+def compass_point(degrees: float) -> str:
+    return _COMPASS[int((degrees % 360) / 22.5 + 0.5) % 16]
+
+
+def compass_degrees(point: str) -> float | None:
+    """Inverse of :func:`compass_point` ("NW" -> 315.0); None for unknown text."""
+
+    key = point.strip().upper()
+    return _COMPASS.index(key) * 22.5 if key in _COMPASS else None
+
+
+def heading_toward(storm: TropicalCyclone) -> str | None:
+    """Name the nearest reference landmass roughly ahead of the storm's motion."""
+
+    if storm.lat is None or storm.lon is None or storm.movement_dir is None:
+        return None
+    best: tuple[float, str] | None = None
+    for name, lat, lon in LANDMASSES:
+        distance = haversine_km(storm.lat, storm.lon, lat, lon)
+        if distance > _TOWARD_MAX_KM:
+            continue
+        offset = abs((_bearing(storm.lat, storm.lon, lat, lon) - storm.movement_dir + 180) % 360 - 180)
+        if offset > _TOWARD_MAX_OFFSET_DEG:
+            continue
+        if best is None or distance < best[0]:
+            best = (distance, name)
+    return best[1] if best else None
+
+
+def summarize_basins(
+    storms: list[TropicalCyclone], *, today: date, severe_only: bool = False
+) -> list[dict[str, Any]]:
+    """Group active storms by basin for rendering.
+
+    In-season basins without storms are reported as quiet. With ``severe_only``
+    only basins holding an active hurricane are kept.
+    """
+
+    basins: dict[str, list[dict[str, Any]]] = {
+        basin: [] for basin in BASIN_SEASONS if in_season(basin, today)
+    }
+    for storm in sorted(storms, key=lambda s: s.intensity_kt or 0, reverse=True):
+        category = saffir_simpson_category(storm.intensity_kt)
+        basins.setdefault(storm.basin, []).append(
+            {
+                "name": storm.name,
+                "classification": CLASSIFICATION_NAMES.get(storm.classification, storm.classification),
+                "category": category,
+                "intensity_kt": storm.intensity_kt,
+                "heading": compass_point(storm.movement_dir) if storm.movement_dir is not None else None,
+                "toward": heading_toward(storm),
+                "hurricane": storm.classification == "HU",
+            }
+        )
+
+    summaries = []
+    for basin, entries in basins.items():
+        if severe_only and not any(entry["hurricane"] for entry in entries):
+            continue
+        summaries.append({"basin": basin, "storms": entries})
+    return summaries
+
+
+def _bearing(lat1: float, lon1: float, lat2: float, lon2: float) -> float:
+    phi1, phi2 = math.radians(lat1), math.radians(lat2)
+    dlambda = math.radians(lon2 - lon1)
+    x = math.sin(dlambda) * math.cos(phi2)
+    y = math.cos(phi1) * math.sin(phi2) - math.sin(phi1) * math.cos(phi2) * math.cos(dlambda)
+    return (math.degrees(math.atan2(x, y)) + 360) % 360
@@ -0,0 +1,286 @@
+"""Full-screen ``wx tui`` dashboard drawn with rich's live display."""
+
+from __future__ import annotations
+
+import os
+import select
+import sys
+import time
+from collections.abc import Iterator
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Any
+
+from rich.console import Console, Group
+from rich.layout import Layout
+from rich.live import Live
+from rich.panel import Panel
+from rich.text import Text
+
+from .cache import CachingProvider
+from .graph import sparkline
+from .units import convert, display_unit
+
+REFRESH_SECONDS = 600
+CHART_HOURS = 24
+KEY_HELP = "←/→ switch · 1-9 jump · r refresh · q quit"
+_SEVERITY_STYLES = {"Extreme": "bold red", "Severe": "red", "Moderate": "yellow"}
+
+
+@dataclass(slots=True)
+class DashboardData:
+    """Everything one location's dashboard shows, fetched in a single pass."""
+
+    place: str
+    resolved: str | None = None
+    current: dict[str, Any] | None = None
+    hourly: dict[str, list[Any]] | None = None
+    alerts: list[dict[str, Any]] = field(default_factory=list)
+    timeline: list[str] = field(default_factory=list)
+    bottom_line: str | None = None
+    fetched_at: float = field(default_factory=time.time)
+
+
+def load_dashboard(orchestrator, place: str, *, refresh: bool = False) -> DashboardData:
+    """Fetch conditions, the hourly forecast, alerts and the AI timeline for ``place``.
+
+    ``refresh`` skips the fetch cache so the view reflects the latest upstream data.
+    """
+
+    provider = orchestrator.provider
+    if isinstance(provider, CachingProvider):
+        provider.refresh = refresh
+    try:
+        result = orchestrator.handle_forecast(
+            place, when_text=None, horizon=f"{CHART_HOURS}h", focus=None, verbose=False
+        )
+        info = result.feature_pack.get("place") or {}
+        data = DashboardData(
+            place=place,
+            resolved=info.get("resolved"),
+            alerts=list(result.feature_pack.get("alerts_quick") or []),
+            timeline=[item for item in result.response.sections.get("timeline") or [] if isinstance(item, str)],
+            bottom_line=result.response.bottom_line,
+        )
+        lat, lon = info.get("lat"), info.get("lon")
+        if isinstance(lat, (int, float)) and isinstance(lon, (int, float)):
+            data.current = provider.current(lat, lon)
+            # Same request handle_forecast made, so the cache answers it.
+            data.hourly = provider.forecast(lat, lon, hours=48)
+    finally:
+        if isinstance(provider, CachingProvider):
+            provider.refresh = False
+    return data
+
+
+def build_dashboard(
+    data: DashboardData | None,
+    *,
+    places: list[str],
+    index: int,
+    units: str,
+    status: str | None = None,
+) -> Layout:
+    """Lay out header, conditions, alerts, hourly chart and timeline for the selected place."""
+
+    tabs = Text()
+    for position, place in enumerate(places):
+        style = "bold reverse" if position == index else "dim"
+        tabs.append(f" {position + 1} {place} ", style=style)
+        tabs.append(" ")
+    header = Text.assemble(("wx ", "bold cyan"), tabs)
+    if data is not None:
+        updated = datetime.fromtimestamp(data.fetched_at).strftime("%H:%M")
+        header.append(f"  updated {updated}", style="dim")
+    if status:
+        header.append(f"  {status}", style="yellow")
+
+    layout = Layout()
+    layout.split_column(
+        Layout(Panel(header, padding=(0, 1)), name="header", size=3),
+        Layout(name="body"),
+        Layout(Text(KEY_HELP, style="dim", justify="center"), name="footer", size=1),
+    )
+    if data is None:
+        layout["body"].update(Panel(Text("Loading…", justify="center"), border_style="dim"))
+        return layout
+
+    layout["body"].split_row(Layout(name="left", ratio=2), Layout(name="right", ratio=3))
+    layout["left"].split_column(
+        Layout(_current_panel(data, units), name="current"),
+        Layout(_alerts_panel(data.alerts), name="alerts"),
+    )
+    layout["right"].split_column(
+        Layout(_chart_panel(data.hourly, units), name="chart", size=9),
+        Layout(_timeline_panel(data), name="timeline"),
+    )
+    return layout
+
+
+def run_dashboard(
+    orchestrator,
+    places: list[str],
+    console: Console,
+    *,
+    interval: float = REFRESH_SECONDS,
+) -> None:
+    """Run the interactive dashboard until the user presses ``q``."""
+
+    units = orchestrator.settings.units
+    loaded: dict[int, DashboardData] = {}
+    index = 0
+
+    def draw(live: Live, status: str | None = None) -> None:
+        live.update(build_dashboard(loaded.get(index), places=places, index=index, units=units, status=status))
+        live.refresh()
+
+    with _raw_keys() as read_key, Live(console=console, screen=True, auto_refresh=False) as live:
+        while True:
+            data = loaded.get(index)
+            if data is None or time.time() - data.fetched_at >= interval:
+                draw(live, "loading…")
+                loaded[index] = load_dashboard(orchestrator, places[index], refresh=data is not None)
+            draw(live)
+            key = read_key(max(1.0, interval - (time.time() - loaded[index].fetched_at)))
+            if key in ("q", "\x1b", "\x03"):
+                return
+            if key in ("right", "n", "\t"):
+                index = (index + 1) % len(places)
+            elif key in ("left", "p"):
+                index = (index - 1) % len(places)
+            elif key is not None and key.isdigit() and 0 < int(key) <= len(places):
+                index = int(key) - 1
+            elif key == "r":
+                draw(live, "refreshing…")
+                loaded[index] = load_dashboard(orchestrator, places[index], refresh=True)
+
+
+def _current_panel(data: DashboardData, units: str) -> Panel:
+    current = data.current or {}
+    temp_unit = display_unit("temp", units)
+    speed_unit = display_unit("speed", units)
+
+    def value(key: str, kind: str) -> str | None:
+        raw = current.get(key)
+        return f"{convert(raw, kind, units):.0f}" if isinstance(raw, (int, float)) else None
+
+    lines = Text()
+    temp = value("temp", "temp")
+    lines.append(f"{temp}{temp_unit}" if temp else "–", style="bold")
+    if current.get("conditions"):
+        lines.append(f"  {current['conditions']}")
+    feels = value("feels_like", "temp")
+    if feels:
+        lines.append(f"\nFeels like {feels}{temp_unit}")
+    wind = value("wind", "speed")
+    if wind:
+        gust = value("gust", "speed")
+        lines.append(f"\nWind {wind} {speed_unit}" + (f", gusts {gust}" if gust else ""))
+    if isinstance(current.get("vis_km"), (int, float)):
+        lines.append(f"\nVisibility {current['vis_km']:.0f} km")
+    if data.bottom_line:
+        lines.append(f"\n\n{data.bottom_line}", style="italic")
+    return Panel(lines, title=data.resolved or data.place, border_style="cyan")
+
+
+def _alerts_panel(alerts: list[dict[str, Any]]) -> Panel:
+    if not alerts:
+        return Panel(Text("No active alerts.", style="green"), title="Alerts", border_style="green")
+    body = Text()
+    for alert in alerts:
+        severity = alert.get("severity") or "Unknown"
+        body.append(f"● {alert.get('event') or 'Alert'}", style=_SEVERITY_STYLES.get(severity, "bold"))
+        if alert.get("headline"):
+            body.append(f"\n  {alert['headline']}", style="dim")
+        body.append("\n")
+    return Panel(body, title=f"Alerts ({len(alerts)})", border_style="red")
+
+
+def _chart_panel(hourly: dict[str, list[Any]] | None, units: str) -> Panel:
+    hourly = hourly or {}
+    times = (hourly.get("time") or [])[:CHART_HOURS]
+    temps = [
+        convert(value, "temp", units) if isinstance(value, (int, float)) else None
+        for value in (hourly.get("temperature_2m") or [])[: len(times)]
+    ]
+    probs = [
+        value if isinstance(value, (int, float)) else None
+        for value in (hourly.get("precipitation_probability") or [])[: len(times)]
+    ]
+    if not any(value is not None for value in temps):
+        return Panel(Text("No hourly forecast available.", style="dim"), title="Next 24h")
+
+    known = [value for value in temps if value is not None]
+    unit = display_unit("temp", units)
+    ticks = [" "] * (len(times) + 2)
+    for col, stamp in enumerate(times):
+        hour = datetime.fromisoformat(stamp).hour
+        if hour % 6 == 0 and col + 2 <= len(ticks):
+            ticks[col : col + 2] = list(f"{hour:02d}")
+    hours = Text("".join(ticks).rstrip(), style="dim")
+    rows = [
+        Text.assemble(("Temp   ", "bold"), (sparkline(temps), "red"), f"  {min(known):.0f}–{max(known):.0f}{unit}")
+    ]
+    if any(value is not None for value in probs):
+        peak = max(value for value in probs if value is not None)
+        rows.append(
+            Text.assemble(("Precip ", "bold"), (sparkline(probs, low=0, high=100), "blue"), f"  max {peak:.0f}%")
+        )
+    rows.append(Text.assemble("       ", hours))
+    return Panel(Group(*rows), title=f"Next {len(times)}h", border_style="magenta")
+
+
+def _timeline_panel(data: DashboardData) -> Panel:
+    if not data.timeline:
+        return Panel(Text("No timeline from the forecaster.", style="dim"), title="Timeline")
+    body = Text("\n".join(f"• {item}" for item in data.timeline))
+    return Panel(body, title="Timeline", border_style="blue")
+
+
+@contextmanager
+def _raw_keys() -> Iterator[Any]:
+    """Yield ``read(timeout) -> key | None`` with the terminal in cbreak mode."""
+
+    if os.name == "nt":  # pragma: no cover - Windows console
+        import msvcrt
+
+        def read_windows(timeout: float) -> str | None:
+            deadline = time.time() + timeout
+            while time.time() < deadline:
+                if msvcrt.kbhit():
+                    char = msvcrt.getwch()
+                    if char in ("\x00", "\xe0"):
+                        return {"M": "right", "K": "left"}.get(msvcrt.getwch())
+                    return char
+                time.sleep(0.05)
+            return None
+
+        yield read_windows
+        return
+
+    import termios
+    import tty
+
+    fd = sys.stdin.fileno()
+    saved = termios.tcgetattr(fd)
+
+    def read_posix(timeout: float) -> str | None:
+        ready, _, _ = select.select([fd], [], [], timeout)
+        if not ready:
+            return None
+        char = os.read(fd, 1).decode(errors="ignore")
+        if char != "\x1b":
+            return char
+        # Arrow keys arrive as ESC [ C / ESC [ D; a lone ESC quits.
+        ready, _, _ = select.select([fd], [], [], 0.05)
+        if not ready:
+            return char
+        sequence = os.read(fd, 2).decode(errors="ignore")
+        return {"[C": "right", "[D": "left"}.get(sequence)
+
+    try:
+        tty.setcbreak(fd)
+        yield read_posix
+    finally:
+        termios.tcsetattr(fd, termios.TCSADRAIN, saved)
@@ -0,0 +1,88 @@
+"""Imperial/metric conversion of Open-Meteo's metric values for display and AI prompts."""
+
+from __future__ import annotations
+
+from typing import Any
+
+UNIT_SYSTEMS = ("imperial", "metric")
+
+
+def parse_units(value: str) -> str:
+    """``imperial``/``metric`` (prefixes like ``us`` or ``si`` accepted)."""
+
+    text = value.strip().lower()
+    if text in {"us", "f", "imperial"} or (text and "imperial".startswith(text)):
+        return "imperial"
+    if text in {"si", "c", "metric"} or (text and "metric".startswith(text)):
+        return "metric"
+    raise ValueError(f"Unknown units '{value}'. Use imperial or metric.")
+
+
+def unit_pack(units: str) -> dict[str, str]:
+    """Units the Feature Pack's converted values are in, so the model labels them correctly."""
+
+    if units == "metric":
+        return {"temp": "C", "wind": "mps", "precip": "mm"}
+    return {"temp": "F", "wind": "mph", "precip": "in"}
+
+
+def display_unit(kind: str, units: str) -> str:
+    imperial = units == "imperial"
+    return {
+        "temp": "°F" if imperial else "°C",
+        "speed": "mph" if imperial else "m/s",
+        "percent": "%",
+        "depth": "in" if imperial else "mm",
+        "energy": "J/kg",
+        "pressure": "inHg" if imperial else "hPa",
+    }[kind]
+
+
+def convert(value: float, kind: str, units: str) -> float:
+    """Convert an Open-Meteo metric value into the display units."""
+
+    if units != "imperial":
+        return round(value / 3.6, 1) if kind == "speed" else value
+    if kind == "temp":
+        return round(value * 9 / 5 + 32, 1)
+    if kind == "speed":
+        return round(value / 1.609, 1)
+    if kind == "depth":
+        return round(value / 25.4, 2)
+    if kind == "pressure":
+        return round(value * 0.02953, 2)
+    return value
+
+
+def display_temp(celsius: float | None, units: str) -> float | None:
+    if celsius is None:
+        return None
+    return round(celsius * 9 / 5 + 32) if units == "imperial" else round(celsius, 1)
+
+
+def display_speed(kmh: float | None, units: str) -> float | None:
+    if kmh is None:
+        return None
+    return round(kmh / 1.609) if units == "imperial" else round(kmh / 3.6, 1)
+
+
+def display_depth(mm: float, units: str) -> float:
+    return round(mm / 25.4, 2) if units == "imperial" else round(mm, 1)
+
+
+def display_observation(obs: dict[str, Any], units: str) -> dict[str, Any]:
+    """Current conditions with temperature, wind and precipitation in ``units``.
+
+    Keys that name their unit (``vis_km``, ``ceiling_m``) stay metric.
+    """
+
+    converted = dict(obs)
+    for key in ("temp", "feels_like", "dewpoint"):
+        if isinstance(obs.get(key), (int, float)):
+            converted[key] = display_temp(obs[key], units)
+    for key in ("wind", "gust"):
+        if isinstance(obs.get(key), (int, float)):
+            converted[key] = display_speed(obs[key], units)
+    if isinstance(obs.get("precip_last_hr"), (int, float)):
+        converted["precip_last_hr"] = display_depth(obs["precip_last_hr"], units)
+    return converted
@@ -0,0 +1,120 @@
+"""UV index: WHO exposure categories and sun-safety decisions for outdoor plans."""
+
+from __future__ import annotations
+
+import re
+from datetime import UTC, datetime
+from typing import Any
+
+from .clock import clock_label, zone
+
+UV_FOCUS = ("outdoor", "outdoors", "uv", "sun", "sunburn", "beach", "hiking", "gardening")
+UV_HOURS = 24
+# WHO: sun protection is needed from UV 3; from 8 avoid the midday sun.
+PROTECT_UV = 3
+SHADE_UV = 8
+UV_BANDS = ((2, "Low"), (5, "Moderate"), (7, "High"), (10, "Very High"))
+_UV_DECISION = re.compile(r"\b(sunscreen|spf|uv|sunburn)\b", re.I)
+
+
+def is_uv_focus(focus: str | None) -> bool:
+    return bool(focus) and focus.strip().lower() in UV_FOCUS
+
+
+def uv_category(index: float | None) -> str | None:
+    if index is None:
+        return None
+    return next((name for limit, name in UV_BANDS if round(index) <= limit), "Extreme")
+
+
+def uv_pack(
+    hourly: dict[str, list[Any]] | None, *, hours: int = UV_HOURS, now: datetime | None = None
+) -> dict[str, Any] | None:
+    """Peak UV over the next ``hours`` and the first window needing protection (UV 3+)."""
+
+    if not hourly:
+        return None
+    start = (now or datetime.now(UTC)).replace(minute=0, second=0, microsecond=0)
+    rows = []
+    for stamp, index in zip(hourly.get("time") or [], hourly.get("uv_index") or [], strict=False):
+        moment = datetime.fromisoformat(str(stamp))
+        moment = moment if moment.tzinfo else moment.replace(tzinfo=UTC)
+        if moment >= start and isinstance(index, (int, float)) and not isinstance(index, bool):
+            rows.append((moment, float(index)))
+    rows = rows[:hours]
+    if not rows:
+        return None
+    peak_time, peak = max(rows, key=lambda row: row[1])
+    pack: dict[str, Any] = {
+        "peak": {"uv_index": round(peak, 1), "category": uv_category(peak), "time_iso": peak_time.isoformat()},
+        "protect": None,
+    }
+    window: list[tuple[datetime, float]] = []
+    for moment, index in rows:
+        if index >= PROTECT_UV:
+            window.append((moment, index))
+        elif window:
+            break
+    if window:
+        pack["protect"] = {
+            "start_iso": window[0][0].isoformat(),
+            "end_iso": window[-1][0].isoformat(),
+            "max_uv": round(max(index for _, index in window), 1),
+        }
+    return pack
+
+
+def sun_safety_actions(pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Sunscreen, reapplication and shade decisions for the UV 3+ window."""
+
+    protect = (pack or {}).get("protect")
+    if not protect:
+        return []
+    start, end = _when(protect["start_iso"], tz_name), _when(protect["end_iso"], tz_name)
+    window = f"around {start}" if start == end else f"from {start} to {end}"
+    reason = f"UV index up to {protect['max_uv']:.0f} ({uv_category(protect['max_uv'])})"
+    actions = [
+        f"Wear SPF 30+ sunscreen, a hat and sunglasses outdoors {window}: {reason}."
+        " Reapply every 2 hours and after swimming or sweating."
+    ]
+    if protect["max_uv"] >= SHADE_UV:
+        actions.append(f"Seek shade around {_when(pack['peak']['time_iso'], tz_name)}, when the sun is strongest.")
+    return actions
+
+
+def apply_sun_safety(response, pack: dict[str, Any] | None, tz_name: str | None = None) -> list[str]:
+    """Add sun-safety decisions the model left out when UV needs protection; returns those added.
+
+    Always records a one-line ``uv`` section when UV data is present.
+    """
+
+    if not pack or not pack.get("peak"):
+        return []
+    sections = response.sections
+    sections["uv"] = _summary_line(pack, tz_name)
+    decision_key = "answer" if "answer" in sections else "actions"
+    decisions = sections.get(decision_key)
+    decision_text = " ".join(decisions) if isinstance(decisions, list) else str(decisions or "")
+    added = [] if _UV_DECISION.search(decision_text) else sun_safety_actions(pack, tz_name)
+    if added and decision_key == "answer":
+        sections["answer"] = " ".join([decision_text.strip(), added[0]]).strip()
+        added = added[:1]
+    elif added:
+        actions = [item for item in decisions if isinstance(item, str)] if isinstance(decisions, list) else []
+        sections["actions"] = actions + added
+    response.meta = {**(response.meta or {}), "uv": {"peak": pack["peak"]["uv_index"], "added": added}}
+    return added
+
+
+def _summary_line(pack: dict[str, Any], tz_name: str | None) -> str:
+    peak = pack["peak"]
+    text = f"UV index peaks at {peak['uv_index']:.0f} ({peak['category']}) around {_when(peak['time_iso'], tz_name)}"
+    protect = pack.get("protect")
+    if protect:
+        start, end = _when(protect["start_iso"], tz_name), _when(protect["end_iso"], tz_name)
+        text += f"; protection needed {start}–{end}"
+    return text + "."
+
+
+def _when(iso: str, tz_name: str | None) -> str:
+    return clock_label(datetime.fromisoformat(iso), zone(tz_name))
@@ -0,0 +1,318 @@
+"""``wx watch``: periodic refetch of a place's conditions and alerts with change detection."""
+
+from __future__ import annotations
+
+import os
+import re
+import shlex
+import subprocess
+import time
+from collections.abc import Callable, Sequence
+from dataclasses import asdict, dataclass, field, replace
+from datetime import datetime
+from typing import Any
+
+from .cache import CachingProvider
+from .feel import feel_descriptor, feel_metrics, with_article
+from .safety import SAFETY_SEVERITIES
+from .staleness import DEFAULT_FORECAST_MAX_MINUTES, DEFAULT_OBS_MAX_MINUTES, stale_data, stale_warning
+from .units import convert, display_unit
+
+DEFAULT_INTERVAL = 600
+MIN_INTERVAL = 60
+OUTLOOK_HOURS = 12
+# Changes smaller than these (in Open-Meteo metric units) are noise between refreshes.
+TEMP_SWING_C = 3.0
+GUST_SHIFT_KMH = 15.0
+PRECIP_SHIFT_PCT = 20.0
+# Seconds an --on-change/--on-alert command may run before it is killed.
+HOOK_TIMEOUT = 30.0
+_INTERVAL = re.compile(r"^\s*(\d+(?:\.\d+)?)\s*([smh]?)\s*$", re.IGNORECASE)
+
+
+@dataclass(slots=True)
+class WatchSnapshot:
+    """Conditions, alerts and the near-term outlook at one refresh."""
+
+    place: str
+    fetched_at: float = field(default_factory=time.time)
+    current: dict[str, Any] = field(default_factory=dict)
+    alerts: list[dict[str, Any]] = field(default_factory=list)
+    # The alert feed could not be reached, so ``alerts`` is empty but not known to be quiet.
+    alerts_unavailable: bool = False
+    # Next outlook_hours: temp_max/temp_min (°C), gust_max (km/h), precip_prob_max (%),
+    # precip_total (mm).
+    outlook: dict[str, float] = field(default_factory=dict)
+    outlook_hours: int = OUTLOOK_HOURS
+    # "crisp fall morning"; see wx.feel.
+    feel: str | None = None
+    # Observation or forecast issuance past its staleness bound; see wx.staleness.
+    stale: list[dict[str, Any]] = field(default_factory=list)
+
+
+@dataclass(slots=True)
+class PlaceReport:
+    """A labelled place's snapshot, or why it could not be fetched (``wx favorites``/``compare``)."""
+
+    label: str
+    place: str
+    snapshot: WatchSnapshot | None = None
+    error: str | None = None
+
+
+@dataclass(slots=True)
+class WatchChange:
+    kind: str  # alert_new | alert_ended | temperature | outlook
+    message: str
+    important: bool = False
+
+
+class HookError(RuntimeError):
+    """An --on-change/--on-alert command failed to run or exited non-zero."""
+
+
+def parse_interval(value: str) -> int:
+    """Seconds for ``90s``/``10m``/``1h`` (bare numbers are minutes), at least a minute."""
+
+    match = _INTERVAL.match(value)
+    if not match:
+        raise ValueError(f"Invalid interval '{value}'. Use e.g. 90s, 10m or 1h.")
+    amount, unit = float(match.group(1)), match.group(2).lower() or "m"
+    seconds = int(amount * {"s": 1, "m": 60, "h": 3600}[unit])
+    if seconds < MIN_INTERVAL:
+        raise ValueError(f"Interval must be at least {MIN_INTERVAL}s to respect upstream rate limits.")
+    return seconds
+
+
+def parse_hook(value: str) -> tuple[str, ...]:
+    """Split a hook command line (``'~/bin/awning retract'``) into argv; no shell is involved."""
+
+    try:
+        command = tuple(shlex.split(value))
+    except ValueError as exc:
+        raise ValueError(f"Invalid command '{value}': {exc}.") from exc
+    if not command:
+        raise ValueError("The command is empty.")
+    return command
+
+
+def watch_payload(snapshot: WatchSnapshot, changes: list[WatchChange]) -> dict[str, Any]:
+    """The ``watch`` JSON document's data for one refresh, as printed and sent to hooks."""
+
+    return {**asdict(snapshot), "changes": [asdict(change) for change in changes]}
+
+
+def run_hook(command: Sequence[str], document: str, *, timeout: float = HOOK_TIMEOUT) -> None:
+    """Run ``command`` with ``document`` on stdin; its output is discarded so --json stays clean."""
+
+    try:
+        completed = subprocess.run(
+            [os.path.expanduser(command[0]), *command[1:]],
+            input=document,
+            capture_output=True,
+            text=True,
+            timeout=timeout,
+            check=False,
+        )
+    except subprocess.TimeoutExpired as exc:
+        raise HookError(f"timed out after {timeout:g}s") from exc
+    except OSError as exc:
+        raise HookError(f"could not start ({exc.strerror or exc})") from exc
+    if completed.returncode != 0:
+        stderr = completed.stderr.strip().splitlines()
+        reason = f"exited with status {completed.returncode}"
+        raise HookError(f"{reason}: {stderr[-1]}" if stderr else reason)
+
+
+def take_snapshot(
+    provider,
+    place_info: dict[str, Any],
+    *,
+    fresh: bool = True,
+    hours: int = OUTLOOK_HOURS,
+    now: datetime | None = None,
+    stale_after: tuple[int, int] = (DEFAULT_OBS_MAX_MINUTES, DEFAULT_FORECAST_MAX_MINUTES),
+) -> WatchSnapshot:
+    """Fetch data for an already geocoded place, bypassing the fetch cache when ``fresh``.
+
+    ``fresh`` flips a flag on the shared provider, so concurrent callers should pass False.
+    ``stale_after`` bounds the observation's and forecast issuance's age in minutes.
+    """
+
+    lat, lon = place_info["lat"], place_info["lon"]
+    bypass = fresh and isinstance(provider, CachingProvider)
+    if bypass:
+        provider.refresh = True
+    try:
+        current = provider.current(lat, lon) or {}
+        alerts = provider.alerts(lat, lon)
+        hourly = provider.forecast(lat, lon, hours=hours) or {}
+    finally:
+        if bypass:
+            provider.refresh = False
+    return WatchSnapshot(
+        place=place_info.get("resolved") or f"{lat},{lon}",
+        current=current,
+        alerts=list(alerts or []),
+        alerts_unavailable=alerts is None,
+        outlook=_outlook(hourly, hours),
+        outlook_hours=hours,
+        feel=feel_descriptor(feel_metrics(current, hourly), lat=lat, tz_name=place_info.get("tz"), now=now),
+        stale=stale_data(
+            observed_iso=current.get("observed_iso"),
+            issued_iso=hourly.get("issued"),
+            max_obs_minutes=stale_after[0],
+            max_forecast_minutes=stale_after[1],
+            now=now,
+        ),
+    )
+
+
+def diff_snapshots(previous: WatchSnapshot | None, latest: WatchSnapshot, units: str) -> list[WatchChange]:
+    """Changes worth highlighting between two refreshes (none for the first one)."""
+
+    if previous is None:
+        return []
+    changes: list[WatchChange] = []
+    # A failed alert fetch says nothing about which alerts began or ended.
+    if not (previous.alerts_unavailable or latest.alerts_unavailable):
+        before = {_alert_key(alert): alert for alert in previous.alerts}
+        after = {_alert_key(alert): alert for alert in latest.alerts}
+        for key, alert in after.items():
+            if key not in before:
+                severity = alert.get("severity") or "Unknown"
+                changes.append(WatchChange("alert_new", f"New {severity.lower()} alert: {alert.get('event')}", True))
+        for key, alert in before.items():
+            if key not in after:
+                changes.append(WatchChange("alert_ended", f"Alert ended: {alert.get('event')}"))
+
+    old_temp, new_temp = previous.current.get("temp"), latest.current.get("temp")
+    if _number(old_temp) and _number(new_temp) and abs(new_temp - old_temp) >= TEMP_SWING_C:
+        direction = "rose" if new_temp > old_temp else "dropped"
+        changes.append(
+            WatchChange(
+                "temperature",
+                f"Temperature {direction} {_temp(old_temp, units)} → {_temp(new_temp, units)}",
+                abs(new_temp - old_temp) >= 2 * TEMP_SWING_C,
+            )
+        )
+
+    shifts = (
+        ("temp_max", TEMP_SWING_C, "Next-12h high", lambda value: _temp(value, units)),
+        ("gust_max", GUST_SHIFT_KMH, "Next-12h peak gust", lambda value: _speed(value, units)),
+        ("precip_prob_max", PRECIP_SHIFT_PCT, "Next-12h precip chance", lambda value: f"{value:.0f}%"),
+    )
+    for key, threshold, label, fmt in shifts:
+        old, new = previous.outlook.get(key), latest.outlook.get(key)
+        if _number(old) and _number(new) and abs(new - old) >= threshold:
+            changes.append(WatchChange("outlook", f"{label} {fmt(old)} → {fmt(new)}"))
+    return changes
+
+
+def new_severe_alerts(previous: WatchSnapshot | None, latest: WatchSnapshot) -> list[dict[str, Any]]:
+    """Severe or Extreme alerts in ``latest`` that ``previous`` did not have (none for the first refresh)."""
+
+    if previous is None or previous.alerts_unavailable or latest.alerts_unavailable:
+        return []
+    before = {_alert_key(alert) for alert in previous.alerts}
+    return [
+        alert
+        for alert in latest.alerts
+        if alert.get("severity") in SAFETY_SEVERITIES and _alert_key(alert) not in before
+    ]
+
+
+def run_watch(
+    provider,
+    place_info: dict[str, Any],
+    *,
+    units: str,
+    interval: int = DEFAULT_INTERVAL,
+    count: int | None = None,
+    emit: Callable[[WatchSnapshot, list[WatchChange]], None],
+    sleep: Callable[[float], None] = time.sleep,
+    stale_after: tuple[int, int] = (DEFAULT_OBS_MAX_MINUTES, DEFAULT_FORECAST_MAX_MINUTES),
+    on_severe: Callable[[WatchSnapshot, list[dict[str, Any]]], None] | None = None,
+) -> None:
+    """Refresh every ``interval`` seconds (``count`` times, or until interrupted).
+
+    ``on_severe`` is called after ``emit`` with any newly issued Severe/Extreme alerts.
+    """
+
+    previous: WatchSnapshot | None = None
+    refreshes = 0
+    while count is None or refreshes < count:
+        if refreshes:
+            sleep(interval)
+        latest = take_snapshot(provider, place_info, stale_after=stale_after)
+        emit(latest, diff_snapshots(previous, latest, units))
+        severe = new_severe_alerts(previous, latest)
+        if on_severe is not None and severe:
+            on_severe(latest, severe)
+        if latest.alerts_unavailable and previous is not None:
+            # Compare the next refresh against the last alerts that were actually fetched.
+            latest = replace(latest, alerts=previous.alerts, alerts_unavailable=previous.alerts_unavailable)
+        previous = latest
+        refreshes += 1
+
+
+def snapshot_line(snapshot: WatchSnapshot, units: str) -> str:
+    """One-line conditions summary printed at every refresh."""
+
+    current = snapshot.current
+    parts = []
+    if _number(current.get("temp")):
+        parts.append(_temp(current["temp"], units))
+    if current.get("conditions"):
+        parts.append(str(current["conditions"]))
+    if _number(current.get("wind")):
+        wind = f"wind {_speed(current['wind'], units)}"
+        if _number(current.get("gust")):
+            wind += f" G{convert(current['gust'], 'speed', units):.0f}"
+        parts.append(wind)
+    outlook = snapshot.outlook
+    if _number(outlook.get("temp_max")) and _number(outlook.get("temp_min")):
+        parts.append(f"next {OUTLOOK_HOURS}h {_temp(outlook['temp_min'], units)}–{_temp(outlook['temp_max'], units)}")
+    if _number(outlook.get("precip_prob_max")):
+        parts.append(f"precip {outlook['precip_prob_max']:.0f}%")
+    if snapshot.alerts_unavailable:
+        parts.append("alerts unavailable")
+    else:
+        parts.append(f"{len(snapshot.alerts)} alert{'s' if len(snapshot.alerts) != 1 else ''}")
+    if snapshot.feel:
+        parts.append(f"feels like {with_article(snapshot.feel)}")
+    warning = stale_warning(snapshot.stale)
+    if warning:
+        parts.insert(0, warning)
+    return " · ".join(parts)
+
+
+def _outlook(hourly: dict[str, list[Any]], hours: int = OUTLOOK_HOURS) -> dict[str, float]:
+    outlook: dict[str, float] = {}
+    for key, source, pick in (
+        ("temp_max", "temperature_2m", max),
+        ("temp_min", "temperature_2m", min),
+        ("gust_max", "wind_gusts_10m", max),
+        ("precip_prob_max", "precipitation_probability", max),
+        ("precip_total", "precipitation", sum),
+    ):
+        values = [value for value in (hourly.get(source) or [])[:hours] if _number(value)]
+        if values:
+            outlook[key] = float(pick(values))
+    return outlook
+
+
+def _alert_key(alert: dict[str, Any]) -> tuple[Any, ...]:
+    return alert.get("event"), alert.get("expires_iso") or alert.get("headline")
+
+
+def _number(value: Any) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+def _temp(celsius: float, units: str) -> str:
+    return f"{convert(celsius, 'temp', units):.0f}{display_unit('temp', units)}"
+
+
+def _speed(kmh: float, units: str) -> str:
+    return f"{convert(kmh, 'speed', units):.0f} {display_unit('speed', units)}"